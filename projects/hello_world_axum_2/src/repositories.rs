@@ -17,12 +17,39 @@ impl RepositoryWithSqlx {
     }
 }
 
-#[derive(Error, Debug, PartialEq)]
+// sqlx::Error が PartialEq を実装していないため、PartialEq は derive しない
+// 比較したい場合は matches! を使う
+#[derive(Error, Debug)]
 pub enum RepositoryError {
-    #[error("NotFound, id is {0}")]
+    #[error("Resource not found: id={0}")]
     NotFound(Uuid),
-    #[error("Unexpected Error: [{0}]")]
-    Unexpected(String),
+    #[error("Unexpected error: {0}")]
+    Unexpected(#[from] sqlx::Error),
     #[error("Duplicated data, id is {0}")]
     Duplicated(Uuid),
 }
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use super::*;
+
+    #[test]
+    fn should_include_id_in_not_found_message() {
+        let id = Uuid::new_v4();
+        let message = RepositoryError::NotFound(id).to_string();
+        assert!(message.contains(&id.to_string()));
+    }
+
+    #[test]
+    fn should_return_sqlx_error_as_source_of_unexpected() {
+        let error = RepositoryError::from(sqlx::Error::PoolTimedOut);
+        assert!(error
+            .to_string()
+            .contains(&sqlx::Error::PoolTimedOut.to_string()));
+        assert!(error
+            .source()
+            .is_some_and(|source| source.is::<sqlx::Error>()));
+    }
+}
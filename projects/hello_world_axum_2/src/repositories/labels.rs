@@ -34,7 +34,7 @@ do update set name=$2
                 .bind(label.get_name().to_string())
                 .execute(&self.pool)
                 .await
-                .map_err(|e| RepositoryError::Unexpected(e.to_string()))?;
+                .map_err(RepositoryError::from)?;
             Ok(())
         }
 
@@ -46,7 +46,7 @@ do update set name=$2
                 .await
                 .map_err(|e| match e {
                     sqlx::Error::RowNotFound => RepositoryError::NotFound(label_id.clone()),
-                    _ => RepositoryError::Unexpected(e.to_string()),
+                    _ => RepositoryError::Unexpected(e),
                 })?;
             Ok(label)
         }
@@ -57,7 +57,7 @@ do update set name=$2
                 .bind(label_name.to_string())
                 .fetch_optional(&self.pool)
                 .await
-                .map_err(|e| RepositoryError::Unexpected(e.to_string()))?;
+                .map_err(RepositoryError::from)?;
             Ok(label)
         }
 
@@ -66,7 +66,7 @@ do update set name=$2
             let label = sqlx::query_as::<_, Label>(sql)
                 .fetch_all(&self.pool)
                 .await
-                .map_err(|e| RepositoryError::Unexpected(e.to_string()))?;
+                .map_err(RepositoryError::from)?;
             Ok(label)
         }
 
@@ -79,7 +79,7 @@ do update set name=$2
                 .await
                 .map_err(|e| match e {
                     sqlx::Error::RowNotFound => RepositoryError::NotFound(id.clone()),
-                    _ => RepositoryError::Unexpected(e.to_string()),
+                    _ => RepositoryError::Unexpected(e),
                 })?;
             Ok(())
         }
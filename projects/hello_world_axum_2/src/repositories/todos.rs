@@ -33,7 +33,7 @@ do update set text=$2, completed=$3
                 .bind(todo.get_completed())
                 .execute(&self.pool)
                 .await
-                .map_err(|e| RepositoryError::Unexpected(e.to_string()))?;
+                .map_err(RepositoryError::from)?;
             Ok(())
         }
 
@@ -45,7 +45,7 @@ do update set text=$2, completed=$3
                 .await
                 .map_err(|e| match e {
                     sqlx::Error::RowNotFound => RepositoryError::NotFound(todo_id.clone()),
-                    _ => RepositoryError::Unexpected(e.to_string()),
+                    _ => RepositoryError::Unexpected(e),
                 })?;
             Ok(todo)
         }
@@ -55,7 +55,7 @@ do update set text=$2, completed=$3
             let todo = sqlx::query_as::<_, Todo>(sql)
                 .fetch_all(&self.pool)
                 .await
-                .map_err(|e| RepositoryError::Unexpected(e.to_string()))?;
+                .map_err(RepositoryError::from)?;
             Ok(todo)
         }
 
@@ -68,7 +68,7 @@ do update set text=$2, completed=$3
                 .await
                 .map_err(|e| match e {
                     sqlx::Error::RowNotFound => RepositoryError::NotFound(id.clone()),
-                    _ => RepositoryError::Unexpected(e.to_string()),
+                    _ => RepositoryError::Unexpected(e),
                 })?;
             Ok(())
         }
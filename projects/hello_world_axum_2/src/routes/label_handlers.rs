@@ -121,7 +121,12 @@ where
             }
         }
         // <https://users.rust-lang.org/t/kind-method-not-found-when-using-anyhow-and-thiserror/81560> を参考に実装
-        Err(error) if error.downcast_ref() == Some(&RepositoryError::NotFound(id)) => {
+        Err(error)
+            if matches!(
+                error.downcast_ref(),
+                Some(RepositoryError::NotFound(not_found_id)) if *not_found_id == id
+            ) =>
+        {
             StatusCode::NOT_FOUND
         }
         Err(_) => StatusCode::INTERNAL_SERVER_ERROR,
@@ -0,0 +1,4 @@
+-- todos テーブルに作成日時・更新日時を追加
+ALTER TABLE todos
+    ADD COLUMN created_at  TIMESTAMPTZ NOT NULL DEFAULT now(),
+    ADD COLUMN updated_at  TIMESTAMPTZ NOT NULL DEFAULT now();
@@ -0,0 +1,3 @@
+-- User テーブルに role カラムを追加
+ALTER TABLE users
+    ADD COLUMN role TEXT NOT NULL DEFAULT 'Member';
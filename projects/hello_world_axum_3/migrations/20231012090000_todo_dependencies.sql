@@ -0,0 +1,10 @@
+-- todo 間の依存関係 (from_todo_id の todo が完了するまで to_todo_id の todo は完了できない) を表すテーブルを作成
+CREATE TABLE todo_dependencies
+(
+    id              UUID    PRIMARY KEY,
+    from_todo_id    UUID    NOT NULL,
+    FOREIGN KEY (from_todo_id) REFERENCES todos(id) ON DELETE CASCADE DEFERRABLE INITIALLY DEFERRED,
+    to_todo_id      UUID    NOT NULL,
+    FOREIGN KEY (to_todo_id) REFERENCES todos(id) ON DELETE CASCADE DEFERRABLE INITIALLY DEFERRED,
+    UNIQUE (from_todo_id, to_todo_id)
+);
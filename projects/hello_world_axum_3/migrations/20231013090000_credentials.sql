@@ -0,0 +1,7 @@
+-- ユーザーのパスワード (Argon2 ハッシュ) を保持するテーブルを作成
+CREATE TABLE credentials
+(
+    user_id         UUID    PRIMARY KEY,
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE DEFERRABLE INITIALLY DEFERRED,
+    password_hash   TEXT    NOT NULL
+);
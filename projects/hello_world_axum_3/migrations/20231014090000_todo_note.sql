@@ -0,0 +1,3 @@
+-- todos テーブルに note カラムを追加
+ALTER TABLE todos
+    ADD COLUMN note TEXT;
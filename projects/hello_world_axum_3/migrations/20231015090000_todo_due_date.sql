@@ -0,0 +1,3 @@
+-- todos テーブルに due_date カラムを追加
+ALTER TABLE todos
+    ADD COLUMN due_date DATE;
\ No newline at end of file
@@ -0,0 +1,9 @@
+-- ログインごとに発行するセッションを保持するテーブルを作成
+CREATE TABLE sessions
+(
+    id          UUID        PRIMARY KEY,
+    user_id     UUID        NOT NULL,
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE DEFERRABLE INITIALLY DEFERRED,
+    created_at  TIMESTAMPTZ NOT NULL,
+    revoked     BOOLEAN     NOT NULL DEFAULT false
+);
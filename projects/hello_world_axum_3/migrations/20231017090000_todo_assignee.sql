@@ -0,0 +1,4 @@
+-- todos テーブルに担当者の assignee_id カラムを追加
+-- 担当者のユーザーが削除された場合は未割り当てに戻す
+ALTER TABLE todos
+    ADD COLUMN assignee_id UUID REFERENCES users(id) ON DELETE SET NULL;
@@ -0,0 +1,3 @@
+-- todo に付けたラベルの表示順を保存する
+ALTER TABLE todo_labels
+    ADD COLUMN order_index INTEGER NOT NULL DEFAULT 0;
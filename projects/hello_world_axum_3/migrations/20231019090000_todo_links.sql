@@ -0,0 +1,10 @@
+-- todo のテキストに含まれていた URL を保存するテーブルを作成
+-- order_index はテキスト内での出現順
+CREATE TABLE todo_links
+(
+    todo_id         UUID    NOT NULL,
+    FOREIGN KEY (todo_id) REFERENCES todos(id) ON DELETE CASCADE DEFERRABLE INITIALLY DEFERRED,
+    url             TEXT    NOT NULL,
+    order_index     INTEGER NOT NULL,
+    PRIMARY KEY (todo_id, order_index)
+);
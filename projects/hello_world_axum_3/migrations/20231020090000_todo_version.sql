@@ -0,0 +1,3 @@
+-- 同時に更新されたことを検出するため、todos テーブルに版を追加する
+ALTER TABLE todos
+    ADD COLUMN version BIGINT NOT NULL DEFAULT 1;
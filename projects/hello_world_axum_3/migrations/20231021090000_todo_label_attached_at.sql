@@ -0,0 +1,3 @@
+-- ラベルを todo に付けた日時を保存する
+ALTER TABLE todo_labels
+    ADD COLUMN attached_at TIMESTAMPTZ NOT NULL DEFAULT NOW();
@@ -0,0 +1,4 @@
+-- パスワードを続けて間違えた回数と、ログインできなくなる期限を保存する
+ALTER TABLE credentials
+    ADD COLUMN failed_login_attempts INTEGER     NOT NULL DEFAULT 0,
+    ADD COLUMN locked_until          TIMESTAMPTZ;
@@ -0,0 +1,2 @@
+-- todo の text 同士の類似度を similarity() で求めるために pg_trgm を有効にする
+CREATE EXTENSION IF NOT EXISTS pg_trgm;
@@ -0,0 +1,5 @@
+-- ラベルの一覧を並べるときの位置。作成した時刻をマイクロ秒で表した値を既定値にする
+ALTER TABLE labels
+    ADD COLUMN position BIGINT NOT NULL DEFAULT (EXTRACT(EPOCH FROM NOW()) * 1000000)::BIGINT;
+
+CREATE INDEX labels_position_idx ON labels (position);
@@ -0,0 +1,4 @@
+-- users テーブルに論理削除した日時の deleted_at カラムを追加
+-- 論理削除したユーザーは匿名化した状態で残し、一覧などからは除く
+ALTER TABLE users
+    ADD COLUMN deleted_at TIMESTAMPTZ;
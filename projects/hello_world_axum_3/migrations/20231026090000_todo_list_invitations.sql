@@ -0,0 +1,21 @@
+-- todo のリストへの招待を保持するテーブルを作成
+-- リストのテーブルはまだないため、list_id には外部キー制約を付けない
+CREATE TABLE todo_list_invitations
+(
+    id              UUID        PRIMARY KEY,
+    list_id         UUID        NOT NULL,
+    invitee_email   TEXT        NOT NULL,
+    invited_by      UUID        NOT NULL,
+    FOREIGN KEY (invited_by) REFERENCES users(id) ON DELETE CASCADE DEFERRABLE INITIALLY DEFERRED,
+    status          TEXT        NOT NULL DEFAULT 'pending',
+    expires_at      TIMESTAMPTZ NOT NULL
+);
+
+-- 招待を承諾してリストに加わったユーザーを保持するテーブルを作成
+CREATE TABLE todo_list_members
+(
+    list_id     UUID    NOT NULL,
+    user_id     UUID    NOT NULL,
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE DEFERRABLE INITIALLY DEFERRED,
+    PRIMARY KEY (list_id, user_id)
+);
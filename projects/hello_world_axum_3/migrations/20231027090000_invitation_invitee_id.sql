@@ -0,0 +1,4 @@
+-- 招待に応答できるユーザーを限るため、招待先のユーザーを保持する列を追加
+-- 追加前に作られた招待では NULL になり、誰も応答できない
+ALTER TABLE todo_list_invitations
+    ADD COLUMN invitee_id UUID REFERENCES users(id) ON DELETE CASCADE DEFERRABLE INITIALLY DEFERRED;
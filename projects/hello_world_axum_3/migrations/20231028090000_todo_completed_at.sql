@@ -0,0 +1,7 @@
+-- todos テーブルに完了にした日時の completed_at カラムを追加
+-- updated_at は完了後の編集でも変わるため、完了した日の集計にはこちらを使う
+ALTER TABLE todos
+    ADD COLUMN completed_at TIMESTAMPTZ;
+
+-- 既に完了している todo は、最後に更新した日時を完了した日時とみなす
+UPDATE todos SET completed_at = updated_at WHERE completed;
@@ -0,0 +1,16 @@
+-- リソースの変更履歴を保持するテーブルを作成
+-- リソースが削除された後も履歴は残すため、resource_id には外部キー制約を付けない
+CREATE TABLE audit_log_entries
+(
+    id              UUID        PRIMARY KEY,
+    resource_type   TEXT        NOT NULL,
+    resource_id     UUID        NOT NULL,
+    actor_id        UUID,
+    FOREIGN KEY (actor_id) REFERENCES users(id) ON DELETE SET NULL,
+    action          TEXT        NOT NULL,
+    occurred_at     TIMESTAMPTZ NOT NULL,
+    before          JSONB       NOT NULL,
+    after           JSONB       NOT NULL
+);
+
+CREATE INDEX audit_log_entries_resource_idx ON audit_log_entries (resource_type, resource_id, occurred_at);
@@ -0,0 +1,228 @@
+use std::{env, time::Duration};
+
+use uuid::Uuid;
+
+use crate::{
+    application::todos::todo_get_all_aplication_service::DEFAULT_QUERY_TIMEOUT,
+    domain::models::todos::todo::MAX_LABELS_PER_TODO,
+};
+
+// 起動時に環境変数から読み込む、アプリケーション全体の設定
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AppConfig {
+    // 複数のインスタンスを動かすときに、トレースやリクエスト ID からインスタンスを区別するための値
+    // 未指定の場合は起動のたびに UUID を生成する
+    pub telemetry_id: String,
+    // 作成したリソースの URL を `Location` ヘッダーなどで返すときに、パスの前に付ける値 (例: "https://example.com")
+    // 未指定の場合は空文字列で、パスだけの相対 URL になる
+    pub base_url: String,
+    // todo の作成・更新で `normalize_case` が指定されなかったときに、先頭の文字を大文字にするかどうか
+    // 未指定の場合は `false` で、テキストをそのまま保存する
+    pub auto_sentence_case: bool,
+    // todo の一覧の取得で、リポジトリからの読み出しを待つ時間の上限 (ミリ秒)
+    // 超えた場合は 503 を返す。未指定または数値でない場合は `DEFAULT_QUERY_TIMEOUT` を使う
+    pub todo_query_timeout_ms: u64,
+    // todo の作成・更新で 1 つの todo に付けられるラベルの数の上限
+    // 未指定または数値でない場合は `MAX_LABELS_PER_TODO` を使う
+    pub max_labels_per_todo: usize,
+    // `Content-Type: application/json` のリクエストボディを `debug` レベルでログに出力するかどうか
+    // 未指定の場合は `false` で、ログに出力しない
+    pub request_body_logging_enabled: bool,
+    // `/api-docs/swagger-ui` で読み込む swagger-ui-dist の配信元 (例: "https://unpkg.com/swagger-ui-dist@5")
+    // CDN に接続できない環境では、自前で配信している URL を指定する。未指定の場合は `DEFAULT_SWAGGER_UI_ASSETS_URL` を使う
+    pub swagger_ui_assets_url: String,
+}
+
+pub const DEFAULT_SWAGGER_UI_ASSETS_URL: &str = "https://unpkg.com/swagger-ui-dist@5";
+
+impl Default for AppConfig {
+    fn default() -> Self {
+        Self {
+            telemetry_id: Uuid::new_v4().to_string(),
+            base_url: String::new(),
+            auto_sentence_case: false,
+            todo_query_timeout_ms: DEFAULT_QUERY_TIMEOUT.as_millis() as u64,
+            max_labels_per_todo: MAX_LABELS_PER_TODO,
+            request_body_logging_enabled: false,
+            swagger_ui_assets_url: DEFAULT_SWAGGER_UI_ASSETS_URL.to_string(),
+        }
+    }
+}
+
+impl AppConfig {
+    pub fn from_env() -> Self {
+        Self::from_lookup(|key| env::var(key).ok())
+    }
+
+    fn from_lookup(lookup: impl Fn(&str) -> Option<String>) -> Self {
+        let telemetry_id = lookup("TELEMETRY_ID")
+            .filter(|telemetry_id| !telemetry_id.is_empty())
+            .unwrap_or_else(|| Uuid::new_v4().to_string());
+        // パスと連結したときに "/" が重ならないよう、末尾の "/" は取り除く
+        let base_url = lookup("BASE_URL")
+            .map(|base_url| base_url.trim_end_matches('/').to_string())
+            .unwrap_or_default();
+        let auto_sentence_case = lookup("AUTO_SENTENCE_CASE")
+            .is_some_and(|auto_sentence_case| auto_sentence_case.eq_ignore_ascii_case("true"));
+        let todo_query_timeout_ms = lookup("TODO_QUERY_TIMEOUT_MS")
+            .and_then(|todo_query_timeout_ms| todo_query_timeout_ms.parse().ok())
+            .unwrap_or(DEFAULT_QUERY_TIMEOUT.as_millis() as u64);
+        let max_labels_per_todo = lookup("MAX_LABELS_PER_TODO")
+            .and_then(|max_labels_per_todo| max_labels_per_todo.parse().ok())
+            .unwrap_or(MAX_LABELS_PER_TODO);
+        let request_body_logging_enabled =
+            lookup("REQUEST_BODY_LOGGING_ENABLED").is_some_and(|request_body_logging_enabled| {
+                request_body_logging_enabled.eq_ignore_ascii_case("true")
+            });
+        // ファイル名と連結したときに "/" が重ならないよう、末尾の "/" は取り除く
+        let swagger_ui_assets_url = lookup("SWAGGER_UI_ASSETS_URL")
+            .filter(|swagger_ui_assets_url| !swagger_ui_assets_url.is_empty())
+            .map(|swagger_ui_assets_url| swagger_ui_assets_url.trim_end_matches('/').to_string())
+            .unwrap_or_else(|| DEFAULT_SWAGGER_UI_ASSETS_URL.to_string());
+        Self {
+            telemetry_id,
+            base_url,
+            auto_sentence_case,
+            todo_query_timeout_ms,
+            max_labels_per_todo,
+            request_body_logging_enabled,
+            swagger_ui_assets_url,
+        }
+    }
+
+    pub fn todo_query_timeout(&self) -> Duration {
+        Duration::from_millis(self.todo_query_timeout_ms)
+    }
+
+    // "/" から始まるパスを、外部から参照できる URL にする
+    pub fn resource_url(&self, path: &str) -> String {
+        format!("{}{}", self.base_url, path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_read_telemetry_id_from_env() {
+        let config = AppConfig::from_lookup(|key| match key {
+            "TELEMETRY_ID" => Some("instance-a".to_string()),
+            _ => None,
+        });
+        assert_eq!("instance-a", config.telemetry_id);
+    }
+
+    #[test]
+    fn should_generate_telemetry_id_if_not_given() {
+        let config = AppConfig::from_lookup(|_| None);
+        assert!(Uuid::parse_str(&config.telemetry_id).is_ok());
+
+        // 空文字列は指定されていないものとして扱う
+        let config = AppConfig::from_lookup(|_| Some("".to_string()));
+        assert!(Uuid::parse_str(&config.telemetry_id).is_ok());
+    }
+
+    #[test]
+    fn should_build_resource_url_from_base_url() {
+        let config = AppConfig::from_lookup(|key| match key {
+            "BASE_URL" => Some("https://example.com/".to_string()),
+            _ => None,
+        });
+        assert_eq!(
+            "https://example.com/todos/1",
+            config.resource_url("/todos/1")
+        );
+
+        // 未指定の場合は相対 URL になる
+        let config = AppConfig::from_lookup(|_| None);
+        assert_eq!("/todos/1", config.resource_url("/todos/1"));
+    }
+
+    #[test]
+    fn should_enable_auto_sentence_case_only_if_requested() {
+        assert!(!AppConfig::default().auto_sentence_case);
+        assert!(!AppConfig::from_lookup(|_| None).auto_sentence_case);
+
+        let config = AppConfig::from_lookup(|key| match key {
+            "AUTO_SENTENCE_CASE" => Some("true".to_string()),
+            _ => None,
+        });
+        assert!(config.auto_sentence_case);
+
+        let config = AppConfig::from_lookup(|key| match key {
+            "AUTO_SENTENCE_CASE" => Some("no".to_string()),
+            _ => None,
+        });
+        assert!(!config.auto_sentence_case);
+    }
+
+    #[test]
+    fn should_read_todo_query_timeout_from_env() {
+        assert_eq!(
+            DEFAULT_QUERY_TIMEOUT,
+            AppConfig::default().todo_query_timeout()
+        );
+
+        let config = AppConfig::from_lookup(|key| match key {
+            "TODO_QUERY_TIMEOUT_MS" => Some("1500".to_string()),
+            _ => None,
+        });
+        assert_eq!(Duration::from_millis(1500), config.todo_query_timeout());
+
+        // 数値でない場合は既定値を使う
+        let config = AppConfig::from_lookup(|key| match key {
+            "TODO_QUERY_TIMEOUT_MS" => Some("1.5s".to_string()),
+            _ => None,
+        });
+        assert_eq!(DEFAULT_QUERY_TIMEOUT, config.todo_query_timeout());
+    }
+
+    #[test]
+    fn should_read_max_labels_per_todo_from_env() {
+        assert_eq!(
+            MAX_LABELS_PER_TODO,
+            AppConfig::from_lookup(|_| None).max_labels_per_todo
+        );
+
+        let config = AppConfig::from_lookup(|key| match key {
+            "MAX_LABELS_PER_TODO" => Some("3".to_string()),
+            _ => None,
+        });
+        assert_eq!(3, config.max_labels_per_todo);
+    }
+
+    #[test]
+    fn should_enable_request_body_logging_only_if_requested() {
+        assert!(!AppConfig::from_lookup(|_| None).request_body_logging_enabled);
+
+        let config = AppConfig::from_lookup(|key| match key {
+            "REQUEST_BODY_LOGGING_ENABLED" => Some("true".to_string()),
+            _ => None,
+        });
+        assert!(config.request_body_logging_enabled);
+
+        let config = AppConfig::from_lookup(|key| match key {
+            "REQUEST_BODY_LOGGING_ENABLED" => Some("1".to_string()),
+            _ => None,
+        });
+        assert!(!config.request_body_logging_enabled);
+    }
+
+    #[test]
+    fn should_read_swagger_ui_assets_url_from_env() {
+        assert_eq!(
+            DEFAULT_SWAGGER_UI_ASSETS_URL,
+            AppConfig::from_lookup(|_| None).swagger_ui_assets_url
+        );
+
+        let config = AppConfig::from_lookup(|key| match key {
+            "SWAGGER_UI_ASSETS_URL" => Some("https://assets.example.com/swagger-ui/".to_string()),
+            _ => None,
+        });
+        assert_eq!(
+            "https://assets.example.com/swagger-ui",
+            config.swagger_ui_assets_url
+        );
+    }
+}
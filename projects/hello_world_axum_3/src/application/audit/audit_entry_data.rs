@@ -0,0 +1,30 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::domain::{models::audit_logs::audit_entry::AuditEntry, value_object::ValueObject};
+
+use super::diff::changes_summary;
+
+#[derive(Serialize, PartialEq, Debug)]
+pub struct AuditEntryData {
+    pub entry_id: Uuid,
+    // 認証せずに操作した場合は None
+    pub actor_name: Option<String>,
+    pub action: String,
+    pub occurred_at: DateTime<Utc>,
+    pub changes_summary: String,
+}
+
+impl AuditEntryData {
+    // 記録にはユーザー id しか残していないため、名前は呼び出し側で解決して渡す
+    pub fn new(entry: AuditEntry, actor_name: Option<String>) -> Self {
+        Self {
+            entry_id: *entry.entry_id().value(),
+            actor_name,
+            action: entry.action().to_string(),
+            occurred_at: *entry.occurred_at(),
+            changes_summary: changes_summary(entry.before(), entry.after()),
+        }
+    }
+}
@@ -0,0 +1,87 @@
+use serde_json::{Map, Value};
+
+// 変更前後の JSON を比べ、変わった項目を `text: 'old' → 'new'` の形式で列挙する
+// オブジェクト同士の場合はキーごとに比べ、片方にしかないキーは null として扱う
+// 作成や削除のように片方が null の場合は、空のオブジェクトと比べる
+pub fn changes_summary(before: &Value, after: &Value) -> String {
+    let empty = Value::Object(Map::new());
+    let (before, after) = match (before, after) {
+        (Value::Null, Value::Object(_)) => (&empty, after),
+        (Value::Object(_), Value::Null) => (before, &empty),
+        _ => (before, after),
+    };
+    match (before, after) {
+        (Value::Object(before), Value::Object(after)) => {
+            let mut keys: Vec<&String> = before.keys().chain(after.keys()).collect();
+            keys.sort();
+            keys.dedup();
+            keys.into_iter()
+                .filter_map(|key| {
+                    let old = before.get(key).unwrap_or(&Value::Null);
+                    let new = after.get(key).unwrap_or(&Value::Null);
+                    (old != new).then(|| format!("{}: {} → {}", key, display(old), display(new)))
+                })
+                .collect::<Vec<String>>()
+                .join(", ")
+        }
+        _ if before == after => String::new(),
+        _ => format!("{} → {}", display(before), display(after)),
+    }
+}
+
+fn display(value: &Value) -> String {
+    match value {
+        Value::String(s) => format!("'{}'", s),
+        _ => value.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn should_summarize_changed_fields_only() {
+        let before = json!({ "text": "old", "completed": false, "note": "same" });
+        let after = json!({ "text": "new", "completed": true, "note": "same" });
+
+        assert_eq!(
+            "completed: false → true, text: 'old' → 'new'",
+            changes_summary(&before, &after)
+        );
+    }
+
+    #[test]
+    fn should_treat_missing_field_as_null() {
+        let before = json!({ "text": "todo" });
+        let after = json!({ "text": "todo", "due_date": "2023-10-31" });
+
+        assert_eq!(
+            "due_date: null → '2023-10-31'",
+            changes_summary(&before, &after)
+        );
+    }
+
+    #[test]
+    fn should_compare_with_empty_object_if_one_side_is_null() {
+        let value = json!({ "text": "todo", "completed": false });
+
+        assert_eq!(
+            "completed: null → false, text: null → 'todo'",
+            changes_summary(&Value::Null, &value)
+        );
+        assert_eq!(
+            "completed: false → null, text: 'todo' → null",
+            changes_summary(&value, &Value::Null)
+        );
+    }
+
+    #[test]
+    fn should_return_empty_string_if_nothing_changed() {
+        let value = json!({ "text": "todo" });
+
+        assert_eq!("", changes_summary(&value, &value));
+    }
+}
@@ -0,0 +1,2 @@
+pub mod audit_entry_data;
+pub mod diff;
@@ -0,0 +1,258 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+
+use crate::domain::{
+    clock::{Clock, SystemClock},
+    models::{invitations::invitation_repository::IInvitationRepository, users::user_id::UserId},
+};
+
+use super::{
+    find_invitation, invitation_application_error::InvitationApplicationError,
+    invitation_data::InvitationData, reject_response, Result,
+};
+
+// trait of application service to accept an invitation to a todo list
+#[async_trait]
+pub trait IInvitationAcceptApplicationService<InvitationRep: IInvitationRepository> {
+    fn new(invitation_repository: Arc<InvitationRep>) -> Self;
+    async fn handle(&self, command: InvitationAcceptCommand) -> Result<InvitationData>;
+}
+
+// command object
+pub struct InvitationAcceptCommand {
+    pub invite_id: String,
+    // 招待を承諾してリストに加わるユーザー (認証済みのユーザー)
+    // 招待先のユーザー本人でなければ承諾できない
+    pub user_id: UserId,
+}
+
+// impl of application service to accept an invitation to a todo list
+pub struct InvitationAcceptApplicationService<InvitationRep: IInvitationRepository> {
+    invitation_repository: Arc<InvitationRep>,
+    clock: Arc<dyn Clock>,
+}
+
+impl<InvitationRep: IInvitationRepository> InvitationAcceptApplicationService<InvitationRep> {
+    pub fn with_clock(invitation_repository: Arc<InvitationRep>, clock: Arc<dyn Clock>) -> Self {
+        Self {
+            invitation_repository,
+            clock,
+        }
+    }
+}
+
+#[async_trait]
+impl<InvitationRep: IInvitationRepository> IInvitationAcceptApplicationService<InvitationRep>
+    for InvitationAcceptApplicationService<InvitationRep>
+{
+    fn new(invitation_repository: Arc<InvitationRep>) -> Self {
+        Self::with_clock(invitation_repository, Arc::new(SystemClock))
+    }
+
+    async fn handle(&self, command: InvitationAcceptCommand) -> Result<InvitationData> {
+        let InvitationAcceptCommand { invite_id, user_id } = command;
+        let mut invitation =
+            find_invitation(self.invitation_repository.as_ref(), invite_id).await?;
+
+        if let Err(e) = invitation.accept(&user_id, self.clock.now()) {
+            return Err(reject_response(self.invitation_repository.as_ref(), invitation, e).await);
+        }
+
+        // 承諾済みなのにメンバーでない状態が残らないよう、まとめて保存する
+        self.invitation_repository
+            .save_accepted(&invitation, &user_id)
+            .await
+            .map_err(|e| InvitationApplicationError::Unexpected(e.to_string()))?;
+
+        Ok(InvitationData::new(invitation))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use chrono::{DateTime, Duration, TimeZone, Utc};
+    use uuid::Uuid;
+
+    use crate::{
+        domain::{
+            clock::FixedClock,
+            models::invitations::{
+                invitation_status::InvitationStatus,
+                todo_list_invitation::{TodoListInvitation, INVITATION_VALID_DAYS},
+            },
+            value_object::ValueObject,
+        },
+        infra::repository_impl::in_memory::invitations::in_memory_invitation_repository::InMemoryInvitationRepository,
+    };
+
+    use super::*;
+
+    fn put_invitation(
+        invitation_repository: &InMemoryInvitationRepository,
+        invitee_id: &UserId,
+        now: DateTime<Utc>,
+    ) -> Result<TodoListInvitation> {
+        let invitation = TodoListInvitation::new(
+            Uuid::new_v4(),
+            "invitee@example.com".to_string(),
+            invitee_id.clone(),
+            UserId::new(Uuid::new_v4())?,
+            now,
+        )?;
+        let mut store = invitation_repository.write_store_ref();
+        store.insert(invitation.invite_id().clone(), invitation.clone());
+        Ok(invitation)
+    }
+
+    #[tokio::test]
+    async fn should_accept_invitation_and_add_user_to_list() -> Result<()> {
+        let invitation_repository = Arc::new(InMemoryInvitationRepository::new());
+        let now = Utc.with_ymd_and_hms(2023, 10, 31, 12, 0, 0).unwrap();
+        let user_id = UserId::new(Uuid::new_v4())?;
+        let invitation = put_invitation(&invitation_repository, &user_id, now)?;
+
+        let invitation_accept_application_service = InvitationAcceptApplicationService::with_clock(
+            invitation_repository.clone(),
+            Arc::new(FixedClock(now + Duration::days(1))),
+        );
+        let invitation_data = invitation_accept_application_service
+            .handle(InvitationAcceptCommand {
+                invite_id: invitation.invite_id().to_string(),
+                user_id: user_id.clone(),
+            })
+            .await?;
+
+        assert_eq!(InvitationStatus::Accepted.as_str(), invitation_data.status);
+        assert_eq!(
+            Some(InvitationStatus::Accepted),
+            invitation_repository
+                .find(invitation.invite_id())
+                .await?
+                .map(|invitation| invitation.status())
+        );
+        assert_eq!(
+            vec![user_id],
+            invitation_repository
+                .find_members_of(invitation.list_id())
+                .await?
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_reject_expired_invitation() -> Result<()> {
+        let invitation_repository = Arc::new(InMemoryInvitationRepository::new());
+        let now = Utc.with_ymd_and_hms(2023, 10, 31, 12, 0, 0).unwrap();
+        let user_id = UserId::new(Uuid::new_v4())?;
+        let invitation = put_invitation(&invitation_repository, &user_id, now)?;
+
+        let invitation_accept_application_service = InvitationAcceptApplicationService::with_clock(
+            invitation_repository.clone(),
+            Arc::new(FixedClock(now + Duration::days(INVITATION_VALID_DAYS))),
+        );
+        let command = || InvitationAcceptCommand {
+            invite_id: invitation.invite_id().to_string(),
+            user_id: user_id.clone(),
+        };
+        let result = invitation_accept_application_service
+            .handle(command())
+            .await;
+
+        assert_eq!(
+            Err(InvitationApplicationError::InvitationExpired(
+                invitation.invite_id().clone()
+            )),
+            result
+        );
+        // 期限切れになったことが保存され、メンバーには加わらない
+        assert_eq!(
+            InvitationStatus::Expired,
+            invitation_repository
+                .read_store_ref()
+                .get(invitation.invite_id())
+                .unwrap()
+                .status()
+        );
+        assert!(invitation_repository
+            .find_members_of(invitation.list_id())
+            .await?
+            .is_empty());
+
+        // 一度期限切れになった招待は、応答済みとして扱う
+        let result = invitation_accept_application_service
+            .handle(command())
+            .await;
+        assert_eq!(
+            Err(InvitationApplicationError::AlreadyResponded(
+                invitation.invite_id().clone(),
+                InvitationStatus::Expired
+            )),
+            result
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_reject_user_other_than_invitee() -> Result<()> {
+        let invitation_repository = Arc::new(InMemoryInvitationRepository::new());
+        let now = Utc.with_ymd_and_hms(2023, 10, 31, 12, 0, 0).unwrap();
+        let invitation =
+            put_invitation(&invitation_repository, &UserId::new(Uuid::new_v4())?, now)?;
+
+        let invitation_accept_application_service = InvitationAcceptApplicationService::with_clock(
+            invitation_repository.clone(),
+            Arc::new(FixedClock(now)),
+        );
+        let result = invitation_accept_application_service
+            .handle(InvitationAcceptCommand {
+                invite_id: invitation.invite_id().to_string(),
+                user_id: UserId::new(Uuid::new_v4())?,
+            })
+            .await;
+
+        assert_eq!(
+            Err(InvitationApplicationError::NotInvitee(
+                invitation.invite_id().clone()
+            )),
+            result
+        );
+        // 招待は保留中のまま残り、メンバーにも加わらない
+        assert_eq!(
+            Some(InvitationStatus::Pending),
+            invitation_repository
+                .find(invitation.invite_id())
+                .await?
+                .map(|invitation| invitation.status())
+        );
+        assert!(invitation_repository
+            .find_members_of(invitation.list_id())
+            .await?
+            .is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_throw_error_if_invitation_does_not_exist() -> Result<()> {
+        let invitation_repository = Arc::new(InMemoryInvitationRepository::new());
+        let invitation_accept_application_service =
+            InvitationAcceptApplicationService::new(invitation_repository);
+
+        let not_stored_invite_id = Uuid::new_v4();
+        let result = invitation_accept_application_service
+            .handle(InvitationAcceptCommand {
+                invite_id: not_stored_invite_id.to_string(),
+                user_id: UserId::new(Uuid::new_v4())?,
+            })
+            .await;
+
+        assert_eq!(
+            Err(InvitationApplicationError::InvitationNotFound(
+                ValueObject::new(not_stored_invite_id)?
+            )),
+            result
+        );
+        Ok(())
+    }
+}
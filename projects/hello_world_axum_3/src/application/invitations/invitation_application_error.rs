@@ -0,0 +1,34 @@
+use thiserror::Error;
+
+use uuid::Uuid;
+
+use crate::domain::models::{
+    invitations::{invitation_id::InvitationId, invitation_status::InvitationStatus},
+    users::user_id::UserId,
+};
+
+#[derive(Debug, Error, PartialEq)]
+pub enum InvitationApplicationError {
+    #[error("Invitation cannnot be found: [id: {0:?}]")]
+    InvitationNotFound(InvitationId),
+    #[error("Invitation has expired: [id: {0:?}]")]
+    InvitationExpired(InvitationId),
+    #[error("Invitation has already been {1}: [id: {0:?}]")]
+    AlreadyResponded(InvitationId, InvitationStatus),
+    #[error("Invitation is not addressed to the user: [id: {0:?}]")]
+    NotInvitee(InvitationId),
+    #[error("User is not a member of the list: [list id: {0}]")]
+    NotListMember(Uuid),
+    #[error("Invitee cannot be found: [id: {0:?}]")]
+    InviteeNotFound(UserId),
+    #[error("Given invitation is incorrect: [{0}]")]
+    IllegalArgumentError(String),
+    #[error("Given invitation id has incorrect format: [{0}]")]
+    IllegalInvitationId(String),
+    #[error("Given list id has incorrect format: [{0}]")]
+    IllegalListId(String),
+    #[error("Given user id has incorrect format: [{0}]")]
+    IllegalUserId(String),
+    #[error("Unexpected error: [{0}]")]
+    Unexpected(String),
+}
@@ -0,0 +1,333 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+use uuid::Uuid;
+
+use crate::domain::{
+    clock::{Clock, SystemClock},
+    events::{event_bus::EventBus, invitation_events::InvitationCreated},
+    models::{
+        invitations::{
+            invitation_repository::IInvitationRepository, todo_list_invitation::TodoListInvitation,
+        },
+        users::{user_id::UserId, user_repository::IUserRepository},
+    },
+};
+
+use super::{
+    invitation_application_error::InvitationApplicationError, invitation_data::InvitationData,
+    Result,
+};
+
+// trait of application service to invite a user to a todo list
+#[async_trait]
+pub trait IInvitationCreateApplicationService<
+    InvitationRep: IInvitationRepository,
+    UserRep: IUserRepository,
+>
+{
+    fn new(
+        invitation_repository: Arc<InvitationRep>,
+        user_repository: Arc<UserRep>,
+        event_bus: Arc<EventBus>,
+    ) -> Self;
+    async fn handle(&self, command: InvitationCreateCommand) -> Result<InvitationData>;
+}
+
+// command object
+pub struct InvitationCreateCommand {
+    pub list_id: String,
+    pub invitee_email: String,
+    // 招待するユーザーの id。招待に応答できるのはこのユーザーだけ
+    pub invitee_id: String,
+    // 招待したユーザー (認証済みのユーザー)
+    pub invited_by: UserId,
+}
+
+// impl of application service to invite a user to a todo list
+pub struct InvitationCreateApplicationService<
+    InvitationRep: IInvitationRepository,
+    UserRep: IUserRepository,
+> {
+    invitation_repository: Arc<InvitationRep>,
+    user_repository: Arc<UserRep>,
+    event_bus: Arc<EventBus>,
+    clock: Arc<dyn Clock>,
+}
+
+impl<InvitationRep, UserRep> InvitationCreateApplicationService<InvitationRep, UserRep>
+where
+    InvitationRep: IInvitationRepository,
+    UserRep: IUserRepository,
+{
+    pub fn with_clock(
+        invitation_repository: Arc<InvitationRep>,
+        user_repository: Arc<UserRep>,
+        event_bus: Arc<EventBus>,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
+        Self {
+            invitation_repository,
+            user_repository,
+            event_bus,
+            clock,
+        }
+    }
+}
+
+#[async_trait]
+impl<InvitationRep, UserRep> IInvitationCreateApplicationService<InvitationRep, UserRep>
+    for InvitationCreateApplicationService<InvitationRep, UserRep>
+where
+    InvitationRep: IInvitationRepository,
+    UserRep: IUserRepository,
+{
+    fn new(
+        invitation_repository: Arc<InvitationRep>,
+        user_repository: Arc<UserRep>,
+        event_bus: Arc<EventBus>,
+    ) -> Self {
+        Self::with_clock(
+            invitation_repository,
+            user_repository,
+            event_bus,
+            Arc::new(SystemClock),
+        )
+    }
+
+    async fn handle(&self, command: InvitationCreateCommand) -> Result<InvitationData> {
+        let InvitationCreateCommand {
+            list_id: list_id_string,
+            invitee_email,
+            invitee_id: invitee_id_string,
+            invited_by,
+        } = command;
+        let list_id = Uuid::try_parse(&list_id_string)
+            .map_err(|e| InvitationApplicationError::IllegalListId(e.to_string()))?;
+        let invitee_id = UserId::parse(invitee_id_string)
+            .map_err(|e| InvitationApplicationError::IllegalUserId(e.to_string()))?;
+
+        let invitation = TodoListInvitation::new(
+            list_id,
+            invitee_email,
+            invitee_id.clone(),
+            invited_by.clone(),
+            self.clock.now(),
+        )
+        .map_err(|e| InvitationApplicationError::IllegalArgumentError(e.to_string()))?;
+
+        let invitee_exists = self
+            .user_repository
+            .exists(&invitee_id)
+            .await
+            .map_err(|e| InvitationApplicationError::Unexpected(e.to_string()))?;
+        if !invitee_exists {
+            return Err(InvitationApplicationError::InviteeNotFound(invitee_id));
+        }
+
+        // リストのテーブルはまだないため、メンバーのいないリストに最初に招待したユーザーを所有者として扱う
+        let is_member = self
+            .invitation_repository
+            .join_if_first_or_is_member(&list_id, &invited_by)
+            .await
+            .map_err(|e| InvitationApplicationError::Unexpected(e.to_string()))?;
+        if !is_member {
+            return Err(InvitationApplicationError::NotListMember(list_id));
+        }
+
+        self.invitation_repository
+            .save(&invitation)
+            .await
+            .map_err(|e| InvitationApplicationError::Unexpected(e.to_string()))?;
+
+        // 招待メールは、このイベントを購読している側が送る
+        self.event_bus
+            .publish(InvitationCreated::new(invitation.clone()));
+
+        Ok(InvitationData::new(invitation))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use chrono::{Duration, TimeZone, Utc};
+
+    use crate::{
+        domain::{
+            clock::FixedClock,
+            models::{
+                invitations::{
+                    invitation_status::InvitationStatus,
+                    todo_list_invitation::INVITATION_VALID_DAYS,
+                },
+                users::{user::User, user_name::UserName},
+            },
+            value_object::ValueObject,
+        },
+        infra::repository_impl::in_memory::{
+            invitations::in_memory_invitation_repository::InMemoryInvitationRepository,
+            users::in_memory_user_repository::InMemoryUserRepository,
+        },
+    };
+
+    use super::*;
+
+    async fn save_user(user_repository: &InMemoryUserRepository, name: &str) -> Result<UserId> {
+        let user = User::new(UserName::new(name.to_string())?)?;
+        user_repository.save(&user).await?;
+        Ok(user.user_id().clone())
+    }
+
+    #[tokio::test]
+    async fn should_create_invitation_and_publish_event() -> Result<()> {
+        let invitation_repository = Arc::new(InMemoryInvitationRepository::new());
+        let user_repository = Arc::new(InMemoryUserRepository::new());
+        let event_bus = Arc::new(EventBus::new());
+        let mut receiver = event_bus.subscribe();
+        let now = Utc.with_ymd_and_hms(2023, 10, 31, 12, 0, 0).unwrap();
+        let invited_by = save_user(&user_repository, "inviter").await?;
+        let invitee_id = save_user(&user_repository, "invitee").await?;
+
+        let invitation_create_application_service = InvitationCreateApplicationService::with_clock(
+            invitation_repository.clone(),
+            user_repository,
+            event_bus.clone(),
+            Arc::new(FixedClock(now)),
+        );
+        let list_id = Uuid::new_v4();
+        let invitation_data = invitation_create_application_service
+            .handle(InvitationCreateCommand {
+                list_id: list_id.to_string(),
+                invitee_email: "invitee@example.com".to_string(),
+                invitee_id: invitee_id.to_string(),
+                invited_by: invited_by.clone(),
+            })
+            .await?;
+
+        assert_eq!(list_id, invitation_data.list_id);
+        assert_eq!(Some(*invitee_id.value()), invitation_data.invitee_id);
+        assert_eq!(invited_by.value(), &invitation_data.invited_by);
+        assert_eq!(InvitationStatus::Pending.as_str(), invitation_data.status);
+        assert_eq!(
+            now + Duration::days(INVITATION_VALID_DAYS),
+            invitation_data.expires_at
+        );
+        assert_eq!(1, invitation_repository.read_store_ref().len());
+        // メンバーのいないリストに招待したユーザーは、リストの最初のメンバーになる
+        assert_eq!(
+            vec![invited_by],
+            invitation_repository.find_members_of(&list_id).await?
+        );
+
+        let event = receiver.recv().await?;
+        assert_eq!("InvitationCreated", event.event_type());
+        assert_eq!("invitee@example.com", event.to_json()["invitee_email"]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_reject_inviter_who_is_not_member_of_list() -> Result<()> {
+        let invitation_repository = Arc::new(InMemoryInvitationRepository::new());
+        let user_repository = Arc::new(InMemoryUserRepository::new());
+        let owner_id = save_user(&user_repository, "owner").await?;
+        let outsider_id = save_user(&user_repository, "outsider").await?;
+        let list_id = Uuid::new_v4();
+        invitation_repository
+            .add_member(&list_id, &owner_id)
+            .await?;
+
+        let invitation_create_application_service = InvitationCreateApplicationService::new(
+            invitation_repository.clone(),
+            user_repository,
+            Arc::new(EventBus::new()),
+        );
+        // メンバーでないユーザーは、自分自身も含めて誰も招待できない
+        let result = invitation_create_application_service
+            .handle(InvitationCreateCommand {
+                list_id: list_id.to_string(),
+                invitee_email: "outsider@example.com".to_string(),
+                invitee_id: outsider_id.to_string(),
+                invited_by: outsider_id.clone(),
+            })
+            .await;
+
+        assert_eq!(
+            Err(InvitationApplicationError::NotListMember(list_id)),
+            result
+        );
+        assert!(invitation_repository.read_store_ref().is_empty());
+        assert_eq!(
+            vec![owner_id],
+            invitation_repository.find_members_of(&list_id).await?
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_throw_error_if_email_or_list_id_is_invalid() -> Result<()> {
+        let invitation_repository = Arc::new(InMemoryInvitationRepository::new());
+        let user_repository = Arc::new(InMemoryUserRepository::new());
+        let invited_by = save_user(&user_repository, "inviter").await?;
+        let invitee_id = save_user(&user_repository, "invitee").await?;
+        let invitation_create_application_service = InvitationCreateApplicationService::new(
+            invitation_repository.clone(),
+            user_repository,
+            Arc::new(EventBus::new()),
+        );
+        let command =
+            |list_id: &str, invitee_email: &str, invitee_id: &str| InvitationCreateCommand {
+                list_id: list_id.to_string(),
+                invitee_email: invitee_email.to_string(),
+                invitee_id: invitee_id.to_string(),
+                invited_by: invited_by.clone(),
+            };
+        let list_id = Uuid::new_v4().to_string();
+
+        let result = invitation_create_application_service
+            .handle(command(&list_id, "invitee", &invitee_id.to_string()))
+            .await;
+        assert!(matches!(
+            result,
+            Err(InvitationApplicationError::IllegalArgumentError(_))
+        ));
+
+        let result = invitation_create_application_service
+            .handle(command(
+                "not-a-uuid",
+                "invitee@example.com",
+                &invitee_id.to_string(),
+            ))
+            .await;
+        assert!(matches!(
+            result,
+            Err(InvitationApplicationError::IllegalListId(_))
+        ));
+
+        let result = invitation_create_application_service
+            .handle(command(&list_id, "invitee@example.com", "not-a-uuid"))
+            .await;
+        assert!(matches!(
+            result,
+            Err(InvitationApplicationError::IllegalUserId(_))
+        ));
+
+        let not_stored_user_id = Uuid::new_v4();
+        let result = invitation_create_application_service
+            .handle(command(
+                &list_id,
+                "invitee@example.com",
+                &not_stored_user_id.to_string(),
+            ))
+            .await;
+        assert_eq!(
+            Err(InvitationApplicationError::InviteeNotFound(
+                ValueObject::new(not_stored_user_id)?
+            )),
+            result
+        );
+
+        assert!(invitation_repository.read_store_ref().is_empty());
+        Ok(())
+    }
+}
@@ -0,0 +1,34 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::domain::{
+    models::invitations::todo_list_invitation::TodoListInvitation, value_object::ValueObject,
+};
+
+#[derive(Serialize, PartialEq, Debug)]
+pub struct InvitationData {
+    pub invite_id: Uuid,
+    pub list_id: Uuid,
+    pub invitee_email: String,
+    pub invitee_id: Option<Uuid>,
+    pub invited_by: Uuid,
+    pub status: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl InvitationData {
+    pub fn new(invitation: TodoListInvitation) -> Self {
+        Self {
+            invite_id: invitation.invite_id().clone().into_value(),
+            list_id: *invitation.list_id(),
+            invitee_email: invitation.invitee_email().to_string(),
+            invitee_id: invitation
+                .invitee_id()
+                .map(|invitee_id| *invitee_id.value()),
+            invited_by: invitation.invited_by().clone().into_value(),
+            status: invitation.status().as_str().to_string(),
+            expires_at: *invitation.expires_at(),
+        }
+    }
+}
@@ -0,0 +1,178 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+
+use crate::domain::{
+    clock::{Clock, SystemClock},
+    models::{invitations::invitation_repository::IInvitationRepository, users::user_id::UserId},
+};
+
+use super::{
+    find_invitation, invitation_application_error::InvitationApplicationError,
+    invitation_data::InvitationData, reject_response, Result,
+};
+
+// trait of application service to decline an invitation to a todo list
+#[async_trait]
+pub trait IInvitationDeclineApplicationService<InvitationRep: IInvitationRepository> {
+    fn new(invitation_repository: Arc<InvitationRep>) -> Self;
+    async fn handle(&self, command: InvitationDeclineCommand) -> Result<InvitationData>;
+}
+
+// command object
+pub struct InvitationDeclineCommand {
+    pub invite_id: String,
+    // 招待を辞退するユーザー (認証済みのユーザー)。招待先のユーザー本人でなければ辞退できない
+    pub user_id: UserId,
+}
+
+// impl of application service to decline an invitation to a todo list
+pub struct InvitationDeclineApplicationService<InvitationRep: IInvitationRepository> {
+    invitation_repository: Arc<InvitationRep>,
+    clock: Arc<dyn Clock>,
+}
+
+impl<InvitationRep: IInvitationRepository> InvitationDeclineApplicationService<InvitationRep> {
+    pub fn with_clock(invitation_repository: Arc<InvitationRep>, clock: Arc<dyn Clock>) -> Self {
+        Self {
+            invitation_repository,
+            clock,
+        }
+    }
+}
+
+#[async_trait]
+impl<InvitationRep: IInvitationRepository> IInvitationDeclineApplicationService<InvitationRep>
+    for InvitationDeclineApplicationService<InvitationRep>
+{
+    fn new(invitation_repository: Arc<InvitationRep>) -> Self {
+        Self::with_clock(invitation_repository, Arc::new(SystemClock))
+    }
+
+    async fn handle(&self, command: InvitationDeclineCommand) -> Result<InvitationData> {
+        let InvitationDeclineCommand { invite_id, user_id } = command;
+        let mut invitation =
+            find_invitation(self.invitation_repository.as_ref(), invite_id).await?;
+
+        if let Err(e) = invitation.decline(&user_id, self.clock.now()) {
+            return Err(reject_response(self.invitation_repository.as_ref(), invitation, e).await);
+        }
+
+        self.invitation_repository
+            .save(&invitation)
+            .await
+            .map_err(|e| InvitationApplicationError::Unexpected(e.to_string()))?;
+
+        Ok(InvitationData::new(invitation))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use chrono::{Duration, TimeZone, Utc};
+    use uuid::Uuid;
+
+    use crate::{
+        domain::{
+            clock::FixedClock,
+            models::invitations::{
+                invitation_status::InvitationStatus,
+                todo_list_invitation::{TodoListInvitation, INVITATION_VALID_DAYS},
+            },
+            value_object::ValueObject,
+        },
+        infra::repository_impl::in_memory::invitations::in_memory_invitation_repository::InMemoryInvitationRepository,
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn should_decline_invitation_without_adding_member() -> Result<()> {
+        let invitation_repository = Arc::new(InMemoryInvitationRepository::new());
+        let now = Utc.with_ymd_and_hms(2023, 10, 31, 12, 0, 0).unwrap();
+        let user_id = UserId::new(Uuid::new_v4())?;
+        let invitation = TodoListInvitation::new(
+            Uuid::new_v4(),
+            "invitee@example.com".to_string(),
+            user_id.clone(),
+            UserId::new(Uuid::new_v4())?,
+            now,
+        )?;
+        invitation_repository.save(&invitation).await?;
+
+        let invitation_decline_application_service =
+            InvitationDeclineApplicationService::with_clock(
+                invitation_repository.clone(),
+                Arc::new(FixedClock(now)),
+            );
+        let command = || InvitationDeclineCommand {
+            invite_id: invitation.invite_id().to_string(),
+            user_id: user_id.clone(),
+        };
+        let invitation_data = invitation_decline_application_service
+            .handle(command())
+            .await?;
+
+        assert_eq!(InvitationStatus::Declined.as_str(), invitation_data.status);
+        assert!(invitation_repository
+            .find_members_of(invitation.list_id())
+            .await?
+            .is_empty());
+
+        // 2 回目は応答済みとして扱う
+        let result = invitation_decline_application_service
+            .handle(command())
+            .await;
+        assert_eq!(
+            Err(InvitationApplicationError::AlreadyResponded(
+                invitation.invite_id().clone(),
+                InvitationStatus::Declined
+            )),
+            result
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_reject_expired_invitation() -> Result<()> {
+        let invitation_repository = Arc::new(InMemoryInvitationRepository::new());
+        let now = Utc.with_ymd_and_hms(2023, 10, 31, 12, 0, 0).unwrap();
+        let user_id = UserId::new(Uuid::new_v4())?;
+        let invitation = TodoListInvitation::new(
+            Uuid::new_v4(),
+            "invitee@example.com".to_string(),
+            user_id.clone(),
+            UserId::new(Uuid::new_v4())?,
+            now,
+        )?;
+        invitation_repository.save(&invitation).await?;
+
+        let invitation_decline_application_service =
+            InvitationDeclineApplicationService::with_clock(
+                invitation_repository.clone(),
+                Arc::new(FixedClock(now + Duration::days(INVITATION_VALID_DAYS + 1))),
+            );
+        let result = invitation_decline_application_service
+            .handle(InvitationDeclineCommand {
+                invite_id: invitation.invite_id().to_string(),
+                user_id,
+            })
+            .await;
+
+        assert_eq!(
+            Err(InvitationApplicationError::InvitationExpired(
+                invitation.invite_id().clone()
+            )),
+            result
+        );
+        assert_eq!(
+            Some(InvitationStatus::Expired),
+            invitation_repository
+                .find(invitation.invite_id())
+                .await?
+                .map(|invitation| invitation.status())
+        );
+        Ok(())
+    }
+}
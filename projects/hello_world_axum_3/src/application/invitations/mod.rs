@@ -0,0 +1,50 @@
+pub mod invitation_accept_application_service;
+pub mod invitation_application_error;
+pub mod invitation_create_application_service;
+pub mod invitation_data;
+pub mod invitation_decline_application_service;
+
+use crate::domain::models::invitations::{
+    invitation_id::InvitationId,
+    invitation_repository::IInvitationRepository,
+    todo_list_invitation::{InvitationResponseError, TodoListInvitation},
+};
+
+use self::invitation_application_error::InvitationApplicationError;
+
+pub type Result<T> = anyhow::Result<T, InvitationApplicationError>;
+
+async fn find_invitation<InvitationRep: IInvitationRepository>(
+    invitation_repository: &InvitationRep,
+    invite_id_string: String,
+) -> Result<TodoListInvitation> {
+    let invite_id = InvitationId::parse(invite_id_string)
+        .map_err(|e| InvitationApplicationError::IllegalInvitationId(e.to_string()))?;
+    invitation_repository
+        .find(&invite_id)
+        .await
+        .map_err(|e| InvitationApplicationError::Unexpected(e.to_string()))?
+        .ok_or(InvitationApplicationError::InvitationNotFound(invite_id))
+}
+
+// 応答できなかった理由をエラーとして返す
+// 期限切れの場合は、期限切れになったことを保存してから返す
+async fn reject_response<InvitationRep: IInvitationRepository>(
+    invitation_repository: &InvitationRep,
+    invitation: TodoListInvitation,
+    e: InvitationResponseError,
+) -> InvitationApplicationError {
+    let invite_id = invitation.invite_id().clone();
+    match e {
+        InvitationResponseError::Expired(_) => {
+            if let Err(e) = invitation_repository.save(&invitation).await {
+                return InvitationApplicationError::Unexpected(e.to_string());
+            }
+            InvitationApplicationError::InvitationExpired(invite_id)
+        }
+        InvitationResponseError::AlreadyResponded(status) => {
+            InvitationApplicationError::AlreadyResponded(invite_id, status)
+        }
+        InvitationResponseError::NotInvitee => InvitationApplicationError::NotInvitee(invite_id),
+    }
+}
@@ -1,8 +1,9 @@
 use serde::Serialize;
 use thiserror::Error;
 
-use crate::domain::models::labels::{label::Label, label_id::LabelId};
-
+use crate::domain::models::labels::{
+    label::Label, label_id::LabelId, label_repository::LabelRepositoryError,
+};
 
 #[derive(Debug, Error, PartialEq)]
 pub enum LabelApplicationError {
@@ -18,6 +19,17 @@ pub enum LabelApplicationError {
     Unexpected(String),
 }
 
+impl From<LabelRepositoryError> for LabelApplicationError {
+    fn from(e: LabelRepositoryError) -> Self {
+        match e {
+            LabelRepositoryError::NotFound(label_id) => Self::LabelNotFound(label_id),
+            // 重複を扱う必要がある場合は、変換する前に呼び出し側で処理する
+            LabelRepositoryError::AlreadyExists(_) => Self::Unexpected(e.to_string()),
+            LabelRepositoryError::Unexpected(e) => Self::Unexpected(e),
+        }
+    }
+}
+
 // <https://github.com/serde-rs/serde/issues/2268#issuecomment-1238962452> を参考に実装
 impl Serialize for LabelApplicationError {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -27,3 +39,79 @@ impl Serialize for LabelApplicationError {
         serializer.serialize_str(self.to_string().as_ref())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::domain::{models::labels::label_name::LabelName, value_object::ValueObject};
+
+    #[test]
+    fn should_compare_every_variant_by_value() -> Result<()> {
+        use LabelApplicationError::*;
+
+        let label_1 = Label::new(LabelName::new("label-1".to_string())?)?;
+        let label_2 = Label::new(LabelName::new("label-2".to_string())?)?;
+        let label_id_1 = LabelId::new(Uuid::new_v4())?;
+        let label_id_2 = LabelId::new(Uuid::new_v4())?;
+
+        // (比較対象, 同じ値, 異なる値)
+        let cases = [
+            (
+                DuplicatedLabel(label_1.clone()),
+                DuplicatedLabel(label_1),
+                DuplicatedLabel(label_2),
+            ),
+            (
+                LabelNotFound(label_id_1.clone()),
+                LabelNotFound(label_id_1),
+                LabelNotFound(label_id_2),
+            ),
+            (
+                IllegalArgumentError("a".to_string()),
+                IllegalArgumentError("a".to_string()),
+                IllegalArgumentError("b".to_string()),
+            ),
+            (
+                IllegalLabelId("a".to_string()),
+                IllegalLabelId("a".to_string()),
+                IllegalLabelId("b".to_string()),
+            ),
+            (
+                Unexpected("a".to_string()),
+                Unexpected("a".to_string()),
+                Unexpected("b".to_string()),
+            ),
+        ];
+
+        for (error, same, different) in cases {
+            assert_eq!(error, same);
+            assert_ne!(error, different);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn should_convert_every_repository_error() -> Result<()> {
+        let label_id = LabelId::new(Uuid::new_v4())?;
+
+        assert_eq!(
+            LabelApplicationError::LabelNotFound(label_id.clone()),
+            LabelRepositoryError::NotFound(label_id).into()
+        );
+        // 重複は呼び出し側で処理するため、ここに来た場合は想定外のエラーとして扱う
+        let already_exists =
+            LabelRepositoryError::AlreadyExists(LabelName::new("label-1".to_string())?);
+        assert_eq!(
+            LabelApplicationError::Unexpected(already_exists.to_string()),
+            already_exists.into()
+        );
+        assert_eq!(
+            LabelApplicationError::Unexpected("a".to_string()),
+            LabelRepositoryError::Unexpected("a".to_string()).into()
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,186 @@
+use std::{collections::HashSet, sync::Arc};
+
+use axum::async_trait;
+
+use super::{label_create_application_service::LabelCreateCommand, label_data::LabelData, Result};
+
+use crate::domain::{
+    models::labels::{label::Label, label_name::LabelName, label_repository::ILabelRepository},
+    services::label_service::LabelService,
+};
+
+use super::label_application_error::LabelApplicationError;
+
+// trait of application service to create labels at once
+#[async_trait]
+pub trait ILabelBulkCreateApplicationService<T: ILabelRepository> {
+    fn new(label_repository: Arc<T>) -> Self;
+    async fn handle(&self, command: LabelBulkCreateCommand) -> Result<BulkCreateLabelResult>;
+}
+
+// command object
+pub struct LabelBulkCreateCommand {
+    pub labels: Vec<LabelCreateCommand>,
+}
+
+// 作成できたラベルと、作成できなかったラベルの (バッチ内の位置, エラー) の組
+#[derive(Debug, PartialEq)]
+pub struct BulkCreateLabelResult {
+    pub created: Vec<LabelData>,
+    pub errors: Vec<(usize, LabelApplicationError)>,
+}
+
+// impl of application service to create labels at once
+pub struct LabelBulkCreateApplicationService<T: ILabelRepository> {
+    label_repository: Arc<T>,
+    label_service: LabelService<T>,
+}
+
+#[async_trait]
+impl<T: ILabelRepository> ILabelBulkCreateApplicationService<T>
+    for LabelBulkCreateApplicationService<T>
+{
+    fn new(label_repository: Arc<T>) -> Self {
+        Self {
+            label_repository: label_repository.clone(),
+            label_service: LabelService::new(label_repository),
+        }
+    }
+
+    async fn handle(&self, command: LabelBulkCreateCommand) -> Result<BulkCreateLabelResult> {
+        let LabelBulkCreateCommand { labels: commands } = command;
+
+        let mut new_labels = Vec::<Label>::new();
+        let mut errors = Vec::<(usize, LabelApplicationError)>::new();
+        // バッチ内で既に出現したラベル名
+        let mut names_in_batch = HashSet::<LabelName>::new();
+
+        for (index, LabelCreateCommand { label_name }) in commands.into_iter().enumerate() {
+            let label_name = match label_name.parse::<LabelName>() {
+                Ok(label_name) => label_name,
+                Err(e) => {
+                    errors.push((
+                        index,
+                        LabelApplicationError::IllegalArgumentError(e.to_string()),
+                    ));
+                    continue;
+                }
+            };
+            let new_label = Label::new(label_name.clone())
+                .map_err(|e| LabelApplicationError::Unexpected(e.to_string()))?;
+
+            let is_duplicated = !names_in_batch.insert(label_name)
+                || self
+                    .label_service
+                    .is_duplicated(&new_label)
+                    .await
+                    .map_err(|e| LabelApplicationError::Unexpected(e.to_string()))?;
+            if is_duplicated {
+                errors.push((index, LabelApplicationError::DuplicatedLabel(new_label)));
+                continue;
+            }
+
+            new_labels.push(new_label);
+        }
+
+        self.label_repository.save_all(&new_labels).await?;
+
+        Ok(BulkCreateLabelResult {
+            created: new_labels.into_iter().map(LabelData::new).collect(),
+            errors,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use crate::{
+        domain::{models::labels::label_id::LabelId, value_object::ValueObject},
+        infra::repository_impl::in_memory::labels::in_memory_label_repository::InMemoryLabelRepository,
+    };
+
+    use super::*;
+
+    fn command_for_test(label_names: &[&str]) -> LabelBulkCreateCommand {
+        LabelBulkCreateCommand {
+            labels: label_names
+                .iter()
+                .map(|label_name| LabelCreateCommand {
+                    label_name: label_name.to_string(),
+                })
+                .collect(),
+        }
+    }
+
+    #[tokio::test]
+    async fn should_report_duplicate_in_batch_and_create_the_others() -> Result<()> {
+        let repository = Arc::new(InMemoryLabelRepository::new());
+        let label_bulk_create_application_service =
+            LabelBulkCreateApplicationService::new(repository.clone());
+
+        let command = command_for_test(&["label-1", "label-1", "label-2"]);
+        let result = label_bulk_create_application_service
+            .handle(command)
+            .await?;
+
+        let created_names: Vec<&str> = result
+            .created
+            .iter()
+            .map(|label_data| label_data.label_name.as_str())
+            .collect();
+        assert_eq!(vec!["label-1", "label-2"], created_names);
+        assert_eq!(1, result.errors.len());
+        let (1, LabelApplicationError::DuplicatedLabel(label)) = &result.errors[0] else {
+            panic!("unexpected error: {:?}", result.errors[0]);
+        };
+        assert_eq!("label-1", label.label_name.value());
+
+        // get labels saved in store
+        assert_eq!(2, repository.find_all().await?.len());
+        for label_data in result.created {
+            assert!(repository
+                .find(&LabelId::new(label_data.label_id)?)
+                .await?
+                .is_some());
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_report_invalid_and_already_stored_labels() -> Result<()> {
+        let repository = Arc::new(InMemoryLabelRepository::new());
+
+        // Put the data in advance
+        repository
+            .save(&Label::new(LabelName::new("tester-1".to_string())?)?)
+            .await?;
+
+        let label_bulk_create_application_service =
+            LabelBulkCreateApplicationService::new(repository.clone());
+
+        let command = command_for_test(&["", "tester-1", "tester-2"]);
+        let result = label_bulk_create_application_service
+            .handle(command)
+            .await?;
+
+        assert_eq!(1, result.created.len());
+        assert_eq!("tester-2", result.created[0].label_name);
+        assert_eq!(
+            (
+                0,
+                LabelApplicationError::IllegalArgumentError(
+                    "Label name must not be empty.".to_string()
+                )
+            ),
+            result.errors[0]
+        );
+        let (1, LabelApplicationError::DuplicatedLabel(label)) = &result.errors[1] else {
+            panic!("unexpected error: {:?}", result.errors[1]);
+        };
+        assert_eq!("tester-1", label.label_name.value());
+        assert_eq!(2, repository.find_all().await?.len());
+        Ok(())
+    }
+}
@@ -0,0 +1,196 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+
+use super::Result;
+
+use crate::domain::models::labels::{label_id::LabelId, label_repository::ILabelRepository};
+
+// trait of application service to delete labels at once
+#[async_trait]
+pub trait ILabelBulkDeleteApplicationService<T: ILabelRepository> {
+    fn new(label_repository: Arc<T>) -> Self;
+    async fn handle(&self, command: LabelBulkDeleteCommand) -> Result<BulkDeleteLabelResult>;
+}
+
+// command object
+pub struct LabelBulkDeleteCommand {
+    pub label_ids: Vec<String>,
+    // true の場合は todo に付いているラベルも、todo から外して削除する
+    pub force: bool,
+}
+
+// 削除したラベルと、削除しなかったラベルをその理由ごとに分けたもの
+#[derive(Debug, PartialEq)]
+pub struct BulkDeleteLabelResult {
+    pub deleted: Vec<LabelId>,
+    // todo に付いているため削除しなかったラベルと、付いている todo の数
+    pub in_use: Vec<(LabelId, u64)>,
+    pub not_found: Vec<LabelId>,
+    // id として読めなかったもの (バッチ内の位置, エラーメッセージ)
+    pub parse_errors: Vec<(usize, String)>,
+}
+
+// impl of application service to delete labels at once
+pub struct LabelBulkDeleteApplicationService<T: ILabelRepository> {
+    label_repository: Arc<T>,
+}
+
+#[async_trait]
+impl<T: ILabelRepository> ILabelBulkDeleteApplicationService<T>
+    for LabelBulkDeleteApplicationService<T>
+{
+    fn new(label_repository: Arc<T>) -> Self {
+        Self { label_repository }
+    }
+
+    async fn handle(&self, command: LabelBulkDeleteCommand) -> Result<BulkDeleteLabelResult> {
+        let LabelBulkDeleteCommand { label_ids, force } = command;
+
+        let mut result = BulkDeleteLabelResult {
+            deleted: vec![],
+            in_use: vec![],
+            not_found: vec![],
+            parse_errors: vec![],
+        };
+
+        let mut candidate_ids: Vec<LabelId> = vec![];
+        for (index, label_id) in label_ids.into_iter().enumerate() {
+            match LabelId::parse(label_id) {
+                // 同じ id が複数回指定された場合は、最初の一つだけを扱う
+                Ok(label_id) if candidate_ids.contains(&label_id) => {}
+                Ok(label_id) => candidate_ids.push(label_id),
+                Err(e) => result.parse_errors.push((index, e.to_string())),
+            }
+        }
+
+        let mut existing_ids = vec![];
+        for label_id in candidate_ids {
+            if self.label_repository.find(&label_id).await?.is_some() {
+                existing_ids.push(label_id);
+            } else {
+                result.not_found.push(label_id);
+            }
+        }
+
+        if force {
+            self.label_repository.delete_all(&existing_ids).await?;
+            result.deleted = existing_ids;
+            return Ok(result);
+        }
+
+        // todo に付いていないことの確認と削除は、間に todo に付けられないようリポジトリで 1 度に行う
+        result.deleted = self
+            .label_repository
+            .delete_all_unused(&existing_ids)
+            .await?;
+        // 削除しなかったラベルは todo に付いていたもの。件数は結果を伝えるためだけに数える
+        let todo_counts = self.label_repository.count_todos_per_label_map().await?;
+        for label_id in existing_ids {
+            if result.deleted.contains(&label_id) {
+                continue;
+            }
+            match todo_counts.get(&label_id) {
+                Some(&todo_count) => result.in_use.push((label_id, todo_count)),
+                // 削除の後に todo から外されたか、他のリクエストで削除された
+                None if self.label_repository.find(&label_id).await?.is_some() => {
+                    result.in_use.push((label_id, 0))
+                }
+                None => result.not_found.push(label_id),
+            }
+        }
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use uuid::Uuid;
+
+    use crate::{
+        domain::{
+            models::{
+                labels::{label::Label, label_name::LabelName},
+                todos::{todo::Todo, todo_repository::ITodoRepository, todo_text::TodoText},
+            },
+            value_object::ValueObject,
+        },
+        infra::repository_impl::in_memory::{
+            labels::in_memory_label_repository::InMemoryLabelRepository,
+            todos::in_memory_todo_repository::InMemoryTodoRepository,
+        },
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn should_report_labels_in_use_not_found_and_illegal_ids() -> Result<()> {
+        let todo_repository = InMemoryTodoRepository::new();
+        let repository = Arc::new(InMemoryLabelRepository::with_todo_repository(
+            todo_repository.clone(),
+        ));
+        let unused = Label::new(LabelName::new("unused".to_string())?)?;
+        let used = Label::new(LabelName::new("used".to_string())?)?;
+        repository.save_all(&[unused.clone(), used.clone()]).await?;
+        for i in 0..2 {
+            let todo = Todo::new(TodoText::new(format!("todo-{}", i))?, vec![used.clone()])?;
+            todo_repository.save(&todo).await?;
+        }
+        let missing_id = LabelId::new(Uuid::new_v4())?;
+
+        let label_bulk_delete_application_service =
+            LabelBulkDeleteApplicationService::new(repository.clone());
+        let result = label_bulk_delete_application_service
+            .handle(LabelBulkDeleteCommand {
+                label_ids: vec![
+                    unused.label_id().to_string(),
+                    used.label_id().to_string(),
+                    missing_id.to_string(),
+                    "not-a-uuid".to_string(),
+                ],
+                force: false,
+            })
+            .await?;
+
+        assert_eq!(vec![unused.label_id().clone()], result.deleted);
+        assert_eq!(vec![(used.label_id().clone(), 2)], result.in_use);
+        assert_eq!(vec![missing_id], result.not_found);
+        assert_eq!(1, result.parse_errors.len());
+        assert_eq!(3, result.parse_errors[0].0);
+
+        // 使われているラベルは残る
+        assert!(repository.find(unused.label_id()).await?.is_none());
+        assert!(repository.find(used.label_id()).await?.is_some());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_delete_labels_in_use_if_forced() -> Result<()> {
+        let todo_repository = InMemoryTodoRepository::new();
+        let repository = Arc::new(InMemoryLabelRepository::with_todo_repository(
+            todo_repository.clone(),
+        ));
+        let used = Label::new(LabelName::new("used".to_string())?)?;
+        repository.save(&used).await?;
+        let todo = Todo::new(TodoText::new("todo".to_string())?, vec![used.clone()])?;
+        todo_repository.save(&todo).await?;
+
+        let label_bulk_delete_application_service =
+            LabelBulkDeleteApplicationService::new(repository.clone());
+        let result = label_bulk_delete_application_service
+            .handle(LabelBulkDeleteCommand {
+                label_ids: vec![used.label_id().to_string(), used.label_id().to_string()],
+                force: true,
+            })
+            .await?;
+
+        assert_eq!(vec![used.label_id().clone()], result.deleted);
+        assert!(result.in_use.is_empty());
+        assert!(repository.find(used.label_id()).await?.is_none());
+        // todo からも外れる
+        let todo_found = todo_repository.find(todo.todo_id()).await?.unwrap();
+        assert!(todo_found.labels.is_empty());
+        Ok(())
+    }
+}
@@ -0,0 +1,205 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+
+use super::{label_data::LabelData, Result};
+
+use crate::domain::models::labels::{
+    label::Label,
+    label_id::LabelId,
+    label_name::LabelName,
+    label_repository::{ILabelRepository, LabelRepositoryError},
+};
+
+use super::label_application_error::LabelApplicationError;
+
+// trait of application service to rename labels at once
+#[async_trait]
+pub trait ILabelBulkRenameApplicationService<T: ILabelRepository> {
+    fn new(label_repository: Arc<T>) -> Self;
+    async fn handle(&self, command: LabelBulkRenameCommand) -> Result<Vec<LabelData>>;
+}
+
+// command object
+pub struct LabelBulkRenameCommand {
+    pub renames: Vec<LabelRename>,
+}
+
+pub struct LabelRename {
+    pub label_id: String,
+    pub label_name: String,
+}
+
+// impl of application service to rename labels at once
+pub struct LabelBulkRenameApplicationService<T: ILabelRepository> {
+    label_repository: Arc<T>,
+}
+
+#[async_trait]
+impl<T: ILabelRepository> ILabelBulkRenameApplicationService<T>
+    for LabelBulkRenameApplicationService<T>
+{
+    fn new(label_repository: Arc<T>) -> Self {
+        Self { label_repository }
+    }
+
+    async fn handle(&self, command: LabelBulkRenameCommand) -> Result<Vec<LabelData>> {
+        let LabelBulkRenameCommand { renames: commands } = command;
+
+        let renames = commands
+            .into_iter()
+            .map(
+                |LabelRename {
+                     label_id,
+                     label_name,
+                 }| {
+                    let label_id = LabelId::parse(label_id)
+                        .map_err(|e| LabelApplicationError::IllegalLabelId(e.to_string()))?;
+                    let label_name = label_name
+                        .parse::<LabelName>()
+                        .map_err(|e| LabelApplicationError::IllegalArgumentError(e.to_string()))?;
+                    Ok((label_id, label_name))
+                },
+            )
+            .collect::<Result<Vec<(LabelId, LabelName)>>>()?;
+
+        match self.label_repository.rename_bulk(&renames).await {
+            Ok(labels) => Ok(labels.into_iter().map(LabelData::new).collect()),
+            Err(LabelRepositoryError::AlreadyExists(label_name)) => {
+                // 既存のラベルと衝突していなければ、バッチ内で同じ名前に変更しようとしている
+                let label_found = self.label_repository.find_by_name(&label_name).await?;
+                let label = label_found.unwrap_or_else(|| {
+                    let (label_id, _) = renames
+                        .iter()
+                        .find(|(_, new_name)| new_name == &label_name)
+                        .expect("conflicting name must be one of the new names");
+                    Label::build(label_id.clone(), label_name)
+                });
+                Err(LabelApplicationError::DuplicatedLabel(label))
+            }
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use crate::{
+        domain::value_object::ValueObject,
+        infra::repository_impl::in_memory::labels::in_memory_label_repository::InMemoryLabelRepository,
+    };
+
+    use super::*;
+
+    async fn repository_with_labels(
+        names: &[&str],
+    ) -> Result<(Vec<Label>, Arc<InMemoryLabelRepository>)> {
+        let repository = Arc::new(InMemoryLabelRepository::new());
+        let mut labels = vec![];
+        // Put the data in advance
+        for name in names {
+            let label = Label::new(LabelName::new(name.to_string())?)?;
+            repository.save(&label).await?;
+            labels.push(label);
+        }
+        Ok((labels, repository))
+    }
+
+    fn command(labels: &[Label], names: &[&str]) -> LabelBulkRenameCommand {
+        LabelBulkRenameCommand {
+            renames: labels
+                .iter()
+                .zip(names)
+                .map(|(label, name)| LabelRename {
+                    label_id: label.label_id().value().to_string(),
+                    label_name: name.to_string(),
+                })
+                .collect(),
+        }
+    }
+
+    #[tokio::test]
+    async fn should_rename_all_labels() -> Result<()> {
+        let (labels, repository) =
+            repository_with_labels(&["label-1", "label-2", "label-3"]).await?;
+        let label_bulk_rename_application_service =
+            LabelBulkRenameApplicationService::new(repository.clone());
+
+        let labels_data = label_bulk_rename_application_service
+            .handle(command(&labels, &["renamed-1", "renamed-2", "renamed-3"]))
+            .await?;
+
+        let names: Vec<&str> = labels_data
+            .iter()
+            .map(|label_data| label_data.label_name.as_str())
+            .collect();
+        assert_eq!(vec!["renamed-1", "renamed-2", "renamed-3"], names);
+        assert_eq!(
+            "renamed-3",
+            repository
+                .find(labels[2].label_id())
+                .await?
+                .unwrap()
+                .label_name
+                .value()
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_rename_no_labels_if_one_conflicts() -> Result<()> {
+        let (labels, repository) =
+            repository_with_labels(&["label-1", "label-2", "label-3", "taken"]).await?;
+        let label_bulk_rename_application_service =
+            LabelBulkRenameApplicationService::new(repository.clone());
+
+        let result = label_bulk_rename_application_service
+            .handle(command(&labels[..3], &["renamed-1", "taken", "renamed-3"]))
+            .await;
+
+        assert_eq!(
+            Err(LabelApplicationError::DuplicatedLabel(labels[3].clone())),
+            result
+        );
+        for label in &labels {
+            assert_eq!(
+                label.label_name.value(),
+                repository
+                    .find(label.label_id())
+                    .await?
+                    .unwrap()
+                    .label_name
+                    .value()
+            );
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_reject_renaming_labels_to_same_name() -> Result<()> {
+        let (labels, repository) = repository_with_labels(&["label-1", "label-2"]).await?;
+        let label_bulk_rename_application_service =
+            LabelBulkRenameApplicationService::new(repository.clone());
+
+        let result = label_bulk_rename_application_service
+            .handle(command(&labels, &["renamed", "renamed"]))
+            .await;
+
+        let Err(LabelApplicationError::DuplicatedLabel(label)) = result else {
+            panic!("unexpected result: {:?}", result);
+        };
+        assert_eq!("renamed", label.label_name.value());
+        assert_eq!(
+            "label-1",
+            repository
+                .find(labels[0].label_id())
+                .await?
+                .unwrap()
+                .label_name
+                .value()
+        );
+        Ok(())
+    }
+}
@@ -5,9 +5,12 @@ use axum::async_trait;
 use super::{label_data::LabelData, Result};
 
 use crate::domain::{
-    models::labels::{label::Label, label_name::LabelName, label_repository::ILabelRepository},
-    services::label_service::LabelService,
-    value_object::ValueObject,
+    models::labels::{
+        label::Label,
+        label_name::LabelName,
+        label_repository::{ILabelRepository, LabelRepositoryError},
+    },
+    services::profanity_filter::ProfanityFilter,
 };
 
 use super::label_application_error::LabelApplicationError;
@@ -15,7 +18,7 @@ use super::label_application_error::LabelApplicationError;
 // trait of application service to create label
 #[async_trait]
 pub trait ILabelCreateApplicationService<T: ILabelRepository> {
-    fn new(label_repository: Arc<T>) -> Self;
+    fn new(label_repository: Arc<T>, profanity_filter: Arc<ProfanityFilter>) -> Self;
     async fn handle(&self, command: LabelCreateCommand) -> Result<LabelData>;
 }
 
@@ -27,15 +30,15 @@ pub struct LabelCreateCommand {
 // impl of application service to create label
 pub struct LabelCreateApplicationService<T: ILabelRepository> {
     label_repository: Arc<T>,
-    label_service: LabelService<T>,
+    profanity_filter: Arc<ProfanityFilter>,
 }
 
 #[async_trait]
 impl<T: ILabelRepository> ILabelCreateApplicationService<T> for LabelCreateApplicationService<T> {
-    fn new(label_repository: Arc<T>) -> Self {
+    fn new(label_repository: Arc<T>, profanity_filter: Arc<ProfanityFilter>) -> Self {
         Self {
-            label_repository: label_repository.clone(),
-            label_service: LabelService::new(label_repository),
+            label_repository,
+            profanity_filter,
         }
     }
 
@@ -43,36 +46,41 @@ impl<T: ILabelRepository> ILabelCreateApplicationService<T> for LabelCreateAppli
         let LabelCreateCommand {
             label_name: label_name_string,
         } = command;
-        let label_name = LabelName::new(label_name_string)
+        let label_name = label_name_string
+            .parse::<LabelName>()
             .map_err(|e| LabelApplicationError::IllegalArgumentError(e.to_string()))?;
+        if self.profanity_filter.contains_profanity(&label_name) {
+            return Err(LabelApplicationError::IllegalArgumentError(
+                "Label name contains prohibited words.".to_string(),
+            ));
+        }
         let new_label =
             Label::new(label_name).map_err(|e| LabelApplicationError::Unexpected(e.to_string()))?;
 
-        if self
-            .label_service
-            .is_duplicated(&new_label)
-            .await
-            .map_err(|e| LabelApplicationError::Unexpected(e.to_string()))?
-        {
-            return Err(LabelApplicationError::DuplicatedLabel(new_label).into());
+        // 事前に重複を確認しても、同時に作成されたラベルとは衝突しうるため、保存時の一意制約で重複を検出する
+        match self.label_repository.save(&new_label).await {
+            Ok(()) => Ok(LabelData::new(new_label)),
+            Err(LabelRepositoryError::AlreadyExists(label_name)) => {
+                let label_found = self.label_repository.find_by_name(&label_name).await?;
+                // 既存のラベルが直後に削除された場合は、作成しようとしたラベルを返す
+                Err(LabelApplicationError::DuplicatedLabel(
+                    label_found.unwrap_or(new_label),
+                ))
+            }
+            Err(e) => Err(LabelApplicationError::Unexpected(e.to_string())),
         }
-
-        self.label_repository
-            .save(&new_label)
-            .await
-            .map_err(|e| LabelApplicationError::Unexpected(e.to_string()))?;
-
-        Ok(LabelData::new(new_label))
     }
 }
 
 #[cfg(test)]
 mod tests {
     use anyhow::Result;
-    use uuid::Uuid;
 
     use crate::{
-        domain::models::labels::label_id::LabelId,
+        domain::{
+            models::labels::{label_id::LabelId, label_repository::MockLabelRepository},
+            value_object::ValueObject,
+        },
         infra::repository_impl::in_memory::labels::in_memory_label_repository::InMemoryLabelRepository,
     };
 
@@ -81,8 +89,10 @@ mod tests {
     #[tokio::test]
     async fn test_success_min_label_name() -> Result<()> {
         let repository = Arc::new(InMemoryLabelRepository::new());
-        let label_create_application_service =
-            LabelCreateApplicationService::new(repository.clone());
+        let label_create_application_service = LabelCreateApplicationService::new(
+            repository.clone(),
+            Arc::new(ProfanityFilter::new()),
+        );
 
         // Is it possible to enter a 1-letter name?
         let command = LabelCreateCommand {
@@ -93,18 +103,51 @@ mod tests {
         assert_eq!("1", label_data.label_name);
 
         // get label saved in store
-        let store = repository.read_store_ref();
-        let stored_label = store.get(&LabelId::new(label_data.label_id)?).unwrap();
+        let stored_label = repository
+            .find(&LabelId::new(label_data.label_id)?)
+            .await?
+            .unwrap();
 
         assert_eq!("1", stored_label.label_name.value());
         Ok(())
     }
 
+    #[tokio::test]
+    async fn should_reject_label_name_with_prohibited_word() -> Result<()> {
+        let repository = Arc::new(InMemoryLabelRepository::new());
+        let label_create_application_service = LabelCreateApplicationService::new(
+            repository.clone(),
+            Arc::new(ProfanityFilter::new()),
+        );
+
+        let command = LabelCreateCommand {
+            label_name: "Damn bugs".to_string(),
+        };
+        let result = label_create_application_service.handle(command).await;
+        assert_eq!(
+            Err(LabelApplicationError::IllegalArgumentError(
+                "Label name contains prohibited words.".to_string()
+            )),
+            result
+        );
+        assert!(repository.find_all().await?.is_empty());
+
+        // 一覧の語を含まない名前は作成できる
+        let command = LabelCreateCommand {
+            label_name: "bugs".to_string(),
+        };
+        let label_data = label_create_application_service.handle(command).await?;
+        assert_eq!("bugs", label_data.label_name);
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_success_max_label_name() -> Result<()> {
         let repository = Arc::new(InMemoryLabelRepository::new());
-        let label_create_application_service =
-            LabelCreateApplicationService::new(repository.clone());
+        let label_create_application_service = LabelCreateApplicationService::new(
+            repository.clone(),
+            Arc::new(ProfanityFilter::new()),
+        );
 
         // Is it possible to enter a 19-letter name?
         let command = LabelCreateCommand {
@@ -115,8 +158,10 @@ mod tests {
         assert_eq!("1234567890123456789", label_data.label_name);
 
         // get label saved in store
-        let store = repository.read_store_ref();
-        let stored_label = store.get(&LabelId::new(label_data.label_id)?).unwrap();
+        let stored_label = repository
+            .find(&LabelId::new(label_data.label_id)?)
+            .await?
+            .unwrap();
 
         assert_eq!("1234567890123456789", stored_label.label_name.value());
         Ok(())
@@ -125,8 +170,10 @@ mod tests {
     #[tokio::test]
     async fn should_throw_error_if_label_name_is_too_short() -> Result<()> {
         let repository = Arc::new(InMemoryLabelRepository::new());
-        let label_create_application_service =
-            LabelCreateApplicationService::new(repository.clone());
+        let label_create_application_service = LabelCreateApplicationService::new(
+            repository.clone(),
+            Arc::new(ProfanityFilter::new()),
+        );
 
         // try to enter empty name?
         let command = LabelCreateCommand {
@@ -147,8 +194,10 @@ mod tests {
     #[tokio::test]
     async fn should_throw_error_if_label_name_is_too_long() -> Result<()> {
         let repository = Arc::new(InMemoryLabelRepository::new());
-        let label_create_application_service =
-            LabelCreateApplicationService::new(repository.clone());
+        let label_create_application_service = LabelCreateApplicationService::new(
+            repository.clone(),
+            Arc::new(ProfanityFilter::new()),
+        );
 
         // Is it possible to enter a 20-letter name?
         let command = LabelCreateCommand {
@@ -171,16 +220,14 @@ mod tests {
         let repository = Arc::new(InMemoryLabelRepository::new());
 
         // Put the data in advance
-        {
-            let mut store = repository.write_store_ref();
-            store.insert(
-                LabelId::new(Uuid::new_v4())?,
-                Label::new(LabelName::new("tester-1".to_string())?)?,
-            );
-        }
+        repository
+            .save(&Label::new(LabelName::new("tester-1".to_string())?)?)
+            .await?;
 
-        let label_create_application_service =
-            LabelCreateApplicationService::new(repository.clone());
+        let label_create_application_service = LabelCreateApplicationService::new(
+            repository.clone(),
+            Arc::new(ProfanityFilter::new()),
+        );
 
         // Attempt to insert duplicate data
         let command = LabelCreateCommand {
@@ -188,11 +235,89 @@ mod tests {
         };
         let label_data = label_create_application_service.handle(command).await;
 
-        assert!(matches!(
-            label_data,
-            Err(LabelApplicationError::DuplicatedLabel(_))
-        ));
+        // 新しく作られたラベルの id は分からないので、中身を取り出して比較する
+        let Err(LabelApplicationError::DuplicatedLabel(label)) = label_data else {
+            panic!("unexpected result: {:?}", label_data);
+        };
+        assert_eq!("tester-1", label.label_name.value());
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn should_return_duplicated_label_if_save_conflicts() -> Result<()> {
+        let competitor = Label::new(LabelName::new("label-1".to_string())?)?;
+        // 同時に作成された同じ名前のラベルが先に保存されたように、save は重複を報告する
+        let mut repository = MockLabelRepository::new();
+        repository.expect_save().times(1).returning(|label| {
+            Err(LabelRepositoryError::AlreadyExists(
+                label.label_name.clone(),
+            ))
+        });
+        let label_found = competitor.clone();
+        repository
+            .expect_find_by_name()
+            .withf(|label_name| label_name.value() == "label-1")
+            .times(1)
+            .returning(move |_| Ok(Some(label_found.clone())));
+        let repository = Arc::new(repository);
+
+        let label_create_application_service = LabelCreateApplicationService::new(
+            repository.clone(),
+            Arc::new(ProfanityFilter::new()),
+        );
+        let command = LabelCreateCommand {
+            label_name: "label-1".to_string(),
+        };
+        let label_data = label_create_application_service.handle(command).await;
+
+        // 作成しようとしたラベルではなく、先に保存されたラベルが返される
+        assert_eq!(
+            Err(LabelApplicationError::DuplicatedLabel(competitor)),
+            label_data
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_save_label_once_without_looking_up_duplicates() -> Result<()> {
+        let mut repository = MockLabelRepository::new();
+        repository
+            .expect_save()
+            .withf(|label| label.label_name.value() == "label-1")
+            .times(1)
+            .returning(|_| Ok(()));
+        // 保存に成功した場合は、重複の確認をしない
+        repository.expect_find_by_name().never();
+        let label_create_application_service = LabelCreateApplicationService::new(
+            Arc::new(repository),
+            Arc::new(ProfanityFilter::new()),
+        );
+
+        let command = LabelCreateCommand {
+            label_name: "label-1".to_string(),
+        };
+        let label_data = label_create_application_service.handle(command).await?;
+
+        assert_eq!("label-1", label_data.label_name);
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "called 2 times")]
+    async fn should_panic_if_save_is_called_more_than_expected() {
+        let mut repository = MockLabelRepository::new();
+        repository.expect_save().times(1).returning(|_| Ok(()));
+        let label_create_application_service = LabelCreateApplicationService::new(
+            Arc::new(repository),
+            Arc::new(ProfanityFilter::new()),
+        );
+
+        for _ in 0..2 {
+            let command = LabelCreateCommand {
+                label_name: "label-1".to_string(),
+            };
+            let _ = label_create_application_service.handle(command).await;
+        }
+    }
 }
@@ -1,21 +1,94 @@
-use serde::Serialize;
+use std::hash::{Hash, Hasher};
+
+use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use crate::domain::{models::labels::label::Label, value_object::ValueObject};
 
-#[derive(Serialize, PartialEq, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
 pub struct LabelData {
     pub label_id: Uuid,
     pub label_name: String,
+    // 一覧の取得時に件数を求められた場合のみ、ラベルが付いている todo の数を持つ
+    pub todo_count: Option<u64>,
+    // todo に付いたラベルの場合のみ、todo に付けた日時を RFC 3339 形式で持つ
+    pub attached_at: Option<String>,
 }
 
 impl LabelData {
     pub fn new(label: Label) -> Self {
         let label_id = label.label_id().clone().into_value();
-        let Label { label_name, .. } = label;
+        let Label {
+            label_name,
+            attached_at,
+            ..
+        } = label;
         Self {
             label_id,
             label_name: label_name.into_value(),
+            todo_count: None,
+            attached_at: attached_at.map(|attached_at| attached_at.to_rfc3339()),
+        }
+    }
+}
+
+impl PartialEq for LabelData {
+    fn eq(&self, other: &Self) -> bool {
+        self.label_id == other.label_id
+    }
+}
+
+impl Eq for LabelData {}
+
+impl Hash for LabelData {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.label_id.hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    fn label_data(label_id: Uuid, label_name: &str) -> LabelData {
+        LabelData {
+            label_id,
+            label_name: label_name.to_string(),
+            todo_count: None,
+            attached_at: None,
         }
     }
+
+    #[test]
+    fn should_be_equal_if_label_ids_are_same_even_if_names_differ() {
+        let label_id = Uuid::new_v4();
+        assert_eq!(
+            label_data(label_id, "label-1"),
+            label_data(label_id, "label-2")
+        );
+    }
+
+    #[test]
+    fn should_not_be_equal_if_label_ids_differ_even_if_names_are_same() {
+        assert_ne!(
+            label_data(Uuid::new_v4(), "label-1"),
+            label_data(Uuid::new_v4(), "label-1")
+        );
+    }
+
+    #[test]
+    fn should_deduplicate_label_data_by_id_in_hash_set() {
+        let label_id = Uuid::new_v4();
+        let set: HashSet<LabelData> = [
+            label_data(label_id, "label-1"),
+            label_data(label_id, "label-renamed"),
+            label_data(Uuid::new_v4(), "label-1"),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(2, set.len());
+    }
 }
@@ -4,10 +4,7 @@ use axum::async_trait;
 
 use super::Result;
 
-use crate::domain::models::labels::{
-    label_id::LabelId,
-    label_repository::{ILabelRepository, LabelRepositoryError},
-};
+use crate::domain::models::labels::{label_id::LabelId, label_repository::ILabelRepository};
 
 use super::label_application_error::LabelApplicationError;
 
@@ -44,21 +41,10 @@ impl<T: ILabelRepository> ILabelDeleteApplicationService<T> for LabelDeleteAppli
         let label = self
             .label_repository
             .find(&label_id)
-            .await
-            .map_err(|e| LabelApplicationError::Unexpected(e.to_string()))?
+            .await?
             .ok_or(LabelApplicationError::LabelNotFound(label_id))?;
 
-        self.label_repository
-            .delete(label)
-            .await
-            .map_err(|e| match e {
-                LabelRepositoryError::NotFound(label_id) => {
-                    LabelApplicationError::LabelNotFound(label_id)
-                }
-                LabelRepositoryError::Unexpected(e) => {
-                    LabelApplicationError::Unexpected(e.to_string())
-                }
-            })
+        Ok(self.label_repository.delete(label).await?)
     }
 }
 
@@ -86,10 +72,7 @@ mod tests {
         let label_id = label.label_id().clone();
 
         // Put the data in advance
-        {
-            let mut store = repository.write_store_ref();
-            store.insert(label_id.clone(), label);
-        }
+        repository.save(&label).await?;
 
         // Delete stored label
         let label_delete_application_service =
@@ -100,10 +83,7 @@ mod tests {
         label_delete_application_service.handle(command).await?;
 
         // check the store is empty
-        {
-            let store = repository.read_store_ref();
-            assert!(store.is_empty());
-        }
+        assert!(repository.find_all().await?.is_empty());
         Ok(())
     }
 
@@ -119,10 +99,14 @@ mod tests {
         };
         let result_of_label_delete = label_delete_application_service.handle(command).await;
 
-        assert!(matches!(
+        assert_eq!(
             result_of_label_delete,
-            Err(LabelApplicationError::IllegalLabelId(_))
-        ));
+            Err(LabelApplicationError::IllegalLabelId(
+                LabelId::parse("incorrect-label-id".to_string())
+                    .unwrap_err()
+                    .to_string()
+            ))
+        );
 
         Ok(())
     }
@@ -134,15 +118,18 @@ mod tests {
         // try to delete label which does not exist
         let label_delete_application_service =
             LabelDeleteApplicationService::new(repository.clone());
+        let label_id = Uuid::new_v4();
         let command = LabelDeleteCommand {
-            label_id: Uuid::new_v4().to_string(),
+            label_id: label_id.to_string(),
         };
         let result_of_label_delete = label_delete_application_service.handle(command).await;
 
-        assert!(matches!(
+        assert_eq!(
             result_of_label_delete,
-            Err(LabelApplicationError::LabelNotFound(_))
-        ));
+            Err(LabelApplicationError::LabelNotFound(LabelId::new(
+                label_id
+            )?))
+        );
 
         Ok(())
     }
@@ -0,0 +1,278 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+
+use crate::domain::{
+    models::labels::{label_name::LabelName, label_repository::ILabelRepository},
+    services::label_service::LabelService,
+};
+
+use super::{label_application_error::LabelApplicationError, label_data::LabelData, Result};
+
+// trait of application service to find a label by name or create it
+#[async_trait]
+pub trait ILabelFindOrCreateApplicationService<T: ILabelRepository> {
+    fn new(label_repository: Arc<T>) -> Self;
+    async fn handle(&self, command: LabelFindOrCreateCommand) -> Result<LabelData>;
+}
+
+// command object
+pub struct LabelFindOrCreateCommand {
+    pub label_name: String,
+}
+
+// impl of application service to find a label by name or create it
+pub struct LabelFindOrCreateApplicationService<T: ILabelRepository> {
+    label_service: LabelService<T>,
+}
+
+#[async_trait]
+impl<T: ILabelRepository> ILabelFindOrCreateApplicationService<T>
+    for LabelFindOrCreateApplicationService<T>
+{
+    fn new(label_repository: Arc<T>) -> Self {
+        Self {
+            label_service: LabelService::new(label_repository),
+        }
+    }
+
+    async fn handle(&self, command: LabelFindOrCreateCommand) -> Result<LabelData> {
+        let LabelFindOrCreateCommand {
+            label_name: label_name_string,
+        } = command;
+        let label_name = label_name_string
+            .parse::<LabelName>()
+            .map_err(|e| LabelApplicationError::IllegalArgumentError(e.to_string()))?;
+
+        let label = self
+            .label_service
+            .find_or_create(label_name)
+            .await
+            .map_err(|e| LabelApplicationError::Unexpected(e.to_string()))?;
+
+        Ok(LabelData::new(label))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::{HashMap, HashSet},
+        sync::atomic::{AtomicBool, Ordering},
+    };
+
+    use anyhow::Result;
+
+    use crate::{
+        domain::{
+            models::{
+                labels::{label::Label, label_id::LabelId, label_repository},
+                users::user_id::UserId,
+            },
+            value_object::ValueObject,
+        },
+        infra::repository_impl::in_memory::labels::in_memory_label_repository::InMemoryLabelRepository,
+    };
+
+    use super::*;
+
+    // 最初の `find_by_name` の直後に、他のリクエストが同じ名前のラベルを保存したように振る舞うリポジトリ
+    #[derive(Clone)]
+    struct RacingLabelRepository {
+        inner: InMemoryLabelRepository,
+        competitor: Label,
+        raced: Arc<AtomicBool>,
+    }
+
+    #[async_trait]
+    impl ILabelRepository for RacingLabelRepository {
+        async fn save(&self, label: &Label) -> label_repository::Result<()> {
+            self.inner.save(label).await
+        }
+
+        async fn save_with_order(
+            &self,
+            label: &Label,
+            after_id: Option<LabelId>,
+        ) -> label_repository::Result<()> {
+            self.inner.save_with_order(label, after_id).await
+        }
+
+        async fn save_all(&self, labels: &[Label]) -> label_repository::Result<()> {
+            self.inner.save_all(labels).await
+        }
+
+        async fn rename_bulk(
+            &self,
+            renames: &[(LabelId, LabelName)],
+        ) -> label_repository::Result<Vec<Label>> {
+            self.inner.rename_bulk(renames).await
+        }
+
+        async fn find(&self, label_id: &LabelId) -> label_repository::Result<Option<Label>> {
+            self.inner.find(label_id).await
+        }
+
+        async fn find_by_name(
+            &self,
+            label_name: &LabelName,
+        ) -> label_repository::Result<Option<Label>> {
+            let label_found = self.inner.find_by_name(label_name).await?;
+            if !self.raced.swap(true, Ordering::SeqCst) {
+                self.inner.save(&self.competitor).await?;
+            }
+            Ok(label_found)
+        }
+
+        async fn find_by_slug(&self, slug: &str) -> label_repository::Result<Option<Label>> {
+            self.inner.find_by_slug(slug).await
+        }
+
+        async fn find_starting_with(&self, prefix: &str) -> label_repository::Result<Vec<Label>> {
+            self.inner.find_starting_with(prefix).await
+        }
+
+        async fn find_all(&self) -> label_repository::Result<Vec<Label>> {
+            self.inner.find_all().await
+        }
+
+        async fn find_all_ordered_by_position(&self) -> label_repository::Result<Vec<Label>> {
+            self.inner.find_all_ordered_by_position().await
+        }
+
+        async fn count_todos_per_label_map(
+            &self,
+        ) -> label_repository::Result<HashMap<LabelId, u64>> {
+            self.inner.count_todos_per_label_map().await
+        }
+
+        async fn find_orphaned(&self) -> label_repository::Result<Vec<Label>> {
+            self.inner.find_orphaned().await
+        }
+
+        async fn find_by_user_id_via_todos(
+            &self,
+            user_id: &UserId,
+        ) -> label_repository::Result<Vec<Label>> {
+            self.inner.find_by_user_id_via_todos(user_id).await
+        }
+
+        async fn find_recently_used(&self, limit: u32) -> label_repository::Result<Vec<Label>> {
+            self.inner.find_recently_used(limit).await
+        }
+
+        async fn delete(&self, label: Label) -> label_repository::Result<()> {
+            self.inner.delete(label).await
+        }
+
+        async fn delete_all(&self, label_ids: &[LabelId]) -> label_repository::Result<()> {
+            self.inner.delete_all(label_ids).await
+        }
+
+        async fn delete_all_unused(
+            &self,
+            label_ids: &[LabelId],
+        ) -> label_repository::Result<Vec<LabelId>> {
+            self.inner.delete_all_unused(label_ids).await
+        }
+    }
+
+    #[tokio::test]
+    async fn should_return_existing_label() -> Result<()> {
+        let repository = Arc::new(InMemoryLabelRepository::new());
+
+        let label = Label::new(LabelName::new("label-1".to_string())?)?;
+
+        // Put the data in advance
+        repository.save(&label).await?;
+
+        let label_find_or_create_application_service =
+            LabelFindOrCreateApplicationService::new(repository.clone());
+        let command = LabelFindOrCreateCommand {
+            label_name: " label-1 ".to_string(),
+        };
+        let label_data = label_find_or_create_application_service
+            .handle(command)
+            .await?;
+
+        assert_eq!(LabelData::new(label), label_data);
+        assert_eq!(1, repository.find_all().await?.len());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_create_label_if_not_found() -> Result<()> {
+        let repository = Arc::new(InMemoryLabelRepository::new());
+
+        let label_find_or_create_application_service =
+            LabelFindOrCreateApplicationService::new(repository.clone());
+        let command = LabelFindOrCreateCommand {
+            label_name: "label-1".to_string(),
+        };
+        let label_data = label_find_or_create_application_service
+            .handle(command)
+            .await?;
+
+        assert_eq!("label-1", label_data.label_name);
+
+        // get label saved in store
+        let stored_label = repository
+            .find(&LabelId::new(label_data.label_id)?)
+            .await?
+            .unwrap();
+        assert_eq!("label-1", stored_label.label_name.value());
+        assert_eq!(1, repository.find_all().await?.len());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_return_label_saved_by_competitor_when_save_conflicts() -> Result<()> {
+        let competitor = Label::new(LabelName::new("label-1".to_string())?)?;
+        let repository = Arc::new(RacingLabelRepository {
+            inner: InMemoryLabelRepository::new(),
+            competitor: competitor.clone(),
+            raced: Arc::new(AtomicBool::new(false)),
+        });
+
+        let label_find_or_create_application_service =
+            LabelFindOrCreateApplicationService::new(repository.clone());
+        let command = LabelFindOrCreateCommand {
+            label_name: "label-1".to_string(),
+        };
+        let label_data = label_find_or_create_application_service
+            .handle(command)
+            .await?;
+
+        // 自分で作成したラベルではなく、先に保存されたラベルが返される
+        assert_eq!(LabelData::new(competitor), label_data);
+        assert_eq!(1, repository.inner.find_all().await?.len());
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 8)]
+    async fn should_create_only_one_label_for_concurrent_requests() -> Result<()> {
+        let repository = Arc::new(InMemoryLabelRepository::new());
+
+        let handles = (0..32)
+            .map(|_| {
+                let repository = repository.clone();
+                tokio::spawn(async move {
+                    LabelFindOrCreateApplicationService::new(repository)
+                        .handle(LabelFindOrCreateCommand {
+                            label_name: "label-1".to_string(),
+                        })
+                        .await
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let mut label_ids = HashSet::new();
+        for handle in handles {
+            label_ids.insert(handle.await??.label_id);
+        }
+
+        assert_eq!(1, label_ids.len());
+        assert_eq!(1, repository.find_all().await?.len());
+        Ok(())
+    }
+}
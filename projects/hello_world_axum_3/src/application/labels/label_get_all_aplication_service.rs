@@ -4,7 +4,7 @@ use axum::async_trait;
 
 use crate::domain::models::labels::label_repository::ILabelRepository;
 
-use super::{label_application_error::LabelApplicationError, label_data::LabelData, Result};
+use super::{label_data::LabelData, Result};
 
 // trait of application service to get labels
 #[async_trait]
@@ -13,7 +13,12 @@ pub trait ILabelGetAllApplicationService<T: ILabelRepository> {
     async fn handle(&self, command: LabelGetAllCommand) -> Result<Vec<LabelData>>;
 }
 
-pub struct LabelGetAllCommand {}
+pub struct LabelGetAllCommand {
+    // true の場合は、各ラベルが付いている todo の数も求める
+    pub include_counts: bool,
+    // true の場合は id ではなく、並び順の位置の順に返す
+    pub order_by_position: bool,
+}
 
 // impl of application service to get labels
 pub struct LabelGetAllApplicationService<T: ILabelRepository> {
@@ -26,16 +31,33 @@ impl<T: ILabelRepository> ILabelGetAllApplicationService<T> for LabelGetAllAppli
         Self { label_repository }
     }
 
-    async fn handle(&self, _: LabelGetAllCommand) -> Result<Vec<LabelData>> {
-        let labels_found = self
-            .label_repository
-            .find_all()
-            .await
-            .map_err(|e| LabelApplicationError::Unexpected(e.to_string()))?;
-        Ok(labels_found
+    async fn handle(&self, command: LabelGetAllCommand) -> Result<Vec<LabelData>> {
+        let LabelGetAllCommand {
+            include_counts,
+            order_by_position,
+        } = command;
+        let labels_found = if order_by_position {
+            self.label_repository.find_all_ordered_by_position().await?
+        } else {
+            self.label_repository.find_all().await?
+        };
+        if !include_counts {
+            return Ok(labels_found.into_iter().map(LabelData::new).collect());
+        }
+
+        let todo_counts = self.label_repository.count_todos_per_label_map().await?;
+        let label_data = labels_found
             .into_iter()
-            .map(|label| LabelData::new(label))
-            .collect())
+            .map(|label| {
+                // todo が一つも付いていないラベルは集計結果に含まれない
+                let todo_count = todo_counts.get(label.label_id()).copied().unwrap_or(0);
+                LabelData {
+                    todo_count: Some(todo_count),
+                    ..LabelData::new(label)
+                }
+            })
+            .collect();
+        Ok(label_data)
     }
 }
 
@@ -45,10 +67,16 @@ mod tests {
 
     use crate::{
         domain::{
-            models::labels::{label::Label, label_name::LabelName},
+            models::{
+                labels::{label::Label, label_name::LabelName},
+                todos::{todo::Todo, todo_repository::ITodoRepository, todo_text::TodoText},
+            },
             value_object::ValueObject,
         },
-        infra::repository_impl::in_memory::labels::in_memory_label_repository::InMemoryLabelRepository,
+        infra::repository_impl::in_memory::{
+            labels::in_memory_label_repository::InMemoryLabelRepository,
+            todos::in_memory_todo_repository::InMemoryTodoRepository,
+        },
     };
 
     use super::*;
@@ -60,35 +88,36 @@ mod tests {
         // 1. Get all stored label
         let label_get_all_application_service =
             LabelGetAllApplicationService::new(repository.clone());
-        let command = LabelGetAllCommand {};
+        let command = LabelGetAllCommand {
+            include_counts: false,
+            order_by_position: false,
+        };
         let labels = label_get_all_application_service.handle(command).await?;
 
         assert!(labels.is_empty());
 
         // 2. Put the first data
         let label_1 = Label::new(LabelName::new("tester-1".to_string())?)?;
-        let label_id = label_1.label_id().clone();
-        {
-            let mut store = repository.write_store_ref();
-            store.insert(label_id.clone(), label_1.clone());
-        }
+        repository.save(&label_1).await?;
 
         // 3. Get all stored label
-        let command = LabelGetAllCommand {};
+        let command = LabelGetAllCommand {
+            include_counts: false,
+            order_by_position: false,
+        };
         let labels = label_get_all_application_service.handle(command).await?;
 
         assert_eq!(vec![LabelData::new(label_1.clone())], labels);
 
         // 4. Put the second data
         let label_2 = Label::new(LabelName::new("tester-2".to_string())?)?;
-        let label_id = label_2.label_id().clone();
-        {
-            let mut store = repository.write_store_ref();
-            store.insert(label_id.clone(), label_2.clone());
-        }
+        repository.save(&label_2).await?;
 
         // 3. Get all stored label
-        let command = LabelGetAllCommand {};
+        let command = LabelGetAllCommand {
+            include_counts: false,
+            order_by_position: false,
+        };
         let mut labels = label_get_all_application_service.handle(command).await?;
 
         // Sort labels alphabetically
@@ -101,4 +130,99 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn should_count_todos_per_label_if_requested() -> Result<()> {
+        let todo_repository = InMemoryTodoRepository::new();
+        let repository = Arc::new(InMemoryLabelRepository::with_todo_repository(
+            todo_repository.clone(),
+        ));
+
+        let label_a = Label::new(LabelName::new("label-a".to_string())?)?;
+        let label_b = Label::new(LabelName::new("label-b".to_string())?)?;
+        let label_c = Label::new(LabelName::new("label-c".to_string())?)?;
+        repository
+            .save_all(&[label_a.clone(), label_b.clone(), label_c.clone()])
+            .await?;
+
+        // label-a: 3 件, label-b: 2 件, label-c: 0 件
+        for (i, labels) in [
+            vec![label_a.clone()],
+            vec![label_a.clone(), label_b.clone()],
+            vec![label_a.clone()],
+            vec![label_b.clone()],
+            vec![],
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            let todo = Todo::new(TodoText::new(format!("todo-{}", i))?, labels)?;
+            todo_repository.save(&todo).await?;
+        }
+
+        let todo_counts = repository.count_todos_per_label_map().await?;
+        assert_eq!(Some(&3), todo_counts.get(label_a.label_id()));
+        assert_eq!(Some(&2), todo_counts.get(label_b.label_id()));
+        assert_eq!(None, todo_counts.get(label_c.label_id()));
+
+        let label_get_all_application_service =
+            LabelGetAllApplicationService::new(repository.clone());
+        let command = LabelGetAllCommand {
+            include_counts: true,
+            order_by_position: false,
+        };
+        let mut labels = label_get_all_application_service.handle(command).await?;
+        labels.sort_by(|a, b| a.label_name.cmp(&b.label_name));
+
+        assert_eq!(
+            vec![Some(3), Some(2), Some(0)],
+            labels
+                .iter()
+                .map(|label| label.todo_count)
+                .collect::<Vec<_>>()
+        );
+
+        // 求められなければ数えない
+        let command = LabelGetAllCommand {
+            include_counts: false,
+            order_by_position: false,
+        };
+        let labels = label_get_all_application_service.handle(command).await?;
+        assert!(labels.iter().all(|label| label.todo_count.is_none()));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_get_labels_in_order_of_position_if_requested() -> Result<()> {
+        let repository = Arc::new(InMemoryLabelRepository::new());
+        let label_a = Label::new(LabelName::new("label-a".to_string())?)?;
+        let label_b = Label::new(LabelName::new("label-b".to_string())?)?;
+        let label_c = Label::new(LabelName::new("label-c".to_string())?)?;
+
+        // label-a, label-c の順に作成してから、label-b をその間に差し込む
+        repository.save_with_order(&label_a, None).await?;
+        repository
+            .save_with_order(&label_c, Some(label_a.label_id().clone()))
+            .await?;
+        repository
+            .save_with_order(&label_b, Some(label_a.label_id().clone()))
+            .await?;
+
+        let label_get_all_application_service =
+            LabelGetAllApplicationService::new(repository.clone());
+        let command = LabelGetAllCommand {
+            include_counts: false,
+            order_by_position: true,
+        };
+        let labels = label_get_all_application_service.handle(command).await?;
+
+        assert_eq!(
+            vec!["label-a", "label-b", "label-c"],
+            labels
+                .iter()
+                .map(|label| label.label_name.as_str())
+                .collect::<Vec<_>>()
+        );
+        Ok(())
+    }
 }
@@ -34,14 +34,10 @@ impl<T: ILabelRepository> ILabelGetApplicationService<T> for LabelGetApplication
         } = command;
         let label_id = LabelId::parse(label_id_string)
             .map_err(|e| LabelApplicationError::IllegalLabelId(e.to_string()))?;
-        let label_found = self
-            .label_repository
-            .find(&label_id)
-            .await
-            .map_err(|e| LabelApplicationError::Unexpected(e.to_string()))?;
+        let label_found = self.label_repository.find(&label_id).await?;
         match label_found {
             Some(label) => Ok(LabelData::new(label)),
-            None => Err(LabelApplicationError::LabelNotFound(label_id).into()),
+            None => Err(LabelApplicationError::LabelNotFound(label_id)),
         }
     }
 }
@@ -69,10 +65,7 @@ mod tests {
         let label_id = label.label_id().clone();
 
         // Put the data in advance
-        {
-            let mut store = repository.write_store_ref();
-            store.insert(label_id.clone(), label.clone());
-        }
+        repository.save(&label).await?;
 
         // Get stored label
         let label_get_application_service = LabelGetApplicationService::new(repository.clone());
@@ -91,15 +84,18 @@ mod tests {
 
         // try to get label which does not exist
         let label_get_application_service = LabelGetApplicationService::new(repository.clone());
+        let label_id = Uuid::new_v4();
         let command = LabelGetCommand {
-            label_id: Uuid::new_v4().to_string(),
+            label_id: label_id.to_string(),
         };
         let result_of_label_delete = label_get_application_service.handle(command).await;
 
-        assert!(matches!(
+        assert_eq!(
             result_of_label_delete,
-            Err(LabelApplicationError::LabelNotFound(_))
-        ));
+            Err(LabelApplicationError::LabelNotFound(LabelId::new(
+                label_id
+            )?))
+        );
 
         Ok(())
     }
@@ -115,10 +111,14 @@ mod tests {
         };
         let result_of_label_delete = label_get_application_service.handle(command).await;
 
-        assert!(matches!(
+        assert_eq!(
             result_of_label_delete,
-            Err(LabelApplicationError::IllegalLabelId(_))
-        ));
+            Err(LabelApplicationError::IllegalLabelId(
+                LabelId::parse("illegal-formated-label-id".to_string())
+                    .unwrap_err()
+                    .to_string()
+            ))
+        );
 
         Ok(())
     }
@@ -0,0 +1,82 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+
+use crate::domain::models::labels::label_repository::ILabelRepository;
+
+use super::{label_data::LabelData, Result};
+
+// trait of application service to get labels not attached to any todo
+#[async_trait]
+pub trait ILabelGetOrphanedApplicationService<T: ILabelRepository> {
+    fn new(label_repository: Arc<T>) -> Self;
+    async fn handle(&self) -> Result<Vec<LabelData>>;
+}
+
+// impl of application service to get labels not attached to any todo
+pub struct LabelGetOrphanedApplicationService<T: ILabelRepository> {
+    label_repository: Arc<T>,
+}
+
+#[async_trait]
+impl<T: ILabelRepository> ILabelGetOrphanedApplicationService<T>
+    for LabelGetOrphanedApplicationService<T>
+{
+    fn new(label_repository: Arc<T>) -> Self {
+        Self { label_repository }
+    }
+
+    async fn handle(&self) -> Result<Vec<LabelData>> {
+        let labels_found = self.label_repository.find_orphaned().await?;
+        Ok(labels_found.into_iter().map(LabelData::new).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use crate::{
+        domain::{
+            models::{
+                labels::{label::Label, label_name::LabelName},
+                todos::{todo::Todo, todo_repository::ITodoRepository, todo_text::TodoText},
+            },
+            value_object::ValueObject,
+        },
+        infra::repository_impl::in_memory::{
+            labels::in_memory_label_repository::InMemoryLabelRepository,
+            todos::in_memory_todo_repository::InMemoryTodoRepository,
+        },
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn should_get_only_labels_without_todos() -> Result<()> {
+        let todo_repository = InMemoryTodoRepository::new();
+        let repository = Arc::new(InMemoryLabelRepository::with_todo_repository(
+            todo_repository.clone(),
+        ));
+
+        let label_a = Label::new(LabelName::new("label-a".to_string())?)?;
+        let label_b = Label::new(LabelName::new("label-b".to_string())?)?;
+        let label_c = Label::new(LabelName::new("label-c".to_string())?)?;
+        repository
+            .save_all(&[label_a.clone(), label_b.clone(), label_c.clone()])
+            .await?;
+
+        let todo = Todo::new(
+            TodoText::new("todo".to_string())?,
+            vec![label_a.clone(), label_b.clone()],
+        )?;
+        todo_repository.save(&todo).await?;
+
+        let label_get_orphaned_application_service =
+            LabelGetOrphanedApplicationService::new(repository.clone());
+        let labels = label_get_orphaned_application_service.handle().await?;
+
+        assert_eq!(vec![LabelData::new(label_c)], labels);
+        Ok(())
+    }
+}
@@ -0,0 +1,100 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+
+use crate::domain::models::labels::label_repository::ILabelRepository;
+
+use super::{label_data::LabelData, Result};
+
+// trait of application service to get labels recently attached to todos
+#[async_trait]
+pub trait ILabelGetRecentlyUsedApplicationService<T: ILabelRepository> {
+    fn new(label_repository: Arc<T>) -> Self;
+    async fn handle(&self, command: LabelGetRecentlyUsedCommand) -> Result<Vec<LabelData>>;
+}
+
+// command object
+pub struct LabelGetRecentlyUsedCommand {
+    pub limit: u32,
+}
+
+// impl of application service to get labels recently attached to todos
+pub struct LabelGetRecentlyUsedApplicationService<T: ILabelRepository> {
+    label_repository: Arc<T>,
+}
+
+#[async_trait]
+impl<T: ILabelRepository> ILabelGetRecentlyUsedApplicationService<T>
+    for LabelGetRecentlyUsedApplicationService<T>
+{
+    fn new(label_repository: Arc<T>) -> Self {
+        Self { label_repository }
+    }
+
+    async fn handle(&self, command: LabelGetRecentlyUsedCommand) -> Result<Vec<LabelData>> {
+        let LabelGetRecentlyUsedCommand { limit } = command;
+        let labels_found = self.label_repository.find_recently_used(limit).await?;
+        Ok(labels_found.into_iter().map(LabelData::new).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use anyhow::Result;
+
+    use crate::{
+        domain::{
+            models::{
+                labels::{label::Label, label_name::LabelName},
+                todos::{todo::Todo, todo_repository::ITodoRepository, todo_text::TodoText},
+            },
+            value_object::ValueObject,
+        },
+        infra::repository_impl::in_memory::{
+            labels::in_memory_label_repository::InMemoryLabelRepository,
+            todos::in_memory_todo_repository::InMemoryTodoRepository,
+        },
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn should_get_most_recently_used_labels_first() -> Result<()> {
+        let todo_repository = InMemoryTodoRepository::new();
+        let repository = Arc::new(InMemoryLabelRepository::with_todo_repository(
+            todo_repository.clone(),
+        ));
+
+        let label_a = Label::new(LabelName::new("label-a".to_string())?)?;
+        let label_b = Label::new(LabelName::new("label-b".to_string())?)?;
+        let unused = Label::new(LabelName::new("label-unused".to_string())?)?;
+        repository
+            .save_all(&[label_a.clone(), label_b.clone(), unused])
+            .await?;
+
+        // A を付け、次に B を付け、もう一度 B を付ける
+        for (i, label) in [&label_a, &label_b, &label_b].into_iter().enumerate() {
+            let todo = Todo::new(TodoText::new(format!("todo-{}", i))?, vec![label.clone()])?;
+            todo_repository.save(&todo).await?;
+            tokio::time::sleep(Duration::from_millis(1)).await;
+        }
+
+        let label_get_recently_used_application_service =
+            LabelGetRecentlyUsedApplicationService::new(repository.clone());
+        let labels = label_get_recently_used_application_service
+            .handle(LabelGetRecentlyUsedCommand { limit: 10 })
+            .await?;
+        assert_eq!(
+            vec![LabelData::new(label_b.clone()), LabelData::new(label_a)],
+            labels
+        );
+
+        let labels = label_get_recently_used_application_service
+            .handle(LabelGetRecentlyUsedCommand { limit: 1 })
+            .await?;
+        assert_eq!(vec![LabelData::new(label_b)], labels);
+        Ok(())
+    }
+}
@@ -9,7 +9,6 @@ use crate::domain::{
         label_id::LabelId, label_name::LabelName, label_repository::ILabelRepository,
     },
     services::label_service::LabelService,
-    value_object::ValueObject,
 };
 
 use super::label_application_error::LabelApplicationError;
@@ -54,12 +53,12 @@ impl<T: ILabelRepository> ILabelUpdateApplicationService<T> for LabelUpdateAppli
         let mut label = self
             .label_repository
             .find(&label_id)
-            .await
-            .map_err(|e| LabelApplicationError::Unexpected(e.to_string()))?
+            .await?
             .ok_or(LabelApplicationError::LabelNotFound(label_id))?;
 
         if let Some(label_name_string) = label_name_string {
-            let label_name = LabelName::new(label_name_string)
+            let label_name = label_name_string
+                .parse::<LabelName>()
                 .map_err(|e| LabelApplicationError::IllegalArgumentError(e.to_string()))?;
             label.label_name = label_name;
         }
@@ -70,13 +69,10 @@ impl<T: ILabelRepository> ILabelUpdateApplicationService<T> for LabelUpdateAppli
             .await
             .map_err(|e| LabelApplicationError::Unexpected(e.to_string()))?
         {
-            return Err(LabelApplicationError::DuplicatedLabel(label).into());
+            return Err(LabelApplicationError::DuplicatedLabel(label));
         }
 
-        self.label_repository
-            .save(&label)
-            .await
-            .map_err(|e| LabelApplicationError::Unexpected(e.to_string()))?;
+        self.label_repository.save(&label).await?;
 
         Ok(LabelData::new(label))
     }
@@ -88,7 +84,7 @@ mod tests {
     use uuid::Uuid;
 
     use crate::{
-        domain::models::labels::label::Label,
+        domain::{models::labels::label::Label, value_object::ValueObject},
         infra::repository_impl::in_memory::labels::in_memory_label_repository::InMemoryLabelRepository,
     };
 
@@ -102,10 +98,7 @@ mod tests {
         let label_id = label.label_id().clone();
 
         // Put the data in advance
-        {
-            let mut store = repository.write_store_ref();
-            store.insert(label_id.clone(), label.clone());
-        }
+        repository.save(&label).await?;
 
         // Update stored label with 1-letter name
         let label_update_application_service =
@@ -121,8 +114,7 @@ mod tests {
 
         // Check if label is updated
         {
-            let store = repository.read_store_ref();
-            let label_in_store = store.get(&label_id).unwrap();
+            let label_in_store = repository.find(&label_id).await?.unwrap();
             assert_eq!("1", label_in_store.label_name.value());
         }
         Ok(())
@@ -136,10 +128,7 @@ mod tests {
         let label_id = label.label_id().clone();
 
         // Put the data in advance
-        {
-            let mut store = repository.write_store_ref();
-            store.insert(label_id.clone(), label.clone());
-        }
+        repository.save(&label).await?;
 
         // Update stored label with 19-letter name
         let label_update_application_service =
@@ -155,8 +144,7 @@ mod tests {
 
         // Check if label is updated
         {
-            let store = repository.read_store_ref();
-            let label_in_store = store.get(&label_id).unwrap();
+            let label_in_store = repository.find(&label_id).await?.unwrap();
             assert_eq!("1234567890123456789", label_in_store.label_name.value());
         }
         Ok(())
@@ -170,10 +158,7 @@ mod tests {
         let label_id = label.label_id().clone();
 
         // Put the data in advance
-        {
-            let mut store = repository.write_store_ref();
-            store.insert(label_id.clone(), label.clone());
-        }
+        repository.save(&label).await?;
 
         // Try update stored label with empty name
         let label_update_application_service =
@@ -201,10 +186,7 @@ mod tests {
         let label_id = label.label_id().clone();
 
         // Put the data in advance
-        {
-            let mut store = repository.write_store_ref();
-            store.insert(label_id.clone(), label.clone());
-        }
+        repository.save(&label).await?;
 
         // Try update stored label with 20-letter name
         let label_update_application_service =
@@ -232,19 +214,12 @@ mod tests {
         let label_id_1 = label_1.label_id().clone();
 
         // Save the 1st label to store
-        {
-            let mut store = repository.write_store_ref();
-            store.insert(label_id_1.clone(), label_1.clone());
-        }
+        repository.save(&label_1).await?;
 
         let label_2 = Label::new(LabelName::new("tester-2".to_string())?)?;
-        let label_id_2 = label_2.label_id().clone();
 
         // Save the 2nd label to store
-        {
-            let mut store = repository.write_store_ref();
-            store.insert(label_id_2.clone(), label_2.clone());
-        }
+        repository.save(&label_2).await?;
 
         // Try update the 1st label with 2nd name's name
         let label_update_application_service =
@@ -255,10 +230,13 @@ mod tests {
         };
         let result_of_label_update = label_update_application_service.handle(command).await;
 
-        assert!(matches!(
+        assert_eq!(
             result_of_label_update,
-            Err(LabelApplicationError::DuplicatedLabel(_))
-        ));
+            Err(LabelApplicationError::DuplicatedLabel(Label::build(
+                label_id_1,
+                LabelName::new("tester-2".to_string())?
+            )))
+        );
         Ok(())
     }
 
@@ -299,10 +277,14 @@ mod tests {
         };
         let result_of_label_update = label_update_application_service.handle(command).await;
 
-        assert!(matches!(
+        assert_eq!(
             result_of_label_update,
-            Err(LabelApplicationError::IllegalLabelId(_))
-        ));
+            Err(LabelApplicationError::IllegalLabelId(
+                LabelId::parse("illegal-label-id".to_string())
+                    .unwrap_err()
+                    .to_string()
+            ))
+        );
 
         Ok(())
     }
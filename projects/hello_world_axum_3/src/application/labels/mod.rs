@@ -1,12 +1,17 @@
+pub mod label_application_error;
+pub mod label_bulk_create_application_service;
+pub mod label_bulk_delete_application_service;
+pub mod label_bulk_rename_application_service;
 pub mod label_create_application_service;
-pub mod label_get_application_service;
+pub mod label_data;
+pub mod label_delete_application_service;
+pub mod label_find_or_create_application_service;
 pub mod label_get_all_aplication_service;
+pub mod label_get_application_service;
+pub mod label_get_orphaned_application_service;
+pub mod label_get_recently_used_application_service;
 pub mod label_update_application_service;
-pub mod label_delete_application_service;
-pub mod label_data;
-pub mod label_application_error;
-
 
 use self::label_application_error::LabelApplicationError;
 
-pub type Result<T> = anyhow::Result<T, LabelApplicationError>;
\ No newline at end of file
+pub type Result<T> = anyhow::Result<T, LabelApplicationError>;
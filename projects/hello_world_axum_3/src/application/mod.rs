@@ -1,3 +1,8 @@
+pub mod audit;
+pub mod invitations;
 pub mod labels;
+pub mod rfc3339;
+pub mod sessions;
+pub mod todo_dependencies;
+pub mod todos;
 pub mod users;
-pub mod todos;
\ No newline at end of file
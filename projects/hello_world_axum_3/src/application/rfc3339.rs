@@ -0,0 +1,29 @@
+use chrono::{DateTime, SecondsFormat, Utc};
+use serde::{de::Error, Deserialize, Deserializer, Serializer};
+use serde_with::{DeserializeAs, SerializeAs};
+
+// `DateTime<Utc>` を RFC 3339 形式の文字列 (e.g. "2024-01-15T10:30:00Z") として
+// シリアライズ・デシリアライズするためのアダプタ
+// `#[serde_as(as = "Rfc3339")]` として使用する
+pub struct Rfc3339;
+
+impl SerializeAs<DateTime<Utc>> for Rfc3339 {
+    fn serialize_as<S>(source: &DateTime<Utc>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&source.to_rfc3339_opts(SecondsFormat::AutoSi, true))
+    }
+}
+
+impl<'de> DeserializeAs<'de, DateTime<Utc>> for Rfc3339 {
+    fn deserialize_as<D>(deserializer: D) -> Result<DateTime<Utc>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        DateTime::parse_from_rfc3339(&value)
+            .map(|date_time| date_time.with_timezone(&Utc))
+            .map_err(D::Error::custom)
+    }
+}
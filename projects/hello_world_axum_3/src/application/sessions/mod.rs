@@ -0,0 +1,8 @@
+pub mod session_application_error;
+pub mod session_authenticate_application_service;
+pub mod session_login_application_service;
+pub mod session_logout_application_service;
+
+use self::session_application_error::SessionApplicationError;
+
+pub type Result<T> = anyhow::Result<T, SessionApplicationError>;
@@ -0,0 +1,22 @@
+use chrono::{DateTime, Utc};
+use thiserror::Error;
+
+use crate::domain::models::sessions::session_id::SessionId;
+
+#[derive(Debug, Error, PartialEq)]
+pub enum SessionApplicationError {
+    #[error("Session cannnot be found: [id: {0:?}]")]
+    SessionNotFound(SessionId),
+    #[error("Session has already been revoked: [id: {0:?}]")]
+    SessionRevoked(SessionId),
+    #[error("Given session id has incorrect format: [{0}]")]
+    IllegalSessionId(String),
+    #[error("Given user id has incorrect format: [{0}]")]
+    IllegalUserId(String),
+    #[error("Invalid user id or password")]
+    InvalidCredentials,
+    #[error("Account is locked until {until}")]
+    AccountLocked { until: DateTime<Utc> },
+    #[error("Unexpected error: [{0}]")]
+    Unexpected(String),
+}
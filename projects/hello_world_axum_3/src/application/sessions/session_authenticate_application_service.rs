@@ -0,0 +1,89 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+
+use crate::domain::models::{
+    sessions::{session_id::SessionId, session_repository::ISessionRepository},
+    users::user_id::UserId,
+};
+
+use super::{session_application_error::SessionApplicationError, Result};
+
+// trait of application service to authenticate a user by session
+#[async_trait]
+pub trait ISessionAuthenticateApplicationService<SessionRep: ISessionRepository> {
+    fn new(session_repository: Arc<SessionRep>) -> Self;
+    async fn handle(&self, command: SessionAuthenticateCommand) -> Result<UserId>;
+}
+
+pub struct SessionAuthenticateCommand {
+    pub session_id: SessionId,
+}
+
+// impl of application service to authenticate a user by session
+pub struct SessionAuthenticateApplicationService<SessionRep: ISessionRepository> {
+    session_repository: Arc<SessionRep>,
+}
+
+#[async_trait]
+impl<SessionRep: ISessionRepository> ISessionAuthenticateApplicationService<SessionRep>
+    for SessionAuthenticateApplicationService<SessionRep>
+{
+    fn new(session_repository: Arc<SessionRep>) -> Self {
+        Self { session_repository }
+    }
+
+    async fn handle(&self, command: SessionAuthenticateCommand) -> Result<UserId> {
+        let SessionAuthenticateCommand { session_id } = command;
+
+        let session = self
+            .session_repository
+            .find(&session_id)
+            .await
+            .map_err(|e| SessionApplicationError::Unexpected(e.to_string()))?
+            .ok_or(SessionApplicationError::SessionNotFound(session_id.clone()))?;
+        if !session.is_valid() {
+            return Err(SessionApplicationError::SessionRevoked(session_id));
+        }
+
+        Ok(session.user_id().clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use uuid::Uuid;
+
+    use crate::{
+        domain::{models::sessions::session::Session, value_object::ValueObject},
+        infra::repository_impl::in_memory::sessions::in_memory_session_repository::InMemorySessionRepository,
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn should_reject_revoked_session() -> Result<()> {
+        let repository = Arc::new(InMemorySessionRepository::new());
+
+        let mut session = Session::new(UserId::new(Uuid::new_v4())?)?;
+        session.revoke();
+        repository.save(&session).await?;
+
+        let session_authenticate_application_service =
+            SessionAuthenticateApplicationService::new(repository.clone());
+        let result = session_authenticate_application_service
+            .handle(SessionAuthenticateCommand {
+                session_id: session.session_id().clone(),
+            })
+            .await;
+
+        assert_eq!(
+            Err(SessionApplicationError::SessionRevoked(
+                session.session_id().clone()
+            )),
+            result
+        );
+        Ok(())
+    }
+}
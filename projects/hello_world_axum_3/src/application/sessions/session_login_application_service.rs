@@ -0,0 +1,167 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+
+use crate::{
+    application::users::{
+        user_application_error::UserApplicationError,
+        user_authenticate_application_service::{
+            IUserAuthenticateApplicationService, UserAuthenticateApplicationService,
+            UserAuthenticateCommand,
+        },
+    },
+    domain::models::{
+        credentials::credential_repository::ICredentialRepository,
+        sessions::{
+            session::Session, session_id::SessionId, session_repository::ISessionRepository,
+        },
+    },
+};
+
+use super::{session_application_error::SessionApplicationError, Result};
+
+// trait of application service to log in and start a session
+#[async_trait]
+pub trait ISessionLoginApplicationService<
+    CredentialRep: ICredentialRepository,
+    SessionRep: ISessionRepository,
+>
+{
+    fn new(credential_repository: Arc<CredentialRep>, session_repository: Arc<SessionRep>) -> Self;
+    async fn handle(&self, command: SessionLoginCommand) -> Result<SessionId>;
+}
+
+// command object
+pub struct SessionLoginCommand {
+    pub user_id: String,
+    pub password: String,
+}
+
+// impl of application service to log in and start a session
+pub struct SessionLoginApplicationService<
+    CredentialRep: ICredentialRepository,
+    SessionRep: ISessionRepository,
+> {
+    user_authenticate_application_service: UserAuthenticateApplicationService<CredentialRep>,
+    session_repository: Arc<SessionRep>,
+}
+
+#[async_trait]
+impl<CredentialRep, SessionRep> ISessionLoginApplicationService<CredentialRep, SessionRep>
+    for SessionLoginApplicationService<CredentialRep, SessionRep>
+where
+    CredentialRep: ICredentialRepository,
+    SessionRep: ISessionRepository,
+{
+    fn new(credential_repository: Arc<CredentialRep>, session_repository: Arc<SessionRep>) -> Self {
+        Self {
+            user_authenticate_application_service: UserAuthenticateApplicationService::new(
+                credential_repository,
+            ),
+            session_repository,
+        }
+    }
+
+    async fn handle(&self, command: SessionLoginCommand) -> Result<SessionId> {
+        let SessionLoginCommand { user_id, password } = command;
+
+        let user_id = self
+            .user_authenticate_application_service
+            .handle(UserAuthenticateCommand { user_id, password })
+            .await
+            .map_err(|e| match e {
+                UserApplicationError::IllegalUserId(e) => SessionApplicationError::IllegalUserId(e),
+                // どちらの理由で失敗したかは区別しない
+                UserApplicationError::PasswordMismatch | UserApplicationError::UserNotFound(_) => {
+                    SessionApplicationError::InvalidCredentials
+                }
+                UserApplicationError::AccountLocked { until } => {
+                    SessionApplicationError::AccountLocked { until }
+                }
+                e => SessionApplicationError::Unexpected(e.to_string()),
+            })?;
+
+        let session = Session::new(user_id)
+            .map_err(|e| SessionApplicationError::Unexpected(e.to_string()))?;
+        self.session_repository
+            .save(&session)
+            .await
+            .map_err(|e| SessionApplicationError::Unexpected(e.to_string()))?;
+
+        Ok(session.session_id().clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use uuid::Uuid;
+
+    use crate::{
+        domain::{
+            models::{
+                credentials::password_credential::PasswordCredential, users::user_id::UserId,
+            },
+            value_object::ValueObject,
+        },
+        infra::repository_impl::in_memory::{
+            credentials::in_memory_credential_repository::InMemoryCredentialRepository,
+            sessions::in_memory_session_repository::InMemorySessionRepository,
+        },
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn should_start_session_with_correct_password() -> Result<()> {
+        let credential_repository = Arc::new(InMemoryCredentialRepository::new());
+        let session_repository = Arc::new(InMemorySessionRepository::new());
+        let user_id = UserId::new(Uuid::new_v4())?;
+        credential_repository
+            .save(&PasswordCredential::new(user_id.clone(), "password1")?)
+            .await?;
+
+        let session_login_application_service = SessionLoginApplicationService::new(
+            credential_repository.clone(),
+            session_repository.clone(),
+        );
+        let session_id = session_login_application_service
+            .handle(SessionLoginCommand {
+                user_id: user_id.to_string(),
+                password: "password1".to_string(),
+            })
+            .await?;
+
+        // get session saved in store
+        let store = session_repository.read_store_ref();
+        let stored_session = store.get(&session_id).unwrap();
+        assert_eq!(&user_id, stored_session.user_id());
+        assert!(stored_session.is_valid());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_not_start_session_with_wrong_password() -> Result<()> {
+        let credential_repository = Arc::new(InMemoryCredentialRepository::new());
+        let session_repository = Arc::new(InMemorySessionRepository::new());
+        let user_id = UserId::new(Uuid::new_v4())?;
+        credential_repository
+            .save(&PasswordCredential::new(user_id.clone(), "password1")?)
+            .await?;
+
+        let session_login_application_service = SessionLoginApplicationService::new(
+            credential_repository.clone(),
+            session_repository.clone(),
+        );
+        let result = session_login_application_service
+            .handle(SessionLoginCommand {
+                user_id: user_id.to_string(),
+                password: "password2".to_string(),
+            })
+            .await;
+
+        assert_eq!(Err(SessionApplicationError::InvalidCredentials), result);
+        assert!(session_repository.read_store_ref().is_empty());
+        Ok(())
+    }
+}
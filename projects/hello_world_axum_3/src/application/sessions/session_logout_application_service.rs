@@ -0,0 +1,98 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+
+use crate::domain::models::sessions::{
+    session_id::SessionId, session_repository::ISessionRepository,
+};
+
+use super::{session_application_error::SessionApplicationError, Result};
+
+// trait of application service to log out and revoke the session
+#[async_trait]
+pub trait ISessionLogoutApplicationService<SessionRep: ISessionRepository> {
+    fn new(session_repository: Arc<SessionRep>) -> Self;
+    async fn handle(&self, command: SessionLogoutCommand) -> Result<()>;
+}
+
+// command object
+pub struct SessionLogoutCommand {
+    pub session_id: String,
+}
+
+// impl of application service to log out and revoke the session
+pub struct SessionLogoutApplicationService<SessionRep: ISessionRepository> {
+    session_repository: Arc<SessionRep>,
+}
+
+#[async_trait]
+impl<SessionRep: ISessionRepository> ISessionLogoutApplicationService<SessionRep>
+    for SessionLogoutApplicationService<SessionRep>
+{
+    fn new(session_repository: Arc<SessionRep>) -> Self {
+        Self { session_repository }
+    }
+
+    async fn handle(&self, command: SessionLogoutCommand) -> Result<()> {
+        let SessionLogoutCommand {
+            session_id: session_id_string,
+        } = command;
+        let session_id = SessionId::parse(session_id_string)
+            .map_err(|e| SessionApplicationError::IllegalSessionId(e.to_string()))?;
+
+        let mut session = self
+            .session_repository
+            .find(&session_id)
+            .await
+            .map_err(|e| SessionApplicationError::Unexpected(e.to_string()))?
+            .ok_or(SessionApplicationError::SessionNotFound(session_id))?;
+
+        session.revoke();
+        self.session_repository
+            .save(&session)
+            .await
+            .map_err(|e| SessionApplicationError::Unexpected(e.to_string()))?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use uuid::Uuid;
+
+    use crate::{
+        domain::{
+            models::{sessions::session::Session, users::user_id::UserId},
+            value_object::ValueObject,
+        },
+        infra::repository_impl::in_memory::sessions::in_memory_session_repository::InMemorySessionRepository,
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn should_revoke_session() -> Result<()> {
+        let repository = Arc::new(InMemorySessionRepository::new());
+
+        let session = Session::new(UserId::new(Uuid::new_v4())?)?;
+
+        // Put the data in advance
+        {
+            let mut store = repository.write_store_ref();
+            store.insert(session.session_id().clone(), session.clone());
+        }
+
+        let session_logout_application_service =
+            SessionLogoutApplicationService::new(repository.clone());
+        session_logout_application_service
+            .handle(SessionLogoutCommand {
+                session_id: session.session_id().to_string(),
+            })
+            .await?;
+
+        let store = repository.read_store_ref();
+        assert!(!store.get(session.session_id()).unwrap().is_valid());
+        Ok(())
+    }
+}
@@ -0,0 +1,9 @@
+pub mod todo_dependency_add_application_service;
+pub mod todo_dependency_application_error;
+pub mod todo_dependency_data;
+pub mod todo_dependency_get_all_application_service;
+pub mod todo_dependency_remove_application_service;
+
+use self::todo_dependency_application_error::TodoDependencyApplicationError;
+
+pub type Result<T> = anyhow::Result<T, TodoDependencyApplicationError>;
@@ -0,0 +1,327 @@
+use std::{collections::HashSet, sync::Arc};
+
+use axum::async_trait;
+
+use super::{todo_dependency_data::TodoDependencyData, Result};
+
+use crate::domain::models::{
+    todo_dependencies::{
+        todo_dependency::TodoDependency, todo_dependency_repository::ITodoDependencyRepository,
+    },
+    todos::{todo_id::TodoId, todo_repository::ITodoRepository},
+};
+
+use super::todo_dependency_application_error::TodoDependencyApplicationError;
+
+// trait of application service to add todo dependency
+#[async_trait]
+pub trait ITodoDependencyAddApplicationService<
+    TodoRep: ITodoRepository,
+    TodoDependencyRep: ITodoDependencyRepository,
+>
+{
+    fn new(
+        todo_repository: Arc<TodoRep>,
+        todo_dependency_repository: Arc<TodoDependencyRep>,
+    ) -> Self;
+    async fn handle(&self, command: TodoDependencyAddCommand) -> Result<TodoDependencyData>;
+}
+
+// command object
+// `todo_id` の todo は `depends_on_todo_id` の todo が完了するまで完了できない
+pub struct TodoDependencyAddCommand {
+    pub todo_id: String,
+    pub depends_on_todo_id: String,
+}
+
+// impl of application service to add todo dependency
+pub struct TodoDependencyAddApplicationService<TodoRep, TodoDependencyRep> {
+    todo_repository: Arc<TodoRep>,
+    todo_dependency_repository: Arc<TodoDependencyRep>,
+}
+
+impl<TodoRep, TodoDependencyRep> TodoDependencyAddApplicationService<TodoRep, TodoDependencyRep>
+where
+    TodoRep: ITodoRepository,
+    TodoDependencyRep: ITodoDependencyRepository,
+{
+    // `todo_id` の todo が、依存関係をたどって `target_todo_id` の todo に依存しているかを返す
+    // 依存先をたどる前に訪れた todo を記録し、既存のデータに循環があっても止まるようにする
+    async fn depends_on(&self, todo_id: &TodoId, target_todo_id: &TodoId) -> Result<bool> {
+        let mut visited = HashSet::<TodoId>::new();
+        let mut stack = vec![todo_id.clone()];
+        while let Some(todo_id) = stack.pop() {
+            if &todo_id == target_todo_id {
+                return Ok(true);
+            }
+            if !visited.insert(todo_id.clone()) {
+                continue;
+            }
+            let todo_dependencies = self
+                .todo_dependency_repository
+                .find_dependencies_of(&todo_id)
+                .await
+                .map_err(|e| TodoDependencyApplicationError::Unexpected(e.to_string()))?;
+            stack.extend(
+                todo_dependencies
+                    .iter()
+                    .map(|todo_dependency| todo_dependency.from_todo_id().clone()),
+            );
+        }
+        Ok(false)
+    }
+}
+
+#[async_trait]
+impl<TodoRep, TodoDependencyRep> ITodoDependencyAddApplicationService<TodoRep, TodoDependencyRep>
+    for TodoDependencyAddApplicationService<TodoRep, TodoDependencyRep>
+where
+    TodoRep: ITodoRepository,
+    TodoDependencyRep: ITodoDependencyRepository,
+{
+    fn new(
+        todo_repository: Arc<TodoRep>,
+        todo_dependency_repository: Arc<TodoDependencyRep>,
+    ) -> Self {
+        Self {
+            todo_repository: todo_repository.clone(),
+            todo_dependency_repository: todo_dependency_repository.clone(),
+        }
+    }
+
+    async fn handle(&self, command: TodoDependencyAddCommand) -> Result<TodoDependencyData> {
+        let TodoDependencyAddCommand {
+            todo_id: todo_id_string,
+            depends_on_todo_id: depends_on_todo_id_string,
+        } = command;
+
+        let to_todo_id = TodoId::parse(todo_id_string)
+            .map_err(|e| TodoDependencyApplicationError::IllegalTodoId(e.to_string()))?;
+        let from_todo_id = TodoId::parse(depends_on_todo_id_string)
+            .map_err(|e| TodoDependencyApplicationError::IllegalTodoId(e.to_string()))?;
+
+        for todo_id in [&from_todo_id, &to_todo_id] {
+            self.todo_repository
+                .find(todo_id)
+                .await
+                .map_err(|e| TodoDependencyApplicationError::Unexpected(e.to_string()))?
+                .ok_or(TodoDependencyApplicationError::TodoNotFound(
+                    todo_id.clone(),
+                ))?;
+        }
+
+        let new_todo_dependency = TodoDependency::new(from_todo_id.clone(), to_todo_id.clone())
+            .map_err(|e| TodoDependencyApplicationError::IllegalArgumentError(e.to_string()))?;
+
+        let is_duplicated = self
+            .todo_dependency_repository
+            .find_dependencies_of(&to_todo_id)
+            .await
+            .map_err(|e| TodoDependencyApplicationError::Unexpected(e.to_string()))?
+            .into_iter()
+            .any(|todo_dependency| todo_dependency.from_todo_id() == &from_todo_id);
+        if is_duplicated {
+            return Err(TodoDependencyApplicationError::DuplicatedDependency(
+                new_todo_dependency,
+            ));
+        }
+
+        if self.depends_on(&from_todo_id, &to_todo_id).await? {
+            return Err(TodoDependencyApplicationError::CyclicDependency(
+                new_todo_dependency,
+            ));
+        }
+
+        self.todo_dependency_repository
+            .add(&new_todo_dependency)
+            .await
+            .map_err(|e| TodoDependencyApplicationError::Unexpected(e.to_string()))?;
+
+        Ok(TodoDependencyData::new(new_todo_dependency))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use uuid::Uuid;
+
+    use crate::{
+        domain::{
+            models::todos::{todo::Todo, todo_text::TodoText},
+            value_object::ValueObject,
+        },
+        infra::repository_impl::in_memory::{
+            todo_dependencies::in_memory_todo_dependency_repository::InMemoryTodoDependencyRepository,
+            todos::in_memory_todo_repository::InMemoryTodoRepository,
+        },
+    };
+
+    use super::*;
+
+    async fn put_todos(todo_repository: &InMemoryTodoRepository) -> Result<(TodoId, TodoId)> {
+        let todo_1 = Todo::new(TodoText::new("todo 1".to_string())?, vec![])?;
+        let todo_2 = Todo::new(TodoText::new("todo 2".to_string())?, vec![])?;
+        let ids = (todo_1.todo_id().clone(), todo_2.todo_id().clone());
+        todo_repository.save(&todo_1).await?;
+        todo_repository.save(&todo_2).await?;
+        Ok(ids)
+    }
+
+    #[tokio::test]
+    async fn should_add_todo_dependency() -> Result<()> {
+        let todo_repository = Arc::new(InMemoryTodoRepository::new());
+        let todo_dependency_repository = Arc::new(InMemoryTodoDependencyRepository::new());
+        let (todo_id_1, todo_id_2) = put_todos(&todo_repository).await?;
+
+        let todo_dependency_add_application_service = TodoDependencyAddApplicationService::new(
+            todo_repository.clone(),
+            todo_dependency_repository.clone(),
+        );
+        let command = TodoDependencyAddCommand {
+            todo_id: todo_id_2.value().to_string(),
+            depends_on_todo_id: todo_id_1.value().to_string(),
+        };
+        let todo_dependency_data = todo_dependency_add_application_service
+            .handle(command)
+            .await?;
+
+        assert_eq!(todo_id_1.value(), &todo_dependency_data.from_todo_id);
+        assert_eq!(todo_id_2.value(), &todo_dependency_data.to_todo_id);
+
+        // get todo dependency saved in store
+        let store = todo_dependency_repository.read_store_ref();
+        assert_eq!(1, store.len());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_throw_error_if_todo_dependency_is_duplicated() -> Result<()> {
+        let todo_repository = Arc::new(InMemoryTodoRepository::new());
+        let todo_dependency_repository = Arc::new(InMemoryTodoDependencyRepository::new());
+        let (todo_id_1, todo_id_2) = put_todos(&todo_repository).await?;
+
+        let todo_dependency_add_application_service = TodoDependencyAddApplicationService::new(
+            todo_repository.clone(),
+            todo_dependency_repository.clone(),
+        );
+        let command = TodoDependencyAddCommand {
+            todo_id: todo_id_2.value().to_string(),
+            depends_on_todo_id: todo_id_1.value().to_string(),
+        };
+        todo_dependency_add_application_service
+            .handle(command)
+            .await?;
+
+        // Attempt to add duplicate dependency
+        let command = TodoDependencyAddCommand {
+            todo_id: todo_id_2.value().to_string(),
+            depends_on_todo_id: todo_id_1.value().to_string(),
+        };
+        let result = todo_dependency_add_application_service
+            .handle(command)
+            .await;
+
+        // 新しく作られた依存関係の id は分からないので、中身を取り出して比較する
+        let Err(TodoDependencyApplicationError::DuplicatedDependency(dependency)) = result else {
+            panic!("unexpected result: {:?}", result);
+        };
+        assert_eq!(&todo_id_1, dependency.from_todo_id());
+        assert_eq!(&todo_id_2, dependency.to_todo_id());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_throw_error_if_todo_depends_on_itself() -> Result<()> {
+        let todo_repository = Arc::new(InMemoryTodoRepository::new());
+        let todo_dependency_repository = Arc::new(InMemoryTodoDependencyRepository::new());
+        let (todo_id_1, _) = put_todos(&todo_repository).await?;
+
+        let todo_dependency_add_application_service = TodoDependencyAddApplicationService::new(
+            todo_repository.clone(),
+            todo_dependency_repository.clone(),
+        );
+        let command = TodoDependencyAddCommand {
+            todo_id: todo_id_1.value().to_string(),
+            depends_on_todo_id: todo_id_1.value().to_string(),
+        };
+        let result = todo_dependency_add_application_service
+            .handle(command)
+            .await;
+
+        assert_eq!(
+            result,
+            Err(TodoDependencyApplicationError::IllegalArgumentError(
+                "Todo cannot depend on itself.".to_string()
+            ))
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_throw_error_if_todo_dependency_makes_cycle() -> Result<()> {
+        let todo_repository = Arc::new(InMemoryTodoRepository::new());
+        let todo_dependency_repository = Arc::new(InMemoryTodoDependencyRepository::new());
+        let (todo_id_1, todo_id_2) = put_todos(&todo_repository).await?;
+        let todo_3 = Todo::new(TodoText::new("todo 3".to_string())?, vec![])?;
+        let todo_id_3 = todo_3.todo_id().clone();
+        todo_repository.save(&todo_3).await?;
+
+        let todo_dependency_add_application_service = TodoDependencyAddApplicationService::new(
+            todo_repository.clone(),
+            todo_dependency_repository.clone(),
+        );
+        let command = |todo_id: &TodoId, depends_on_todo_id: &TodoId| TodoDependencyAddCommand {
+            todo_id: todo_id.value().to_string(),
+            depends_on_todo_id: depends_on_todo_id.value().to_string(),
+        };
+        // 1 <- 2 <- 3 の順に依存させる
+        todo_dependency_add_application_service
+            .handle(command(&todo_id_2, &todo_id_1))
+            .await?;
+        todo_dependency_add_application_service
+            .handle(command(&todo_id_3, &todo_id_2))
+            .await?;
+
+        // 1 が 3 に依存すると循環する
+        let result = todo_dependency_add_application_service
+            .handle(command(&todo_id_1, &todo_id_3))
+            .await;
+
+        let Err(TodoDependencyApplicationError::CyclicDependency(dependency)) = result else {
+            panic!("unexpected result: {:?}", result);
+        };
+        assert_eq!(&todo_id_3, dependency.from_todo_id());
+        assert_eq!(&todo_id_1, dependency.to_todo_id());
+        assert_eq!(2, todo_dependency_repository.read_store_ref().len());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_throw_error_if_todo_does_not_exist() -> Result<()> {
+        let todo_repository = Arc::new(InMemoryTodoRepository::new());
+        let todo_dependency_repository = Arc::new(InMemoryTodoDependencyRepository::new());
+        let (todo_id_1, _) = put_todos(&todo_repository).await?;
+
+        let todo_dependency_add_application_service = TodoDependencyAddApplicationService::new(
+            todo_repository.clone(),
+            todo_dependency_repository.clone(),
+        );
+        let not_stored_todo_id = Uuid::new_v4();
+        let command = TodoDependencyAddCommand {
+            todo_id: todo_id_1.value().to_string(),
+            depends_on_todo_id: not_stored_todo_id.to_string(),
+        };
+        let result = todo_dependency_add_application_service
+            .handle(command)
+            .await;
+
+        assert_eq!(
+            Err(TodoDependencyApplicationError::TodoNotFound(TodoId::new(
+                not_stored_todo_id
+            )?)),
+            result
+        );
+        Ok(())
+    }
+}
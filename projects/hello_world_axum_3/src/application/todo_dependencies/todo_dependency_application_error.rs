@@ -0,0 +1,37 @@
+use serde::Serialize;
+use thiserror::Error;
+
+use crate::domain::models::{
+    todo_dependencies::{dependency_id::DependencyId, todo_dependency::TodoDependency},
+    todos::todo_id::TodoId,
+};
+
+#[derive(Debug, Error, PartialEq)]
+pub enum TodoDependencyApplicationError {
+    #[error("Given todo dependency is duplicated: [given todo dependency: {0:?}]")]
+    DuplicatedDependency(TodoDependency),
+    #[error("Given todo dependency makes a cycle: [given todo dependency: {0:?}]")]
+    CyclicDependency(TodoDependency),
+    #[error("Todo cannnot be found: [id: {0:?}]")]
+    TodoNotFound(TodoId),
+    #[error("Todo dependency cannnot be found: [id: {0:?}]")]
+    DependencyNotFound(DependencyId),
+    #[error("Given todo dependency is incorrect: [{0}]")]
+    IllegalArgumentError(String),
+    #[error("Given todo id has incorrect format: [{0}]")]
+    IllegalTodoId(String),
+    #[error("Given dependency id has incorrect format: [{0}]")]
+    IllegalDependencyId(String),
+    #[error("Unexpected error: [{0}]")]
+    Unexpected(String),
+}
+
+// <https://github.com/serde-rs/serde/issues/2268#issuecomment-1238962452> を参考に実装
+impl Serialize for TodoDependencyApplicationError {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.to_string().as_ref())
+    }
+}
@@ -0,0 +1,23 @@
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::domain::{
+    models::todo_dependencies::todo_dependency::TodoDependency, value_object::ValueObject,
+};
+
+#[derive(Serialize, PartialEq, Debug)]
+pub struct TodoDependencyData {
+    pub dependency_id: Uuid,
+    pub from_todo_id: Uuid,
+    pub to_todo_id: Uuid,
+}
+
+impl TodoDependencyData {
+    pub fn new(todo_dependency: TodoDependency) -> Self {
+        Self {
+            dependency_id: todo_dependency.dependency_id().clone().into_value(),
+            from_todo_id: todo_dependency.from_todo_id().clone().into_value(),
+            to_todo_id: todo_dependency.to_todo_id().clone().into_value(),
+        }
+    }
+}
@@ -0,0 +1,156 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+
+use crate::domain::models::{
+    todo_dependencies::todo_dependency_repository::ITodoDependencyRepository,
+    todos::{todo_id::TodoId, todo_repository::ITodoRepository},
+};
+
+use super::{
+    todo_dependency_application_error::TodoDependencyApplicationError,
+    todo_dependency_data::TodoDependencyData, Result,
+};
+
+// trait of application service to get dependencies of a todo
+#[async_trait]
+pub trait ITodoDependencyGetAllApplicationService<
+    TodoRep: ITodoRepository,
+    TodoDependencyRep: ITodoDependencyRepository,
+>
+{
+    fn new(
+        todo_repository: Arc<TodoRep>,
+        todo_dependency_repository: Arc<TodoDependencyRep>,
+    ) -> Self;
+    async fn handle(&self, command: TodoDependencyGetAllCommand)
+        -> Result<Vec<TodoDependencyData>>;
+}
+
+pub struct TodoDependencyGetAllCommand {
+    pub todo_id: String,
+}
+
+// impl of application service to get dependencies of a todo
+pub struct TodoDependencyGetAllApplicationService<TodoRep, TodoDependencyRep> {
+    todo_repository: Arc<TodoRep>,
+    todo_dependency_repository: Arc<TodoDependencyRep>,
+}
+
+#[async_trait]
+impl<TodoRep, TodoDependencyRep> ITodoDependencyGetAllApplicationService<TodoRep, TodoDependencyRep>
+    for TodoDependencyGetAllApplicationService<TodoRep, TodoDependencyRep>
+where
+    TodoRep: ITodoRepository,
+    TodoDependencyRep: ITodoDependencyRepository,
+{
+    fn new(
+        todo_repository: Arc<TodoRep>,
+        todo_dependency_repository: Arc<TodoDependencyRep>,
+    ) -> Self {
+        Self {
+            todo_repository,
+            todo_dependency_repository,
+        }
+    }
+
+    async fn handle(
+        &self,
+        command: TodoDependencyGetAllCommand,
+    ) -> Result<Vec<TodoDependencyData>> {
+        let TodoDependencyGetAllCommand {
+            todo_id: todo_id_string,
+        } = command;
+        let todo_id = TodoId::parse(todo_id_string)
+            .map_err(|e| TodoDependencyApplicationError::IllegalTodoId(e.to_string()))?;
+
+        self.todo_repository
+            .find(&todo_id)
+            .await
+            .map_err(|e| TodoDependencyApplicationError::Unexpected(e.to_string()))?
+            .ok_or(TodoDependencyApplicationError::TodoNotFound(
+                todo_id.clone(),
+            ))?;
+
+        let todo_dependencies_found = self
+            .todo_dependency_repository
+            .find_dependencies_of(&todo_id)
+            .await
+            .map_err(|e| TodoDependencyApplicationError::Unexpected(e.to_string()))?;
+        Ok(todo_dependencies_found
+            .into_iter()
+            .map(TodoDependencyData::new)
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use crate::{
+        domain::{
+            models::{
+                todo_dependencies::todo_dependency::TodoDependency,
+                todos::{todo::Todo, todo_text::TodoText},
+            },
+            value_object::ValueObject,
+        },
+        infra::repository_impl::in_memory::{
+            todo_dependencies::in_memory_todo_dependency_repository::InMemoryTodoDependencyRepository,
+            todos::in_memory_todo_repository::InMemoryTodoRepository,
+        },
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn should_get_dependencies_of_todo() -> Result<()> {
+        let todo_repository = Arc::new(InMemoryTodoRepository::new());
+        let todo_dependency_repository = Arc::new(InMemoryTodoDependencyRepository::new());
+
+        let todo_1 = Todo::new(TodoText::new("todo 1".to_string())?, vec![])?;
+        let todo_2 = Todo::new(TodoText::new("todo 2".to_string())?, vec![])?;
+        let todo_dependency =
+            TodoDependency::new(todo_1.todo_id().clone(), todo_2.todo_id().clone())?;
+
+        // Put the data in advance
+        todo_repository.save(&todo_1).await?;
+        todo_repository.save(&todo_2).await?;
+        {
+            let mut store = todo_dependency_repository.write_store_ref();
+            store.insert(
+                todo_dependency.dependency_id().clone(),
+                todo_dependency.clone(),
+            );
+        }
+
+        let todo_dependency_get_all_application_service =
+            TodoDependencyGetAllApplicationService::new(
+                todo_repository.clone(),
+                todo_dependency_repository.clone(),
+            );
+
+        // todo_2 depends on todo_1
+        let command = TodoDependencyGetAllCommand {
+            todo_id: todo_2.todo_id().value().to_string(),
+        };
+        let todo_dependencies = todo_dependency_get_all_application_service
+            .handle(command)
+            .await?;
+        assert_eq!(
+            vec![TodoDependencyData::new(todo_dependency)],
+            todo_dependencies
+        );
+
+        // todo_1 depends on nothing
+        let command = TodoDependencyGetAllCommand {
+            todo_id: todo_1.todo_id().value().to_string(),
+        };
+        let todo_dependencies = todo_dependency_get_all_application_service
+            .handle(command)
+            .await?;
+        assert!(todo_dependencies.is_empty());
+        Ok(())
+    }
+}
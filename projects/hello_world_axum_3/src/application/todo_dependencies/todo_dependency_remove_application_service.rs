@@ -0,0 +1,153 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+
+use crate::domain::models::{
+    todo_dependencies::{
+        dependency_id::DependencyId, todo_dependency_repository::ITodoDependencyRepository,
+    },
+    todos::todo_id::TodoId,
+};
+
+use super::{todo_dependency_application_error::TodoDependencyApplicationError, Result};
+
+// trait of application service to remove todo dependency
+#[async_trait]
+pub trait ITodoDependencyRemoveApplicationService<TodoDependencyRep: ITodoDependencyRepository> {
+    fn new(todo_dependency_repository: Arc<TodoDependencyRep>) -> Self;
+    async fn handle(&self, command: TodoDependencyRemoveCommand) -> Result<()>;
+}
+
+// command object
+pub struct TodoDependencyRemoveCommand {
+    pub todo_id: String,
+    pub dependency_id: String,
+}
+
+// impl of application service to remove todo dependency
+pub struct TodoDependencyRemoveApplicationService<TodoDependencyRep> {
+    todo_dependency_repository: Arc<TodoDependencyRep>,
+}
+
+#[async_trait]
+impl<TodoDependencyRep: ITodoDependencyRepository>
+    ITodoDependencyRemoveApplicationService<TodoDependencyRep>
+    for TodoDependencyRemoveApplicationService<TodoDependencyRep>
+{
+    fn new(todo_dependency_repository: Arc<TodoDependencyRep>) -> Self {
+        Self {
+            todo_dependency_repository,
+        }
+    }
+
+    async fn handle(&self, command: TodoDependencyRemoveCommand) -> Result<()> {
+        let TodoDependencyRemoveCommand {
+            todo_id: todo_id_string,
+            dependency_id: dependency_id_string,
+        } = command;
+        let todo_id = TodoId::parse(todo_id_string)
+            .map_err(|e| TodoDependencyApplicationError::IllegalTodoId(e.to_string()))?;
+        let dependency_id = DependencyId::parse(dependency_id_string)
+            .map_err(|e| TodoDependencyApplicationError::IllegalDependencyId(e.to_string()))?;
+
+        // 指定された todo の依存関係でなければ見つからなかったものとして扱う
+        let todo_dependency = self
+            .todo_dependency_repository
+            .find(&dependency_id)
+            .await
+            .map_err(|e| TodoDependencyApplicationError::Unexpected(e.to_string()))?
+            .filter(|todo_dependency| todo_dependency.to_todo_id() == &todo_id)
+            .ok_or(TodoDependencyApplicationError::DependencyNotFound(
+                dependency_id,
+            ))?;
+
+        self.todo_dependency_repository
+            .remove(todo_dependency)
+            .await
+            .map_err(|e| TodoDependencyApplicationError::Unexpected(e.to_string()))?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use uuid::Uuid;
+
+    use crate::{
+        domain::{
+            models::todo_dependencies::todo_dependency::TodoDependency, value_object::ValueObject,
+        },
+        infra::repository_impl::in_memory::todo_dependencies::in_memory_todo_dependency_repository::InMemoryTodoDependencyRepository,
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn should_remove_todo_dependency() -> Result<()> {
+        let todo_dependency_repository = Arc::new(InMemoryTodoDependencyRepository::new());
+
+        let to_todo_id = TodoId::new(Uuid::new_v4())?;
+        let todo_dependency =
+            TodoDependency::new(TodoId::new(Uuid::new_v4())?, to_todo_id.clone())?;
+        let dependency_id = todo_dependency.dependency_id().clone();
+
+        // Put the data in advance
+        {
+            let mut store = todo_dependency_repository.write_store_ref();
+            store.insert(dependency_id.clone(), todo_dependency);
+        }
+
+        let todo_dependency_remove_application_service =
+            TodoDependencyRemoveApplicationService::new(todo_dependency_repository.clone());
+        let command = TodoDependencyRemoveCommand {
+            todo_id: to_todo_id.value().to_string(),
+            dependency_id: dependency_id.value().to_string(),
+        };
+        todo_dependency_remove_application_service
+            .handle(command)
+            .await?;
+
+        // Check if todo dependency is removed
+        let store = todo_dependency_repository.read_store_ref();
+        assert!(store.get(&dependency_id).is_none());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_throw_error_if_dependency_belongs_to_another_todo() -> Result<()> {
+        let todo_dependency_repository = Arc::new(InMemoryTodoDependencyRepository::new());
+
+        let from_todo_id = TodoId::new(Uuid::new_v4())?;
+        let todo_dependency =
+            TodoDependency::new(from_todo_id.clone(), TodoId::new(Uuid::new_v4())?)?;
+        let dependency_id = todo_dependency.dependency_id().clone();
+
+        // Put the data in advance
+        {
+            let mut store = todo_dependency_repository.write_store_ref();
+            store.insert(dependency_id.clone(), todo_dependency);
+        }
+
+        // Try to remove the dependency through the todo it does not belong to
+        let todo_dependency_remove_application_service =
+            TodoDependencyRemoveApplicationService::new(todo_dependency_repository.clone());
+        let command = TodoDependencyRemoveCommand {
+            todo_id: from_todo_id.value().to_string(),
+            dependency_id: dependency_id.value().to_string(),
+        };
+        let result = todo_dependency_remove_application_service
+            .handle(command)
+            .await;
+
+        assert_eq!(
+            Err(TodoDependencyApplicationError::DependencyNotFound(
+                dependency_id.clone()
+            )),
+            result
+        );
+        let store = todo_dependency_repository.read_store_ref();
+        assert!(store.get(&dependency_id).is_some());
+        Ok(())
+    }
+}
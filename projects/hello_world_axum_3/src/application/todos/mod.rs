@@ -1,12 +1,129 @@
+pub mod todo_application_error;
+pub mod todo_bulk_complete_by_label_application_service;
+pub mod todo_bulk_create_application_service;
+pub mod todo_bulk_get_application_service;
+pub mod todo_clear_completed_application_service;
 pub mod todo_create_application_service;
-pub mod todo_get_application_service;
+pub mod todo_data;
+pub mod todo_delete_application_service;
+pub mod todo_export_application_service;
+pub mod todo_filter_by_label_application_service;
 pub mod todo_get_all_aplication_service;
+pub mod todo_get_application_service;
+pub mod todo_get_assigned_application_service;
+pub mod todo_get_audit_application_service;
+pub mod todo_get_due_soon_application_service;
+pub mod todo_get_similar_application_service;
+pub mod todo_ical_export_application_service;
+pub mod todo_list_view_data;
+pub mod todo_search_application_service;
 pub mod todo_update_application_service;
-pub mod todo_delete_application_service;
-pub mod todo_data;
-pub mod todo_application_error;
 
+use serde_json::{json, Value};
+
+use crate::domain::{
+    models::{
+        audit_logs::{
+            audit_action::AuditAction, audit_entry::AuditEntry,
+            audit_log_repository::IAuditLogRepository,
+        },
+        todos::{
+            todo::Todo,
+            todo_id::TodoId,
+            todo_text::{TodoText, TodoTextError},
+        },
+        users::{user_id::UserId, user_repository::IUserRepository},
+    },
+    value_object::ValueObject,
+};
 
 use self::todo_application_error::TodoApplicationError;
 
-pub type Result<T> = anyhow::Result<T, TodoApplicationError>;
\ No newline at end of file
+pub type Result<T> = anyhow::Result<T, TodoApplicationError>;
+
+// 監査ログで todo を表すリソースの種類
+const AUDIT_RESOURCE_TYPE: &str = "todo";
+
+// `normalize_case` が true の場合は先頭の文字を大文字にしてから、todo のテキストとして検証する
+fn parse_todo_text(
+    todo_text: String,
+    normalize_case: bool,
+) -> anyhow::Result<TodoText, TodoTextError> {
+    if normalize_case {
+        TodoText::with_sentence_case(todo_text)
+    } else {
+        TodoText::sanitized(todo_text)
+    }
+}
+
+// "YYYY-MM-DD" 形式の文字列を期限日としてパースする
+fn parse_due_date(due_date: &str) -> anyhow::Result<chrono::NaiveDate> {
+    chrono::NaiveDate::parse_from_str(due_date, "%Y-%m-%d")
+        .map_err(|_| anyhow::anyhow!("Due date must be in YYYY-MM-DD format: [{}]", due_date))
+}
+
+// ラベルの数が上限を超えたときのエラー
+fn too_many_labels(max_labels: usize) -> TodoApplicationError {
+    TodoApplicationError::IllegalArgumentError(format!(
+        "A todo can have at most {} labels.",
+        max_labels
+    ))
+}
+
+// 担当者として指定されたユーザーが存在することを確認する
+async fn parse_assignee_id<UserRep: IUserRepository>(
+    user_repository: &UserRep,
+    assignee_id: String,
+) -> Result<UserId> {
+    let assignee_id = UserId::parse(assignee_id)
+        .map_err(|e| TodoApplicationError::IllegalUserId(e.to_string()))?;
+    let exists = user_repository
+        .exists(&assignee_id)
+        .await
+        .map_err(|e| TodoApplicationError::Unexpected(e.to_string()))?;
+    if !exists {
+        return Err(TodoApplicationError::AssigneeNotFound(assignee_id));
+    }
+    Ok(assignee_id)
+}
+
+// 監査ログに変更前後の内容として残す todo の項目
+fn audit_snapshot(todo: &Todo) -> Value {
+    json!({
+        "text": todo.todo_text.value(),
+        "note": todo.note.as_ref().map(|note| note.value()),
+        "due_date": todo.due_date.map(|due_date| due_date.to_string()),
+        "assignee_id": todo.assignee_id.as_ref().map(|assignee_id| assignee_id.to_string()),
+        "completed": todo.completed,
+        "labels": todo
+            .labels
+            .iter()
+            .map(|label| label.label_name.value())
+            .collect::<Vec<_>>(),
+    })
+}
+
+// todo の変更を監査ログに記録する
+// 作成時は `before`、削除時は `after` に null を渡す
+async fn record_audit<AuditLogRep: IAuditLogRepository>(
+    audit_log_repository: &AuditLogRep,
+    todo_id: &TodoId,
+    actor_id: Option<UserId>,
+    action: AuditAction,
+    before: Value,
+    after: Value,
+) -> Result<()> {
+    let entry = AuditEntry::new(
+        AUDIT_RESOURCE_TYPE,
+        *todo_id.value(),
+        actor_id,
+        action,
+        before,
+        after,
+    )
+    .map_err(|e| TodoApplicationError::Unexpected(e.to_string()))?;
+    audit_log_repository
+        .save(&entry)
+        .await
+        .map_err(|e| TodoApplicationError::Unexpected(e.to_string()))
+}
@@ -1,7 +1,13 @@
 use serde::Serialize;
 use thiserror::Error;
 
-use crate::domain::models::{todos::{todo::Todo, todo_id::TodoId}, labels::label_id::LabelId};
+use super::todo_data::TodoData;
+
+use crate::domain::models::{
+    labels::label_id::LabelId,
+    todos::{todo::Todo, todo_id::TodoId, todo_repository::TodoRepositoryError},
+    users::user_id::UserId,
+};
 
 #[derive(Debug, Error, PartialEq)]
 pub enum TodoApplicationError {
@@ -11,16 +17,50 @@ pub enum TodoApplicationError {
     TodoNotFound(TodoId),
     #[error("Label cannnot be found: [id: {0:?}]")]
     LabelNotFound(LabelId),
+    #[error("Assignee cannnot be found: [id: {0:?}]")]
+    AssigneeNotFound(UserId),
     #[error("Given todo is incorrect: [{0}]")]
     IllegalArgumentError(String),
     #[error("Given todo id has incorrect format: [{0}]")]
     IllegalTodoId(String),
     #[error("Given label id has incorrect format: [{0}]")]
     IllegalLabelId(String),
+    #[error("Given user id has incorrect format: [{0}]")]
+    IllegalUserId(String),
+    #[error("Todos that must be completed first are not completed: [ids: {0:?}]")]
+    DependencyNotMet(Vec<TodoId>),
+    // 読み出した時点から、他のリクエストによって todo が更新されていた
+    #[error("Todo has been modified by another request: [id: {0:?}]")]
+    StaleData(TodoId),
+    // 他のユーザーの todo を操作する権限がない
+    #[error("Not allowed to modify todos of another user: [user id: {0:?}]")]
+    PermissionDenied(UserId),
+    // まとめて作成した todo の一部だけが作成できた
+    // 作成できなかった todo はバッチ内の位置とエラーメッセージの組で表す
+    #[error("Some todos cannot be created: [failed: {failed:?}]")]
+    PartialSuccess {
+        created: Vec<TodoData>,
+        failed: Vec<(usize, String)>,
+    },
+    // データベースが混み合っているなどでクエリが制限時間内に終わらなかった
+    // 時間をおいて再試行すれば成功する可能性がある
+    #[error("Timed out while accessing todos")]
+    Timeout,
     #[error("Unexpected error: [{0}]")]
     Unexpected(String),
 }
 
+impl From<TodoRepositoryError> for TodoApplicationError {
+    fn from(e: TodoRepositoryError) -> Self {
+        match e {
+            TodoRepositoryError::NotFound(todo_id) => Self::TodoNotFound(todo_id),
+            TodoRepositoryError::StaleData(todo_id) => Self::StaleData(todo_id),
+            TodoRepositoryError::Timeout => Self::Timeout,
+            TodoRepositoryError::Unexpected(e) => Self::Unexpected(e),
+        }
+    }
+}
+
 // <https://github.com/serde-rs/serde/issues/2268#issuecomment-1238962452> を参考に実装
 impl Serialize for TodoApplicationError {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -30,3 +70,131 @@ impl Serialize for TodoApplicationError {
         serializer.serialize_str(self.to_string().as_ref())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::domain::{models::todos::todo_text::TodoText, value_object::ValueObject};
+
+    #[test]
+    fn should_compare_every_variant_by_value() -> Result<()> {
+        use TodoApplicationError::*;
+
+        let todo_1 = Todo::new(TodoText::new("test-1".to_string())?, vec![])?;
+        let todo_2 = Todo::new(TodoText::new("test-2".to_string())?, vec![])?;
+        let todo_id_1 = TodoId::new(Uuid::new_v4())?;
+        let todo_id_2 = TodoId::new(Uuid::new_v4())?;
+        let label_id_1 = LabelId::new(Uuid::new_v4())?;
+        let label_id_2 = LabelId::new(Uuid::new_v4())?;
+        let user_id_1 = UserId::new(Uuid::new_v4())?;
+        let user_id_2 = UserId::new(Uuid::new_v4())?;
+
+        // (比較対象, 同じ値, 異なる値)
+        let cases = [
+            (
+                DuplicatedTodo(todo_1.clone()),
+                DuplicatedTodo(todo_1),
+                DuplicatedTodo(todo_2),
+            ),
+            (
+                TodoNotFound(todo_id_1.clone()),
+                TodoNotFound(todo_id_1.clone()),
+                TodoNotFound(todo_id_2.clone()),
+            ),
+            (
+                LabelNotFound(label_id_1.clone()),
+                LabelNotFound(label_id_1),
+                LabelNotFound(label_id_2),
+            ),
+            (
+                AssigneeNotFound(user_id_1.clone()),
+                AssigneeNotFound(user_id_1.clone()),
+                AssigneeNotFound(user_id_2.clone()),
+            ),
+            (
+                IllegalArgumentError("a".to_string()),
+                IllegalArgumentError("a".to_string()),
+                IllegalArgumentError("b".to_string()),
+            ),
+            (
+                IllegalTodoId("a".to_string()),
+                IllegalTodoId("a".to_string()),
+                IllegalTodoId("b".to_string()),
+            ),
+            (
+                IllegalLabelId("a".to_string()),
+                IllegalLabelId("a".to_string()),
+                IllegalLabelId("b".to_string()),
+            ),
+            (
+                IllegalUserId("a".to_string()),
+                IllegalUserId("a".to_string()),
+                IllegalUserId("b".to_string()),
+            ),
+            (
+                DependencyNotMet(vec![todo_id_1.clone()]),
+                DependencyNotMet(vec![todo_id_1.clone()]),
+                DependencyNotMet(vec![todo_id_2.clone()]),
+            ),
+            (
+                StaleData(todo_id_1.clone()),
+                StaleData(todo_id_1),
+                StaleData(todo_id_2),
+            ),
+            (
+                PermissionDenied(user_id_1.clone()),
+                PermissionDenied(user_id_1),
+                PermissionDenied(user_id_2),
+            ),
+            (
+                PartialSuccess {
+                    created: vec![],
+                    failed: vec![(1, "a".to_string())],
+                },
+                PartialSuccess {
+                    created: vec![],
+                    failed: vec![(1, "a".to_string())],
+                },
+                PartialSuccess {
+                    created: vec![],
+                    failed: vec![(2, "a".to_string())],
+                },
+            ),
+            (
+                Unexpected("a".to_string()),
+                Unexpected("a".to_string()),
+                Unexpected("b".to_string()),
+            ),
+        ];
+
+        for (error, same, different) in cases {
+            assert_eq!(error, same);
+            assert_ne!(error, different);
+        }
+        assert_eq!(Timeout, Timeout);
+        assert_ne!(Timeout, Unexpected("a".to_string()));
+        Ok(())
+    }
+
+    #[test]
+    fn should_convert_every_repository_error() -> Result<()> {
+        let todo_id = TodoId::new(Uuid::new_v4())?;
+
+        assert_eq!(
+            TodoApplicationError::TodoNotFound(todo_id.clone()),
+            TodoRepositoryError::NotFound(todo_id).into()
+        );
+        assert_eq!(
+            TodoApplicationError::Timeout,
+            TodoRepositoryError::Timeout.into()
+        );
+        assert_eq!(
+            TodoApplicationError::Unexpected("a".to_string()),
+            TodoRepositoryError::Unexpected("a".to_string()).into()
+        );
+        Ok(())
+    }
+}
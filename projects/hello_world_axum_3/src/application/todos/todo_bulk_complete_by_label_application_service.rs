@@ -0,0 +1,389 @@
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
+
+use axum::async_trait;
+
+use crate::domain::{
+    events::{event_bus::EventBus, todo_events::TodoUpdated},
+    models::{
+        labels::{label_id::LabelId, label_repository::ILabelRepository},
+        todo_dependencies::todo_dependency_repository::ITodoDependencyRepository,
+        todos::{
+            label_filter::{FilterOperator, LabelFilter},
+            todo::Todo,
+            todo_id::TodoId,
+            todo_repository::ITodoRepository,
+        },
+    },
+};
+
+use super::{todo_application_error::TodoApplicationError, Result};
+
+// trait of application service to change completion of all todos with a label
+#[async_trait]
+pub trait ITodoBulkCompleteByLabelApplicationService<
+    TodoRep: ITodoRepository,
+    LabelRep: ILabelRepository,
+    TodoDependencyRep: ITodoDependencyRepository,
+>
+{
+    fn new(
+        todo_repository: Arc<TodoRep>,
+        label_repository: Arc<LabelRep>,
+        todo_dependency_repository: Arc<TodoDependencyRep>,
+        event_bus: Arc<EventBus>,
+    ) -> Self;
+    async fn handle(&self, command: TodoBulkCompleteByLabelCommand) -> Result<BulkUpdateResult>;
+}
+
+// command object
+pub struct TodoBulkCompleteByLabelCommand {
+    pub label_id: String,
+    pub completed: bool,
+}
+
+// 完了状態を変更した todo の件数。すでに `completed` になっていた todo は数えない
+// 先に完了していなければならない todo が完了していないため、完了にしなかった todo は `skipped_todo_ids` に入る
+#[derive(Debug, PartialEq)]
+pub struct BulkUpdateResult {
+    pub updated_count: u64,
+    pub skipped_todo_ids: Vec<TodoId>,
+}
+
+// impl of application service to change completion of all todos with a label
+pub struct TodoBulkCompleteByLabelApplicationService<
+    TodoRep: ITodoRepository,
+    LabelRep: ILabelRepository,
+    TodoDependencyRep: ITodoDependencyRepository,
+> {
+    todo_repository: Arc<TodoRep>,
+    label_repository: Arc<LabelRep>,
+    todo_dependency_repository: Arc<TodoDependencyRep>,
+    event_bus: Arc<EventBus>,
+}
+
+impl<TodoRep, LabelRep, TodoDependencyRep>
+    TodoBulkCompleteByLabelApplicationService<TodoRep, LabelRep, TodoDependencyRep>
+where
+    TodoRep: ITodoRepository,
+    LabelRep: ILabelRepository,
+    TodoDependencyRep: ITodoDependencyRepository,
+{
+    // 完了にする todo のうち、先に完了していなければならない todo が完了しないものを取り除き、その id を返す
+    // 同時に完了にする todo は完了しているものとみなす。取り除いた todo に依存する todo も取り除く
+    async fn exclude_todos_with_unmet_dependencies(
+        &self,
+        todos: &mut Vec<Todo>,
+    ) -> Result<Vec<TodoId>> {
+        let mut dependencies_of = HashMap::<TodoId, Vec<TodoId>>::new();
+        for todo in todos.iter() {
+            let todo_dependencies = self
+                .todo_dependency_repository
+                .find_dependencies_of(todo.todo_id())
+                .await
+                .map_err(|e| TodoApplicationError::Unexpected(e.to_string()))?;
+            dependencies_of.insert(
+                todo.todo_id().clone(),
+                todo_dependencies
+                    .iter()
+                    .map(|todo_dependency| todo_dependency.from_todo_id().clone())
+                    .collect(),
+            );
+        }
+        let dependency_ids: Vec<TodoId> = dependencies_of
+            .values()
+            .flatten()
+            .cloned()
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        let completed_ids: HashSet<TodoId> = self
+            .todo_repository
+            .find_many_by_ids(&dependency_ids)
+            .await?
+            .into_iter()
+            .filter(|todo| todo.completed)
+            .map(|todo| todo.todo_id().clone())
+            .collect();
+
+        let mut skipped_todo_ids = Vec::<TodoId>::new();
+        loop {
+            let target_ids: HashSet<TodoId> =
+                todos.iter().map(|todo| todo.todo_id().clone()).collect();
+            let (met, unmet): (Vec<Todo>, Vec<Todo>) = todos.drain(..).partition(|todo| {
+                dependencies_of[todo.todo_id()].iter().all(|dependency_id| {
+                    completed_ids.contains(dependency_id) || target_ids.contains(dependency_id)
+                })
+            });
+            *todos = met;
+            if unmet.is_empty() {
+                return Ok(skipped_todo_ids);
+            }
+            skipped_todo_ids.extend(unmet.iter().map(|todo| todo.todo_id().clone()));
+        }
+    }
+}
+
+#[async_trait]
+impl<TodoRep, LabelRep, TodoDependencyRep>
+    ITodoBulkCompleteByLabelApplicationService<TodoRep, LabelRep, TodoDependencyRep>
+    for TodoBulkCompleteByLabelApplicationService<TodoRep, LabelRep, TodoDependencyRep>
+where
+    TodoRep: ITodoRepository,
+    LabelRep: ILabelRepository,
+    TodoDependencyRep: ITodoDependencyRepository,
+{
+    fn new(
+        todo_repository: Arc<TodoRep>,
+        label_repository: Arc<LabelRep>,
+        todo_dependency_repository: Arc<TodoDependencyRep>,
+        event_bus: Arc<EventBus>,
+    ) -> Self {
+        Self {
+            todo_repository,
+            label_repository,
+            todo_dependency_repository,
+            event_bus,
+        }
+    }
+
+    async fn handle(&self, command: TodoBulkCompleteByLabelCommand) -> Result<BulkUpdateResult> {
+        let TodoBulkCompleteByLabelCommand {
+            label_id,
+            completed,
+        } = command;
+        let label_id = LabelId::parse(label_id)
+            .map_err(|e| TodoApplicationError::IllegalLabelId(e.to_string()))?;
+        self.label_repository
+            .find(&label_id)
+            .await
+            .map_err(|e| TodoApplicationError::Unexpected(e.to_string()))?
+            .ok_or(TodoApplicationError::LabelNotFound(label_id.clone()))?;
+
+        let mut todos: Vec<Todo> = self
+            .todo_repository
+            .find_by_label_filter(&LabelFilter {
+                ids: vec![label_id],
+                operator: FilterOperator::And,
+            })
+            .await?
+            .into_iter()
+            .filter(|todo| todo.completed != completed)
+            .collect();
+
+        // 1 件ずつ更新する場合と同じく、先に完了していなければならない todo が完了していれば完了にする
+        let skipped_todo_ids = if completed {
+            self.exclude_todos_with_unmet_dependencies(&mut todos)
+                .await?
+        } else {
+            vec![]
+        };
+
+        let todo_ids: Vec<TodoId> = todos.iter().map(|todo| todo.todo_id().clone()).collect();
+        let updated_count = self
+            .todo_repository
+            .set_completed_many(&todo_ids, completed)
+            .await?;
+
+        // 更新日時と版は保存時に決まるため、読み出し直したものをイベントとして発行する
+        for todo in self.todo_repository.find_many_by_ids(&todo_ids).await? {
+            self.event_bus.publish(TodoUpdated::new(todo));
+        }
+
+        Ok(BulkUpdateResult {
+            updated_count,
+            skipped_todo_ids,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use uuid::Uuid;
+
+    use crate::{
+        domain::{
+            models::{
+                labels::{label::Label, label_name::LabelName},
+                todo_dependencies::todo_dependency::TodoDependency,
+                todos::todo_text::TodoText,
+            },
+            value_object::ValueObject,
+        },
+        infra::repository_impl::in_memory::{
+            labels::in_memory_label_repository::InMemoryLabelRepository,
+            todo_dependencies::in_memory_todo_dependency_repository::InMemoryTodoDependencyRepository,
+            todos::in_memory_todo_repository::InMemoryTodoRepository,
+        },
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn should_complete_only_incomplete_todos_with_label() -> Result<()> {
+        let todo_repository = Arc::new(InMemoryTodoRepository::new());
+        let label_repository = Arc::new(InMemoryLabelRepository::new());
+        let event_bus = Arc::new(EventBus::new());
+        let mut receiver = event_bus.subscribe();
+
+        let label = Label::new(LabelName::new("label-1".to_string())?)?;
+        label_repository.save(&label).await?;
+
+        // Put the data in advance
+        // ラベルの付いた todo を 3 件 (うち 1 件は完了済み) と、ラベルの付いていない todo を 1 件用意する
+        let other_todo = Todo::new(TodoText::new("other".to_string())?, vec![])?;
+        for (i, completed) in [false, false, true].into_iter().enumerate() {
+            let mut todo = Todo::new(TodoText::new(format!("todo-{}", i))?, vec![label.clone()])?;
+            todo.completed = completed;
+            todo_repository.save(&todo).await?;
+        }
+        todo_repository.save(&other_todo).await?;
+
+        let todo_bulk_complete_by_label_application_service =
+            TodoBulkCompleteByLabelApplicationService::new(
+                todo_repository.clone(),
+                label_repository,
+                Arc::new(InMemoryTodoDependencyRepository::new()),
+                event_bus,
+            );
+        let command = || TodoBulkCompleteByLabelCommand {
+            label_id: label.label_id().to_string(),
+            completed: true,
+        };
+        let result = todo_bulk_complete_by_label_application_service
+            .handle(command())
+            .await?;
+
+        assert_eq!(
+            BulkUpdateResult {
+                updated_count: 2,
+                skipped_todo_ids: vec![]
+            },
+            result
+        );
+        todo_repository.for_each_todo(|_, todo| {
+            assert!(todo.completed || !todo.labels.contains(&label));
+        });
+        assert!(
+            !todo_repository
+                .find(other_todo.todo_id())
+                .await?
+                .unwrap()
+                .completed
+        );
+        // 変更した todo ごとにイベントを発行する
+        for _ in 0..2 {
+            let event = receiver.recv().await?;
+            assert_eq!("TodoUpdated", event.event_type());
+            assert_eq!(true, event.to_json()["completed"]);
+        }
+        assert!(receiver.try_recv().is_err());
+
+        // 2 回目は変更するものがない
+        let result = todo_bulk_complete_by_label_application_service
+            .handle(command())
+            .await?;
+        assert_eq!(0, result.updated_count);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_skip_todos_with_unmet_dependencies() -> Result<()> {
+        let todo_repository = Arc::new(InMemoryTodoRepository::new());
+        let label_repository = Arc::new(InMemoryLabelRepository::new());
+        let todo_dependency_repository = Arc::new(InMemoryTodoDependencyRepository::new());
+
+        let label = Label::new(LabelName::new("label-1".to_string())?)?;
+        label_repository.save(&label).await?;
+
+        // blocked は未完了のラベルなしの todo に、chained は blocked に、
+        // unblocked は同時に完了にする free に依存する
+        let new_todo = |text: &str, labels: Vec<Label>| -> Result<Todo> {
+            Todo::new(TodoText::new(text.to_string())?, labels)
+        };
+        let incomplete = new_todo("incomplete", vec![])?;
+        let blocked = new_todo("blocked", vec![label.clone()])?;
+        let chained = new_todo("chained", vec![label.clone()])?;
+        let free = new_todo("free", vec![label.clone()])?;
+        let unblocked = new_todo("unblocked", vec![label.clone()])?;
+        for todo in [&incomplete, &blocked, &chained, &free, &unblocked] {
+            todo_repository.save(todo).await?;
+        }
+        for (from_todo, to_todo) in [
+            (&incomplete, &blocked),
+            (&blocked, &chained),
+            (&free, &unblocked),
+        ] {
+            todo_dependency_repository
+                .add(&TodoDependency::new(
+                    from_todo.todo_id().clone(),
+                    to_todo.todo_id().clone(),
+                )?)
+                .await?;
+        }
+
+        let todo_bulk_complete_by_label_application_service =
+            TodoBulkCompleteByLabelApplicationService::new(
+                todo_repository.clone(),
+                label_repository,
+                todo_dependency_repository,
+                Arc::new(EventBus::new()),
+            );
+        let mut result = todo_bulk_complete_by_label_application_service
+            .handle(TodoBulkCompleteByLabelCommand {
+                label_id: label.label_id().to_string(),
+                completed: true,
+            })
+            .await?;
+
+        assert_eq!(2, result.updated_count);
+        result
+            .skipped_todo_ids
+            .sort_by_key(|todo_id| *todo_id.value());
+        let mut expected_skipped_ids = vec![blocked.todo_id().clone(), chained.todo_id().clone()];
+        expected_skipped_ids.sort_by_key(|todo_id| *todo_id.value());
+        assert_eq!(expected_skipped_ids, result.skipped_todo_ids);
+
+        let mut completed_of = HashMap::new();
+        todo_repository.for_each_todo(|todo_id, todo| {
+            completed_of.insert(todo_id.clone(), todo.completed);
+        });
+        assert!(completed_of[free.todo_id()]);
+        assert!(completed_of[unblocked.todo_id()]);
+        assert!(!completed_of[blocked.todo_id()]);
+        assert!(!completed_of[chained.todo_id()]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_throw_error_if_label_does_not_exist() -> Result<()> {
+        let todo_repository = Arc::new(InMemoryTodoRepository::new());
+        let label_repository = Arc::new(InMemoryLabelRepository::new());
+        let todo_bulk_complete_by_label_application_service =
+            TodoBulkCompleteByLabelApplicationService::new(
+                todo_repository,
+                label_repository,
+                Arc::new(InMemoryTodoDependencyRepository::new()),
+                Arc::new(EventBus::new()),
+            );
+
+        let not_stored_label_id = Uuid::new_v4();
+        let result = todo_bulk_complete_by_label_application_service
+            .handle(TodoBulkCompleteByLabelCommand {
+                label_id: not_stored_label_id.to_string(),
+                completed: true,
+            })
+            .await;
+
+        assert_eq!(
+            Err(TodoApplicationError::LabelNotFound(LabelId::new(
+                not_stored_label_id
+            )?)),
+            result
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,219 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+
+use super::{
+    todo_create_application_service::{
+        ITodoCreateApplicationService, TodoCreateApplicationService, TodoCreateCommand,
+    },
+    todo_data::TodoData,
+    Result,
+};
+
+use crate::domain::{
+    events::event_bus::EventBus,
+    models::{
+        audit_logs::audit_log_repository::IAuditLogRepository,
+        labels::label_repository::ILabelRepository, todos::todo_repository::ITodoRepository,
+        users::user_repository::IUserRepository,
+    },
+};
+
+use super::todo_application_error::TodoApplicationError;
+
+// trait of application service to create todos at once
+#[async_trait]
+pub trait ITodoBulkCreateApplicationService<
+    TodoRep: ITodoRepository,
+    LabelRep: ILabelRepository,
+    UserRep: IUserRepository,
+    AuditLogRep: IAuditLogRepository,
+>
+{
+    fn new(
+        todo_repository: Arc<TodoRep>,
+        label_repository: Arc<LabelRep>,
+        user_repository: Arc<UserRep>,
+        audit_log_repository: Arc<AuditLogRep>,
+        event_bus: Arc<EventBus>,
+    ) -> Self;
+    // 1 つの todo に付けられるラベルの数の上限を変える
+    fn with_max_labels(self, max_labels: usize) -> Self;
+    async fn handle(&self, command: TodoBulkCreateCommand) -> Result<Vec<TodoData>>;
+}
+
+// command object
+pub struct TodoBulkCreateCommand {
+    pub todos: Vec<TodoCreateCommand>,
+}
+
+// impl of application service to create todos at once
+pub struct TodoBulkCreateApplicationService<
+    TodoRep: ITodoRepository,
+    LabelRep: ILabelRepository,
+    UserRep: IUserRepository,
+    AuditLogRep: IAuditLogRepository,
+> {
+    todo_create_application_service:
+        TodoCreateApplicationService<TodoRep, LabelRep, UserRep, AuditLogRep>,
+}
+
+#[async_trait]
+impl<TodoRep, LabelRep, UserRep, AuditLogRep>
+    ITodoBulkCreateApplicationService<TodoRep, LabelRep, UserRep, AuditLogRep>
+    for TodoBulkCreateApplicationService<TodoRep, LabelRep, UserRep, AuditLogRep>
+where
+    TodoRep: ITodoRepository,
+    LabelRep: ILabelRepository,
+    UserRep: IUserRepository,
+    AuditLogRep: IAuditLogRepository,
+{
+    fn new(
+        todo_repository: Arc<TodoRep>,
+        label_repository: Arc<LabelRep>,
+        user_repository: Arc<UserRep>,
+        audit_log_repository: Arc<AuditLogRep>,
+        event_bus: Arc<EventBus>,
+    ) -> Self {
+        Self {
+            todo_create_application_service: TodoCreateApplicationService::new(
+                todo_repository,
+                label_repository,
+                user_repository,
+                audit_log_repository,
+                event_bus,
+            ),
+        }
+    }
+
+    fn with_max_labels(self, max_labels: usize) -> Self {
+        Self {
+            todo_create_application_service: self
+                .todo_create_application_service
+                .with_max_labels(max_labels),
+        }
+    }
+
+    // 1 件ずつ作成し、作成できなかった todo があっても残りの作成を続ける
+    // 1 件でも失敗した場合は、作成できた todo と合わせて PartialSuccess として返す
+    async fn handle(&self, command: TodoBulkCreateCommand) -> Result<Vec<TodoData>> {
+        let TodoBulkCreateCommand { todos: commands } = command;
+
+        let mut created = Vec::<TodoData>::new();
+        let mut failed = Vec::<(usize, String)>::new();
+
+        for (index, command) in commands.into_iter().enumerate() {
+            match self.todo_create_application_service.handle(command).await {
+                Ok(todo_data) => created.push(todo_data),
+                Err(e) => failed.push((index, e.to_string())),
+            }
+        }
+
+        if failed.is_empty() {
+            Ok(created)
+        } else {
+            Err(TodoApplicationError::PartialSuccess { created, failed })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use crate::{
+        domain::value_object::ValueObject,
+        infra::repository_impl::in_memory::{
+            audit_logs::in_memory_audit_log_repository::InMemoryAuditLogRepository,
+            labels::in_memory_label_repository::InMemoryLabelRepository,
+            todos::in_memory_todo_repository::InMemoryTodoRepository,
+            users::in_memory_user_repository::InMemoryUserRepository,
+        },
+    };
+
+    use super::*;
+
+    fn command_for_test(todo_texts: &[&str]) -> TodoBulkCreateCommand {
+        TodoBulkCreateCommand {
+            todos: todo_texts
+                .iter()
+                .map(|todo_text| TodoCreateCommand {
+                    todo_text: todo_text.to_string(),
+                    label_ids: vec![],
+                    label_names: vec![],
+                    note: None,
+                    due_date: None,
+                    assignee_id: None,
+                    normalize_case: false,
+                    requested_by: None,
+                })
+                .collect(),
+        }
+    }
+
+    fn service_for_test(
+        todo_repository: Arc<InMemoryTodoRepository>,
+    ) -> TodoBulkCreateApplicationService<
+        InMemoryTodoRepository,
+        InMemoryLabelRepository,
+        InMemoryUserRepository,
+        InMemoryAuditLogRepository,
+    > {
+        TodoBulkCreateApplicationService::new(
+            todo_repository,
+            Arc::new(InMemoryLabelRepository::new()),
+            Arc::new(InMemoryUserRepository::new()),
+            Arc::new(InMemoryAuditLogRepository::new()),
+            Arc::new(EventBus::new()),
+        )
+    }
+
+    #[tokio::test]
+    async fn should_create_every_todo_if_none_fails() -> Result<()> {
+        let todo_repository = Arc::new(InMemoryTodoRepository::new());
+        let todo_bulk_create_application_service = service_for_test(todo_repository.clone());
+
+        let command = command_for_test(&["todo-1", "todo-2"]);
+        let created = todo_bulk_create_application_service.handle(command).await?;
+
+        let created_texts: Vec<&str> = created
+            .iter()
+            .map(|todo_data| todo_data.todo_text.as_str())
+            .collect();
+        assert_eq!(vec!["todo-1", "todo-2"], created_texts);
+        assert_eq!(2, todo_repository.find_all().await?.len());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_return_partial_success_if_some_todos_fail() -> Result<()> {
+        let todo_repository = Arc::new(InMemoryTodoRepository::new());
+        let todo_bulk_create_application_service = service_for_test(todo_repository.clone());
+
+        let command = command_for_test(&["todo-0", "", "todo-2"]);
+        let result = todo_bulk_create_application_service.handle(command).await;
+
+        // 作成された todo の id は分からないので、保存された todo から期待値を組み立てる
+        let stored_todo_data = |todo_text: &str| -> TodoData {
+            let mut todo_found = None;
+            todo_repository.for_each_todo(|_, todo| {
+                if todo.todo_text.value() == todo_text {
+                    todo_found = Some(todo.clone());
+                }
+            });
+            TodoData::new(todo_found.unwrap())
+        };
+        assert_eq!(
+            Err(TodoApplicationError::PartialSuccess {
+                created: vec![stored_todo_data("todo-0"), stored_todo_data("todo-2")],
+                failed: vec![(
+                    1,
+                    "Given todo is incorrect: [Todo text must not be empty.]".to_string()
+                )],
+            }),
+            result
+        );
+        assert_eq!(2, todo_repository.find_all().await?.len());
+        Ok(())
+    }
+}
@@ -0,0 +1,159 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+
+use crate::domain::models::{
+    common::batch_result::BatchFindResult,
+    todos::{todo_id::TodoId, todo_repository::ITodoRepository},
+};
+
+use super::{todo_application_error::TodoApplicationError, todo_data::TodoData, Result};
+
+// 一度に取得できる todo の数の上限
+const MAX_TODO_IDS: usize = 100;
+
+// trait of application service to get multiple todos at once
+#[async_trait]
+pub trait ITodoBulkGetApplicationService<T: ITodoRepository> {
+    fn new(todo_repository: Arc<T>) -> Self;
+    async fn handle(
+        &self,
+        command: TodoBulkGetCommand,
+    ) -> Result<BatchFindResult<TodoData, TodoId>>;
+}
+
+// command object
+pub struct TodoBulkGetCommand {
+    pub todo_ids: Vec<String>,
+}
+
+// impl of application service to get multiple todos at once
+pub struct TodoBulkGetApplicationService<T: ITodoRepository> {
+    todo_repository: Arc<T>,
+}
+
+#[async_trait]
+impl<T: ITodoRepository> ITodoBulkGetApplicationService<T> for TodoBulkGetApplicationService<T> {
+    fn new(todo_repository: Arc<T>) -> Self {
+        Self { todo_repository }
+    }
+
+    async fn handle(
+        &self,
+        command: TodoBulkGetCommand,
+    ) -> Result<BatchFindResult<TodoData, TodoId>> {
+        let TodoBulkGetCommand { todo_ids } = command;
+        if todo_ids.len() > MAX_TODO_IDS {
+            return Err(TodoApplicationError::IllegalArgumentError(format!(
+                "Up to {} todo ids can be requested at once.",
+                MAX_TODO_IDS
+            )));
+        }
+        let todo_ids = todo_ids
+            .into_iter()
+            .map(TodoId::parse)
+            .collect::<std::result::Result<Vec<TodoId>, _>>()
+            .map_err(|e| TodoApplicationError::IllegalTodoId(e.to_string()))?;
+
+        let BatchFindResult {
+            mut found,
+            not_found,
+        } = self.todo_repository.batch_find_result(&todo_ids).await?;
+        // 指定された順に並べて返す
+        found.sort_by_key(|todo| {
+            todo_ids
+                .iter()
+                .position(|todo_id| todo_id == todo.todo_id())
+        });
+        Ok(BatchFindResult {
+            found: found.into_iter().map(TodoData::new).collect(),
+            not_found,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use uuid::Uuid;
+
+    use crate::{
+        domain::{
+            models::todos::{todo::Todo, todo_text::TodoText},
+            value_object::ValueObject,
+        },
+        infra::repository_impl::in_memory::todos::in_memory_todo_repository::InMemoryTodoRepository,
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn should_return_found_todos_and_ids_not_found() -> Result<()> {
+        let repository = Arc::new(InMemoryTodoRepository::new());
+        let mut todos = vec![];
+        for i in 0..3 {
+            let todo = Todo::new(TodoText::new(format!("todo-{}", i))?, vec![])?;
+            repository.save(&todo).await?;
+            todos.push(todo);
+        }
+        let missing_ids = vec![TodoId::new(Uuid::new_v4())?, TodoId::new(Uuid::new_v4())?];
+
+        let todo_bulk_get_application_service = TodoBulkGetApplicationService::new(repository);
+        let todo_ids = [
+            todos[2].todo_id(),
+            &missing_ids[0],
+            todos[0].todo_id(),
+            &missing_ids[1],
+            todos[1].todo_id(),
+        ];
+        let result = todo_bulk_get_application_service
+            .handle(TodoBulkGetCommand {
+                todo_ids: todo_ids.iter().map(|todo_id| todo_id.to_string()).collect(),
+            })
+            .await?;
+
+        assert_eq!(3, result.found.len());
+        assert_eq!(
+            vec![
+                TodoData::new(todos[2].clone()),
+                TodoData::new(todos[0].clone()),
+                TodoData::new(todos[1].clone()),
+            ],
+            result.found
+        );
+        assert_eq!(2, result.not_found.len());
+        assert_eq!(missing_ids, result.not_found);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_reject_illegal_or_too_many_ids() -> Result<()> {
+        let todo_bulk_get_application_service =
+            TodoBulkGetApplicationService::new(Arc::new(InMemoryTodoRepository::new()));
+
+        let result = todo_bulk_get_application_service
+            .handle(TodoBulkGetCommand {
+                todo_ids: vec!["not-a-uuid".to_string()],
+            })
+            .await;
+        assert!(matches!(
+            result,
+            Err(TodoApplicationError::IllegalTodoId(_))
+        ));
+
+        let result = todo_bulk_get_application_service
+            .handle(TodoBulkGetCommand {
+                todo_ids: (0..=MAX_TODO_IDS)
+                    .map(|_| Uuid::new_v4().to_string())
+                    .collect(),
+            })
+            .await;
+        assert_eq!(
+            Err(TodoApplicationError::IllegalArgumentError(
+                "Up to 100 todo ids can be requested at once.".to_string()
+            )),
+            result
+        );
+        Ok(())
+    }
+}
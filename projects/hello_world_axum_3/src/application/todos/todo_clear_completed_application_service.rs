@@ -0,0 +1,217 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+
+use crate::domain::{
+    events::{event_bus::EventBus, todo_events::TodoDeleted},
+    models::{
+        todos::todo_repository::ITodoRepository,
+        users::{user_id::UserId, user_repository::IUserRepository, user_role::UserRole},
+    },
+};
+
+use super::{parse_assignee_id, todo_application_error::TodoApplicationError, Result};
+
+// trait of application service to delete completed todos of a user
+#[async_trait]
+pub trait ITodoClearCompletedApplicationService<TodoRep: ITodoRepository, UserRep: IUserRepository>
+{
+    fn new(
+        todo_repository: Arc<TodoRep>,
+        user_repository: Arc<UserRep>,
+        event_bus: Arc<EventBus>,
+    ) -> Self;
+    async fn handle(&self, command: TodoClearCompletedCommand) -> Result<u64>;
+}
+
+// command object
+pub struct TodoClearCompletedCommand {
+    pub user_id: String,
+    // 操作を依頼した認証済みのユーザー
+    pub requested_by: UserId,
+}
+
+// impl of application service to delete completed todos of a user
+pub struct TodoClearCompletedApplicationService<TodoRep: ITodoRepository, UserRep: IUserRepository>
+{
+    todo_repository: Arc<TodoRep>,
+    user_repository: Arc<UserRep>,
+    event_bus: Arc<EventBus>,
+}
+
+#[async_trait]
+impl<TodoRep, UserRep> ITodoClearCompletedApplicationService<TodoRep, UserRep>
+    for TodoClearCompletedApplicationService<TodoRep, UserRep>
+where
+    TodoRep: ITodoRepository,
+    UserRep: IUserRepository,
+{
+    fn new(
+        todo_repository: Arc<TodoRep>,
+        user_repository: Arc<UserRep>,
+        event_bus: Arc<EventBus>,
+    ) -> Self {
+        Self {
+            todo_repository,
+            user_repository,
+            event_bus,
+        }
+    }
+
+    async fn handle(&self, command: TodoClearCompletedCommand) -> Result<u64> {
+        let TodoClearCompletedCommand {
+            user_id: user_id_string,
+            requested_by,
+        } = command;
+        let user_id = parse_assignee_id(self.user_repository.as_ref(), user_id_string).await?;
+
+        // 自分の todo 以外を削除できるのは管理者だけ
+        if user_id != requested_by {
+            let requester = self
+                .user_repository
+                .find(&requested_by)
+                .await
+                .map_err(|e| TodoApplicationError::Unexpected(e.to_string()))?;
+            let is_admin = requester.is_some_and(|user| user.user_role == UserRole::Admin);
+            if !is_admin {
+                return Err(TodoApplicationError::PermissionDenied(user_id));
+            }
+        }
+
+        let deleted_ids = self
+            .todo_repository
+            .delete_all_completed_by_user(&user_id)
+            .await?;
+
+        // 1 件ずつ削除した場合と同じく、削除した todo ごとにイベントを発行する
+        let deleted_count = deleted_ids.len() as u64;
+        for todo_id in deleted_ids {
+            self.event_bus.publish(TodoDeleted::new(todo_id));
+        }
+        Ok(deleted_count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use crate::{
+        domain::{
+            models::{
+                todos::{todo::Todo, todo_text::TodoText},
+                users::{user::User, user_name::UserName},
+            },
+            value_object::ValueObject,
+        },
+        infra::repository_impl::in_memory::{
+            todos::in_memory_todo_repository::InMemoryTodoRepository,
+            users::in_memory_user_repository::InMemoryUserRepository,
+        },
+    };
+
+    use super::*;
+
+    // 完了済みの todo を 3 件、未完了の todo を 1 件担当するユーザーを用意する
+    async fn setup() -> Result<(
+        User,
+        Arc<InMemoryTodoRepository>,
+        Arc<InMemoryUserRepository>,
+    )> {
+        let todo_repository = Arc::new(InMemoryTodoRepository::new());
+        let user_repository = Arc::new(InMemoryUserRepository::new());
+
+        let user = User::new(UserName::new("tester-1".to_string())?)?;
+        user_repository.save(&user).await?;
+
+        // Put the data in advance
+        {
+            for (i, completed) in [true, true, true, false].into_iter().enumerate() {
+                let mut todo = Todo::new(TodoText::new(format!("todo-{}", i))?, vec![])?;
+                todo.assignee_id = Some(user.user_id().clone());
+                todo.completed = completed;
+                todo_repository.save(&todo).await?;
+            }
+            // 他のユーザーの完了済みの todo は削除しない
+            let mut others_todo = Todo::new(TodoText::new("others".to_string())?, vec![])?;
+            others_todo.completed = true;
+            todo_repository.save(&others_todo).await?;
+        }
+        Ok((user, todo_repository, user_repository))
+    }
+
+    #[tokio::test]
+    async fn should_delete_only_completed_todos_of_user() -> Result<()> {
+        let (user, todo_repository, user_repository) = setup().await?;
+        let event_bus = Arc::new(EventBus::new());
+        let mut receiver = event_bus.subscribe();
+
+        let todo_clear_completed_application_service = TodoClearCompletedApplicationService::new(
+            todo_repository.clone(),
+            user_repository,
+            event_bus,
+        );
+        let command = || TodoClearCompletedCommand {
+            user_id: user.user_id().to_string(),
+            requested_by: user.user_id().clone(),
+        };
+        let deleted_count = todo_clear_completed_application_service
+            .handle(command())
+            .await?;
+
+        assert_eq!(3, deleted_count);
+        // 削除した todo ごとにイベントが発行される
+        for _ in 0..3 {
+            assert_eq!("TodoDeleted", receiver.recv().await?.event_type());
+        }
+        assert!(receiver.try_recv().is_err());
+        assert_eq!(2, todo_repository.find_all().await?.len());
+        todo_repository.for_each_todo(|_, todo| {
+            assert!(!todo.completed || todo.assignee_id.is_none());
+        });
+
+        // 2 回目は削除するものがない
+        let deleted_count = todo_clear_completed_application_service
+            .handle(command())
+            .await?;
+        assert_eq!(0, deleted_count);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_allow_only_admin_to_clear_todos_of_another_user() -> Result<()> {
+        let (user, todo_repository, user_repository) = setup().await?;
+        let member = User::new(UserName::new("tester-2".to_string())?)?;
+        user_repository.save(&member).await?;
+        let mut admin = User::new(UserName::new("admin-1".to_string())?)?;
+        admin.user_role = UserRole::Admin;
+        user_repository.save(&admin).await?;
+
+        let todo_clear_completed_application_service = TodoClearCompletedApplicationService::new(
+            todo_repository.clone(),
+            user_repository,
+            Arc::new(EventBus::new()),
+        );
+        let command = |requester: &User| TodoClearCompletedCommand {
+            user_id: user.user_id().to_string(),
+            requested_by: requester.user_id().clone(),
+        };
+
+        let result = todo_clear_completed_application_service
+            .handle(command(&member))
+            .await;
+        assert_eq!(
+            Err(TodoApplicationError::PermissionDenied(
+                user.user_id().clone()
+            )),
+            result
+        );
+        assert_eq!(5, todo_repository.find_all().await?.len());
+
+        let deleted_count = todo_clear_completed_application_service
+            .handle(command(&admin))
+            .await?;
+        assert_eq!(3, deleted_count);
+        Ok(())
+    }
+}
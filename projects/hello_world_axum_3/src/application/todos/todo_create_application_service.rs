@@ -1,14 +1,28 @@
-use std::{collections::HashSet, sync::Arc};
+use std::sync::Arc;
 
 use axum::async_trait;
 
-use super::{todo_data::TodoData, Result};
+use super::{
+    audit_snapshot, parse_assignee_id, parse_due_date, parse_todo_text, record_audit,
+    todo_data::TodoData, too_many_labels, Result,
+};
 
 use crate::domain::{
+    events::{event_bus::EventBus, todo_events::TodoCreated},
     models::{
-        labels::{label::Label, label_id::LabelId, label_repository::ILabelRepository},
-        todos::{todo::Todo, todo_repository::ITodoRepository, todo_text::TodoText},
+        audit_logs::{audit_action::AuditAction, audit_log_repository::IAuditLogRepository},
+        labels::{
+            label::Label, label_id::LabelId, label_name::LabelName,
+            label_repository::ILabelRepository,
+        },
+        todos::{
+            todo::{Todo, MAX_LABELS_PER_TODO},
+            todo_note::TodoNote,
+            todo_repository::ITodoRepository,
+        },
+        users::{user_id::UserId, user_repository::IUserRepository},
     },
+    services::{label_service::LabelService, todo_link_service, todo_service::TodoService},
     value_object::ValueObject,
 };
 
@@ -16,8 +30,22 @@ use super::todo_application_error::TodoApplicationError;
 
 // trait of application service to create todo
 #[async_trait]
-pub trait ITodoCreateApplicationService<TodoRep: ITodoRepository, LabelRep: ILabelRepository> {
-    fn new(todo_repository: Arc<TodoRep>, label_repository: Arc<LabelRep>) -> Self;
+pub trait ITodoCreateApplicationService<
+    TodoRep: ITodoRepository,
+    LabelRep: ILabelRepository,
+    UserRep: IUserRepository,
+    AuditLogRep: IAuditLogRepository,
+>
+{
+    fn new(
+        todo_repository: Arc<TodoRep>,
+        label_repository: Arc<LabelRep>,
+        user_repository: Arc<UserRep>,
+        audit_log_repository: Arc<AuditLogRep>,
+        event_bus: Arc<EventBus>,
+    ) -> Self;
+    // 1 つの todo に付けられるラベルの数の上限を変える
+    fn with_max_labels(self, max_labels: usize) -> Self;
     async fn handle(&self, command: TodoCreateCommand) -> Result<TodoData>;
 }
 
@@ -25,37 +53,104 @@ pub trait ITodoCreateApplicationService<TodoRep: ITodoRepository, LabelRep: ILab
 pub struct TodoCreateCommand {
     pub todo_text: String,
     pub label_ids: Vec<String>,
+    // label_ids が空の場合のみ使われ、同じ名前のラベルがなければ作成する
+    pub label_names: Vec<String>,
+    pub note: Option<String>,
+    // "YYYY-MM-DD" 形式
+    pub due_date: Option<String>,
+    // 担当者のユーザー id
+    pub assignee_id: Option<String>,
+    // true の場合は、テキストの先頭の文字を大文字にしてから保存する
+    pub normalize_case: bool,
+    // 監査ログに操作したユーザーとして残す。認証していなければ None
+    pub requested_by: Option<UserId>,
 }
 
 // impl of application service to create todo
-pub struct TodoCreateApplicationService<TodoRep, LabelRep> {
+pub struct TodoCreateApplicationService<
+    TodoRep: ITodoRepository,
+    LabelRep: ILabelRepository,
+    UserRep: IUserRepository,
+    AuditLogRep: IAuditLogRepository,
+> {
     todo_repository: Arc<TodoRep>,
     label_repository: Arc<LabelRep>,
+    user_repository: Arc<UserRep>,
+    audit_log_repository: Arc<AuditLogRep>,
+    todo_service: TodoService<TodoRep>,
+    label_service: LabelService<LabelRep>,
+    event_bus: Arc<EventBus>,
+    max_labels: usize,
 }
 
 #[async_trait]
-impl<TodoRep, LabelRep> ITodoCreateApplicationService<TodoRep, LabelRep>
-    for TodoCreateApplicationService<TodoRep, LabelRep>
+impl<TodoRep, LabelRep, UserRep, AuditLogRep>
+    ITodoCreateApplicationService<TodoRep, LabelRep, UserRep, AuditLogRep>
+    for TodoCreateApplicationService<TodoRep, LabelRep, UserRep, AuditLogRep>
 where
     TodoRep: ITodoRepository,
     LabelRep: ILabelRepository,
+    UserRep: IUserRepository,
+    AuditLogRep: IAuditLogRepository,
 {
-    fn new(todo_repository: Arc<TodoRep>, label_repository: Arc<LabelRep>) -> Self {
+    fn new(
+        todo_repository: Arc<TodoRep>,
+        label_repository: Arc<LabelRep>,
+        user_repository: Arc<UserRep>,
+        audit_log_repository: Arc<AuditLogRep>,
+        event_bus: Arc<EventBus>,
+    ) -> Self {
         Self {
             todo_repository: todo_repository.clone(),
             label_repository: label_repository.clone(),
+            user_repository,
+            audit_log_repository,
+            todo_service: TodoService::new(todo_repository),
+            label_service: LabelService::new(label_repository),
+            event_bus,
+            max_labels: MAX_LABELS_PER_TODO,
         }
     }
 
+    fn with_max_labels(mut self, max_labels: usize) -> Self {
+        self.max_labels = max_labels;
+        self
+    }
+
     async fn handle(&self, command: TodoCreateCommand) -> Result<TodoData> {
         let TodoCreateCommand {
             todo_text: todo_text_string,
             label_ids: label_id_strings,
+            label_names: label_name_strings,
+            note: note_string,
+            due_date: due_date_string,
+            assignee_id: assignee_id_string,
+            normalize_case,
+            requested_by,
         } = command;
-        let todo_text = TodoText::new(todo_text_string)
+        let todo_text = parse_todo_text(todo_text_string.clone(), normalize_case)
+            .map_err(|e| TodoApplicationError::IllegalArgumentError(e.to_string()))?;
+        // 空文字列は note なしとして扱う
+        let note = note_string
+            .filter(|note_string| !note_string.is_empty())
+            .map(TodoNote::new)
+            .transpose()
+            .map_err(|e| TodoApplicationError::IllegalArgumentError(e.to_string()))?;
+        let due_date = due_date_string
+            .as_deref()
+            .map(parse_due_date)
+            .transpose()
             .map_err(|e| TodoApplicationError::IllegalArgumentError(e.to_string()))?;
 
-        let mut labels = HashSet::<Label>::new();
+        let assignee_id = match assignee_id_string {
+            Some(assignee_id_string) => {
+                Some(parse_assignee_id(self.user_repository.as_ref(), assignee_id_string).await?)
+            }
+            None => None,
+        };
+
+        // label_ids の並び順がそのままラベルの表示順になる
+        let mut labels = Vec::<Label>::new();
 
         for label_id_string in label_id_strings {
             let label_id = LabelId::parse(label_id_string)
@@ -66,18 +161,86 @@ where
                 .await
                 .map_err(|e| TodoApplicationError::Unexpected(e.to_string()))?
                 .ok_or(TodoApplicationError::LabelNotFound(label_id))?;
-            labels.insert(label);
+            if !labels.contains(&label) {
+                labels.push(label);
+            }
+        }
+
+        if labels.is_empty() {
+            // 上限を超えて作成に失敗する todo のために、ラベルを作成しないよう先に数を確かめる
+            let mut label_names = Vec::<LabelName>::new();
+            for label_name_string in label_name_strings {
+                let label_name = label_name_string
+                    .parse::<LabelName>()
+                    .map_err(|e| TodoApplicationError::IllegalArgumentError(e.to_string()))?;
+                if !label_names.contains(&label_name) {
+                    label_names.push(label_name);
+                }
+            }
+            if label_names.len() > self.max_labels {
+                return Err(too_many_labels(self.max_labels));
+            }
+            for label_name in label_names {
+                let label = self
+                    .label_service
+                    .find_or_create(label_name)
+                    .await
+                    .map_err(|e| TodoApplicationError::Unexpected(e.to_string()))?;
+                if !labels.contains(&label) {
+                    labels.push(label);
+                }
+            }
+        }
+
+        if labels.len() > self.max_labels {
+            return Err(too_many_labels(self.max_labels));
         }
 
-        let new_todo = Todo::new(todo_text, labels)
+        let mut new_todo = Todo::new(todo_text, labels)
             .map_err(|e| TodoApplicationError::Unexpected(e.to_string()))?;
+        new_todo.note = note;
+        new_todo.due_date = due_date;
+        new_todo.assignee_id = assignee_id;
 
-        self.todo_repository
-            .save(&new_todo)
+        if self
+            .todo_service
+            .is_duplicated(&new_todo)
             .await
-            .map_err(|e| TodoApplicationError::Unexpected(e.to_string()))?;
+            .map_err(|e| TodoApplicationError::Unexpected(e.to_string()))?
+        {
+            return Err(TodoApplicationError::DuplicatedTodo(new_todo));
+        }
+
+        self.todo_repository.save(&new_todo).await?;
+
+        // テキストに含まれる URL は、リポジトリが todo と同じトランザクションで todo_links に保存する
+        if new_todo.todo_text.contains_url() {
+            tracing::info!(
+                "todo {} contains links: {:?}",
+                new_todo.todo_id(),
+                todo_link_service::extract_links(&new_todo.todo_text)
+                    .iter()
+                    .map(|link| link.as_str())
+                    .collect::<Vec<_>>()
+            );
+        }
 
-        Ok(TodoData::new(new_todo))
+        record_audit(
+            self.audit_log_repository.as_ref(),
+            new_todo.todo_id(),
+            requested_by,
+            AuditAction::Created,
+            serde_json::Value::Null,
+            audit_snapshot(&new_todo),
+        )
+        .await?;
+
+        self.event_bus.publish(TodoCreated::new(new_todo.clone()));
+
+        Ok(TodoData {
+            todo_text_raw: todo_text_string,
+            ..TodoData::new(new_todo)
+        })
     }
 }
 
@@ -85,11 +248,21 @@ where
 mod tests {
     use anyhow::Result;
 
+    use uuid::Uuid;
+
     use crate::{
-        domain::models::todos::todo_id::TodoId,
+        domain::models::{
+            todo_links::todo_link_repository::ITodoLinkRepository,
+            todos::todo_id::TodoId,
+            users::{user::User, user_id::UserId, user_name::UserName},
+        },
         infra::repository_impl::in_memory::{
+            audit_logs::in_memory_audit_log_repository::InMemoryAuditLogRepository,
             labels::in_memory_label_repository::InMemoryLabelRepository,
+            todo_dependencies::in_memory_todo_dependency_repository::InMemoryTodoDependencyRepository,
+            todo_links::in_memory_todo_link_repository::InMemoryTodoLinkRepository,
             todos::in_memory_todo_repository::InMemoryTodoRepository,
+            users::in_memory_user_repository::InMemoryUserRepository,
         },
     };
 
@@ -99,25 +272,153 @@ mod tests {
     async fn should_create_todo_with_min_length_text() -> Result<()> {
         let todo_repository = Arc::new(InMemoryTodoRepository::new());
         let label_repository = Arc::new(InMemoryLabelRepository::new());
-        let todo_create_application_service =
-            TodoCreateApplicationService::new(todo_repository.clone(), label_repository.clone());
+        let todo_create_application_service = TodoCreateApplicationService::new(
+            todo_repository.clone(),
+            label_repository.clone(),
+            Arc::new(InMemoryUserRepository::new()),
+            Arc::new(InMemoryAuditLogRepository::new()),
+            Arc::new(EventBus::new()),
+        );
 
         // Try to create todo with 1-length text
         let command = TodoCreateCommand {
             todo_text: "1".to_string(),
             label_ids: vec![],
+            label_names: vec![],
+            note: None,
+            due_date: None,
+            assignee_id: None,
+            normalize_case: false,
+            requested_by: None,
         };
         let todo_data = todo_create_application_service.handle(command).await?;
 
         assert_eq!("1", todo_data.todo_text);
-        assert_eq!(false, todo_data.completed);
+        assert!(!todo_data.completed);
 
         // get todo saved in store
-        let store = todo_repository.read_store_ref();
-        let stored_todo = store.get(&TodoId::new(todo_data.todo_id)?).unwrap();
+        let stored_todo = todo_repository
+            .find(&TodoId::new(todo_data.todo_id)?)
+            .await?
+            .unwrap();
 
         assert_eq!("1", stored_todo.todo_text.value());
-        assert_eq!(false, stored_todo.completed);
+        assert!(!stored_todo.completed);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_strip_html_tags_from_todo_text() -> Result<()> {
+        let todo_repository = Arc::new(InMemoryTodoRepository::new());
+        let todo_create_application_service = TodoCreateApplicationService::new(
+            todo_repository.clone(),
+            Arc::new(InMemoryLabelRepository::new()),
+            Arc::new(InMemoryUserRepository::new()),
+            Arc::new(InMemoryAuditLogRepository::new()),
+            Arc::new(EventBus::new()),
+        );
+
+        let command = TodoCreateCommand {
+            todo_text: "<b>Buy milk</b>".to_string(),
+            label_ids: vec![],
+            label_names: vec![],
+            note: None,
+            due_date: None,
+            assignee_id: None,
+            normalize_case: false,
+            requested_by: None,
+        };
+        let todo_data = todo_create_application_service.handle(command).await?;
+
+        assert_eq!("Buy milk", todo_data.todo_text);
+        assert_eq!("<b>Buy milk</b>", todo_data.todo_text_raw);
+        let stored_todo = todo_repository
+            .find(&TodoId::new(todo_data.todo_id)?)
+            .await?
+            .unwrap();
+        assert_eq!("Buy milk", stored_todo.todo_text.value());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_capitalize_first_char_if_normalize_case_is_requested() -> Result<()> {
+        let todo_repository = Arc::new(InMemoryTodoRepository::new());
+        let todo_create_application_service = TodoCreateApplicationService::new(
+            todo_repository.clone(),
+            Arc::new(InMemoryLabelRepository::new()),
+            Arc::new(InMemoryUserRepository::new()),
+            Arc::new(InMemoryAuditLogRepository::new()),
+            Arc::new(EventBus::new()),
+        );
+        let command = |todo_text: &str, normalize_case: bool| TodoCreateCommand {
+            todo_text: todo_text.to_string(),
+            label_ids: vec![],
+            label_names: vec![],
+            note: None,
+            due_date: None,
+            assignee_id: None,
+            normalize_case,
+            requested_by: None,
+        };
+
+        let todo_data = todo_create_application_service
+            .handle(command("buy milk", true))
+            .await?;
+        assert_eq!("Buy milk", todo_data.todo_text);
+        let stored_todo = todo_repository
+            .find(&TodoId::new(todo_data.todo_id)?)
+            .await?
+            .unwrap();
+        assert_eq!("Buy milk", stored_todo.todo_text.value());
+
+        // 先頭以外の文字はそのまま
+        let todo_data = todo_create_application_service
+            .handle(command("BUY MILK", true))
+            .await?;
+        assert_eq!("BUY MILK", todo_data.todo_text);
+
+        // 指定しなければテキストをそのまま保存する
+        let todo_data = todo_create_application_service
+            .handle(command("buy bread", false))
+            .await?;
+        assert_eq!("buy bread", todo_data.todo_text);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_save_links_in_todo_text() -> Result<()> {
+        let todo_link_repository = InMemoryTodoLinkRepository::new();
+        let todo_create_application_service = TodoCreateApplicationService::new(
+            Arc::new(InMemoryTodoRepository::with_related_repositories(
+                InMemoryTodoDependencyRepository::new(),
+                todo_link_repository.clone(),
+            )),
+            Arc::new(InMemoryLabelRepository::new()),
+            Arc::new(InMemoryUserRepository::new()),
+            Arc::new(InMemoryAuditLogRepository::new()),
+            Arc::new(EventBus::new()),
+        );
+
+        let command = TodoCreateCommand {
+            todo_text: "see http://example.com for details".to_string(),
+            label_ids: vec![],
+            label_names: vec![],
+            note: None,
+            due_date: None,
+            assignee_id: None,
+            normalize_case: false,
+            requested_by: None,
+        };
+        let todo_data = todo_create_application_service.handle(command).await?;
+
+        let links = todo_link_repository
+            .find_links(&TodoId::new(todo_data.todo_id)?)
+            .await?;
+        assert_eq!(
+            vec!["http://example.com/"],
+            links.iter().map(|link| link.as_str()).collect::<Vec<_>>()
+        );
+        assert_eq!(vec!["http://example.com/"], todo_data.links);
         Ok(())
     }
 
@@ -125,25 +426,37 @@ mod tests {
     async fn should_create_todo_with_max_length_text() -> Result<()> {
         let todo_repository = Arc::new(InMemoryTodoRepository::new());
         let label_repository = Arc::new(InMemoryLabelRepository::new());
-        let todo_create_application_service =
-            TodoCreateApplicationService::new(todo_repository.clone(), label_repository.clone());
+        let todo_create_application_service = TodoCreateApplicationService::new(
+            todo_repository.clone(),
+            label_repository.clone(),
+            Arc::new(InMemoryUserRepository::new()),
+            Arc::new(InMemoryAuditLogRepository::new()),
+            Arc::new(EventBus::new()),
+        );
 
         // Is it possible to enter a 99-letter text?
         let command = TodoCreateCommand {
             todo_text: "123456789-123456789-123456789-123456789-123456789-123456789-123456789-123456789-123456789-123456789".to_string(),
             label_ids: vec![],
+            label_names: vec![],
+            note: None,
+            due_date: None,
+            assignee_id: None,
+            normalize_case: false, requested_by: None,
         };
         let todo_data = todo_create_application_service.handle(command).await?;
 
         assert_eq!(todo_data.todo_text, "123456789-123456789-123456789-123456789-123456789-123456789-123456789-123456789-123456789-123456789");
-        assert_eq!(todo_data.completed, false);
+        assert!(!todo_data.completed);
 
         // get todo from store
-        let store = todo_repository.read_store_ref();
-        let stored_todo = store.get(&TodoId::new(todo_data.todo_id)?).unwrap();
+        let stored_todo = todo_repository
+            .find(&TodoId::new(todo_data.todo_id)?)
+            .await?
+            .unwrap();
 
         assert_eq!(stored_todo.todo_text.value(), "123456789-123456789-123456789-123456789-123456789-123456789-123456789-123456789-123456789-123456789");
-        assert_eq!(stored_todo.completed, false);
+        assert!(!stored_todo.completed);
         Ok(())
     }
 
@@ -151,13 +464,24 @@ mod tests {
     async fn should_throw_error_if_todo_text_is_empty() -> Result<()> {
         let todo_repository = Arc::new(InMemoryTodoRepository::new());
         let label_repository = Arc::new(InMemoryLabelRepository::new());
-        let todo_create_application_service =
-            TodoCreateApplicationService::new(todo_repository.clone(), label_repository.clone());
+        let todo_create_application_service = TodoCreateApplicationService::new(
+            todo_repository.clone(),
+            label_repository.clone(),
+            Arc::new(InMemoryUserRepository::new()),
+            Arc::new(InMemoryAuditLogRepository::new()),
+            Arc::new(EventBus::new()),
+        );
 
         // Is it possible to enter a 2-letter text?
         let command = TodoCreateCommand {
             todo_text: "".to_string(),
             label_ids: vec![],
+            label_names: vec![],
+            note: None,
+            due_date: None,
+            assignee_id: None,
+            normalize_case: false,
+            requested_by: None,
         };
         let todo_data = todo_create_application_service.handle(command).await;
 
@@ -175,13 +499,23 @@ mod tests {
     async fn should_throw_error_if_todo_text_is_too_long() -> Result<()> {
         let todo_repository = Arc::new(InMemoryTodoRepository::new());
         let label_repository = Arc::new(InMemoryLabelRepository::new());
-        let todo_create_application_service =
-            TodoCreateApplicationService::new(todo_repository.clone(), label_repository.clone());
+        let todo_create_application_service = TodoCreateApplicationService::new(
+            todo_repository.clone(),
+            label_repository.clone(),
+            Arc::new(InMemoryUserRepository::new()),
+            Arc::new(InMemoryAuditLogRepository::new()),
+            Arc::new(EventBus::new()),
+        );
 
         // Is it possible to enter a 20-letter text?
         let command = TodoCreateCommand {
             todo_text: "123456789-123456789-123456789-123456789-123456789-123456789-123456789-123456789-123456789-123456789-".to_string(),
             label_ids: vec![],
+            label_names: vec![],
+            note: None,
+            due_date: None,
+            assignee_id: None,
+            normalize_case: false, requested_by: None,
         };
         let todo_data = todo_create_application_service.handle(command).await;
 
@@ -194,4 +528,434 @@ mod tests {
         );
         Ok(())
     }
+
+    #[tokio::test]
+    async fn should_throw_error_if_todo_text_is_duplicated() -> Result<()> {
+        let todo_repository = Arc::new(InMemoryTodoRepository::new());
+        let label_repository = Arc::new(InMemoryLabelRepository::new());
+        let todo_create_application_service = TodoCreateApplicationService::new(
+            todo_repository.clone(),
+            label_repository.clone(),
+            Arc::new(InMemoryUserRepository::new()),
+            Arc::new(InMemoryAuditLogRepository::new()),
+            Arc::new(EventBus::new()),
+        );
+
+        let command = TodoCreateCommand {
+            todo_text: "todo text".to_string(),
+            label_ids: vec![],
+            label_names: vec![],
+            note: None,
+            due_date: None,
+            assignee_id: None,
+            normalize_case: false,
+            requested_by: None,
+        };
+        todo_create_application_service.handle(command).await?;
+
+        // Try to create todo with the same text
+        let command = TodoCreateCommand {
+            todo_text: "todo text".to_string(),
+            label_ids: vec![],
+            label_names: vec![],
+            note: None,
+            due_date: None,
+            assignee_id: None,
+            normalize_case: false,
+            requested_by: None,
+        };
+        let todo_data = todo_create_application_service.handle(command).await;
+
+        // 新しく作られた todo の id は分からないので、中身を取り出して比較する
+        let Err(TodoApplicationError::DuplicatedTodo(todo)) = todo_data else {
+            panic!("unexpected result: {:?}", todo_data);
+        };
+        assert_eq!("todo text", todo.todo_text.value());
+        assert_eq!(1, todo_repository.find_all().await?.len());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_create_todo_with_different_text() -> Result<()> {
+        let todo_repository = Arc::new(InMemoryTodoRepository::new());
+        let label_repository = Arc::new(InMemoryLabelRepository::new());
+        let todo_create_application_service = TodoCreateApplicationService::new(
+            todo_repository.clone(),
+            label_repository.clone(),
+            Arc::new(InMemoryUserRepository::new()),
+            Arc::new(InMemoryAuditLogRepository::new()),
+            Arc::new(EventBus::new()),
+        );
+
+        let command = TodoCreateCommand {
+            todo_text: "todo text".to_string(),
+            label_ids: vec![],
+            label_names: vec![],
+            note: None,
+            due_date: None,
+            assignee_id: None,
+            normalize_case: false,
+            requested_by: None,
+        };
+        todo_create_application_service.handle(command).await?;
+
+        // Try to create todo with a different text
+        let command = TodoCreateCommand {
+            todo_text: "another todo text".to_string(),
+            label_ids: vec![],
+            label_names: vec![],
+            note: None,
+            due_date: None,
+            assignee_id: None,
+            normalize_case: false,
+            requested_by: None,
+        };
+        let todo_data = todo_create_application_service.handle(command).await?;
+
+        assert_eq!("another todo text", todo_data.todo_text);
+        assert_eq!(2, todo_repository.find_all().await?.len());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_create_todo_with_note() -> Result<()> {
+        let todo_repository = Arc::new(InMemoryTodoRepository::new());
+        let label_repository = Arc::new(InMemoryLabelRepository::new());
+        let todo_create_application_service = TodoCreateApplicationService::new(
+            todo_repository.clone(),
+            label_repository.clone(),
+            Arc::new(InMemoryUserRepository::new()),
+            Arc::new(InMemoryAuditLogRepository::new()),
+            Arc::new(EventBus::new()),
+        );
+
+        let command = TodoCreateCommand {
+            todo_text: "todo text".to_string(),
+            label_ids: vec![],
+            label_names: vec![],
+            note: Some("# note\n- item".to_string()),
+            due_date: None,
+            assignee_id: None,
+            normalize_case: false,
+            requested_by: None,
+        };
+        let todo_data = todo_create_application_service.handle(command).await?;
+
+        assert_eq!(Some("# note\n- item".to_string()), todo_data.note);
+
+        // get todo saved in store
+        let stored_todo = todo_repository
+            .find(&TodoId::new(todo_data.todo_id)?)
+            .await?
+            .unwrap();
+
+        assert_eq!(
+            Some("# note\n- item"),
+            stored_todo.note.as_ref().map(|note| note.value().as_str())
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_throw_error_if_todo_note_is_too_long() -> Result<()> {
+        let todo_repository = Arc::new(InMemoryTodoRepository::new());
+        let label_repository = Arc::new(InMemoryLabelRepository::new());
+        let todo_create_application_service = TodoCreateApplicationService::new(
+            todo_repository.clone(),
+            label_repository.clone(),
+            Arc::new(InMemoryUserRepository::new()),
+            Arc::new(InMemoryAuditLogRepository::new()),
+            Arc::new(EventBus::new()),
+        );
+
+        let command = TodoCreateCommand {
+            todo_text: "todo text".to_string(),
+            label_ids: vec![],
+            label_names: vec![],
+            note: Some("a".repeat(5001)),
+            due_date: None,
+            assignee_id: None,
+            normalize_case: false,
+            requested_by: None,
+        };
+        let todo_data = todo_create_application_service.handle(command).await;
+
+        assert_eq!(
+            Err(TodoApplicationError::IllegalArgumentError(
+                "Todo note must be at most 5000 characters.".to_string()
+            )),
+            todo_data
+        );
+        assert!(todo_repository.find_all().await?.is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_publish_todo_created_event() -> Result<()> {
+        let todo_repository = Arc::new(InMemoryTodoRepository::new());
+        let label_repository = Arc::new(InMemoryLabelRepository::new());
+        let event_bus = Arc::new(EventBus::new());
+        let mut receiver = event_bus.subscribe();
+        let todo_create_application_service = TodoCreateApplicationService::new(
+            todo_repository.clone(),
+            label_repository.clone(),
+            Arc::new(InMemoryUserRepository::new()),
+            Arc::new(InMemoryAuditLogRepository::new()),
+            event_bus.clone(),
+        );
+
+        let command = TodoCreateCommand {
+            todo_text: "todo text".to_string(),
+            label_ids: vec![],
+            label_names: vec![],
+            note: None,
+            due_date: None,
+            assignee_id: None,
+            normalize_case: false,
+            requested_by: None,
+        };
+        let todo_data = todo_create_application_service.handle(command).await?;
+
+        let event = receiver.try_recv()?;
+        assert_eq!("TodoCreated", event.event_type());
+        assert_eq!(todo_data.todo_id.to_string(), event.to_json()["todo_id"]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_create_todo_with_due_date() -> Result<()> {
+        let todo_repository = Arc::new(InMemoryTodoRepository::new());
+        let label_repository = Arc::new(InMemoryLabelRepository::new());
+        let todo_create_application_service = TodoCreateApplicationService::new(
+            todo_repository.clone(),
+            label_repository.clone(),
+            Arc::new(InMemoryUserRepository::new()),
+            Arc::new(InMemoryAuditLogRepository::new()),
+            Arc::new(EventBus::new()),
+        );
+
+        // 1. Valid due date
+        let command = TodoCreateCommand {
+            todo_text: "todo text".to_string(),
+            label_ids: vec![],
+            label_names: vec![],
+            note: None,
+            due_date: Some("2023-10-31".to_string()),
+            assignee_id: None,
+            normalize_case: false,
+            requested_by: None,
+        };
+        let todo_data = todo_create_application_service.handle(command).await?;
+
+        assert_eq!(
+            chrono::NaiveDate::from_ymd_opt(2023, 10, 31),
+            todo_data.due_date
+        );
+
+        // 2. Invalid due date
+        let command = TodoCreateCommand {
+            todo_text: "another todo text".to_string(),
+            label_ids: vec![],
+            label_names: vec![],
+            note: None,
+            due_date: Some("2023/10/31".to_string()),
+            assignee_id: None,
+            normalize_case: false,
+            requested_by: None,
+        };
+        let result = todo_create_application_service.handle(command).await;
+
+        assert_eq!(
+            Err(TodoApplicationError::IllegalArgumentError(
+                "Due date must be in YYYY-MM-DD format: [2023/10/31]".to_string()
+            )),
+            result
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_attach_labels_by_name_creating_missing_ones() -> Result<()> {
+        let todo_repository = Arc::new(InMemoryTodoRepository::new());
+        let label_repository = Arc::new(InMemoryLabelRepository::new());
+
+        let existing_label = Label::new(LabelName::new("label-1".to_string())?)?;
+
+        // Put the data in advance
+        label_repository.save(&existing_label).await?;
+
+        let todo_create_application_service = TodoCreateApplicationService::new(
+            todo_repository.clone(),
+            label_repository.clone(),
+            Arc::new(InMemoryUserRepository::new()),
+            Arc::new(InMemoryAuditLogRepository::new()),
+            Arc::new(EventBus::new()),
+        );
+
+        let command = TodoCreateCommand {
+            todo_text: "todo text".to_string(),
+            label_ids: vec![],
+            label_names: vec!["label-1".to_string(), "label-2".to_string()],
+            note: None,
+            due_date: None,
+            assignee_id: None,
+            normalize_case: false,
+            requested_by: None,
+        };
+        let todo_data = todo_create_application_service.handle(command).await?;
+
+        // 既存のラベルは再利用され、存在しないラベルだけが作成される
+        let mut label_names: Vec<&str> = todo_data
+            .labels
+            .iter()
+            .map(|label| label.label_name.as_str())
+            .collect();
+        label_names.sort();
+        assert_eq!(vec!["label-1", "label-2"], label_names);
+        assert!(todo_data
+            .labels
+            .iter()
+            .any(|label| &label.label_id == existing_label.label_id().value()));
+        assert_eq!(2, label_repository.find_all().await?.len());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_create_todo_assigned_to_existing_user() -> Result<()> {
+        let todo_repository = Arc::new(InMemoryTodoRepository::new());
+        let label_repository = Arc::new(InMemoryLabelRepository::new());
+        let user_repository = Arc::new(InMemoryUserRepository::new());
+
+        let user = User::new(UserName::new("tester-1".to_string())?)?;
+        user_repository.save(&user).await?;
+
+        let todo_create_application_service = TodoCreateApplicationService::new(
+            todo_repository.clone(),
+            label_repository.clone(),
+            user_repository.clone(),
+            Arc::new(InMemoryAuditLogRepository::new()),
+            Arc::new(EventBus::new()),
+        );
+
+        // 1. Existing user
+        let command = TodoCreateCommand {
+            todo_text: "todo text".to_string(),
+            label_ids: vec![],
+            label_names: vec![],
+            note: None,
+            due_date: None,
+            assignee_id: Some(user.user_id().to_string()),
+            normalize_case: false,
+            requested_by: None,
+        };
+        let todo_data = todo_create_application_service.handle(command).await?;
+
+        assert_eq!(Some(*user.user_id().value()), todo_data.assignee_id);
+
+        // 2. Non-existent user
+        let user_id = UserId::new(Uuid::new_v4())?;
+        let command = TodoCreateCommand {
+            todo_text: "another todo text".to_string(),
+            label_ids: vec![],
+            label_names: vec![],
+            note: None,
+            due_date: None,
+            assignee_id: Some(user_id.to_string()),
+            normalize_case: false,
+            requested_by: None,
+        };
+        let result = todo_create_application_service.handle(command).await;
+
+        assert_eq!(Err(TodoApplicationError::AssigneeNotFound(user_id)), result);
+        assert_eq!(1, todo_repository.find_all().await?.len());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_throw_error_if_labels_exceed_max_labels() -> Result<()> {
+        let todo_repository = Arc::new(InMemoryTodoRepository::new());
+        let label_repository = Arc::new(InMemoryLabelRepository::new());
+
+        let labels = (0..=MAX_LABELS_PER_TODO)
+            .map(|i| Label::new(LabelName::new(format!("label-{}", i))?))
+            .collect::<anyhow::Result<Vec<Label>>>()?;
+        label_repository.save_all(&labels).await?;
+
+        let todo_create_application_service = TodoCreateApplicationService::new(
+            todo_repository.clone(),
+            label_repository.clone(),
+            Arc::new(InMemoryUserRepository::new()),
+            Arc::new(InMemoryAuditLogRepository::new()),
+            Arc::new(EventBus::new()),
+        );
+        let command_with_labels = |count: usize| TodoCreateCommand {
+            todo_text: format!("todo with {} labels", count),
+            label_ids: labels[..count]
+                .iter()
+                .map(|label| label.label_id().value().to_string())
+                .collect(),
+            label_names: vec![],
+            note: None,
+            due_date: None,
+            assignee_id: None,
+            normalize_case: false,
+            requested_by: None,
+        };
+
+        // 上限ちょうどまでは作成できる
+        for count in [0, MAX_LABELS_PER_TODO] {
+            let todo_data = todo_create_application_service
+                .handle(command_with_labels(count))
+                .await?;
+            assert_eq!(count, todo_data.labels.len());
+        }
+
+        let result = todo_create_application_service
+            .handle(command_with_labels(MAX_LABELS_PER_TODO + 1))
+            .await;
+        assert_eq!(
+            Err(TodoApplicationError::IllegalArgumentError(
+                "A todo can have at most 10 labels.".to_string()
+            )),
+            result.map(|_| ())
+        );
+        assert_eq!(2, todo_repository.find_all().await?.len());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_not_create_labels_by_name_if_they_exceed_max_labels() -> Result<()> {
+        let label_repository = Arc::new(InMemoryLabelRepository::new());
+        let todo_create_application_service = TodoCreateApplicationService::new(
+            Arc::new(InMemoryTodoRepository::new()),
+            label_repository.clone(),
+            Arc::new(InMemoryUserRepository::new()),
+            Arc::new(InMemoryAuditLogRepository::new()),
+            Arc::new(EventBus::new()),
+        )
+        .with_max_labels(2);
+
+        let command = TodoCreateCommand {
+            todo_text: "todo text".to_string(),
+            label_ids: vec![],
+            // 重複した名前は 1 つと数える
+            label_names: ["label-1", "label-2", "label-2", "label-3"]
+                .into_iter()
+                .map(String::from)
+                .collect(),
+            note: None,
+            due_date: None,
+            assignee_id: None,
+            normalize_case: false,
+            requested_by: None,
+        };
+        let result = todo_create_application_service.handle(command).await;
+
+        assert!(matches!(
+            result,
+            Err(TodoApplicationError::IllegalArgumentError(_))
+        ));
+        assert!(label_repository.find_all().await?.is_empty());
+        Ok(())
+    }
 }
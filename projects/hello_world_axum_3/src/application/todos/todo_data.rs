@@ -1,37 +1,314 @@
-use serde::Serialize;
+use std::hash::{Hash, Hasher};
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
 use uuid::Uuid;
 
 use crate::{
-    application::labels::label_data::LabelData,
-    domain::{models::todos::todo::Todo, value_object::ValueObject},
+    application::{labels::label_data::LabelData, rfc3339::Rfc3339},
+    domain::{
+        models::todos::{todo::Todo, todo_status::TodoStatus},
+        services::todo_link_service,
+        value_object::ValueObject,
+    },
 };
 
-#[derive(Serialize, PartialEq, Debug)]
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug)]
 pub struct TodoData {
     pub todo_id: Uuid,
     pub todo_text: String,
+    // HTML のタグを取り除く前の、リクエストで渡されたままのテキスト (監査用)
+    // 保存はしないため、作成・更新の結果以外では todo_text と同じになる
+    #[serde(default)]
+    pub todo_text_raw: String,
+    pub note: Option<String>,
+    pub due_date: Option<NaiveDate>,
+    pub assignee_id: Option<Uuid>,
     pub completed: bool,
+    // 完了状態と期限日から求めた状態 (`active`, `completed`, `overdue`)
+    #[serde(default)]
+    pub status: TodoStatus,
     pub labels: Vec<LabelData>,
+    // テキストに含まれる URL。todo_links テーブルにも同じものを todo と同じトランザクションで保存する
+    #[serde(default)]
+    pub links: Vec<String>,
+    // 入力画面で残りの文字数などを表示するための値。テキストから導出する
+    #[serde(default)]
+    pub word_count: usize,
+    #[serde(default)]
+    pub char_count: usize,
+    #[serde_as(as = "Rfc3339")]
+    pub created_at: DateTime<Utc>,
+    #[serde_as(as = "Rfc3339")]
+    pub updated_at: DateTime<Utc>,
+    // 更新時に送り返してもらい、その間に他から更新されていないかを確かめる
+    #[serde(default)]
+    pub version: i64,
 }
 
 impl TodoData {
     pub fn new(todo: Todo) -> Self {
+        Self::new_on(todo, Utc::now().date_naive())
+    }
+
+    // `today` の時点での状態を求める。テストなどで日付を固定したい場合に使う
+    pub fn new_on(todo: Todo, today: NaiveDate) -> Self {
+        let status = todo.status(today);
         let todo_id = todo.todo_id().clone().into_value();
+        let created_at = *todo.created_at();
+        let updated_at = *todo.updated_at();
+        let version = todo.version();
+        let links = todo_link_service::extract_links(&todo.todo_text)
+            .into_iter()
+            .map(String::from)
+            .collect();
+        let word_count = todo.todo_text.word_count();
+        let char_count = todo.todo_text.char_count();
         let Todo {
             todo_text,
+            note,
+            due_date,
+            assignee_id,
             completed,
             labels,
             ..
         } = todo;
-        let labels = labels
-            .into_iter()
-            .map(|label| LabelData::new(label))
-            .collect();
+        let labels = labels.into_iter().map(LabelData::new).collect();
+        let todo_text = todo_text.into_value();
         Self {
             todo_id,
-            todo_text: todo_text.into_value(),
+            todo_text_raw: todo_text.clone(),
+            todo_text,
+            note: note.map(|note| note.into_value()),
+            due_date,
+            assignee_id: assignee_id.map(|assignee_id| assignee_id.into_value()),
             completed,
+            status,
             labels,
+            links,
+            word_count,
+            char_count,
+            created_at,
+            updated_at,
+            version,
+        }
+    }
+}
+
+impl TodoData {
+    // README などに貼り付けられるよう、Markdown のタスクリストの 1 行として出力する
+    // 例: `- [x] text (labels: A, B) (due: 2024-01-15)`
+    pub fn to_markdown(&self) -> String {
+        let mut markdown = format!(
+            "- [{}] {}",
+            if self.completed { "x" } else { " " },
+            self.todo_text
+        );
+        if !self.labels.is_empty() {
+            let label_names: Vec<&str> = self
+                .labels
+                .iter()
+                .map(|label| label.label_name.as_str())
+                .collect();
+            markdown.push_str(&format!(" (labels: {})", label_names.join(", ")));
+        }
+        if let Some(due_date) = self.due_date {
+            markdown.push_str(&format!(" (due: {})", due_date.format("%Y-%m-%d")));
+        }
+        markdown
+    }
+}
+
+// todo の一覧を Markdown のタスクリストとして出力する
+pub trait ToMarkdownList {
+    fn to_markdown_list(&self) -> String;
+}
+
+impl ToMarkdownList for [TodoData] {
+    fn to_markdown_list(&self) -> String {
+        self.iter()
+            .map(TodoData::to_markdown)
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+// ドメインのエンティティと同様に、todo_id のみで同一性を判定する
+impl PartialEq for TodoData {
+    fn eq(&self, other: &Self) -> bool {
+        self.todo_id == other.todo_id
+    }
+}
+
+impl Eq for TodoData {}
+
+impl Hash for TodoData {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.todo_id.hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use anyhow::Result;
+    use chrono::TimeZone;
+
+    use super::*;
+    use crate::domain::models::todos::todo_text::TodoText;
+
+    fn todo_data_for_test() -> TodoData {
+        TodoData {
+            todo_id: Uuid::new_v4(),
+            todo_text: "test-1".to_string(),
+            todo_text_raw: "test-1".to_string(),
+            note: None,
+            due_date: None,
+            assignee_id: None,
+            completed: false,
+            status: TodoStatus::Active,
+            labels: vec![],
+            links: vec![],
+            word_count: 1,
+            char_count: 6,
+            created_at: Utc.with_ymd_and_hms(2024, 1, 15, 10, 30, 0).unwrap(),
+            updated_at: Utc.with_ymd_and_hms(2024, 1, 16, 8, 0, 0).unwrap(),
+            version: 1,
         }
     }
+
+    #[test]
+    fn should_serialize_timestamps_as_rfc3339_strings() -> Result<()> {
+        let todo_data = todo_data_for_test();
+
+        let json = serde_json::to_value(&todo_data)?;
+
+        assert_eq!("2024-01-15T10:30:00Z", json["created_at"]);
+        assert_eq!("2024-01-16T08:00:00Z", json["updated_at"]);
+        Ok(())
+    }
+
+    #[test]
+    fn should_keep_fractional_seconds_in_rfc3339_strings() -> Result<()> {
+        let mut todo_data = todo_data_for_test();
+        todo_data.created_at = Utc.timestamp_opt(1705314600, 123_456_000).unwrap();
+
+        let json = serde_json::to_value(&todo_data)?;
+
+        assert_eq!("2024-01-15T10:30:00.123456Z", json["created_at"]);
+        Ok(())
+    }
+
+    #[test]
+    fn should_deserialize_serialized_todo_data() -> Result<()> {
+        let todo_data = todo_data_for_test();
+
+        let json = serde_json::to_string(&todo_data)?;
+        let deserialized: TodoData = serde_json::from_str(&json)?;
+
+        // `PartialEq` は todo_id のみを比較するため、JSON 表現でも比較する
+        assert_eq!(todo_data, deserialized);
+        assert_eq!(
+            serde_json::to_value(&todo_data)?,
+            serde_json::to_value(&deserialized)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn should_convert_todos_to_markdown_list() {
+        let mut completed_with_labels = todo_data_for_test();
+        completed_with_labels.completed = true;
+        completed_with_labels.labels = ["A", "B"]
+            .into_iter()
+            .map(|label_name| LabelData {
+                label_id: Uuid::new_v4(),
+                label_name: label_name.to_string(),
+                todo_count: None,
+                attached_at: None,
+            })
+            .collect();
+        let mut with_due_date = todo_data_for_test();
+        with_due_date.todo_text = "test-2".to_string();
+        with_due_date.due_date = NaiveDate::from_ymd_opt(2024, 1, 15);
+        let mut plain = todo_data_for_test();
+        plain.todo_text = "test-3".to_string();
+
+        let todos_data = [completed_with_labels, with_due_date, plain];
+
+        assert_eq!(
+            "- [x] test-1 (labels: A, B)\n- [ ] test-2 (due: 2024-01-15)\n- [ ] test-3",
+            todos_data.to_markdown_list()
+        );
+    }
+
+    #[test]
+    fn should_populate_links_from_todo_text() -> Result<()> {
+        let todo = Todo::new(
+            TodoText::new("read https://example.com/docs".to_string())?,
+            vec![],
+        )?;
+
+        let todo_data = TodoData::new(todo);
+
+        assert_eq!(vec!["https://example.com/docs"], todo_data.links);
+        Ok(())
+    }
+
+    #[test]
+    fn should_deduplicate_todo_data_with_same_id_in_hash_set() {
+        let todo_data_1 = todo_data_for_test();
+        let mut todo_data_2 = todo_data_for_test();
+        todo_data_2.todo_id = todo_data_1.todo_id;
+        todo_data_2.todo_text = "test-2".to_string();
+
+        let set: HashSet<TodoData> = [todo_data_1, todo_data_2].into_iter().collect();
+
+        assert_eq!(1, set.len());
+    }
+
+    #[test]
+    fn should_deserialize_rfc3339_string_with_offset() -> Result<()> {
+        let json = format!(
+            r#"{{
+                "todo_id": "{}",
+                "todo_text": "test-1",
+                "completed": false,
+                "labels": [],
+                "created_at": "2024-01-15T19:30:00+09:00",
+                "updated_at": "2024-01-16T08:00:00Z"
+            }}"#,
+            Uuid::new_v4()
+        );
+
+        let todo_data: TodoData = serde_json::from_str(&json)?;
+
+        assert_eq!(
+            Utc.with_ymd_and_hms(2024, 1, 15, 10, 30, 0).unwrap(),
+            todo_data.created_at
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn should_fail_to_deserialize_non_rfc3339_string() {
+        let json = format!(
+            r#"{{
+                "todo_id": "{}",
+                "todo_text": "test-1",
+                "completed": false,
+                "labels": [],
+                "created_at": "2024/01/15 10:30:00",
+                "updated_at": "2024-01-16T08:00:00Z"
+            }}"#,
+            Uuid::new_v4()
+        );
+
+        let result = serde_json::from_str::<TodoData>(&json);
+
+        assert!(result.is_err());
+    }
 }
@@ -2,41 +2,67 @@ use std::sync::Arc;
 
 use axum::async_trait;
 
-use super::Result;
-
-use crate::domain::models::todos::{
-    todo_id::TodoId,
-    todo_repository::{ITodoRepository, TodoRepositoryError},
+use super::{audit_snapshot, record_audit, Result};
+
+use crate::domain::{
+    events::{event_bus::EventBus, todo_events::TodoDeleted},
+    models::{
+        audit_logs::{audit_action::AuditAction, audit_log_repository::IAuditLogRepository},
+        todos::{todo_id::TodoId, todo_repository::ITodoRepository},
+        users::user_id::UserId,
+    },
 };
 
 use super::todo_application_error::TodoApplicationError;
 
 // trait of application service to delete todo
 #[async_trait]
-pub trait ITodoDeleteApplicationService<T: ITodoRepository> {
-    fn new(todo_repository: Arc<T>) -> Self;
+pub trait ITodoDeleteApplicationService<T: ITodoRepository, AuditLogRep: IAuditLogRepository> {
+    fn new(
+        todo_repository: Arc<T>,
+        audit_log_repository: Arc<AuditLogRep>,
+        event_bus: Arc<EventBus>,
+    ) -> Self;
     async fn handle(&self, command: TodoDeleteCommand) -> Result<()>;
 }
 
 // command object
 pub struct TodoDeleteCommand {
     pub todo_id: String,
+    // 監査ログに操作したユーザーとして残す。認証していなければ None
+    pub requested_by: Option<UserId>,
 }
 
 // impl of application service to delete todo
-pub struct TodoDeleteApplicationService<T: ITodoRepository> {
+pub struct TodoDeleteApplicationService<T: ITodoRepository, AuditLogRep: IAuditLogRepository> {
     todo_repository: Arc<T>,
+    audit_log_repository: Arc<AuditLogRep>,
+    event_bus: Arc<EventBus>,
 }
 
 #[async_trait]
-impl<T: ITodoRepository> ITodoDeleteApplicationService<T> for TodoDeleteApplicationService<T> {
-    fn new(todo_repository: Arc<T>) -> Self {
-        Self { todo_repository }
+impl<T, AuditLogRep> ITodoDeleteApplicationService<T, AuditLogRep>
+    for TodoDeleteApplicationService<T, AuditLogRep>
+where
+    T: ITodoRepository,
+    AuditLogRep: IAuditLogRepository,
+{
+    fn new(
+        todo_repository: Arc<T>,
+        audit_log_repository: Arc<AuditLogRep>,
+        event_bus: Arc<EventBus>,
+    ) -> Self {
+        Self {
+            todo_repository,
+            audit_log_repository,
+            event_bus,
+        }
     }
 
     async fn handle(&self, command: TodoDeleteCommand) -> Result<()> {
         let TodoDeleteCommand {
             todo_id: todo_id_string,
+            requested_by,
         } = command;
         let todo_id = TodoId::parse(todo_id_string)
             .map_err(|e| TodoApplicationError::IllegalTodoId(e.to_string()))?;
@@ -44,27 +70,31 @@ impl<T: ITodoRepository> ITodoDeleteApplicationService<T> for TodoDeleteApplicat
         let todo = self
             .todo_repository
             .find(&todo_id)
-            .await
-            .map_err(|e| TodoApplicationError::Unexpected(e.to_string()))?
-            .ok_or(TodoApplicationError::TodoNotFound(todo_id))?;
-
-        self.todo_repository
-            .delete(todo)
-            .await
-            .map_err(|e| match e {
-                TodoRepositoryError::NotFound(todo_id) => {
-                    TodoApplicationError::TodoNotFound(todo_id)
-                }
-                TodoRepositoryError::Unexpected(e) => {
-                    TodoApplicationError::Unexpected(e.to_string())
-                }
-            })
+            .await?
+            .ok_or(TodoApplicationError::TodoNotFound(todo_id.clone()))?;
+        let before = audit_snapshot(&todo);
+
+        self.todo_repository.delete(todo).await?;
+
+        record_audit(
+            self.audit_log_repository.as_ref(),
+            &todo_id,
+            requested_by,
+            AuditAction::Deleted,
+            before,
+            serde_json::Value::Null,
+        )
+        .await?;
+
+        self.event_bus.publish(TodoDeleted::new(todo_id));
+
+        Ok(())
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use std::{sync::Arc, collections::HashSet};
+    use std::sync::Arc;
 
     use anyhow::Result;
     use uuid::Uuid;
@@ -75,34 +105,36 @@ mod tests {
             models::todos::{todo::Todo, todo_text::TodoText},
             value_object::ValueObject,
         },
-        infra::repository_impl::in_memory::todos::in_memory_todo_repository::InMemoryTodoRepository,
+        infra::repository_impl::in_memory::{
+            audit_logs::in_memory_audit_log_repository::InMemoryAuditLogRepository,
+            todos::in_memory_todo_repository::InMemoryTodoRepository,
+        },
     };
 
     #[tokio::test]
     async fn should_delete_todo() -> Result<()> {
         let repository = Arc::new(InMemoryTodoRepository::new());
 
-        let todo = Todo::new(TodoText::new("test-1".to_string())?, HashSet::new())?;
+        let todo = Todo::new(TodoText::new("test-1".to_string())?, vec![])?;
         let todo_id = todo.todo_id().clone();
 
         // Create todo in store
-        {
-            let mut store = repository.write_store_ref();
-            store.insert(todo_id.clone(), todo);
-        }
+        repository.save(&todo).await?;
 
         // Delete stored todo
-        let todo_delete_application_service = TodoDeleteApplicationService::new(repository.clone());
+        let todo_delete_application_service = TodoDeleteApplicationService::new(
+            repository.clone(),
+            Arc::new(InMemoryAuditLogRepository::new()),
+            Arc::new(EventBus::new()),
+        );
         let command = TodoDeleteCommand {
             todo_id: todo_id.value().to_string(),
+            requested_by: None,
         };
         todo_delete_application_service.handle(command).await?;
 
         // check if the store is empty
-        {
-            let store = repository.read_store_ref();
-            assert!(store.is_empty());
-        }
+        assert!(repository.find_all().await?.is_empty());
         Ok(())
     }
 
@@ -111,16 +143,25 @@ mod tests {
         let repository = Arc::new(InMemoryTodoRepository::new());
 
         // try to delete todo with illegal-formated todo-id
-        let todo_delete_application_service = TodoDeleteApplicationService::new(repository.clone());
+        let todo_delete_application_service = TodoDeleteApplicationService::new(
+            repository.clone(),
+            Arc::new(InMemoryAuditLogRepository::new()),
+            Arc::new(EventBus::new()),
+        );
         let command = TodoDeleteCommand {
             todo_id: "incorrect-todo-id".to_string(),
+            requested_by: None,
         };
         let result_of_todo_delete = todo_delete_application_service.handle(command).await;
 
-        assert!(matches!(
+        assert_eq!(
             result_of_todo_delete,
-            Err(TodoApplicationError::IllegalTodoId(_))
-        ));
+            Err(TodoApplicationError::IllegalTodoId(
+                TodoId::parse("incorrect-todo-id".to_string())
+                    .unwrap_err()
+                    .to_string()
+            ))
+        );
 
         Ok(())
     }
@@ -130,16 +171,22 @@ mod tests {
         let repository = Arc::new(InMemoryTodoRepository::new());
 
         // try to delete todo which does not exist
-        let todo_delete_application_service = TodoDeleteApplicationService::new(repository.clone());
+        let todo_delete_application_service = TodoDeleteApplicationService::new(
+            repository.clone(),
+            Arc::new(InMemoryAuditLogRepository::new()),
+            Arc::new(EventBus::new()),
+        );
+        let todo_id = Uuid::new_v4();
         let command = TodoDeleteCommand {
-            todo_id: Uuid::new_v4().to_string(),
+            todo_id: todo_id.to_string(),
+            requested_by: None,
         };
         let result_of_todo_delete = todo_delete_application_service.handle(command).await;
 
-        assert!(matches!(
+        assert_eq!(
             result_of_todo_delete,
-            Err(TodoApplicationError::TodoNotFound(_))
-        ));
+            Err(TodoApplicationError::TodoNotFound(TodoId::new(todo_id)?))
+        );
 
         Ok(())
     }
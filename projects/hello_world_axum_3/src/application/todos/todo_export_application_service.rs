@@ -0,0 +1,155 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+
+use crate::domain::models::todos::todo_repository::ITodoRepository;
+
+use super::{
+    todo_application_error::TodoApplicationError,
+    todo_data::{ToMarkdownList, TodoData},
+    Result,
+};
+
+// trait of application service to export todos
+#[async_trait]
+pub trait ITodoExportApplicationService<T: ITodoRepository> {
+    fn new(todo_repository: Arc<T>) -> Self;
+    async fn handle(&self, command: TodoExportCommand) -> Result<TodoExportData>;
+}
+
+// command object
+pub struct TodoExportCommand {
+    // 指定されなければ NDJSON で出力する
+    pub format: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TodoExportFormat {
+    // 1 行に 1 件の TodoData を JSON で出力する
+    Ndjson,
+    // Markdown のタスクリストとして出力する
+    Markdown,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct TodoExportData {
+    pub format: TodoExportFormat,
+    pub body: String,
+}
+
+// impl of application service to export todos
+pub struct TodoExportApplicationService<T: ITodoRepository> {
+    todo_repository: Arc<T>,
+}
+
+#[async_trait]
+impl<T: ITodoRepository> ITodoExportApplicationService<T> for TodoExportApplicationService<T> {
+    fn new(todo_repository: Arc<T>) -> Self {
+        Self { todo_repository }
+    }
+
+    async fn handle(&self, command: TodoExportCommand) -> Result<TodoExportData> {
+        // 不正な形式の場合は、全件を読み出す前にエラーにする
+        let format = match command.format.as_deref() {
+            None | Some("ndjson") => TodoExportFormat::Ndjson,
+            Some("markdown") => TodoExportFormat::Markdown,
+            Some(format) => {
+                return Err(TodoApplicationError::IllegalArgumentError(format!(
+                    "format must be ndjson or markdown: [{}]",
+                    format
+                )))
+            }
+        };
+
+        let todos_data: Vec<TodoData> = self
+            .todo_repository
+            .find_all()
+            .await?
+            .into_iter()
+            .map(TodoData::new)
+            .collect();
+
+        let body = match format {
+            TodoExportFormat::Ndjson => {
+                let mut body = String::new();
+                for todo_data in &todos_data {
+                    let line = serde_json::to_string(todo_data)
+                        .map_err(|e| TodoApplicationError::Unexpected(e.to_string()))?;
+                    body.push_str(&line);
+                    body.push('\n');
+                }
+                body
+            }
+            TodoExportFormat::Markdown => todos_data.to_markdown_list(),
+        };
+        Ok(TodoExportData { format, body })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use crate::{
+        domain::{
+            models::todos::{todo::Todo, todo_text::TodoText},
+            value_object::ValueObject,
+        },
+        infra::repository_impl::in_memory::todos::in_memory_todo_repository::InMemoryTodoRepository,
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn should_export_todos_in_given_format() -> Result<()> {
+        let todo_repository = Arc::new(InMemoryTodoRepository::new());
+        let mut todo = Todo::new(TodoText::new("test-1".to_string())?, vec![])?;
+        todo.completed = true;
+        todo_repository.save(&todo).await?;
+
+        let todo_export_application_service = TodoExportApplicationService::new(todo_repository);
+
+        let markdown = todo_export_application_service
+            .handle(TodoExportCommand {
+                format: Some("markdown".to_string()),
+            })
+            .await?;
+        assert_eq!(
+            TodoExportData {
+                format: TodoExportFormat::Markdown,
+                body: "- [x] test-1".to_string(),
+            },
+            markdown
+        );
+
+        let ndjson = todo_export_application_service
+            .handle(TodoExportCommand { format: None })
+            .await?;
+        assert_eq!(TodoExportFormat::Ndjson, ndjson.format);
+        let lines: Vec<&str> = ndjson.body.lines().collect();
+        assert_eq!(1, lines.len());
+        let todo_data: TodoData = serde_json::from_str(lines[0])?;
+        assert_eq!(TodoData::new(todo), todo_data);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_fail_for_unknown_format() -> Result<()> {
+        let todo_repository = Arc::new(InMemoryTodoRepository::new());
+        let todo_export_application_service = TodoExportApplicationService::new(todo_repository);
+
+        let result = todo_export_application_service
+            .handle(TodoExportCommand {
+                format: Some("csv".to_string()),
+            })
+            .await;
+
+        assert_eq!(
+            Err(TodoApplicationError::IllegalArgumentError(
+                "format must be ndjson or markdown: [csv]".to_string()
+            )),
+            result
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,171 @@
+use std::{sync::Arc, time::Duration};
+
+use axum::async_trait;
+use chrono::Utc;
+
+use crate::domain::models::{
+    labels::label_repository::ILabelRepository, todos::todo_repository::ITodoRepository,
+    users::user_repository::IUserRepository,
+};
+
+use super::{
+    todo_application_error::TodoApplicationError,
+    todo_get_all_aplication_service::DEFAULT_QUERY_TIMEOUT,
+    todo_list_view_data::{TodoListViewData, TodoViewFactory},
+    Result,
+};
+
+// trait of application service to get todos with a label specified by slug
+#[async_trait]
+pub trait ITodoFilterByLabelApplicationService<
+    TodoRep: ITodoRepository,
+    LabelRep: ILabelRepository,
+    UserRep: IUserRepository,
+>
+{
+    fn new(
+        todo_repository: Arc<TodoRep>,
+        label_repository: Arc<LabelRep>,
+        user_repository: Arc<UserRep>,
+    ) -> Self;
+    // `handle` でリポジトリからの読み出しを待つ時間の上限を変える
+    fn with_query_timeout(self, query_timeout: Duration) -> Self;
+    async fn handle(&self, command: TodoFilterByLabelCommand) -> Result<Vec<TodoListViewData>>;
+}
+
+// command object
+pub struct TodoFilterByLabelCommand {
+    pub label_slug: String,
+}
+
+// impl of application service to get todos with a label specified by slug
+pub struct TodoFilterByLabelApplicationService<
+    TodoRep: ITodoRepository,
+    LabelRep: ILabelRepository,
+    UserRep: IUserRepository,
+> {
+    todo_repository: Arc<TodoRep>,
+    label_repository: Arc<LabelRep>,
+    user_repository: Arc<UserRep>,
+    query_timeout: Duration,
+}
+
+#[async_trait]
+impl<TodoRep, LabelRep, UserRep> ITodoFilterByLabelApplicationService<TodoRep, LabelRep, UserRep>
+    for TodoFilterByLabelApplicationService<TodoRep, LabelRep, UserRep>
+where
+    TodoRep: ITodoRepository,
+    LabelRep: ILabelRepository,
+    UserRep: IUserRepository,
+{
+    fn new(
+        todo_repository: Arc<TodoRep>,
+        label_repository: Arc<LabelRep>,
+        user_repository: Arc<UserRep>,
+    ) -> Self {
+        Self {
+            todo_repository,
+            label_repository,
+            user_repository,
+            query_timeout: DEFAULT_QUERY_TIMEOUT,
+        }
+    }
+
+    fn with_query_timeout(mut self, query_timeout: Duration) -> Self {
+        self.query_timeout = query_timeout;
+        self
+    }
+
+    async fn handle(&self, command: TodoFilterByLabelCommand) -> Result<Vec<TodoListViewData>> {
+        let TodoFilterByLabelCommand { label_slug } = command;
+
+        // 絞り込みなので、該当するラベルが無い場合はエラーではなく空の一覧を返す
+        let label_found = self.label_repository.find_by_slug(&label_slug);
+        let Some(label) = tokio::time::timeout(self.query_timeout, label_found)
+            .await
+            .map_err(|_| TodoApplicationError::Timeout)?
+            .map_err(|e| TodoApplicationError::Unexpected(e.to_string()))?
+        else {
+            return Ok(vec![]);
+        };
+
+        let todo_view_factory = TodoViewFactory::load(self.user_repository.as_ref()).await?;
+        let today = Utc::now().date_naive();
+        let todos_found = tokio::time::timeout(self.query_timeout, self.todo_repository.find_all())
+            .await
+            .map_err(|_| TodoApplicationError::Timeout)??;
+        Ok(todos_found
+            .iter()
+            .filter(|todo| todo.labels.contains(&label))
+            .map(|todo| todo_view_factory.to_list_view(todo, today))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use crate::{
+        application::todos::todo_data::TodoData,
+        domain::{
+            models::{
+                labels::{label::Label, label_name::LabelName},
+                todos::{todo::Todo, todo_text::TodoText},
+            },
+            value_object::ValueObject,
+        },
+        infra::repository_impl::in_memory::{
+            labels::in_memory_label_repository::InMemoryLabelRepository,
+            todos::in_memory_todo_repository::InMemoryTodoRepository,
+            users::in_memory_user_repository::InMemoryUserRepository,
+        },
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn should_get_only_todos_with_label_of_given_slug() -> Result<()> {
+        let todo_repository = Arc::new(InMemoryTodoRepository::new());
+        let label_repository = Arc::new(InMemoryLabelRepository::new());
+
+        let label = Label::new(LabelName::new("Work In Progress".to_string())?)?;
+        let labeled_todo = Todo::new(TodoText::new("test-1".to_string())?, vec![label.clone()])?;
+        let unlabeled_todo = Todo::new(TodoText::new("test-2".to_string())?, vec![])?;
+
+        // Put the data in advance
+        label_repository.save(&label).await?;
+        for todo in [&labeled_todo, &unlabeled_todo] {
+            todo_repository.save(todo).await?;
+        }
+
+        let todo_filter_by_label_application_service = TodoFilterByLabelApplicationService::new(
+            todo_repository,
+            label_repository,
+            Arc::new(InMemoryUserRepository::new()),
+        );
+
+        // 1. Existing slug
+        let todos_data = todo_filter_by_label_application_service
+            .handle(TodoFilterByLabelCommand {
+                label_slug: "work-in-progress".to_string(),
+            })
+            .await?;
+        assert_eq!(
+            vec![TodoData::new(labeled_todo)],
+            todos_data
+                .into_iter()
+                .map(|todo_view| todo_view.todo)
+                .collect::<Vec<TodoData>>()
+        );
+
+        // 2. Unknown slug
+        let todos_data = todo_filter_by_label_application_service
+            .handle(TodoFilterByLabelCommand {
+                label_slug: "done".to_string(),
+            })
+            .await?;
+        assert!(todos_data.is_empty());
+        Ok(())
+    }
+}
@@ -1,97 +1,369 @@
-use std::sync::Arc;
+use std::{pin::Pin, sync::Arc, time::Duration};
 
 use axum::async_trait;
+use tokio::sync::mpsc;
+use tokio_stream::{wrappers::ReceiverStream, Stream, StreamExt};
+use tokio_util::sync::CancellationToken;
 
-use crate::domain::models::todos::todo_repository::ITodoRepository;
+use crate::domain::{
+    clock::{Clock, SystemClock},
+    models::{
+        todos::{
+            label_filter::LabelFilter,
+            todo_repository::{ITodoRepository, TodoCollectionVersion},
+        },
+        users::user_repository::IUserRepository,
+    },
+};
 
-use super::{todo_application_error::TodoApplicationError, todo_data::TodoData, Result};
+use super::{
+    todo_application_error::TodoApplicationError,
+    todo_list_view_data::{TodoListViewData, TodoViewFactory},
+    Result,
+};
 
 // trait of application service to get todos
 #[async_trait]
-pub trait ITodoGetAllApplicationService<T: ITodoRepository> {
-    fn new(todo_repository: Arc<T>) -> Self;
-    async fn handle(&self, command: TodoGetAllCommand) -> Result<Vec<TodoData>>;
+pub trait ITodoGetAllApplicationService<TodoRep: ITodoRepository, UserRep: IUserRepository> {
+    fn new(todo_repository: Arc<TodoRep>, user_repository: Arc<UserRep>) -> Self;
+    // `handle` と `handle_streaming` でリポジトリからの読み出しを待つ時間の上限を変える
+    // `handle_streaming` では、最初の 1 件が届くまでの時間に上限を設ける
+    fn with_query_timeout(self, query_timeout: Duration) -> Self;
+    async fn handle(&self, command: TodoGetAllCommand) -> Result<Vec<TodoListViewData>>;
+    // todo 全体の版を返す。一覧を読み出す前に、前回から変わったかどうかを判定するために使う
+    async fn collection_version(&self) -> Result<TodoCollectionVersion>;
+    // `cancellation_token` がキャンセルされたら、リポジトリからの読み出しを打ち切ってストリームを終える
+    async fn handle_streaming(
+        &self,
+        command: TodoGetAllCommand,
+        cancellation_token: CancellationToken,
+    ) -> TodoListViewDataStream;
+}
+
+pub struct TodoGetAllCommand {
+    // 指定された場合は、条件に合うラベルが付いた todo のみを返す
+    pub label_filter: Option<LabelFilter>,
 }
 
-pub struct TodoGetAllCommand {}
+pub type TodoListViewDataStream = Pin<Box<dyn Stream<Item = Result<TodoListViewData>> + Send>>;
+
+// `handle` でリポジトリからの読み出しを待つ時間の上限の既定値
+// DB の statement_timeout では止まらない、接続の取得やネットワークでの待ちも打ち切るために使う
+pub const DEFAULT_QUERY_TIMEOUT: Duration = Duration::from_secs(10);
+
+// ストリームから読み出し済みで、まだ送り出していない todo の最大件数
+const STREAM_BUFFER_SIZE: usize = 64;
 
 // impl of application service to get todos
-pub struct TodoGetAllApplicationService<T: ITodoRepository> {
-    todo_repository: Arc<T>,
+pub struct TodoGetAllApplicationService<TodoRep: ITodoRepository, UserRep: IUserRepository> {
+    todo_repository: Arc<TodoRep>,
+    user_repository: Arc<UserRep>,
+    clock: Arc<dyn Clock>,
+    query_timeout: Duration,
+}
+
+impl<TodoRep, UserRep> TodoGetAllApplicationService<TodoRep, UserRep>
+where
+    TodoRep: ITodoRepository,
+    UserRep: IUserRepository,
+{
+    pub fn with_clock(
+        todo_repository: Arc<TodoRep>,
+        user_repository: Arc<UserRep>,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
+        Self {
+            todo_repository,
+            user_repository,
+            clock,
+            query_timeout: DEFAULT_QUERY_TIMEOUT,
+        }
+    }
 }
 
 #[async_trait]
-impl<T: ITodoRepository> ITodoGetAllApplicationService<T> for TodoGetAllApplicationService<T> {
-    fn new(todo_repository: Arc<T>) -> Self {
-        Self { todo_repository }
+impl<TodoRep, UserRep> ITodoGetAllApplicationService<TodoRep, UserRep>
+    for TodoGetAllApplicationService<TodoRep, UserRep>
+where
+    TodoRep: ITodoRepository,
+    UserRep: IUserRepository,
+{
+    fn new(todo_repository: Arc<TodoRep>, user_repository: Arc<UserRep>) -> Self {
+        Self::with_clock(todo_repository, user_repository, Arc::new(SystemClock))
+    }
+
+    fn with_query_timeout(mut self, query_timeout: Duration) -> Self {
+        self.query_timeout = query_timeout;
+        self
     }
 
-    async fn handle(&self, _: TodoGetAllCommand) -> Result<Vec<TodoData>> {
-        let todos_found = self
-            .todo_repository
-            .find_all()
+    async fn handle(&self, command: TodoGetAllCommand) -> Result<Vec<TodoListViewData>> {
+        let TodoGetAllCommand { label_filter } = command;
+        let todo_view_factory = TodoViewFactory::load(self.user_repository.as_ref()).await?;
+        let today = self.clock.today();
+        let todos_found = async {
+            match label_filter {
+                Some(label_filter) => {
+                    self.todo_repository
+                        .find_by_label_filter(&label_filter)
+                        .await
+                }
+                None => self.todo_repository.find_all().await,
+            }
+        };
+        let todos_found = tokio::time::timeout(self.query_timeout, todos_found)
             .await
-            .map_err(|e| TodoApplicationError::Unexpected(e.to_string()))?;
+            .map_err(|_| TodoApplicationError::Timeout)??;
         Ok(todos_found
-            .into_iter()
-            .map(|todo| TodoData::new(todo))
+            .iter()
+            .map(|todo| todo_view_factory.to_list_view(todo, today))
             .collect())
     }
+
+    async fn collection_version(&self) -> Result<TodoCollectionVersion> {
+        let collection_version = self.todo_repository.collection_version();
+        Ok(tokio::time::timeout(self.query_timeout, collection_version)
+            .await
+            .map_err(|_| TodoApplicationError::Timeout)??)
+    }
+
+    async fn handle_streaming(
+        &self,
+        command: TodoGetAllCommand,
+        cancellation_token: CancellationToken,
+    ) -> TodoListViewDataStream {
+        let TodoGetAllCommand { label_filter } = command;
+        let todo_view_factory = match TodoViewFactory::load(self.user_repository.as_ref()).await {
+            Ok(todo_view_factory) => todo_view_factory,
+            Err(e) => return Box::pin(tokio_stream::once(Err(e))),
+        };
+        let today = self.clock.today();
+        // リポジトリのストリームは `&self` を借用するため、
+        // 別タスクで読み出して channel 経由で渡すことで 'static なストリームにする
+        let (tx, rx) = mpsc::channel(STREAM_BUFFER_SIZE);
+        let todo_repository = self.todo_repository.clone();
+        let query_timeout = self.query_timeout;
+        tokio::spawn(async move {
+            let mut todos_found = todo_repository.find_all_stream(cancellation_token.clone());
+            // 最初の 1 件が届くまでの時間にだけ上限を設ける
+            // 以降は送り出しながら読み出すため、クライアントが受け取る速さにも左右される
+            let first_todo_found =
+                match tokio::time::timeout(query_timeout, todos_found.next()).await {
+                    Ok(first_todo_found) => first_todo_found,
+                    Err(_) => {
+                        cancellation_token.cancel();
+                        let _ = tx.send(Err(TodoApplicationError::Timeout)).await;
+                        return;
+                    }
+                };
+            let mut todos_found = tokio_stream::iter(first_todo_found).chain(todos_found);
+            while let Some(todo_found) = todos_found.next().await {
+                // ストリームでは読み出しながら絞り込む
+                if let (Ok(todo), Some(label_filter)) = (&todo_found, &label_filter) {
+                    if !label_filter.matches(todo) {
+                        continue;
+                    }
+                }
+                let todo_view = todo_found
+                    .map(|todo| todo_view_factory.to_list_view(&todo, today))
+                    .map_err(TodoApplicationError::from);
+                // 受信側が切断されたら読み出しを打ち切る
+                if tx.send(todo_view).await.is_err() {
+                    cancellation_token.cancel();
+                    break;
+                }
+            }
+        });
+        Box::pin(ReceiverStream::new(rx))
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use std::collections::HashSet;
-
     use anyhow::Result;
+    use chrono::{Days, NaiveDate, TimeZone, Utc};
 
     use crate::{
+        application::todos::todo_data::TodoData,
         domain::{
-            models::todos::{todo::Todo, todo_text::TodoText},
+            clock::FixedClock,
+            models::{
+                labels::{label::Label, label_name::LabelName},
+                todos::{
+                    label_filter::FilterOperator,
+                    todo::Todo,
+                    todo_id::TodoId,
+                    todo_repository::{self, TodoStream},
+                    todo_text::TodoText,
+                },
+                users::user_id::UserId,
+            },
             value_object::ValueObject,
         },
-        infra::repository_impl::in_memory::todos::in_memory_todo_repository::InMemoryTodoRepository,
+        infra::repository_impl::in_memory::{
+            todos::in_memory_todo_repository::InMemoryTodoRepository,
+            users::in_memory_user_repository::InMemoryUserRepository,
+        },
     };
 
     use super::*;
 
+    // 一覧の読み出しに `delay` だけかかるリポジトリ
+    #[derive(Clone)]
+    struct SlowTodoRepository {
+        inner: InMemoryTodoRepository,
+        delay: Duration,
+    }
+
+    #[async_trait]
+    impl ITodoRepository for SlowTodoRepository {
+        async fn save(&self, todo: &Todo) -> todo_repository::Result<()> {
+            self.inner.save(todo).await
+        }
+
+        async fn find(&self, todo_id: &TodoId) -> todo_repository::Result<Option<Todo>> {
+            self.inner.find(todo_id).await
+        }
+
+        async fn find_by_text_exact(
+            &self,
+            todo_text: &TodoText,
+        ) -> todo_repository::Result<Option<Todo>> {
+            self.inner.find_by_text_exact(todo_text).await
+        }
+
+        async fn find_all(&self) -> todo_repository::Result<Vec<Todo>> {
+            tokio::time::sleep(self.delay).await;
+            self.inner.find_all().await
+        }
+
+        async fn find_many_by_ids(
+            &self,
+            todo_ids: &[TodoId],
+        ) -> todo_repository::Result<Vec<Todo>> {
+            self.inner.find_many_by_ids(todo_ids).await
+        }
+
+        async fn find_by_label_filter(
+            &self,
+            label_filter: &LabelFilter,
+        ) -> todo_repository::Result<Vec<Todo>> {
+            tokio::time::sleep(self.delay).await;
+            self.inner.find_by_label_filter(label_filter).await
+        }
+
+        async fn find_by_assignee(
+            &self,
+            assignee_id: &UserId,
+        ) -> todo_repository::Result<Vec<Todo>> {
+            self.inner.find_by_assignee(assignee_id).await
+        }
+
+        async fn count_by_user_and_completion(
+            &self,
+            user_id: &UserId,
+        ) -> todo_repository::Result<(u64, u64)> {
+            self.inner.count_by_user_and_completion(user_id).await
+        }
+
+        async fn collection_version(
+            &self,
+        ) -> todo_repository::Result<todo_repository::TodoCollectionVersion> {
+            tokio::time::sleep(self.delay).await;
+            self.inner.collection_version().await
+        }
+
+        async fn find_recently_completed_dates_by_user(
+            &self,
+            user_id: &UserId,
+            limit: u32,
+        ) -> todo_repository::Result<Vec<NaiveDate>> {
+            self.inner
+                .find_recently_completed_dates_by_user(user_id, limit)
+                .await
+        }
+
+        async fn find_due_within(&self, days: u32) -> todo_repository::Result<Vec<Todo>> {
+            self.inner.find_due_within(days).await
+        }
+
+        async fn find_similar_to(
+            &self,
+            todo_id: &TodoId,
+            threshold: f64,
+            limit: u32,
+        ) -> todo_repository::Result<Vec<(Todo, f64)>> {
+            self.inner.find_similar_to(todo_id, threshold, limit).await
+        }
+
+        fn find_all_stream(&self, cancellation_token: CancellationToken) -> TodoStream<'_> {
+            // 最初の 1 件を読み出す前に `delay` だけ待つ
+            let delay = futures_util::stream::once(tokio::time::sleep(self.delay));
+            Box::pin(
+                delay
+                    .filter_map(|()| None)
+                    .chain(self.inner.find_all_stream(cancellation_token)),
+            )
+        }
+
+        async fn delete(&self, todo: Todo) -> todo_repository::Result<()> {
+            self.inner.delete(todo).await
+        }
+
+        async fn delete_all_completed_by_user(
+            &self,
+            user_id: &UserId,
+        ) -> todo_repository::Result<Vec<TodoId>> {
+            self.inner.delete_all_completed_by_user(user_id).await
+        }
+
+        async fn set_completed_many(
+            &self,
+            todo_ids: &[TodoId],
+            completed: bool,
+        ) -> todo_repository::Result<u64> {
+            self.inner.set_completed_many(todo_ids, completed).await
+        }
+    }
+
+    fn todo_data_of(todo_views: Vec<TodoListViewData>) -> Vec<TodoData> {
+        todo_views
+            .into_iter()
+            .map(|todo_view| todo_view.todo)
+            .collect()
+    }
+
     #[tokio::test]
     async fn should_get_all_todos() -> Result<()> {
         let repository = Arc::new(InMemoryTodoRepository::new());
 
         // 1. Get all stored todo
-        let todo_get_all_application_service =
-            TodoGetAllApplicationService::new(repository.clone());
-        let command = TodoGetAllCommand {};
+        let todo_get_all_application_service = TodoGetAllApplicationService::new(
+            repository.clone(),
+            Arc::new(InMemoryUserRepository::new()),
+        );
+        let command = TodoGetAllCommand { label_filter: None };
         let todos = todo_get_all_application_service.handle(command).await?;
 
         assert!(todos.is_empty());
 
         // 2. Put the first data
-        let todo_1 = Todo::new(TodoText::new("test-1".to_string())?, HashSet::new())?;
-        let todo_id = todo_1.todo_id().clone();
-        {
-            let mut store = repository.write_store_ref();
-            store.insert(todo_id.clone(), todo_1.clone());
-        }
+        let todo_1 = Todo::new(TodoText::new("test-1".to_string())?, vec![])?;
+        repository.save(&todo_1).await?;
 
         // 3. Get all stored todo
-        let command = TodoGetAllCommand {};
-        let todos = todo_get_all_application_service.handle(command).await?;
+        let command = TodoGetAllCommand { label_filter: None };
+        let todos = todo_data_of(todo_get_all_application_service.handle(command).await?);
 
         assert_eq!(vec![TodoData::new(todo_1.clone())], todos);
 
         // 4. Put the second data
-        let todo_2 = Todo::new(TodoText::new("test-2".to_string())?, HashSet::new())?;
-        let todo_id = todo_2.todo_id().clone();
-        {
-            let mut store = repository.write_store_ref();
-            store.insert(todo_id.clone(), todo_2.clone());
-        }
+        let todo_2 = Todo::new(TodoText::new("test-2".to_string())?, vec![])?;
+        repository.save(&todo_2).await?;
 
         // 3. Get all stored todo
-        let command = TodoGetAllCommand {};
-        let mut todos = todo_get_all_application_service.handle(command).await?;
+        let command = TodoGetAllCommand { label_filter: None };
+        let mut todos = todo_data_of(todo_get_all_application_service.handle(command).await?);
 
         // Sort todos alphabetically
         todos.sort_by(|a, b| a.todo_text.cmp(&b.todo_text));
@@ -100,4 +372,241 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn should_stream_all_todos() -> Result<()> {
+        let repository = Arc::new(InMemoryTodoRepository::new());
+
+        let todo_1 = Todo::new(TodoText::new("test-1".to_string())?, vec![])?;
+        let todo_2 = Todo::new(TodoText::new("test-2".to_string())?, vec![])?;
+        repository.save(&todo_1).await?;
+        repository.save(&todo_2).await?;
+
+        let todo_get_all_application_service = TodoGetAllApplicationService::new(
+            repository.clone(),
+            Arc::new(InMemoryUserRepository::new()),
+        );
+        let todos = todo_get_all_application_service
+            .handle_streaming(
+                TodoGetAllCommand { label_filter: None },
+                CancellationToken::new(),
+            )
+            .await
+            .collect::<Result<Vec<TodoListViewData>, TodoApplicationError>>()
+            .await?;
+        let mut todos = todo_data_of(todos);
+
+        // Sort todos alphabetically
+        todos.sort_by(|a, b| a.todo_text.cmp(&b.todo_text));
+
+        assert_eq!(vec![TodoData::new(todo_1), TodoData::new(todo_2)], todos);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_end_stream_early_when_cancelled() -> Result<()> {
+        // 送り出し待ちの分を読み切っても、全件には届かない件数にする
+        const TODO_COUNT: usize = STREAM_BUFFER_SIZE * 3;
+
+        let repository = Arc::new(InMemoryTodoRepository::new());
+        for i in 0..TODO_COUNT {
+            let todo = Todo::new(TodoText::new(format!("todo-{}", i))?, vec![])?;
+            repository.save(&todo).await?;
+        }
+
+        let todo_get_all_application_service = TodoGetAllApplicationService::new(
+            repository.clone(),
+            Arc::new(InMemoryUserRepository::new()),
+        );
+        let cancellation_token = CancellationToken::new();
+        let mut todos = todo_get_all_application_service
+            .handle_streaming(
+                TodoGetAllCommand { label_filter: None },
+                cancellation_token.clone(),
+            )
+            .await;
+        assert!(todos.next().await.transpose()?.is_some());
+
+        cancellation_token.cancel();
+        let rest = todos
+            .collect::<Result<Vec<TodoListViewData>, TodoApplicationError>>()
+            .await?;
+        assert!(1 + rest.len() < TODO_COUNT);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_get_only_todos_matching_label_filter() -> Result<()> {
+        let repository = Arc::new(InMemoryTodoRepository::new());
+
+        let label_a = Label::new(LabelName::new("label-a".to_string())?)?;
+        let label_b = Label::new(LabelName::new("label-b".to_string())?)?;
+        let todo_1 = Todo::new(TodoText::new("todo-1".to_string())?, vec![label_a.clone()])?;
+        let todo_2 = Todo::new(
+            TodoText::new("todo-2".to_string())?,
+            vec![label_a.clone(), label_b.clone()],
+        )?;
+        let todo_3 = Todo::new(TodoText::new("todo-3".to_string())?, vec![])?;
+        for todo in [&todo_1, &todo_2, &todo_3] {
+            repository.save(todo).await?;
+        }
+
+        let todo_get_all_application_service = TodoGetAllApplicationService::new(
+            repository.clone(),
+            Arc::new(InMemoryUserRepository::new()),
+        );
+        let command_of = |operator| TodoGetAllCommand {
+            label_filter: Some(LabelFilter {
+                ids: vec![label_a.label_id().clone(), label_b.label_id().clone()],
+                operator,
+            }),
+        };
+
+        // A AND B
+        let todos = todo_data_of(
+            todo_get_all_application_service
+                .handle(command_of(FilterOperator::And))
+                .await?,
+        );
+        assert_eq!(vec![TodoData::new(todo_2.clone())], todos);
+
+        // ストリームでも同じく絞り込む
+        let todos = todo_get_all_application_service
+            .handle_streaming(command_of(FilterOperator::And), CancellationToken::new())
+            .await
+            .collect::<Result<Vec<TodoListViewData>, TodoApplicationError>>()
+            .await?;
+        assert_eq!(vec![TodoData::new(todo_2.clone())], todo_data_of(todos));
+
+        // A OR B
+        let mut todos = todo_data_of(
+            todo_get_all_application_service
+                .handle(command_of(FilterOperator::Or))
+                .await?,
+        );
+        todos.sort_by(|a, b| a.todo_text.cmp(&b.todo_text));
+        assert_eq!(vec![TodoData::new(todo_1), TodoData::new(todo_2)], todos);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_compute_status_with_injected_clock() -> Result<()> {
+        let repository = Arc::new(InMemoryTodoRepository::new());
+        let now = Utc.with_ymd_and_hms(2023, 10, 31, 12, 0, 0).unwrap();
+        let today = now.date_naive();
+
+        let mut overdue = Todo::new(TodoText::new("overdue".to_string())?, vec![])?;
+        overdue.due_date = today.checked_sub_days(Days::new(1));
+        let mut due_today = Todo::new(TodoText::new("due-today".to_string())?, vec![])?;
+        due_today.due_date = Some(today);
+        let mut completed = Todo::new(TodoText::new("completed".to_string())?, vec![])?;
+        completed.due_date = today.checked_sub_days(Days::new(1));
+        completed.completed = true;
+        for todo in [&overdue, &due_today, &completed] {
+            repository.save(todo).await?;
+        }
+
+        let todo_get_all_application_service = TodoGetAllApplicationService::with_clock(
+            repository.clone(),
+            Arc::new(InMemoryUserRepository::new()),
+            Arc::new(FixedClock(now)),
+        );
+        let mut todos = todo_data_of(
+            todo_get_all_application_service
+                .handle(TodoGetAllCommand { label_filter: None })
+                .await?,
+        );
+        todos.sort_by(|a, b| a.todo_text.cmp(&b.todo_text));
+
+        let statuses: Vec<(&str, &str)> = todos
+            .iter()
+            .map(|todo| (todo.todo_text.as_str(), todo.status.as_str()))
+            .collect();
+        assert_eq!(
+            vec![
+                ("completed", "completed"),
+                ("due-today", "active"),
+                ("overdue", "overdue"),
+            ],
+            statuses
+        );
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn should_time_out_if_repository_is_too_slow() -> Result<()> {
+        let repository = Arc::new(SlowTodoRepository {
+            inner: InMemoryTodoRepository::new(),
+            delay: Duration::from_secs(3),
+        });
+        repository
+            .save(&Todo::new(TodoText::new("todo".to_string())?, vec![])?)
+            .await?;
+
+        let todo_get_all_application_service = TodoGetAllApplicationService::new(
+            repository.clone(),
+            Arc::new(InMemoryUserRepository::new()),
+        )
+        .with_query_timeout(Duration::from_secs(1));
+        let result = todo_get_all_application_service
+            .handle(TodoGetAllCommand { label_filter: None })
+            .await;
+        assert!(matches!(result, Err(TodoApplicationError::Timeout)));
+
+        // 上限に収まれば読み出せる
+        let todo_get_all_application_service =
+            TodoGetAllApplicationService::new(repository, Arc::new(InMemoryUserRepository::new()))
+                .with_query_timeout(Duration::from_secs(5));
+        let todos = todo_get_all_application_service
+            .handle(TodoGetAllCommand { label_filter: None })
+            .await?;
+        assert_eq!(1, todos.len());
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn should_time_out_streaming_if_first_todo_is_too_slow() -> Result<()> {
+        let repository = Arc::new(SlowTodoRepository {
+            inner: InMemoryTodoRepository::new(),
+            delay: Duration::from_secs(3),
+        });
+        repository
+            .save(&Todo::new(TodoText::new("todo".to_string())?, vec![])?)
+            .await?;
+
+        let todo_get_all_application_service = TodoGetAllApplicationService::new(
+            repository.clone(),
+            Arc::new(InMemoryUserRepository::new()),
+        )
+        .with_query_timeout(Duration::from_secs(1));
+        let todo_views: Vec<_> = todo_get_all_application_service
+            .handle_streaming(
+                TodoGetAllCommand { label_filter: None },
+                CancellationToken::new(),
+            )
+            .await
+            .collect()
+            .await;
+        assert!(matches!(
+            todo_views[..],
+            [Err(TodoApplicationError::Timeout)]
+        ));
+
+        // 上限に収まれば読み出せる
+        let todo_get_all_application_service =
+            TodoGetAllApplicationService::new(repository, Arc::new(InMemoryUserRepository::new()))
+                .with_query_timeout(Duration::from_secs(5));
+        let todo_views: Vec<_> = todo_get_all_application_service
+            .handle_streaming(
+                TodoGetAllCommand { label_filter: None },
+                CancellationToken::new(),
+            )
+            .await
+            .collect()
+            .await;
+        assert_eq!(1, todo_views.len());
+        assert!(todo_views[0].is_ok());
+        Ok(())
+    }
 }
@@ -37,19 +37,14 @@ impl<T: ITodoRepository> ITodoGetApplicationService<T> for TodoGetApplicationSer
         let todo_found = self
             .todo_repository
             .find(&todo_id)
-            .await
-            .map_err(|e| TodoApplicationError::Unexpected(e.to_string()))?;
-        match todo_found {
-            Some(todo) => Ok(TodoData::new(todo)),
-            None => Err(TodoApplicationError::TodoNotFound(todo_id).into()),
-        }
+            .await?
+            .ok_or(TodoApplicationError::TodoNotFound(todo_id))?;
+        Ok(TodoData::new(todo_found))
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use std::collections::HashSet;
-
     use anyhow::Result;
     use uuid::Uuid;
 
@@ -67,14 +62,11 @@ mod tests {
     async fn should_get_todo() -> Result<()> {
         let repository = Arc::new(InMemoryTodoRepository::new());
 
-        let todo = Todo::new(TodoText::new("test-1".to_string())?, HashSet::new())?;
+        let todo = Todo::new(TodoText::new("test-1".to_string())?, vec![])?;
         let todo_id = todo.todo_id().clone();
 
         // Put the data in advance
-        {
-            let mut store = repository.write_store_ref();
-            store.insert(todo_id.clone(), todo.clone());
-        }
+        repository.save(&todo).await?;
 
         // Get stored todo
         let todo_get_application_service = TodoGetApplicationService::new(repository.clone());
@@ -93,15 +85,16 @@ mod tests {
 
         // try to get todo which does not exist
         let todo_get_application_service = TodoGetApplicationService::new(repository.clone());
+        let todo_id = Uuid::new_v4();
         let command = TodoGetCommand {
-            todo_id: Uuid::new_v4().to_string(),
+            todo_id: todo_id.to_string(),
         };
         let result_of_todo_delete = todo_get_application_service.handle(command).await;
 
-        assert!(matches!(
+        assert_eq!(
             result_of_todo_delete,
-            Err(TodoApplicationError::TodoNotFound(_))
-        ));
+            Err(TodoApplicationError::TodoNotFound(TodoId::new(todo_id)?))
+        );
 
         Ok(())
     }
@@ -117,10 +110,14 @@ mod tests {
         };
         let result_of_todo_delete = todo_get_application_service.handle(command).await;
 
-        assert!(matches!(
+        assert_eq!(
             result_of_todo_delete,
-            Err(TodoApplicationError::IllegalTodoId(_))
-        ));
+            Err(TodoApplicationError::IllegalTodoId(
+                TodoId::parse("illegal-formated-todo-id".to_string())
+                    .unwrap_err()
+                    .to_string()
+            ))
+        );
 
         Ok(())
     }
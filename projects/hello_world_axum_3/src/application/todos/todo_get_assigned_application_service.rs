@@ -0,0 +1,123 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+
+use crate::domain::models::{
+    todos::todo_repository::ITodoRepository, users::user_repository::IUserRepository,
+};
+
+use super::{parse_assignee_id, todo_data::TodoData, Result};
+
+// trait of application service to get todos assigned to a user
+#[async_trait]
+pub trait ITodoGetAssignedApplicationService<TodoRep: ITodoRepository, UserRep: IUserRepository> {
+    fn new(todo_repository: Arc<TodoRep>, user_repository: Arc<UserRep>) -> Self;
+    async fn handle(&self, command: TodoGetAssignedCommand) -> Result<Vec<TodoData>>;
+}
+
+// command object
+pub struct TodoGetAssignedCommand {
+    pub user_id: String,
+}
+
+// impl of application service to get todos assigned to a user
+pub struct TodoGetAssignedApplicationService<TodoRep: ITodoRepository, UserRep: IUserRepository> {
+    todo_repository: Arc<TodoRep>,
+    user_repository: Arc<UserRep>,
+}
+
+#[async_trait]
+impl<TodoRep, UserRep> ITodoGetAssignedApplicationService<TodoRep, UserRep>
+    for TodoGetAssignedApplicationService<TodoRep, UserRep>
+where
+    TodoRep: ITodoRepository,
+    UserRep: IUserRepository,
+{
+    fn new(todo_repository: Arc<TodoRep>, user_repository: Arc<UserRep>) -> Self {
+        Self {
+            todo_repository,
+            user_repository,
+        }
+    }
+
+    async fn handle(&self, command: TodoGetAssignedCommand) -> Result<Vec<TodoData>> {
+        let TodoGetAssignedCommand {
+            user_id: user_id_string,
+        } = command;
+        // 存在しないユーザーの場合は、空の一覧ではなくエラーを返す
+        let assignee_id = parse_assignee_id(self.user_repository.as_ref(), user_id_string).await?;
+
+        let todos_found = self.todo_repository.find_by_assignee(&assignee_id).await?;
+        Ok(todos_found.into_iter().map(TodoData::new).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use uuid::Uuid;
+
+    use crate::{
+        application::todos::todo_application_error::TodoApplicationError,
+        domain::{
+            models::{
+                todos::{todo::Todo, todo_text::TodoText},
+                users::{user::User, user_id::UserId, user_name::UserName},
+            },
+            value_object::ValueObject,
+        },
+        infra::repository_impl::in_memory::{
+            todos::in_memory_todo_repository::InMemoryTodoRepository,
+            users::in_memory_user_repository::InMemoryUserRepository,
+        },
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn should_get_only_todos_assigned_to_user() -> Result<()> {
+        let todo_repository = Arc::new(InMemoryTodoRepository::new());
+        let user_repository = Arc::new(InMemoryUserRepository::new());
+
+        let user = User::new(UserName::new("tester-1".to_string())?)?;
+        user_repository.save(&user).await?;
+
+        let mut assigned_todo = Todo::new(TodoText::new("test-1".to_string())?, vec![])?;
+        assigned_todo.assignee_id = Some(user.user_id().clone());
+        let unassigned_todo = Todo::new(TodoText::new("test-2".to_string())?, vec![])?;
+
+        // Put the data in advance
+        for todo in [&assigned_todo, &unassigned_todo] {
+            todo_repository.save(todo).await?;
+        }
+
+        let todo_get_assigned_application_service =
+            TodoGetAssignedApplicationService::new(todo_repository.clone(), user_repository);
+        let todos_data = todo_get_assigned_application_service
+            .handle(TodoGetAssignedCommand {
+                user_id: user.user_id().to_string(),
+            })
+            .await?;
+
+        assert_eq!(vec![TodoData::new(assigned_todo)], todos_data);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_fail_for_non_existent_user() -> Result<()> {
+        let todo_repository = Arc::new(InMemoryTodoRepository::new());
+        let user_repository = Arc::new(InMemoryUserRepository::new());
+        let user_id = UserId::new(Uuid::new_v4())?;
+
+        let todo_get_assigned_application_service =
+            TodoGetAssignedApplicationService::new(todo_repository, user_repository);
+        let result = todo_get_assigned_application_service
+            .handle(TodoGetAssignedCommand {
+                user_id: user_id.to_string(),
+            })
+            .await;
+
+        assert_eq!(Err(TodoApplicationError::AssigneeNotFound(user_id)), result);
+        Ok(())
+    }
+}
@@ -0,0 +1,175 @@
+use std::{collections::HashMap, sync::Arc};
+
+use axum::async_trait;
+
+use crate::{
+    application::audit::audit_entry_data::AuditEntryData,
+    domain::{
+        models::{
+            audit_logs::audit_log_repository::IAuditLogRepository,
+            todos::todo_id::TodoId,
+            users::{user_id::UserId, user_repository::IUserRepository},
+        },
+        value_object::ValueObject,
+    },
+};
+
+use super::{todo_application_error::TodoApplicationError, Result, AUDIT_RESOURCE_TYPE};
+
+// trait of application service to get the audit log of a todo
+#[async_trait]
+pub trait ITodoGetAuditApplicationService<
+    AuditLogRep: IAuditLogRepository,
+    UserRep: IUserRepository,
+>
+{
+    fn new(audit_log_repository: Arc<AuditLogRep>, user_repository: Arc<UserRep>) -> Self;
+    async fn handle(&self, command: TodoGetAuditCommand) -> Result<Vec<AuditEntryData>>;
+}
+
+// command object
+pub struct TodoGetAuditCommand {
+    pub todo_id: String,
+}
+
+// impl of application service to get the audit log of a todo
+pub struct TodoGetAuditApplicationService<
+    AuditLogRep: IAuditLogRepository,
+    UserRep: IUserRepository,
+> {
+    audit_log_repository: Arc<AuditLogRep>,
+    user_repository: Arc<UserRep>,
+}
+
+#[async_trait]
+impl<AuditLogRep, UserRep> ITodoGetAuditApplicationService<AuditLogRep, UserRep>
+    for TodoGetAuditApplicationService<AuditLogRep, UserRep>
+where
+    AuditLogRep: IAuditLogRepository,
+    UserRep: IUserRepository,
+{
+    fn new(audit_log_repository: Arc<AuditLogRep>, user_repository: Arc<UserRep>) -> Self {
+        Self {
+            audit_log_repository,
+            user_repository,
+        }
+    }
+
+    // 削除された todo の記録も返すため、todo が存在するかは確認しない
+    async fn handle(&self, command: TodoGetAuditCommand) -> Result<Vec<AuditEntryData>> {
+        let TodoGetAuditCommand {
+            todo_id: todo_id_string,
+        } = command;
+        let todo_id = TodoId::parse(todo_id_string)
+            .map_err(|e| TodoApplicationError::IllegalTodoId(e.to_string()))?;
+
+        let entries_found = self
+            .audit_log_repository
+            .find_by_resource(AUDIT_RESOURCE_TYPE, todo_id.value())
+            .await
+            .map_err(|e| TodoApplicationError::Unexpected(e.to_string()))?;
+
+        // 同じユーザーの操作が続くことが多いため、一度引いた名前は使い回す
+        let mut actor_names = HashMap::<UserId, Option<String>>::new();
+        let mut audit_entries_data = Vec::<AuditEntryData>::new();
+        for entry in entries_found {
+            let actor_name = match entry.actor_id() {
+                Some(actor_id) => match actor_names.get(actor_id) {
+                    Some(actor_name) => actor_name.clone(),
+                    None => {
+                        let actor_name = self
+                            .user_repository
+                            .find(actor_id)
+                            .await
+                            .map_err(|e| TodoApplicationError::Unexpected(e.to_string()))?
+                            .map(|user| user.user_name.value().to_string());
+                        actor_names.insert(actor_id.clone(), actor_name.clone());
+                        actor_name
+                    }
+                },
+                None => None,
+            };
+            audit_entries_data.push(AuditEntryData::new(entry, actor_name));
+        }
+        Ok(audit_entries_data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use serde_json::json;
+    use uuid::Uuid;
+
+    use crate::{
+        domain::models::{
+            audit_logs::{audit_action::AuditAction, audit_entry::AuditEntry},
+            users::{user::User, user_name::UserName},
+        },
+        infra::repository_impl::in_memory::{
+            audit_logs::in_memory_audit_log_repository::InMemoryAuditLogRepository,
+            users::in_memory_user_repository::InMemoryUserRepository,
+        },
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn should_get_entries_of_todo_with_actor_name_and_changes_summary() -> Result<()> {
+        let audit_log_repository = Arc::new(InMemoryAuditLogRepository::new());
+        let user_repository = Arc::new(InMemoryUserRepository::new());
+
+        let actor = User::new(UserName::new("tester-1".to_string())?)?;
+        user_repository.save(&actor).await?;
+
+        // Put the data in advance
+        let todo_id = Uuid::new_v4();
+        for (resource_id, actor_id, action, before, after) in [
+            (
+                todo_id,
+                Some(actor.user_id().clone()),
+                AuditAction::Created,
+                json!(null),
+                json!({ "text": "old" }),
+            ),
+            (
+                todo_id,
+                None,
+                AuditAction::Updated,
+                json!({ "text": "old" }),
+                json!({ "text": "new" }),
+            ),
+            // 他の todo の記録は含めない
+            (
+                Uuid::new_v4(),
+                None,
+                AuditAction::Deleted,
+                json!({ "text": "other" }),
+                json!(null),
+            ),
+        ] {
+            let entry = AuditEntry::new("todo", resource_id, actor_id, action, before, after)?;
+            audit_log_repository.save(&entry).await?;
+        }
+
+        let todo_get_audit_application_service =
+            TodoGetAuditApplicationService::new(audit_log_repository, user_repository);
+        let audit_entries_data = todo_get_audit_application_service
+            .handle(TodoGetAuditCommand {
+                todo_id: todo_id.to_string(),
+            })
+            .await?;
+
+        assert_eq!(2, audit_entries_data.len());
+        assert_eq!("Created", audit_entries_data[0].action);
+        assert_eq!(
+            Some("tester-1".to_string()),
+            audit_entries_data[0].actor_name
+        );
+        assert_eq!("text: null → 'old'", audit_entries_data[0].changes_summary);
+        assert_eq!("Updated", audit_entries_data[1].action);
+        assert_eq!(None, audit_entries_data[1].actor_name);
+        assert_eq!("text: 'old' → 'new'", audit_entries_data[1].changes_summary);
+        Ok(())
+    }
+}
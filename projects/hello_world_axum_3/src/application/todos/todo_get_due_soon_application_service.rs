@@ -0,0 +1,133 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+
+use crate::domain::models::todos::todo_repository::ITodoRepository;
+
+use super::{todo_application_error::TodoApplicationError, todo_data::TodoData, Result};
+
+// 何日先までを対象にできるか
+const MIN_DAYS: u32 = 1;
+const MAX_DAYS: u32 = 365;
+
+// trait of application service to get todos due soon
+#[async_trait]
+pub trait ITodoGetDueSoonApplicationService<T: ITodoRepository> {
+    fn new(todo_repository: Arc<T>) -> Self;
+    async fn handle(&self, command: TodoGetDueSoonCommand) -> Result<Vec<TodoData>>;
+}
+
+// command object
+pub struct TodoGetDueSoonCommand {
+    pub days: u32,
+}
+
+// impl of application service to get todos due soon
+pub struct TodoGetDueSoonApplicationService<T: ITodoRepository> {
+    todo_repository: Arc<T>,
+}
+
+#[async_trait]
+impl<T: ITodoRepository> ITodoGetDueSoonApplicationService<T>
+    for TodoGetDueSoonApplicationService<T>
+{
+    fn new(todo_repository: Arc<T>) -> Self {
+        Self { todo_repository }
+    }
+
+    async fn handle(&self, command: TodoGetDueSoonCommand) -> Result<Vec<TodoData>> {
+        let TodoGetDueSoonCommand { days } = command;
+        if !(MIN_DAYS..=MAX_DAYS).contains(&days) {
+            return Err(TodoApplicationError::IllegalArgumentError(format!(
+                "days must be between {} and {}.",
+                MIN_DAYS, MAX_DAYS
+            )));
+        }
+
+        let todos_found = self.todo_repository.find_due_within(days).await?;
+        Ok(todos_found.into_iter().map(TodoData::new).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use chrono::{Duration, TimeZone, Utc};
+
+    use crate::{
+        domain::{
+            clock::FixedClock,
+            models::todos::{todo::Todo, todo_text::TodoText},
+            value_object::ValueObject,
+        },
+        infra::repository_impl::in_memory::todos::in_memory_todo_repository::InMemoryTodoRepository,
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn should_get_only_incomplete_todos_due_within_days() -> Result<()> {
+        let now = Utc.with_ymd_and_hms(2024, 1, 15, 10, 30, 0).unwrap();
+        let repository = Arc::new(InMemoryTodoRepository::with_clock(Arc::new(FixedClock(
+            now,
+        ))));
+
+        let due_in = |text: &str, days: i64| -> Result<Todo> {
+            let mut todo = Todo::new(TodoText::new(text.to_string())?, vec![])?;
+            todo.due_date = Some(now.date_naive() + Duration::days(days));
+            Ok(todo)
+        };
+        let due_in_3_days = due_in("due in 3 days", 3)?;
+        let due_in_8_days = due_in("due in 8 days", 8)?;
+        // 期限を過ぎたものは「もうすぐ」ではないので含めない
+        let overdue = due_in("overdue", -1)?;
+        let mut completed = due_in("completed", 3)?;
+        completed.completed = true;
+        let without_due_date = Todo::new(TodoText::new("no due date".to_string())?, vec![])?;
+
+        // Put the data in advance
+        for todo in [
+            &due_in_3_days,
+            &due_in_8_days,
+            &overdue,
+            &completed,
+            &without_due_date,
+        ] {
+            repository.save(todo).await?;
+        }
+
+        let todo_get_due_soon_application_service =
+            TodoGetDueSoonApplicationService::new(repository.clone());
+        let todos_data = todo_get_due_soon_application_service
+            .handle(TodoGetDueSoonCommand { days: 7 })
+            .await?;
+
+        assert_eq!(vec![TodoData::new(due_in_3_days)], todos_data);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_reject_days_out_of_range() -> Result<()> {
+        let todo_get_due_soon_application_service =
+            TodoGetDueSoonApplicationService::new(Arc::new(InMemoryTodoRepository::new()));
+
+        for days in [0, 366] {
+            let result = todo_get_due_soon_application_service
+                .handle(TodoGetDueSoonCommand { days })
+                .await;
+            assert_eq!(
+                Err(TodoApplicationError::IllegalArgumentError(
+                    "days must be between 1 and 365.".to_string()
+                )),
+                result
+            );
+        }
+        for days in [1, 365] {
+            assert!(todo_get_due_soon_application_service
+                .handle(TodoGetDueSoonCommand { days })
+                .await
+                .is_ok());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,168 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+
+use crate::domain::models::todos::{todo_id::TodoId, todo_repository::ITodoRepository};
+
+use super::{todo_application_error::TodoApplicationError, todo_data::TodoData, Result};
+
+// 一度に返せる件数の上限
+const MAX_LIMIT: u32 = 100;
+
+// trait of application service to get todos similar to a todo
+#[async_trait]
+pub trait ITodoGetSimilarApplicationService<T: ITodoRepository> {
+    fn new(todo_repository: Arc<T>) -> Self;
+    async fn handle(&self, command: TodoGetSimilarCommand) -> Result<Vec<SimilarTodoData>>;
+}
+
+// command object
+pub struct TodoGetSimilarCommand {
+    pub todo_id: String,
+    // 0.0 から 1.0 の範囲で、これ以上似ている todo だけを返す
+    pub threshold: f64,
+    pub limit: u32,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct SimilarTodoData {
+    pub todo: TodoData,
+    pub similarity_score: f64,
+}
+
+// impl of application service to get todos similar to a todo
+pub struct TodoGetSimilarApplicationService<T: ITodoRepository> {
+    todo_repository: Arc<T>,
+}
+
+#[async_trait]
+impl<T: ITodoRepository> ITodoGetSimilarApplicationService<T>
+    for TodoGetSimilarApplicationService<T>
+{
+    fn new(todo_repository: Arc<T>) -> Self {
+        Self { todo_repository }
+    }
+
+    async fn handle(&self, command: TodoGetSimilarCommand) -> Result<Vec<SimilarTodoData>> {
+        let TodoGetSimilarCommand {
+            todo_id: todo_id_string,
+            threshold,
+            limit,
+        } = command;
+        let todo_id = TodoId::parse(todo_id_string)
+            .map_err(|e| TodoApplicationError::IllegalTodoId(e.to_string()))?;
+        if !(0.0..=1.0).contains(&threshold) {
+            return Err(TodoApplicationError::IllegalArgumentError(
+                "threshold must be between 0 and 1.".to_string(),
+            ));
+        }
+        if !(1..=MAX_LIMIT).contains(&limit) {
+            return Err(TodoApplicationError::IllegalArgumentError(format!(
+                "limit must be between 1 and {}.",
+                MAX_LIMIT
+            )));
+        }
+
+        // 存在しない todo に似たものは無いので、空ではなく NotFound を返す
+        if self.todo_repository.find(&todo_id).await?.is_none() {
+            return Err(TodoApplicationError::TodoNotFound(todo_id));
+        }
+
+        let todos_found = self
+            .todo_repository
+            .find_similar_to(&todo_id, threshold, limit)
+            .await?;
+        Ok(todos_found
+            .into_iter()
+            .map(|(todo, similarity_score)| SimilarTodoData {
+                todo: TodoData::new(todo),
+                similarity_score,
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use uuid::Uuid;
+
+    use crate::{
+        domain::{
+            models::todos::{todo::Todo, todo_text::TodoText},
+            value_object::ValueObject,
+        },
+        infra::repository_impl::in_memory::todos::in_memory_todo_repository::InMemoryTodoRepository,
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn should_find_each_other_as_similar_todos() -> Result<()> {
+        let repository = Arc::new(InMemoryTodoRepository::new());
+        let groceries = Todo::new(TodoText::new("Buy groceries".to_string())?, vec![])?;
+        let grocery_items = Todo::new(TodoText::new("Buy grocery items".to_string())?, vec![])?;
+        let dog = Todo::new(TodoText::new("Walk the dog".to_string())?, vec![])?;
+        for todo in [&groceries, &grocery_items, &dog] {
+            repository.save(todo).await?;
+        }
+
+        let todo_get_similar_application_service =
+            TodoGetSimilarApplicationService::new(repository.clone());
+        for (todo, expected) in [(&groceries, &grocery_items), (&grocery_items, &groceries)] {
+            let similar_todos = todo_get_similar_application_service
+                .handle(TodoGetSimilarCommand {
+                    todo_id: todo.todo_id().to_string(),
+                    threshold: 0.6,
+                    limit: 5,
+                })
+                .await?;
+
+            assert_eq!(1, similar_todos.len());
+            assert_eq!(TodoData::new(expected.clone()), similar_todos[0].todo);
+            assert!(similar_todos[0].similarity_score >= 0.6);
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_reject_unknown_todo_and_out_of_range_arguments() -> Result<()> {
+        let todo_get_similar_application_service =
+            TodoGetSimilarApplicationService::new(Arc::new(InMemoryTodoRepository::new()));
+        let todo_id = TodoId::new(Uuid::new_v4())?;
+
+        let result = todo_get_similar_application_service
+            .handle(TodoGetSimilarCommand {
+                todo_id: todo_id.to_string(),
+                threshold: 0.7,
+                limit: 5,
+            })
+            .await;
+        assert_eq!(
+            Err(TodoApplicationError::TodoNotFound(todo_id.clone())),
+            result
+        );
+
+        for (threshold, limit, message) in [
+            (1.5, 5, "threshold must be between 0 and 1."),
+            (-0.1, 5, "threshold must be between 0 and 1."),
+            (0.7, 0, "limit must be between 1 and 100."),
+            (0.7, 101, "limit must be between 1 and 100."),
+        ] {
+            let result = todo_get_similar_application_service
+                .handle(TodoGetSimilarCommand {
+                    todo_id: todo_id.to_string(),
+                    threshold,
+                    limit,
+                })
+                .await;
+            assert_eq!(
+                Err(TodoApplicationError::IllegalArgumentError(
+                    message.to_string()
+                )),
+                result
+            );
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,178 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::domain::{
+    models::todos::{todo::Todo, todo_repository::ITodoRepository},
+    value_object::ValueObject,
+};
+
+use super::Result;
+
+// trait of application service to export todos as iCalendar
+#[async_trait]
+pub trait ITodoIcalExportApplicationService<T: ITodoRepository> {
+    fn new(todo_repository: Arc<T>) -> Self;
+    async fn handle(&self, command: TodoIcalExportCommand) -> Result<String>;
+}
+
+pub struct TodoIcalExportCommand {}
+
+// impl of application service to export todos as iCalendar
+pub struct TodoIcalExportApplicationService<T: ITodoRepository> {
+    todo_repository: Arc<T>,
+}
+
+#[async_trait]
+impl<T: ITodoRepository> ITodoIcalExportApplicationService<T>
+    for TodoIcalExportApplicationService<T>
+{
+    fn new(todo_repository: Arc<T>) -> Self {
+        Self { todo_repository }
+    }
+
+    async fn handle(&self, _: TodoIcalExportCommand) -> Result<String> {
+        let todos_found = self.todo_repository.find_all().await?;
+
+        // 未完了かつ期限日のある todo のみを VEVENT として出力する
+        let events: String = todos_found
+            .iter()
+            .filter(|todo| !todo.completed)
+            .filter_map(to_vevent)
+            .collect();
+
+        Ok(format!(
+            "BEGIN:VCALENDAR\r\nVERSION:2.0\r\nPRODID:-//hello_world_axum_3//todos//EN\r\n{}END:VCALENDAR\r\n",
+            events
+        ))
+    }
+}
+
+fn to_vevent(todo: &Todo) -> Option<String> {
+    let due_date = todo.due_date?;
+    let lines = [
+        "BEGIN:VEVENT".to_string(),
+        format!("UID:{}", todo.todo_id()),
+        format!("DTSTAMP:{}", format_date_time(todo.updated_at())),
+        format!("DTSTART;VALUE=DATE:{}", due_date.format("%Y%m%d")),
+        format!("SUMMARY:{}", escape_text(todo.todo_text.value())),
+        "STATUS:NEEDS-ACTION".to_string(),
+        "END:VEVENT".to_string(),
+    ];
+    Some(lines.iter().map(|line| fold_line(line)).collect())
+}
+
+fn format_date_time(date_time: &DateTime<Utc>) -> String {
+    date_time.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+// RFC 5545 の TEXT 型に合わせてエスケープする
+// 改行は CRLF・CR・LF のいずれも `\n` にする
+fn escape_text(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace("\r\n", "\\n")
+        .replace(['\r', '\n'], "\\n")
+}
+
+// RFC 5545 §3.1 に合わせて、1 行が改行を除いて 75 オクテットを超えないよう折り返し、CRLF で終える
+// 折り返した行は空白 1 つで始め、マルチバイト文字の途中では折り返さない
+fn fold_line(line: &str) -> String {
+    const MAX_LINE_OCTETS: usize = 75;
+
+    let mut folded = String::with_capacity(line.len() + 3);
+    let mut line_octets = 0;
+    for c in line.chars() {
+        if line_octets + c.len_utf8() > MAX_LINE_OCTETS {
+            folded.push_str("\r\n ");
+            line_octets = 1;
+        }
+        folded.push(c);
+        line_octets += c.len_utf8();
+    }
+    folded.push_str("\r\n");
+    folded
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use chrono::NaiveDate;
+
+    use crate::{
+        domain::models::todos::todo_text::TodoText,
+        infra::repository_impl::in_memory::todos::in_memory_todo_repository::InMemoryTodoRepository,
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn should_export_only_incomplete_todos_with_due_date() -> Result<()> {
+        let repository = Arc::new(InMemoryTodoRepository::new());
+
+        let mut todo_1 = Todo::new(TodoText::new("test-1".to_string())?, vec![])?;
+        todo_1.due_date = NaiveDate::from_ymd_opt(2023, 10, 31);
+        let mut todo_2 = Todo::new(TodoText::new("test-2".to_string())?, vec![])?;
+        todo_2.due_date = NaiveDate::from_ymd_opt(2023, 11, 1);
+        let todo_3 = Todo::new(TodoText::new("test-3".to_string())?, vec![])?;
+        let mut todo_4 = Todo::new(TodoText::new("test-4".to_string())?, vec![])?;
+        todo_4.due_date = NaiveDate::from_ymd_opt(2023, 11, 2);
+        todo_4.completed = true;
+
+        // Put the data in advance
+        for todo in [&todo_1, &todo_2, &todo_3, &todo_4] {
+            repository.save(todo).await?;
+        }
+
+        let todo_ical_export_application_service =
+            TodoIcalExportApplicationService::new(repository.clone());
+        let ics = todo_ical_export_application_service
+            .handle(TodoIcalExportCommand {})
+            .await?;
+
+        assert!(ics.starts_with("BEGIN:VCALENDAR\r\n"));
+        assert!(ics.ends_with("END:VCALENDAR\r\n"));
+        assert_eq!(2, ics.matches("BEGIN:VEVENT").count());
+        assert!(ics.contains(&format!("UID:{}\r\n", todo_1.todo_id())));
+        assert!(ics.contains(&format!("UID:{}\r\n", todo_2.todo_id())));
+        assert!(ics.contains("DTSTART;VALUE=DATE:20231031\r\n"));
+        assert!(ics.contains("SUMMARY:test-1\r\n"));
+        assert_eq!(2, ics.matches("STATUS:NEEDS-ACTION\r\n").count());
+        Ok(())
+    }
+
+    #[test]
+    fn should_escape_special_characters_in_text() {
+        assert_eq!("a\\, b\\; c\\\\d\\ne", escape_text("a, b; c\\d\ne"));
+        // CRLF は 1 つの改行として、CR だけの改行もそのまま残さずにエスケープする
+        assert_eq!("a\\nb\\nc", escape_text("a\r\nb\rc"));
+    }
+
+    #[test]
+    fn should_fold_lines_longer_than_75_octets() {
+        let line = format!("SUMMARY:{}", "a".repeat(100));
+        let folded = fold_line(&line);
+
+        assert!(folded.ends_with("\r\n"));
+        let lines: Vec<&str> = folded.trim_end_matches("\r\n").split("\r\n").collect();
+        assert_eq!(2, lines.len());
+        assert_eq!(75, lines[0].len());
+        assert!(lines[1].starts_with(' '));
+        // 折り返しを戻すと元の行になる
+        assert_eq!(line, folded.trim_end_matches("\r\n").replace("\r\n ", ""));
+    }
+
+    #[test]
+    fn should_not_split_multibyte_characters_when_folding() {
+        // 「あ」は UTF-8 で 3 オクテット
+        let line = format!("SUMMARY:{}", "あ".repeat(40));
+        let folded = fold_line(&line);
+
+        for line in folded.trim_end_matches("\r\n").split("\r\n") {
+            assert!(line.len() <= 75);
+        }
+        assert_eq!(line, folded.trim_end_matches("\r\n").replace("\r\n ", ""));
+    }
+}
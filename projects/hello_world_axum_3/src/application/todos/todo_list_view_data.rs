@@ -0,0 +1,147 @@
+use std::collections::HashMap;
+
+use chrono::NaiveDate;
+use serde::Serialize;
+
+use crate::domain::{
+    models::{
+        todos::todo::Todo,
+        users::{user_id::UserId, user_repository::IUserRepository},
+    },
+    value_object::ValueObject,
+};
+
+use super::{todo_application_error::TodoApplicationError, todo_data::TodoData, Result};
+
+// 一覧表示用に、todo から導出できる値をあらかじめ計算しておいたもの
+#[derive(Serialize, Debug, PartialEq)]
+pub struct TodoListViewData {
+    pub todo: TodoData,
+    // 期限日が今日より前であれば true
+    pub is_overdue: bool,
+    pub label_names: Vec<String>,
+    pub assignee_name: Option<String>,
+    // 期限日までの日数。期限日を過ぎている場合は負の値になる
+    pub days_until_due: Option<i64>,
+}
+
+// todo を一覧表示用のデータに変換する
+// 担当者名を引くため、ユーザー名をあらかじめ読み込んでおく
+pub struct TodoViewFactory {
+    user_names: HashMap<UserId, String>,
+}
+
+impl TodoViewFactory {
+    pub fn new(user_names: HashMap<UserId, String>) -> Self {
+        Self { user_names }
+    }
+
+    pub async fn load<UserRep: IUserRepository>(user_repository: &UserRep) -> Result<Self> {
+        let user_names = user_repository
+            .find_all()
+            .await
+            .map_err(|e| TodoApplicationError::Unexpected(e.to_string()))?
+            .into_iter()
+            .map(|user| (user.user_id().clone(), user.user_name.into_value()))
+            .collect();
+        Ok(Self::new(user_names))
+    }
+
+    pub fn to_list_view(&self, todo: &Todo, now: NaiveDate) -> TodoListViewData {
+        let label_names = todo
+            .labels
+            .iter()
+            .map(|label| label.label_name.value().clone())
+            .collect();
+        // 担当者のユーザーが読み込んだ後に削除された場合は、担当者名なしとして扱う
+        let assignee_name = todo
+            .assignee_id
+            .as_ref()
+            .and_then(|assignee_id| self.user_names.get(assignee_id))
+            .cloned();
+        let days_until_due = todo.due_date.map(|due_date| (due_date - now).num_days());
+        TodoListViewData {
+            todo: TodoData::new_on(todo.clone(), now),
+            is_overdue: todo.due_date.is_some_and(|due_date| due_date < now),
+            label_names,
+            assignee_name,
+            days_until_due,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use chrono::Days;
+
+    use super::*;
+    use crate::domain::models::{
+        labels::{label::Label, label_name::LabelName},
+        todos::todo_text::TodoText,
+        users::{user::User, user_name::UserName},
+    };
+
+    fn todo_due_on(due_date: Option<NaiveDate>) -> Result<Todo> {
+        let mut todo = Todo::new(TodoText::new("test-1".to_string())?, vec![])?;
+        todo.due_date = due_date;
+        Ok(todo)
+    }
+
+    #[test]
+    fn should_be_overdue_if_due_yesterday() -> Result<()> {
+        let today = NaiveDate::from_ymd_opt(2023, 10, 31).unwrap();
+        let todo = todo_due_on(today.checked_sub_days(Days::new(1)))?;
+
+        let view = TodoViewFactory::new(HashMap::new()).to_list_view(&todo, today);
+
+        assert!(view.is_overdue);
+        assert_eq!(Some(-1), view.days_until_due);
+        Ok(())
+    }
+
+    #[test]
+    fn should_not_be_overdue_if_due_tomorrow() -> Result<()> {
+        let today = NaiveDate::from_ymd_opt(2023, 10, 31).unwrap();
+        let todo = todo_due_on(today.checked_add_days(Days::new(1)))?;
+
+        let view = TodoViewFactory::new(HashMap::new()).to_list_view(&todo, today);
+
+        assert!(!view.is_overdue);
+        assert_eq!(Some(1), view.days_until_due);
+        Ok(())
+    }
+
+    #[test]
+    fn should_not_be_overdue_without_due_date() -> Result<()> {
+        let today = NaiveDate::from_ymd_opt(2023, 10, 31).unwrap();
+        let todo = todo_due_on(None)?;
+
+        let view = TodoViewFactory::new(HashMap::new()).to_list_view(&todo, today);
+
+        assert!(!view.is_overdue);
+        assert_eq!(None, view.days_until_due);
+        Ok(())
+    }
+
+    #[test]
+    fn should_flatten_label_names_and_resolve_assignee_name() -> Result<()> {
+        let user = User::new(UserName::new("tester-1".to_string())?)?;
+        let mut todo = Todo::new(
+            TodoText::new("test-1".to_string())?,
+            vec![
+                Label::new(LabelName::new("label-b".to_string())?)?,
+                Label::new(LabelName::new("label-a".to_string())?)?,
+            ],
+        )?;
+        todo.assignee_id = Some(user.user_id().clone());
+
+        let user_names = HashMap::from([(user.user_id().clone(), "tester-1".to_string())]);
+        let view = TodoViewFactory::new(user_names)
+            .to_list_view(&todo, NaiveDate::from_ymd_opt(2023, 10, 31).unwrap());
+
+        assert_eq!(vec!["label-b", "label-a"], view.label_names);
+        assert_eq!(Some("tester-1".to_string()), view.assignee_name);
+        Ok(())
+    }
+}
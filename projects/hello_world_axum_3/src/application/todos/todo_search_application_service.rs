@@ -0,0 +1,121 @@
+use std::{sync::Arc, time::Duration};
+
+use axum::async_trait;
+use chrono::Utc;
+
+use crate::domain::{
+    models::{todos::todo_repository::ITodoRepository, users::user_repository::IUserRepository},
+    value_object::ValueObject,
+};
+
+use super::{
+    todo_application_error::TodoApplicationError,
+    todo_get_all_aplication_service::DEFAULT_QUERY_TIMEOUT,
+    todo_list_view_data::{TodoListViewData, TodoViewFactory},
+    Result,
+};
+
+// trait of application service to search todos by text
+#[async_trait]
+pub trait ITodoSearchApplicationService<TodoRep: ITodoRepository, UserRep: IUserRepository> {
+    fn new(todo_repository: Arc<TodoRep>, user_repository: Arc<UserRep>) -> Self;
+    // `handle` でリポジトリからの読み出しを待つ時間の上限を変える
+    fn with_query_timeout(self, query_timeout: Duration) -> Self;
+    async fn handle(&self, command: TodoSearchCommand) -> Result<Vec<TodoListViewData>>;
+}
+
+pub struct TodoSearchCommand {
+    pub query: String,
+}
+
+// impl of application service to search todos by text
+pub struct TodoSearchApplicationService<TodoRep: ITodoRepository, UserRep: IUserRepository> {
+    todo_repository: Arc<TodoRep>,
+    user_repository: Arc<UserRep>,
+    query_timeout: Duration,
+}
+
+#[async_trait]
+impl<TodoRep, UserRep> ITodoSearchApplicationService<TodoRep, UserRep>
+    for TodoSearchApplicationService<TodoRep, UserRep>
+where
+    TodoRep: ITodoRepository,
+    UserRep: IUserRepository,
+{
+    fn new(todo_repository: Arc<TodoRep>, user_repository: Arc<UserRep>) -> Self {
+        Self {
+            todo_repository,
+            user_repository,
+            query_timeout: DEFAULT_QUERY_TIMEOUT,
+        }
+    }
+
+    fn with_query_timeout(mut self, query_timeout: Duration) -> Self {
+        self.query_timeout = query_timeout;
+        self
+    }
+
+    async fn handle(&self, command: TodoSearchCommand) -> Result<Vec<TodoListViewData>> {
+        let TodoSearchCommand { query } = command;
+        // 大文字小文字を区別せず、text に query を含む todo を返す
+        let query = query.trim().to_lowercase();
+        let todo_view_factory = TodoViewFactory::load(self.user_repository.as_ref()).await?;
+        let today = Utc::now().date_naive();
+
+        let todos_found = tokio::time::timeout(self.query_timeout, self.todo_repository.find_all())
+            .await
+            .map_err(|_| TodoApplicationError::Timeout)??;
+        Ok(todos_found
+            .into_iter()
+            .filter(|todo| todo.todo_text.value().to_lowercase().contains(&query))
+            .map(|todo| todo_view_factory.to_list_view(&todo, today))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use crate::{
+        application::todos::todo_data::TodoData,
+        domain::models::todos::{todo::Todo, todo_text::TodoText},
+        infra::repository_impl::in_memory::{
+            todos::in_memory_todo_repository::InMemoryTodoRepository,
+            users::in_memory_user_repository::InMemoryUserRepository,
+        },
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn should_search_todos_by_partial_text_ignoring_case() -> Result<()> {
+        let repository = Arc::new(InMemoryTodoRepository::new());
+
+        let todo_1 = Todo::new(TodoText::new("Buy milk".to_string())?, vec![])?;
+        let todo_2 = Todo::new(TodoText::new("Write report".to_string())?, vec![])?;
+
+        // Put the data in advance
+        repository.save(&todo_1).await?;
+        repository.save(&todo_2).await?;
+
+        let todo_search_application_service = TodoSearchApplicationService::new(
+            repository.clone(),
+            Arc::new(InMemoryUserRepository::new()),
+        );
+        let todos = todo_search_application_service
+            .handle(TodoSearchCommand {
+                query: "MILK".to_string(),
+            })
+            .await?;
+
+        assert_eq!(
+            vec![TodoData::new(todo_1)],
+            todos
+                .into_iter()
+                .map(|todo_view| todo_view.todo)
+                .collect::<Vec<TodoData>>()
+        );
+        Ok(())
+    }
+}
@@ -1,13 +1,23 @@
-use std::{collections::HashSet, sync::Arc};
+use std::sync::Arc;
 
 use axum::async_trait;
 
-use super::{todo_data::TodoData, Result};
+use super::{
+    audit_snapshot, parse_assignee_id, parse_due_date, parse_todo_text, record_audit,
+    todo_data::TodoData, too_many_labels, Result,
+};
 
 use crate::domain::{
+    events::{event_bus::EventBus, todo_events::TodoUpdated},
     models::{
+        audit_logs::{audit_action::AuditAction, audit_log_repository::IAuditLogRepository},
         labels::{label::Label, label_id::LabelId, label_repository::ILabelRepository},
-        todos::{todo_id::TodoId, todo_repository::ITodoRepository, todo_text::TodoText},
+        todo_dependencies::todo_dependency_repository::ITodoDependencyRepository,
+        todos::{
+            todo::MAX_LABELS_PER_TODO, todo_id::TodoId, todo_note::TodoNote,
+            todo_repository::ITodoRepository,
+        },
+        users::{user_id::UserId, user_repository::IUserRepository},
     },
     value_object::ValueObject,
 };
@@ -16,8 +26,24 @@ use super::todo_application_error::TodoApplicationError;
 
 // trait of application service to update todo
 #[async_trait]
-pub trait ITodoUpdateApplicationService<TodoRep: ITodoRepository, LabelRep: ILabelRepository> {
-    fn new(todo_repository: Arc<TodoRep>, label_repository: Arc<LabelRep>) -> Self;
+pub trait ITodoUpdateApplicationService<
+    TodoRep: ITodoRepository,
+    LabelRep: ILabelRepository,
+    TodoDependencyRep: ITodoDependencyRepository,
+    UserRep: IUserRepository,
+    AuditLogRep: IAuditLogRepository,
+>
+{
+    fn new(
+        todo_repository: Arc<TodoRep>,
+        label_repository: Arc<LabelRep>,
+        todo_dependency_repository: Arc<TodoDependencyRep>,
+        user_repository: Arc<UserRep>,
+        audit_log_repository: Arc<AuditLogRep>,
+        event_bus: Arc<EventBus>,
+    ) -> Self;
+    // 1 つの todo に付けられるラベルの数の上限を変える
+    fn with_max_labels(self, max_labels: usize) -> Self;
     async fn handle(&self, command: TodoUpdateCommand) -> Result<TodoData>;
 }
 
@@ -27,32 +53,85 @@ pub struct TodoUpdateCommand {
     pub todo_text: Option<String>,
     pub completed: Option<bool>,
     pub label_ids: Option<Vec<String>>,
+    // `None` は変更なし、`Some(None)` は note の削除を表す
+    // 以前のクライアントのため、空文字列も削除として扱う
+    pub note: Option<Option<String>>,
+    // "YYYY-MM-DD" 形式。`None` は変更なし、`Some(None)` と空文字列は期限日の削除を表す
+    pub due_date: Option<Option<String>>,
+    // 担当者のユーザー id。`None` は変更なし、`Some(None)` と空文字列は担当者の解除を表す
+    pub assignee_id: Option<Option<String>>,
+    // 更新の元にした todo の版。`None` の場合は確認せずに上書きする
+    pub version: Option<i64>,
+    // true の場合は、テキストの先頭の文字を大文字にしてから保存する。テキストを変更しない場合は使われない
+    pub normalize_case: bool,
+    // 監査ログに操作したユーザーとして残す。認証していなければ None
+    pub requested_by: Option<UserId>,
 }
 
 // impl of application service to update todo
-pub struct TodoUpdateApplicationService<TodoRep: ITodoRepository, LabelRep: ILabelRepository> {
+pub struct TodoUpdateApplicationService<
+    TodoRep: ITodoRepository,
+    LabelRep: ILabelRepository,
+    TodoDependencyRep: ITodoDependencyRepository,
+    UserRep: IUserRepository,
+    AuditLogRep: IAuditLogRepository,
+> {
     todo_repository: Arc<TodoRep>,
     label_repository: Arc<LabelRep>,
+    todo_dependency_repository: Arc<TodoDependencyRep>,
+    user_repository: Arc<UserRep>,
+    audit_log_repository: Arc<AuditLogRep>,
+    event_bus: Arc<EventBus>,
+    max_labels: usize,
 }
 
 #[async_trait]
-impl<TodoRep: ITodoRepository, LabelRep: ILabelRepository>
-    ITodoUpdateApplicationService<TodoRep, LabelRep>
-    for TodoUpdateApplicationService<TodoRep, LabelRep>
+impl<TodoRep, LabelRep, TodoDependencyRep, UserRep, AuditLogRep>
+    ITodoUpdateApplicationService<TodoRep, LabelRep, TodoDependencyRep, UserRep, AuditLogRep>
+    for TodoUpdateApplicationService<TodoRep, LabelRep, TodoDependencyRep, UserRep, AuditLogRep>
+where
+    TodoRep: ITodoRepository,
+    LabelRep: ILabelRepository,
+    TodoDependencyRep: ITodoDependencyRepository,
+    UserRep: IUserRepository,
+    AuditLogRep: IAuditLogRepository,
 {
-    fn new(todo_repository: Arc<TodoRep>, label_repository: Arc<LabelRep>) -> Self {
+    fn new(
+        todo_repository: Arc<TodoRep>,
+        label_repository: Arc<LabelRep>,
+        todo_dependency_repository: Arc<TodoDependencyRep>,
+        user_repository: Arc<UserRep>,
+        audit_log_repository: Arc<AuditLogRep>,
+        event_bus: Arc<EventBus>,
+    ) -> Self {
         Self {
             todo_repository: todo_repository.clone(),
             label_repository: label_repository.clone(),
+            todo_dependency_repository: todo_dependency_repository.clone(),
+            user_repository,
+            audit_log_repository,
+            event_bus,
+            max_labels: MAX_LABELS_PER_TODO,
         }
     }
 
+    fn with_max_labels(mut self, max_labels: usize) -> Self {
+        self.max_labels = max_labels;
+        self
+    }
+
     async fn handle(&self, command: TodoUpdateCommand) -> Result<TodoData> {
         let TodoUpdateCommand {
             todo_id: todo_id_string,
             todo_text: todo_text_string,
             completed,
             label_ids: label_id_strings,
+            note: note_string,
+            due_date: due_date_string,
+            assignee_id: assignee_id_string,
+            version,
+            normalize_case,
+            requested_by,
         } = command;
 
         let todo_id = TodoId::parse(todo_id_string)
@@ -61,22 +140,77 @@ impl<TodoRep: ITodoRepository, LabelRep: ILabelRepository>
         let mut todo = self
             .todo_repository
             .find(&todo_id)
-            .await
-            .map_err(|e| TodoApplicationError::Unexpected(e.to_string()))?
+            .await?
             .ok_or(TodoApplicationError::TodoNotFound(todo_id))?;
 
-        if let Some(todo_text_string) = todo_text_string {
-            let todo_text = TodoText::new(todo_text_string)
+        // 読み出されてから他のリクエストで更新されていれば、その変更を上書きしないよう失敗させる
+        if version.is_some_and(|version| version != todo.version()) {
+            return Err(TodoApplicationError::StaleData(todo.todo_id().clone()));
+        }
+        let before = audit_snapshot(&todo);
+
+        if let Some(todo_text_string) = &todo_text_string {
+            todo.todo_text = parse_todo_text(todo_text_string.clone(), normalize_case)
+                .map_err(|e| TodoApplicationError::IllegalArgumentError(e.to_string()))?;
+        }
+
+        if let Some(note_string) = note_string {
+            todo.note = note_string
+                .filter(|note_string| !note_string.is_empty())
+                .map(TodoNote::new)
+                .transpose()
                 .map_err(|e| TodoApplicationError::IllegalArgumentError(e.to_string()))?;
-            todo.todo_text = todo_text;
+        }
+
+        if let Some(due_date_string) = due_date_string {
+            todo.due_date = due_date_string
+                .filter(|due_date_string| !due_date_string.is_empty())
+                .map(|due_date_string| parse_due_date(&due_date_string))
+                .transpose()
+                .map_err(|e| TodoApplicationError::IllegalArgumentError(e.to_string()))?;
+        }
+
+        if let Some(assignee_id_string) = assignee_id_string {
+            todo.assignee_id = match assignee_id_string
+                .filter(|assignee_id_string| !assignee_id_string.is_empty())
+            {
+                Some(assignee_id_string) => Some(
+                    parse_assignee_id(self.user_repository.as_ref(), assignee_id_string).await?,
+                ),
+                None => None,
+            };
         }
 
         if let Some(completed) = completed {
+            // 完了にする場合は、先に完了していなければならない todo がすべて完了しているかを確認する
+            if completed && !todo.completed {
+                let mut incomplete_todo_ids = Vec::<TodoId>::new();
+                let todo_dependencies = self
+                    .todo_dependency_repository
+                    .find_dependencies_of(todo.todo_id())
+                    .await
+                    .map_err(|e| TodoApplicationError::Unexpected(e.to_string()))?;
+                for todo_dependency in todo_dependencies {
+                    let from_todo_id = todo_dependency.from_todo_id();
+                    let from_todo = self
+                        .todo_repository
+                        .find(from_todo_id)
+                        .await?
+                        .ok_or(TodoApplicationError::TodoNotFound(from_todo_id.clone()))?;
+                    if !from_todo.completed {
+                        incomplete_todo_ids.push(from_todo_id.clone());
+                    }
+                }
+                if !incomplete_todo_ids.is_empty() {
+                    return Err(TodoApplicationError::DependencyNotMet(incomplete_todo_ids));
+                }
+            }
             todo.completed = completed;
         }
 
         if let Some(label_id_strings) = label_id_strings {
-            let mut labels = HashSet::<Label>::new();
+            // label_ids の並び順がそのままラベルの表示順になる
+            let mut labels = Vec::<Label>::new();
 
             for label_id_string in label_id_strings {
                 let label_id = LabelId::parse(label_id_string)
@@ -87,17 +221,39 @@ impl<TodoRep: ITodoRepository, LabelRep: ILabelRepository>
                     .await
                     .map_err(|e| TodoApplicationError::Unexpected(e.to_string()))?
                     .ok_or(TodoApplicationError::LabelNotFound(label_id))?;
-                labels.insert(label);
+                if !labels.contains(&label) {
+                    labels.push(label);
+                }
+            }
+            if labels.len() > self.max_labels {
+                return Err(too_many_labels(self.max_labels));
             }
             todo.labels = labels;
         }
 
-        self.todo_repository
-            .save(&todo)
-            .await
-            .map_err(|e| TodoApplicationError::Unexpected(e.to_string()))?;
+        todo.touch();
+
+        // テキストに含まれる URL も、リポジトリが todo と同じトランザクションで保存し直す
+        self.todo_repository.save(&todo).await?;
 
-        Ok(TodoData::new(todo))
+        record_audit(
+            self.audit_log_repository.as_ref(),
+            todo.todo_id(),
+            requested_by,
+            AuditAction::Updated,
+            before,
+            audit_snapshot(&todo),
+        )
+        .await?;
+
+        self.event_bus.publish(TodoUpdated::new(todo.clone()));
+
+        let todo_data = TodoData::new(todo);
+        Ok(TodoData {
+            // テキストを変更しなかった場合は、保存されているテキストのまま
+            todo_text_raw: todo_text_string.unwrap_or_else(|| todo_data.todo_text.clone()),
+            ..todo_data
+        })
     }
 }
 
@@ -107,50 +263,288 @@ mod tests {
     use uuid::Uuid;
 
     use crate::{
-        domain::models::todos::todo::Todo,
+        domain::models::{
+            labels::label_name::LabelName,
+            todo_dependencies::todo_dependency::TodoDependency,
+            todos::{todo::Todo, todo_text::TodoText},
+            users::{user::User, user_name::UserName},
+        },
         infra::repository_impl::in_memory::{
+            audit_logs::in_memory_audit_log_repository::InMemoryAuditLogRepository,
             labels::in_memory_label_repository::InMemoryLabelRepository,
+            todo_dependencies::in_memory_todo_dependency_repository::InMemoryTodoDependencyRepository,
             todos::in_memory_todo_repository::InMemoryTodoRepository,
+            users::in_memory_user_repository::InMemoryUserRepository,
         },
     };
 
     use super::*;
 
+    #[tokio::test]
+    async fn should_strip_html_tags_from_updated_todo_text() -> Result<()> {
+        let todo_repository = Arc::new(InMemoryTodoRepository::new());
+        let todo = Todo::new(TodoText::new("test1".to_string())?, vec![])?;
+        let todo_id = todo.todo_id().clone();
+        todo_repository.save(&todo).await?;
+
+        let todo_update_application_service = TodoUpdateApplicationService::new(
+            todo_repository.clone(),
+            Arc::new(InMemoryLabelRepository::new()),
+            Arc::new(InMemoryTodoDependencyRepository::new()),
+            Arc::new(InMemoryUserRepository::new()),
+            Arc::new(InMemoryAuditLogRepository::new()),
+            Arc::new(EventBus::new()),
+        );
+        let command = |todo_text: Option<&str>| TodoUpdateCommand {
+            todo_id: todo_id.value().to_string(),
+            todo_text: todo_text.map(String::from),
+            completed: None,
+            label_ids: None,
+            note: None,
+            due_date: None,
+            assignee_id: None,
+            version: None,
+            normalize_case: false,
+            requested_by: None,
+        };
+
+        let todo_data = todo_update_application_service
+            .handle(command(Some("<b>Buy milk</b>")))
+            .await?;
+        assert_eq!("Buy milk", todo_data.todo_text);
+        assert_eq!("<b>Buy milk</b>", todo_data.todo_text_raw);
+        assert_eq!(
+            "Buy milk",
+            todo_repository
+                .find(&todo_id)
+                .await?
+                .unwrap()
+                .todo_text
+                .value()
+        );
+
+        // テキストを変更しなければ、保存されているテキストを返す
+        let todo_data = todo_update_application_service
+            .handle(command(None))
+            .await?;
+        assert_eq!("Buy milk", todo_data.todo_text_raw);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_capitalize_first_char_of_updated_todo_text_if_requested() -> Result<()> {
+        let todo_repository = Arc::new(InMemoryTodoRepository::new());
+        let todo = Todo::new(TodoText::new("test1".to_string())?, vec![])?;
+        let todo_id = todo.todo_id().clone();
+        todo_repository.save(&todo).await?;
+
+        let todo_update_application_service = TodoUpdateApplicationService::new(
+            todo_repository.clone(),
+            Arc::new(InMemoryLabelRepository::new()),
+            Arc::new(InMemoryTodoDependencyRepository::new()),
+            Arc::new(InMemoryUserRepository::new()),
+            Arc::new(InMemoryAuditLogRepository::new()),
+            Arc::new(EventBus::new()),
+        );
+        let command = |todo_text: &str, normalize_case: bool| TodoUpdateCommand {
+            todo_id: todo_id.value().to_string(),
+            todo_text: Some(todo_text.to_string()),
+            completed: None,
+            label_ids: None,
+            note: None,
+            due_date: None,
+            assignee_id: None,
+            version: None,
+            normalize_case,
+            requested_by: None,
+        };
+
+        let todo_data = todo_update_application_service
+            .handle(command("buy milk", true))
+            .await?;
+        assert_eq!("Buy milk", todo_data.todo_text);
+        assert_eq!(
+            "Buy milk",
+            todo_repository
+                .find(&todo_id)
+                .await?
+                .unwrap()
+                .todo_text
+                .value()
+        );
+
+        // 大文字にすると長すぎる場合は更新しない
+        let result = todo_update_application_service
+            .handle(command(&format!("ß{}", "a".repeat(98)), true))
+            .await;
+        assert!(matches!(
+            result,
+            Err(TodoApplicationError::IllegalArgumentError(_))
+        ));
+        assert_eq!(
+            "Buy milk",
+            todo_repository
+                .find(&todo_id)
+                .await?
+                .unwrap()
+                .todo_text
+                .value()
+        );
+
+        let todo_data = todo_update_application_service
+            .handle(command("buy milk", false))
+            .await?;
+        assert_eq!("buy milk", todo_data.todo_text);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_fail_if_todo_is_modified_after_it_was_read() -> Result<()> {
+        let todo_repository = Arc::new(InMemoryTodoRepository::new());
+        let todo = Todo::new(TodoText::new("test1".to_string())?, vec![])?;
+        let todo_id = todo.todo_id().clone();
+        todo_repository.save(&todo).await?;
+
+        let todo_update_application_service = TodoUpdateApplicationService::new(
+            todo_repository.clone(),
+            Arc::new(InMemoryLabelRepository::new()),
+            Arc::new(InMemoryTodoDependencyRepository::new()),
+            Arc::new(InMemoryUserRepository::new()),
+            Arc::new(InMemoryAuditLogRepository::new()),
+            Arc::new(EventBus::new()),
+        );
+        let command = |version| TodoUpdateCommand {
+            todo_id: todo_id.value().to_string(),
+            todo_text: Some("test2".to_string()),
+            completed: None,
+            label_ids: None,
+            note: None,
+            due_date: None,
+            assignee_id: None,
+            version: Some(version),
+            normalize_case: false,
+            requested_by: None,
+        };
+        let stale_command = command(todo.version());
+
+        // コマンドを組み立てた後に、他のリクエストで更新されたものとする
+        let mut updated_todo = todo.clone();
+        updated_todo.touch();
+        todo_repository.save(&updated_todo).await?;
+
+        let result = todo_update_application_service.handle(stale_command).await;
+        assert_eq!(
+            Err(TodoApplicationError::StaleData(todo_id.clone())),
+            result
+        );
+        assert_eq!(
+            "test1",
+            todo_repository
+                .find(&todo_id)
+                .await?
+                .unwrap()
+                .todo_text
+                .value()
+        );
+
+        // 最新の版を指定すれば更新でき、版が進む
+        let todo_data = todo_update_application_service
+            .handle(command(todo.version() + 1))
+            .await?;
+        assert_eq!("test2", todo_data.todo_text);
+        assert_eq!(todo.version() + 2, todo_data.version);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_record_update_in_audit_log() -> Result<()> {
+        use crate::domain::models::audit_logs::audit_action::AuditAction;
+
+        let todo_repository = Arc::new(InMemoryTodoRepository::new());
+        let audit_log_repository = Arc::new(InMemoryAuditLogRepository::new());
+        let todo = Todo::new(TodoText::new("test1".to_string())?, vec![])?;
+        let todo_id = todo.todo_id().clone();
+        todo_repository.save(&todo).await?;
+        let actor = User::new(UserName::new("tester-1".to_string())?)?;
+
+        let todo_update_application_service = TodoUpdateApplicationService::new(
+            todo_repository,
+            Arc::new(InMemoryLabelRepository::new()),
+            Arc::new(InMemoryTodoDependencyRepository::new()),
+            Arc::new(InMemoryUserRepository::new()),
+            audit_log_repository.clone(),
+            Arc::new(EventBus::new()),
+        );
+        todo_update_application_service
+            .handle(TodoUpdateCommand {
+                todo_id: todo_id.value().to_string(),
+                todo_text: Some("test2".to_string()),
+                completed: None,
+                label_ids: None,
+                note: None,
+                due_date: None,
+                assignee_id: None,
+                version: None,
+                normalize_case: false,
+                requested_by: Some(actor.user_id().clone()),
+            })
+            .await?;
+
+        let entries = audit_log_repository
+            .find_by_resource("todo", todo_id.value())
+            .await?;
+        assert_eq!(1, entries.len());
+        assert_eq!(AuditAction::Updated, entries[0].action());
+        assert_eq!(Some(actor.user_id()), entries[0].actor_id());
+        assert_eq!("test1", entries[0].before()["text"]);
+        assert_eq!("test2", entries[0].after()["text"]);
+        Ok(())
+    }
+
     #[tokio::test]
     async fn should_update_todo_with_min_length_todo_text() -> Result<()> {
         let todo_repository = Arc::new(InMemoryTodoRepository::new());
         let label_repository = Arc::new(InMemoryLabelRepository::new());
+        let todo_dependency_repository = Arc::new(InMemoryTodoDependencyRepository::new());
 
-        let todo = Todo::new(TodoText::new("test1".to_string())?, HashSet::new())?;
+        let todo = Todo::new(TodoText::new("test1".to_string())?, vec![])?;
         let todo_id = todo.todo_id().clone();
 
         // Put the data in advance
-        {
-            let mut store = todo_repository.write_store_ref();
-            store.insert(todo_id.clone(), todo.clone());
-        }
+        todo_repository.save(&todo).await?;
 
         // Update stored todo with 1-letter text
-        let todo_update_application_service =
-            TodoUpdateApplicationService::new(todo_repository.clone(), label_repository.clone());
+        let todo_update_application_service = TodoUpdateApplicationService::new(
+            todo_repository.clone(),
+            label_repository.clone(),
+            todo_dependency_repository.clone(),
+            Arc::new(InMemoryUserRepository::new()),
+            Arc::new(InMemoryAuditLogRepository::new()),
+            Arc::new(EventBus::new()),
+        );
         let command = TodoUpdateCommand {
             todo_id: todo_id.value().to_string(),
             todo_text: Some("1".to_string()),
             completed: None,
             label_ids: Some(vec![]),
+            note: None,
+            due_date: None,
+            assignee_id: None,
+            version: None,
+            normalize_case: false,
+            requested_by: None,
         };
         let todo_found = todo_update_application_service.handle(command).await?;
 
         assert_eq!(todo_id.value(), &todo_found.todo_id);
         assert_eq!("1", todo_found.todo_text);
-        assert_eq!(false, todo_found.completed);
+        assert!(!todo_found.completed);
 
         // Check if todo is updated
         {
-            let store = todo_repository.read_store_ref();
-            let todo_in_store = store.get(&todo_id).unwrap();
+            let todo_in_store = todo_repository.find(&todo_id).await?.unwrap();
             assert_eq!("1", todo_in_store.todo_text.value());
-            assert_eq!(false, todo_in_store.completed);
+            assert!(!todo_in_store.completed);
         }
         Ok(())
     }
@@ -159,37 +553,45 @@ mod tests {
     async fn should_update_todo_with_max_length_todo_text() -> Result<()> {
         let todo_repository = Arc::new(InMemoryTodoRepository::new());
         let label_repository = Arc::new(InMemoryLabelRepository::new());
+        let todo_dependency_repository = Arc::new(InMemoryTodoDependencyRepository::new());
 
-        let todo = Todo::new(TodoText::new("test-1".to_string())?, HashSet::new())?;
+        let todo = Todo::new(TodoText::new("test-1".to_string())?, vec![])?;
         let todo_id = todo.todo_id().clone();
 
         // Put the data in advance
-        {
-            let mut store = todo_repository.write_store_ref();
-            store.insert(todo_id.clone(), todo.clone());
-        }
+        todo_repository.save(&todo).await?;
 
         // Update stored todo with 99-letter text
-        let todo_update_application_service =
-            TodoUpdateApplicationService::new(todo_repository.clone(), label_repository.clone());
+        let todo_update_application_service = TodoUpdateApplicationService::new(
+            todo_repository.clone(),
+            label_repository.clone(),
+            todo_dependency_repository.clone(),
+            Arc::new(InMemoryUserRepository::new()),
+            Arc::new(InMemoryAuditLogRepository::new()),
+            Arc::new(EventBus::new()),
+        );
         let command = TodoUpdateCommand {
             todo_id: todo_id.value().to_string(),
             todo_text: Some("123456789-123456789-123456789-123456789-123456789-123456789-123456789-123456789-123456789-123456789".to_string()),
             completed: None,
             label_ids: Some(vec![]),
+            note: None,
+            due_date: None,
+            assignee_id: None,
+            version: None,
+            normalize_case: false, requested_by: None,
         };
         let todo_found = todo_update_application_service.handle(command).await?;
 
         assert_eq!(todo_id.value(), &todo_found.todo_id);
         assert_eq!(todo_found.todo_text, "123456789-123456789-123456789-123456789-123456789-123456789-123456789-123456789-123456789-123456789");
-        assert_eq!(todo_found.completed, false);
+        assert!(!todo_found.completed);
 
         // Check if todo is updated
         {
-            let store = todo_repository.read_store_ref();
-            let todo_in_store = store.get(&todo_id).unwrap();
+            let todo_in_store = todo_repository.find(&todo_id).await?.unwrap();
             assert_eq!(todo_in_store.todo_text.value(), "123456789-123456789-123456789-123456789-123456789-123456789-123456789-123456789-123456789-123456789");
-            assert_eq!(todo_in_store.completed, false);
+            assert!(!todo_in_store.completed);
         }
         Ok(())
     }
@@ -198,37 +600,46 @@ mod tests {
     async fn should_change_completed_in_todo() -> Result<()> {
         let todo_repository = Arc::new(InMemoryTodoRepository::new());
         let label_repository = Arc::new(InMemoryLabelRepository::new());
+        let todo_dependency_repository = Arc::new(InMemoryTodoDependencyRepository::new());
 
-        let todo = Todo::new(TodoText::new("test1".to_string())?, HashSet::new())?;
+        let todo = Todo::new(TodoText::new("test1".to_string())?, vec![])?;
         let todo_id = todo.todo_id().clone();
 
         // Put the data in advance
-        {
-            let mut store = todo_repository.write_store_ref();
-            store.insert(todo_id.clone(), todo.clone());
-        }
+        todo_repository.save(&todo).await?;
 
         // Update stored todo with 1-letter text
-        let todo_update_application_service =
-            TodoUpdateApplicationService::new(todo_repository.clone(), label_repository.clone());
+        let todo_update_application_service = TodoUpdateApplicationService::new(
+            todo_repository.clone(),
+            label_repository.clone(),
+            todo_dependency_repository.clone(),
+            Arc::new(InMemoryUserRepository::new()),
+            Arc::new(InMemoryAuditLogRepository::new()),
+            Arc::new(EventBus::new()),
+        );
         let command = TodoUpdateCommand {
             todo_id: todo_id.value().to_string(),
             todo_text: None,
             completed: Some(true),
             label_ids: Some(vec![]),
+            note: None,
+            due_date: None,
+            assignee_id: None,
+            version: None,
+            normalize_case: false,
+            requested_by: None,
         };
         let todo_found = todo_update_application_service.handle(command).await?;
 
         assert_eq!(todo_id.value(), &todo_found.todo_id);
         assert_eq!("test1", todo_found.todo_text);
-        assert_eq!(true, todo_found.completed);
+        assert!(todo_found.completed);
 
         // Check if todo is updated
         {
-            let store = todo_repository.read_store_ref();
-            let todo_in_store = store.get(&todo_id).unwrap();
+            let todo_in_store = todo_repository.find(&todo_id).await?.unwrap();
             assert_eq!("test1", todo_in_store.todo_text.value());
-            assert_eq!(true, todo_in_store.completed);
+            assert!(todo_in_store.completed);
         }
         Ok(())
     }
@@ -237,24 +648,34 @@ mod tests {
     async fn should_throw_error_if_todo_text_is_empty() -> Result<()> {
         let todo_repository = Arc::new(InMemoryTodoRepository::new());
         let label_repository = Arc::new(InMemoryLabelRepository::new());
+        let todo_dependency_repository = Arc::new(InMemoryTodoDependencyRepository::new());
 
-        let todo = Todo::new(TodoText::new("tester-1".to_string())?, HashSet::new())?;
+        let todo = Todo::new(TodoText::new("tester-1".to_string())?, vec![])?;
         let todo_id = todo.todo_id().clone();
 
         // Put the data in advance
-        {
-            let mut store = todo_repository.write_store_ref();
-            store.insert(todo_id.clone(), todo.clone());
-        }
+        todo_repository.save(&todo).await?;
 
         // Try update stored todo with empty text
-        let todo_update_application_service =
-            TodoUpdateApplicationService::new(todo_repository.clone(), label_repository.clone());
+        let todo_update_application_service = TodoUpdateApplicationService::new(
+            todo_repository.clone(),
+            label_repository.clone(),
+            todo_dependency_repository.clone(),
+            Arc::new(InMemoryUserRepository::new()),
+            Arc::new(InMemoryAuditLogRepository::new()),
+            Arc::new(EventBus::new()),
+        );
         let command = TodoUpdateCommand {
             todo_id: todo_id.value().to_string(),
             todo_text: Some("".to_string()),
             completed: None,
             label_ids: Some(vec![]),
+            note: None,
+            due_date: None,
+            assignee_id: None,
+            version: None,
+            normalize_case: false,
+            requested_by: None,
         };
         let result_of_todo_update = todo_update_application_service.handle(command).await;
 
@@ -271,24 +692,33 @@ mod tests {
     async fn should_throw_error_if_todo_text_is_too_long() -> Result<()> {
         let todo_repository = Arc::new(InMemoryTodoRepository::new());
         let label_repository = Arc::new(InMemoryLabelRepository::new());
+        let todo_dependency_repository = Arc::new(InMemoryTodoDependencyRepository::new());
 
-        let todo = Todo::new(TodoText::new("test-1".to_string())?, HashSet::new())?;
+        let todo = Todo::new(TodoText::new("test-1".to_string())?, vec![])?;
         let todo_id = todo.todo_id().clone();
 
         // Put the data in advance
-        {
-            let mut store = todo_repository.write_store_ref();
-            store.insert(todo_id.clone(), todo.clone());
-        }
+        todo_repository.save(&todo).await?;
 
         // Try update stored todo with 100-letter text
-        let todo_update_application_service =
-            TodoUpdateApplicationService::new(todo_repository.clone(), label_repository.clone());
+        let todo_update_application_service = TodoUpdateApplicationService::new(
+            todo_repository.clone(),
+            label_repository.clone(),
+            todo_dependency_repository.clone(),
+            Arc::new(InMemoryUserRepository::new()),
+            Arc::new(InMemoryAuditLogRepository::new()),
+            Arc::new(EventBus::new()),
+        );
         let command = TodoUpdateCommand {
             todo_id: todo_id.value().to_string(),
             todo_text: Some("123456789-123456789-123456789-123456789-123456789-123456789-123456789-123456789-123456789-1234567890".to_string()),
             completed: None,
             label_ids: Some(vec![]),
+            note: None,
+            due_date: None,
+            assignee_id: None,
+            version: None,
+            normalize_case: false, requested_by: None,
         };
         let result_of_todo_update = todo_update_application_service.handle(command).await;
 
@@ -305,16 +735,29 @@ mod tests {
     async fn should_throw_error_if_target_todo_does_not_exist() -> Result<()> {
         let todo_repository = Arc::new(InMemoryTodoRepository::new());
         let label_repository = Arc::new(InMemoryLabelRepository::new());
+        let todo_dependency_repository = Arc::new(InMemoryTodoDependencyRepository::new());
 
         // Try to update not-stored todo
         let todo_id = Uuid::new_v4();
-        let todo_update_application_service =
-            TodoUpdateApplicationService::new(todo_repository.clone(), label_repository.clone());
+        let todo_update_application_service = TodoUpdateApplicationService::new(
+            todo_repository.clone(),
+            label_repository.clone(),
+            todo_dependency_repository.clone(),
+            Arc::new(InMemoryUserRepository::new()),
+            Arc::new(InMemoryAuditLogRepository::new()),
+            Arc::new(EventBus::new()),
+        );
         let command = TodoUpdateCommand {
             todo_id: todo_id.to_string(),
             todo_text: Some("test-1".to_string()),
             completed: None,
             label_ids: Some(vec![]),
+            note: None,
+            due_date: None,
+            assignee_id: None,
+            version: None,
+            normalize_case: false,
+            requested_by: None,
         };
         let result_of_todo_update = todo_update_application_service.handle(command).await;
 
@@ -329,24 +772,481 @@ mod tests {
     async fn should_throw_error_if_todo_id_has_incorrect_format() -> Result<()> {
         let todo_repository = Arc::new(InMemoryTodoRepository::new());
         let label_repository = Arc::new(InMemoryLabelRepository::new());
+        let todo_dependency_repository = Arc::new(InMemoryTodoDependencyRepository::new());
 
         // Try to update not-stored todo
         let todo_id = "illegal-todo-id";
-        let todo_update_application_service =
-            TodoUpdateApplicationService::new(todo_repository.clone(), label_repository.clone());
+        let todo_update_application_service = TodoUpdateApplicationService::new(
+            todo_repository.clone(),
+            label_repository.clone(),
+            todo_dependency_repository.clone(),
+            Arc::new(InMemoryUserRepository::new()),
+            Arc::new(InMemoryAuditLogRepository::new()),
+            Arc::new(EventBus::new()),
+        );
         let command = TodoUpdateCommand {
             todo_id: todo_id.to_string(),
             todo_text: Some("test-1".to_string()),
             completed: None,
             label_ids: Some(vec![]),
+            note: None,
+            due_date: None,
+            assignee_id: None,
+            version: None,
+            normalize_case: false,
+            requested_by: None,
         };
         let result_of_todo_update = todo_update_application_service.handle(command).await;
 
-        assert!(matches!(
+        assert_eq!(
             result_of_todo_update,
-            Err(TodoApplicationError::IllegalTodoId(_))
-        ));
+            Err(TodoApplicationError::IllegalTodoId(
+                TodoId::parse("illegal-todo-id".to_string())
+                    .unwrap_err()
+                    .to_string()
+            ))
+        );
+
+        Ok(())
+    }
 
+    #[tokio::test]
+    async fn should_throw_error_if_dependency_is_not_completed() -> Result<()> {
+        let todo_repository = Arc::new(InMemoryTodoRepository::new());
+        let label_repository = Arc::new(InMemoryLabelRepository::new());
+        let todo_dependency_repository = Arc::new(InMemoryTodoDependencyRepository::new());
+
+        let blocking_todo = Todo::new(TodoText::new("blocking".to_string())?, vec![])?;
+        let blocked_todo = Todo::new(TodoText::new("blocked".to_string())?, vec![])?;
+        let blocking_todo_id = blocking_todo.todo_id().clone();
+        let blocked_todo_id = blocked_todo.todo_id().clone();
+
+        // Put the data in advance
+        todo_repository.save(&blocking_todo).await?;
+        todo_repository.save(&blocked_todo).await?;
+        {
+            let todo_dependency =
+                TodoDependency::new(blocking_todo_id.clone(), blocked_todo_id.clone())?;
+            let mut store = todo_dependency_repository.write_store_ref();
+            store.insert(todo_dependency.dependency_id().clone(), todo_dependency);
+        }
+
+        // Try to complete the blocked todo
+        let todo_update_application_service = TodoUpdateApplicationService::new(
+            todo_repository.clone(),
+            label_repository.clone(),
+            todo_dependency_repository.clone(),
+            Arc::new(InMemoryUserRepository::new()),
+            Arc::new(InMemoryAuditLogRepository::new()),
+            Arc::new(EventBus::new()),
+        );
+        let command = TodoUpdateCommand {
+            todo_id: blocked_todo_id.value().to_string(),
+            todo_text: None,
+            completed: Some(true),
+            label_ids: None,
+            note: None,
+            due_date: None,
+            assignee_id: None,
+            version: None,
+            normalize_case: false,
+            requested_by: None,
+        };
+        let result_of_todo_update = todo_update_application_service.handle(command).await;
+
+        assert_eq!(
+            Err(TodoApplicationError::DependencyNotMet(vec![
+                blocking_todo_id
+            ])),
+            result_of_todo_update
+        );
+
+        // Check if todo is not updated
+        {
+            let todo_in_store = todo_repository.find(&blocked_todo_id).await?.unwrap();
+            assert!(!todo_in_store.completed);
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_complete_todo_if_dependency_is_completed() -> Result<()> {
+        let todo_repository = Arc::new(InMemoryTodoRepository::new());
+        let label_repository = Arc::new(InMemoryLabelRepository::new());
+        let todo_dependency_repository = Arc::new(InMemoryTodoDependencyRepository::new());
+
+        let mut blocking_todo = Todo::new(TodoText::new("blocking".to_string())?, vec![])?;
+        blocking_todo.completed = true;
+        let blocked_todo = Todo::new(TodoText::new("blocked".to_string())?, vec![])?;
+        let blocking_todo_id = blocking_todo.todo_id().clone();
+        let blocked_todo_id = blocked_todo.todo_id().clone();
+
+        // Put the data in advance
+        todo_repository.save(&blocking_todo).await?;
+        todo_repository.save(&blocked_todo).await?;
+        {
+            let todo_dependency =
+                TodoDependency::new(blocking_todo_id.clone(), blocked_todo_id.clone())?;
+            let mut store = todo_dependency_repository.write_store_ref();
+            store.insert(todo_dependency.dependency_id().clone(), todo_dependency);
+        }
+
+        // Complete the blocked todo
+        let todo_update_application_service = TodoUpdateApplicationService::new(
+            todo_repository.clone(),
+            label_repository.clone(),
+            todo_dependency_repository.clone(),
+            Arc::new(InMemoryUserRepository::new()),
+            Arc::new(InMemoryAuditLogRepository::new()),
+            Arc::new(EventBus::new()),
+        );
+        let command = TodoUpdateCommand {
+            todo_id: blocked_todo_id.value().to_string(),
+            todo_text: None,
+            completed: Some(true),
+            label_ids: None,
+            note: None,
+            due_date: None,
+            assignee_id: None,
+            version: None,
+            normalize_case: false,
+            requested_by: None,
+        };
+        let todo_found = todo_update_application_service.handle(command).await?;
+
+        assert!(todo_found.completed);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_update_and_clear_todo_note() -> Result<()> {
+        let todo_repository = Arc::new(InMemoryTodoRepository::new());
+        let label_repository = Arc::new(InMemoryLabelRepository::new());
+        let todo_dependency_repository = Arc::new(InMemoryTodoDependencyRepository::new());
+
+        let todo = Todo::new(TodoText::new("test1".to_string())?, vec![])?;
+        let todo_id = todo.todo_id().clone();
+
+        // Put the data in advance
+        todo_repository.save(&todo).await?;
+
+        let todo_update_application_service = TodoUpdateApplicationService::new(
+            todo_repository.clone(),
+            label_repository.clone(),
+            todo_dependency_repository.clone(),
+            Arc::new(InMemoryUserRepository::new()),
+            Arc::new(InMemoryAuditLogRepository::new()),
+            Arc::new(EventBus::new()),
+        );
+
+        // 1. Update note
+        let command = TodoUpdateCommand {
+            todo_id: todo_id.value().to_string(),
+            todo_text: None,
+            completed: None,
+            label_ids: None,
+            note: Some(Some("updated note".to_string())),
+            due_date: None,
+            assignee_id: None,
+            version: None,
+            normalize_case: false,
+            requested_by: None,
+        };
+        let todo_found = todo_update_application_service.handle(command).await?;
+        assert_eq!(Some("updated note".to_string()), todo_found.note);
+
+        // 2. `None` does not change note
+        let command = TodoUpdateCommand {
+            todo_id: todo_id.value().to_string(),
+            todo_text: Some("test2".to_string()),
+            completed: None,
+            label_ids: None,
+            note: None,
+            due_date: None,
+            assignee_id: None,
+            version: None,
+            normalize_case: false,
+            requested_by: None,
+        };
+        let todo_found = todo_update_application_service.handle(command).await?;
+        assert_eq!(Some("updated note".to_string()), todo_found.note);
+
+        // 3. Empty string clears note
+        let command = TodoUpdateCommand {
+            todo_id: todo_id.value().to_string(),
+            todo_text: None,
+            completed: None,
+            label_ids: None,
+            note: Some(Some("".to_string())),
+            due_date: None,
+            assignee_id: None,
+            version: None,
+            normalize_case: false,
+            requested_by: None,
+        };
+        let todo_found = todo_update_application_service.handle(command).await?;
+        assert_eq!(None, todo_found.note);
+
+        // Check if todo is updated
+        {
+            let todo_in_store = todo_repository.find(&todo_id).await?.unwrap();
+            assert_eq!(None, todo_in_store.note);
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_clear_due_date_only_if_explicitly_set_to_none() -> Result<()> {
+        let todo_repository = Arc::new(InMemoryTodoRepository::new());
+        let due_date = chrono::NaiveDate::from_ymd_opt(2023, 10, 31).unwrap();
+
+        let mut todo = Todo::new(TodoText::new("test1".to_string())?, vec![])?;
+        todo.due_date = Some(due_date);
+        let todo_id = todo.todo_id().clone();
+
+        // Put the data in advance
+        todo_repository.save(&todo).await?;
+
+        let todo_update_application_service = TodoUpdateApplicationService::new(
+            todo_repository.clone(),
+            Arc::new(InMemoryLabelRepository::new()),
+            Arc::new(InMemoryTodoDependencyRepository::new()),
+            Arc::new(InMemoryUserRepository::new()),
+            Arc::new(InMemoryAuditLogRepository::new()),
+            Arc::new(EventBus::new()),
+        );
+
+        // 1. `None` does not change due date
+        let command = TodoUpdateCommand {
+            todo_id: todo_id.value().to_string(),
+            todo_text: None,
+            completed: None,
+            label_ids: None,
+            note: None,
+            due_date: None,
+            assignee_id: None,
+            version: None,
+            normalize_case: false,
+            requested_by: None,
+        };
+        let todo_found = todo_update_application_service.handle(command).await?;
+        assert_eq!(Some(due_date), todo_found.due_date);
+
+        // 2. `Some(None)` clears due date
+        let command = TodoUpdateCommand {
+            todo_id: todo_id.value().to_string(),
+            todo_text: None,
+            completed: None,
+            label_ids: None,
+            note: None,
+            due_date: Some(None),
+            assignee_id: None,
+            version: None,
+            normalize_case: false,
+            requested_by: None,
+        };
+        let todo_found = todo_update_application_service.handle(command).await?;
+        assert_eq!(None, todo_found.due_date);
+
+        // Check if todo is updated
+        {
+            let todo_in_store = todo_repository.find(&todo_id).await?.unwrap();
+            assert_eq!(None, todo_in_store.due_date);
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_reassign_and_unassign_todo() -> Result<()> {
+        let todo_repository = Arc::new(InMemoryTodoRepository::new());
+        let label_repository = Arc::new(InMemoryLabelRepository::new());
+        let todo_dependency_repository = Arc::new(InMemoryTodoDependencyRepository::new());
+        let user_repository = Arc::new(InMemoryUserRepository::new());
+
+        let user_1 = User::new(UserName::new("tester-1".to_string())?)?;
+        let user_2 = User::new(UserName::new("tester-2".to_string())?)?;
+        user_repository.save(&user_1).await?;
+        user_repository.save(&user_2).await?;
+
+        let mut todo = Todo::new(TodoText::new("test1".to_string())?, vec![])?;
+        todo.assignee_id = Some(user_1.user_id().clone());
+        let todo_id = todo.todo_id().clone();
+
+        // Put the data in advance
+        todo_repository.save(&todo).await?;
+
+        let todo_update_application_service = TodoUpdateApplicationService::new(
+            todo_repository.clone(),
+            label_repository.clone(),
+            todo_dependency_repository.clone(),
+            user_repository.clone(),
+            Arc::new(InMemoryAuditLogRepository::new()),
+            Arc::new(EventBus::new()),
+        );
+
+        // 1. Reassign to another user
+        let command = TodoUpdateCommand {
+            todo_id: todo_id.value().to_string(),
+            todo_text: None,
+            completed: None,
+            label_ids: None,
+            note: None,
+            due_date: None,
+            assignee_id: Some(Some(user_2.user_id().to_string())),
+            version: None,
+            normalize_case: false,
+            requested_by: None,
+        };
+        let todo_found = todo_update_application_service.handle(command).await?;
+        assert_eq!(Some(*user_2.user_id().value()), todo_found.assignee_id);
+
+        // 2. Empty string unassigns todo
+        let command = TodoUpdateCommand {
+            todo_id: todo_id.value().to_string(),
+            todo_text: None,
+            completed: None,
+            label_ids: None,
+            note: None,
+            due_date: None,
+            assignee_id: Some(Some("".to_string())),
+            version: None,
+            normalize_case: false,
+            requested_by: None,
+        };
+        let todo_found = todo_update_application_service.handle(command).await?;
+        assert_eq!(None, todo_found.assignee_id);
+
+        // Check if todo is updated
+        {
+            let todo_in_store = todo_repository.find(&todo_id).await?.unwrap();
+            assert_eq!(None, todo_in_store.assignee_id);
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_replace_labels_in_given_order() -> Result<()> {
+        let todo_repository = Arc::new(InMemoryTodoRepository::new());
+        let label_repository = Arc::new(InMemoryLabelRepository::new());
+        let todo_dependency_repository = Arc::new(InMemoryTodoDependencyRepository::new());
+
+        let label_a = Label::new(LabelName::new("label-a".to_string())?)?;
+        let label_b = Label::new(LabelName::new("label-b".to_string())?)?;
+        let label_c = Label::new(LabelName::new("label-c".to_string())?)?;
+        let todo = Todo::new(
+            TodoText::new("test1".to_string())?,
+            vec![label_a.clone(), label_b.clone(), label_c.clone()],
+        )?;
+        let todo_id = todo.todo_id().clone();
+
+        // Put the data in advance
+        for label in [&label_a, &label_b, &label_c] {
+            label_repository.save(label).await?;
+        }
+        todo_repository.save(&todo).await?;
+
+        let todo_update_application_service = TodoUpdateApplicationService::new(
+            todo_repository.clone(),
+            label_repository.clone(),
+            todo_dependency_repository.clone(),
+            Arc::new(InMemoryUserRepository::new()),
+            Arc::new(InMemoryAuditLogRepository::new()),
+            Arc::new(EventBus::new()),
+        );
+        let command = TodoUpdateCommand {
+            todo_id: todo_id.value().to_string(),
+            todo_text: None,
+            completed: None,
+            label_ids: Some(vec![
+                label_c.label_id().value().to_string(),
+                label_a.label_id().value().to_string(),
+            ]),
+            note: None,
+            due_date: None,
+            assignee_id: None,
+            version: None,
+            normalize_case: false,
+            requested_by: None,
+        };
+        let todo_found = todo_update_application_service.handle(command).await?;
+
+        let label_names: Vec<&str> = todo_found
+            .labels
+            .iter()
+            .map(|label| label.label_name.as_str())
+            .collect();
+        assert_eq!(vec!["label-c", "label-a"], label_names);
+
+        // Check if todo is updated
+        {
+            let todo_in_store = todo_repository.find(&todo_id).await?.unwrap();
+            assert_eq!(vec![label_c, label_a], todo_in_store.labels);
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_throw_error_if_labels_exceed_max_labels() -> Result<()> {
+        let todo_repository = Arc::new(InMemoryTodoRepository::new());
+        let label_repository = Arc::new(InMemoryLabelRepository::new());
+
+        let labels = (0..=MAX_LABELS_PER_TODO)
+            .map(|i| Label::new(LabelName::new(format!("label-{}", i))?))
+            .collect::<anyhow::Result<Vec<Label>>>()?;
+        label_repository.save_all(&labels).await?;
+        let todo = Todo::new(TodoText::new("test1".to_string())?, vec![])?;
+        let todo_id = todo.todo_id().clone();
+        todo_repository.save(&todo).await?;
+
+        let todo_update_application_service = TodoUpdateApplicationService::new(
+            todo_repository.clone(),
+            label_repository.clone(),
+            Arc::new(InMemoryTodoDependencyRepository::new()),
+            Arc::new(InMemoryUserRepository::new()),
+            Arc::new(InMemoryAuditLogRepository::new()),
+            Arc::new(EventBus::new()),
+        );
+        let command_with_labels = |count: usize| TodoUpdateCommand {
+            todo_id: todo_id.value().to_string(),
+            todo_text: None,
+            completed: None,
+            label_ids: Some(
+                labels[..count]
+                    .iter()
+                    .map(|label| label.label_id().value().to_string())
+                    .collect(),
+            ),
+            note: None,
+            due_date: None,
+            assignee_id: None,
+            version: None,
+            normalize_case: false,
+            requested_by: None,
+        };
+
+        for count in [MAX_LABELS_PER_TODO, 0] {
+            let todo_data = todo_update_application_service
+                .handle(command_with_labels(count))
+                .await?;
+            assert_eq!(count, todo_data.labels.len());
+        }
+
+        let result = todo_update_application_service
+            .handle(command_with_labels(MAX_LABELS_PER_TODO + 1))
+            .await;
+        assert_eq!(
+            Err(TodoApplicationError::IllegalArgumentError(
+                "A todo can have at most 10 labels.".to_string()
+            )),
+            result.map(|_| ())
+        );
+        // 失敗した場合はラベルを変えない
+        assert!(todo_repository
+            .find(&todo_id)
+            .await?
+            .unwrap()
+            .labels
+            .is_empty());
         Ok(())
     }
 }
@@ -1,12 +1,110 @@
+pub mod user_application_error;
+pub mod user_authenticate_application_service;
 pub mod user_create_application_service;
-pub mod user_get_application_service;
+pub mod user_data;
+pub mod user_delete_application_service;
 pub mod user_get_all_aplication_service;
+pub mod user_get_application_service;
+pub mod user_get_by_role_application_service;
+pub mod user_label_application_service;
+pub mod user_password_change_application_service;
+pub mod user_role_distribution_application_service;
+pub mod user_search_application_service;
+pub mod user_self_update_application_service;
 pub mod user_update_application_service;
-pub mod user_delete_application_service;
-pub mod user_data;
-pub mod user_application_error;
 
+use chrono::{DateTime, Utc};
+
+use crate::domain::{
+    models::{
+        credentials::{
+            credential_repository::ICredentialRepository, password_credential::PasswordCredential,
+        },
+        users::{user_id::UserId, user_repository::IUserRepository, user_role::UserRole},
+    },
+    value_object::ValueObject,
+};
+
+use self::{
+    user_application_error::UserApplicationError,
+    user_data::{UserData, UserDataPublic, UserDataView},
+};
+
+pub type Result<T> = anyhow::Result<T, UserApplicationError>;
+
+// ロック中でないことを確かめてからパスワードを検証し、間違えた回数とロックを保存する
+// ログイン、パスワードの変更、本人による削除で同じ回数を数え、どれで間違えてもロックする
+async fn verify_password<CredentialRep: ICredentialRepository>(
+    credential_repository: &CredentialRep,
+    credential: &mut PasswordCredential,
+    password: &str,
+    now: DateTime<Utc>,
+) -> Result<()> {
+    // ロック中は、正しいパスワードであっても通さない
+    if let Some(until) = credential.locked_at(now) {
+        return Err(UserApplicationError::AccountLocked { until });
+    }
+    if !credential.verify(password) {
+        credential.record_failed_login(now);
+        save_credential(credential_repository, credential).await?;
+        return Err(UserApplicationError::PasswordMismatch);
+    }
+    // 成功するたびに保存しないよう、失敗の記録が残っている場合だけ書き戻す
+    if credential.record_successful_login() {
+        save_credential(credential_repository, credential).await?;
+    }
+    Ok(())
+}
+
+async fn save_credential<CredentialRep: ICredentialRepository>(
+    credential_repository: &CredentialRep,
+    credential: &PasswordCredential,
+) -> Result<()> {
+    credential_repository
+        .save(credential)
+        .await
+        .map_err(|e| UserApplicationError::Unexpected(e.to_string()))
+}
+
+// 情報を求めたユーザーが管理者かどうか
+// 認証していない場合と、認証後に削除されたユーザーは権限がないものとして扱う
+async fn is_admin<UserRep: IUserRepository>(
+    user_repository: &UserRep,
+    requested_by: Option<&UserId>,
+) -> Result<bool> {
+    let Some(requested_by) = requested_by else {
+        return Ok(false);
+    };
+    let requester = user_repository.find(requested_by).await?;
+    Ok(requester.is_some_and(|requester| requester.user_role == UserRole::Admin))
+}
 
-use self::user_application_error::UserApplicationError;
+// 情報を求めたユーザーが本人か管理者であればすべての項目を、それ以外には id と名前だけを見せる
+async fn view_users_data<UserRep: IUserRepository>(
+    user_repository: &UserRep,
+    users_data: Vec<UserData>,
+    requested_by: Option<&UserId>,
+) -> Result<Vec<UserDataView>> {
+    let is_admin = is_admin(user_repository, requested_by).await?;
+    Ok(users_data
+        .into_iter()
+        .map(|user_data| {
+            let is_themselves =
+                requested_by.is_some_and(|requested_by| requested_by.value() == &user_data.user_id);
+            if is_admin || is_themselves {
+                UserDataView::Full(user_data)
+            } else {
+                UserDataView::Public(UserDataPublic::from(user_data))
+            }
+        })
+        .collect())
+}
 
-pub type Result<T> = anyhow::Result<T, UserApplicationError>;
\ No newline at end of file
+async fn view_user_data<UserRep: IUserRepository>(
+    user_repository: &UserRep,
+    user_data: UserData,
+    requested_by: Option<&UserId>,
+) -> Result<UserDataView> {
+    let mut users_data = view_users_data(user_repository, vec![user_data], requested_by).await?;
+    Ok(users_data.remove(0))
+}
@@ -1,7 +1,10 @@
+use chrono::{DateTime, Utc};
 use serde::Serialize;
 use thiserror::Error;
 
-use crate::domain::models::users::{user::User, user_id::UserId};
+use crate::domain::models::users::{
+    user::User, user_id::UserId, user_repository::UserRepositoryError,
+};
 
 #[derive(Debug, Error, PartialEq)]
 pub enum UserApplicationError {
@@ -13,10 +16,28 @@ pub enum UserApplicationError {
     IllegalArgumentError(String),
     #[error("Given user id has incorrect format: [{0}]")]
     IllegalUserId(String),
+    #[error("Given user role has incorrect format: [{0}]")]
+    IllegalUserRole(String),
+    #[error("Given password does not match")]
+    PasswordMismatch,
+    #[error("Account is locked until {until}")]
+    AccountLocked { until: DateTime<Utc> },
+    // 本人か管理者でなければ操作できないユーザーを、他のユーザーが操作しようとした
+    #[error("Not allowed to modify another user: [user id: {0:?}]")]
+    PermissionDenied(UserId),
     #[error("Unexpected error: [{0}]")]
     Unexpected(String),
 }
 
+impl From<UserRepositoryError> for UserApplicationError {
+    fn from(e: UserRepositoryError) -> Self {
+        match e {
+            UserRepositoryError::NotFound(user_id) => Self::UserNotFound(user_id),
+            UserRepositoryError::Unexpected(e) => Self::Unexpected(e),
+        }
+    }
+}
+
 // <https://github.com/serde-rs/serde/issues/2268#issuecomment-1238962452> を参考に実装
 impl Serialize for UserApplicationError {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -26,3 +47,95 @@ impl Serialize for UserApplicationError {
         serializer.serialize_str(self.to_string().as_ref())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::domain::{models::users::user_name::UserName, value_object::ValueObject};
+
+    #[test]
+    fn should_compare_every_variant_by_value() -> Result<()> {
+        use UserApplicationError::*;
+
+        let user_1 = User::new(UserName::new("tester-1".to_string())?)?;
+        let user_2 = User::new(UserName::new("tester-2".to_string())?)?;
+        let user_id_1 = UserId::new(Uuid::new_v4())?;
+        let user_id_2 = UserId::new(Uuid::new_v4())?;
+        let now = Utc::now();
+
+        // (比較対象, 同じ値, 異なる値)
+        let cases = [
+            (
+                DuplicatedUser(user_1.clone()),
+                DuplicatedUser(user_1),
+                DuplicatedUser(user_2),
+            ),
+            (
+                UserNotFound(user_id_1.clone()),
+                UserNotFound(user_id_1.clone()),
+                UserNotFound(user_id_2.clone()),
+            ),
+            (
+                IllegalArgumentError("a".to_string()),
+                IllegalArgumentError("a".to_string()),
+                IllegalArgumentError("b".to_string()),
+            ),
+            (
+                IllegalUserId("a".to_string()),
+                IllegalUserId("a".to_string()),
+                IllegalUserId("b".to_string()),
+            ),
+            (
+                IllegalUserRole("a".to_string()),
+                IllegalUserRole("a".to_string()),
+                IllegalUserRole("b".to_string()),
+            ),
+            (
+                PasswordMismatch,
+                PasswordMismatch,
+                Unexpected("a".to_string()),
+            ),
+            (
+                AccountLocked { until: now },
+                AccountLocked { until: now },
+                AccountLocked {
+                    until: now + chrono::Duration::minutes(1),
+                },
+            ),
+            (
+                PermissionDenied(user_id_1.clone()),
+                PermissionDenied(user_id_1),
+                PermissionDenied(user_id_2),
+            ),
+            (
+                Unexpected("a".to_string()),
+                Unexpected("a".to_string()),
+                Unexpected("b".to_string()),
+            ),
+        ];
+
+        for (error, same, different) in cases {
+            assert_eq!(error, same);
+            assert_ne!(error, different);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn should_convert_every_repository_error() -> Result<()> {
+        let user_id = UserId::new(Uuid::new_v4())?;
+
+        assert_eq!(
+            UserApplicationError::UserNotFound(user_id.clone()),
+            UserRepositoryError::NotFound(user_id).into()
+        );
+        assert_eq!(
+            UserApplicationError::Unexpected("a".to_string()),
+            UserRepositoryError::Unexpected("a".to_string()).into()
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,223 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+
+use crate::domain::{
+    clock::{Clock, SystemClock},
+    models::{credentials::credential_repository::ICredentialRepository, users::user_id::UserId},
+};
+
+use super::{user_application_error::UserApplicationError, verify_password, Result};
+
+// trait of application service to authenticate a user by password
+#[async_trait]
+pub trait IUserAuthenticateApplicationService<CredentialRep: ICredentialRepository> {
+    fn new(credential_repository: Arc<CredentialRep>) -> Self;
+    async fn handle(&self, command: UserAuthenticateCommand) -> Result<UserId>;
+}
+
+pub struct UserAuthenticateCommand {
+    pub user_id: String,
+    pub password: String,
+}
+
+// impl of application service to authenticate a user by password
+pub struct UserAuthenticateApplicationService<CredentialRep: ICredentialRepository> {
+    credential_repository: Arc<CredentialRep>,
+    clock: Arc<dyn Clock>,
+}
+
+impl<CredentialRep: ICredentialRepository> UserAuthenticateApplicationService<CredentialRep> {
+    pub fn with_clock(credential_repository: Arc<CredentialRep>, clock: Arc<dyn Clock>) -> Self {
+        Self {
+            credential_repository,
+            clock,
+        }
+    }
+}
+
+#[async_trait]
+impl<CredentialRep: ICredentialRepository> IUserAuthenticateApplicationService<CredentialRep>
+    for UserAuthenticateApplicationService<CredentialRep>
+{
+    fn new(credential_repository: Arc<CredentialRep>) -> Self {
+        Self::with_clock(credential_repository, Arc::new(SystemClock))
+    }
+
+    async fn handle(&self, command: UserAuthenticateCommand) -> Result<UserId> {
+        let UserAuthenticateCommand {
+            user_id: user_id_string,
+            password,
+        } = command;
+        let user_id = UserId::parse(user_id_string)
+            .map_err(|e| UserApplicationError::IllegalUserId(e.to_string()))?;
+
+        // パスワードが登録されていないユーザーはログインできない
+        let mut credential = self
+            .credential_repository
+            .find_by_user_id(&user_id)
+            .await
+            .map_err(|e| UserApplicationError::Unexpected(e.to_string()))?
+            .ok_or(UserApplicationError::UserNotFound(user_id.clone()))?;
+
+        verify_password(
+            self.credential_repository.as_ref(),
+            &mut credential,
+            &password,
+            self.clock.now(),
+        )
+        .await?;
+
+        Ok(user_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use uuid::Uuid;
+
+    use chrono::{DateTime, Duration, Utc};
+
+    use crate::{
+        domain::{
+            clock::FixedClock, models::credentials::password_credential::PasswordCredential,
+            value_object::ValueObject,
+        },
+        infra::repository_impl::in_memory::credentials::in_memory_credential_repository::InMemoryCredentialRepository,
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn should_authenticate_with_correct_password() -> Result<()> {
+        let repository = Arc::new(InMemoryCredentialRepository::new());
+        let user_id = UserId::new(Uuid::new_v4())?;
+        repository
+            .save(&PasswordCredential::new(user_id.clone(), "password1")?)
+            .await?;
+
+        let user_authenticate_application_service =
+            UserAuthenticateApplicationService::new(repository.clone());
+        let user_id_authenticated = user_authenticate_application_service
+            .handle(UserAuthenticateCommand {
+                user_id: user_id.to_string(),
+                password: "password1".to_string(),
+            })
+            .await?;
+
+        assert_eq!(user_id, user_id_authenticated);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_reject_wrong_password() -> Result<()> {
+        let repository = Arc::new(InMemoryCredentialRepository::new());
+        let user_id = UserId::new(Uuid::new_v4())?;
+        repository
+            .save(&PasswordCredential::new(user_id.clone(), "password1")?)
+            .await?;
+
+        let user_authenticate_application_service =
+            UserAuthenticateApplicationService::new(repository.clone());
+        let result = user_authenticate_application_service
+            .handle(UserAuthenticateCommand {
+                user_id: user_id.to_string(),
+                password: "password2".to_string(),
+            })
+            .await;
+
+        assert_eq!(Err(UserApplicationError::PasswordMismatch), result);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_reject_user_without_password() -> Result<()> {
+        let repository = Arc::new(InMemoryCredentialRepository::new());
+        let user_id = UserId::new(Uuid::new_v4())?;
+
+        let user_authenticate_application_service =
+            UserAuthenticateApplicationService::new(repository.clone());
+        let result = user_authenticate_application_service
+            .handle(UserAuthenticateCommand {
+                user_id: user_id.to_string(),
+                password: "password1".to_string(),
+            })
+            .await;
+
+        assert_eq!(Err(UserApplicationError::UserNotFound(user_id)), result);
+        Ok(())
+    }
+
+    async fn authenticate(
+        repository: &Arc<InMemoryCredentialRepository>,
+        now: DateTime<Utc>,
+        user_id: &UserId,
+        password: &str,
+    ) -> std::result::Result<UserId, UserApplicationError> {
+        UserAuthenticateApplicationService::with_clock(
+            repository.clone(),
+            Arc::new(FixedClock(now)),
+        )
+        .handle(UserAuthenticateCommand {
+            user_id: user_id.to_string(),
+            password: password.to_string(),
+        })
+        .await
+    }
+
+    #[tokio::test]
+    async fn should_lock_account_after_five_failed_logins() -> Result<()> {
+        let repository = Arc::new(InMemoryCredentialRepository::new());
+        let user_id = UserId::new(Uuid::new_v4())?;
+        repository
+            .save(&PasswordCredential::new(user_id.clone(), "password1")?)
+            .await?;
+        let now = Utc::now();
+
+        for _ in 0..5 {
+            let result = authenticate(&repository, now, &user_id, "password2").await;
+            assert_eq!(Err(UserApplicationError::PasswordMismatch), result);
+        }
+
+        // 6 回目は正しいパスワードでも拒否される
+        let until = now + Duration::minutes(15);
+        let result = authenticate(&repository, now, &user_id, "password1").await;
+        assert_eq!(Err(UserApplicationError::AccountLocked { until }), result);
+
+        // ロックが解除されればログインでき、間違えた回数も元に戻る
+        let result = authenticate(&repository, until, &user_id, "password1").await;
+        assert_eq!(Ok(user_id.clone()), result);
+        let credential = repository.find_by_user_id(&user_id).await?.unwrap();
+        assert_eq!(0, credential.failed_login_attempts());
+        assert_eq!(None, credential.locked_until());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_reset_failed_login_attempts_on_success() -> Result<()> {
+        let repository = Arc::new(InMemoryCredentialRepository::new());
+        let user_id = UserId::new(Uuid::new_v4())?;
+        repository
+            .save(&PasswordCredential::new(user_id.clone(), "password1")?)
+            .await?;
+        let now = Utc::now();
+
+        // 途中で成功すれば、続けて間違えたことにはならない
+        for _ in 0..4 {
+            authenticate(&repository, now, &user_id, "password2")
+                .await
+                .ok();
+        }
+        authenticate(&repository, now, &user_id, "password1").await?;
+        for _ in 0..4 {
+            authenticate(&repository, now, &user_id, "password2")
+                .await
+                .ok();
+        }
+
+        let result = authenticate(&repository, now, &user_id, "password1").await;
+        assert_eq!(Ok(user_id), result);
+        Ok(())
+    }
+}
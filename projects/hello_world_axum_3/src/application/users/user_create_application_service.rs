@@ -1,13 +1,18 @@
 use std::sync::Arc;
 
 use axum::async_trait;
+use serde::Deserialize;
 
-use super::{user_data::UserData, Result};
+use super::{
+    user_data::{UserData, UserDataView},
+    view_user_data, Result,
+};
 
 use crate::domain::{
-    models::users::{user::User, user_name::UserName, user_repository::IUserRepository},
-    services::user_service::UserService,
-    value_object::ValueObject,
+    models::users::{
+        user::User, user_id::UserId, user_name::UserName, user_repository::IUserRepository,
+    },
+    services::profanity_filter::ProfanityFilter,
 };
 
 use super::user_application_error::UserApplicationError;
@@ -15,64 +20,119 @@ use super::user_application_error::UserApplicationError;
 // trait of application service to create user
 #[async_trait]
 pub trait IUserCreateApplicationService<T: IUserRepository> {
-    fn new(user_repository: Arc<T>) -> Self;
-    async fn handle(&self, command: UserCreateCommand) -> Result<UserData>;
+    fn new(user_repository: Arc<T>, profanity_filter: Arc<ProfanityFilter>) -> Self;
+    async fn handle(&self, command: UserCreateCommand) -> Result<UserCreateResult>;
+}
+
+// 同じ名前のユーザーが既に存在する場合の扱い
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IfExists {
+    // DuplicatedUser を返す
+    #[default]
+    Fail,
+    // 既存のユーザーをそのまま返す
+    Return,
+    // 既存のユーザーをコマンドの値で更新して返す
+    Update,
 }
 
 // command object
 pub struct UserCreateCommand {
     pub user_name: String,
+    pub if_exists: IfExists,
+    // 情報を求めたユーザー。本人か管理者であればすべての項目を、それ以外には id と名前だけを返す
+    pub requested_by: Option<UserId>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct UserCreateResult {
+    pub user_data: UserDataView,
+    // 新しく作成した場合は true、既存のユーザーを返した場合は false
+    pub created: bool,
 }
 
 // impl of application service to create user
 pub struct UserCreateApplicationService<T: IUserRepository> {
     user_repository: Arc<T>,
-    user_service: UserService<T>,
+    profanity_filter: Arc<ProfanityFilter>,
 }
 
 #[async_trait]
 impl<T: IUserRepository> IUserCreateApplicationService<T> for UserCreateApplicationService<T> {
-    fn new(user_repository: Arc<T>) -> Self {
+    fn new(user_repository: Arc<T>, profanity_filter: Arc<ProfanityFilter>) -> Self {
         Self {
-            user_repository: user_repository.clone(),
-            user_service: UserService::new(user_repository),
+            user_repository,
+            profanity_filter,
         }
     }
 
-    async fn handle(&self, command: UserCreateCommand) -> Result<UserData> {
+    async fn handle(&self, command: UserCreateCommand) -> Result<UserCreateResult> {
         let UserCreateCommand {
             user_name: user_name_string,
+            if_exists,
+            requested_by,
         } = command;
-        let user_name = UserName::new(user_name_string)
+        let user_name = user_name_string
+            .parse::<UserName>()
             .map_err(|e| UserApplicationError::IllegalArgumentError(e.to_string()))?;
+        if self.profanity_filter.contains_profanity(&user_name) {
+            return Err(UserApplicationError::IllegalArgumentError(
+                "User name contains prohibited words.".to_string(),
+            ));
+        }
         let new_user =
             User::new(user_name).map_err(|e| UserApplicationError::Unexpected(e.to_string()))?;
 
-        if self
-            .user_service
-            .is_duplicated(&new_user)
-            .await
-            .map_err(|e| UserApplicationError::Unexpected(e.to_string()))?
-        {
-            return Err(UserApplicationError::DuplicatedUser(new_user).into());
-        }
-
-        self.user_repository
-            .save(&new_user)
-            .await
-            .map_err(|e| UserApplicationError::Unexpected(e.to_string()))?;
+        let user_found = self
+            .user_repository
+            .find_by_name(&new_user.user_name)
+            .await?;
+
+        let (user, created) = match (user_found, if_exists) {
+            (None, _) => (new_user, true),
+            (Some(_), IfExists::Fail) => {
+                return Err(UserApplicationError::DuplicatedUser(new_user))
+            }
+            (Some(user_found), IfExists::Return) => {
+                return Ok(UserCreateResult {
+                    user_data: view_user_data(
+                        self.user_repository.as_ref(),
+                        UserData::new(user_found),
+                        requested_by.as_ref(),
+                    )
+                    .await?,
+                    created: false,
+                })
+            }
+            // 作成時に指定できる項目は名前だけなので、今のところ名前を書き戻すだけになる
+            (Some(mut user_found), IfExists::Update) => {
+                user_found.user_name = new_user.user_name;
+                (user_found, false)
+            }
+        };
 
-        Ok(UserData::new(new_user))
+        self.user_repository.save(&user).await?;
+
+        Ok(UserCreateResult {
+            user_data: view_user_data(
+                self.user_repository.as_ref(),
+                UserData::new(user),
+                requested_by.as_ref(),
+            )
+            .await?,
+            created,
+        })
     }
 }
 
 #[cfg(test)]
 mod tests {
     use anyhow::Result;
-    use uuid::Uuid;
 
     use crate::{
-        domain::models::users::user_id::UserId,
+        application::users::user_data::UserDataPublic,
+        domain::{models::users::user_id::UserId, value_object::ValueObject},
         infra::repository_impl::in_memory::users::in_memory_user_repository::InMemoryUserRepository,
     };
 
@@ -81,40 +141,90 @@ mod tests {
     #[tokio::test]
     async fn test_success_min_user_name() -> Result<()> {
         let repository = Arc::new(InMemoryUserRepository::new());
-        let user_create_application_service = UserCreateApplicationService::new(repository.clone());
+        let user_create_application_service =
+            UserCreateApplicationService::new(repository.clone(), Arc::new(ProfanityFilter::new()));
 
         // Is it possible to enter a 3-letter name?
         let command = UserCreateCommand {
             user_name: "123".to_string(),
+            if_exists: IfExists::Fail,
+            requested_by: None,
         };
-        let user_data = user_create_application_service.handle(command).await?;
+        let user_data = user_create_application_service
+            .handle(command)
+            .await?
+            .user_data;
 
-        assert_eq!("123", user_data.user_name);
+        assert_eq!("123", user_data.user_name());
 
         // get user saved in store
-        let store = repository.read_store_ref();
-        let stored_user = store.get(&UserId::new(user_data.user_id)?).unwrap();
+        let stored_user = repository
+            .find(&UserId::new(*user_data.user_id())?)
+            .await?
+            .unwrap();
 
         assert_eq!("123", stored_user.user_name.value());
         Ok(())
     }
 
+    #[tokio::test]
+    async fn should_reject_user_name_with_prohibited_word() -> Result<()> {
+        let repository = Arc::new(InMemoryUserRepository::new());
+        let user_create_application_service =
+            UserCreateApplicationService::new(repository.clone(), Arc::new(ProfanityFilter::new()));
+
+        let command = UserCreateCommand {
+            user_name: "big idiot".to_string(),
+            if_exists: IfExists::Fail,
+            requested_by: None,
+        };
+        let result = user_create_application_service.handle(command).await;
+        assert_eq!(
+            Err(UserApplicationError::IllegalArgumentError(
+                "User name contains prohibited words.".to_string()
+            )),
+            result
+        );
+        assert!(repository.find_all().await?.is_empty());
+
+        // 一覧の語を含まない名前は作成できる
+        let command = UserCreateCommand {
+            user_name: "big tester".to_string(),
+            if_exists: IfExists::Fail,
+            requested_by: None,
+        };
+        let user_data = user_create_application_service
+            .handle(command)
+            .await?
+            .user_data;
+        assert_eq!("big tester", user_data.user_name());
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_success_max_user_name() -> Result<()> {
         let repository = Arc::new(InMemoryUserRepository::new());
-        let user_create_application_service = UserCreateApplicationService::new(repository.clone());
+        let user_create_application_service =
+            UserCreateApplicationService::new(repository.clone(), Arc::new(ProfanityFilter::new()));
 
         // Is it possible to enter a 19-letter name?
         let command = UserCreateCommand {
             user_name: "1234567890123456789".to_string(),
+            if_exists: IfExists::Fail,
+            requested_by: None,
         };
-        let user_data = user_create_application_service.handle(command).await?;
+        let user_data = user_create_application_service
+            .handle(command)
+            .await?
+            .user_data;
 
-        assert_eq!("1234567890123456789", user_data.user_name);
+        assert_eq!("1234567890123456789", user_data.user_name());
 
         // get user saved in store
-        let store = repository.read_store_ref();
-        let stored_user = store.get(&UserId::new(user_data.user_id)?).unwrap();
+        let stored_user = repository
+            .find(&UserId::new(*user_data.user_id())?)
+            .await?
+            .unwrap();
 
         assert_eq!("1234567890123456789", stored_user.user_name.value());
         Ok(())
@@ -123,11 +233,14 @@ mod tests {
     #[tokio::test]
     async fn should_throw_error_if_user_name_is_too_short() -> Result<()> {
         let repository = Arc::new(InMemoryUserRepository::new());
-        let user_create_application_service = UserCreateApplicationService::new(repository.clone());
+        let user_create_application_service =
+            UserCreateApplicationService::new(repository.clone(), Arc::new(ProfanityFilter::new()));
 
         // Is it possible to enter a 2-letter name?
         let command = UserCreateCommand {
             user_name: "12".to_string(),
+            if_exists: IfExists::Fail,
+            requested_by: None,
         };
         let user_data = user_create_application_service.handle(command).await;
 
@@ -144,11 +257,14 @@ mod tests {
     #[tokio::test]
     async fn should_throw_error_if_user_name_is_too_long() -> Result<()> {
         let repository = Arc::new(InMemoryUserRepository::new());
-        let user_create_application_service = UserCreateApplicationService::new(repository.clone());
+        let user_create_application_service =
+            UserCreateApplicationService::new(repository.clone(), Arc::new(ProfanityFilter::new()));
 
         // Is it possible to enter a 20-letter name?
         let command = UserCreateCommand {
             user_name: "12345678901234567890".to_string(),
+            if_exists: IfExists::Fail,
+            requested_by: None,
         };
         let user_data = user_create_application_service.handle(command).await;
 
@@ -167,27 +283,93 @@ mod tests {
         let repository = Arc::new(InMemoryUserRepository::new());
 
         // Put the data in advance
-        {
-            let mut store = repository.write_store_ref();
-            store.insert(
-                UserId::new(Uuid::new_v4())?,
-                User::new(UserName::new("tester-1".to_string())?)?,
-            );
-        }
+        repository
+            .save(&User::new(UserName::new("tester-1".to_string())?)?)
+            .await?;
 
-        let user_create_application_service = UserCreateApplicationService::new(repository.clone());
+        let user_create_application_service =
+            UserCreateApplicationService::new(repository.clone(), Arc::new(ProfanityFilter::new()));
 
         // Attempt to insert duplicate data
         let command = UserCreateCommand {
             user_name: "tester-1".to_string(),
+            if_exists: IfExists::Fail,
+            requested_by: None,
         };
         let user_data = user_create_application_service.handle(command).await;
 
-        assert!(matches!(
-            user_data,
-            Err(UserApplicationError::DuplicatedUser(_))
-        ));
+        // 新しく作られたユーザーの id は分からないので、中身を取り出して比較する
+        let Err(UserApplicationError::DuplicatedUser(user)) = user_data else {
+            panic!("unexpected result: {:?}", user_data);
+        };
+        assert_eq!("tester-1", user.user_name.value());
 
         Ok(())
     }
+
+    async fn user_create_service_with_user(
+        user_name: &str,
+    ) -> Result<(
+        User,
+        Arc<InMemoryUserRepository>,
+        UserCreateApplicationService<InMemoryUserRepository>,
+    )> {
+        let repository = Arc::new(InMemoryUserRepository::new());
+        let user = User::new(UserName::new(user_name.to_string())?)?;
+        repository.save(&user).await?;
+        let user_create_application_service =
+            UserCreateApplicationService::new(repository.clone(), Arc::new(ProfanityFilter::new()));
+        Ok((user, repository, user_create_application_service))
+    }
+
+    #[tokio::test]
+    async fn should_return_existing_user_if_asked_to() -> Result<()> {
+        let (user, repository, user_create_application_service) =
+            user_create_service_with_user("tester-1").await?;
+
+        let command = UserCreateCommand {
+            user_name: "tester-1".to_string(),
+            if_exists: IfExists::Return,
+            requested_by: None,
+        };
+        let result = user_create_application_service.handle(command).await?;
+
+        assert_eq!(
+            UserCreateResult {
+                user_data: UserDataView::Public(UserDataPublic::new(user)),
+                created: false,
+            },
+            result
+        );
+        assert_eq!(1, repository.find_all().await?.len());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_update_existing_user_if_asked_to() -> Result<()> {
+        let (user, repository, user_create_application_service) =
+            user_create_service_with_user("tester-1").await?;
+
+        let command = UserCreateCommand {
+            user_name: "tester-1".to_string(),
+            if_exists: IfExists::Update,
+            requested_by: None,
+        };
+        let result = user_create_application_service.handle(command).await?;
+
+        assert!(!result.created);
+        assert_eq!(user.user_id().value(), result.user_data.user_id());
+        assert_eq!("tester-1", result.user_data.user_name());
+        assert_eq!(1, repository.find_all().await?.len());
+        assert_eq!(
+            "tester-1",
+            repository
+                .find(user.user_id())
+                .await?
+                .unwrap()
+                .user_name
+                .value()
+        );
+        Ok(())
+    }
 }
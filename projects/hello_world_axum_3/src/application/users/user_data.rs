@@ -1,21 +1,107 @@
+use std::hash::{Hash, Hasher};
+
 use serde::Serialize;
 use uuid::Uuid;
 
 use crate::domain::{models::users::user::User, value_object::ValueObject};
 
-#[derive(Serialize, PartialEq, Debug)]
+#[derive(Serialize, Debug)]
 pub struct UserData {
     pub user_id: Uuid,
     pub user_name: String,
+    pub user_role: String,
+    // 担当している todo のうち完了済みのものの割合。求められた場合だけ設定する
+    pub completion_rate: Option<f64>,
+    // 今日まで todo を完了した日が何日続いているか。求められた場合だけ設定する
+    pub current_streak: Option<u32>,
 }
 
 impl UserData {
     pub fn new(user: User) -> Self {
         let user_id = user.user_id().clone().into_value();
-        let User { user_name, .. } = user;
+        let User {
+            user_name,
+            user_role,
+            ..
+        } = user;
         Self {
             user_id,
             user_name: user_name.into_value(),
+            user_role: user_role.to_string(),
+            completion_rate: None,
+            current_streak: None,
+        }
+    }
+}
+
+impl PartialEq for UserData {
+    fn eq(&self, other: &Self) -> bool {
+        self.user_id == other.user_id
+    }
+}
+
+impl Eq for UserData {}
+
+impl Hash for UserData {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.user_id.hash(state);
+    }
+}
+
+// 本人と管理者以外に見せる項目
+#[derive(Serialize, Debug, PartialEq, Eq)]
+pub struct UserDataPublic {
+    pub user_id: Uuid,
+    pub user_name: String,
+}
+
+impl UserDataPublic {
+    pub fn new(user: User) -> Self {
+        let user_id = user.user_id().clone().into_value();
+        Self {
+            user_id,
+            user_name: user.user_name.into_value(),
+        }
+    }
+}
+
+impl From<UserData> for UserDataPublic {
+    fn from(user_data: UserData) -> Self {
+        Self {
+            user_id: user_data.user_id,
+            user_name: user_data.user_name,
+        }
+    }
+}
+
+// 情報を求めたユーザーによって、見せる項目を変える
+#[derive(Serialize, Debug, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum UserDataView {
+    Public(UserDataPublic),
+    Full(UserData),
+}
+
+impl UserDataView {
+    pub fn user_id(&self) -> &Uuid {
+        match self {
+            Self::Public(user_data) => &user_data.user_id,
+            Self::Full(user_data) => &user_data.user_id,
+        }
+    }
+
+    pub fn user_name(&self) -> &str {
+        match self {
+            Self::Public(user_data) => &user_data.user_name,
+            Self::Full(user_data) => &user_data.user_name,
+        }
+    }
+
+    // すべての項目を見せる場合のみ `Some` を返す
+    pub fn into_full(self) -> Option<UserData> {
+        match self {
+            Self::Public(_) => None,
+            Self::Full(user_data) => Some(user_data),
         }
     }
 }
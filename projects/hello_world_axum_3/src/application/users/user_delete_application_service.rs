@@ -1,42 +1,85 @@
 use std::sync::Arc;
 
 use axum::async_trait;
+use chrono::Utc;
 
-use super::Result;
+use super::{verify_password, Result};
 
-use crate::domain::models::users::{
-    user_id::UserId,
-    user_repository::{IUserRepository, UserRepositoryError},
+use crate::domain::models::{
+    credentials::credential_repository::ICredentialRepository,
+    users::{user::User, user_id::UserId, user_repository::IUserRepository, user_role::UserRole},
 };
 
 use super::user_application_error::UserApplicationError;
 
 // trait of application service to delete user
 #[async_trait]
-pub trait IUserDeleteApplicationService<T: IUserRepository> {
-    fn new(user_repository: Arc<T>) -> Self;
+pub trait IUserDeleteApplicationService<T: IUserRepository, CredentialRep: ICredentialRepository> {
+    fn new(user_repository: Arc<T>, credential_repository: Arc<CredentialRep>) -> Self;
     async fn handle(&self, command: UserDeleteCommand) -> Result<()>;
+    // 物理削除の代わりに、名前を匿名化して論理削除する
+    // 担当していた todo は残したまま未割り当てに戻す
+    async fn soft_delete_with_anonymization(&self, command: UserDeleteCommand) -> Result<()>;
 }
 
 // command object
 pub struct UserDeleteCommand {
     pub user_id: String,
+    pub password_confirmation: String,
+    // 削除を求めたユーザー。本人か管理者でなければ削除できない
+    // 管理者による削除の場合はパスワードの確認を省略する
+    pub requested_by: UserId,
 }
 
 // impl of application service to delete user
-pub struct UserDeleteApplicationService<T: IUserRepository> {
+pub struct UserDeleteApplicationService<T: IUserRepository, CredentialRep: ICredentialRepository> {
     user_repository: Arc<T>,
+    credential_repository: Arc<CredentialRep>,
 }
 
 #[async_trait]
-impl<T: IUserRepository> IUserDeleteApplicationService<T> for UserDeleteApplicationService<T> {
-    fn new(user_repository: Arc<T>) -> Self {
-        Self { user_repository }
+impl<T, CredentialRep> IUserDeleteApplicationService<T, CredentialRep>
+    for UserDeleteApplicationService<T, CredentialRep>
+where
+    T: IUserRepository,
+    CredentialRep: ICredentialRepository,
+{
+    fn new(user_repository: Arc<T>, credential_repository: Arc<CredentialRep>) -> Self {
+        Self {
+            user_repository,
+            credential_repository,
+        }
     }
 
     async fn handle(&self, command: UserDeleteCommand) -> Result<()> {
+        if self.is_admin(&command.requested_by).await? {
+            // 管理者は論理削除したユーザーも含めて物理削除できる
+            let user_id = UserId::parse(command.user_id)
+                .map_err(|e| UserApplicationError::IllegalUserId(e.to_string()))?;
+            return Ok(self.user_repository.hard_delete(&user_id).await?);
+        }
+        let user = self.find_user_to_delete(command).await?;
+        Ok(self.user_repository.delete(user).await?)
+    }
+
+    async fn soft_delete_with_anonymization(&self, command: UserDeleteCommand) -> Result<()> {
+        let mut user = self.find_user_to_delete(command).await?;
+        user.anonymize(Utc::now());
+        Ok(self.user_repository.soft_delete(&user).await?)
+    }
+}
+
+impl<T, CredentialRep> UserDeleteApplicationService<T, CredentialRep>
+where
+    T: IUserRepository,
+    CredentialRep: ICredentialRepository,
+{
+    // 削除するユーザーを探し、本人からの削除であればパスワードを確認する
+    async fn find_user_to_delete(&self, command: UserDeleteCommand) -> Result<User> {
         let UserDeleteCommand {
             user_id: user_id_string,
+            password_confirmation,
+            requested_by,
         } = command;
         let user_id = UserId::parse(user_id_string)
             .map_err(|e| UserApplicationError::IllegalUserId(e.to_string()))?;
@@ -44,21 +87,41 @@ impl<T: IUserRepository> IUserDeleteApplicationService<T> for UserDeleteApplicat
         let user = self
             .user_repository
             .find(&user_id)
-            .await
-            .map_err(|e| UserApplicationError::Unexpected(e.to_string()))?
-            .ok_or(UserApplicationError::UserNotFound(user_id))?;
+            .await?
+            .ok_or(UserApplicationError::UserNotFound(user_id.clone()))?;
+
+        // 本人以外で削除できるのは管理者だけ
+        if &requested_by != user.user_id() {
+            if self.is_admin(&requested_by).await? {
+                return Ok(user);
+            }
+            return Err(UserApplicationError::PermissionDenied(user_id));
+        }
 
-        self.user_repository
-            .delete(user)
+        // パスワードが登録されていないユーザーは確認するパスワードがないため、そのまま削除する
+        // ログインと同じく、続けて間違えるとロックする
+        let credential = self
+            .credential_repository
+            .find_by_user_id(user.user_id())
             .await
-            .map_err(|e| match e {
-                UserRepositoryError::NotFound(user_id) => {
-                    UserApplicationError::UserNotFound(user_id)
-                }
-                UserRepositoryError::Unexpected(e) => {
-                    UserApplicationError::Unexpected(e.to_string())
-                }
-            })
+            .map_err(|e| UserApplicationError::Unexpected(e.to_string()))?;
+        if let Some(mut credential) = credential {
+            verify_password(
+                self.credential_repository.as_ref(),
+                &mut credential,
+                &password_confirmation,
+                Utc::now(),
+            )
+            .await?;
+        }
+
+        Ok(user)
+    }
+
+    // 認証後に削除されたユーザーは、権限がないものとして扱う
+    async fn is_admin(&self, user_id: &UserId) -> Result<bool> {
+        let user = self.user_repository.find(user_id).await?;
+        Ok(user.is_some_and(|user| user.user_role == UserRole::Admin))
     }
 }
 
@@ -70,54 +133,110 @@ mod tests {
     use uuid::Uuid;
 
     use super::*;
+
+    // 論理削除したユーザーも含めて、ストアに残っているユーザー
+    fn stored_users(repository: &InMemoryUserRepository) -> Vec<User> {
+        let mut users = vec![];
+        repository.for_each_user(|_, user| users.push(user.clone()));
+        users
+    }
     use crate::{
-        domain::{models::users::{user::User, user_name::UserName}, value_object::ValueObject},
-        infra::repository_impl::in_memory::users::in_memory_user_repository::InMemoryUserRepository,
+        domain::models::credentials::password_credential::PasswordCredential,
+        domain::{
+            models::users::{user::User, user_name::UserName},
+            value_object::ValueObject,
+        },
+        infra::repository_impl::in_memory::{
+            credentials::in_memory_credential_repository::InMemoryCredentialRepository,
+            todos::in_memory_todo_repository::InMemoryTodoRepository,
+            users::in_memory_user_repository::InMemoryUserRepository,
+        },
     };
 
     #[tokio::test]
     async fn should_delete_user() -> Result<()> {
         let repository = Arc::new(InMemoryUserRepository::new());
+        let credential_repository = Arc::new(InMemoryCredentialRepository::new());
 
         let user = User::new(UserName::new("tester-1".to_string())?)?;
         let user_id = user.user_id().clone();
 
         // Put the data in advance
-        {
-            let mut store = repository.write_store_ref();
-            store.insert(user_id.clone(), user);
-        }
+        repository.save(&user).await?;
 
         // Delete stored user
-        let user_delete_application_service = UserDeleteApplicationService::new(repository.clone());
+        let user_delete_application_service =
+            UserDeleteApplicationService::new(repository.clone(), credential_repository.clone());
         let command = UserDeleteCommand {
             user_id: user_id.value().to_string(),
+            password_confirmation: String::new(),
+            requested_by: user_id.clone(),
         };
         user_delete_application_service.handle(command).await?;
 
         // check the store is empty
-        {
-            let store = repository.read_store_ref();
-            assert!(store.is_empty());
-        }
+        assert!(stored_users(&repository).is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_unassign_todos_of_deleted_user() -> Result<()> {
+        use crate::domain::models::todos::{
+            todo::Todo, todo_repository::ITodoRepository, todo_text::TodoText,
+        };
+
+        let todo_repository = InMemoryTodoRepository::new();
+        let repository = Arc::new(InMemoryUserRepository::with_todo_repository(
+            todo_repository.clone(),
+        ));
+        let credential_repository = Arc::new(InMemoryCredentialRepository::new());
+
+        let user = User::new(UserName::new("tester-1".to_string())?)?;
+        let user_id = user.user_id().clone();
+        repository.save(&user).await?;
+        let mut todo = Todo::new(TodoText::new("assigned".to_string())?, vec![])?;
+        todo.assignee_id = Some(user_id.clone());
+        todo_repository.save(&todo).await?;
+
+        let user_delete_application_service =
+            UserDeleteApplicationService::new(repository.clone(), credential_repository.clone());
+        let command = UserDeleteCommand {
+            user_id: user_id.value().to_string(),
+            password_confirmation: String::new(),
+            requested_by: user_id.clone(),
+        };
+        user_delete_application_service.handle(command).await?;
+
+        // DB の `on delete set null` と同じく、todo は残り担当者だけが外れる
+        assert!(repository.find_all().await?.is_empty());
+        let todo_found = todo_repository.find(todo.todo_id()).await?.unwrap();
+        assert_eq!(None, todo_found.assignee_id);
         Ok(())
     }
 
     #[tokio::test]
     async fn should_throw_error_if_user_id_has_incorrect_format() -> Result<()> {
         let repository = Arc::new(InMemoryUserRepository::new());
+        let credential_repository = Arc::new(InMemoryCredentialRepository::new());
 
         // try to delete user with illegal-formated user-id
-        let user_delete_application_service = UserDeleteApplicationService::new(repository.clone());
+        let user_delete_application_service =
+            UserDeleteApplicationService::new(repository.clone(), credential_repository.clone());
         let command = UserDeleteCommand {
             user_id: "incorrect-user-id".to_string(),
+            password_confirmation: String::new(),
+            requested_by: UserId::new(Uuid::new_v4())?,
         };
         let result_of_user_delete = user_delete_application_service.handle(command).await;
 
-        assert!(matches!(
+        assert_eq!(
             result_of_user_delete,
-            Err(UserApplicationError::IllegalUserId(_))
-        ));
+            Err(UserApplicationError::IllegalUserId(
+                UserId::parse("incorrect-user-id".to_string())
+                    .unwrap_err()
+                    .to_string()
+            ))
+        );
 
         Ok(())
     }
@@ -125,19 +244,284 @@ mod tests {
     #[tokio::test]
     async fn should_throw_error_if_target_user_does_not_exist() -> Result<()> {
         let repository = Arc::new(InMemoryUserRepository::new());
+        let credential_repository = Arc::new(InMemoryCredentialRepository::new());
 
         // try to delete user which does not exist
-        let user_delete_application_service = UserDeleteApplicationService::new(repository.clone());
+        let user_delete_application_service =
+            UserDeleteApplicationService::new(repository.clone(), credential_repository.clone());
+        let user_id = Uuid::new_v4();
         let command = UserDeleteCommand {
-            user_id: Uuid::new_v4().to_string(),
+            user_id: user_id.to_string(),
+            password_confirmation: String::new(),
+            requested_by: UserId::new(user_id)?,
         };
         let result_of_user_delete = user_delete_application_service.handle(command).await;
 
-        assert!(matches!(
+        assert_eq!(
             result_of_user_delete,
-            Err(UserApplicationError::UserNotFound(_))
+            Err(UserApplicationError::UserNotFound(UserId::new(user_id)?))
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_delete_user_with_correct_password() -> Result<()> {
+        let repository = Arc::new(InMemoryUserRepository::new());
+        let credential_repository = Arc::new(InMemoryCredentialRepository::new());
+
+        let user = User::new(UserName::new("tester-1".to_string())?)?;
+        let user_id = user.user_id().clone();
+
+        // Put the data in advance
+        repository.save(&user).await?;
+        {
+            let mut store = credential_repository.write_store_ref();
+            store.insert(
+                user_id.clone(),
+                PasswordCredential::new(user_id.clone(), "password1")?,
+            );
+        }
+
+        // Delete stored user with correct password
+        let user_delete_application_service =
+            UserDeleteApplicationService::new(repository.clone(), credential_repository.clone());
+        let command = UserDeleteCommand {
+            user_id: user_id.value().to_string(),
+            password_confirmation: "password1".to_string(),
+            requested_by: user_id.clone(),
+        };
+        user_delete_application_service.handle(command).await?;
+
+        // check the store is empty
+        assert!(stored_users(&repository).is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_throw_error_if_password_does_not_match() -> Result<()> {
+        let repository = Arc::new(InMemoryUserRepository::new());
+        let credential_repository = Arc::new(InMemoryCredentialRepository::new());
+
+        let user = User::new(UserName::new("tester-1".to_string())?)?;
+        let user_id = user.user_id().clone();
+
+        // Put the data in advance
+        repository.save(&user).await?;
+        {
+            let mut store = credential_repository.write_store_ref();
+            store.insert(
+                user_id.clone(),
+                PasswordCredential::new(user_id.clone(), "password1")?,
+            );
+        }
+
+        // Try to delete stored user with incorrect password
+        let user_delete_application_service =
+            UserDeleteApplicationService::new(repository.clone(), credential_repository.clone());
+        let command = UserDeleteCommand {
+            user_id: user_id.value().to_string(),
+            password_confirmation: "password2".to_string(),
+            requested_by: user_id.clone(),
+        };
+        let result_of_user_delete = user_delete_application_service.handle(command).await;
+
+        assert_eq!(
+            Err(UserApplicationError::PasswordMismatch),
+            result_of_user_delete
+        );
+
+        // check the user is not deleted
+        assert!(repository.find(&user_id).await?.is_some());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_lock_after_five_wrong_password_confirmations() -> Result<()> {
+        let repository = Arc::new(InMemoryUserRepository::new());
+        let credential_repository = Arc::new(InMemoryCredentialRepository::new());
+
+        let user = User::new(UserName::new("tester-1".to_string())?)?;
+        let user_id = user.user_id().clone();
+
+        // Put the data in advance
+        repository.save(&user).await?;
+        credential_repository
+            .save(&PasswordCredential::new(user_id.clone(), "password1")?)
+            .await?;
+
+        let user_delete_application_service =
+            UserDeleteApplicationService::new(repository.clone(), credential_repository.clone());
+        let command = |password_confirmation: &str| UserDeleteCommand {
+            user_id: user_id.value().to_string(),
+            password_confirmation: password_confirmation.to_string(),
+            requested_by: user_id.clone(),
+        };
+        for _ in 0..5 {
+            let result = user_delete_application_service
+                .handle(command("password2"))
+                .await;
+            assert_eq!(Err(UserApplicationError::PasswordMismatch), result);
+        }
+
+        // ロック中は正しいパスワードでも削除できない
+        let result = user_delete_application_service
+            .handle(command("password1"))
+            .await;
+        assert!(matches!(
+            result,
+            Err(UserApplicationError::AccountLocked { .. })
         ));
+        assert!(repository.find(&user_id).await?.is_some());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_delete_user_without_password_if_requested_by_admin() -> Result<()> {
+        let repository = Arc::new(InMemoryUserRepository::new());
+        let credential_repository = Arc::new(InMemoryCredentialRepository::new());
+
+        let user = User::new(UserName::new("tester-1".to_string())?)?;
+        let user_id = user.user_id().clone();
+
+        let mut admin = User::new(UserName::new("admin-1".to_string())?)?;
+        admin.user_role = UserRole::Admin;
+        let admin_id = admin.user_id().clone();
 
+        // Put the data in advance
+        repository.save(&user).await?;
+        repository.save(&admin).await?;
+        {
+            let mut store = credential_repository.write_store_ref();
+            store.insert(
+                user_id.clone(),
+                PasswordCredential::new(user_id.clone(), "password1")?,
+            );
+        }
+
+        // Delete stored user as admin without password
+        let user_delete_application_service =
+            UserDeleteApplicationService::new(repository.clone(), credential_repository.clone());
+        let command = UserDeleteCommand {
+            user_id: user_id.value().to_string(),
+            password_confirmation: String::new(),
+            requested_by: admin_id.clone(),
+        };
+        user_delete_application_service.handle(command).await?;
+
+        // check only the admin remains
+        let stored_user_ids: Vec<UserId> = stored_users(&repository)
+            .iter()
+            .map(|user| user.user_id().clone())
+            .collect();
+        assert_eq!(vec![admin_id], stored_user_ids);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_not_delete_another_user_unless_requested_by_admin() -> Result<()> {
+        let repository = Arc::new(InMemoryUserRepository::new());
+        let credential_repository = Arc::new(InMemoryCredentialRepository::new());
+
+        // パスワードを登録していないユーザーでも、本人以外は削除できない
+        let user = User::new(UserName::new("tester-1".to_string())?)?;
+        let user_id = user.user_id().clone();
+        let other_user = User::new(UserName::new("tester-2".to_string())?)?;
+        let other_user_id = other_user.user_id().clone();
+        repository.save(&user).await?;
+        repository.save(&other_user).await?;
+
+        let user_delete_application_service =
+            UserDeleteApplicationService::new(repository.clone(), credential_repository.clone());
+        for anonymize in [false, true] {
+            let command = UserDeleteCommand {
+                user_id: user_id.value().to_string(),
+                password_confirmation: String::new(),
+                requested_by: other_user_id.clone(),
+            };
+            let result_of_user_delete = if anonymize {
+                user_delete_application_service
+                    .soft_delete_with_anonymization(command)
+                    .await
+            } else {
+                user_delete_application_service.handle(command).await
+            };
+
+            assert_eq!(
+                Err(UserApplicationError::PermissionDenied(user_id.clone())),
+                result_of_user_delete
+            );
+        }
+        assert_eq!(Some(user), repository.find(&user_id).await?);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_anonymize_user_and_keep_assigned_todos() -> Result<()> {
+        use crate::domain::models::todos::{
+            todo::Todo, todo_repository::ITodoRepository, todo_text::TodoText,
+        };
+
+        let todo_repository = InMemoryTodoRepository::new();
+        let repository = Arc::new(InMemoryUserRepository::with_todo_repository(
+            todo_repository.clone(),
+        ));
+        let credential_repository = Arc::new(InMemoryCredentialRepository::new());
+
+        let user = User::new(UserName::new("tester-1".to_string())?)?;
+        let user_id = user.user_id().clone();
+        repository.save(&user).await?;
+        let mut todo = Todo::new(TodoText::new("assigned".to_string())?, vec![])?;
+        todo.assignee_id = Some(user_id.clone());
+        todo_repository.save(&todo).await?;
+
+        let user_delete_application_service =
+            UserDeleteApplicationService::new(repository.clone(), credential_repository.clone());
+        let command = UserDeleteCommand {
+            user_id: user_id.value().to_string(),
+            password_confirmation: String::new(),
+            requested_by: user_id.clone(),
+        };
+        user_delete_application_service
+            .soft_delete_with_anonymization(command)
+            .await?;
+
+        // 匿名化した状態で残る
+        let user_deleted = stored_users(&repository).pop().unwrap();
+        assert_eq!(&user_id, user_deleted.user_id());
+        assert!(user_deleted.user_name.value().starts_with("deleted_user_"));
+        assert!(user_deleted.deleted_at().is_some());
+        // todo は残り、担当者だけが外れる
+        let todo_found = todo_repository.find(todo.todo_id()).await?.unwrap();
+        assert_eq!(None, todo_found.assignee_id);
+        // 一覧や検索には現れない
+        assert!(repository.find_all().await?.is_empty());
+        assert_eq!(None, repository.find(&user_id).await?);
+
+        // 論理削除したユーザーをもう一度削除することはできないが、管理者は物理削除できる
+        let command = UserDeleteCommand {
+            user_id: user_id.value().to_string(),
+            password_confirmation: String::new(),
+            requested_by: user_id.clone(),
+        };
+        assert_eq!(
+            Err(UserApplicationError::UserNotFound(user_id.clone())),
+            user_delete_application_service
+                .soft_delete_with_anonymization(command)
+                .await
+        );
+        let mut admin = User::new(UserName::new("admin-1".to_string())?)?;
+        admin.user_role = UserRole::Admin;
+        repository.save(&admin).await?;
+        let command = UserDeleteCommand {
+            user_id: user_id.value().to_string(),
+            password_confirmation: String::new(),
+            requested_by: admin.user_id().clone(),
+        };
+        user_delete_application_service.handle(command).await?;
+        assert!(stored_users(&repository)
+            .iter()
+            .all(|user| user.user_id() != &user_id));
         Ok(())
     }
 }
@@ -2,18 +2,25 @@ use std::sync::Arc;
 
 use axum::async_trait;
 
-use crate::domain::models::users::user_repository::IUserRepository;
+use crate::domain::models::users::{user_id::UserId, user_repository::IUserRepository};
 
-use super::{user_application_error::UserApplicationError, user_data::UserData, Result};
+use super::{
+    user_data::{UserData, UserDataView},
+    view_users_data, Result,
+};
 
 // trait of application service to get users
 #[async_trait]
 pub trait IUserGetAllApplicationService<T: IUserRepository> {
     fn new(user_repository: Arc<T>) -> Self;
-    async fn handle(&self, command: UserGetAllCommand) -> Result<Vec<UserData>>;
+    async fn handle(&self, command: UserGetAllCommand) -> Result<Vec<UserDataView>>;
 }
 
-pub struct UserGetAllCommand {}
+pub struct UserGetAllCommand {
+    // 情報を求めたユーザー。認証していない場合は `None`
+    // 本人か管理者であればすべての項目を、それ以外には id と名前だけを返す
+    pub requested_by: Option<UserId>,
+}
 
 // impl of application service to get users
 pub struct UserGetAllApplicationService<T: IUserRepository> {
@@ -26,16 +33,15 @@ impl<T: IUserRepository> IUserGetAllApplicationService<T> for UserGetAllApplicat
         Self { user_repository }
     }
 
-    async fn handle(&self, _: UserGetAllCommand) -> Result<Vec<UserData>> {
-        let users_found = self
-            .user_repository
-            .find_all()
-            .await
-            .map_err(|e| UserApplicationError::Unexpected(e.to_string()))?;
-        Ok(users_found
-            .into_iter()
-            .map(|user| UserData::new(user))
-            .collect())
+    async fn handle(&self, command: UserGetAllCommand) -> Result<Vec<UserDataView>> {
+        let UserGetAllCommand { requested_by } = command;
+        let users_found = self.user_repository.find_all().await?;
+        view_users_data(
+            self.user_repository.as_ref(),
+            users_found.into_iter().map(UserData::new).collect(),
+            requested_by.as_ref(),
+        )
+        .await
     }
 }
 
@@ -44,7 +50,11 @@ mod tests {
     use anyhow::Result;
 
     use crate::{
-        domain::{models::users::{user::User, user_name::UserName}, value_object::ValueObject},
+        application::users::user_data::UserDataPublic,
+        domain::{
+            models::users::{user::User, user_name::UserName, user_role::UserRole},
+            value_object::ValueObject,
+        },
         infra::repository_impl::in_memory::users::in_memory_user_repository::InMemoryUserRepository,
     };
 
@@ -57,42 +67,77 @@ mod tests {
         // 1. Get all stored user
         let user_get_all_application_service =
             UserGetAllApplicationService::new(repository.clone());
-        let command = UserGetAllCommand {};
+        let command = UserGetAllCommand { requested_by: None };
         let users = user_get_all_application_service.handle(command).await?;
 
         assert!(users.is_empty());
 
         // 2. Put the first data
         let user_1 = User::new(UserName::new("tester-1".to_string())?)?;
-        let user_id = user_1.user_id().clone();
-        {
-            let mut store = repository.write_store_ref();
-            store.insert(user_id.clone(), user_1.clone());
-        }
+        repository.save(&user_1).await?;
 
         // 3. Get all stored user
-        let command = UserGetAllCommand {};
+        let command = UserGetAllCommand { requested_by: None };
         let users = user_get_all_application_service.handle(command).await?;
 
-        assert_eq!(vec![UserData::new(user_1.clone())], users);
+        assert_eq!(
+            vec![UserDataView::Public(UserDataPublic::new(user_1.clone()))],
+            users
+        );
 
         // 4. Put the second data
         let user_2 = User::new(UserName::new("tester-2".to_string())?)?;
-        let user_id = user_2.user_id().clone();
-        {
-            let mut store = repository.write_store_ref();
-            store.insert(user_id.clone(), user_2.clone());
-        }
+        repository.save(&user_2).await?;
 
         // 3. Get all stored user
-        let command = UserGetAllCommand {};
+        let command = UserGetAllCommand { requested_by: None };
         let mut users = user_get_all_application_service.handle(command).await?;
 
         // Sort users alphabetically
-        users.sort_by(|a, b| a.user_name.cmp(&b.user_name));
+        users.sort_by(|a, b| a.user_name().cmp(b.user_name()));
+
+        assert_eq!(
+            vec![
+                UserDataView::Public(UserDataPublic::new(user_1)),
+                UserDataView::Public(UserDataPublic::new(user_2)),
+            ],
+            users
+        );
+
+        Ok(())
+    }
 
-        assert_eq!(vec![UserData::new(user_1), UserData::new(user_2)], users);
+    #[tokio::test]
+    async fn should_show_all_items_only_to_admin_or_user_themselves() -> Result<()> {
+        let repository = Arc::new(InMemoryUserRepository::new());
+
+        let mut admin = User::new(UserName::new("admin".to_string())?)?;
+        admin.user_role = UserRole::Admin;
+        let member = User::new(UserName::new("member".to_string())?)?;
+        for user in [&admin, &member] {
+            repository.save(user).await?;
+        }
 
+        let user_get_all_application_service =
+            UserGetAllApplicationService::new(repository.clone());
+        for (requested_by, expected_full_names) in [
+            (Some(admin.user_id()), vec!["admin", "member"]),
+            (Some(member.user_id()), vec!["member"]),
+            (None, vec![]),
+        ] {
+            let command = UserGetAllCommand {
+                requested_by: requested_by.cloned(),
+            };
+            let mut full_names: Vec<String> = user_get_all_application_service
+                .handle(command)
+                .await?
+                .into_iter()
+                .filter_map(UserDataView::into_full)
+                .map(|user_data| user_data.user_name)
+                .collect();
+            full_names.sort();
+            assert_eq!(expected_full_names, full_names);
+        }
         Ok(())
     }
 }
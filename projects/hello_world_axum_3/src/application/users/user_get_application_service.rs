@@ -2,47 +2,109 @@ use std::sync::Arc;
 
 use axum::async_trait;
 
-use crate::domain::models::users::{user_id::UserId, user_repository::IUserRepository};
+use crate::domain::{
+    models::{
+        todos::todo_repository::ITodoRepository,
+        users::{user::User, user_id::UserId, user_repository::IUserRepository},
+    },
+    services::streak_service::StreakService,
+};
 
-use super::{user_application_error::UserApplicationError, user_data::UserData, Result};
+use super::{
+    is_admin,
+    user_application_error::UserApplicationError,
+    user_data::{UserData, UserDataPublic, UserDataView},
+    Result,
+};
 
 // trait of application service to get a user
 #[async_trait]
-pub trait IUserGetApplicationService<T: IUserRepository> {
-    fn new(user_repository: Arc<T>) -> Self;
-    async fn handle(&self, command: UserGetCommand) -> Result<UserData>;
+pub trait IUserGetApplicationService<UserRep: IUserRepository, TodoRep: ITodoRepository> {
+    fn new(user_repository: Arc<UserRep>, todo_repository: Arc<TodoRep>) -> Self;
+    async fn handle(&self, command: UserGetCommand) -> Result<UserDataView>;
 }
 
 pub struct UserGetCommand {
     pub user_id: String,
+    // true の場合は、担当している todo の完了率も求める
+    pub include_stats: bool,
+    // 情報を求めたユーザー。認証していない場合は `None`
+    // 本人か管理者であればすべての項目を、それ以外には id と名前だけを返す
+    pub requested_by: Option<UserId>,
 }
 
 // impl of application service to get a user
-pub struct UserGetApplicationService<T: IUserRepository> {
-    user_repository: Arc<T>,
+pub struct UserGetApplicationService<UserRep: IUserRepository, TodoRep: ITodoRepository> {
+    user_repository: Arc<UserRep>,
+    todo_repository: Arc<TodoRep>,
 }
 
 #[async_trait]
-impl<T: IUserRepository> IUserGetApplicationService<T> for UserGetApplicationService<T> {
-    fn new(user_repository: Arc<T>) -> Self {
-        Self { user_repository }
+impl<UserRep, TodoRep> IUserGetApplicationService<UserRep, TodoRep>
+    for UserGetApplicationService<UserRep, TodoRep>
+where
+    UserRep: IUserRepository,
+    TodoRep: ITodoRepository,
+{
+    fn new(user_repository: Arc<UserRep>, todo_repository: Arc<TodoRep>) -> Self {
+        Self {
+            user_repository,
+            todo_repository,
+        }
     }
 
-    async fn handle(&self, command: UserGetCommand) -> Result<UserData> {
+    async fn handle(&self, command: UserGetCommand) -> Result<UserDataView> {
         let UserGetCommand {
             user_id: user_id_string,
+            include_stats,
+            requested_by,
         } = command;
         let user_id = UserId::parse(user_id_string)
             .map_err(|e| UserApplicationError::IllegalUserId(e.to_string()))?;
-        let user_found = self
-            .user_repository
-            .find(&user_id)
+        let user_found = self.user_repository.find(&user_id).await?;
+        let Some(user) = user_found else {
+            return Err(UserApplicationError::UserNotFound(user_id));
+        };
+        if !self.can_view_all_of(&user, requested_by).await? {
+            return Ok(UserDataView::Public(UserDataPublic::new(user)));
+        }
+        if !include_stats {
+            return Ok(UserDataView::Full(UserData::new(user)));
+        }
+
+        let (total, completed) = self
+            .todo_repository
+            .count_by_user_and_completion(&user_id)
             .await
             .map_err(|e| UserApplicationError::Unexpected(e.to_string()))?;
-        match user_found {
-            Some(user) => Ok(UserData::new(user)),
-            None => Err(UserApplicationError::UserNotFound(user_id).into()),
+        // 担当している todo がなければ完了率は求めない
+        let completion_rate = (total > 0).then(|| completed as f64 / total as f64);
+        let current_streak = StreakService::new(self.todo_repository.clone())
+            .compute_streak(&user_id)
+            .await
+            .map_err(|e| UserApplicationError::Unexpected(e.to_string()))?;
+        Ok(UserDataView::Full(UserData {
+            completion_rate,
+            current_streak: Some(current_streak),
+            ..UserData::new(user)
+        }))
+    }
+}
+
+impl<UserRep, TodoRep> UserGetApplicationService<UserRep, TodoRep>
+where
+    UserRep: IUserRepository,
+    TodoRep: ITodoRepository,
+{
+    // 本人か管理者であれば、すべての項目を見せる
+    async fn can_view_all_of(&self, user: &User, requested_by: Option<UserId>) -> Result<bool> {
+        let Some(requested_by) = requested_by else {
+            return Ok(false);
+        };
+        if &requested_by == user.user_id() {
+            return Ok(true);
         }
+        is_admin(self.user_repository.as_ref(), Some(&requested_by)).await
     }
 }
 
@@ -53,10 +115,16 @@ mod tests {
 
     use crate::{
         domain::{
-            models::users::{user::User, user_name::UserName},
+            models::{
+                todos::{todo::Todo, todo_repository::ITodoRepository, todo_text::TodoText},
+                users::{user_name::UserName, user_role::UserRole},
+            },
             value_object::ValueObject,
         },
-        infra::repository_impl::in_memory::users::in_memory_user_repository::InMemoryUserRepository,
+        infra::repository_impl::in_memory::{
+            todos::in_memory_todo_repository::InMemoryTodoRepository,
+            users::in_memory_user_repository::InMemoryUserRepository,
+        },
     };
 
     use super::*;
@@ -69,19 +137,74 @@ mod tests {
         let user_id = user.user_id().clone();
 
         // Put the data in advance
-        {
-            let mut store = repository.write_store_ref();
-            store.insert(user_id.clone(), user.clone());
-        }
+        repository.save(&user).await?;
 
         // Get stored user
-        let user_get_application_service = UserGetApplicationService::new(repository.clone());
+        let user_get_application_service = UserGetApplicationService::new(
+            repository.clone(),
+            Arc::new(InMemoryTodoRepository::new()),
+        );
         let command = UserGetCommand {
             user_id: user_id.value().to_string(),
+            include_stats: false,
+            requested_by: Some(user_id.clone()),
         };
-        let user_found = user_get_application_service.handle(command).await?;
+        let user_found = user_get_application_service
+            .handle(command)
+            .await?
+            .into_full()
+            .unwrap();
 
         assert_eq!(UserData::new(user), user_found);
+        assert_eq!(None, user_found.completion_rate);
+        assert_eq!(None, user_found.current_streak);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_compute_completion_rate_if_requested() -> Result<()> {
+        let repository = Arc::new(InMemoryUserRepository::new());
+        let todo_repository = Arc::new(InMemoryTodoRepository::new());
+
+        let user = User::new(UserName::new("tester-1".to_string())?)?;
+        let user_id = user.user_id().clone();
+        repository.save(&user).await?;
+
+        let user_get_application_service =
+            UserGetApplicationService::new(repository.clone(), todo_repository.clone());
+        let command = || UserGetCommand {
+            user_id: user_id.value().to_string(),
+            include_stats: true,
+            requested_by: Some(user_id.clone()),
+        };
+
+        // 担当している todo がなければ求めない
+        let user_found = user_get_application_service
+            .handle(command())
+            .await?
+            .into_full()
+            .unwrap();
+        assert_eq!(None, user_found.completion_rate);
+
+        // 4 件のうち 2 件が完了済み
+        for (i, completed) in [true, true, false, false].into_iter().enumerate() {
+            let mut todo = Todo::new(TodoText::new(format!("todo-{}", i))?, vec![])?;
+            todo.assignee_id = Some(user_id.clone());
+            todo.completed = completed;
+            todo_repository.save(&todo).await?;
+        }
+        // 他のユーザーの todo は数えない
+        let others_todo = Todo::new(TodoText::new("others".to_string())?, vec![])?;
+        todo_repository.save(&others_todo).await?;
+
+        let user_found = user_get_application_service
+            .handle(command())
+            .await?
+            .into_full()
+            .unwrap();
+        assert_eq!(Some(0.5), user_found.completion_rate);
+        // 今日完了した todo があるので 1 日になる
+        assert_eq!(Some(1), user_found.current_streak);
         Ok(())
     }
 
@@ -90,16 +213,22 @@ mod tests {
         let repository = Arc::new(InMemoryUserRepository::new());
 
         // try to get user which does not exist
-        let user_get_application_service = UserGetApplicationService::new(repository.clone());
+        let user_get_application_service = UserGetApplicationService::new(
+            repository.clone(),
+            Arc::new(InMemoryTodoRepository::new()),
+        );
+        let user_id = Uuid::new_v4();
         let command = UserGetCommand {
-            user_id: Uuid::new_v4().to_string(),
+            user_id: user_id.to_string(),
+            include_stats: false,
+            requested_by: None,
         };
         let result_of_user_delete = user_get_application_service.handle(command).await;
 
-        assert!(matches!(
+        assert_eq!(
             result_of_user_delete,
-            Err(UserApplicationError::UserNotFound(_))
-        ));
+            Err(UserApplicationError::UserNotFound(UserId::new(user_id)?))
+        );
 
         Ok(())
     }
@@ -109,17 +238,84 @@ mod tests {
         let repository = Arc::new(InMemoryUserRepository::new());
 
         // try to get user with illegal-formated user-id
-        let user_get_application_service = UserGetApplicationService::new(repository.clone());
+        let user_get_application_service = UserGetApplicationService::new(
+            repository.clone(),
+            Arc::new(InMemoryTodoRepository::new()),
+        );
         let command = UserGetCommand {
             user_id: "illegal-formated-user-id".to_string(),
+            include_stats: false,
+            requested_by: None,
         };
         let result_of_user_delete = user_get_application_service.handle(command).await;
 
-        assert!(matches!(
+        assert_eq!(
             result_of_user_delete,
-            Err(UserApplicationError::IllegalUserId(_))
-        ));
+            Err(UserApplicationError::IllegalUserId(
+                UserId::parse("illegal-formated-user-id".to_string())
+                    .unwrap_err()
+                    .to_string()
+            ))
+        );
+
+        Ok(())
+    }
 
+    #[tokio::test]
+    async fn should_show_all_fields_only_to_admin_or_user_themselves() -> Result<()> {
+        let repository = Arc::new(InMemoryUserRepository::new());
+
+        let mut admin = User::new(UserName::new("admin".to_string())?)?;
+        admin.user_role = UserRole::Admin;
+        let mut member = User::new(UserName::new("member".to_string())?)?;
+        member.user_role = UserRole::Member;
+        let user_b = User::new(UserName::new("user-b".to_string())?)?;
+        for user in [&admin, &member, &user_b] {
+            repository.save(user).await?;
+        }
+
+        let user_get_application_service = UserGetApplicationService::new(
+            repository.clone(),
+            Arc::new(InMemoryTodoRepository::new()),
+        );
+        let command_requested_by = |requested_by: Option<&User>| UserGetCommand {
+            user_id: user_b.user_id().value().to_string(),
+            include_stats: false,
+            requested_by: requested_by.map(|user| user.user_id().clone()),
+        };
+
+        // 管理者にはロールも見せる
+        let user_found = user_get_application_service
+            .handle(command_requested_by(Some(&admin)))
+            .await?;
+        assert_eq!(
+            UserDataView::Full(UserData::new(user_b.clone())),
+            user_found
+        );
+        let json = serde_json::to_value(&user_found)?;
+        assert_eq!(user_b.user_role.to_string(), json["user_role"]);
+
+        // 本人にもロールを見せる
+        let user_found = user_get_application_service
+            .handle(command_requested_by(Some(&user_b)))
+            .await?;
+        assert!(serde_json::to_value(&user_found)?
+            .get("user_role")
+            .is_some());
+
+        // 管理者でないユーザーや、認証していない場合は id と名前だけを見せる
+        for requested_by in [Some(&member), None] {
+            let user_found = user_get_application_service
+                .handle(command_requested_by(requested_by))
+                .await?;
+            assert_eq!(
+                UserDataView::Public(UserDataPublic::new(user_b.clone())),
+                user_found
+            );
+            let json = serde_json::to_value(&user_found)?;
+            assert_eq!("user-b", json["user_name"]);
+            assert!(json.get("user_role").is_none());
+        }
         Ok(())
     }
 }
@@ -0,0 +1,124 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+
+use crate::domain::models::users::{
+    user_id::UserId, user_repository::IUserRepository, user_role::UserRole,
+};
+
+use super::{
+    user_application_error::UserApplicationError,
+    user_data::{UserData, UserDataView},
+    view_users_data, Result,
+};
+
+// trait of application service to get users by role
+#[async_trait]
+pub trait IUserGetByRoleApplicationService<T: IUserRepository> {
+    fn new(user_repository: Arc<T>) -> Self;
+    async fn handle(&self, command: UserGetByRoleCommand) -> Result<Vec<UserDataView>>;
+}
+
+pub struct UserGetByRoleCommand {
+    pub role: String,
+    // 情報を求めたユーザー。本人か管理者であればすべての項目を、それ以外には id と名前だけを返す
+    pub requested_by: Option<UserId>,
+}
+
+// impl of application service to get users by role
+pub struct UserGetByRoleApplicationService<T: IUserRepository> {
+    user_repository: Arc<T>,
+}
+
+#[async_trait]
+impl<T: IUserRepository> IUserGetByRoleApplicationService<T>
+    for UserGetByRoleApplicationService<T>
+{
+    fn new(user_repository: Arc<T>) -> Self {
+        Self { user_repository }
+    }
+
+    async fn handle(&self, command: UserGetByRoleCommand) -> Result<Vec<UserDataView>> {
+        let UserGetByRoleCommand {
+            role: role_string,
+            requested_by,
+        } = command;
+        let user_role = UserRole::parse(role_string)
+            .map_err(|e| UserApplicationError::IllegalUserRole(e.to_string()))?;
+        let users_found = self.user_repository.find_all_by_role(&user_role).await?;
+        view_users_data(
+            self.user_repository.as_ref(),
+            users_found.into_iter().map(UserData::new).collect(),
+            requested_by.as_ref(),
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use crate::{
+        application::users::user_data::UserDataPublic,
+        domain::{
+            models::users::{user::User, user_name::UserName},
+            value_object::ValueObject,
+        },
+        infra::repository_impl::in_memory::users::in_memory_user_repository::InMemoryUserRepository,
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn should_get_users_by_role() -> Result<()> {
+        let repository = Arc::new(InMemoryUserRepository::new());
+
+        // Put users with each role in advance
+        let mut admin = User::new(UserName::new("admin-1".to_string())?)?;
+        admin.user_role = UserRole::Admin;
+        let mut member = User::new(UserName::new("member-1".to_string())?)?;
+        member.user_role = UserRole::Member;
+        let mut viewer = User::new(UserName::new("viewer-1".to_string())?)?;
+        viewer.user_role = UserRole::Viewer;
+        for user in [&admin, &member, &viewer] {
+            repository.save(user).await?;
+        }
+
+        // Role string is case-insensitive
+        let user_get_by_role_application_service =
+            UserGetByRoleApplicationService::new(repository.clone());
+        let command = UserGetByRoleCommand {
+            role: "member".to_string(),
+            requested_by: None,
+        };
+        let users = user_get_by_role_application_service.handle(command).await?;
+
+        assert_eq!(
+            vec![UserDataView::Public(UserDataPublic::new(member))],
+            users
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_throw_error_if_role_is_unknown() -> Result<()> {
+        let repository = Arc::new(InMemoryUserRepository::new());
+
+        let user_get_by_role_application_service =
+            UserGetByRoleApplicationService::new(repository.clone());
+        let command = UserGetByRoleCommand {
+            role: "owner".to_string(),
+            requested_by: None,
+        };
+        let result = user_get_by_role_application_service.handle(command).await;
+
+        assert_eq!(
+            result,
+            Err(UserApplicationError::IllegalUserRole(
+                "Failure to parse string as user_role: [owner]".to_string()
+            ))
+        );
+        Ok(())
+    }
+}
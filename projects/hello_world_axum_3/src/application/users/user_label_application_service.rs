@@ -0,0 +1,152 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+
+use crate::{
+    application::labels::label_data::LabelData,
+    domain::models::{
+        labels::label_repository::ILabelRepository,
+        users::{user_id::UserId, user_repository::IUserRepository},
+    },
+};
+
+use super::{user_application_error::UserApplicationError, Result};
+
+// trait of application service to get labels related to a user
+#[async_trait]
+pub trait IUserLabelApplicationService<UserRep: IUserRepository, LabelRep: ILabelRepository> {
+    fn new(user_repository: Arc<UserRep>, label_repository: Arc<LabelRep>) -> Self;
+    async fn handle(&self, command: UserLabelCommand) -> Result<Vec<LabelData>>;
+}
+
+// command object
+pub struct UserLabelCommand {
+    pub user_id: String,
+}
+
+// impl of application service to get labels related to a user
+pub struct UserLabelApplicationService<UserRep: IUserRepository, LabelRep: ILabelRepository> {
+    user_repository: Arc<UserRep>,
+    label_repository: Arc<LabelRep>,
+}
+
+#[async_trait]
+impl<UserRep, LabelRep> IUserLabelApplicationService<UserRep, LabelRep>
+    for UserLabelApplicationService<UserRep, LabelRep>
+where
+    UserRep: IUserRepository,
+    LabelRep: ILabelRepository,
+{
+    fn new(user_repository: Arc<UserRep>, label_repository: Arc<LabelRep>) -> Self {
+        Self {
+            user_repository,
+            label_repository,
+        }
+    }
+
+    async fn handle(&self, command: UserLabelCommand) -> Result<Vec<LabelData>> {
+        let UserLabelCommand {
+            user_id: user_id_string,
+        } = command;
+        let user_id = UserId::parse(user_id_string)
+            .map_err(|e| UserApplicationError::IllegalUserId(e.to_string()))?;
+        // 存在しないユーザーの場合は、空の一覧ではなくエラーを返す
+        if self.user_repository.find(&user_id).await?.is_none() {
+            return Err(UserApplicationError::UserNotFound(user_id));
+        }
+
+        let labels_found = self
+            .label_repository
+            .find_by_user_id_via_todos(&user_id)
+            .await
+            .map_err(|e| UserApplicationError::Unexpected(e.to_string()))?;
+        Ok(labels_found.into_iter().map(LabelData::new).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use uuid::Uuid;
+
+    use crate::{
+        domain::{
+            models::{
+                labels::{label::Label, label_name::LabelName},
+                todos::{todo::Todo, todo_repository::ITodoRepository, todo_text::TodoText},
+                users::{user::User, user_name::UserName},
+            },
+            value_object::ValueObject,
+        },
+        infra::repository_impl::in_memory::{
+            labels::in_memory_label_repository::InMemoryLabelRepository,
+            todos::in_memory_todo_repository::InMemoryTodoRepository,
+            users::in_memory_user_repository::InMemoryUserRepository,
+        },
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn should_get_distinct_labels_of_todos_assigned_to_user() -> Result<()> {
+        let todo_repository = InMemoryTodoRepository::new();
+        let label_repository = Arc::new(InMemoryLabelRepository::with_todo_repository(
+            todo_repository.clone(),
+        ));
+        let user_repository = Arc::new(InMemoryUserRepository::new());
+
+        let user = User::new(UserName::new("tester-1".to_string())?)?;
+        user_repository.save(&user).await?;
+
+        let label_a = Label::new(LabelName::new("A".to_string())?)?;
+        let label_b = Label::new(LabelName::new("B".to_string())?)?;
+        let label_c = Label::new(LabelName::new("C".to_string())?)?;
+
+        // Put the data in advance
+        {
+            for labels in [
+                vec![label_a.clone()],
+                vec![label_b.clone(), label_a.clone()],
+            ] {
+                let mut todo = Todo::new(TodoText::new("assigned".to_string())?, labels)?;
+                todo.assignee_id = Some(user.user_id().clone());
+                todo_repository.save(&todo).await?;
+            }
+            // 担当していない todo のラベルは含めない
+            let others_todo = Todo::new(TodoText::new("others".to_string())?, vec![label_c])?;
+            todo_repository.save(&others_todo).await?;
+        }
+
+        let user_label_application_service =
+            UserLabelApplicationService::new(user_repository, label_repository);
+        let labels_data = user_label_application_service
+            .handle(UserLabelCommand {
+                user_id: user.user_id().value().to_string(),
+            })
+            .await?;
+
+        assert_eq!(
+            vec![LabelData::new(label_a), LabelData::new(label_b)],
+            labels_data
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_throw_error_if_target_user_does_not_exist() -> Result<()> {
+        let user_label_application_service = UserLabelApplicationService::new(
+            Arc::new(InMemoryUserRepository::new()),
+            Arc::new(InMemoryLabelRepository::new()),
+        );
+        let user_id = UserId::new(Uuid::new_v4())?;
+
+        let result = user_label_application_service
+            .handle(UserLabelCommand {
+                user_id: user_id.value().to_string(),
+            })
+            .await;
+
+        assert_eq!(Err(UserApplicationError::UserNotFound(user_id)), result);
+        Ok(())
+    }
+}
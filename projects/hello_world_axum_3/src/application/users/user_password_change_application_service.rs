@@ -0,0 +1,236 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+
+use crate::domain::{
+    clock::{Clock, SystemClock},
+    models::{
+        credentials::{credential_repository::ICredentialRepository, password::Password},
+        users::user_id::UserId,
+    },
+    value_object::ValueObject,
+};
+
+use super::{
+    save_credential, user_application_error::UserApplicationError, verify_password, Result,
+};
+
+// trait of application service to change a user's password
+#[async_trait]
+pub trait IUserPasswordChangeApplicationService<CredentialRep: ICredentialRepository> {
+    fn new(credential_repository: Arc<CredentialRep>) -> Self;
+    async fn handle(&self, command: UserPasswordChangeCommand) -> Result<()>;
+}
+
+// command object
+pub struct UserPasswordChangeCommand {
+    pub user_id: String,
+    pub current_password: String,
+    pub new_password: String,
+    // 変更を求めたユーザー。本人でなければ変更できない
+    pub requested_by: UserId,
+}
+
+// impl of application service to change a user's password
+pub struct UserPasswordChangeApplicationService<CredentialRep: ICredentialRepository> {
+    credential_repository: Arc<CredentialRep>,
+    clock: Arc<dyn Clock>,
+}
+
+impl<CredentialRep: ICredentialRepository> UserPasswordChangeApplicationService<CredentialRep> {
+    pub fn with_clock(credential_repository: Arc<CredentialRep>, clock: Arc<dyn Clock>) -> Self {
+        Self {
+            credential_repository,
+            clock,
+        }
+    }
+}
+
+#[async_trait]
+impl<CredentialRep: ICredentialRepository> IUserPasswordChangeApplicationService<CredentialRep>
+    for UserPasswordChangeApplicationService<CredentialRep>
+{
+    fn new(credential_repository: Arc<CredentialRep>) -> Self {
+        Self::with_clock(credential_repository, Arc::new(SystemClock))
+    }
+
+    async fn handle(&self, command: UserPasswordChangeCommand) -> Result<()> {
+        let UserPasswordChangeCommand {
+            user_id: user_id_string,
+            current_password,
+            new_password: new_password_string,
+            requested_by,
+        } = command;
+        let user_id = UserId::parse(user_id_string)
+            .map_err(|e| UserApplicationError::IllegalUserId(e.to_string()))?;
+        // 管理者であっても、他のユーザーのパスワードは変更できない
+        if requested_by != user_id {
+            return Err(UserApplicationError::PermissionDenied(user_id));
+        }
+        let new_password = Password::new(new_password_string)
+            .map_err(|e| UserApplicationError::IllegalArgumentError(e.to_string()))?;
+
+        let mut credential = self
+            .credential_repository
+            .find_by_user_id(&user_id)
+            .await
+            .map_err(|e| UserApplicationError::Unexpected(e.to_string()))?
+            .ok_or(UserApplicationError::UserNotFound(user_id))?;
+
+        // ログインと同じく、続けて間違えるとロックし、ロック中は正しいパスワードでも変更できない
+        verify_password(
+            self.credential_repository.as_ref(),
+            &mut credential,
+            &current_password,
+            self.clock.now(),
+        )
+        .await?;
+
+        credential
+            .change_password(&new_password)
+            .map_err(|e| UserApplicationError::Unexpected(e.to_string()))?;
+        save_credential(self.credential_repository.as_ref(), &credential).await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use chrono::Utc;
+    use uuid::Uuid;
+
+    use crate::{
+        domain::{clock::FixedClock, models::credentials::password_credential::PasswordCredential},
+        infra::repository_impl::in_memory::credentials::in_memory_credential_repository::InMemoryCredentialRepository,
+    };
+
+    use super::*;
+
+    async fn repository_with_password(
+        password: &str,
+    ) -> Result<(UserId, Arc<InMemoryCredentialRepository>)> {
+        let repository = Arc::new(InMemoryCredentialRepository::new());
+        let user_id = UserId::new(Uuid::new_v4())?;
+        repository
+            .save(&PasswordCredential::new(user_id.clone(), password)?)
+            .await?;
+        Ok((user_id, repository))
+    }
+
+    fn command_for_test(
+        user_id: &UserId,
+        current_password: &str,
+        new_password: &str,
+    ) -> UserPasswordChangeCommand {
+        UserPasswordChangeCommand {
+            user_id: user_id.to_string(),
+            current_password: current_password.to_string(),
+            new_password: new_password.to_string(),
+            requested_by: user_id.clone(),
+        }
+    }
+
+    #[tokio::test]
+    async fn should_change_password_if_current_password_is_correct() -> Result<()> {
+        let (user_id, repository) = repository_with_password("password1").await?;
+        let user_password_change_application_service =
+            UserPasswordChangeApplicationService::new(repository.clone());
+
+        user_password_change_application_service
+            .handle(command_for_test(&user_id, "password1", "password2"))
+            .await?;
+
+        let credential = repository.find_by_user_id(&user_id).await?.unwrap();
+        assert!(!credential.verify("password1"));
+        assert!(credential.verify("password2"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_reject_wrong_current_password() -> Result<()> {
+        let (user_id, repository) = repository_with_password("password1").await?;
+        let user_password_change_application_service =
+            UserPasswordChangeApplicationService::new(repository.clone());
+
+        let result = user_password_change_application_service
+            .handle(command_for_test(&user_id, "password9", "password2"))
+            .await;
+
+        assert_eq!(Err(UserApplicationError::PasswordMismatch), result);
+        let credential = repository.find_by_user_id(&user_id).await?.unwrap();
+        assert!(credential.verify("password1"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_reject_weak_new_password() -> Result<()> {
+        let (user_id, repository) = repository_with_password("password1").await?;
+        let user_password_change_application_service =
+            UserPasswordChangeApplicationService::new(repository.clone());
+
+        let result = user_password_change_application_service
+            .handle(command_for_test(&user_id, "password1", "password"))
+            .await;
+
+        assert_eq!(
+            Err(UserApplicationError::IllegalArgumentError(
+                "Password must contain at least one digit.".to_string()
+            )),
+            result
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_reject_changing_password_of_other_user() -> Result<()> {
+        let (user_id, repository) = repository_with_password("password1").await?;
+        let user_password_change_application_service =
+            UserPasswordChangeApplicationService::new(repository.clone());
+
+        let mut command = command_for_test(&user_id, "password1", "password2");
+        command.requested_by = UserId::new(Uuid::new_v4())?;
+        let result = user_password_change_application_service
+            .handle(command)
+            .await;
+
+        assert_eq!(
+            Err(UserApplicationError::PermissionDenied(user_id.clone())),
+            result
+        );
+        let credential = repository.find_by_user_id(&user_id).await?.unwrap();
+        assert!(credential.verify("password1"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_lock_after_five_wrong_current_passwords() -> Result<()> {
+        let (user_id, repository) = repository_with_password("password1").await?;
+        let now = Utc::now();
+        let user_password_change_application_service =
+            UserPasswordChangeApplicationService::with_clock(
+                repository.clone(),
+                Arc::new(FixedClock(now)),
+            );
+
+        for _ in 0..5 {
+            let result = user_password_change_application_service
+                .handle(command_for_test(&user_id, "password9", "password2"))
+                .await;
+            assert_eq!(Err(UserApplicationError::PasswordMismatch), result);
+        }
+
+        // ロック中は正しいパスワードでも変更できない
+        let result = user_password_change_application_service
+            .handle(command_for_test(&user_id, "password1", "password2"))
+            .await;
+        assert!(matches!(
+            result,
+            Err(UserApplicationError::AccountLocked { until }) if until > now
+        ));
+        let credential = repository.find_by_user_id(&user_id).await?.unwrap();
+        assert!(credential.verify("password1"));
+        Ok(())
+    }
+}
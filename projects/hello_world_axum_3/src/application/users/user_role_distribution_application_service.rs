@@ -0,0 +1,85 @@
+use std::{collections::HashMap, sync::Arc};
+
+use axum::async_trait;
+
+use crate::domain::models::users::{user_repository::IUserRepository, user_role::UserRole};
+
+use super::Result;
+
+// trait of application service to count users per role
+#[async_trait]
+pub trait IUserRoleDistributionApplicationService<T: IUserRepository> {
+    fn new(user_repository: Arc<T>) -> Self;
+    async fn handle(&self) -> Result<HashMap<UserRole, u64>>;
+}
+
+// impl of application service to count users per role
+pub struct UserRoleDistributionApplicationService<T: IUserRepository> {
+    user_repository: Arc<T>,
+}
+
+#[async_trait]
+impl<T: IUserRepository> IUserRoleDistributionApplicationService<T>
+    for UserRoleDistributionApplicationService<T>
+{
+    fn new(user_repository: Arc<T>) -> Self {
+        Self { user_repository }
+    }
+
+    async fn handle(&self) -> Result<HashMap<UserRole, u64>> {
+        let mut counts = self.user_repository.count_by_role().await?;
+        // ダッシュボードで常にすべてのロールを表示できるよう、ユーザーがいないロールも 0 件として含める
+        for user_role in [UserRole::Admin, UserRole::Member, UserRole::Viewer] {
+            counts.entry(user_role).or_insert(0);
+        }
+        Ok(counts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use crate::{
+        domain::{
+            models::users::{user::User, user_name::UserName},
+            value_object::ValueObject,
+        },
+        infra::repository_impl::in_memory::users::in_memory_user_repository::InMemoryUserRepository,
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn should_count_users_per_role() -> Result<()> {
+        let repository = Arc::new(InMemoryUserRepository::new());
+
+        // Put 2 admins and 3 members in advance
+        let roles = [
+            UserRole::Admin,
+            UserRole::Admin,
+            UserRole::Member,
+            UserRole::Member,
+            UserRole::Member,
+        ];
+        for (i, user_role) in roles.into_iter().enumerate() {
+            let mut user = User::new(UserName::new(format!("tester-{}", i))?)?;
+            user.user_role = user_role;
+            repository.save(&user).await?;
+        }
+
+        let user_role_distribution_application_service =
+            UserRoleDistributionApplicationService::new(repository);
+        let counts = user_role_distribution_application_service.handle().await?;
+
+        assert_eq!(
+            HashMap::from([
+                (UserRole::Admin, 2),
+                (UserRole::Member, 3),
+                (UserRole::Viewer, 0),
+            ]),
+            counts
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,127 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+
+use crate::domain::models::users::{user_id::UserId, user_repository::IUserRepository};
+
+use super::{
+    user_application_error::UserApplicationError,
+    user_data::{UserData, UserDataView},
+    view_users_data, Result,
+};
+
+// trait of application service to search users by name
+#[async_trait]
+pub trait IUserSearchApplicationService<T: IUserRepository> {
+    fn new(user_repository: Arc<T>) -> Self;
+    async fn handle(&self, command: UserSearchCommand) -> Result<Vec<UserDataView>>;
+}
+
+pub struct UserSearchCommand {
+    pub query: String,
+    pub limit: u32,
+    // 情報を求めたユーザー。本人か管理者であればすべての項目を、それ以外には id と名前だけを返す
+    pub requested_by: Option<UserId>,
+}
+
+// impl of application service to search users by name
+pub struct UserSearchApplicationService<T: IUserRepository> {
+    user_repository: Arc<T>,
+}
+
+#[async_trait]
+impl<T: IUserRepository> IUserSearchApplicationService<T> for UserSearchApplicationService<T> {
+    fn new(user_repository: Arc<T>) -> Self {
+        Self { user_repository }
+    }
+
+    async fn handle(&self, command: UserSearchCommand) -> Result<Vec<UserDataView>> {
+        let UserSearchCommand {
+            query,
+            limit,
+            requested_by,
+        } = command;
+        let query = query.trim();
+        if query.is_empty() {
+            return Err(UserApplicationError::IllegalArgumentError(
+                "Search query must not be empty.".to_string(),
+            ));
+        }
+
+        let users_found = self.user_repository.search_by_name(query, limit).await?;
+        view_users_data(
+            self.user_repository.as_ref(),
+            users_found.into_iter().map(UserData::new).collect(),
+            requested_by.as_ref(),
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use crate::{
+        domain::{
+            models::users::{user::User, user_name::UserName},
+            value_object::ValueObject,
+        },
+        infra::repository_impl::in_memory::users::in_memory_user_repository::InMemoryUserRepository,
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn should_search_users_by_name_prefix() -> Result<()> {
+        let repository = Arc::new(InMemoryUserRepository::new());
+
+        // Put the data in advance
+        for name in ["Alice", "Alison", "Bob", "Carol", "Malcolm"] {
+            let user = User::new(UserName::new(name.to_string())?)?;
+            repository.save(&user).await?;
+        }
+
+        let user_search_application_service = UserSearchApplicationService::new(repository.clone());
+        let command = UserSearchCommand {
+            query: "al".to_string(),
+            limit: 5,
+            requested_by: None,
+        };
+        let users = user_search_application_service.handle(command).await?;
+
+        // 前方一致のみで、名前順に並ぶ
+        let names: Vec<&str> = users.iter().map(|user| user.user_name()).collect();
+        assert_eq!(vec!["Alice", "Alison"], names);
+
+        let command = UserSearchCommand {
+            query: "al".to_string(),
+            limit: 1,
+            requested_by: None,
+        };
+        let users = user_search_application_service.handle(command).await?;
+        assert_eq!(1, users.len());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_throw_error_if_query_is_empty() -> Result<()> {
+        let repository = Arc::new(InMemoryUserRepository::new());
+
+        let user_search_application_service = UserSearchApplicationService::new(repository.clone());
+        let command = UserSearchCommand {
+            query: " ".to_string(),
+            limit: 5,
+            requested_by: None,
+        };
+        let result = user_search_application_service.handle(command).await;
+
+        assert_eq!(
+            result,
+            Err(UserApplicationError::IllegalArgumentError(
+                "Search query must not be empty.".to_string()
+            ))
+        );
+        Ok(())
+    }
+}
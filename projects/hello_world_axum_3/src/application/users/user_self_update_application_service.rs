@@ -0,0 +1,123 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+
+use crate::domain::models::users::{user_id::UserId, user_repository::IUserRepository};
+
+use super::{
+    user_data::UserDataView,
+    user_update_application_service::{
+        IUserUpdateApplicationService, UserUpdateApplicationService, UserUpdateCommand,
+    },
+    Result,
+};
+
+// trait of application service to update the authenticated user's own profile
+#[async_trait]
+pub trait IUserSelfUpdateApplicationService<T: IUserRepository> {
+    fn new(user_repository: Arc<T>) -> Self;
+    async fn handle(&self, command: UserSelfUpdateCommand) -> Result<UserDataView>;
+}
+
+// command object
+// 更新対象は認証済みのユーザー自身に限られるため、id は文字列ではなく検証済みの `UserId` で受け取る
+pub struct UserSelfUpdateCommand {
+    pub authenticated_user_id: UserId,
+    pub user_name: Option<String>,
+}
+
+// impl of application service to update the authenticated user's own profile
+pub struct UserSelfUpdateApplicationService<T: IUserRepository> {
+    user_update_application_service: UserUpdateApplicationService<T>,
+}
+
+#[async_trait]
+impl<T: IUserRepository> IUserSelfUpdateApplicationService<T>
+    for UserSelfUpdateApplicationService<T>
+{
+    fn new(user_repository: Arc<T>) -> Self {
+        Self {
+            user_update_application_service: UserUpdateApplicationService::new(user_repository),
+        }
+    }
+
+    async fn handle(&self, command: UserSelfUpdateCommand) -> Result<UserDataView> {
+        let UserSelfUpdateCommand {
+            authenticated_user_id,
+            user_name,
+        } = command;
+
+        self.user_update_application_service
+            .handle(UserUpdateCommand {
+                user_id: authenticated_user_id.to_string(),
+                user_name,
+                // 本人の更新なので、すべての項目を返す
+                requested_by: Some(authenticated_user_id),
+            })
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use crate::{
+        domain::{
+            models::users::{user::User, user_name::UserName},
+            value_object::ValueObject,
+        },
+        infra::repository_impl::in_memory::users::in_memory_user_repository::InMemoryUserRepository,
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn should_update_only_authenticated_user() -> Result<()> {
+        let repository = Arc::new(InMemoryUserRepository::new());
+
+        let user_1 = User::new(UserName::new("tester-1".to_string())?)?;
+        let user_2 = User::new(UserName::new("tester-2".to_string())?)?;
+
+        // Put the data in advance
+        repository.save(&user_1).await?;
+        repository.save(&user_2).await?;
+
+        let user_self_update_application_service =
+            UserSelfUpdateApplicationService::new(repository.clone());
+        let command = UserSelfUpdateCommand {
+            authenticated_user_id: user_1.user_id().clone(),
+            user_name: Some("updated".to_string()),
+        };
+        // 本人の更新なので、すべての項目を返す
+        let user_updated = user_self_update_application_service
+            .handle(command)
+            .await?
+            .into_full()
+            .unwrap();
+
+        assert_eq!(user_1.user_id().value(), &user_updated.user_id);
+        assert_eq!("updated", user_updated.user_name);
+
+        // Check that the other user is untouched
+        assert_eq!(
+            "updated",
+            repository
+                .find(user_1.user_id())
+                .await?
+                .unwrap()
+                .user_name
+                .value()
+        );
+        assert_eq!(
+            "tester-2",
+            repository
+                .find(user_2.user_id())
+                .await?
+                .unwrap()
+                .user_name
+                .value()
+        );
+        Ok(())
+    }
+}
@@ -2,12 +2,14 @@ use std::sync::Arc;
 
 use axum::async_trait;
 
-use super::{user_data::UserData, Result};
+use super::{
+    user_data::{UserData, UserDataView},
+    view_user_data, Result,
+};
 
 use crate::domain::{
     models::users::{user_id::UserId, user_name::UserName, user_repository::IUserRepository},
     services::user_service::UserService,
-    value_object::ValueObject,
 };
 
 use super::user_application_error::UserApplicationError;
@@ -16,13 +18,15 @@ use super::user_application_error::UserApplicationError;
 #[async_trait]
 pub trait IUserUpdateApplicationService<T: IUserRepository> {
     fn new(user_repository: Arc<T>) -> Self;
-    async fn handle(&self, command: UserUpdateCommand) -> Result<UserData>;
+    async fn handle(&self, command: UserUpdateCommand) -> Result<UserDataView>;
 }
 
 // command object
 pub struct UserUpdateCommand {
     pub user_id: String,
     pub user_name: Option<String>,
+    // 情報を求めたユーザー。本人か管理者であればすべての項目を、それ以外には id と名前だけを返す
+    pub requested_by: Option<UserId>,
 }
 
 // impl of application service to update user
@@ -40,10 +44,11 @@ impl<T: IUserRepository> IUserUpdateApplicationService<T> for UserUpdateApplicat
         }
     }
 
-    async fn handle(&self, command: UserUpdateCommand) -> Result<UserData> {
+    async fn handle(&self, command: UserUpdateCommand) -> Result<UserDataView> {
         let UserUpdateCommand {
             user_id: user_id_string,
             user_name: user_name_string,
+            requested_by,
         } = command;
 
         let user_id = UserId::parse(user_id_string)
@@ -52,12 +57,12 @@ impl<T: IUserRepository> IUserUpdateApplicationService<T> for UserUpdateApplicat
         let mut user = self
             .user_repository
             .find(&user_id)
-            .await
-            .map_err(|e| UserApplicationError::Unexpected(e.to_string()))?
+            .await?
             .ok_or(UserApplicationError::UserNotFound(user_id))?;
 
         if let Some(user_name_string) = user_name_string {
-            let user_name = UserName::new(user_name_string)
+            let user_name = user_name_string
+                .parse::<UserName>()
                 .map_err(|e| UserApplicationError::IllegalArgumentError(e.to_string()))?;
             user.user_name = user_name;
         }
@@ -68,15 +73,17 @@ impl<T: IUserRepository> IUserUpdateApplicationService<T> for UserUpdateApplicat
             .await
             .map_err(|e| UserApplicationError::Unexpected(e.to_string()))?
         {
-            return Err(UserApplicationError::DuplicatedUser(user).into());
+            return Err(UserApplicationError::DuplicatedUser(user));
         }
 
-        self.user_repository
-            .save(&user)
-            .await
-            .map_err(|e| UserApplicationError::Unexpected(e.to_string()))?;
+        self.user_repository.save(&user).await?;
 
-        Ok(UserData::new(user))
+        view_user_data(
+            self.user_repository.as_ref(),
+            UserData::new(user),
+            requested_by.as_ref(),
+        )
+        .await
     }
 }
 
@@ -86,7 +93,10 @@ mod tests {
     use uuid::Uuid;
 
     use crate::{
-        domain::models::users::user::User,
+        domain::{
+            models::users::{user::User, user_role::UserRole},
+            value_object::ValueObject,
+        },
         infra::repository_impl::in_memory::users::in_memory_user_repository::InMemoryUserRepository,
     };
 
@@ -100,26 +110,23 @@ mod tests {
         let user_id = user.user_id().clone();
 
         // Put the data in advance
-        {
-            let mut store = repository.write_store_ref();
-            store.insert(user_id.clone(), user.clone());
-        }
+        repository.save(&user).await?;
 
         // Update stored user with 3-letter name
         let user_update_application_service = UserUpdateApplicationService::new(repository.clone());
         let command = UserUpdateCommand {
             user_id: user_id.value().to_string(),
             user_name: Some("123".to_string()),
+            requested_by: None,
         };
         let user_found = user_update_application_service.handle(command).await?;
 
-        assert_eq!(user_id.value(), &user_found.user_id);
-        assert_eq!("123", user_found.user_name);
+        assert_eq!(user_id.value(), user_found.user_id());
+        assert_eq!("123", user_found.user_name());
 
         // Check if user is updated
         {
-            let store = repository.read_store_ref();
-            let user_in_store = store.get(&user_id).unwrap();
+            let user_in_store = repository.find(&user_id).await?.unwrap();
             assert_eq!("123", user_in_store.user_name.value());
         }
         Ok(())
@@ -133,26 +140,23 @@ mod tests {
         let user_id = user.user_id().clone();
 
         // Put the data in advance
-        {
-            let mut store = repository.write_store_ref();
-            store.insert(user_id.clone(), user.clone());
-        }
+        repository.save(&user).await?;
 
         // Update stored user with 19-letter name
         let user_update_application_service = UserUpdateApplicationService::new(repository.clone());
         let command = UserUpdateCommand {
             user_id: user_id.value().to_string(),
             user_name: Some("1234567890123456789".to_string()),
+            requested_by: None,
         };
         let user_found = user_update_application_service.handle(command).await?;
 
-        assert_eq!(user_id.value(), &user_found.user_id);
-        assert_eq!("1234567890123456789", user_found.user_name);
+        assert_eq!(user_id.value(), user_found.user_id());
+        assert_eq!("1234567890123456789", user_found.user_name());
 
         // Check if user is updated
         {
-            let store = repository.read_store_ref();
-            let user_in_store = store.get(&user_id).unwrap();
+            let user_in_store = repository.find(&user_id).await?.unwrap();
             assert_eq!("1234567890123456789", user_in_store.user_name.value());
         }
         Ok(())
@@ -166,16 +170,14 @@ mod tests {
         let user_id = user.user_id().clone();
 
         // Put the data in advance
-        {
-            let mut store = repository.write_store_ref();
-            store.insert(user_id.clone(), user.clone());
-        }
+        repository.save(&user).await?;
 
         // Try update stored user with 2-letter name
         let user_update_application_service = UserUpdateApplicationService::new(repository.clone());
         let command = UserUpdateCommand {
             user_id: user_id.value().to_string(),
             user_name: Some("12".to_string()),
+            requested_by: None,
         };
         let result_of_user_update = user_update_application_service.handle(command).await;
 
@@ -196,16 +198,14 @@ mod tests {
         let user_id = user.user_id().clone();
 
         // Put the data in advance
-        {
-            let mut store = repository.write_store_ref();
-            store.insert(user_id.clone(), user.clone());
-        }
+        repository.save(&user).await?;
 
         // Try update stored user with 20-letter name
         let user_update_application_service = UserUpdateApplicationService::new(repository.clone());
         let command = UserUpdateCommand {
             user_id: user_id.value().to_string(),
             user_name: Some("12345678901234567890".to_string()),
+            requested_by: None,
         };
         let result_of_user_update = user_update_application_service.handle(command).await;
 
@@ -226,32 +226,30 @@ mod tests {
         let user_id_1 = user_1.user_id().clone();
 
         // Save the 1st user to store
-        {
-            let mut store = repository.write_store_ref();
-            store.insert(user_id_1.clone(), user_1.clone());
-        }
+        repository.save(&user_1).await?;
 
         let user_2 = User::new(UserName::new("tester-2".to_string())?)?;
-        let user_id_2 = user_2.user_id().clone();
 
         // Save the 2nd user to store
-        {
-            let mut store = repository.write_store_ref();
-            store.insert(user_id_2.clone(), user_2.clone());
-        }
+        repository.save(&user_2).await?;
 
         // Try update the 1st user with 2nd name's name
         let user_update_application_service = UserUpdateApplicationService::new(repository.clone());
         let command = UserUpdateCommand {
             user_id: user_id_1.value().to_string(),
             user_name: Some("tester-2".to_string()),
+            requested_by: None,
         };
         let result_of_user_update = user_update_application_service.handle(command).await;
 
-        assert!(matches!(
+        assert_eq!(
             result_of_user_update,
-            Err(UserApplicationError::DuplicatedUser(_))
-        ));
+            Err(UserApplicationError::DuplicatedUser(User::build(
+                user_id_1,
+                UserName::new("tester-2".to_string())?,
+                UserRole::default()
+            )))
+        );
         Ok(())
     }
 
@@ -265,6 +263,7 @@ mod tests {
         let command = UserUpdateCommand {
             user_id: user_id.to_string(),
             user_name: Some("123".to_string()),
+            requested_by: None,
         };
         let result_of_user_update = user_update_application_service.handle(command).await;
 
@@ -285,13 +284,18 @@ mod tests {
         let command = UserUpdateCommand {
             user_id: user_id.to_string(),
             user_name: Some("123".to_string()),
+            requested_by: None,
         };
         let result_of_user_update = user_update_application_service.handle(command).await;
 
-        assert!(matches!(
+        assert_eq!(
             result_of_user_update,
-            Err(UserApplicationError::IllegalUserId(_))
-        ));
+            Err(UserApplicationError::IllegalUserId(
+                UserId::parse("illegal-user-id".to_string())
+                    .unwrap_err()
+                    .to_string()
+            ))
+        );
 
         Ok(())
     }
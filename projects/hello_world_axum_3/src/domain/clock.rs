@@ -0,0 +1,30 @@
+use chrono::{DateTime, NaiveDate, Utc};
+
+// 現在時刻を返す。テストで日付を固定できるよう、直接 `Utc::now()` を呼ばずにこれを経由する
+pub trait Clock: Send + Sync + 'static {
+    fn now(&self) -> DateTime<Utc>;
+
+    // 期限日と比べるための今日の日付 (UTC)
+    fn today(&self) -> NaiveDate {
+        self.now().date_naive()
+    }
+}
+
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+// 常に同じ時刻を返す
+#[cfg(test)]
+pub struct FixedClock(pub DateTime<Utc>);
+
+#[cfg(test)]
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime<Utc> {
+        self.0
+    }
+}
@@ -0,0 +1,16 @@
+use std::fmt::Debug;
+
+use chrono::{DateTime, Utc};
+
+// ドメイン内で発生した出来事を表すイベント
+pub trait DomainEvent: Debug + Send + Sync {
+    // イベントの種類 (例: `TodoCreated`)
+    fn event_type(&self) -> &'static str;
+    fn occurred_at(&self) -> &DateTime<Utc>;
+    // イベントの内容を JSON で表したもの
+    fn to_json(&self) -> serde_json::Value;
+    // Server-Sent Events などでクライアントにそのまま配信してよいかどうか
+    fn is_public(&self) -> bool {
+        true
+    }
+}
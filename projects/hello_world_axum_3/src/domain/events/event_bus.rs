@@ -0,0 +1,73 @@
+use std::sync::Arc;
+
+use tokio::sync::broadcast;
+
+use super::domain_event::DomainEvent;
+
+// 購読者ごとにバッファしておけるイベントの最大数
+const EVENT_BUS_CAPACITY: usize = 1024;
+
+// ドメインイベントを購読者に配信するバス
+// 購読者がいない場合、発行されたイベントは破棄される
+#[derive(Clone)]
+pub struct EventBus {
+    sender: broadcast::Sender<Arc<dyn DomainEvent>>,
+}
+
+impl EventBus {
+    pub fn new() -> Self {
+        let (sender, _) = broadcast::channel(EVENT_BUS_CAPACITY);
+        Self { sender }
+    }
+
+    pub fn publish(&self, event: impl DomainEvent + 'static) {
+        // 購読者がいないときの送信エラーは無視する
+        let _ = self.sender.send(Arc::new(event));
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<Arc<dyn DomainEvent>> {
+        self.sender.subscribe()
+    }
+}
+
+impl Default for EventBus {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use super::*;
+    use crate::domain::{
+        events::todo_events::TodoDeleted, models::todos::todo_id::TodoId, value_object::ValueObject,
+    };
+
+    #[tokio::test]
+    async fn should_deliver_published_event_to_every_subscriber() -> Result<()> {
+        let event_bus = EventBus::new();
+        let mut receiver_1 = event_bus.subscribe();
+        let mut receiver_2 = event_bus.subscribe();
+
+        let todo_id = TodoId::new(uuid::Uuid::new_v4())?;
+        event_bus.publish(TodoDeleted::new(todo_id.clone()));
+
+        for receiver in [&mut receiver_1, &mut receiver_2] {
+            let event = receiver.recv().await?;
+            assert_eq!("TodoDeleted", event.event_type());
+            assert_eq!(todo_id.to_string(), event.to_json()["todo_id"]);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn should_not_fail_to_publish_without_subscribers() -> Result<()> {
+        let event_bus = EventBus::new();
+
+        event_bus.publish(TodoDeleted::new(TodoId::new(uuid::Uuid::new_v4())?));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,49 @@
+use chrono::{DateTime, Utc};
+use serde_json::json;
+
+use crate::domain::models::invitations::todo_list_invitation::TodoListInvitation;
+
+use super::domain_event::DomainEvent;
+
+// todo のリストへの招待が作成されたことを表すイベント
+// 招待メールの送信はこのイベントを購読して行う
+#[derive(Debug, Clone)]
+pub struct InvitationCreated {
+    invitation: TodoListInvitation,
+    occurred_at: DateTime<Utc>,
+}
+
+impl InvitationCreated {
+    pub fn new(invitation: TodoListInvitation) -> Self {
+        Self {
+            invitation,
+            occurred_at: Utc::now(),
+        }
+    }
+}
+
+impl DomainEvent for InvitationCreated {
+    fn event_type(&self) -> &'static str {
+        "InvitationCreated"
+    }
+
+    fn occurred_at(&self) -> &DateTime<Utc> {
+        &self.occurred_at
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        json!({
+            "invite_id": self.invitation.invite_id().to_string(),
+            "list_id": self.invitation.list_id().to_string(),
+            "invitee_email": self.invitation.invitee_email(),
+            "invited_by": self.invitation.invited_by().to_string(),
+            "expires_at": self.invitation.expires_at().to_rfc3339(),
+            "occurred_at": self.occurred_at.to_rfc3339(),
+        })
+    }
+
+    // 招待された人のメールアドレスを含むため、todo の変更と一緒には配信しない
+    fn is_public(&self) -> bool {
+        false
+    }
+}
@@ -0,0 +1,4 @@
+pub mod domain_event;
+pub mod event_bus;
+pub mod invitation_events;
+pub mod todo_events;
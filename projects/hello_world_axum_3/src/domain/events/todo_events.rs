@@ -0,0 +1,111 @@
+use chrono::{DateTime, Utc};
+use serde_json::json;
+
+use crate::domain::{
+    models::todos::{todo::Todo, todo_id::TodoId},
+    value_object::ValueObject,
+};
+
+use super::domain_event::DomainEvent;
+
+// todo が作成されたことを表すイベント
+#[derive(Debug, Clone)]
+pub struct TodoCreated {
+    todo: Todo,
+    occurred_at: DateTime<Utc>,
+}
+
+impl TodoCreated {
+    pub fn new(todo: Todo) -> Self {
+        Self {
+            todo,
+            occurred_at: Utc::now(),
+        }
+    }
+}
+
+impl DomainEvent for TodoCreated {
+    fn event_type(&self) -> &'static str {
+        "TodoCreated"
+    }
+
+    fn occurred_at(&self) -> &DateTime<Utc> {
+        &self.occurred_at
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        todo_json(&self.todo, &self.occurred_at)
+    }
+}
+
+// todo が更新されたことを表すイベント
+#[derive(Debug, Clone)]
+pub struct TodoUpdated {
+    todo: Todo,
+    occurred_at: DateTime<Utc>,
+}
+
+impl TodoUpdated {
+    pub fn new(todo: Todo) -> Self {
+        Self {
+            todo,
+            occurred_at: Utc::now(),
+        }
+    }
+}
+
+impl DomainEvent for TodoUpdated {
+    fn event_type(&self) -> &'static str {
+        "TodoUpdated"
+    }
+
+    fn occurred_at(&self) -> &DateTime<Utc> {
+        &self.occurred_at
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        todo_json(&self.todo, &self.occurred_at)
+    }
+}
+
+// todo が削除されたことを表すイベント
+#[derive(Debug, Clone)]
+pub struct TodoDeleted {
+    todo_id: TodoId,
+    occurred_at: DateTime<Utc>,
+}
+
+impl TodoDeleted {
+    pub fn new(todo_id: TodoId) -> Self {
+        Self {
+            todo_id,
+            occurred_at: Utc::now(),
+        }
+    }
+}
+
+impl DomainEvent for TodoDeleted {
+    fn event_type(&self) -> &'static str {
+        "TodoDeleted"
+    }
+
+    fn occurred_at(&self) -> &DateTime<Utc> {
+        &self.occurred_at
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        json!({
+            "todo_id": self.todo_id.to_string(),
+            "occurred_at": self.occurred_at.to_rfc3339(),
+        })
+    }
+}
+
+fn todo_json(todo: &Todo, occurred_at: &DateTime<Utc>) -> serde_json::Value {
+    json!({
+        "todo_id": todo.todo_id().to_string(),
+        "text": todo.todo_text.value(),
+        "completed": todo.completed,
+        "occurred_at": occurred_at.to_rfc3339(),
+    })
+}
@@ -1,5 +1,7 @@
+pub mod clock;
+pub mod events;
 pub mod models;
 pub mod services;
 
-pub mod value_object;
 pub mod entity;
+pub mod value_object;
@@ -0,0 +1,50 @@
+use std::{fmt::Display, str::FromStr};
+
+use thiserror::Error;
+
+// 監査ログに記録する操作の種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AuditAction {
+    Created,
+    Updated,
+    Deleted,
+}
+
+#[derive(Debug, Error)]
+pub enum AuditActionError {
+    #[error("Failure to parse string as audit action: [{0}]")]
+    FailToParse(String),
+}
+
+impl AuditAction {
+    pub fn parse(s: String) -> Result<Self, AuditActionError> {
+        match s.as_str() {
+            "Created" => Ok(Self::Created),
+            "Updated" => Ok(Self::Updated),
+            "Deleted" => Ok(Self::Deleted),
+            _ => Err(AuditActionError::FailToParse(s)),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Created => "Created",
+            Self::Updated => "Updated",
+            Self::Deleted => "Deleted",
+        }
+    }
+}
+
+impl FromStr for AuditAction {
+    type Err = AuditActionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s.to_string())
+    }
+}
+
+impl Display for AuditAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
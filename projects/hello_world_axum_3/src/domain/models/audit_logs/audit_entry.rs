@@ -0,0 +1,116 @@
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::domain::{entity::Entity, models::users::user_id::UserId, value_object::ValueObject};
+
+use super::{audit_action::AuditAction, audit_entry_id::AuditEntryId};
+
+// entity
+// 誰がいつどのリソースをどう変更したかの記録。作成後に変更することはない
+// 変更前後の内容は JSON で保持する。作成時の変更前と削除時の変更後は null になる
+#[derive(Debug, Clone)]
+pub struct AuditEntry {
+    entry_id: AuditEntryId,
+    // 変更されたリソースの種類 (例: `todo`)
+    resource_type: String,
+    resource_id: Uuid,
+    // 認証せずに操作した場合は None
+    actor_id: Option<UserId>,
+    action: AuditAction,
+    occurred_at: DateTime<Utc>,
+    before: Value,
+    after: Value,
+}
+
+impl AuditEntry {
+    pub fn new(
+        resource_type: &str,
+        resource_id: Uuid,
+        actor_id: Option<UserId>,
+        action: AuditAction,
+        before: Value,
+        after: Value,
+    ) -> anyhow::Result<Self> {
+        let entry_id = AuditEntryId::new(Uuid::new_v4())?;
+        Ok(Self {
+            entry_id,
+            resource_type: resource_type.to_string(),
+            resource_id,
+            actor_id,
+            action,
+            occurred_at: Utc::now(),
+            before,
+            after,
+        })
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn build(
+        entry_id: AuditEntryId,
+        resource_type: String,
+        resource_id: Uuid,
+        actor_id: Option<UserId>,
+        action: AuditAction,
+        occurred_at: DateTime<Utc>,
+        before: Value,
+        after: Value,
+    ) -> Self {
+        Self {
+            entry_id,
+            resource_type,
+            resource_id,
+            actor_id,
+            action,
+            occurred_at,
+            before,
+            after,
+        }
+    }
+
+    pub fn entry_id(&self) -> &AuditEntryId {
+        &self.entry_id
+    }
+
+    pub fn resource_type(&self) -> &str {
+        &self.resource_type
+    }
+
+    pub fn resource_id(&self) -> &Uuid {
+        &self.resource_id
+    }
+
+    pub fn actor_id(&self) -> Option<&UserId> {
+        self.actor_id.as_ref()
+    }
+
+    pub fn action(&self) -> AuditAction {
+        self.action
+    }
+
+    pub fn occurred_at(&self) -> &DateTime<Utc> {
+        &self.occurred_at
+    }
+
+    pub fn before(&self) -> &Value {
+        &self.before
+    }
+
+    pub fn after(&self) -> &Value {
+        &self.after
+    }
+}
+
+impl Entity for AuditEntry {
+    type Identity = AuditEntryId;
+
+    fn identity(&self) -> &Self::Identity {
+        &self.entry_id
+    }
+}
+
+impl PartialEq for AuditEntry {
+    fn eq(&self, other: &Self) -> bool {
+        Entity::eq(self, other)
+    }
+}
@@ -0,0 +1,26 @@
+use axum::async_trait;
+use thiserror::Error;
+use uuid::Uuid;
+
+use super::audit_entry::AuditEntry;
+
+pub type Result<T> = anyhow::Result<T, AuditLogRepositoryError>;
+
+#[async_trait]
+pub trait IAuditLogRepository: Clone + Send + Sync + 'static {
+    // 記録は追記のみで、更新も削除もしない
+    async fn save(&self, entry: &AuditEntry) -> Result<()>;
+    // 与えられたリソースの記録を、古いものから順に返す
+    // リソースが削除された後も記録は残る
+    async fn find_by_resource(
+        &self,
+        resource_type: &str,
+        resource_id: &Uuid,
+    ) -> Result<Vec<AuditEntry>>;
+}
+
+#[derive(Debug, Error)]
+pub enum AuditLogRepositoryError {
+    #[error("Unexpected error: [{0}]")]
+    Unexpected(String),
+}
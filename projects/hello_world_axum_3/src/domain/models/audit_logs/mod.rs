@@ -0,0 +1,4 @@
+pub mod audit_action;
+pub mod audit_entry;
+pub mod audit_entry_id;
+pub mod audit_log_repository;
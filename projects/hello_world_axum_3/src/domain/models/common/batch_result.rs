@@ -0,0 +1,52 @@
+use crate::domain::entity::Entity;
+
+// 複数の id でまとめて検索した結果
+// 見つからなかった id も返し、呼び出し側がどれが欠けていたかを判別できるようにする
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BatchFindResult<T, Id> {
+    pub found: Vec<T>,
+    // 指定された順に並ぶ
+    pub not_found: Vec<Id>,
+}
+
+impl<T, Id> BatchFindResult<T, Id>
+where
+    T: Entity<Identity = Id>,
+    Id: PartialEq + Clone,
+{
+    // 指定された id のうち、見つかったものに含まれないものを見つからなかった id とする
+    pub fn new(requested_ids: &[Id], found: Vec<T>) -> Self {
+        let mut not_found: Vec<Id> = Vec::new();
+        for id in requested_ids {
+            let is_found = found.iter().any(|item| item.identity() == id);
+            if !is_found && !not_found.contains(id) {
+                not_found.push(id.clone());
+            }
+        }
+        Self { found, not_found }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct Item(u32);
+
+    impl Entity for Item {
+        type Identity = u32;
+
+        fn identity(&self) -> &Self::Identity {
+            &self.0
+        }
+    }
+
+    #[test]
+    fn should_collect_ids_not_found() {
+        let result = BatchFindResult::new(&[1, 2, 3, 2, 4], vec![Item(3), Item(1)]);
+        assert_eq!(vec![Item(3), Item(1)], result.found);
+        // 重複して指定された id は一度だけ含まれる
+        assert_eq!(vec![2, 4], result.not_found);
+    }
+}
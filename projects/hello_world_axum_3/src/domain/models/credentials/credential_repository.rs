@@ -0,0 +1,22 @@
+use axum::async_trait;
+use thiserror::Error;
+
+use crate::domain::models::users::user_id::UserId;
+
+use super::password_credential::PasswordCredential;
+
+pub type Result<T> = anyhow::Result<T, CredentialRepositoryError>;
+
+#[async_trait]
+pub trait ICredentialRepository: Clone + Send + Sync + 'static {
+    async fn save(&self, credential: &PasswordCredential) -> Result<()>;
+    async fn find_by_user_id(&self, user_id: &UserId) -> Result<Option<PasswordCredential>>;
+}
+
+#[derive(Debug, Error)]
+pub enum CredentialRepositoryError {
+    #[error("Credential cannot be found, user id is {0:?}")]
+    NotFound(UserId),
+    #[error("Unexpected error: [{0}]")]
+    Unexpected(String),
+}
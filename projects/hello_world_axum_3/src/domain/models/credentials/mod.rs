@@ -0,0 +1,3 @@
+pub mod credential_repository;
+pub mod password;
+pub mod password_credential;
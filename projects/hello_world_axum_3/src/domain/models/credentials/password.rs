@@ -0,0 +1,78 @@
+use std::fmt;
+
+use thiserror::Error;
+
+pub use crate::domain::value_object::ValueObject;
+
+// value object
+// ハッシュ化する前のパスワード。ログに出ないよう Debug では値を伏せる
+#[derive(Clone, PartialEq, Eq)]
+pub struct Password {
+    value: String,
+}
+
+#[derive(Debug, Error)]
+pub enum PasswordError {
+    #[error("Password must be at least 8 characters.")]
+    PasswordTooShortError,
+    #[error("Password must contain at least one digit.")]
+    PasswordWithoutDigitError,
+}
+
+impl ValueObject for Password {
+    type Value = String;
+    type Error = PasswordError;
+
+    fn new(value: Self::Value) -> Result<Self, Self::Error> {
+        if value.chars().count() < 8 {
+            return Err(PasswordError::PasswordTooShortError);
+        }
+        if !value.chars().any(|c| c.is_ascii_digit()) {
+            return Err(PasswordError::PasswordWithoutDigitError);
+        }
+        Ok(Self { value })
+    }
+
+    fn value(&self) -> &Self::Value {
+        &self.value
+    }
+
+    fn into_value(self) -> Self::Value {
+        self.value
+    }
+}
+
+impl fmt::Debug for Password {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Password(***)")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_accept_password_with_8_characters_and_digit() {
+        assert!(Password::new("passwor1".to_string()).is_ok());
+    }
+
+    #[test]
+    fn should_reject_weak_passwords() {
+        assert!(matches!(
+            Password::new("passw1".to_string()),
+            Err(PasswordError::PasswordTooShortError)
+        ));
+        assert!(matches!(
+            Password::new("password".to_string()),
+            Err(PasswordError::PasswordWithoutDigitError)
+        ));
+    }
+
+    #[test]
+    fn should_not_expose_value_in_debug_output() -> anyhow::Result<()> {
+        let password = Password::new("password1".to_string())?;
+        assert!(!format!("{:?}", password).contains("password1"));
+        Ok(())
+    }
+}
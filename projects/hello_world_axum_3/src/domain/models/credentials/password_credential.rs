@@ -0,0 +1,180 @@
+use argon2::{
+    password_hash::{rand_core::OsRng, SaltString},
+    Argon2, PasswordHash, PasswordHasher, PasswordVerifier,
+};
+use chrono::{DateTime, Duration, Utc};
+
+use crate::domain::{entity::Entity, models::users::user_id::UserId};
+
+use super::password::{Password, ValueObject};
+
+// entity
+// ユーザーのパスワードを Argon2 でハッシュ化した状態で保持する
+#[derive(Debug, Clone)]
+pub struct PasswordCredential {
+    user_id: UserId,
+    password_hash: String,
+    // 直前のログイン成功以降に続けてパスワードを間違えた回数
+    failed_login_attempts: u32,
+    locked_until: Option<DateTime<Utc>>,
+}
+
+// この回数続けてパスワードを間違えると、しばらくログインできなくなる
+const MAX_FAILED_LOGIN_ATTEMPTS: u32 = 5;
+const LOCKOUT_MINUTES: i64 = 15;
+
+// 呼び出しごとに新しいソルトを生成してハッシュ化する
+fn hash_password(password: &str) -> anyhow::Result<String> {
+    let salt = SaltString::generate(&mut OsRng);
+    let password_hash = Argon2::default()
+        .hash_password(password.as_bytes(), &salt)
+        .map_err(|e| anyhow::anyhow!(e.to_string()))?
+        .to_string();
+    Ok(password_hash)
+}
+
+impl PasswordCredential {
+    pub fn new(user_id: UserId, password: &str) -> anyhow::Result<Self> {
+        Ok(Self {
+            user_id,
+            password_hash: hash_password(password)?,
+            failed_login_attempts: 0,
+            locked_until: None,
+        })
+    }
+
+    pub fn build(
+        user_id: UserId,
+        password_hash: String,
+        failed_login_attempts: u32,
+        locked_until: Option<DateTime<Utc>>,
+    ) -> Self {
+        Self {
+            user_id,
+            password_hash,
+            failed_login_attempts,
+            locked_until,
+        }
+    }
+
+    pub fn user_id(&self) -> &UserId {
+        &self.user_id
+    }
+
+    pub fn password_hash(&self) -> &str {
+        &self.password_hash
+    }
+
+    pub fn failed_login_attempts(&self) -> u32 {
+        self.failed_login_attempts
+    }
+
+    pub fn locked_until(&self) -> Option<DateTime<Utc>> {
+        self.locked_until
+    }
+
+    // ロックされていれば、解除される時刻を返す
+    pub fn locked_at(&self, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        self.locked_until.filter(|locked_until| *locked_until > now)
+    }
+
+    // パスワードを間違えた回数を数え、上限に達していればロックする
+    pub fn record_failed_login(&mut self, now: DateTime<Utc>) {
+        self.failed_login_attempts = self.failed_login_attempts.saturating_add(1);
+        if self.failed_login_attempts >= MAX_FAILED_LOGIN_ATTEMPTS {
+            self.locked_until = Some(now + Duration::minutes(LOCKOUT_MINUTES));
+        }
+    }
+
+    // ログインに成功したら、間違えた回数とロックを解除する
+    // 変更があった場合は true を返す
+    pub fn record_successful_login(&mut self) -> bool {
+        let changed = self.failed_login_attempts > 0 || self.locked_until.is_some();
+        self.failed_login_attempts = 0;
+        self.locked_until = None;
+        changed
+    }
+
+    // 与えられたパスワードが保持しているハッシュと一致するかを検証する
+    pub fn verify(&self, password: &str) -> bool {
+        match PasswordHash::new(&self.password_hash) {
+            Ok(password_hash) => Argon2::default()
+                .verify_password(password.as_bytes(), &password_hash)
+                .is_ok(),
+            Err(_) => false,
+        }
+    }
+
+    pub fn change_password(&mut self, new_password: &Password) -> anyhow::Result<()> {
+        self.password_hash = hash_password(new_password.value())?;
+        Ok(())
+    }
+}
+
+impl Entity for PasswordCredential {
+    type Identity = UserId;
+
+    fn identity(&self) -> &Self::Identity {
+        &self.user_id
+    }
+}
+
+impl PartialEq for PasswordCredential {
+    fn eq(&self, other: &Self) -> bool {
+        Entity::eq(self, other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use uuid::Uuid;
+
+    use super::*;
+
+    #[test]
+    fn should_verify_password() -> Result<()> {
+        let credential = PasswordCredential::new(UserId::new(Uuid::new_v4())?, "password1")?;
+
+        assert_ne!("password1", credential.password_hash());
+        assert!(credential.verify("password1"));
+        assert!(!credential.verify("password2"));
+        Ok(())
+    }
+
+    #[test]
+    fn should_verify_only_new_password_after_change() -> Result<()> {
+        let mut credential = PasswordCredential::new(UserId::new(Uuid::new_v4())?, "password1")?;
+        let old_password_hash = credential.password_hash().to_string();
+
+        credential.change_password(&Password::new("password2".to_string())?)?;
+
+        assert_ne!(old_password_hash, credential.password_hash());
+        assert!(!credential.verify("password1"));
+        assert!(credential.verify("password2"));
+        Ok(())
+    }
+
+    #[test]
+    fn should_lock_after_too_many_failed_logins() -> Result<()> {
+        let mut credential = PasswordCredential::new(UserId::new(Uuid::new_v4())?, "password1")?;
+        let now = Utc::now();
+
+        for _ in 0..MAX_FAILED_LOGIN_ATTEMPTS - 1 {
+            credential.record_failed_login(now);
+        }
+        assert_eq!(None, credential.locked_at(now));
+
+        credential.record_failed_login(now);
+        let locked_until = now + Duration::minutes(LOCKOUT_MINUTES);
+        assert_eq!(Some(locked_until), credential.locked_at(now));
+        // 解除される時刻を過ぎればロックされていない
+        assert_eq!(None, credential.locked_at(locked_until));
+
+        assert!(credential.record_successful_login());
+        assert_eq!(0, credential.failed_login_attempts());
+        assert_eq!(None, credential.locked_until());
+        assert!(!credential.record_successful_login());
+        Ok(())
+    }
+}
@@ -0,0 +1,5 @@
+pub use crate::domain::value_object::ValueObject;
+
+use crate::domain::value_object::impl_uuid_value_object;
+
+impl_uuid_value_object!(InvitationId, InvitationIdError, "invitation_id");
@@ -0,0 +1,34 @@
+use axum::async_trait;
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::domain::models::users::user_id::UserId;
+
+use super::{invitation_id::InvitationId, todo_list_invitation::TodoListInvitation};
+
+pub type Result<T> = anyhow::Result<T, InvitationRepositoryError>;
+
+#[async_trait]
+pub trait IInvitationRepository: Clone + Send + Sync + 'static {
+    async fn save(&self, invitation: &TodoListInvitation) -> Result<()>;
+    async fn find(&self, invite_id: &InvitationId) -> Result<Option<TodoListInvitation>>;
+    // 与えられたリストへの招待を、応答済みのものも含めて返す
+    async fn find_by_list(&self, list_id: &Uuid) -> Result<Vec<TodoListInvitation>>;
+    async fn delete(&self, invitation: TodoListInvitation) -> Result<()>;
+    // 招待を承諾したユーザーをリストのメンバーに加える。すでにメンバーであれば何もしない
+    async fn add_member(&self, list_id: &Uuid, user_id: &UserId) -> Result<()>;
+    // 承諾した招待の保存と、メンバーへの追加をまとめて 1 つのトランザクションで行う
+    async fn save_accepted(&self, invitation: &TodoListInvitation, user_id: &UserId) -> Result<()>;
+    // ユーザーがリストのメンバーであるかを返す
+    // メンバーがまだ一人もいないリストでは、そのユーザーを最初のメンバーとして加えたうえで true を返す
+    async fn join_if_first_or_is_member(&self, list_id: &Uuid, user_id: &UserId) -> Result<bool>;
+    async fn find_members_of(&self, list_id: &Uuid) -> Result<Vec<UserId>>;
+}
+
+#[derive(Debug, Error)]
+pub enum InvitationRepositoryError {
+    #[error("Invitation cannot be found, invitation id is {0:?}")]
+    NotFound(InvitationId),
+    #[error("Unexpected error: [{0}]")]
+    Unexpected(String),
+}
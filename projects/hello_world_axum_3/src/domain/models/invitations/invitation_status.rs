@@ -0,0 +1,54 @@
+use std::{fmt::Display, str::FromStr};
+
+use thiserror::Error;
+
+// 招待への応答状況
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InvitationStatus {
+    Pending,
+    Accepted,
+    Declined,
+    // 応答しないまま期限を過ぎた
+    Expired,
+}
+
+#[derive(Debug, Error)]
+pub enum InvitationStatusError {
+    #[error("Failure to parse string as invitation status: [{0}]")]
+    FailToParse(String),
+}
+
+impl InvitationStatus {
+    pub fn parse(s: String) -> Result<Self, InvitationStatusError> {
+        match s.as_str() {
+            "pending" => Ok(Self::Pending),
+            "accepted" => Ok(Self::Accepted),
+            "declined" => Ok(Self::Declined),
+            "expired" => Ok(Self::Expired),
+            _ => Err(InvitationStatusError::FailToParse(s)),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Pending => "pending",
+            Self::Accepted => "accepted",
+            Self::Declined => "declined",
+            Self::Expired => "expired",
+        }
+    }
+}
+
+impl FromStr for InvitationStatus {
+    type Err = InvitationStatusError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s.to_string())
+    }
+}
+
+impl Display for InvitationStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
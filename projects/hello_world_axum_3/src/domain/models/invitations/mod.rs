@@ -0,0 +1,4 @@
+pub mod invitation_id;
+pub mod invitation_repository;
+pub mod invitation_status;
+pub mod todo_list_invitation;
@@ -0,0 +1,299 @@
+use chrono::{DateTime, Duration, Utc};
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::domain::{entity::Entity, models::users::user_id::UserId, value_object::ValueObject};
+
+use super::{invitation_id::InvitationId, invitation_status::InvitationStatus};
+
+// 招待を送ってから応答できる期間
+pub const INVITATION_VALID_DAYS: i64 = 7;
+
+// entity
+// todo のリストを共同で使うために、ユーザーを招待する。招待メールはメールアドレスへ送る
+// リストそのものはまだモデルにないため、`list_id` はどのリストへの招待かを区別するためだけに使う
+#[derive(Debug, Clone)]
+pub struct TodoListInvitation {
+    invite_id: InvitationId,
+    list_id: Uuid,
+    invitee_email: String,
+    // 招待した相手のユーザー。招待に応答できるのはこのユーザーだけ
+    // 招待先のユーザーを記録する前に作られた招待では None になり、誰も応答できない
+    invitee_id: Option<UserId>,
+    invited_by: UserId,
+    status: InvitationStatus,
+    expires_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Error, PartialEq)]
+pub enum InvitationResponseError {
+    #[error("Invitation has expired at {0}")]
+    Expired(DateTime<Utc>),
+    #[error("Invitation has already been {0}")]
+    AlreadyResponded(InvitationStatus),
+    #[error("Invitation is not addressed to the user")]
+    NotInvitee,
+}
+
+impl TodoListInvitation {
+    // `now` から `INVITATION_VALID_DAYS` 日後まで応答できる招待を作る
+    pub fn new(
+        list_id: Uuid,
+        invitee_email: String,
+        invitee_id: UserId,
+        invited_by: UserId,
+        now: DateTime<Utc>,
+    ) -> anyhow::Result<Self> {
+        let invitee_email = invitee_email.trim().to_string();
+        if !is_email_address(&invitee_email) {
+            anyhow::bail!("Invitee email is not a valid email address.");
+        }
+        let invite_id = InvitationId::new(Uuid::new_v4())?;
+        Ok(Self {
+            invite_id,
+            list_id,
+            invitee_email,
+            invitee_id: Some(invitee_id),
+            invited_by,
+            status: InvitationStatus::Pending,
+            expires_at: now + Duration::days(INVITATION_VALID_DAYS),
+        })
+    }
+
+    pub fn build(
+        invite_id: InvitationId,
+        list_id: Uuid,
+        invitee_email: String,
+        invitee_id: Option<UserId>,
+        invited_by: UserId,
+        status: InvitationStatus,
+        expires_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            invite_id,
+            list_id,
+            invitee_email,
+            invitee_id,
+            invited_by,
+            status,
+            expires_at,
+        }
+    }
+
+    pub fn invite_id(&self) -> &InvitationId {
+        &self.invite_id
+    }
+
+    pub fn list_id(&self) -> &Uuid {
+        &self.list_id
+    }
+
+    pub fn invitee_email(&self) -> &str {
+        &self.invitee_email
+    }
+
+    pub fn invitee_id(&self) -> Option<&UserId> {
+        self.invitee_id.as_ref()
+    }
+
+    pub fn invited_by(&self) -> &UserId {
+        &self.invited_by
+    }
+
+    pub fn status(&self) -> InvitationStatus {
+        self.status
+    }
+
+    pub fn expires_at(&self) -> &DateTime<Utc> {
+        &self.expires_at
+    }
+
+    // 期限の時刻ちょうどになった時点で期限切れとする
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at <= now
+    }
+
+    pub fn accept(
+        &mut self,
+        user_id: &UserId,
+        now: DateTime<Utc>,
+    ) -> Result<(), InvitationResponseError> {
+        self.respond(user_id, now, InvitationStatus::Accepted)
+    }
+
+    pub fn decline(
+        &mut self,
+        user_id: &UserId,
+        now: DateTime<Utc>,
+    ) -> Result<(), InvitationResponseError> {
+        self.respond(user_id, now, InvitationStatus::Declined)
+    }
+
+    // 応答できるのは、招待された本人が保留中の招待に応答する場合のみ
+    // 期限を過ぎていた場合は、状態を `Expired` にしたうえでエラーを返す
+    fn respond(
+        &mut self,
+        user_id: &UserId,
+        now: DateTime<Utc>,
+        status: InvitationStatus,
+    ) -> Result<(), InvitationResponseError> {
+        // 本人以外が期限切れの状態に変えることもできないよう、最初に確かめる
+        if self.invitee_id.as_ref() != Some(user_id) {
+            return Err(InvitationResponseError::NotInvitee);
+        }
+        if self.status != InvitationStatus::Pending {
+            return Err(InvitationResponseError::AlreadyResponded(self.status));
+        }
+        if self.is_expired(now) {
+            self.status = InvitationStatus::Expired;
+            return Err(InvitationResponseError::Expired(self.expires_at));
+        }
+        self.status = status;
+        Ok(())
+    }
+}
+
+// 送信できるかまでは確かめず、`@` の前後に文字があることだけを確かめる
+fn is_email_address(s: &str) -> bool {
+    match s.split_once('@') {
+        Some((local, domain)) => {
+            !local.is_empty()
+                && !domain.is_empty()
+                && !domain.contains('@')
+                && !s.contains(char::is_whitespace)
+        }
+        None => false,
+    }
+}
+
+impl Entity for TodoListInvitation {
+    type Identity = InvitationId;
+
+    fn identity(&self) -> &Self::Identity {
+        &self.invite_id
+    }
+}
+
+impl PartialEq for TodoListInvitation {
+    fn eq(&self, other: &Self) -> bool {
+        Entity::eq(self, other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    fn invitation_for_test(
+        invitee_id: &UserId,
+        now: DateTime<Utc>,
+    ) -> anyhow::Result<TodoListInvitation> {
+        TodoListInvitation::new(
+            Uuid::new_v4(),
+            "invitee@example.com".to_string(),
+            invitee_id.clone(),
+            UserId::new(Uuid::new_v4())?,
+            now,
+        )
+    }
+
+    #[test]
+    fn should_reject_invalid_email_address() -> anyhow::Result<()> {
+        let now = Utc::now();
+        for invitee_email in ["", "invitee", "@example.com", "invitee@", "a@b@c", "a b@c"] {
+            let result = TodoListInvitation::new(
+                Uuid::new_v4(),
+                invitee_email.to_string(),
+                UserId::new(Uuid::new_v4())?,
+                UserId::new(Uuid::new_v4())?,
+                now,
+            );
+            assert!(result.is_err(), "{} should be rejected", invitee_email);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn should_accept_or_decline_pending_invitation_only_once() -> anyhow::Result<()> {
+        let now = Utc.with_ymd_and_hms(2023, 10, 31, 12, 0, 0).unwrap();
+        let invitee_id = UserId::new(Uuid::new_v4())?;
+
+        let mut invitation = invitation_for_test(&invitee_id, now)?;
+        invitation.accept(&invitee_id, now)?;
+        assert_eq!(InvitationStatus::Accepted, invitation.status());
+        assert_eq!(
+            Err(InvitationResponseError::AlreadyResponded(
+                InvitationStatus::Accepted
+            )),
+            invitation.decline(&invitee_id, now)
+        );
+
+        let mut invitation = invitation_for_test(&invitee_id, now)?;
+        invitation.decline(&invitee_id, now)?;
+        assert_eq!(InvitationStatus::Declined, invitation.status());
+        assert_eq!(
+            Err(InvitationResponseError::AlreadyResponded(
+                InvitationStatus::Declined
+            )),
+            invitation.accept(&invitee_id, now)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn should_expire_when_responded_after_expires_at() -> anyhow::Result<()> {
+        let now = Utc.with_ymd_and_hms(2023, 10, 31, 12, 0, 0).unwrap();
+        let invitee_id = UserId::new(Uuid::new_v4())?;
+        let mut invitation = invitation_for_test(&invitee_id, now)?;
+        let expires_at = *invitation.expires_at();
+        assert_eq!(now + Duration::days(INVITATION_VALID_DAYS), expires_at);
+
+        // 期限の直前までは応答できる
+        assert!(!invitation.is_expired(expires_at - Duration::seconds(1)));
+
+        assert_eq!(
+            Err(InvitationResponseError::Expired(expires_at)),
+            invitation.accept(&invitee_id, expires_at)
+        );
+        assert_eq!(InvitationStatus::Expired, invitation.status());
+        Ok(())
+    }
+
+    #[test]
+    fn should_let_only_invitee_respond() -> anyhow::Result<()> {
+        let now = Utc.with_ymd_and_hms(2023, 10, 31, 12, 0, 0).unwrap();
+        let invitee_id = UserId::new(Uuid::new_v4())?;
+        let other_user_id = UserId::new(Uuid::new_v4())?;
+
+        let mut invitation = invitation_for_test(&invitee_id, now)?;
+        assert_eq!(
+            Err(InvitationResponseError::NotInvitee),
+            invitation.accept(&other_user_id, now)
+        );
+        // 期限切れの招待でも、本人以外には状態を変えさせない
+        let expires_at = *invitation.expires_at();
+        assert_eq!(
+            Err(InvitationResponseError::NotInvitee),
+            invitation.decline(&other_user_id, expires_at)
+        );
+        assert_eq!(InvitationStatus::Pending, invitation.status());
+
+        // 招待先のユーザーが記録されていない招待には、誰も応答できない
+        let mut invitation = TodoListInvitation::build(
+            invitation.invite_id().clone(),
+            *invitation.list_id(),
+            invitation.invitee_email().to_string(),
+            None,
+            invitation.invited_by().clone(),
+            InvitationStatus::Pending,
+            expires_at,
+        );
+        assert_eq!(
+            Err(InvitationResponseError::NotInvitee),
+            invitation.accept(&invitee_id, now)
+        );
+        Ok(())
+    }
+}
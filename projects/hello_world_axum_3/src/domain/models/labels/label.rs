@@ -1,3 +1,6 @@
+use std::hash::{Hash, Hasher};
+
+use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
 use crate::domain::entity::Entity;
@@ -7,20 +10,39 @@ use super::label_id::LabelId;
 use super::label_name::LabelName;
 
 // entity
-#[derive(Debug, Clone, Eq, Hash)]
+#[derive(Debug, Clone, Eq)]
 pub struct Label {
     label_id: LabelId,
     pub label_name: LabelName,
+    // todo に付いたラベルとして読み出した場合のみ、todo に付けた日時を持つ
+    pub attached_at: Option<DateTime<Utc>>,
+    // ラベルの一覧を並べるときの位置で、小さいほど前に並ぶ
+    // DB の既定値と同じく、作成した時刻をマイクロ秒で表した値から始まる
+    // ラベルの一覧として読み出した場合のみ、保存されている位置を持つ
+    pub position: i64,
 }
 
+// 前後のラベルの位置の間隔が足りないときに、後ろのラベルをずらす幅
+pub const POSITION_GAP: i64 = 1_000_000;
+
 impl Label {
     pub fn new(label_name: LabelName) -> anyhow::Result<Self> {
         let label_id = LabelId::new(Uuid::new_v4())?;
-        Ok(Self { label_id, label_name })
+        Ok(Self {
+            label_id,
+            label_name,
+            attached_at: None,
+            position: Utc::now().timestamp_micros(),
+        })
     }
 
     pub fn build(label_id: LabelId, label_name: LabelName) -> Self {
-        Self { label_id, label_name }
+        Self {
+            label_id,
+            label_name,
+            attached_at: None,
+            position: 0,
+        }
     }
 
     pub fn label_id(&self) -> &LabelId {
@@ -28,6 +50,21 @@ impl Label {
     }
 }
 
+// `before` と `after` の間に並べるための位置を求める
+// どちらかが無い場合は、もう一方から `POSITION_GAP` だけ離れた位置にする
+// 間に入る整数が無い場合は `None` を返すので、呼び出し側で後ろのラベルをずらしてから求め直す
+pub fn position_between(before: Option<i64>, after: Option<i64>) -> Option<i64> {
+    match (before, after) {
+        (Some(before), Some(after)) => {
+            let middle = before + (after - before) / 2;
+            (before < middle && middle < after).then_some(middle)
+        }
+        (Some(before), None) => before.checked_add(POSITION_GAP),
+        (None, Some(after)) => after.checked_sub(POSITION_GAP),
+        (None, None) => Some(Utc::now().timestamp_micros()),
+    }
+}
+
 impl Entity for Label {
     type Identity = LabelId;
 
@@ -41,3 +78,25 @@ impl PartialEq for Label {
         Entity::eq(self, other)
     }
 }
+
+impl Hash for Label {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.identity().hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_find_position_between_neighbors() {
+        assert_eq!(Some(150), position_between(Some(100), Some(200)));
+        assert_eq!(Some(100 + POSITION_GAP), position_between(Some(100), None));
+        assert_eq!(Some(200 - POSITION_GAP), position_between(None, Some(200)));
+
+        // 間に入る整数が無い
+        assert_eq!(None, position_between(Some(100), Some(101)));
+        assert_eq!(None, position_between(Some(100), Some(100)));
+    }
+}
@@ -1,4 +1,7 @@
+use std::str::FromStr;
+
 use thiserror::Error;
+use unicode_normalization::UnicodeNormalization;
 
 pub use crate::domain::value_object::ValueObject;
 
@@ -21,10 +24,13 @@ impl ValueObject for LabelName {
     type Error = LabelNameError;
 
     fn new(value: Self::Value) -> anyhow::Result<Self, LabelNameError> {
+        // TodoText と同じく、NFC に正規化してから検証する
+        let value: String = value.nfc().collect();
         if value.is_empty() {
             return Err(LabelNameError::NameTooShortError);
         }
-        if value.len() >= 20 {
+        // TodoText と同じく、バイト数ではなく文字数で検証する
+        if value.chars().count() >= 20 {
             return Err(LabelNameError::NameTooLongError);
         }
         Ok(Self { value })
@@ -38,3 +44,143 @@ impl ValueObject for LabelName {
         self.value
     }
 }
+
+impl LabelName {
+    // 前後の空白を取り除いてから `new` と同じ検証を行う
+    pub fn parse(s: impl Into<String>) -> Result<Self, LabelNameError> {
+        Self::new(s.into().trim().to_string())
+    }
+
+    // URL に埋め込めるよう、英数字以外の文字の並びを `-` 一つに置き換えて小文字にしたもの
+    // PgLabelRepository::find_by_slug の SQL と同じ規則にすること
+    pub fn slug(&self) -> String {
+        self.value
+            .split(|c: char| !c.is_ascii_alphanumeric())
+            .filter(|word| !word.is_empty())
+            .map(|word| word.to_ascii_lowercase())
+            .collect::<Vec<String>>()
+            .join("-")
+    }
+
+    // 大文字と小文字を区別せずに、`prefix` で始まるかどうかを返す
+    // PgLabelRepository::find_starting_with の SQL と同じく、小文字にしてから比べる
+    pub fn starts_with(&self, prefix: &str) -> bool {
+        self.value
+            .to_lowercase()
+            .starts_with(&normalize_for_search(prefix))
+    }
+
+    // 大文字と小文字を区別せずに、`substr` を含むかどうかを返す
+    pub fn contains(&self, substr: &str) -> bool {
+        self.value
+            .to_lowercase()
+            .contains(&normalize_for_search(substr))
+    }
+}
+
+// 保存されている名前と同じく NFC に正規化してから小文字にする
+fn normalize_for_search(s: &str) -> String {
+    s.nfc().collect::<String>().to_lowercase()
+}
+
+// `"..".parse::<LabelName>()` の形で書けるようにする。`LabelName::parse` と同じく前後の空白を取り除く
+impl FromStr for LabelName {
+    type Err = LabelNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_parse_from_str_like_new() -> anyhow::Result<()> {
+        for value in ["label-1".to_string(), "".to_string(), "a".repeat(20)] {
+            assert_eq!(
+                LabelName::new(value.clone()).map_err(|e| e.to_string()),
+                value.parse::<LabelName>().map_err(|e| e.to_string())
+            );
+        }
+        assert_eq!(
+            LabelName::new("label-1".to_string())?,
+            "  label-1 ".parse::<LabelName>()?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn should_parse_like_new() {
+        assert_eq!(
+            LabelName::new("label-1".to_string()).map_err(|e| e.to_string()),
+            LabelName::parse("label-1").map_err(|e| e.to_string())
+        );
+        assert_eq!(
+            LabelName::new("".to_string()).map_err(|e| e.to_string()),
+            LabelName::parse("").map_err(|e| e.to_string())
+        );
+        assert_eq!(
+            LabelName::new("a".repeat(20)).map_err(|e| e.to_string()),
+            LabelName::parse("a".repeat(20)).map_err(|e| e.to_string())
+        );
+    }
+
+    #[test]
+    fn should_trim_before_validation_on_parse() {
+        assert_eq!("label-1", LabelName::parse(" label-1 ").unwrap().value());
+        assert_eq!(
+            LabelNameError::NameTooShortError.to_string(),
+            LabelName::parse("   ").unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn should_normalize_to_nfc() -> anyhow::Result<()> {
+        assert_eq!(
+            LabelName::new("caf\u{e9}".to_string())?,
+            LabelName::new("cafe\u{301}".to_string())?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn should_match_prefix_and_substring_case_insensitively() -> anyhow::Result<()> {
+        let label_name = LabelName::new("Work In Progress".to_string())?;
+        assert!(label_name.starts_with("work"));
+        assert!(label_name.starts_with(""));
+        assert!(!label_name.starts_with("XYZ"));
+        assert!(!label_name.starts_with("progress"));
+        assert!(label_name.contains("In"));
+        assert!(label_name.contains("PROGRESS"));
+        assert!(!label_name.contains("done"));
+
+        // 検索語も NFC に正規化してから比べる
+        let label_name = LabelName::new("Caf\u{e9}".to_string())?;
+        assert!(label_name.starts_with("cafe\u{301}"));
+        Ok(())
+    }
+
+    #[test]
+    fn should_validate_length_by_characters_instead_of_bytes() {
+        assert!(
+            LabelName::new("あ".repeat(19)).is_ok(),
+            "19 CJK characters (57 bytes) must be accepted"
+        );
+        assert!(
+            LabelName::new("あ".repeat(20)).is_err(),
+            "20 CJK characters must be rejected"
+        );
+    }
+
+    #[test]
+    fn should_make_slug_from_name() {
+        assert_eq!(
+            "work-in-progress",
+            LabelName::parse("Work In Progress").unwrap().slug()
+        );
+        assert_eq!("c-tasks", LabelName::parse("C++ Tasks!").unwrap().slug());
+        assert_eq!("label-1", LabelName::parse("label--1").unwrap().slug());
+    }
+}
@@ -1,23 +1,91 @@
+use std::collections::HashMap;
+
 use axum::async_trait;
 use thiserror::Error;
 
 use super::{label::Label, label_id::LabelId, label_name::LabelName};
+use crate::domain::models::users::user_id::UserId;
 
 pub type Result<T> = anyhow::Result<T, LabelRepositoryError>;
 
 #[async_trait]
 pub trait ILabelRepository: Clone + Send + Sync + 'static {
     async fn save(&self, label: &Label) -> Result<()>;
+    // `after_id` のラベルと、その次に並ぶラベルの間の位置に並べてラベルを保存する
+    // `after_id` が `None` の場合は先頭に並べる
+    async fn save_with_order(&self, label: &Label, after_id: Option<LabelId>) -> Result<()>;
+    // 全てのラベルを保存するか、一つも保存しないかのどちらかになる
+    async fn save_all(&self, labels: &[Label]) -> Result<()>;
+    // (id, 新しい名前) の組ごとにラベルの名前を変更し、変更後のラベルを返す
+    // 全てのラベルの名前を変更するか、一つも変更しないかのどちらかになる
+    async fn rename_bulk(&self, renames: &[(LabelId, LabelName)]) -> Result<Vec<Label>>;
     async fn find(&self, label_id: &LabelId) -> Result<Option<Label>>;
     async fn find_by_name(&self, label_name: &LabelName) -> Result<Option<Label>>;
+    // slug が同じラベルが複数ある場合は、そのうちの一つを返す
+    async fn find_by_slug(&self, slug: &str) -> Result<Option<Label>>;
+    // 大文字と小文字を区別せずに、名前が `prefix` で始まるラベルを名前順に返す
+    async fn find_starting_with(&self, prefix: &str) -> Result<Vec<Label>>;
+    // ドロップダウンなどで探しやすいよう、名前順に全てのラベルを返す
     async fn find_all(&self) -> Result<Vec<Label>>;
+    // 位置の小さい順に全てのラベルを返す
+    async fn find_all_ordered_by_position(&self) -> Result<Vec<Label>>;
+    // ラベルごとに、そのラベルが付いている todo の数を返す
+    // todo が一つも付いていないラベルは含まれないため、呼び出し側で 0 として扱う
+    async fn count_todos_per_label_map(&self) -> Result<HashMap<LabelId, u64>>;
+    // どの todo にも付いていないラベルを返す
+    async fn find_orphaned(&self) -> Result<Vec<Label>>;
+    // 指定したユーザーが担当している todo に付いているラベルを、重複を除いて名前順に返す
+    async fn find_by_user_id_via_todos(&self, user_id: &UserId) -> Result<Vec<Label>>;
+    // todo に最後に付けた日時が新しい順に、最大 `limit` 件のラベルを返す
+    // どの todo にも付いていないラベルは含まれない
+    async fn find_recently_used(&self, limit: u32) -> Result<Vec<Label>>;
     async fn delete(&self, label: Label) -> Result<()>;
+    // 全てのラベルを削除するか、一つも削除しないかのどちらかになる
+    // todo に付いているラベルは、その todo から外した上で削除する
+    async fn delete_all(&self, label_ids: &[LabelId]) -> Result<()>;
+    // 指定したラベルのうち、どの todo にも付いていないものだけを削除し、削除したラベルの id を返す
+    // 付いているかどうかの確認と削除を 1 回で行うため、その間に todo に付けられたラベルを消すことはない
+    async fn delete_all_unused(&self, label_ids: &[LabelId]) -> Result<Vec<LabelId>>;
 }
 
 #[derive(Debug, Error)]
 pub enum LabelRepositoryError {
     #[error("Label cannot be found, label id is {0:?}")]
     NotFound(LabelId),
+    #[error("Label already exists, label name is {0:?}")]
+    AlreadyExists(LabelName),
     #[error("Unexpected error: [{0}]")]
     Unexpected(String),
 }
+
+// application service の単体テストで、リポジトリの実装に依存せずに呼び出しを検証するためのモック
+// `Clone` を要求するため `#[automock]` ではなく `mock!` で定義する
+#[cfg(test)]
+mockall::mock! {
+    pub LabelRepository {}
+
+    impl Clone for LabelRepository {
+        fn clone(&self) -> Self;
+    }
+
+    #[async_trait]
+    impl ILabelRepository for LabelRepository {
+        async fn save(&self, label: &Label) -> Result<()>;
+        async fn save_with_order(&self, label: &Label, after_id: Option<LabelId>) -> Result<()>;
+        async fn save_all(&self, labels: &[Label]) -> Result<()>;
+        async fn rename_bulk(&self, renames: &[(LabelId, LabelName)]) -> Result<Vec<Label>>;
+        async fn find(&self, label_id: &LabelId) -> Result<Option<Label>>;
+        async fn find_by_name(&self, label_name: &LabelName) -> Result<Option<Label>>;
+        async fn find_by_slug(&self, slug: &str) -> Result<Option<Label>>;
+        async fn find_starting_with(&self, prefix: &str) -> Result<Vec<Label>>;
+        async fn find_all(&self) -> Result<Vec<Label>>;
+        async fn find_all_ordered_by_position(&self) -> Result<Vec<Label>>;
+        async fn count_todos_per_label_map(&self) -> Result<HashMap<LabelId, u64>>;
+        async fn find_orphaned(&self) -> Result<Vec<Label>>;
+        async fn find_by_user_id_via_todos(&self, user_id: &UserId) -> Result<Vec<Label>>;
+        async fn find_recently_used(&self, limit: u32) -> Result<Vec<Label>>;
+        async fn delete(&self, label: Label) -> Result<()>;
+        async fn delete_all(&self, label_ids: &[LabelId]) -> Result<()>;
+        async fn delete_all_unused(&self, label_ids: &[LabelId]) -> Result<Vec<LabelId>>;
+    }
+}
@@ -1,4 +1,4 @@
 pub mod label;
 pub mod label_id;
 pub mod label_name;
-pub mod label_repository;
\ No newline at end of file
+pub mod label_repository;
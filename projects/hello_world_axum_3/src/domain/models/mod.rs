@@ -1,3 +1,10 @@
+pub mod audit_logs;
+pub mod common;
+pub mod credentials;
+pub mod invitations;
 pub mod labels;
+pub mod sessions;
+pub mod todo_dependencies;
+pub mod todo_links;
+pub mod todos;
 pub mod users;
-pub mod todos;
\ No newline at end of file
@@ -0,0 +1,3 @@
+pub mod session;
+pub mod session_id;
+pub mod session_repository;
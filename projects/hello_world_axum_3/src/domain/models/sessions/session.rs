@@ -0,0 +1,97 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::{entity::Entity, models::users::user_id::UserId, value_object::ValueObject};
+
+use super::session_id::SessionId;
+
+// entity
+// ログインごとに発行され、ログアウトすると失効する
+#[derive(Debug, Clone)]
+pub struct Session {
+    session_id: SessionId,
+    user_id: UserId,
+    created_at: DateTime<Utc>,
+    revoked: bool,
+}
+
+impl Session {
+    pub fn new(user_id: UserId) -> anyhow::Result<Self> {
+        let session_id = SessionId::new(Uuid::new_v4())?;
+        Ok(Self {
+            session_id,
+            user_id,
+            created_at: Utc::now(),
+            revoked: false,
+        })
+    }
+
+    pub fn build(
+        session_id: SessionId,
+        user_id: UserId,
+        created_at: DateTime<Utc>,
+        revoked: bool,
+    ) -> Self {
+        Self {
+            session_id,
+            user_id,
+            created_at,
+            revoked,
+        }
+    }
+
+    pub fn session_id(&self) -> &SessionId {
+        &self.session_id
+    }
+
+    pub fn user_id(&self) -> &UserId {
+        &self.user_id
+    }
+
+    pub fn created_at(&self) -> &DateTime<Utc> {
+        &self.created_at
+    }
+
+    pub fn revoked(&self) -> bool {
+        self.revoked
+    }
+
+    // 一度失効したセッションを有効に戻す手段は用意しない
+    pub fn revoke(&mut self) {
+        self.revoked = true;
+    }
+
+    pub fn is_valid(&self) -> bool {
+        !self.revoked
+    }
+}
+
+impl Entity for Session {
+    type Identity = SessionId;
+
+    fn identity(&self) -> &Self::Identity {
+        &self.session_id
+    }
+}
+
+impl PartialEq for Session {
+    fn eq(&self, other: &Self) -> bool {
+        Entity::eq(self, other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_invalid_after_revoked() -> anyhow::Result<()> {
+        let mut session = Session::new(UserId::new(Uuid::new_v4())?)?;
+        assert!(session.is_valid());
+
+        session.revoke();
+
+        assert!(!session.is_valid());
+        Ok(())
+    }
+}
@@ -0,0 +1,20 @@
+use axum::async_trait;
+use thiserror::Error;
+
+use super::{session::Session, session_id::SessionId};
+
+pub type Result<T> = anyhow::Result<T, SessionRepositoryError>;
+
+#[async_trait]
+pub trait ISessionRepository: Clone + Send + Sync + 'static {
+    async fn save(&self, session: &Session) -> Result<()>;
+    async fn find(&self, session_id: &SessionId) -> Result<Option<Session>>;
+}
+
+#[derive(Debug, Error)]
+pub enum SessionRepositoryError {
+    #[error("Session cannot be found, session id is {0:?}")]
+    NotFound(SessionId),
+    #[error("Unexpected error: [{0}]")]
+    Unexpected(String),
+}
@@ -0,0 +1,3 @@
+pub mod dependency_id;
+pub mod todo_dependency;
+pub mod todo_dependency_repository;
@@ -0,0 +1,77 @@
+use uuid::Uuid;
+
+use crate::domain::entity::Entity;
+use crate::domain::models::todos::todo_id::TodoId;
+use crate::domain::value_object::ValueObject;
+
+use super::dependency_id::DependencyId;
+
+// entity
+// `from_todo_id` の todo が完了するまで `to_todo_id` の todo は完了できないことを表す
+#[derive(Debug, Clone)]
+pub struct TodoDependency {
+    dependency_id: DependencyId,
+    from_todo_id: TodoId,
+    to_todo_id: TodoId,
+}
+
+impl TodoDependency {
+    pub fn new(from_todo_id: TodoId, to_todo_id: TodoId) -> anyhow::Result<Self> {
+        if from_todo_id == to_todo_id {
+            anyhow::bail!("Todo cannot depend on itself.");
+        }
+        let dependency_id = DependencyId::new(Uuid::new_v4())?;
+        Ok(Self {
+            dependency_id,
+            from_todo_id,
+            to_todo_id,
+        })
+    }
+
+    pub fn build(dependency_id: DependencyId, from_todo_id: TodoId, to_todo_id: TodoId) -> Self {
+        Self {
+            dependency_id,
+            from_todo_id,
+            to_todo_id,
+        }
+    }
+
+    pub fn dependency_id(&self) -> &DependencyId {
+        &self.dependency_id
+    }
+
+    pub fn from_todo_id(&self) -> &TodoId {
+        &self.from_todo_id
+    }
+
+    pub fn to_todo_id(&self) -> &TodoId {
+        &self.to_todo_id
+    }
+}
+
+impl Entity for TodoDependency {
+    type Identity = DependencyId;
+
+    fn identity(&self) -> &Self::Identity {
+        &self.dependency_id
+    }
+}
+
+impl PartialEq for TodoDependency {
+    fn eq(&self, other: &Self) -> bool {
+        Entity::eq(self, other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_not_create_self_dependency() -> anyhow::Result<()> {
+        let todo_id = TodoId::new(Uuid::new_v4())?;
+
+        assert!(TodoDependency::new(todo_id.clone(), todo_id).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,27 @@
+use axum::async_trait;
+use thiserror::Error;
+
+use crate::domain::models::todos::todo_id::TodoId;
+
+use super::{dependency_id::DependencyId, todo_dependency::TodoDependency};
+
+pub type Result<T> = anyhow::Result<T, TodoDependencyRepositoryError>;
+
+#[async_trait]
+pub trait ITodoDependencyRepository: Clone + Send + Sync + 'static {
+    async fn add(&self, todo_dependency: &TodoDependency) -> Result<()>;
+    async fn remove(&self, todo_dependency: TodoDependency) -> Result<()>;
+    async fn find(&self, dependency_id: &DependencyId) -> Result<Option<TodoDependency>>;
+    // 与えられた todo が完了する前に完了していなければならない依存関係を返す
+    async fn find_dependencies_of(&self, todo_id: &TodoId) -> Result<Vec<TodoDependency>>;
+    // 与えられた todo の完了を待っている依存関係を返す
+    async fn find_dependents_of(&self, todo_id: &TodoId) -> Result<Vec<TodoDependency>>;
+}
+
+#[derive(Debug, Error)]
+pub enum TodoDependencyRepositoryError {
+    #[error("Todo dependency cannot be found, dependency id is {0:?}")]
+    NotFound(DependencyId),
+    #[error("Unexpected error: [{0}]")]
+    Unexpected(String),
+}
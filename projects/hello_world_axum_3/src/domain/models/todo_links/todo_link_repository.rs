@@ -0,0 +1,20 @@
+use axum::async_trait;
+use thiserror::Error;
+use url::Url;
+
+use crate::domain::models::todos::todo_id::TodoId;
+
+pub type Result<T> = anyhow::Result<T, TodoLinkRepositoryError>;
+
+#[async_trait]
+pub trait ITodoLinkRepository: Clone + Send + Sync + 'static {
+    // 保存は todo と同じトランザクションで行うため、todo リポジトリの save が受け持つ
+    // 保存した順に返す
+    async fn find_links(&self, todo_id: &TodoId) -> Result<Vec<Url>>;
+}
+
+#[derive(Debug, Error)]
+pub enum TodoLinkRepositoryError {
+    #[error("Unexpected error: [{0}]")]
+    Unexpected(String),
+}
@@ -0,0 +1,75 @@
+use serde::Deserialize;
+
+use crate::domain::models::labels::label_id::LabelId;
+
+use super::todo::Todo;
+
+// 複数のラベルで todo を絞り込むための条件
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LabelFilter {
+    pub ids: Vec<LabelId>,
+    pub operator: FilterOperator,
+}
+
+// And: すべてのラベルが付いている todo, Or: いずれかのラベルが付いている todo
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FilterOperator {
+    #[default]
+    And,
+    Or,
+}
+
+impl LabelFilter {
+    pub fn matches(&self, todo: &Todo) -> bool {
+        let has_label =
+            |label_id: &LabelId| todo.labels.iter().any(|label| label.label_id() == label_id);
+        match self.operator {
+            FilterOperator::And => self.ids.iter().all(has_label),
+            FilterOperator::Or => self.ids.iter().any(has_label),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use crate::domain::{
+        models::{
+            labels::{label::Label, label_name::LabelName},
+            todos::todo_text::TodoText,
+        },
+        value_object::ValueObject,
+    };
+
+    use super::*;
+
+    #[test]
+    fn should_match_todos_by_operator() -> Result<()> {
+        let label_a = Label::new(LabelName::new("label-a".to_string())?)?;
+        let label_b = Label::new(LabelName::new("label-b".to_string())?)?;
+        let todo_a = Todo::new(TodoText::new("todo-a".to_string())?, vec![label_a.clone()])?;
+        let todo_ab = Todo::new(
+            TodoText::new("todo-ab".to_string())?,
+            vec![label_a.clone(), label_b.clone()],
+        )?;
+        let todo_none = Todo::new(TodoText::new("todo-none".to_string())?, vec![])?;
+
+        let filter_of = |operator| LabelFilter {
+            ids: vec![label_a.label_id().clone(), label_b.label_id().clone()],
+            operator,
+        };
+
+        let and_filter = filter_of(FilterOperator::And);
+        assert!(!and_filter.matches(&todo_a));
+        assert!(and_filter.matches(&todo_ab));
+        assert!(!and_filter.matches(&todo_none));
+
+        let or_filter = filter_of(FilterOperator::Or);
+        assert!(or_filter.matches(&todo_a));
+        assert!(or_filter.matches(&todo_ab));
+        assert!(!or_filter.matches(&todo_none));
+        Ok(())
+    }
+}
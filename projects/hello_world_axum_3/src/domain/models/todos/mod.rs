@@ -1,4 +1,8 @@
+pub mod label_filter;
 pub mod todo;
+pub mod todo_filter;
 pub mod todo_id;
+pub mod todo_note;
+pub mod todo_repository;
+pub mod todo_status;
 pub mod todo_text;
-pub mod todo_repository;
\ No newline at end of file
@@ -1,46 +1,119 @@
-use std::collections::HashSet;
-
+use chrono::{DateTime, NaiveDate, Utc};
 use uuid::Uuid;
 
 use crate::domain::entity::Entity;
 use crate::domain::models::labels::label::Label;
+use crate::domain::models::users::user_id::UserId;
 use crate::domain::value_object::ValueObject;
 
 use super::todo_id::TodoId;
+use super::todo_note::TodoNote;
+use super::todo_status::TodoStatus;
 use super::todo_text::TodoText;
 
+// 1 つの todo に付けられるラベルの数の上限の既定値
+pub const MAX_LABELS_PER_TODO: usize = 10;
+
 // entity
 #[derive(Debug, Clone)]
 pub struct Todo {
     todo_id: TodoId,
     pub todo_text: TodoText,
+    pub note: Option<TodoNote>,
+    pub due_date: Option<NaiveDate>,
+    // 担当者。担当者のユーザーが削除された場合は未割り当てに戻る
+    pub assignee_id: Option<UserId>,
     pub completed: bool,
-    pub labels: HashSet<Label>,
+    // 表示順に並べたラベル。添字がそのまま並び順になる
+    pub labels: Vec<Label>,
+    // 完了にした日時。完了に変わった todo を保存するときにリポジトリが設定し、未完了に戻すと None になる
+    pub completed_at: Option<DateTime<Utc>>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+    // 更新のたびに 1 つ進める版。読み出してから保存するまでに他から更新されていないかの確認に使う
+    version: i64,
 }
 
 impl Todo {
-    pub fn new(todo_text: TodoText, labels: HashSet<Label>) -> anyhow::Result<Self> {
+    pub fn new(todo_text: TodoText, labels: Vec<Label>) -> anyhow::Result<Self> {
         let todo_id = TodoId::new(Uuid::new_v4())?;
+        let now = Utc::now();
         Ok(Self {
             todo_id,
             todo_text,
+            note: None,
+            due_date: None,
+            assignee_id: None,
             completed: false,
             labels,
+            completed_at: None,
+            created_at: now,
+            updated_at: now,
+            version: 1,
         })
     }
 
-    pub fn build(todo_id: TodoId, todo_text: TodoText, completed: bool, labels: HashSet<Label>) -> Self {
+    #[allow(clippy::too_many_arguments)]
+    pub fn build(
+        todo_id: TodoId,
+        todo_text: TodoText,
+        note: Option<TodoNote>,
+        due_date: Option<NaiveDate>,
+        assignee_id: Option<UserId>,
+        completed: bool,
+        labels: Vec<Label>,
+        completed_at: Option<DateTime<Utc>>,
+        created_at: DateTime<Utc>,
+        updated_at: DateTime<Utc>,
+        version: i64,
+    ) -> Self {
         Self {
             todo_id,
             todo_text,
+            note,
+            due_date,
+            assignee_id,
             completed,
-            labels
+            labels,
+            completed_at,
+            created_at,
+            updated_at,
+            version,
         }
     }
 
     pub fn todo_id(&self) -> &TodoId {
         &self.todo_id
     }
+
+    pub fn created_at(&self) -> &DateTime<Utc> {
+        &self.created_at
+    }
+
+    pub fn updated_at(&self) -> &DateTime<Utc> {
+        &self.updated_at
+    }
+
+    pub fn version(&self) -> i64 {
+        self.version
+    }
+
+    // 期限日の当日はまだ期限切れとして扱わない
+    pub fn status(&self, today: NaiveDate) -> TodoStatus {
+        if self.completed {
+            TodoStatus::Completed
+        } else if self.due_date.is_some_and(|due_date| due_date < today) {
+            TodoStatus::Overdue
+        } else {
+            TodoStatus::Active
+        }
+    }
+
+    // 更新日時を現在時刻に更新し、版を進める
+    pub fn touch(&mut self) {
+        self.updated_at = Utc::now();
+        self.version += 1;
+    }
 }
 
 impl Entity for Todo {
@@ -56,3 +129,41 @@ impl PartialEq for Todo {
         Entity::eq(self, other)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use chrono::Days;
+
+    use super::*;
+
+    #[test]
+    fn should_compute_status_from_completed_and_due_date() -> Result<()> {
+        let today = NaiveDate::from_ymd_opt(2023, 10, 31).unwrap();
+        let yesterday = today.checked_sub_days(Days::new(1));
+        let tomorrow = today.checked_add_days(Days::new(1));
+
+        for (completed, due_date, expected) in [
+            (false, None, TodoStatus::Active),
+            (false, tomorrow, TodoStatus::Active),
+            // 期限日の当日は期限切れではない
+            (false, Some(today), TodoStatus::Active),
+            (false, yesterday, TodoStatus::Overdue),
+            (true, None, TodoStatus::Completed),
+            // 完了していれば期限を過ぎていても完了として扱う
+            (true, yesterday, TodoStatus::Completed),
+        ] {
+            let mut todo = Todo::new(TodoText::new("todo".to_string())?, vec![])?;
+            todo.completed = completed;
+            todo.due_date = due_date;
+            assert_eq!(
+                expected,
+                todo.status(today),
+                "completed: {}, due_date: {:?}",
+                completed,
+                due_date
+            );
+        }
+        Ok(())
+    }
+}
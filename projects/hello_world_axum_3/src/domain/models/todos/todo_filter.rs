@@ -0,0 +1,27 @@
+use crate::domain::models::users::user_id::UserId;
+
+use super::{label_filter::LabelFilter, todo::Todo};
+
+// 担当者・ラベル・完了状態を組み合わせて todo を絞り込むための条件
+// None の項目では絞り込まない
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TodoFilter {
+    pub assignee_id: Option<UserId>,
+    pub label_filter: Option<LabelFilter>,
+    pub completed: Option<bool>,
+}
+
+impl TodoFilter {
+    pub fn matches(&self, todo: &Todo) -> bool {
+        self.assignee_id
+            .as_ref()
+            .is_none_or(|assignee_id| todo.assignee_id.as_ref() == Some(assignee_id))
+            && self
+                .label_filter
+                .as_ref()
+                .is_none_or(|label_filter| label_filter.matches(todo))
+            && self
+                .completed
+                .is_none_or(|completed| todo.completed == completed)
+    }
+}
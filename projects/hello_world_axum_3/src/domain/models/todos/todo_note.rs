@@ -0,0 +1,57 @@
+use thiserror::Error;
+
+pub use crate::domain::value_object::ValueObject;
+
+// value object
+// todo の本文 (markdown)。空文字列も許容する
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TodoNote {
+    value: String,
+}
+
+#[derive(Debug, Error)]
+pub enum TodoNoteError {
+    #[error("Todo note must be at most 5000 characters.")]
+    NoteTooLongError,
+}
+
+impl ValueObject for TodoNote {
+    type Value = String;
+    type Error = TodoNoteError;
+
+    fn new(value: Self::Value) -> Result<Self, TodoNoteError> {
+        // `len` はバイト数を返すため、日本語などの多バイト文字では文字数より早く上限に達してしまう
+        if value.chars().count() > 5000 {
+            return Err(TodoNoteError::NoteTooLongError);
+        }
+        Ok(Self { value })
+    }
+
+    fn value(&self) -> &Self::Value {
+        &self.value
+    }
+
+    fn into_value(self) -> Self::Value {
+        self.value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_count_characters_instead_of_bytes() {
+        // 1 文字 3 バイトなので 15000 バイトになる
+        let note = "あ".repeat(5000);
+        assert_eq!(15000, note.len());
+        assert!(
+            TodoNote::new(note).is_ok(),
+            "5000 CJK characters must be accepted"
+        );
+        assert!(
+            TodoNote::new("あ".repeat(5001)).is_err(),
+            "5001 CJK characters must be rejected"
+        );
+    }
+}
@@ -1,22 +1,131 @@
+use std::pin::Pin;
+
 use axum::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
 use thiserror::Error;
+use tokio_stream::Stream;
+use tokio_util::sync::CancellationToken;
+
+use crate::domain::models::{common::batch_result::BatchFindResult, users::user_id::UserId};
 
-use super::{todo::Todo, todo_id::TodoId};
+use super::{label_filter::LabelFilter, todo::Todo, todo_id::TodoId, todo_text::TodoText};
 
 pub type Result<T> = anyhow::Result<T, TodoRepositoryError>;
 
+// 全件を一度にメモリへ載せずに、1 件ずつ読み出すためのストリーム
+pub type TodoStream<'a> = Pin<Box<dyn Stream<Item = Result<Todo>> + Send + 'a>>;
+
 #[async_trait]
 pub trait ITodoRepository: Clone + Send + Sync + 'static {
+    // 保存済みの todo は、保存されている版が `todo.version() - 1` の場合にだけ上書きする
+    // 読み出してから他の書き込みで版が進んでいれば、上書きせずに `StaleData` を返す
     async fn save(&self, todo: &Todo) -> Result<()>;
     async fn find(&self, todo_id: &TodoId) -> Result<Option<Todo>>;
+    async fn find_by_text_exact(&self, todo_text: &TodoText) -> Result<Option<Todo>>;
     async fn find_all(&self) -> Result<Vec<Todo>>;
+    // 与えられた id の todo をまとめて返す。見つからない id は無視する
+    async fn find_many_by_ids(&self, todo_ids: &[TodoId]) -> Result<Vec<Todo>>;
+    // `find_many_by_ids` の結果に加えて、見つからなかった id も返す
+    async fn batch_find_result(
+        &self,
+        todo_ids: &[TodoId],
+    ) -> Result<BatchFindResult<Todo, TodoId>> {
+        let todos_found = self.find_many_by_ids(todo_ids).await?;
+        Ok(BatchFindResult::new(todo_ids, todos_found))
+    }
+    // 複数のラベルの条件に合う todo を返す
+    async fn find_by_label_filter(&self, label_filter: &LabelFilter) -> Result<Vec<Todo>>;
+    // 与えられたユーザーが担当者になっている todo を返す
+    async fn find_by_assignee(&self, assignee_id: &UserId) -> Result<Vec<Todo>>;
+    // 与えられたユーザーが担当している todo の (総数, 完了済みの数) を返す
+    async fn count_by_user_and_completion(&self, user_id: &UserId) -> Result<(u64, u64)>;
+    // todo 全体の件数、最後に更新された日時と版の合計を、全件を読み出さずに返す
+    async fn collection_version(&self) -> Result<TodoCollectionVersion>;
+    // 与えられたユーザーが担当している todo を完了した日付を、重複を除いて新しい順に最大 `limit` 件返す
+    // 完了した日時は記録していないため、完了済みの todo を最後に更新した日付 (UTC) を完了した日とみなす
+    async fn find_recently_completed_dates_by_user(
+        &self,
+        user_id: &UserId,
+        limit: u32,
+    ) -> Result<Vec<NaiveDate>>;
+    // 今日から `days` 日後までに期限を迎える未完了の todo を、期限の近い順に返す
+    async fn find_due_within(&self, days: u32) -> Result<Vec<Todo>>;
+    // 与えられた todo 以外で、text の類似度 (0.0 から 1.0) が `threshold` 以上の todo を
+    // 類似度の高い順に最大 `limit` 件、類似度と組にして返す
+    async fn find_similar_to(
+        &self,
+        todo_id: &TodoId,
+        threshold: f64,
+        limit: u32,
+    ) -> Result<Vec<(Todo, f64)>>;
+    // `cancellation_token` がキャンセルされたら、残りを読み出さずにストリームを終える
+    fn find_all_stream(&self, cancellation_token: CancellationToken) -> TodoStream<'_>;
     async fn delete(&self, todo: Todo) -> Result<()>;
+    // 与えられたユーザーが担当している完了済みの todo をまとめて削除し、削除した todo の id を返す
+    async fn delete_all_completed_by_user(&self, user_id: &UserId) -> Result<Vec<TodoId>>;
+    // 与えられた todo の完了状態をまとめて `completed` にし、変更した件数を返す
+    // すでに `completed` になっている todo は更新しない (更新日時も版も変わらない)
+    async fn set_completed_many(&self, todo_ids: &[TodoId], completed: bool) -> Result<u64>;
+}
+
+// todo の作成・更新・削除のいずれかがあれば変わる、todo 全体の版
+// 更新日時が前回と同じ時刻になった場合にも変わるよう、版の合計も含める
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TodoCollectionVersion {
+    pub count: u64,
+    pub last_updated_at: Option<DateTime<Utc>>,
+    pub version_sum: i64,
 }
 
 #[derive(Debug, Error)]
 pub enum TodoRepositoryError {
     #[error("Todo cannot be found, todo id is {0:?}")]
     NotFound(TodoId),
+    // 保存しようとした todo が、読み出したあとに他の書き込みで更新されていた
+    #[error("Todo has been updated by another request, todo id is {0:?}")]
+    StaleData(TodoId),
+    // クエリが制限時間内に終わらなかった
+    #[error("Query timed out")]
+    Timeout,
     #[error("Unexpected error: [{0}]")]
     Unexpected(String),
-}
\ No newline at end of file
+}
+
+// application service の単体テストで使うモック
+#[cfg(test)]
+mockall::mock! {
+    pub TodoRepository {}
+
+    impl Clone for TodoRepository {
+        fn clone(&self) -> Self;
+    }
+
+    #[async_trait]
+    impl ITodoRepository for TodoRepository {
+        async fn save(&self, todo: &Todo) -> Result<()>;
+        async fn find(&self, todo_id: &TodoId) -> Result<Option<Todo>>;
+        async fn find_by_text_exact(&self, todo_text: &TodoText) -> Result<Option<Todo>>;
+        async fn find_all(&self) -> Result<Vec<Todo>>;
+        async fn find_many_by_ids(&self, todo_ids: &[TodoId]) -> Result<Vec<Todo>>;
+        async fn find_by_label_filter(&self, label_filter: &LabelFilter) -> Result<Vec<Todo>>;
+        async fn find_by_assignee(&self, assignee_id: &UserId) -> Result<Vec<Todo>>;
+        async fn count_by_user_and_completion(&self, user_id: &UserId) -> Result<(u64, u64)>;
+        async fn collection_version(&self) -> Result<TodoCollectionVersion>;
+        async fn find_recently_completed_dates_by_user(
+            &self,
+            user_id: &UserId,
+            limit: u32,
+        ) -> Result<Vec<NaiveDate>>;
+        async fn find_due_within(&self, days: u32) -> Result<Vec<Todo>>;
+        async fn find_similar_to(
+            &self,
+            todo_id: &TodoId,
+            threshold: f64,
+            limit: u32,
+        ) -> Result<Vec<(Todo, f64)>>;
+        fn find_all_stream<'a>(&'a self, cancellation_token: CancellationToken) -> TodoStream<'a>;
+        async fn delete(&self, todo: Todo) -> Result<()>;
+        async fn delete_all_completed_by_user(&self, user_id: &UserId) -> Result<Vec<TodoId>>;
+        async fn set_completed_many(&self, todo_ids: &[TodoId], completed: bool) -> Result<u64>;
+    }
+}
@@ -0,0 +1,80 @@
+use std::{fmt::Display, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+// 完了状態と期限日から求める todo の状態。UI の表示を切り替えるために使う
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TodoStatus {
+    #[default]
+    Active,
+    Completed,
+    Overdue,
+    // todo をアーカイブする機能はまだ無いため、現在は `Todo::status` から返されることはない
+    Archived,
+}
+
+#[derive(Debug, Error)]
+pub enum TodoStatusError {
+    #[error("Failure to parse string as todo status: [{0}]")]
+    FailToParse(String),
+}
+
+impl TodoStatus {
+    // 大文字・小文字を区別せずにパースする
+    pub fn parse(s: String) -> Result<Self, TodoStatusError> {
+        match s.to_lowercase().as_str() {
+            "active" => Ok(Self::Active),
+            "completed" => Ok(Self::Completed),
+            "overdue" => Ok(Self::Overdue),
+            "archived" => Ok(Self::Archived),
+            _ => Err(TodoStatusError::FailToParse(s)),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Active => "active",
+            Self::Completed => "completed",
+            Self::Overdue => "overdue",
+            Self::Archived => "archived",
+        }
+    }
+}
+
+impl FromStr for TodoStatus {
+    type Err = TodoStatusError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s.to_string())
+    }
+}
+
+impl Display for TodoStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_parse_what_as_str_returns() {
+        for status in [
+            TodoStatus::Active,
+            TodoStatus::Completed,
+            TodoStatus::Overdue,
+            TodoStatus::Archived,
+        ] {
+            assert_eq!(status, status.as_str().parse::<TodoStatus>().unwrap());
+        }
+        assert_eq!(
+            TodoStatus::Overdue,
+            "OVERDUE".parse::<TodoStatus>().unwrap()
+        );
+        assert!("done".parse::<TodoStatus>().is_err());
+    }
+}
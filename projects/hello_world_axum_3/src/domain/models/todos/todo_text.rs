@@ -1,9 +1,30 @@
+use std::{str::FromStr, sync::OnceLock};
+
+use regex::Regex;
 use thiserror::Error;
+use unicode_normalization::UnicodeNormalization;
 
 pub use crate::domain::value_object::ValueObject;
 
+use crate::domain::services::todo_link_service;
+
+// 開始タグ・終了タグ・コメントなど、`<` から `>` までをタグとみなす
+fn html_tag_pattern() -> &'static Regex {
+    static HTML_TAG_PATTERN: OnceLock<Regex> = OnceLock::new();
+    HTML_TAG_PATTERN.get_or_init(|| Regex::new(r"<[^>]+>").expect("invalid regex"))
+}
+
+// 先頭の 1 文字だけを大文字にし、残りはそのままにする
+fn sentence_case(s: &str) -> String {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
 // value object
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct TodoText {
     value: String,
 }
@@ -21,10 +42,13 @@ impl ValueObject for TodoText {
     type Error = TodoTextError;
 
     fn new(value: Self::Value) -> Result<Self, TodoTextError> {
+        // 見た目が同じでも符号化の異なる文字列 (é と e + 結合文字など) を同じ値として扱えるよう、
+        // NFC に正規化してから検証する
+        let value: String = value.nfc().collect();
         if value.is_empty() {
             return Err(TodoTextError::TextEnptyError);
         }
-        if value.len() >= 100 {
+        if value.chars().count() >= 100 {
             return Err(TodoTextError::TextTooLongError);
         }
         Ok(Self { value })
@@ -38,3 +62,213 @@ impl ValueObject for TodoText {
         self.value
     }
 }
+
+impl TodoText {
+    // 前後の空白を取り除いてから `new` と同じ検証を行う
+    pub fn parse(s: impl Into<String>) -> Result<Self, TodoTextError> {
+        Self::new(s.into().trim().to_string())
+    }
+
+    // HTML のタグを取り除いてから `parse` と同じ検証を行う
+    // 長さはタグを取り除いた後のテキストで検証する
+    pub fn sanitized(raw: impl Into<String>) -> Result<Self, TodoTextError> {
+        Self::parse(html_tag_pattern().replace_all(&raw.into(), ""))
+    }
+
+    // `sanitized` と同じくタグと前後の空白を取り除き、先頭の文字を大文字にしてから検証する
+    // 大文字にすると文字数が変わることがある (ß → SS など) ため、長さは大文字にした後のテキストで検証する
+    pub fn with_sentence_case(raw: impl Into<String>) -> Result<Self, TodoTextError> {
+        let raw = raw.into();
+        let stripped = html_tag_pattern().replace_all(&raw, "");
+        Self::new(sentence_case(stripped.trim()))
+    }
+
+    // 空白（タブや改行を含む）で区切られた単語の数
+    pub fn word_count(&self) -> usize {
+        self.value.split_whitespace().count()
+    }
+
+    // Unicode のスカラー値の数。画面に表示する文字数として使い、長さの検証もこの値で行う
+    pub fn char_count(&self) -> usize {
+        self.value.chars().count()
+    }
+
+    // UTF-8 でのバイト数
+    pub fn byte_count(&self) -> usize {
+        self.value.len()
+    }
+
+    // `todo_link_service::extract_links` で取り出せる URL を含むかどうか
+    pub fn contains_url(&self) -> bool {
+        !todo_link_service::extract_links(self).is_empty()
+    }
+}
+
+// `"..".parse::<TodoText>()` の形で書けるようにする。`TodoText::parse` と同じく前後の空白を取り除く
+impl FromStr for TodoText {
+    type Err = TodoTextError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_parse_from_str_like_new() -> anyhow::Result<()> {
+        for value in ["todo-1".to_string(), "".to_string(), "a".repeat(101)] {
+            assert_eq!(
+                TodoText::new(value.clone()).map_err(|e| e.to_string()),
+                value.parse::<TodoText>().map_err(|e| e.to_string())
+            );
+        }
+        assert_eq!(
+            TodoText::new("todo-1".to_string())?,
+            "  todo-1 ".parse::<TodoText>()?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn should_normalize_to_nfc() -> anyhow::Result<()> {
+        let nfc = TodoText::new("caf\u{e9}".to_string())?;
+        let nfd = TodoText::new("cafe\u{301}".to_string())?;
+        assert_eq!(nfc, nfd);
+        assert_eq!("caf\u{e9}", nfd.value());
+        Ok(())
+    }
+
+    #[test]
+    fn should_parse_like_new() {
+        assert_eq!(
+            TodoText::new("todo text".to_string()).map_err(|e| e.to_string()),
+            TodoText::parse("todo text").map_err(|e| e.to_string())
+        );
+        assert_eq!(
+            TodoText::new("".to_string()).map_err(|e| e.to_string()),
+            TodoText::parse("").map_err(|e| e.to_string())
+        );
+        assert_eq!(
+            TodoText::new("a".repeat(100)).map_err(|e| e.to_string()),
+            TodoText::parse("a".repeat(100)).map_err(|e| e.to_string())
+        );
+    }
+
+    #[test]
+    fn should_trim_before_validation_on_parse() {
+        assert_eq!("todo text", TodoText::parse(" todo text ").unwrap().value());
+        assert_eq!(
+            TodoTextError::TextEnptyError.to_string(),
+            TodoText::parse("   ").unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn should_strip_html_tags_on_sanitized() -> anyhow::Result<()> {
+        assert_eq!("Buy milk", TodoText::sanitized("<b>Buy milk</b>")?.value());
+        assert_eq!(
+            "alert(1) and milk",
+            TodoText::sanitized(r#"<script type="text/javascript">alert(1)</script> and milk"#)?
+                .value()
+        );
+        // タグではない `<` はそのまま残す
+        assert_eq!("1 < 2", TodoText::sanitized("1 < 2")?.value());
+
+        // タグを取り除くと空になる
+        assert_eq!(
+            TodoTextError::TextEnptyError.to_string(),
+            TodoText::sanitized("<br/>").unwrap_err().to_string()
+        );
+        // タグを取り除いても長すぎる
+        assert_eq!(
+            TodoTextError::TextTooLongError.to_string(),
+            TodoText::sanitized(format!("<i>{}</i>", "a".repeat(100)))
+                .unwrap_err()
+                .to_string()
+        );
+        // タグを含めると長すぎるが、取り除けば収まる
+        let todo_text = TodoText::sanitized(format!("<i>{}</i>", "a".repeat(99)))?;
+        assert_eq!(99, todo_text.byte_count());
+        Ok(())
+    }
+
+    #[test]
+    fn should_capitalize_only_first_char_on_with_sentence_case() -> anyhow::Result<()> {
+        assert_eq!(
+            "Buy milk",
+            TodoText::with_sentence_case("buy milk")?.value()
+        );
+        assert_eq!(
+            "BUY MILK",
+            TodoText::with_sentence_case("BUY MILK")?.value()
+        );
+        assert_eq!(
+            "Buy milk",
+            TodoText::with_sentence_case(" <b>buy</b> milk ")?.value()
+        );
+        // 大文字のない文字はそのまま
+        assert_eq!("日本語", TodoText::with_sentence_case("日本語")?.value());
+        assert_eq!(
+            TodoTextError::TextEnptyError.to_string(),
+            TodoText::with_sentence_case("  ").unwrap_err().to_string()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn should_validate_length_after_sentence_case() -> anyhow::Result<()> {
+        // "ß" は大文字にすると "SS" の 2 文字になる
+        let raw = format!("ß{}", "a".repeat(98));
+        assert_eq!(99, TodoText::sanitized(raw.clone())?.char_count());
+        assert_eq!(
+            TodoTextError::TextTooLongError.to_string(),
+            TodoText::with_sentence_case(raw).unwrap_err().to_string()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn should_count_words_and_chars() {
+        let todo_text = TodoText::new("hello world".to_string()).unwrap();
+        assert_eq!(2, todo_text.word_count());
+        assert_eq!(11, todo_text.char_count());
+        assert_eq!(11, todo_text.byte_count());
+    }
+
+    #[test]
+    fn should_distinguish_chars_from_bytes() {
+        let todo_text = TodoText::new("日本語".to_string()).unwrap();
+        assert_eq!(1, todo_text.word_count());
+        assert_eq!(3, todo_text.char_count());
+        assert_eq!(9, todo_text.byte_count());
+    }
+
+    #[test]
+    fn should_validate_length_by_characters_instead_of_bytes() {
+        assert!(
+            TodoText::new("あ".repeat(99)).is_ok(),
+            "99 CJK characters (297 bytes) must be accepted"
+        );
+        assert!(
+            TodoText::new("あ".repeat(100)).is_err(),
+            "100 CJK characters must be rejected"
+        );
+    }
+
+    #[test]
+    fn should_tell_whether_text_contains_url() {
+        let todo_text = TodoText::new("see http://example.com for details".to_string()).unwrap();
+        assert!(todo_text.contains_url());
+        let todo_text = TodoText::new("see example.com for details".to_string()).unwrap();
+        assert!(!todo_text.contains_url());
+    }
+
+    #[test]
+    fn should_count_words_separated_by_multiple_spaces_and_tabs() {
+        let todo_text = TodoText::new("buy  milk\t\tand \t eggs".to_string()).unwrap();
+        assert_eq!(4, todo_text.word_count());
+    }
+}
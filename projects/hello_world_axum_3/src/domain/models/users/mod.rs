@@ -1,4 +1,5 @@
 pub mod user;
 pub mod user_id;
 pub mod user_name;
-pub mod user_repository;
\ No newline at end of file
+pub mod user_repository;
+pub mod user_role;
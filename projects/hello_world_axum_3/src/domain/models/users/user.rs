@@ -1,3 +1,5 @@
+use chrono::{DateTime, Utc};
+use sha2::{Digest, Sha256};
 use uuid::Uuid;
 
 use crate::domain::entity::Entity;
@@ -5,27 +7,60 @@ use crate::domain::value_object::ValueObject;
 
 use super::user_id::UserId;
 use super::user_name::UserName;
+use super::user_role::UserRole;
 
 // entity
 #[derive(Debug, Clone)]
 pub struct User {
     user_id: UserId,
     pub user_name: UserName,
+    pub user_role: UserRole,
+    // 論理削除した日時。論理削除したユーザーはリポジトリから読み出されない
+    deleted_at: Option<DateTime<Utc>>,
 }
 
 impl User {
     pub fn new(user_name: UserName) -> anyhow::Result<Self> {
         let user_id = UserId::new(Uuid::new_v4())?;
-        Ok(Self { user_id, user_name })
+        Ok(Self {
+            user_id,
+            user_name,
+            user_role: UserRole::default(),
+            deleted_at: None,
+        })
     }
 
-    pub fn build(user_id: UserId, user_name: UserName) -> Self {
-        Self { user_id, user_name }
+    pub fn build(user_id: UserId, user_name: UserName, user_role: UserRole) -> Self {
+        Self {
+            user_id,
+            user_name,
+            user_role,
+            deleted_at: None,
+        }
+    }
+
+    pub fn with_deleted_at(mut self, deleted_at: Option<DateTime<Utc>>) -> Self {
+        self.deleted_at = deleted_at;
+        self
     }
 
     pub fn user_id(&self) -> &UserId {
         &self.user_id
     }
+
+    pub fn deleted_at(&self) -> Option<&DateTime<Utc>> {
+        self.deleted_at.as_ref()
+    }
+
+    // 個人を特定できる名前を取り除き、論理削除した日時を記録する
+    // 元の名前には戻せないが、削除したユーザー同士を区別できるよう ID のハッシュを名前に使う
+    pub fn anonymize(&mut self, deleted_at: DateTime<Utc>) {
+        let hash = format!("{:x}", Sha256::digest(self.user_id.value().as_bytes()));
+        // UserName の長さの上限に収まるよう、ハッシュは先頭だけを使う
+        let user_name = format!("deleted_user_{}", &hash[..6]);
+        self.user_name = UserName::new(user_name).expect("anonymized user name must be valid");
+        self.deleted_at = Some(deleted_at);
+    }
 }
 
 impl Entity for User {
@@ -41,3 +76,29 @@ impl PartialEq for User {
         Entity::eq(self, other)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use super::*;
+
+    #[test]
+    fn should_anonymize_user_name_by_hash_of_id() -> Result<()> {
+        let mut user = User::new(UserName::new("tester-1".to_string())?)?;
+        let mut other_user = User::new(UserName::new("tester-1".to_string())?)?;
+        let deleted_at = Utc::now();
+        user.anonymize(deleted_at);
+        other_user.anonymize(deleted_at);
+
+        assert!(user.user_name.value().starts_with("deleted_user_"));
+        assert_ne!(user.user_name, other_user.user_name);
+        assert_eq!(Some(&deleted_at), user.deleted_at());
+
+        // 同じユーザーであれば何度匿名化しても同じ名前になる
+        let user_name = user.user_name.clone();
+        user.anonymize(Utc::now());
+        assert_eq!(user_name, user.user_name);
+        Ok(())
+    }
+}
@@ -1,4 +1,7 @@
+use std::str::FromStr;
+
 use thiserror::Error;
+use unicode_normalization::UnicodeNormalization;
 
 pub use crate::domain::value_object::ValueObject;
 
@@ -21,11 +24,13 @@ impl ValueObject for UserName {
     type Error = UserNameError;
 
     fn new(value: Self::Value) -> Result<Self, Self::Error> {
+        // TodoText と同じく、NFC に正規化してから検証する
+        let value: String = value.nfc().collect();
         if value.len() < 3 {
-            return Err(UserNameError::NameTooShortError.into());
+            return Err(UserNameError::NameTooShortError);
         }
         if value.len() >= 20 {
-            return Err(UserNameError::NameTooLongError.into());
+            return Err(UserNameError::NameTooLongError);
         }
         Ok(Self { value })
     }
@@ -38,3 +43,73 @@ impl ValueObject for UserName {
         self.value
     }
 }
+
+impl UserName {
+    // 前後の空白を取り除いてから `new` と同じ検証を行う
+    pub fn parse(s: impl Into<String>) -> Result<Self, UserNameError> {
+        Self::new(s.into().trim().to_string())
+    }
+}
+
+// `"..".parse::<UserName>()` の形で書けるようにする。`UserName::parse` と同じく前後の空白を取り除く
+impl FromStr for UserName {
+    type Err = UserNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_parse_from_str_like_new() -> anyhow::Result<()> {
+        for value in ["tester-1".to_string(), "12".to_string(), "a".repeat(20)] {
+            assert_eq!(
+                UserName::new(value.clone()).map_err(|e| e.to_string()),
+                value.parse::<UserName>().map_err(|e| e.to_string())
+            );
+        }
+        assert_eq!(
+            UserName::new("tester-1".to_string())?,
+            "  tester-1 ".parse::<UserName>()?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn should_parse_like_new() {
+        assert_eq!(
+            UserName::new("tester-1".to_string()).map_err(|e| e.to_string()),
+            UserName::parse("tester-1").map_err(|e| e.to_string())
+        );
+        assert_eq!(
+            UserName::new("ab".to_string()).map_err(|e| e.to_string()),
+            UserName::parse("ab").map_err(|e| e.to_string())
+        );
+        assert_eq!(
+            UserName::new("a".repeat(20)).map_err(|e| e.to_string()),
+            UserName::parse("a".repeat(20)).map_err(|e| e.to_string())
+        );
+    }
+
+    #[test]
+    fn should_normalize_to_nfc() -> anyhow::Result<()> {
+        assert_eq!(
+            UserName::new("jos\u{e9}".to_string())?,
+            UserName::new("jose\u{301}".to_string())?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn should_trim_before_validation_on_parse() {
+        assert_eq!("tester-1", UserName::parse(" tester-1 ").unwrap().value());
+        assert_eq!(
+            UserNameError::NameTooShortError.to_string(),
+            UserName::parse(" ab ").unwrap_err().to_string()
+        );
+    }
+}
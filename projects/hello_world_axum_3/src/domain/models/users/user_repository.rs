@@ -1,7 +1,9 @@
+use std::collections::HashMap;
+
 use axum::async_trait;
 use thiserror::Error;
 
-use super::{user::User, user_id::UserId, user_name::UserName};
+use super::{user::User, user_id::UserId, user_name::UserName, user_role::UserRole};
 
 pub type Result<T> = anyhow::Result<T, UserRepositoryError>;
 
@@ -9,9 +11,21 @@ pub type Result<T> = anyhow::Result<T, UserRepositoryError>;
 pub trait IUserRepository: Clone + Send + Sync + 'static {
     async fn save(&self, user: &User) -> Result<()>;
     async fn find(&self, user_id: &UserId) -> Result<Option<User>>;
+    async fn exists(&self, user_id: &UserId) -> Result<bool>;
     async fn find_by_name(&self, user_name: &UserName) -> Result<Option<User>>;
     async fn find_all(&self) -> Result<Vec<User>>;
+    async fn find_all_by_role(&self, user_role: &UserRole) -> Result<Vec<User>>;
+    // ロールごとのユーザー数を返す
+    // ユーザーが一人もいないロールは含まれないため、呼び出し側で 0 として扱う
+    async fn count_by_role(&self) -> Result<HashMap<UserRole, u64>>;
+    // 名前が `query` で始まるユーザーを大文字小文字を区別せずに、名前順で最大 `limit` 件返す
+    async fn search_by_name(&self, query: &str, limit: u32) -> Result<Vec<User>>;
     async fn delete(&self, user: User) -> Result<()>;
+    // 匿名化したユーザーを論理削除として保存し、担当している todo を未割り当てに戻す
+    // 一部だけが反映されないよう、まとめて 1 つのトランザクションで行う
+    async fn soft_delete(&self, user: &User) -> Result<()>;
+    // 論理削除したユーザーも含めて物理削除する。管理者による削除でだけ使う
+    async fn hard_delete(&self, user_id: &UserId) -> Result<()>;
 }
 
 #[derive(Debug, Error)]
@@ -20,4 +34,29 @@ pub enum UserRepositoryError {
     NotFound(UserId),
     #[error("Unexpected error: [{0}]")]
     Unexpected(String),
-}
\ No newline at end of file
+}
+
+// application service の単体テストで使うモック
+#[cfg(test)]
+mockall::mock! {
+    pub UserRepository {}
+
+    impl Clone for UserRepository {
+        fn clone(&self) -> Self;
+    }
+
+    #[async_trait]
+    impl IUserRepository for UserRepository {
+        async fn save(&self, user: &User) -> Result<()>;
+        async fn find(&self, user_id: &UserId) -> Result<Option<User>>;
+        async fn exists(&self, user_id: &UserId) -> Result<bool>;
+        async fn find_by_name(&self, user_name: &UserName) -> Result<Option<User>>;
+        async fn find_all(&self) -> Result<Vec<User>>;
+        async fn find_all_by_role(&self, user_role: &UserRole) -> Result<Vec<User>>;
+        async fn count_by_role(&self) -> Result<HashMap<UserRole, u64>>;
+        async fn search_by_name(&self, query: &str, limit: u32) -> Result<Vec<User>>;
+        async fn delete(&self, user: User) -> Result<()>;
+        async fn soft_delete(&self, user: &User) -> Result<()>;
+        async fn hard_delete(&self, user_id: &UserId) -> Result<()>;
+    }
+}
@@ -0,0 +1,85 @@
+use std::{fmt::Display, str::FromStr};
+
+use thiserror::Error;
+
+// value object
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum UserRole {
+    Admin,
+    #[default]
+    Member,
+    Viewer,
+}
+
+#[derive(Debug, Error)]
+pub enum UserRoleError {
+    #[error("Failure to parse string as user_role: [{0}]")]
+    FailToParse(String),
+}
+
+impl UserRole {
+    // 大文字・小文字を区別せずにパースする
+    pub fn parse(s: String) -> Result<Self, UserRoleError> {
+        match s.to_lowercase().as_str() {
+            "admin" => Ok(Self::Admin),
+            "member" => Ok(Self::Member),
+            "viewer" => Ok(Self::Viewer),
+            _ => Err(UserRoleError::FailToParse(s)),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Admin => "Admin",
+            Self::Member => "Member",
+            Self::Viewer => "Viewer",
+        }
+    }
+}
+
+// `"..".parse::<UserRole>()` の形で書けるようにする。`UserRole::parse` と同じく大文字・小文字を区別しない
+impl FromStr for UserRole {
+    type Err = UserRoleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s.to_string())
+    }
+}
+
+impl Display for UserRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_parse_role_case_insensitively() {
+        assert_eq!(
+            UserRole::Admin,
+            UserRole::parse("admin".to_string()).unwrap()
+        );
+        assert_eq!(
+            UserRole::Member,
+            UserRole::parse("MEMBER".to_string()).unwrap()
+        );
+        assert_eq!(
+            UserRole::Viewer,
+            UserRole::parse("Viewer".to_string()).unwrap()
+        );
+    }
+
+    #[test]
+    fn should_parse_from_str_like_parse() {
+        assert_eq!(UserRole::Admin, "ADMIN".parse::<UserRole>().unwrap());
+        assert!("owner".parse::<UserRole>().is_err());
+    }
+
+    #[test]
+    fn should_fail_to_parse_unknown_role() {
+        assert!(UserRole::parse("owner".to_string()).is_err());
+    }
+}
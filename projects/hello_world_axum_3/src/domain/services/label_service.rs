@@ -1,6 +1,10 @@
 use std::sync::Arc;
 
-use crate::domain::models::labels::{label::Label, label_repository::ILabelRepository};
+use crate::domain::models::labels::{
+    label::Label,
+    label_name::LabelName,
+    label_repository::{ILabelRepository, LabelRepositoryError},
+};
 
 pub struct LabelService<T: ILabelRepository> {
     label_repository: Arc<T>,
@@ -19,4 +23,25 @@ impl<T: ILabelRepository> LabelService<T> {
             None => Ok(false),
         }
     }
+
+    // 同じ名前のラベルがあればそれを返し、なければ新しく作成して保存する
+    pub async fn find_or_create(&self, label_name: LabelName) -> anyhow::Result<Label> {
+        if let Some(label_found) = self.label_repository.find_by_name(&label_name).await? {
+            return Ok(label_found);
+        }
+
+        let new_label = Label::new(label_name)?;
+        match self.label_repository.save(&new_label).await {
+            Ok(()) => Ok(new_label),
+            // 検索してから保存するまでの間に、他のリクエストが同じ名前のラベルを保存した
+            Err(LabelRepositoryError::AlreadyExists(label_name)) => self
+                .label_repository
+                .find_by_name(&label_name)
+                .await?
+                .ok_or_else(|| {
+                    anyhow::anyhow!("Label disappeared while being created: [{:?}]", label_name)
+                }),
+            Err(e) => Err(e.into()),
+        }
+    }
 }
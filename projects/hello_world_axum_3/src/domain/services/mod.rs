@@ -1,2 +1,6 @@
 pub mod label_service;
-pub mod user_service;
\ No newline at end of file
+pub mod profanity_filter;
+pub mod streak_service;
+pub mod todo_link_service;
+pub mod todo_service;
+pub mod user_service;
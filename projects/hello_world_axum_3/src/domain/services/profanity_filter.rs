@@ -0,0 +1,69 @@
+use std::collections::HashSet;
+
+use crate::domain::value_object::ValueObject;
+
+// 名前に使えない語の一覧。ビルド時に埋め込む
+const PROFANITY_LIST: &str = include_str!("profanity_list.txt");
+
+pub struct ProfanityFilter {
+    words: HashSet<String>,
+}
+
+impl ProfanityFilter {
+    pub fn new() -> Self {
+        let words = PROFANITY_LIST
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(str::to_lowercase)
+            .collect();
+        Self { words }
+    }
+
+    // 空白や記号で区切った語のいずれかが一覧に含まれていれば true を返す
+    // 単語単位で比べるので、"scrap" のように一部に含むだけの語は対象にしない
+    pub fn contains_profanity<V>(&self, name: &V) -> bool
+    where
+        V: ValueObject<Value = String>,
+    {
+        name.value()
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|word| !word.is_empty())
+            .any(|word| self.words.contains(&word.to_lowercase()))
+    }
+}
+
+impl Default for ProfanityFilter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use crate::domain::models::{labels::label_name::LabelName, users::user_name::UserName};
+
+    use super::*;
+
+    #[test]
+    fn should_detect_word_in_list() -> Result<()> {
+        let profanity_filter = ProfanityFilter::new();
+
+        assert!(profanity_filter.contains_profanity(&UserName::new("idiot".to_string())?));
+        assert!(profanity_filter.contains_profanity(&UserName::new("the-Idiot_1".to_string())?));
+        assert!(profanity_filter.contains_profanity(&LabelName::new("CRAP".to_string())?));
+        Ok(())
+    }
+
+    #[test]
+    fn should_not_detect_name_without_word_in_list() -> Result<()> {
+        let profanity_filter = ProfanityFilter::new();
+
+        assert!(!profanity_filter.contains_profanity(&UserName::new("tester-1".to_string())?));
+        // 一覧の語を一部に含むだけの語は対象外
+        assert!(!profanity_filter.contains_profanity(&LabelName::new("scrap".to_string())?));
+        Ok(())
+    }
+}
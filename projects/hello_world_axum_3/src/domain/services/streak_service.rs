@@ -0,0 +1,130 @@
+use std::sync::Arc;
+
+use chrono::{Days, NaiveDate};
+
+use crate::domain::{
+    clock::{Clock, SystemClock},
+    models::{todos::todo_repository::ITodoRepository, users::user_id::UserId},
+};
+
+// 連続日数を求めるときに遡る日数の上限
+const STREAK_LOOKBACK_DAYS: u32 = 365;
+
+pub struct StreakService<T: ITodoRepository> {
+    todo_repository: Arc<T>,
+    clock: Arc<dyn Clock>,
+}
+
+impl<T: ITodoRepository> StreakService<T> {
+    pub fn new(todo_repository: Arc<T>) -> Self {
+        Self::with_clock(todo_repository, Arc::new(SystemClock))
+    }
+
+    pub fn with_clock(todo_repository: Arc<T>, clock: Arc<dyn Clock>) -> Self {
+        Self {
+            todo_repository,
+            clock,
+        }
+    }
+
+    // 今日から遡って、担当している todo を 1 つ以上完了した日が何日続いているかを返す
+    pub async fn compute_streak(&self, user_id: &UserId) -> anyhow::Result<u32> {
+        let completed_dates = self
+            .todo_repository
+            .find_recently_completed_dates_by_user(user_id, STREAK_LOOKBACK_DAYS)
+            .await?;
+        let today = self.clock.now().date_naive();
+        Ok(count_consecutive_days(&completed_dates, today))
+    }
+}
+
+// `dates` は重複のない新しい順に並んでいる必要がある
+// 今日完了していなければ 0 になり、1 日でも抜けた日があればそこで数えるのをやめる
+fn count_consecutive_days(dates: &[NaiveDate], today: NaiveDate) -> u32 {
+    let mut streak = 0;
+    let mut expected = Some(today);
+    // 時計のずれなどで今日より後の日付があっても、連続日数には含めない
+    for date in dates.iter().skip_while(|date| **date > today) {
+        if Some(*date) != expected {
+            break;
+        }
+        streak += 1;
+        expected = date.checked_sub_days(Days::new(1));
+    }
+    streak
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use chrono::{TimeZone, Utc};
+
+    use crate::{
+        domain::{
+            clock::FixedClock,
+            models::todos::{todo::Todo, todo_id::TodoId, todo_text::TodoText},
+            value_object::ValueObject,
+        },
+        infra::repository_impl::in_memory::todos::in_memory_todo_repository::InMemoryTodoRepository,
+    };
+
+    use super::*;
+
+    fn days_ago(today: NaiveDate, days: &[u64]) -> Vec<NaiveDate> {
+        days.iter()
+            .map(|days| today.checked_sub_days(Days::new(*days)).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn should_count_consecutive_days_from_today() {
+        let today = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+
+        assert_eq!(
+            3,
+            count_consecutive_days(&days_ago(today, &[0, 1, 2, 5]), today)
+        );
+        // 昨日完了していないので、今日だけになる
+        assert_eq!(1, count_consecutive_days(&days_ago(today, &[0, 2]), today));
+        // 今日完了していなければ、昨日まで続いていても 0 になる
+        assert_eq!(0, count_consecutive_days(&days_ago(today, &[1, 2]), today));
+        assert_eq!(0, count_consecutive_days(&[], today));
+    }
+
+    #[tokio::test]
+    async fn should_compute_streak_from_completed_todos_of_user() -> Result<()> {
+        let now = Utc.with_ymd_and_hms(2024, 3, 1, 12, 0, 0).unwrap();
+        let todo_repository = Arc::new(InMemoryTodoRepository::new());
+        let user_id = UserId::new(uuid::Uuid::new_v4())?;
+
+        // 今日・昨日・一昨日・5 日前に完了した todo と、昨日更新した未完了の todo
+        for (i, (days, completed)) in [(0, true), (1, true), (2, true), (5, true), (3, false)]
+            .into_iter()
+            .enumerate()
+        {
+            let updated_at = now - chrono::Duration::days(days);
+            let todo = Todo::build(
+                TodoId::new(uuid::Uuid::new_v4())?,
+                TodoText::new(format!("todo-{}", i))?,
+                None,
+                None,
+                Some(user_id.clone()),
+                completed,
+                vec![],
+                completed.then_some(updated_at),
+                updated_at,
+                updated_at,
+                1,
+            );
+            todo_repository.save(&todo).await?;
+        }
+
+        let streak_service = StreakService::with_clock(todo_repository, Arc::new(FixedClock(now)));
+        assert_eq!(3, streak_service.compute_streak(&user_id).await?);
+
+        // 他のユーザーの完了は数えない
+        let other_user_id = UserId::new(uuid::Uuid::new_v4())?;
+        assert_eq!(0, streak_service.compute_streak(&other_user_id).await?);
+        Ok(())
+    }
+}
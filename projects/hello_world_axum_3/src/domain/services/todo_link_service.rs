@@ -0,0 +1,72 @@
+use std::sync::OnceLock;
+
+use regex::Regex;
+use url::Url;
+
+use crate::domain::{models::todos::todo_text::TodoText, value_object::ValueObject};
+
+// RFC 3986 の URI に使える文字を簡略化したもの
+// クライアントでリンクとして表示するため、http と https のみを対象にする
+fn url_pattern() -> &'static Regex {
+    static URL_PATTERN: OnceLock<Regex> = OnceLock::new();
+    URL_PATTERN.get_or_init(|| {
+        Regex::new(r"(?i)\bhttps?://[a-z0-9\-._~:/?#\[\]@!$&'()*+,;=%]+").expect("invalid regex")
+    })
+}
+
+// 文末の句読点や括弧は URL の一部ではなく、文章の一部として扱う
+const TRAILING_PUNCTUATIONS: &[char] = &['.', ',', ';', ':', '!', '?', '\'', ')', ']'];
+
+// todo のテキストに含まれる URL を出現順に返す
+pub fn extract_links(text: &TodoText) -> Vec<Url> {
+    url_pattern()
+        .find_iter(text.value())
+        .map(|found| found.as_str().trim_end_matches(TRAILING_PUNCTUATIONS))
+        .filter_map(|candidate| Url::parse(candidate).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use super::*;
+
+    fn links_in(text: &str) -> Result<Vec<String>> {
+        let todo_text = TodoText::new(text.to_string())?;
+        Ok(extract_links(&todo_text)
+            .into_iter()
+            .map(String::from)
+            .collect())
+    }
+
+    #[test]
+    fn should_extract_url_in_text() -> Result<()> {
+        assert_eq!(
+            vec!["https://example.com/docs?page=1"],
+            links_in("read https://example.com/docs?page=1 first")?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn should_return_empty_vec_if_text_has_no_url() -> Result<()> {
+        assert!(links_in("buy milk")?.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn should_extract_every_url_in_order() -> Result<()> {
+        assert_eq!(
+            vec!["http://a.example/", "https://b.example/x"],
+            links_in("see http://a.example and (https://b.example/x).")?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn should_exclude_non_http_urls() -> Result<()> {
+        assert!(links_in("download ftp://example.com/file.txt")?.is_empty());
+        Ok(())
+    }
+}
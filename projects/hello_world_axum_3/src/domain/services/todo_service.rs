@@ -0,0 +1,22 @@
+use std::sync::Arc;
+
+use crate::domain::models::todos::{todo::Todo, todo_repository::ITodoRepository};
+
+pub struct TodoService<T: ITodoRepository> {
+    todo_repository: Arc<T>,
+}
+
+impl<T: ITodoRepository> TodoService<T> {
+    pub fn new(todo_repository: Arc<T>) -> Self {
+        Self { todo_repository }
+    }
+
+    pub async fn is_duplicated(&self, todo: &Todo) -> anyhow::Result<bool> {
+        let todo_text = &todo.todo_text;
+        let search_result = self.todo_repository.find_by_text_exact(todo_text).await?;
+        match search_result {
+            Some(todo_found) => Ok(!(&todo_found == todo)),
+            None => Ok(false),
+        }
+    }
+}
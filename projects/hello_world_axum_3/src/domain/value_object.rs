@@ -9,3 +9,113 @@ where
     fn value(&self) -> &Self::Value;
     fn into_value(self) -> Self::Value;
 }
+
+// Uuid を値に持つ id の value object を定義する
+// 構造体とパースエラーの型、ValueObject・Display・Serialize/Deserialize の実装をまとめて生成する
+// 第 3 引数はパースに失敗したときのエラーメッセージに使う名前
+macro_rules! impl_uuid_value_object {
+    ($name:ident, $error:ident, $label:literal) => {
+        // value object
+        #[derive(Debug, PartialEq, Eq, Hash, Clone, ::serde::Serialize, ::serde::Deserialize)]
+        #[serde(transparent)]
+        pub struct $name {
+            value: ::uuid::Uuid,
+        }
+
+        #[derive(Debug, ::thiserror::Error)]
+        pub enum $error {
+            #[error("Failure to parse string as {}: [{}]", $label, .0)]
+            FailToParse(String),
+        }
+
+        impl $crate::domain::value_object::ValueObject for $name {
+            type Value = ::uuid::Uuid;
+            type Error = $error;
+
+            fn new(value: ::uuid::Uuid) -> Result<Self, $error> {
+                Ok(Self { value })
+            }
+
+            fn value(&self) -> &Self::Value {
+                &self.value
+            }
+
+            fn into_value(self) -> Self::Value {
+                self.value
+            }
+        }
+
+        impl $name {
+            pub fn parse(s: String) -> Result<Self, $error> {
+                Ok(Self {
+                    value: ::uuid::Uuid::try_parse(&s)
+                        .map_err(|e| $error::FailToParse(e.to_string()))?,
+                })
+            }
+        }
+
+        impl ::std::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                write!(f, "{}", self.value)
+            }
+        }
+    };
+}
+
+pub(crate) use impl_uuid_value_object;
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use anyhow::Result;
+    use uuid::Uuid;
+
+    use super::*;
+
+    impl_uuid_value_object!(SampleId, SampleIdError, "sample_id");
+
+    #[test]
+    fn should_parse_uuid_string() -> Result<()> {
+        let uuid = Uuid::new_v4();
+
+        let sample_id = SampleId::parse(uuid.to_string())?;
+
+        assert_eq!(&uuid, sample_id.value());
+        assert_eq!(SampleId::new(uuid)?, sample_id);
+        assert_eq!(uuid.to_string(), sample_id.to_string());
+        Ok(())
+    }
+
+    #[test]
+    fn should_fail_to_parse_non_uuid_string() {
+        let error = SampleId::parse("not-a-uuid".to_string()).unwrap_err();
+
+        assert!(matches!(error, SampleIdError::FailToParse(_)));
+        assert!(error
+            .to_string()
+            .starts_with("Failure to parse string as sample_id: ["));
+    }
+
+    #[test]
+    fn should_hash_by_value() -> Result<()> {
+        let uuid = Uuid::new_v4();
+
+        let sample_ids = HashSet::from([SampleId::new(uuid)?, SampleId::new(uuid)?]);
+
+        assert_eq!(1, sample_ids.len());
+        Ok(())
+    }
+
+    #[test]
+    fn should_serialize_as_plain_uuid_string() -> Result<()> {
+        let uuid = Uuid::new_v4();
+        let sample_id = SampleId::new(uuid)?;
+
+        let json = serde_json::to_string(&sample_id)?;
+
+        assert_eq!(format!("\"{}\"", uuid), json);
+        assert_eq!(sample_id, serde_json::from_str::<SampleId>(&json)?);
+        Ok(())
+    }
+}
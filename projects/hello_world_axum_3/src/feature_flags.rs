@@ -0,0 +1,96 @@
+use std::env;
+
+// 機能ごとに API を有効化・無効化するためのフラグ
+// 環境変数で `false` が指定された機能のみ無効になる
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FeatureFlags {
+    pub enable_bulk_operations: bool,
+    pub enable_search: bool,
+    pub enable_sse: bool,
+    pub enable_export: bool,
+}
+
+impl Default for FeatureFlags {
+    fn default() -> Self {
+        Self {
+            enable_bulk_operations: true,
+            enable_search: true,
+            enable_sse: true,
+            enable_export: true,
+        }
+    }
+}
+
+impl FeatureFlags {
+    pub fn from_env() -> Self {
+        Self::from_lookup(|key| env::var(key).ok())
+    }
+
+    fn from_lookup(lookup: impl Fn(&str) -> Option<String>) -> Self {
+        let is_enabled = |key: &str| lookup(key).map(|value| value != "false").unwrap_or(true);
+        Self {
+            enable_bulk_operations: is_enabled("FEATURE_BULK_OPERATIONS_ENABLED"),
+            enable_search: is_enabled("FEATURE_SEARCH_ENABLED"),
+            enable_sse: is_enabled("FEATURE_SSE_ENABLED"),
+            enable_export: is_enabled("FEATURE_EXPORT_ENABLED"),
+        }
+    }
+
+    pub fn is_enabled(&self, feature: Feature) -> bool {
+        match feature {
+            Feature::BulkOperations => self.enable_bulk_operations,
+            Feature::Search => self.enable_search,
+            Feature::Sse => self.enable_sse,
+            Feature::Export => self.enable_export,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Feature {
+    BulkOperations,
+    Search,
+    Sse,
+    Export,
+}
+
+impl Feature {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Feature::BulkOperations => "bulk operations",
+            Feature::Search => "search",
+            Feature::Sse => "server-sent events",
+            Feature::Export => "export",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    #[test]
+    fn should_enable_every_feature_unless_disabled_explicitly() {
+        let env = HashMap::from([
+            ("FEATURE_SEARCH_ENABLED", "false"),
+            ("FEATURE_SSE_ENABLED", "true"),
+        ]);
+
+        let feature_flags =
+            FeatureFlags::from_lookup(|key| env.get(key).map(|value| value.to_string()));
+
+        assert_eq!(
+            FeatureFlags {
+                enable_bulk_operations: true,
+                enable_search: false,
+                enable_sse: true,
+                enable_export: true,
+            },
+            feature_flags
+        );
+        assert!(!feature_flags.is_enabled(Feature::Search));
+        assert!(feature_flags.is_enabled(Feature::Export));
+    }
+}
@@ -0,0 +1,52 @@
+use axum::async_trait;
+use sqlx::PgPool;
+
+// ヘルスチェックでデータベースに問い合わせるための trait
+// 応答時間の計測は呼び出し側で行う
+#[async_trait]
+pub trait HealthProbe: Send + Sync + 'static {
+    async fn ping(&self) -> anyhow::Result<()>;
+}
+
+pub struct PgHealthProbe {
+    pool: PgPool,
+}
+
+impl PgHealthProbe {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl HealthProbe for PgHealthProbe {
+    async fn ping(&self) -> anyhow::Result<()> {
+        sqlx::query("select 1").execute(&self.pool).await?;
+        Ok(())
+    }
+}
+
+// InMemory のリポジトリを使う場合は、問い合わせ先が無いので常に成功する
+pub struct InMemoryHealthProbe;
+
+#[async_trait]
+impl HealthProbe for InMemoryHealthProbe {
+    async fn ping(&self) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "database-test")]
+mod tests {
+    use anyhow::Result;
+
+    use super::*;
+    use crate::pg_pool;
+
+    #[tokio::test]
+    async fn should_ping_database() -> Result<()> {
+        let pool = pg_pool::connect_to_test_pg_pool().await;
+        PgHealthProbe::new(pool).ping().await
+    }
+}
@@ -1 +1,2 @@
-pub mod repository_impl;
\ No newline at end of file
+pub mod health_probe;
+pub mod repository_impl;
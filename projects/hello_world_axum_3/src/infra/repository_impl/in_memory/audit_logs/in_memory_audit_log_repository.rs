@@ -0,0 +1,53 @@
+use std::sync::{Arc, RwLock};
+
+use axum::async_trait;
+use uuid::Uuid;
+
+use crate::domain::models::audit_logs::{
+    audit_entry::AuditEntry,
+    audit_log_repository::{IAuditLogRepository, Result},
+};
+
+// 記録は追記のみのため、記録した順に並べて保持する
+#[derive(Clone)]
+pub struct InMemoryAuditLogRepository {
+    store: Arc<RwLock<Vec<AuditEntry>>>,
+}
+
+impl Default for InMemoryAuditLogRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InMemoryAuditLogRepository {
+    pub fn new() -> Self {
+        Self {
+            store: Arc::default(),
+        }
+    }
+}
+
+#[async_trait]
+impl IAuditLogRepository for InMemoryAuditLogRepository {
+    async fn save(&self, entry: &AuditEntry) -> Result<()> {
+        self.store.write().unwrap().push(entry.clone());
+        Ok(())
+    }
+
+    async fn find_by_resource(
+        &self,
+        resource_type: &str,
+        resource_id: &Uuid,
+    ) -> Result<Vec<AuditEntry>> {
+        let store = self.store.read().unwrap();
+        let entries_found = store
+            .iter()
+            .filter(|entry| {
+                entry.resource_type() == resource_type && entry.resource_id() == resource_id
+            })
+            .cloned()
+            .collect();
+        Ok(entries_found)
+    }
+}
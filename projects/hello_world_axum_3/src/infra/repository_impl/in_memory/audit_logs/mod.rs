@@ -0,0 +1 @@
+pub mod in_memory_audit_log_repository;
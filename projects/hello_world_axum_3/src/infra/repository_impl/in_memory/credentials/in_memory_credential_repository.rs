@@ -0,0 +1,57 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard},
+};
+
+use axum::async_trait;
+
+use crate::domain::models::{
+    credentials::{
+        credential_repository::{ICredentialRepository, Result},
+        password_credential::PasswordCredential,
+    },
+    users::user_id::UserId,
+};
+
+type CredentialStore = HashMap<UserId, PasswordCredential>;
+
+#[derive(Clone)]
+pub struct InMemoryCredentialRepository {
+    store: Arc<RwLock<CredentialStore>>,
+}
+
+impl Default for InMemoryCredentialRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InMemoryCredentialRepository {
+    pub fn new() -> Self {
+        Self {
+            store: Arc::default(),
+        }
+    }
+
+    pub fn write_store_ref(&self) -> RwLockWriteGuard<'_, CredentialStore> {
+        self.store.write().unwrap()
+    }
+
+    pub fn read_store_ref(&self) -> RwLockReadGuard<'_, CredentialStore> {
+        self.store.read().unwrap()
+    }
+}
+
+#[async_trait]
+impl ICredentialRepository for InMemoryCredentialRepository {
+    async fn save(&self, credential: &PasswordCredential) -> Result<()> {
+        let mut store = self.write_store_ref();
+        store.insert(credential.user_id().clone(), credential.clone());
+        Ok(())
+    }
+
+    async fn find_by_user_id(&self, user_id: &UserId) -> Result<Option<PasswordCredential>> {
+        let store = self.read_store_ref();
+        Ok(store.get(user_id).cloned())
+    }
+}
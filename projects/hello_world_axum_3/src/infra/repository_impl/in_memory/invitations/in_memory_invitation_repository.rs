@@ -0,0 +1,116 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard},
+};
+
+use axum::async_trait;
+use uuid::Uuid;
+
+use crate::domain::models::{
+    invitations::{
+        invitation_id::InvitationId,
+        invitation_repository::{IInvitationRepository, InvitationRepositoryError, Result},
+        todo_list_invitation::TodoListInvitation,
+    },
+    users::user_id::UserId,
+};
+
+type InvitationStore = HashMap<InvitationId, TodoListInvitation>;
+type MemberStore = HashMap<Uuid, Vec<UserId>>;
+
+#[derive(Clone)]
+pub struct InMemoryInvitationRepository {
+    store: Arc<RwLock<InvitationStore>>,
+    members: Arc<RwLock<MemberStore>>,
+}
+
+impl Default for InMemoryInvitationRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InMemoryInvitationRepository {
+    pub fn new() -> Self {
+        Self {
+            store: Arc::default(),
+            members: Arc::default(),
+        }
+    }
+
+    pub fn write_store_ref(&self) -> RwLockWriteGuard<'_, InvitationStore> {
+        self.store.write().unwrap()
+    }
+
+    pub fn read_store_ref(&self) -> RwLockReadGuard<'_, InvitationStore> {
+        self.store.read().unwrap()
+    }
+}
+
+#[async_trait]
+impl IInvitationRepository for InMemoryInvitationRepository {
+    async fn save(&self, invitation: &TodoListInvitation) -> Result<()> {
+        let mut store = self.write_store_ref();
+        store.insert(invitation.invite_id().clone(), invitation.clone());
+        Ok(())
+    }
+
+    async fn find(&self, invite_id: &InvitationId) -> Result<Option<TodoListInvitation>> {
+        let store = self.read_store_ref();
+        Ok(store.get(invite_id).cloned())
+    }
+
+    async fn find_by_list(&self, list_id: &Uuid) -> Result<Vec<TodoListInvitation>> {
+        let store = self.read_store_ref();
+        let invitations_found = store
+            .values()
+            .filter(|invitation| invitation.list_id() == list_id)
+            .cloned()
+            .collect();
+        Ok(invitations_found)
+    }
+
+    async fn delete(&self, invitation: TodoListInvitation) -> Result<()> {
+        let mut store = self.write_store_ref();
+        let invite_id = invitation.invite_id();
+        match store.remove(invite_id) {
+            Some(_) => Ok(()),
+            None => Err(InvitationRepositoryError::NotFound(invite_id.clone())),
+        }
+    }
+
+    async fn add_member(&self, list_id: &Uuid, user_id: &UserId) -> Result<()> {
+        let mut members = self.members.write().unwrap();
+        let members_of_list = members.entry(*list_id).or_default();
+        if !members_of_list.contains(user_id) {
+            members_of_list.push(user_id.clone());
+        }
+        Ok(())
+    }
+
+    async fn save_accepted(&self, invitation: &TodoListInvitation, user_id: &UserId) -> Result<()> {
+        // 途中の状態が見えないよう、両方のロックを取ってから書き込む
+        let mut store = self.write_store_ref();
+        let mut members = self.members.write().unwrap();
+        store.insert(invitation.invite_id().clone(), invitation.clone());
+        let members_of_list = members.entry(*invitation.list_id()).or_default();
+        if !members_of_list.contains(user_id) {
+            members_of_list.push(user_id.clone());
+        }
+        Ok(())
+    }
+
+    async fn join_if_first_or_is_member(&self, list_id: &Uuid, user_id: &UserId) -> Result<bool> {
+        let mut members = self.members.write().unwrap();
+        let members_of_list = members.entry(*list_id).or_default();
+        if members_of_list.is_empty() {
+            members_of_list.push(user_id.clone());
+        }
+        Ok(members_of_list.contains(user_id))
+    }
+
+    async fn find_members_of(&self, list_id: &Uuid) -> Result<Vec<UserId>> {
+        let members = self.members.read().unwrap();
+        Ok(members.get(list_id).cloned().unwrap_or_default())
+    }
+}
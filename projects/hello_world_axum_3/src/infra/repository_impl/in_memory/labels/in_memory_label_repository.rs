@@ -1,77 +1,395 @@
 use std::{
-    collections::HashMap,
-    sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard},
+    cmp::Reverse,
+    collections::{HashMap, HashSet},
+    sync::{Arc, Mutex},
 };
 
 use axum::async_trait;
+use chrono::{DateTime, Utc};
+use dashmap::DashMap;
 
-use crate::domain::models::labels::{
-    label::Label,
-    label_id::LabelId,
-    label_name::LabelName,
-    label_repository::{ILabelRepository, LabelRepositoryError, Result},
+use crate::{
+    domain::{
+        models::{
+            labels::{
+                label::{position_between, Label, POSITION_GAP},
+                label_id::LabelId,
+                label_name::LabelName,
+                label_repository::{ILabelRepository, LabelRepositoryError, Result},
+            },
+            users::user_id::UserId,
+        },
+        value_object::ValueObject,
+    },
+    infra::repository_impl::in_memory::todos::in_memory_todo_repository::InMemoryTodoRepository,
 };
 
-type TodoStore = HashMap<LabelId, Label>;
+type TodoStore = DashMap<LabelId, Label>;
 
 #[derive(Clone)]
 pub struct InMemoryLabelRepository {
-    store: Arc<RwLock<TodoStore>>,
+    store: Arc<TodoStore>,
+    // 名前の重複の確認や位置のずらしは複数のラベルにまたがるため、書き込みどうしはこのロックで 1 つずつ行う
+    // 読み出しはこのロックを待たない
+    write_lock: Arc<Mutex<()>>,
+    // ラベルの付いた todo を数えるときに参照する
+    todo_repository: Option<InMemoryTodoRepository>,
+}
+
+impl Default for InMemoryLabelRepository {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl InMemoryLabelRepository {
     pub fn new() -> Self {
         Self {
             store: Arc::default(),
+            write_lock: Arc::default(),
+            todo_repository: None,
         }
     }
 
-    pub fn write_store_ref(&self) -> RwLockWriteGuard<TodoStore> {
-        self.store.write().unwrap()
+    // DB の todo_labels テーブルの代わりに、todo_repository に保存された todo のラベルを数える
+    pub fn with_todo_repository(todo_repository: InMemoryTodoRepository) -> Self {
+        Self {
+            todo_repository: Some(todo_repository),
+            ..Self::new()
+        }
+    }
+
+    fn find_where(&self, predicate: impl Fn(&Label) -> bool) -> Vec<Label> {
+        self.store
+            .iter()
+            .filter(|entry| predicate(entry.value()))
+            .map(|entry| entry.value().clone())
+            .collect()
+    }
+
+    // 入力途中の名前からラベルの候補を返す
+    // 大文字と小文字を区別せず、`query` で始まるものを先に、途中に含むものを後に、それぞれ名前順に並べる
+    pub fn search_by_name(&self, query: &str) -> Vec<Label> {
+        let mut labels_found = self.find_where(|label| label.label_name.contains(query));
+        labels_found.sort_by(|a, b| {
+            (!a.label_name.starts_with(query), a.label_name.value())
+                .cmp(&(!b.label_name.starts_with(query), b.label_name.value()))
+        });
+        labels_found
+    }
+}
+
+// DB の UNIQUE 制約と同様に、別の id で同じ名前のラベルを保存できないようにする
+fn ensure_name_is_unique(store: &TodoStore, label: &Label) -> Result<()> {
+    let is_taken = store.iter().any(|stored_label| {
+        stored_label.label_name == label.label_name && stored_label.value() != label
+    });
+    if is_taken {
+        return Err(LabelRepositoryError::AlreadyExists(
+            label.label_name.clone(),
+        ));
     }
+    Ok(())
+}
 
-    pub fn read_store_ref(&self) -> RwLockReadGuard<TodoStore> {
-        self.store.read().unwrap()
+// DB の upsert と同じく、保存済みのラベルの位置は変えない
+fn insert_keeping_position(store: &TodoStore, label: &Label) {
+    let mut label = label.clone();
+    if let Some(stored_label) = store.get(label.label_id()) {
+        label.position = stored_label.position;
     }
+    store.insert(label.label_id().clone(), label);
 }
 
 #[async_trait]
 impl ILabelRepository for InMemoryLabelRepository {
     async fn save(&self, label: &Label) -> Result<()> {
-        let mut store = self.write_store_ref();
-        store.insert(label.label_id().clone(), label.clone());
+        let _write_lock = self.write_lock.lock().unwrap();
+        ensure_name_is_unique(&self.store, label)?;
+        insert_keeping_position(&self.store, label);
+        Ok(())
+    }
+
+    async fn save_with_order(&self, label: &Label, after_id: Option<LabelId>) -> Result<()> {
+        let _write_lock = self.write_lock.lock().unwrap();
+        let store = &self.store;
+        ensure_name_is_unique(store, label)?;
+        let before = after_id
+            .map(|after_id| {
+                store
+                    .get(&after_id)
+                    .map(|after_label| after_label.position)
+                    .ok_or(LabelRepositoryError::NotFound(after_id))
+            })
+            .transpose()?;
+        let next_position = |store: &TodoStore| {
+            store
+                .iter()
+                .filter(|other| other.value() != label)
+                .map(|other| other.position)
+                .filter(|position| before.is_none_or(|before| *position > before))
+                .min()
+        };
+
+        let position = match position_between(before, next_position(store)) {
+            Some(position) => position,
+            None => {
+                // 後ろに並ぶラベルをずらして間を空ける
+                for mut other in store.iter_mut().filter(|other| other.value() != label) {
+                    if before.is_none_or(|before| other.position > before) {
+                        other.position += POSITION_GAP;
+                    }
+                }
+                position_between(before, next_position(store))
+                    .expect("labels after the position must have been shifted")
+            }
+        };
+        let mut label = label.clone();
+        label.position = position;
+        store.insert(label.label_id().clone(), label);
         Ok(())
     }
 
+    async fn save_all(&self, labels: &[Label]) -> Result<()> {
+        let _write_lock = self.write_lock.lock().unwrap();
+        for label in labels {
+            ensure_name_is_unique(&self.store, label)?;
+        }
+        for label in labels {
+            insert_keeping_position(&self.store, label);
+        }
+        Ok(())
+    }
+
+    // 全ての変更を検証してから反映し、途中で失敗しても一部だけ変更されないようにする
+    async fn rename_bulk(&self, renames: &[(LabelId, LabelName)]) -> Result<Vec<Label>> {
+        let _write_lock = self.write_lock.lock().unwrap();
+        let renamed_store = (*self.store).clone();
+        let mut renamed_labels = Vec::with_capacity(renames.len());
+        for (label_id, label_name) in renames {
+            let mut label = renamed_store
+                .get_mut(label_id)
+                .ok_or_else(|| LabelRepositoryError::NotFound(label_id.clone()))?;
+            label.label_name = label_name.clone();
+            renamed_labels.push(label.clone());
+        }
+        for label in &renamed_labels {
+            ensure_name_is_unique(&renamed_store, label)?;
+        }
+
+        for label in &renamed_labels {
+            self.store.insert(label.label_id().clone(), label.clone());
+        }
+        Ok(renamed_labels)
+    }
+
     async fn find(&self, label_id: &LabelId) -> Result<Option<Label>> {
-        let store = self.read_store_ref();
-        Ok(store.get(label_id).map(|label| label.clone()))
+        Ok(self.store.get(label_id).map(|label| label.clone()))
     }
 
     async fn find_by_name(&self, label_name: &LabelName) -> Result<Option<Label>> {
-        let store = self.read_store_ref();
-        let label_found = store
-            .iter()
-            .find(|(_, label)| &label.label_name == label_name)
-            .map(|(_, label)| label.clone());
-        Ok(label_found)
+        Ok(self
+            .find_where(|label| &label.label_name == label_name)
+            .pop())
+    }
+
+    async fn find_by_slug(&self, slug: &str) -> Result<Option<Label>> {
+        Ok(self
+            .find_where(|label| label.label_name.slug() == slug)
+            .pop())
+    }
+
+    async fn find_starting_with(&self, prefix: &str) -> Result<Vec<Label>> {
+        let mut labels_found = self.find_where(|label| label.label_name.starts_with(prefix));
+        labels_found.sort_by(|a, b| a.label_name.value().cmp(b.label_name.value()));
+        Ok(labels_found)
     }
 
     async fn find_all(&self) -> Result<Vec<Label>> {
-        let store = self.read_store_ref();
-        let labels_found = store.iter().map(|(_, label)| label.clone()).collect();
+        let mut labels_found = self.find_where(|_| true);
+        labels_found.sort_by(|a, b| a.label_name.value().cmp(b.label_name.value()));
+        Ok(labels_found)
+    }
+
+    async fn find_all_ordered_by_position(&self) -> Result<Vec<Label>> {
+        let mut labels_found = self.find_where(|_| true);
+        labels_found.sort_by_key(|label| label.position);
+        Ok(labels_found)
+    }
+
+    async fn count_todos_per_label_map(&self) -> Result<HashMap<LabelId, u64>> {
+        let mut counts = HashMap::new();
+        let Some(todo_repository) = &self.todo_repository else {
+            return Ok(counts);
+        };
+        todo_repository.for_each_todo(|_, todo| {
+            for label in &todo.labels {
+                *counts.entry(label.label_id().clone()).or_insert(0) += 1;
+            }
+        });
+        Ok(counts)
+    }
+
+    async fn find_orphaned(&self) -> Result<Vec<Label>> {
+        let mut used_label_ids = HashSet::new();
+        if let Some(todo_repository) = &self.todo_repository {
+            todo_repository.for_each_todo(|_, todo| {
+                used_label_ids.extend(todo.labels.iter().map(|label| label.label_id().clone()));
+            });
+        }
+        Ok(self.find_where(|label| !used_label_ids.contains(label.label_id())))
+    }
+
+    async fn find_by_user_id_via_todos(&self, user_id: &UserId) -> Result<Vec<Label>> {
+        let Some(todo_repository) = &self.todo_repository else {
+            return Ok(vec![]);
+        };
+        let mut seen_label_ids = HashSet::new();
+        let mut labels_found = vec![];
+        todo_repository.for_each_todo(|_, todo| {
+            if todo.assignee_id.as_ref() != Some(user_id) {
+                return;
+            }
+            labels_found.extend(
+                todo.labels
+                    .iter()
+                    .filter(|label| seen_label_ids.insert(label.label_id().clone()))
+                    .cloned(),
+            );
+        });
+        labels_found.sort_by(|a: &Label, b| a.label_name.value().cmp(b.label_name.value()));
+        Ok(labels_found)
+    }
+
+    async fn find_recently_used(&self, limit: u32) -> Result<Vec<Label>> {
+        let Some(todo_repository) = &self.todo_repository else {
+            return Ok(vec![]);
+        };
+        // DB の `MAX(attached_at)` と同じく、ラベルごとに最後に付けた日時を求める
+        let mut last_used = HashMap::new();
+        todo_repository.for_each_todo(|_, todo| {
+            for label in &todo.labels {
+                let Some(attached_at) = label.attached_at else {
+                    continue;
+                };
+                last_used
+                    .entry(label.label_id().clone())
+                    .and_modify(|used_at: &mut DateTime<Utc>| {
+                        *used_at = (*used_at).max(attached_at)
+                    })
+                    .or_insert(attached_at);
+            }
+        });
+        let mut last_used: Vec<(LabelId, DateTime<Utc>)> = last_used.into_iter().collect();
+        last_used.sort_by_key(|(_, used_at)| Reverse(*used_at));
+
+        // 名前は todo に埋め込まれたものではなく、ラベルのストアにある最新のものを返す
+        let labels_found = last_used
+            .into_iter()
+            .filter_map(|(label_id, _)| self.store.get(&label_id).map(|label| label.clone()))
+            .take(limit as usize)
+            .collect();
         Ok(labels_found)
     }
 
     async fn delete(&self, label: Label) -> Result<()> {
-        let mut store = self.write_store_ref();
+        let _write_lock = self.write_lock.lock().unwrap();
         let label_id = label.label_id();
-        match store.get(label_id) {
-            Some(_) => store.remove(label_id),
-            None => {
-                return Err(LabelRepositoryError::NotFound(label_id.clone()));
-            }
+        self.store
+            .remove(label_id)
+            .ok_or_else(|| LabelRepositoryError::NotFound(label_id.clone()))?;
+        Ok(())
+    }
+
+    async fn delete_all(&self, label_ids: &[LabelId]) -> Result<()> {
+        let _write_lock = self.write_lock.lock().unwrap();
+        if let Some(label_id) = label_ids
+            .iter()
+            .find(|label_id| !self.store.contains_key(label_id))
+        {
+            return Err(LabelRepositoryError::NotFound(label_id.clone()));
+        }
+        for label_id in label_ids {
+            self.store.remove(label_id);
+        }
+        // DB の `ON DELETE CASCADE` の代わりに、todo からも外す
+        if let Some(todo_repository) = &self.todo_repository {
+            todo_repository.detach_labels(label_ids);
+        }
+        Ok(())
+    }
+
+    async fn delete_all_unused(&self, label_ids: &[LabelId]) -> Result<Vec<LabelId>> {
+        let _write_lock = self.write_lock.lock().unwrap();
+        let mut used_label_ids = HashSet::new();
+        if let Some(todo_repository) = &self.todo_repository {
+            todo_repository.for_each_todo(|_, todo| {
+                used_label_ids.extend(todo.labels.iter().map(|label| label.label_id().clone()));
+            });
+        }
+        let deleted_ids: Vec<LabelId> = label_ids
+            .iter()
+            .filter(|label_id| !used_label_ids.contains(label_id))
+            .filter(|label_id| self.store.remove(label_id).is_some())
+            .cloned()
+            .collect();
+        // todo のストア全体はロックできないため、確認から削除までの間に付けられたラベルは DB の `ON DELETE CASCADE` と同じく todo から外す
+        if let Some(todo_repository) = &self.todo_repository {
+            todo_repository.detach_labels(&deleted_ids);
+        }
+        Ok(deleted_ids)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn should_search_labels_by_prefix_then_substring() -> Result<()> {
+        let repository = InMemoryLabelRepository::new();
+        for name in ["Work In Progress", "Homework", "work-later", "Done"] {
+            repository
+                .save(&Label::new(LabelName::new(name.to_string())?)?)
+                .await?;
+        }
+        let names = |labels: Vec<Label>| {
+            labels
+                .into_iter()
+                .map(|label| label.label_name.into_value())
+                .collect::<Vec<_>>()
         };
+
+        assert_eq!(
+            vec!["Work In Progress", "work-later", "Homework"],
+            names(repository.search_by_name("WORK"))
+        );
+        assert_eq!(
+            vec!["Work In Progress", "work-later"],
+            names(repository.find_starting_with("work").await?)
+        );
+        assert!(repository.find_starting_with("XYZ").await?.is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_find_all_labels_in_order_of_name() -> Result<()> {
+        let repository = InMemoryLabelRepository::new();
+        for name in ["Zebra", "apple", "Apple", "Mango"] {
+            repository
+                .save(&Label::new(LabelName::new(name.to_string())?)?)
+                .await?;
+        }
+
+        let names: Vec<String> = repository
+            .find_all()
+            .await?
+            .into_iter()
+            .map(|label| label.label_name.into_value())
+            .collect();
+        assert_eq!(vec!["Apple", "Mango", "Zebra", "apple"], names);
         Ok(())
     }
 }
@@ -1,2 +1 @@
 pub mod in_memory_label_repository;
-
@@ -0,0 +1,55 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard},
+};
+
+use axum::async_trait;
+
+use crate::domain::models::sessions::{
+    session::Session,
+    session_id::SessionId,
+    session_repository::{ISessionRepository, Result},
+};
+
+type SessionStore = HashMap<SessionId, Session>;
+
+#[derive(Clone)]
+pub struct InMemorySessionRepository {
+    store: Arc<RwLock<SessionStore>>,
+}
+
+impl Default for InMemorySessionRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InMemorySessionRepository {
+    pub fn new() -> Self {
+        Self {
+            store: Arc::default(),
+        }
+    }
+
+    pub fn write_store_ref(&self) -> RwLockWriteGuard<'_, SessionStore> {
+        self.store.write().unwrap()
+    }
+
+    pub fn read_store_ref(&self) -> RwLockReadGuard<'_, SessionStore> {
+        self.store.read().unwrap()
+    }
+}
+
+#[async_trait]
+impl ISessionRepository for InMemorySessionRepository {
+    async fn save(&self, session: &Session) -> Result<()> {
+        let mut store = self.write_store_ref();
+        store.insert(session.session_id().clone(), session.clone());
+        Ok(())
+    }
+
+    async fn find(&self, session_id: &SessionId) -> Result<Option<Session>> {
+        let store = self.read_store_ref();
+        Ok(store.get(session_id).cloned())
+    }
+}
@@ -0,0 +1,97 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard},
+};
+
+use axum::async_trait;
+
+use crate::domain::models::{
+    todo_dependencies::{
+        dependency_id::DependencyId,
+        todo_dependency::TodoDependency,
+        todo_dependency_repository::{
+            ITodoDependencyRepository, Result, TodoDependencyRepositoryError,
+        },
+    },
+    todos::todo_id::TodoId,
+};
+
+type TodoDependencyStore = HashMap<DependencyId, TodoDependency>;
+
+#[derive(Clone)]
+pub struct InMemoryTodoDependencyRepository {
+    store: Arc<RwLock<TodoDependencyStore>>,
+}
+
+impl Default for InMemoryTodoDependencyRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InMemoryTodoDependencyRepository {
+    pub fn new() -> Self {
+        Self {
+            store: Arc::default(),
+        }
+    }
+
+    pub fn write_store_ref(&self) -> RwLockWriteGuard<'_, TodoDependencyStore> {
+        self.store.write().unwrap()
+    }
+
+    pub fn read_store_ref(&self) -> RwLockReadGuard<'_, TodoDependencyStore> {
+        self.store.read().unwrap()
+    }
+}
+
+#[async_trait]
+impl ITodoDependencyRepository for InMemoryTodoDependencyRepository {
+    async fn add(&self, todo_dependency: &TodoDependency) -> Result<()> {
+        let mut store = self.write_store_ref();
+        store.insert(
+            todo_dependency.dependency_id().clone(),
+            todo_dependency.clone(),
+        );
+        Ok(())
+    }
+
+    async fn remove(&self, todo_dependency: TodoDependency) -> Result<()> {
+        let mut store = self.write_store_ref();
+        let dependency_id = todo_dependency.dependency_id();
+        match store.get(dependency_id) {
+            Some(_) => store.remove(dependency_id),
+            None => {
+                return Err(TodoDependencyRepositoryError::NotFound(
+                    dependency_id.clone(),
+                ));
+            }
+        };
+        Ok(())
+    }
+
+    async fn find(&self, dependency_id: &DependencyId) -> Result<Option<TodoDependency>> {
+        let store = self.read_store_ref();
+        Ok(store.get(dependency_id).cloned())
+    }
+
+    async fn find_dependencies_of(&self, todo_id: &TodoId) -> Result<Vec<TodoDependency>> {
+        let store = self.read_store_ref();
+        let dependencies_found = store
+            .values()
+            .filter(|todo_dependency| todo_dependency.to_todo_id() == todo_id)
+            .cloned()
+            .collect();
+        Ok(dependencies_found)
+    }
+
+    async fn find_dependents_of(&self, todo_id: &TodoId) -> Result<Vec<TodoDependency>> {
+        let store = self.read_store_ref();
+        let dependents_found = store
+            .values()
+            .filter(|todo_dependency| todo_dependency.from_todo_id() == todo_id)
+            .cloned()
+            .collect();
+        Ok(dependents_found)
+    }
+}
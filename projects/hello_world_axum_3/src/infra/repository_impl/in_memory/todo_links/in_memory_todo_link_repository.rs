@@ -0,0 +1,89 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard},
+};
+
+use axum::async_trait;
+use url::Url;
+
+use crate::domain::models::{
+    todo_links::todo_link_repository::{ITodoLinkRepository, Result},
+    todos::todo_id::TodoId,
+};
+
+type TodoLinkStore = HashMap<TodoId, Vec<Url>>;
+
+#[derive(Clone)]
+pub struct InMemoryTodoLinkRepository {
+    store: Arc<RwLock<TodoLinkStore>>,
+}
+
+impl Default for InMemoryTodoLinkRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InMemoryTodoLinkRepository {
+    pub fn new() -> Self {
+        Self {
+            store: Arc::default(),
+        }
+    }
+
+    pub fn write_store_ref(&self) -> RwLockWriteGuard<'_, TodoLinkStore> {
+        self.store.write().unwrap()
+    }
+
+    pub fn read_store_ref(&self) -> RwLockReadGuard<'_, TodoLinkStore> {
+        self.store.read().unwrap()
+    }
+
+    // 与えられた todo の URL を、保存済みのものと置き換える
+    // DB では todo と同じトランザクションで保存するため、InMemoryTodoRepository の save から呼ぶ
+    pub fn save_links(&self, todo_id: &TodoId, links: Vec<Url>) {
+        let mut store = self.write_store_ref();
+        if links.is_empty() {
+            store.remove(todo_id);
+        } else {
+            store.insert(todo_id.clone(), links);
+        }
+    }
+}
+
+#[async_trait]
+impl ITodoLinkRepository for InMemoryTodoLinkRepository {
+    async fn find_links(&self, todo_id: &TodoId) -> Result<Vec<Url>> {
+        let store = self.read_store_ref();
+        Ok(store.get(todo_id).cloned().unwrap_or_default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::domain::value_object::ValueObject;
+
+    #[tokio::test]
+    async fn should_replace_saved_links() -> Result<()> {
+        let repository = InMemoryTodoLinkRepository::new();
+        let todo_id = TodoId::new(Uuid::new_v4())?;
+
+        assert!(repository.find_links(&todo_id).await?.is_empty());
+
+        let links = vec![
+            Url::parse("http://a.example")?,
+            Url::parse("http://b.example")?,
+        ];
+        repository.save_links(&todo_id, links.clone());
+        assert_eq!(links, repository.find_links(&todo_id).await?);
+
+        repository.save_links(&todo_id, vec![]);
+        assert!(repository.find_links(&todo_id).await?.is_empty());
+        assert!(repository.read_store_ref().is_empty());
+        Ok(())
+    }
+}
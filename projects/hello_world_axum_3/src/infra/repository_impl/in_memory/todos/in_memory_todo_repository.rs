@@ -1,67 +1,775 @@
-use std::{
-    collections::HashMap,
-    sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard},
-};
+use std::{collections::BTreeSet, sync::Arc};
 
 use axum::async_trait;
+use chrono::{Days, NaiveDate, Utc};
+use dashmap::{mapref::entry::Entry, DashMap};
+use futures_util::StreamExt;
+use tokio_util::sync::CancellationToken;
 
-use crate::domain::models::todos::{
-    todo::Todo,
-    todo_id::TodoId,
-    todo_repository::{ITodoRepository, Result, TodoRepositoryError},
+use crate::{
+    domain::{
+        clock::{Clock, SystemClock},
+        models::{
+            labels::label_id::LabelId,
+            todos::{
+                label_filter::LabelFilter,
+                todo::Todo,
+                todo_filter::TodoFilter,
+                todo_id::TodoId,
+                todo_repository::{
+                    ITodoRepository, Result, TodoCollectionVersion, TodoRepositoryError, TodoStream,
+                },
+                todo_text::TodoText,
+            },
+            users::user_id::UserId,
+        },
+        services::todo_link_service,
+        value_object::ValueObject,
+    },
+    infra::repository_impl::in_memory::{
+        todo_dependencies::in_memory_todo_dependency_repository::InMemoryTodoDependencyRepository,
+        todo_links::in_memory_todo_link_repository::InMemoryTodoLinkRepository,
+    },
 };
 
-type TodoStore = HashMap<TodoId, Todo>;
+// 読み出しが書き込みを待たないよう、todo ごとに細かくロックする DashMap に保存する
+// 書き込みだけを続けた場合は、RwLock は読み書きを区別するぶん Mutex よりロックの取得がやや重い
+// それでも、このリポジトリは find 系の読み出しが大半で、書き込みは save と delete の短い区間だけなので、
+// Mutex にはせず読み出し同士が待たない DashMap (シャードごとの RwLock) にしている
+type TodoStore = DashMap<TodoId, Todo>;
 
 #[derive(Clone)]
 pub struct InMemoryTodoRepository {
-    store: Arc<RwLock<TodoStore>>,
+    store: Arc<TodoStore>,
+    clock: Arc<dyn Clock>,
+    // todo を削除したときに、その todo の依存関係も削除する
+    todo_dependency_repository: Option<InMemoryTodoDependencyRepository>,
+    // todo を保存したときに、テキストに含まれる URL も保存し直す
+    todo_link_repository: Option<InMemoryTodoLinkRepository>,
+}
+
+impl Default for InMemoryTodoRepository {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl InMemoryTodoRepository {
     pub fn new() -> Self {
+        Self::with_clock(Arc::new(SystemClock))
+    }
+
+    // 期限の判定に使う「今日」を差し替える
+    pub fn with_clock(clock: Arc<dyn Clock>) -> Self {
         Self {
             store: Arc::default(),
+            clock,
+            todo_dependency_repository: None,
+            todo_link_repository: None,
+        }
+    }
+
+    // DB の todo_dependencies・todo_links テーブルの ON DELETE CASCADE の代わりに、それぞれのリポジトリからも取り除く
+    // DB では todo と同じトランザクションで todo_links も書き込むため、save でも todo_link_repository に保存する
+    pub fn with_related_repositories(
+        todo_dependency_repository: InMemoryTodoDependencyRepository,
+        todo_link_repository: InMemoryTodoLinkRepository,
+    ) -> Self {
+        Self {
+            todo_dependency_repository: Some(todo_dependency_repository),
+            todo_link_repository: Some(todo_link_repository),
+            ..Self::new()
+        }
+    }
+
+    // 保存されている todo を 1 つずつ渡す。ストア全体はロックせず、渡している todo のあるシャードだけを読み出しの間ロックする
+    // ロックしているシャードに書き込むとデッドロックするため、`f` の中からこのリポジトリに書き込まないこと
+    pub fn for_each_todo(&self, mut f: impl FnMut(&TodoId, &Todo)) {
+        for entry in self.store.iter() {
+            f(entry.key(), entry.value());
+        }
+    }
+
+    // DB の `on delete set null` の代わりに、ユーザーが担当している todo を未割り当てに戻す
+    pub fn unassign_todos(&self, user_id: &UserId) {
+        for mut todo in self.store.iter_mut() {
+            if todo.assignee_id.as_ref() == Some(user_id) {
+                todo.assignee_id = None;
+                todo.touch();
+            }
+        }
+    }
+
+    // DB の todo_labels テーブルの `ON DELETE CASCADE` の代わりに、削除したラベルを todo から外す
+    pub fn detach_labels(&self, label_ids: &[LabelId]) {
+        for mut todo in self.store.iter_mut() {
+            todo.labels
+                .retain(|label| !label_ids.contains(label.label_id()));
         }
     }
 
-    pub fn write_store_ref(&self) -> RwLockWriteGuard<TodoStore> {
-        self.store.write().unwrap()
+    // 削除した todo の依存関係と URL を、それぞれのリポジトリから取り除く
+    fn delete_related(&self, todo_ids: &[TodoId]) {
+        if let Some(todo_dependency_repository) = &self.todo_dependency_repository {
+            todo_dependency_repository
+                .write_store_ref()
+                .retain(|_, todo_dependency| {
+                    !todo_ids.contains(todo_dependency.from_todo_id())
+                        && !todo_ids.contains(todo_dependency.to_todo_id())
+                });
+        }
+        if let Some(todo_link_repository) = &self.todo_link_repository {
+            let mut todo_link_store = todo_link_repository.write_store_ref();
+            for todo_id in todo_ids {
+                todo_link_store.remove(todo_id);
+            }
+        }
     }
 
-    pub fn read_store_ref(&self) -> RwLockReadGuard<TodoStore> {
-        self.store.read().unwrap()
+    // 複数の条件での絞り込みを、find_by_* を組み合わせずに 1 回のロックで行う
+    pub fn find_by_filter(&self, filter: &TodoFilter) -> Result<Vec<Todo>> {
+        Ok(self.find_where(|todo| filter.matches(todo)))
+    }
+
+    fn find_where(&self, predicate: impl Fn(&Todo) -> bool) -> Vec<Todo> {
+        self.store
+            .iter()
+            .filter(|entry| predicate(entry.value()))
+            .map(|entry| entry.value().clone())
+            .collect()
     }
 }
 
 #[async_trait]
 impl ITodoRepository for InMemoryTodoRepository {
     async fn save(&self, todo: &Todo) -> Result<()> {
-        let mut store = self.write_store_ref();
-        store.insert(todo.todo_id().clone(), todo.clone());
+        // 版の確認から書き込みまでの間に他の書き込みが入らないよう、この todo のエントリをロックしたまま保存する
+        let entry = self.store.entry(todo.todo_id().clone());
+        let stored = match &entry {
+            Entry::Occupied(entry) => Some(entry.get()),
+            Entry::Vacant(_) => None,
+        };
+        // DB と同じく、保存されている版が想定と違えば上書きしない
+        if stored.is_some_and(|stored| stored.version() != todo.version() - 1) {
+            return Err(TodoRepositoryError::StaleData(todo.todo_id().clone()));
+        }
+        // DB の `DEFAULT NOW()` と同じく、新しく付けたラベルにだけ現在日時を設定し、付いたままのラベルは元の日時を保つ
+        let mut todo = todo.clone();
+        let stored_labels = stored
+            .map(|stored| stored.labels.clone())
+            .unwrap_or_default();
+        let now = Utc::now();
+        for label in todo.labels.iter_mut() {
+            let stored_label = stored_labels.iter().find(|stored| *stored == &*label);
+            label.attached_at = Some(
+                stored_label
+                    .and_then(|stored| stored.attached_at)
+                    .unwrap_or(now),
+            );
+        }
+        if let Some(todo_link_repository) = &self.todo_link_repository {
+            todo_link_repository.save_links(
+                todo.todo_id(),
+                todo_link_service::extract_links(&todo.todo_text),
+            );
+        }
+        // DB と同じく、完了に変わったときだけ更新日時を完了した日時にし、完了のままなら元の日時を保つ
+        todo.completed_at = match stored {
+            _ if !todo.completed => None,
+            Some(stored) if stored.completed => stored.completed_at,
+            _ => Some(*todo.updated_at()),
+        };
+        entry.insert(todo);
         Ok(())
     }
 
     async fn find(&self, todo_id: &TodoId) -> Result<Option<Todo>> {
-        let store = self.read_store_ref();
-        Ok(store.get(todo_id).map(|todo| todo.clone()))
+        Ok(self.store.get(todo_id).map(|todo| todo.clone()))
+    }
+
+    async fn find_by_text_exact(&self, todo_text: &TodoText) -> Result<Option<Todo>> {
+        let todo_found = self
+            .store
+            .iter()
+            .find(|todo| &todo.todo_text == todo_text)
+            .map(|todo| todo.clone());
+        Ok(todo_found)
     }
 
     async fn find_all(&self) -> Result<Vec<Todo>> {
-        let store = self.read_store_ref();
-        let todos_found = store.iter().map(|(_, todo)| todo.clone()).collect();
+        Ok(self.find_where(|_| true))
+    }
+
+    async fn find_many_by_ids(&self, todo_ids: &[TodoId]) -> Result<Vec<Todo>> {
+        Ok(self.find_where(|todo| todo_ids.contains(todo.todo_id())))
+    }
+
+    async fn find_by_label_filter(&self, label_filter: &LabelFilter) -> Result<Vec<Todo>> {
+        Ok(self.find_where(|todo| label_filter.matches(todo)))
+    }
+
+    async fn find_by_assignee(&self, assignee_id: &UserId) -> Result<Vec<Todo>> {
+        Ok(self.find_where(|todo| todo.assignee_id.as_ref() == Some(assignee_id)))
+    }
+
+    async fn count_by_user_and_completion(&self, user_id: &UserId) -> Result<(u64, u64)> {
+        let (total, completed) = self
+            .store
+            .iter()
+            .filter(|todo| todo.assignee_id.as_ref() == Some(user_id))
+            .fold((0, 0), |(total, completed), todo| {
+                (total + 1, completed + u64::from(todo.completed))
+            });
+        Ok((total, completed))
+    }
+
+    async fn collection_version(&self) -> Result<TodoCollectionVersion> {
+        Ok(TodoCollectionVersion {
+            count: self.store.len() as u64,
+            last_updated_at: self.store.iter().map(|todo| *todo.updated_at()).max(),
+            version_sum: self.store.iter().map(|todo| todo.version()).sum(),
+        })
+    }
+
+    async fn find_recently_completed_dates_by_user(
+        &self,
+        user_id: &UserId,
+        limit: u32,
+    ) -> Result<Vec<NaiveDate>> {
+        let completed_dates: BTreeSet<NaiveDate> = self
+            .store
+            .iter()
+            .filter(|todo| todo.completed && todo.assignee_id.as_ref() == Some(user_id))
+            .filter_map(|todo| todo.completed_at)
+            .map(|completed_at| completed_at.date_naive())
+            .collect();
+        Ok(completed_dates
+            .into_iter()
+            .rev()
+            .take(limit as usize)
+            .collect())
+    }
+
+    async fn find_due_within(&self, days: u32) -> Result<Vec<Todo>> {
+        let today = self.clock.now().date_naive();
+        let last_day = today.checked_add_days(Days::new(days.into())).ok_or(
+            TodoRepositoryError::Unexpected(format!("{} days from today is out of range", days)),
+        )?;
+        let mut todos_found = self.find_where(|todo| {
+            !todo.completed
+                && todo
+                    .due_date
+                    .is_some_and(|due_date| today <= due_date && due_date <= last_day)
+        });
+        todos_found.sort_by_key(|todo| todo.due_date);
+        Ok(todos_found)
+    }
+
+    // DB の pg_trgm とは求め方が異なるため、同じ todo でも類似度は一致しない
+    async fn find_similar_to(
+        &self,
+        todo_id: &TodoId,
+        threshold: f64,
+        limit: u32,
+    ) -> Result<Vec<(Todo, f64)>> {
+        // 他の todo を読み出す間にシャードのロックを重ねて持たないよう、比べるテキストは複製しておく
+        let Some(target_text) = self
+            .store
+            .get(todo_id)
+            .map(|target| target.todo_text.value().clone())
+        else {
+            return Ok(vec![]);
+        };
+        let mut todos_found: Vec<(Todo, f64)> = self
+            .store
+            .iter()
+            .filter(|todo| todo.todo_id() != todo_id)
+            .map(|todo| {
+                let similarity =
+                    strsim::normalized_levenshtein(&target_text, todo.todo_text.value());
+                (todo, similarity)
+            })
+            .filter(|(_, similarity)| *similarity >= threshold)
+            .map(|(todo, similarity)| (todo.clone(), similarity))
+            .collect();
+        todos_found.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+        todos_found.truncate(limit as usize);
         Ok(todos_found)
     }
 
+    fn find_all_stream(&self, cancellation_token: CancellationToken) -> TodoStream<'_> {
+        // ストアのロックを保持し続けないよう、複製してから流す
+        let todos_found = self.find_where(|_| true);
+        Box::pin(
+            tokio_stream::iter(todos_found.into_iter().map(Ok))
+                .take_until(cancellation_token.cancelled_owned()),
+        )
+    }
+
     async fn delete(&self, todo: Todo) -> Result<()> {
-        let mut store = self.write_store_ref();
         let todo_id = todo.todo_id();
-        match store.get(todo_id) {
-            Some(_) => store.remove(todo_id),
-            None => {
-                return Err(TodoRepositoryError::NotFound(todo_id.clone()));
+        self.store
+            .remove(todo_id)
+            .ok_or_else(|| TodoRepositoryError::NotFound(todo_id.clone()))?;
+        self.delete_related(std::slice::from_ref(todo_id));
+        Ok(())
+    }
+
+    async fn delete_all_completed_by_user(&self, user_id: &UserId) -> Result<Vec<TodoId>> {
+        let is_deleted = |todo: &Todo| todo.completed && todo.assignee_id.as_ref() == Some(user_id);
+        let candidate_ids: Vec<TodoId> = self
+            .find_where(is_deleted)
+            .into_iter()
+            .map(|todo| todo.todo_id().clone())
+            .collect();
+        // 探してから削除するまでの間に変更された todo は、削除する時点でもう一度確かめる
+        let deleted_ids: Vec<TodoId> = candidate_ids
+            .into_iter()
+            .filter(|todo_id| {
+                self.store
+                    .remove_if(todo_id, |_, todo| is_deleted(todo))
+                    .is_some()
+            })
+            .collect();
+        self.delete_related(&deleted_ids);
+        Ok(deleted_ids)
+    }
+
+    async fn set_completed_many(&self, todo_ids: &[TodoId], completed: bool) -> Result<u64> {
+        let mut updated_count = 0;
+        for todo_id in todo_ids {
+            let Some(mut todo) = self.store.get_mut(todo_id) else {
+                continue;
+            };
+            if todo.completed == completed {
+                continue;
             }
+            todo.completed = completed;
+            todo.touch();
+            todo.completed_at = completed.then(|| *todo.updated_at());
+            updated_count += 1;
+        }
+        Ok(updated_count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use uuid::Uuid;
+
+    use crate::domain::{
+        models::{
+            labels::{label::Label, label_name::LabelName},
+            todo_dependencies::{
+                todo_dependency::TodoDependency,
+                todo_dependency_repository::ITodoDependencyRepository,
+            },
+            todos::label_filter::FilterOperator,
+        },
+        value_object::ValueObject,
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn should_find_todos_matching_every_filter_condition() -> Result<()> {
+        let repository = InMemoryTodoRepository::new();
+        let user_x = UserId::new(Uuid::new_v4())?;
+        let user_z = UserId::new(Uuid::new_v4())?;
+        let label_y = Label::new(LabelName::new("label-y".to_string())?)?;
+        let label_w = Label::new(LabelName::new("label-w".to_string())?)?;
+
+        // (担当者, ラベル, 完了) の組み合わせを変えて登録する
+        let mut expected = None;
+        for (i, (assignee_id, label, completed)) in [
+            (Some(&user_x), Some(&label_y), false),
+            (Some(&user_x), Some(&label_y), true),
+            (Some(&user_x), Some(&label_w), false),
+            (Some(&user_z), Some(&label_y), false),
+            (None, None, false),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            let mut todo = Todo::new(
+                TodoText::new(format!("todo-{}", i))?,
+                label.into_iter().cloned().collect(),
+            )?;
+            todo.assignee_id = assignee_id.cloned();
+            todo.completed = completed;
+            repository.save(&todo).await?;
+            expected.get_or_insert(repository.find(todo.todo_id()).await?.unwrap());
+        }
+
+        let todos_found = repository.find_by_filter(&TodoFilter {
+            assignee_id: Some(user_x),
+            label_filter: Some(LabelFilter {
+                ids: vec![label_y.label_id().clone()],
+                operator: FilterOperator::And,
+            }),
+            completed: Some(false),
+        })?;
+
+        assert_eq!(vec![expected.unwrap()], todos_found);
+        // 条件を指定しなければすべて返す
+        assert_eq!(5, repository.find_by_filter(&TodoFilter::default())?.len());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_not_overwrite_todo_updated_by_another_writer() -> Result<()> {
+        let repository = InMemoryTodoRepository::new();
+        let todo = Todo::new(TodoText::new("todo".to_string())?, vec![])?;
+        repository.save(&todo).await?;
+
+        // 同じ版から 2 つの更新を作り、先に保存したほうだけが反映される
+        let mut first = todo.clone();
+        first.completed = true;
+        first.touch();
+        let mut second = todo.clone();
+        second.todo_text = TodoText::new("updated".to_string())?;
+        second.touch();
+        repository.save(&first).await?;
+        let result = repository.save(&second).await;
+
+        assert!(matches!(
+            result,
+            Err(TodoRepositoryError::StaleData(todo_id)) if &todo_id == todo.todo_id()
+        ));
+        let todo_found = repository.find(todo.todo_id()).await?.unwrap();
+        assert!(todo_found.completed);
+        assert_eq!("todo", todo_found.todo_text.value());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_delete_dependencies_of_deleted_todo() -> Result<()> {
+        let todo_dependency_repository = InMemoryTodoDependencyRepository::new();
+        let repository = InMemoryTodoRepository::with_related_repositories(
+            todo_dependency_repository.clone(),
+            InMemoryTodoLinkRepository::new(),
+        );
+        let mut todos = vec![];
+        for i in 0..3 {
+            let todo = Todo::new(TodoText::new(format!("todo-{}", i))?, vec![])?;
+            repository.save(&todo).await?;
+            todos.push(todo);
+        }
+        // 0 <- 1 <- 2 の順に依存させる
+        for (from, to) in [(&todos[0], &todos[1]), (&todos[1], &todos[2])] {
+            todo_dependency_repository
+                .add(&TodoDependency::new(
+                    from.todo_id().clone(),
+                    to.todo_id().clone(),
+                )?)
+                .await?;
+        }
+
+        repository.delete(todos[1].clone()).await?;
+
+        // 削除した todo が依存する側にも、依存される側にも残らない
+        assert!(todo_dependency_repository.read_store_ref().is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_delete_dependencies_and_links_of_cleared_todos() -> Result<()> {
+        let todo_dependency_repository = InMemoryTodoDependencyRepository::new();
+        let todo_link_repository = InMemoryTodoLinkRepository::new();
+        let repository = InMemoryTodoRepository::with_related_repositories(
+            todo_dependency_repository.clone(),
+            todo_link_repository.clone(),
+        );
+        let user_id = UserId::new(Uuid::new_v4())?;
+        let mut completed_todo =
+            Todo::new(TodoText::new("see http://a.example".to_string())?, vec![])?;
+        completed_todo.assignee_id = Some(user_id.clone());
+        completed_todo.completed = true;
+        repository.save(&completed_todo).await?;
+        let active_todo = Todo::new(TodoText::new("see http://b.example".to_string())?, vec![])?;
+        repository.save(&active_todo).await?;
+        todo_dependency_repository
+            .add(&TodoDependency::new(
+                active_todo.todo_id().clone(),
+                completed_todo.todo_id().clone(),
+            )?)
+            .await?;
+
+        let deleted_ids = repository.delete_all_completed_by_user(&user_id).await?;
+
+        assert_eq!(vec![completed_todo.todo_id().clone()], deleted_ids);
+        assert!(todo_dependency_repository.read_store_ref().is_empty());
+        let todo_link_store = todo_link_repository.read_store_ref();
+        assert!(!todo_link_store.contains_key(completed_todo.todo_id()));
+        assert!(todo_link_store.contains_key(active_todo.todo_id()));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_save_links_with_todo_and_delete_them_with_todo() -> Result<()> {
+        let todo_link_repository = InMemoryTodoLinkRepository::new();
+        let repository = InMemoryTodoRepository::with_related_repositories(
+            InMemoryTodoDependencyRepository::new(),
+            todo_link_repository.clone(),
+        );
+        let todo = Todo::new(TodoText::new("see http://a.example".to_string())?, vec![])?;
+        repository.save(&todo).await?;
+        assert_eq!(
+            vec!["http://a.example/".to_string()],
+            todo_link_repository.read_store_ref()[todo.todo_id()]
+                .iter()
+                .map(|url| url.to_string())
+                .collect::<Vec<_>>()
+        );
+
+        repository.delete(todo).await?;
+
+        assert!(todo_link_repository.read_store_ref().is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_keep_completed_at_until_todo_is_reopened() -> Result<()> {
+        let repository = InMemoryTodoRepository::new();
+        let mut todo = Todo::new(TodoText::new("todo".to_string())?, vec![])?;
+        repository.save(&todo).await?;
+        assert!(repository
+            .find(todo.todo_id())
+            .await?
+            .unwrap()
+            .completed_at
+            .is_none());
+
+        todo.completed = true;
+        todo.touch();
+        repository.save(&todo).await?;
+        let completed_at = repository.find(todo.todo_id()).await?.unwrap().completed_at;
+        assert_eq!(Some(*todo.updated_at()), completed_at);
+
+        // 完了後に編集しても、完了した日時は変わらない
+        todo.todo_text = TodoText::new("edited".to_string())?;
+        todo.touch();
+        repository.save(&todo).await?;
+        assert_eq!(
+            completed_at,
+            repository.find(todo.todo_id()).await?.unwrap().completed_at
+        );
+
+        todo.completed = false;
+        todo.touch();
+        repository.save(&todo).await?;
+        assert!(repository
+            .find(todo.todo_id())
+            .await?
+            .unwrap()
+            .completed_at
+            .is_none());
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 16)]
+    async fn should_keep_every_todo_saved_by_concurrent_writers() -> Result<()> {
+        let repository = InMemoryTodoRepository::new();
+
+        let handles: Vec<_> = (0..1000)
+            .map(|i| {
+                let repository = repository.clone();
+                tokio::spawn(async move {
+                    let todo = Todo::new(TodoText::new(format!("todo-{}", i))?, vec![])?;
+                    repository.save(&todo).await?;
+                    anyhow::Ok(())
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.await??;
+        }
+
+        assert_eq!(1000, repository.find_all().await?.len());
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 16)]
+    async fn should_accept_only_one_of_concurrent_updates_from_same_version() -> Result<()> {
+        let repository = InMemoryTodoRepository::new();
+        let todo = Todo::new(TodoText::new("todo".to_string())?, vec![])?;
+        repository.save(&todo).await?;
+
+        // 版の確認と書き込みの間に他の書き込みが入ると、複数の更新が成功してしまう
+        let handles: Vec<_> = (0..100)
+            .map(|i| {
+                let repository = repository.clone();
+                let mut todo = todo.clone();
+                tokio::spawn(async move {
+                    todo.todo_text = TodoText::new(format!("updated-{}", i))?;
+                    todo.touch();
+                    anyhow::Ok(repository.save(&todo).await.is_ok())
+                })
+            })
+            .collect();
+        let mut saved_count = 0;
+        for handle in handles {
+            saved_count += usize::from(handle.await??);
+        }
+
+        assert_eq!(1, saved_count);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_find_todo_by_text_in_another_unicode_normalization_form() -> Result<()> {
+        let repository = InMemoryTodoRepository::new();
+        let todo = Todo::new(TodoText::new("Visit the caf\u{e9}".to_string())?, vec![])?;
+        repository.save(&todo).await?;
+
+        // NFD で表した同じ文字列でも、保存する前と同じく NFC に正規化されて一致する
+        let todo_found = repository
+            .find_by_text_exact(&TodoText::new("Visit the cafe\u{301}".to_string())?)
+            .await?;
+        assert_eq!(Some(todo), todo_found);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_find_similar_todos_in_order_of_similarity() -> Result<()> {
+        let repository = InMemoryTodoRepository::new();
+        let mut todos = vec![];
+        for text in [
+            "Buy groceries",
+            "Buy grocery items",
+            "Buy groceries!",
+            "Walk the dog",
+        ] {
+            let todo = Todo::new(TodoText::new(text.to_string())?, vec![])?;
+            repository.save(&todo).await?;
+            todos.push(todo);
+        }
+
+        let todos_found = repository
+            .find_similar_to(todos[0].todo_id(), 0.6, 5)
+            .await?;
+        let texts_found: Vec<&str> = todos_found
+            .iter()
+            .map(|(todo, _)| todo.todo_text.value().as_str())
+            .collect();
+        // 自分自身と、似ていない todo は含まない
+        assert_eq!(vec!["Buy groceries!", "Buy grocery items"], texts_found);
+        assert!(todos_found[0].1 > todos_found[1].1);
+
+        // 件数を制限できる
+        let todos_found = repository
+            .find_similar_to(todos[0].todo_id(), 0.6, 1)
+            .await?;
+        assert_eq!(1, todos_found.len());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_end_stream_early_when_cancelled() -> Result<()> {
+        let repository = InMemoryTodoRepository::new();
+        for i in 0..10 {
+            let todo = Todo::new(TodoText::new(format!("todo-{}", i))?, vec![])?;
+            repository.save(&todo).await?;
+        }
+
+        let cancellation_token = CancellationToken::new();
+        let mut todos_found = repository.find_all_stream(cancellation_token.clone());
+        assert!(todos_found.next().await.transpose()?.is_some());
+        assert!(todos_found.next().await.transpose()?.is_some());
+
+        cancellation_token.cancel();
+        assert!(todos_found.next().await.is_none());
+        Ok(())
+    }
+}
+
+// ロックを解放したあと、待っていた他方のスレッドがすぐに進めることを確かめる
+// ガードは同期的なもので、保持したまま await をまたぐ処理はないため、デッドロックは起きないはず
+// ストア全体のロックはないため、同じ todo のエントリ (シャード) のロックで確かめる
+#[cfg(test)]
+mod deadlock_tests {
+    use std::{
+        sync::mpsc,
+        thread,
+        time::{Duration, Instant},
+    };
+
+    use anyhow::Result;
+
+    use crate::domain::value_object::ValueObject;
+
+    use super::*;
+
+    const HOLD_DURATION: Duration = Duration::from_millis(50);
+    const MAX_WAIT_AFTER_RELEASE: Duration = Duration::from_millis(100);
+
+    // 1 つの todo だけを保存したリポジトリを作る。同じ todo を読み書きすれば、必ず同じシャードのロックを取り合う
+    fn repository_with_todo() -> Result<(InMemoryTodoRepository, TodoId)> {
+        let repository = InMemoryTodoRepository::new();
+        let todo = Todo::new(TodoText::new("todo".to_string())?, vec![])?;
+        let todo_id = todo.todo_id().clone();
+        repository.store.insert(todo_id.clone(), todo);
+        Ok((repository, todo_id))
+    }
+
+    #[test]
+    fn reader_should_proceed_soon_after_writer_releases_lock() -> Result<()> {
+        let (repository, todo_id) = repository_with_todo()?;
+        let (locked_tx, locked_rx) = mpsc::channel();
+
+        let writer = {
+            let repository = repository.clone();
+            let todo_id = todo_id.clone();
+            thread::spawn(move || {
+                let todo = repository.store.get_mut(&todo_id).unwrap();
+                locked_tx.send(()).unwrap();
+                thread::sleep(HOLD_DURATION);
+                let released_at = Instant::now();
+                drop(todo);
+                released_at
+            })
+        };
+        let reader = thread::spawn(move || {
+            locked_rx.recv().unwrap();
+            let _todo = repository.store.get(&todo_id).unwrap();
+            Instant::now()
+        });
+
+        let released_at = writer.join().unwrap();
+        let acquired_at = reader.join().unwrap();
+        assert!(acquired_at >= released_at);
+        assert!(acquired_at - released_at < MAX_WAIT_AFTER_RELEASE);
+        Ok(())
+    }
+
+    #[test]
+    fn writer_should_proceed_soon_after_reader_releases_lock() -> Result<()> {
+        let (repository, todo_id) = repository_with_todo()?;
+        let (locked_tx, locked_rx) = mpsc::channel();
+
+        let reader = {
+            let repository = repository.clone();
+            let todo_id = todo_id.clone();
+            thread::spawn(move || {
+                let todo = repository.store.get(&todo_id).unwrap();
+                locked_tx.send(()).unwrap();
+                thread::sleep(HOLD_DURATION);
+                let released_at = Instant::now();
+                drop(todo);
+                released_at
+            })
         };
+        let writer = thread::spawn(move || {
+            locked_rx.recv().unwrap();
+            let _todo = repository.store.get_mut(&todo_id).unwrap();
+            Instant::now()
+        });
+
+        let released_at = reader.join().unwrap();
+        let acquired_at = writer.join().unwrap();
+        assert!(acquired_at >= released_at);
+        assert!(acquired_at - released_at < MAX_WAIT_AFTER_RELEASE);
         Ok(())
     }
 }
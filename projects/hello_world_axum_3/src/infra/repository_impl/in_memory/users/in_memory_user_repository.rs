@@ -1,77 +1,149 @@
-use std::{
-    collections::HashMap,
-    sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard},
-};
+use std::{collections::HashMap, sync::Arc};
 
 use axum::async_trait;
+use dashmap::DashMap;
 
-use crate::domain::models::users::{
-    user::User,
-    user_id::UserId,
-    user_name::UserName,
-    user_repository::{IUserRepository, Result, UserRepositoryError},
+use crate::{
+    domain::{
+        models::users::{
+            user::User,
+            user_id::UserId,
+            user_name::UserName,
+            user_repository::{IUserRepository, Result, UserRepositoryError},
+            user_role::UserRole,
+        },
+        value_object::ValueObject,
+    },
+    infra::repository_impl::in_memory::todos::in_memory_todo_repository::InMemoryTodoRepository,
 };
 
-type TodoStore = HashMap<UserId, User>;
+type TodoStore = DashMap<UserId, User>;
 
 #[derive(Clone)]
 pub struct InMemoryUserRepository {
-    store: Arc<RwLock<TodoStore>>,
+    store: Arc<TodoStore>,
+    // 論理削除したユーザーが担当している todo を未割り当てに戻すときに参照する
+    todo_repository: Option<InMemoryTodoRepository>,
+}
+
+impl Default for InMemoryUserRepository {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl InMemoryUserRepository {
     pub fn new() -> Self {
         Self {
             store: Arc::default(),
+            todo_repository: None,
+        }
+    }
+
+    // DB の外部キーの代わりに、todo_repository に保存された todo の担当者を外す
+    pub fn with_todo_repository(todo_repository: InMemoryTodoRepository) -> Self {
+        Self {
+            store: Arc::default(),
+            todo_repository: Some(todo_repository),
+        }
+    }
+
+    // 論理削除したユーザーも含めて、保存されているユーザーを 1 つずつ渡す。`f` の中からこのリポジトリに書き込まないこと
+    pub fn for_each_user(&self, mut f: impl FnMut(&UserId, &User)) {
+        for entry in self.store.iter() {
+            f(entry.key(), entry.value());
         }
     }
 
-    pub fn write_store_ref(&self) -> RwLockWriteGuard<TodoStore> {
-        self.store.write().unwrap()
+    // DB の `on delete set null` と同じく、ユーザーが担当している todo を未割り当てに戻す
+    fn unassign_todos(&self, user_id: &UserId) {
+        if let Some(todo_repository) = &self.todo_repository {
+            todo_repository.unassign_todos(user_id);
+        }
     }
 
-    pub fn read_store_ref(&self) -> RwLockReadGuard<TodoStore> {
-        self.store.read().unwrap()
+    // 論理削除したユーザーを除いたユーザーのうち、`predicate` を満たすもの
+    fn find_where(&self, predicate: impl Fn(&User) -> bool) -> Vec<User> {
+        self.store
+            .iter()
+            .filter(|user| user.deleted_at().is_none() && predicate(user.value()))
+            .map(|user| user.value().clone())
+            .collect()
     }
 }
 
 #[async_trait]
 impl IUserRepository for InMemoryUserRepository {
     async fn save(&self, user: &User) -> Result<()> {
-        let mut store = self.write_store_ref();
-        store.insert(user.user_id().clone(), user.clone());
+        self.store.insert(user.user_id().clone(), user.clone());
         Ok(())
     }
 
     async fn find(&self, user_id: &UserId) -> Result<Option<User>> {
-        let store = self.read_store_ref();
-        Ok(store.get(user_id).map(|user| user.clone()))
+        Ok(self
+            .store
+            .get(user_id)
+            .filter(|user| user.deleted_at().is_none())
+            .map(|user| user.clone()))
+    }
+
+    async fn exists(&self, user_id: &UserId) -> Result<bool> {
+        Ok(self.find(user_id).await?.is_some())
     }
 
     async fn find_by_name(&self, user_name: &UserName) -> Result<Option<User>> {
-        let store = self.read_store_ref();
-        let user_found = store
-            .iter()
-            .find(|(_, user)| &user.user_name == user_name)
-            .map(|(_, user)| user.clone());
-        Ok(user_found)
+        Ok(self.find_where(|user| &user.user_name == user_name).pop())
     }
 
     async fn find_all(&self) -> Result<Vec<User>> {
-        let store = self.read_store_ref();
-        let users_found = store.iter().map(|(_, user)| user.clone()).collect();
+        Ok(self.find_where(|_| true))
+    }
+
+    async fn find_all_by_role(&self, user_role: &UserRole) -> Result<Vec<User>> {
+        Ok(self.find_where(|user| &user.user_role == user_role))
+    }
+
+    async fn count_by_role(&self) -> Result<HashMap<UserRole, u64>> {
+        let mut counts = HashMap::new();
+        for user in self.find_where(|_| true) {
+            *counts.entry(user.user_role).or_insert(0) += 1;
+        }
+        Ok(counts)
+    }
+
+    async fn search_by_name(&self, query: &str, limit: u32) -> Result<Vec<User>> {
+        let query = query.to_lowercase();
+        let mut users_found =
+            self.find_where(|user| user.user_name.value().to_lowercase().starts_with(&query));
+        users_found.sort_by(|a, b| a.user_name.value().cmp(b.user_name.value()));
+        users_found.truncate(limit as usize);
         Ok(users_found)
     }
 
     async fn delete(&self, user: User) -> Result<()> {
-        let mut store = self.write_store_ref();
         let user_id = user.user_id();
-        match store.get(user_id) {
-            Some(_) => store.remove(user_id),
-            None => {
-                return Err(UserRepositoryError::NotFound(user_id.clone()));
-            }
-        };
+        self.store
+            .remove(user_id)
+            .ok_or_else(|| UserRepositoryError::NotFound(user_id.clone()))?;
+        self.unassign_todos(user_id);
+        Ok(())
+    }
+
+    async fn soft_delete(&self, user: &User) -> Result<()> {
+        let user_id = user.user_id();
+        match self.store.get_mut(user_id) {
+            Some(mut stored) if stored.deleted_at().is_none() => *stored = user.clone(),
+            _ => return Err(UserRepositoryError::NotFound(user_id.clone())),
+        }
+        self.unassign_todos(user_id);
+        Ok(())
+    }
+
+    async fn hard_delete(&self, user_id: &UserId) -> Result<()> {
+        self.store
+            .remove(user_id)
+            .ok_or_else(|| UserRepositoryError::NotFound(user_id.clone()))?;
+        self.unassign_todos(user_id);
         Ok(())
     }
 }
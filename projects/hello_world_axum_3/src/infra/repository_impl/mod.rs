@@ -1,4 +1,4 @@
 pub mod pg;
 
-#[cfg(test)]
-pub mod in_memory;
\ No newline at end of file
+#[cfg(any(test, feature = "in-memory-repository"))]
+pub mod in_memory;
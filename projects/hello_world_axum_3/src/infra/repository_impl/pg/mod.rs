@@ -1,3 +1,11 @@
+pub mod pg_audit_log_repository;
+pub mod pg_credential_repository;
+pub mod pg_invitation_repository;
 pub mod pg_label_repository;
+pub mod pg_session_repository;
+pub mod pg_todo_dependency_repository;
+pub mod pg_todo_link_repository;
+pub mod pg_todo_repository;
 pub mod pg_user_repository;
-pub mod pg_todo_repository;
\ No newline at end of file
+mod slow_query_log;
+mod transaction;
@@ -0,0 +1,210 @@
+use axum::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgConnection, PgPool};
+use uuid::Uuid;
+
+use crate::domain::{
+    models::{
+        audit_logs::{
+            audit_action::AuditAction,
+            audit_entry::AuditEntry,
+            audit_entry_id::AuditEntryId,
+            audit_log_repository::{AuditLogRepositoryError, IAuditLogRepository, Result},
+        },
+        users::user_id::UserId,
+    },
+    value_object::ValueObject,
+};
+
+#[derive(FromRow)]
+#[sqlx(rename_all = "snake_case")]
+struct AuditEntryRow {
+    id: Uuid,
+    resource_type: String,
+    resource_id: Uuid,
+    actor_id: Option<Uuid>,
+    action: String,
+    occurred_at: DateTime<Utc>,
+    before: String,
+    after: String,
+}
+
+impl AuditEntryRow {
+    fn into_audit_entry(self) -> Result<AuditEntry> {
+        let entry_id = AuditEntryId::new(self.id).map_err(map_error)?;
+        let actor_id = self
+            .actor_id
+            .map(UserId::new)
+            .transpose()
+            .map_err(map_error)?;
+        let action = AuditAction::parse(self.action).map_err(map_error)?;
+        let before = serde_json::from_str(&self.before).map_err(map_error)?;
+        let after = serde_json::from_str(&self.after).map_err(map_error)?;
+        Ok(AuditEntry::build(
+            entry_id,
+            self.resource_type,
+            self.resource_id,
+            actor_id,
+            action,
+            self.occurred_at,
+            before,
+            after,
+        ))
+    }
+}
+
+#[derive(Clone)]
+pub struct PgAuditLogRepository {
+    pool: PgPool,
+}
+
+impl PgAuditLogRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+fn map_error(e: impl ToString) -> AuditLogRepositoryError {
+    AuditLogRepositoryError::Unexpected(e.to_string())
+}
+
+#[async_trait]
+impl IAuditLogRepository for PgAuditLogRepository {
+    async fn save(&self, entry: &AuditEntry) -> Result<()> {
+        let mut conn = self.pool.acquire().await.map_err(map_error)?;
+        let mut internal_audit_log_repository = InternalAuditLogRepository::new(&mut conn);
+        internal_audit_log_repository.save(entry).await
+    }
+
+    async fn find_by_resource(
+        &self,
+        resource_type: &str,
+        resource_id: &Uuid,
+    ) -> Result<Vec<AuditEntry>> {
+        let mut conn = self.pool.acquire().await.map_err(map_error)?;
+        let mut internal_audit_log_repository = InternalAuditLogRepository::new(&mut conn);
+        internal_audit_log_repository
+            .find_by_resource(resource_type, resource_id)
+            .await
+    }
+}
+
+struct InternalAuditLogRepository<'a> {
+    conn: &'a mut PgConnection,
+}
+
+impl<'a> InternalAuditLogRepository<'a> {
+    fn new(conn: &'a mut PgConnection) -> Self {
+        Self { conn }
+    }
+
+    async fn save(&mut self, entry: &AuditEntry) -> Result<()> {
+        let sql = r#"
+insert into audit_log_entries (id, resource_type, resource_id, actor_id, action, occurred_at, before, after)
+values ($1, $2, $3, $4, $5, $6, $7::jsonb, $8::jsonb)
+"#;
+        sqlx::query(sql)
+            .bind(entry.entry_id().value())
+            .bind(entry.resource_type())
+            .bind(entry.resource_id())
+            .bind(entry.actor_id().map(|actor_id| actor_id.value()))
+            .bind(entry.action().as_str())
+            .bind(entry.occurred_at())
+            .bind(entry.before().to_string())
+            .bind(entry.after().to_string())
+            .execute(&mut *self.conn)
+            .await
+            .map_err(map_error)?;
+        Ok(())
+    }
+
+    async fn find_by_resource(
+        &mut self,
+        resource_type: &str,
+        resource_id: &Uuid,
+    ) -> Result<Vec<AuditEntry>> {
+        // 同じ時刻に記録されたものは id で並べ、順序を一定にする
+        let sql = r#"
+select id, resource_type, resource_id, actor_id, action, occurred_at, before::text, after::text
+from audit_log_entries
+where resource_type=$1 and resource_id=$2
+order by occurred_at, id
+"#;
+        let entries_from_rows = sqlx::query_as::<_, AuditEntryRow>(sql)
+            .bind(resource_type)
+            .bind(resource_id)
+            .fetch_all(&mut *self.conn)
+            .await
+            .map_err(map_error)?;
+        entries_from_rows
+            .into_iter()
+            .map(|row| row.into_audit_entry())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "database-test")]
+mod tests {
+    use anyhow::Result;
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        domain::models::users::{user::User, user_name::UserName},
+        pg_pool,
+    };
+
+    #[tokio::test]
+    async fn audit_log_senario() -> Result<()> {
+        let pool = pg_pool::connect_to_test_pg_pool().await;
+
+        let mut tx = pool.begin().await?;
+
+        // save a user for test
+        let actor = User::new(UserName::new("actor".to_string())?)?;
+        sqlx::query(r#"insert into users (id, name) values ($1, $2)"#)
+            .bind(actor.user_id().value())
+            .bind(actor.user_name.value())
+            .execute(&mut *tx)
+            .await?;
+
+        let mut internal_audit_log_repository = InternalAuditLogRepository::new(&mut tx);
+
+        // save
+        let resource_id = Uuid::new_v4();
+        let created = AuditEntry::new(
+            "todo",
+            resource_id,
+            Some(actor.user_id().clone()),
+            AuditAction::Created,
+            json!(null),
+            json!({ "text": "old" }),
+        )?;
+        let updated = AuditEntry::new(
+            "todo",
+            resource_id,
+            None,
+            AuditAction::Updated,
+            json!({ "text": "old" }),
+            json!({ "text": "new" }),
+        )?;
+        internal_audit_log_repository.save(&created).await?;
+        internal_audit_log_repository.save(&updated).await?;
+
+        // find_by_resource
+        let entries_found = internal_audit_log_repository
+            .find_by_resource("todo", &resource_id)
+            .await?;
+        assert_eq!(vec![created, updated], entries_found);
+        assert_eq!(Some(actor.user_id()), entries_found[0].actor_id());
+        assert_eq!(&json!({ "text": "new" }), entries_found[1].after());
+
+        // 種類が違えば別のリソースとして扱う
+        assert!(internal_audit_log_repository
+            .find_by_resource("label", &resource_id)
+            .await?
+            .is_empty());
+        Ok(())
+    }
+}
@@ -0,0 +1,218 @@
+use axum::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{pool::PoolConnection, FromRow, PgConnection, PgPool, Postgres};
+use uuid::Uuid;
+
+use crate::domain::{
+    models::{
+        credentials::{
+            credential_repository::{CredentialRepositoryError, ICredentialRepository, Result},
+            password_credential::PasswordCredential,
+        },
+        users::user_id::UserId,
+    },
+    value_object::ValueObject,
+};
+
+#[derive(FromRow)]
+#[sqlx(rename_all = "snake_case")]
+struct CredentialRow {
+    user_id: Uuid,
+    password_hash: String,
+    failed_login_attempts: i32,
+    locked_until: Option<DateTime<Utc>>,
+}
+
+impl CredentialRow {
+    fn into_credential(self) -> Result<PasswordCredential> {
+        let user_id = UserId::new(self.user_id)
+            .map_err(|e| CredentialRepositoryError::Unexpected(e.to_string()))?;
+        let failed_login_attempts = u32::try_from(self.failed_login_attempts)
+            .map_err(|e| CredentialRepositoryError::Unexpected(e.to_string()))?;
+        Ok(PasswordCredential::build(
+            user_id,
+            self.password_hash,
+            failed_login_attempts,
+            self.locked_until,
+        ))
+    }
+}
+
+#[derive(Clone)]
+pub struct PgCredentialRepository {
+    pool: PgPool,
+}
+
+impl PgCredentialRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    async fn connection(&self) -> Result<PoolConnection<Postgres>> {
+        self.pool
+            .acquire()
+            .await
+            .map_err(|e| CredentialRepositoryError::Unexpected(e.to_string()))
+    }
+}
+
+#[async_trait]
+impl ICredentialRepository for PgCredentialRepository {
+    async fn save(&self, credential: &PasswordCredential) -> Result<()> {
+        let mut conn = self.connection().await?;
+        let mut internal_credential_repository = InternalCredentialRepository::new(&mut conn);
+        internal_credential_repository.save(credential).await
+    }
+
+    async fn find_by_user_id(&self, user_id: &UserId) -> Result<Option<PasswordCredential>> {
+        let mut conn = self.connection().await?;
+        let mut internal_credential_repository = InternalCredentialRepository::new(&mut conn);
+        internal_credential_repository
+            .find_by_user_id(user_id)
+            .await
+    }
+}
+
+struct InternalCredentialRepository<'a> {
+    conn: &'a mut PgConnection,
+}
+
+impl<'a> InternalCredentialRepository<'a> {
+    fn new(conn: &'a mut PgConnection) -> Self {
+        Self { conn }
+    }
+
+    async fn save(&mut self, credential: &PasswordCredential) -> Result<()> {
+        let sql = r#"
+insert into credentials (user_id, password_hash, failed_login_attempts, locked_until)
+values ($1, $2, $3, $4)
+on conflict (user_id)
+do update set password_hash=$2, failed_login_attempts=$3, locked_until=$4
+"#;
+        // 回数は上限に達した時点でロックされるため、i32 を超えることはない
+        let failed_login_attempts = i32::try_from(credential.failed_login_attempts())
+            .map_err(|e| CredentialRepositoryError::Unexpected(e.to_string()))?;
+        sqlx::query(sql)
+            .bind(credential.user_id().value())
+            .bind(credential.password_hash())
+            .bind(failed_login_attempts)
+            .bind(credential.locked_until())
+            .execute(&mut *self.conn)
+            .await
+            .map_err(|e| CredentialRepositoryError::Unexpected(e.to_string()))?;
+        Ok(())
+    }
+
+    async fn find_by_user_id(&mut self, user_id: &UserId) -> Result<Option<PasswordCredential>> {
+        let sql = r#"select * from credentials where user_id=$1"#;
+        let credential_from_row = sqlx::query_as::<_, CredentialRow>(sql)
+            .bind(user_id.value())
+            .fetch_optional(&mut *self.conn)
+            .await
+            .map_err(|e| CredentialRepositoryError::Unexpected(e.to_string()))?;
+        let credential = credential_from_row
+            .map(|row| row.into_credential())
+            .transpose()?;
+        Ok(credential)
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "database-test")]
+mod tests {
+    use anyhow::Result;
+    use chrono::SubsecRound;
+
+    use super::*;
+    use crate::{
+        domain::models::users::{user::User, user_name::UserName},
+        pg_pool,
+    };
+
+    #[tokio::test]
+    async fn credential_crud_senario() -> Result<()> {
+        let pool = pg_pool::connect_to_test_pg_pool().await;
+
+        let mut tx = pool.begin().await?;
+
+        // save user for test
+        let user = User::new(UserName::new("user name".to_string())?)?;
+        sqlx::query(r#"insert into users (id, name) values ($1, $2)"#)
+            .bind(user.user_id().value())
+            .bind(user.user_name.value())
+            .execute(&mut *tx)
+            .await?;
+
+        let mut internal_credential_repository = InternalCredentialRepository::new(&mut tx);
+
+        // save
+        let new_credential = PasswordCredential::new(user.user_id().clone(), "password1")?;
+        internal_credential_repository.save(&new_credential).await?;
+
+        // find_by_user_id
+        let credential_found = internal_credential_repository
+            .find_by_user_id(user.user_id())
+            .await?
+            .unwrap();
+        assert_eq!(new_credential, credential_found);
+        assert!(credential_found.verify("password1"));
+
+        // save (update)
+        let updated_credential = PasswordCredential::new(user.user_id().clone(), "password2")?;
+        internal_credential_repository
+            .save(&updated_credential)
+            .await?;
+
+        // find_by_user_id
+        let credential_found = internal_credential_repository
+            .find_by_user_id(user.user_id())
+            .await?
+            .unwrap();
+        assert!(!credential_found.verify("password1"));
+        assert!(credential_found.verify("password2"));
+        assert_eq!(0, credential_found.failed_login_attempts());
+        assert_eq!(None, credential_found.locked_until());
+
+        // save (lockout)
+        let mut locked_credential = credential_found;
+        let now = Utc::now().trunc_subsecs(6);
+        for _ in 0..5 {
+            locked_credential.record_failed_login(now);
+        }
+        internal_credential_repository
+            .save(&locked_credential)
+            .await?;
+
+        // find_by_user_id
+        let credential_found = internal_credential_repository
+            .find_by_user_id(user.user_id())
+            .await?
+            .unwrap();
+        assert_eq!(5, credential_found.failed_login_attempts());
+        assert_eq!(
+            locked_credential.locked_until(),
+            credential_found.locked_until()
+        );
+
+        tx.rollback().await?;
+        Ok(())
+    }
+
+    // 行の構造体の各フィールドがスキーマ上の列と型に一致することを、コンパイル時に照合する
+    #[tokio::test]
+    async fn row_struct_should_type_check_against_schema() -> Result<()> {
+        let pool = pg_pool::connect_to_test_pg_pool().await;
+        let mut tx = pool.begin().await?;
+
+        sqlx::query_as!(
+            CredentialRow,
+            r#"select * from credentials where user_id=$1"#,
+            Uuid::new_v4()
+        )
+        .fetch_optional(&mut *tx)
+        .await?;
+
+        tx.rollback().await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,427 @@
+use std::panic::AssertUnwindSafe;
+
+use axum::async_trait;
+use chrono::{DateTime, Utc};
+use futures_util::FutureExt;
+use sqlx::{pool::PoolConnection, FromRow, PgConnection, PgPool, Postgres};
+use uuid::Uuid;
+
+use super::transaction;
+use crate::domain::{
+    models::{
+        invitations::{
+            invitation_id::InvitationId,
+            invitation_repository::{IInvitationRepository, InvitationRepositoryError, Result},
+            invitation_status::InvitationStatus,
+            todo_list_invitation::TodoListInvitation,
+        },
+        users::user_id::UserId,
+    },
+    value_object::ValueObject,
+};
+
+#[derive(FromRow)]
+#[sqlx(rename_all = "snake_case")]
+struct InvitationRow {
+    id: Uuid,
+    list_id: Uuid,
+    invitee_email: String,
+    invited_by: Uuid,
+    status: String,
+    expires_at: DateTime<Utc>,
+    invitee_id: Option<Uuid>,
+}
+
+impl InvitationRow {
+    fn into_invitation(self) -> Result<TodoListInvitation> {
+        let invite_id = InvitationId::new(self.id)
+            .map_err(|e| InvitationRepositoryError::Unexpected(e.to_string()))?;
+        let invitee_id = self
+            .invitee_id
+            .map(UserId::new)
+            .transpose()
+            .map_err(|e| InvitationRepositoryError::Unexpected(e.to_string()))?;
+        let invited_by = UserId::new(self.invited_by)
+            .map_err(|e| InvitationRepositoryError::Unexpected(e.to_string()))?;
+        let status = InvitationStatus::parse(self.status)
+            .map_err(|e| InvitationRepositoryError::Unexpected(e.to_string()))?;
+        Ok(TodoListInvitation::build(
+            invite_id,
+            self.list_id,
+            self.invitee_email,
+            invitee_id,
+            invited_by,
+            status,
+            self.expires_at,
+        ))
+    }
+}
+
+#[derive(Clone)]
+pub struct PgInvitationRepository {
+    pool: PgPool,
+}
+
+impl PgInvitationRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    async fn connection(&self) -> Result<PoolConnection<Postgres>> {
+        self.pool
+            .acquire()
+            .await
+            .map_err(|e| InvitationRepositoryError::Unexpected(e.to_string()))
+    }
+
+    async fn start_tx(&self) -> Result<sqlx::Transaction<'_, Postgres>> {
+        self.pool
+            .begin()
+            .await
+            .map_err(|e| InvitationRepositoryError::Unexpected(e.to_string()))
+    }
+}
+
+#[async_trait]
+impl IInvitationRepository for PgInvitationRepository {
+    async fn save(&self, invitation: &TodoListInvitation) -> Result<()> {
+        let mut conn = self.connection().await?;
+        let mut internal_invitation_repository = InternalInvitationRepository::new(&mut conn);
+        internal_invitation_repository.save(invitation).await
+    }
+
+    async fn find(&self, invite_id: &InvitationId) -> Result<Option<TodoListInvitation>> {
+        let mut conn = self.connection().await?;
+        let mut internal_invitation_repository = InternalInvitationRepository::new(&mut conn);
+        internal_invitation_repository.find(invite_id).await
+    }
+
+    async fn find_by_list(&self, list_id: &Uuid) -> Result<Vec<TodoListInvitation>> {
+        let mut conn = self.connection().await?;
+        let mut internal_invitation_repository = InternalInvitationRepository::new(&mut conn);
+        internal_invitation_repository.find_by_list(list_id).await
+    }
+
+    async fn delete(&self, invitation: TodoListInvitation) -> Result<()> {
+        let mut conn = self.connection().await?;
+        let mut internal_invitation_repository = InternalInvitationRepository::new(&mut conn);
+        internal_invitation_repository.delete(invitation).await
+    }
+
+    async fn add_member(&self, list_id: &Uuid, user_id: &UserId) -> Result<()> {
+        let mut conn = self.connection().await?;
+        let mut internal_invitation_repository = InternalInvitationRepository::new(&mut conn);
+        internal_invitation_repository
+            .add_member(list_id, user_id)
+            .await
+    }
+
+    async fn save_accepted(&self, invitation: &TodoListInvitation, user_id: &UserId) -> Result<()> {
+        let mut tx = self.start_tx().await?;
+        let result = AssertUnwindSafe(async {
+            let mut internal_invitation_repository = InternalInvitationRepository::new(&mut tx);
+            internal_invitation_repository.save(invitation).await?;
+            internal_invitation_repository
+                .add_member(invitation.list_id(), user_id)
+                .await
+        })
+        .catch_unwind()
+        .await;
+        transaction::finish_tx(tx, result, |e| {
+            InvitationRepositoryError::Unexpected(e.to_string())
+        })
+        .await
+    }
+
+    async fn join_if_first_or_is_member(&self, list_id: &Uuid, user_id: &UserId) -> Result<bool> {
+        let mut tx = self.start_tx().await?;
+        let result = AssertUnwindSafe(async {
+            let mut internal_invitation_repository = InternalInvitationRepository::new(&mut tx);
+            internal_invitation_repository
+                .join_if_first_or_is_member(list_id, user_id)
+                .await
+        })
+        .catch_unwind()
+        .await;
+        transaction::finish_tx(tx, result, |e| {
+            InvitationRepositoryError::Unexpected(e.to_string())
+        })
+        .await
+    }
+
+    async fn find_members_of(&self, list_id: &Uuid) -> Result<Vec<UserId>> {
+        let mut conn = self.connection().await?;
+        let mut internal_invitation_repository = InternalInvitationRepository::new(&mut conn);
+        internal_invitation_repository
+            .find_members_of(list_id)
+            .await
+    }
+}
+
+struct InternalInvitationRepository<'a> {
+    conn: &'a mut PgConnection,
+}
+
+impl<'a> InternalInvitationRepository<'a> {
+    fn new(conn: &'a mut PgConnection) -> Self {
+        Self { conn }
+    }
+
+    // 作成後に変わりうるのは `status` のみ
+    async fn save(&mut self, invitation: &TodoListInvitation) -> Result<()> {
+        let sql = r#"
+insert into todo_list_invitations (id, list_id, invitee_email, invited_by, status, expires_at, invitee_id)
+values ($1, $2, $3, $4, $5, $6, $7)
+on conflict (id)
+do update set status=$5
+"#;
+        sqlx::query(sql)
+            .bind(invitation.invite_id().value())
+            .bind(invitation.list_id())
+            .bind(invitation.invitee_email())
+            .bind(invitation.invited_by().value())
+            .bind(invitation.status().as_str())
+            .bind(invitation.expires_at())
+            .bind(invitation.invitee_id().map(|invitee_id| invitee_id.value()))
+            .execute(&mut *self.conn)
+            .await
+            .map_err(|e| InvitationRepositoryError::Unexpected(e.to_string()))?;
+        Ok(())
+    }
+
+    async fn find(&mut self, invite_id: &InvitationId) -> Result<Option<TodoListInvitation>> {
+        let sql = r#"select * from todo_list_invitations where id=$1"#;
+        let invitation_from_row = sqlx::query_as::<_, InvitationRow>(sql)
+            .bind(invite_id.value())
+            .fetch_optional(&mut *self.conn)
+            .await
+            .map_err(|e| InvitationRepositoryError::Unexpected(e.to_string()))?;
+        let invitation = invitation_from_row
+            .map(|row| row.into_invitation())
+            .transpose()?;
+        Ok(invitation)
+    }
+
+    async fn find_by_list(&mut self, list_id: &Uuid) -> Result<Vec<TodoListInvitation>> {
+        let sql = r#"select * from todo_list_invitations where list_id=$1 order by expires_at"#;
+        let invitations_from_rows = sqlx::query_as::<_, InvitationRow>(sql)
+            .bind(list_id)
+            .fetch_all(&mut *self.conn)
+            .await
+            .map_err(|e| InvitationRepositoryError::Unexpected(e.to_string()))?;
+        invitations_from_rows
+            .into_iter()
+            .map(|row| row.into_invitation())
+            .collect()
+    }
+
+    async fn delete(&mut self, invitation: TodoListInvitation) -> Result<()> {
+        let invite_id = invitation.invite_id();
+        let sql = r#"delete from todo_list_invitations where id=$1"#;
+        let result = sqlx::query(sql)
+            .bind(invite_id.value())
+            .execute(&mut *self.conn)
+            .await
+            .map_err(|e| InvitationRepositoryError::Unexpected(e.to_string()))?;
+        if result.rows_affected() == 0 {
+            return Err(InvitationRepositoryError::NotFound(invite_id.clone()));
+        }
+        Ok(())
+    }
+
+    async fn add_member(&mut self, list_id: &Uuid, user_id: &UserId) -> Result<()> {
+        let sql = r#"
+insert into todo_list_members (list_id, user_id)
+values ($1, $2)
+on conflict do nothing
+"#;
+        sqlx::query(sql)
+            .bind(list_id)
+            .bind(user_id.value())
+            .execute(&mut *self.conn)
+            .await
+            .map_err(|e| InvitationRepositoryError::Unexpected(e.to_string()))?;
+        Ok(())
+    }
+
+    async fn join_if_first_or_is_member(
+        &mut self,
+        list_id: &Uuid,
+        user_id: &UserId,
+    ) -> Result<bool> {
+        // 同じリストへの最初のメンバーの追加が同時に行われないよう、リストごとにロックする
+        // ロックはトランザクションの終了時に解放される
+        let sql = r#"select pg_advisory_xact_lock(hashtextextended($1::text, 0))"#;
+        sqlx::query(sql)
+            .bind(list_id)
+            .execute(&mut *self.conn)
+            .await
+            .map_err(|e| InvitationRepositoryError::Unexpected(e.to_string()))?;
+
+        let sql = r#"
+insert into todo_list_members (list_id, user_id)
+select $1, $2
+where not exists (select 1 from todo_list_members where list_id=$1)
+"#;
+        sqlx::query(sql)
+            .bind(list_id)
+            .bind(user_id.value())
+            .execute(&mut *self.conn)
+            .await
+            .map_err(|e| InvitationRepositoryError::Unexpected(e.to_string()))?;
+
+        let sql =
+            r#"select exists (select 1 from todo_list_members where list_id=$1 and user_id=$2)"#;
+        sqlx::query_scalar::<_, bool>(sql)
+            .bind(list_id)
+            .bind(user_id.value())
+            .fetch_one(&mut *self.conn)
+            .await
+            .map_err(|e| InvitationRepositoryError::Unexpected(e.to_string()))
+    }
+
+    async fn find_members_of(&mut self, list_id: &Uuid) -> Result<Vec<UserId>> {
+        let sql = r#"select user_id from todo_list_members where list_id=$1 order by user_id"#;
+        let user_ids = sqlx::query_scalar::<_, Uuid>(sql)
+            .bind(list_id)
+            .fetch_all(&mut *self.conn)
+            .await
+            .map_err(|e| InvitationRepositoryError::Unexpected(e.to_string()))?;
+        user_ids
+            .into_iter()
+            .map(|user_id| {
+                UserId::new(user_id)
+                    .map_err(|e| InvitationRepositoryError::Unexpected(e.to_string()))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "database-test")]
+mod tests {
+    use anyhow::Result;
+
+    use super::*;
+    use crate::{
+        domain::models::users::{user::User, user_name::UserName},
+        pg_pool,
+    };
+
+    #[tokio::test]
+    async fn invitation_crud_senario() -> Result<()> {
+        let pool = pg_pool::connect_to_test_pg_pool().await;
+
+        let mut tx = pool.begin().await?;
+
+        // save users for test
+        let inviter = User::new(UserName::new("inviter".to_string())?)?;
+        let invitee = User::new(UserName::new("invitee".to_string())?)?;
+        for user in [&inviter, &invitee] {
+            sqlx::query(r#"insert into users (id, name) values ($1, $2)"#)
+                .bind(user.user_id().value())
+                .bind(user.user_name.value())
+                .execute(&mut *tx)
+                .await?;
+        }
+
+        let mut internal_invitation_repository = InternalInvitationRepository::new(&mut tx);
+
+        // save
+        let list_id = Uuid::new_v4();
+        let mut new_invitation = TodoListInvitation::new(
+            list_id,
+            "invitee@example.com".to_string(),
+            invitee.user_id().clone(),
+            inviter.user_id().clone(),
+            Utc::now(),
+        )?;
+        internal_invitation_repository.save(&new_invitation).await?;
+
+        // find
+        let invitation_found = internal_invitation_repository
+            .find(new_invitation.invite_id())
+            .await?
+            .unwrap();
+        assert_eq!(new_invitation, invitation_found);
+        assert_eq!("invitee@example.com", invitation_found.invitee_email());
+        assert_eq!(Some(invitee.user_id()), invitation_found.invitee_id());
+        assert_eq!(InvitationStatus::Pending, invitation_found.status());
+
+        // join_if_first_or_is_member
+        // メンバーがいないリストでは、最初のユーザーがメンバーになる
+        assert!(
+            internal_invitation_repository
+                .join_if_first_or_is_member(&list_id, inviter.user_id())
+                .await?
+        );
+        assert!(
+            !internal_invitation_repository
+                .join_if_first_or_is_member(&list_id, invitee.user_id())
+                .await?
+        );
+
+        // save (accept)
+        new_invitation.accept(invitee.user_id(), Utc::now())?;
+        internal_invitation_repository.save(&new_invitation).await?;
+        internal_invitation_repository
+            .add_member(&list_id, invitee.user_id())
+            .await?;
+        // 2 回目は何もしない
+        internal_invitation_repository
+            .add_member(&list_id, invitee.user_id())
+            .await?;
+
+        // find_by_list
+        let invitations_found = internal_invitation_repository
+            .find_by_list(&list_id)
+            .await?;
+        assert_eq!(vec![new_invitation.clone()], invitations_found);
+        assert_eq!(InvitationStatus::Accepted, invitations_found[0].status());
+
+        // find_members_of
+        let members = internal_invitation_repository
+            .find_members_of(&list_id)
+            .await?;
+        let mut expected_members = vec![inviter.user_id().clone(), invitee.user_id().clone()];
+        expected_members.sort_by_key(|user_id| *user_id.value());
+        assert_eq!(expected_members, members);
+
+        // delete
+        internal_invitation_repository
+            .delete(new_invitation.clone())
+            .await?;
+        let invitation_found = internal_invitation_repository
+            .find(new_invitation.invite_id())
+            .await?;
+        assert!(invitation_found.is_none());
+
+        // delete (not found)
+        let result = internal_invitation_repository.delete(new_invitation).await;
+        assert!(matches!(
+            result,
+            Err(InvitationRepositoryError::NotFound(_))
+        ));
+
+        tx.rollback().await?;
+        Ok(())
+    }
+
+    // 行の構造体の各フィールドがスキーマ上の列と型に一致することを、コンパイル時に照合する
+    #[tokio::test]
+    async fn row_struct_should_type_check_against_schema() -> Result<()> {
+        let pool = pg_pool::connect_to_test_pg_pool().await;
+        let mut tx = pool.begin().await?;
+
+        sqlx::query_as!(
+            InvitationRow,
+            r#"select * from todo_list_invitations where id=$1"#,
+            Uuid::new_v4()
+        )
+        .fetch_optional(&mut *tx)
+        .await?;
+
+        tx.rollback().await?;
+        Ok(())
+    }
+}
@@ -1,21 +1,33 @@
+use std::{collections::HashMap, panic::AssertUnwindSafe};
+
 use axum::async_trait;
-use sqlx::{pool::PoolConnection, FromRow, PgConnection, PgPool, Postgres};
+use futures_util::FutureExt;
+use sqlx::{
+    pool::PoolConnection, postgres::PgDatabaseError, FromRow, PgConnection, PgPool, Postgres,
+};
+use unicode_normalization::UnicodeNormalization;
 use uuid::Uuid;
 
+use super::transaction;
 use crate::domain::{
-    models::labels::{
-        label::Label,
-        label_id::LabelId,
-        label_name::LabelName,
-        label_repository::{ILabelRepository, LabelRepositoryError, Result},
+    models::{
+        labels::{
+            label::{position_between, Label, POSITION_GAP},
+            label_id::LabelId,
+            label_name::LabelName,
+            label_repository::{ILabelRepository, LabelRepositoryError, Result},
+        },
+        users::user_id::UserId,
     },
     value_object::ValueObject,
 };
 
 #[derive(FromRow)]
+#[sqlx(rename_all = "snake_case")]
 pub struct LabelRow {
     id: Uuid,
     name: String,
+    position: i64,
 }
 
 impl LabelRow {
@@ -24,7 +36,9 @@ impl LabelRow {
             LabelId::new(self.id).map_err(|e| LabelRepositoryError::Unexpected(e.to_string()))?;
         let label_name = LabelName::new(self.name)
             .map_err(|e| LabelRepositoryError::Unexpected(e.to_string()))?;
-        Ok(Label::build(label_id, label_name))
+        let mut label = Label::build(label_id, label_name);
+        label.position = self.position;
+        Ok(label)
     }
 }
 
@@ -44,6 +58,15 @@ impl PgLabelRepository {
             .await
             .map_err(|e| LabelRepositoryError::Unexpected(e.to_string()))
     }
+
+    async fn start_tx(&self) -> Result<sqlx::Transaction<'_, Postgres>> {
+        let tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| LabelRepositoryError::Unexpected(e.to_string()))?;
+        Ok(tx)
+    }
 }
 
 #[async_trait]
@@ -54,6 +77,50 @@ impl ILabelRepository for PgLabelRepository {
         internal_label_repository.save(label).await
     }
 
+    async fn save_with_order(&self, label: &Label, after_id: Option<LabelId>) -> Result<()> {
+        let mut tx = self.start_tx().await?;
+        let result = AssertUnwindSafe(async {
+            let mut internal_label_repository = InternalLabelRepository::new(&mut tx);
+            internal_label_repository
+                .save_with_order(label, after_id.as_ref())
+                .await
+        })
+        .catch_unwind()
+        .await;
+        transaction::finish_tx(tx, result, |e| {
+            LabelRepositoryError::Unexpected(e.to_string())
+        })
+        .await
+    }
+
+    async fn save_all(&self, labels: &[Label]) -> Result<()> {
+        let mut tx = self.start_tx().await?;
+        let result = AssertUnwindSafe(async {
+            let mut internal_label_repository = InternalLabelRepository::new(&mut tx);
+            internal_label_repository.save_all(labels).await
+        })
+        .catch_unwind()
+        .await;
+        transaction::finish_tx(tx, result, |e| {
+            LabelRepositoryError::Unexpected(e.to_string())
+        })
+        .await
+    }
+
+    async fn rename_bulk(&self, renames: &[(LabelId, LabelName)]) -> Result<Vec<Label>> {
+        let mut tx = self.start_tx().await?;
+        let result = AssertUnwindSafe(async {
+            let mut internal_label_repository = InternalLabelRepository::new(&mut tx);
+            internal_label_repository.rename_bulk(renames).await
+        })
+        .catch_unwind()
+        .await;
+        transaction::finish_tx(tx, result, |e| {
+            LabelRepositoryError::Unexpected(e.to_string())
+        })
+        .await
+    }
+
     async fn find(&self, label_id: &LabelId) -> Result<Option<Label>> {
         let mut conn = self.connection().await?;
         let mut internal_label_repository = InternalLabelRepository::new(&mut conn);
@@ -66,17 +133,83 @@ impl ILabelRepository for PgLabelRepository {
         internal_label_repository.find_by_name(label_name).await
     }
 
+    async fn find_by_slug(&self, slug: &str) -> Result<Option<Label>> {
+        let mut conn = self.connection().await?;
+        let mut internal_label_repository = InternalLabelRepository::new(&mut conn);
+        internal_label_repository.find_by_slug(slug).await
+    }
+
+    async fn find_starting_with(&self, prefix: &str) -> Result<Vec<Label>> {
+        let mut conn = self.connection().await?;
+        let mut internal_label_repository = InternalLabelRepository::new(&mut conn);
+        internal_label_repository.find_starting_with(prefix).await
+    }
+
     async fn find_all(&self) -> Result<Vec<Label>> {
         let mut conn = self.connection().await?;
         let mut internal_label_repository = InternalLabelRepository::new(&mut conn);
         internal_label_repository.find_all().await
     }
 
+    async fn find_all_ordered_by_position(&self) -> Result<Vec<Label>> {
+        let mut conn = self.connection().await?;
+        let mut internal_label_repository = InternalLabelRepository::new(&mut conn);
+        internal_label_repository
+            .find_all_ordered_by_position()
+            .await
+    }
+
+    async fn count_todos_per_label_map(&self) -> Result<HashMap<LabelId, u64>> {
+        let mut conn = self.connection().await?;
+        let mut internal_label_repository = InternalLabelRepository::new(&mut conn);
+        internal_label_repository.count_todos_per_label_map().await
+    }
+
+    async fn find_orphaned(&self) -> Result<Vec<Label>> {
+        let mut conn = self.connection().await?;
+        let mut internal_label_repository = InternalLabelRepository::new(&mut conn);
+        internal_label_repository.find_orphaned().await
+    }
+
+    async fn find_by_user_id_via_todos(&self, user_id: &UserId) -> Result<Vec<Label>> {
+        let mut conn = self.connection().await?;
+        let mut internal_label_repository = InternalLabelRepository::new(&mut conn);
+        internal_label_repository
+            .find_by_user_id_via_todos(user_id)
+            .await
+    }
+
+    async fn find_recently_used(&self, limit: u32) -> Result<Vec<Label>> {
+        let mut conn = self.connection().await?;
+        let mut internal_label_repository = InternalLabelRepository::new(&mut conn);
+        internal_label_repository.find_recently_used(limit).await
+    }
+
     async fn delete(&self, label: Label) -> Result<()> {
         let mut conn = self.connection().await?;
         let mut internal_label_repository = InternalLabelRepository::new(&mut conn);
         internal_label_repository.delete(label).await
     }
+
+    async fn delete_all(&self, label_ids: &[LabelId]) -> Result<()> {
+        let mut tx = self.start_tx().await?;
+        let result = AssertUnwindSafe(async {
+            let mut internal_label_repository = InternalLabelRepository::new(&mut tx);
+            internal_label_repository.delete_all(label_ids).await
+        })
+        .catch_unwind()
+        .await;
+        transaction::finish_tx(tx, result, |e| {
+            LabelRepositoryError::Unexpected(e.to_string())
+        })
+        .await
+    }
+
+    async fn delete_all_unused(&self, label_ids: &[LabelId]) -> Result<Vec<LabelId>> {
+        let mut conn = self.connection().await?;
+        let mut internal_label_repository = InternalLabelRepository::new(&mut conn);
+        internal_label_repository.delete_all_unused(label_ids).await
+    }
 }
 
 pub(super) struct InternalLabelRepository<'a> {
@@ -90,20 +223,189 @@ impl<'a> InternalLabelRepository<'a> {
 
     pub(super) async fn save(&mut self, label: &Label) -> Result<()> {
         let sql = r#"
-insert into labels (id, name)
-values ($1, $2)
+insert into labels (id, name, position)
+values ($1, $2, $3)
 on conflict (id)
 do update set name=$2
 "#;
         sqlx::query(sql)
             .bind(label.label_id().value())
             .bind(label.label_name.value())
+            .bind(label.position)
             .execute(&mut *self.conn)
             .await
-            .map_err(|e| LabelRepositoryError::Unexpected(e.to_string()))?;
+            .map_err(|e| match e.as_database_error() {
+                // 別の id で同じ名前のラベルが保存されている
+                Some(db_error) if db_error.is_unique_violation() => {
+                    LabelRepositoryError::AlreadyExists(label.label_name.clone())
+                }
+                _ => LabelRepositoryError::Unexpected(e.to_string()),
+            })?;
         Ok(())
     }
 
+    // 呼び出し側のトランザクション内で実行し、後ろのラベルをずらす途中で失敗した場合はロールバックしてもらう
+    pub(super) async fn save_with_order(
+        &mut self,
+        label: &Label,
+        after_id: Option<&LabelId>,
+    ) -> Result<()> {
+        let before = match after_id {
+            Some(after_id) => {
+                let sql = r#"select position from labels where id=$1"#;
+                let position = sqlx::query_scalar::<_, i64>(sql)
+                    .bind(after_id.value())
+                    .fetch_optional(&mut *self.conn)
+                    .await
+                    .map_err(|e| LabelRepositoryError::Unexpected(e.to_string()))?
+                    .ok_or_else(|| LabelRepositoryError::NotFound(after_id.clone()))?;
+                Some(position)
+            }
+            None => None,
+        };
+
+        let position = match position_between(before, self.next_position(label, before).await?) {
+            Some(position) => position,
+            None => {
+                // 後ろに並ぶラベルをずらして間を空ける
+                let sql = r#"
+                    update labels set position = position + $3
+                    where id <> $1 and ($2::int8 is null or position > $2)"#;
+                sqlx::query(sql)
+                    .bind(label.label_id().value())
+                    .bind(before)
+                    .bind(POSITION_GAP)
+                    .execute(&mut *self.conn)
+                    .await
+                    .map_err(|e| LabelRepositoryError::Unexpected(e.to_string()))?;
+                position_between(before, self.next_position(label, before).await?)
+                    .expect("labels after the position must have been shifted")
+            }
+        };
+
+        let sql = r#"
+insert into labels (id, name, position)
+values ($1, $2, $3)
+on conflict (id)
+do update set name=$2, position=$3
+"#;
+        sqlx::query(sql)
+            .bind(label.label_id().value())
+            .bind(label.label_name.value())
+            .bind(position)
+            .execute(&mut *self.conn)
+            .await
+            .map_err(|e| match e.as_database_error() {
+                // 別の id で同じ名前のラベルが保存されている
+                Some(db_error) if db_error.is_unique_violation() => {
+                    LabelRepositoryError::AlreadyExists(label.label_name.clone())
+                }
+                _ => LabelRepositoryError::Unexpected(e.to_string()),
+            })?;
+        Ok(())
+    }
+
+    // `before` より後ろに並ぶラベルのうち、最も前にあるものの位置を返す
+    async fn next_position(&mut self, label: &Label, before: Option<i64>) -> Result<Option<i64>> {
+        let sql = r#"
+            select min(position) from labels
+            where id <> $1 and ($2::int8 is null or position > $2)"#;
+        sqlx::query_scalar::<_, Option<i64>>(sql)
+            .bind(label.label_id().value())
+            .bind(before)
+            .fetch_one(&mut *self.conn)
+            .await
+            .map_err(|e| LabelRepositoryError::Unexpected(e.to_string()))
+    }
+
+    // ラベルごとにクエリを発行せず、配列を unnest して一度に upsert する
+    pub(super) async fn save_all(&mut self, labels: &[Label]) -> Result<()> {
+        let sql = r#"
+insert into labels (id, name, position)
+select * from unnest($1::uuid[], $2::text[], $3::int8[])
+on conflict (id)
+do update set name=excluded.name
+"#;
+        let mut ids = Vec::with_capacity(labels.len());
+        let mut names = Vec::with_capacity(labels.len());
+        let mut positions = Vec::with_capacity(labels.len());
+        for label in labels {
+            ids.push(*label.label_id().value());
+            names.push(label.label_name.value().to_string());
+            positions.push(label.position);
+        }
+        sqlx::query(sql)
+            .bind(ids)
+            .bind(names)
+            .bind(positions)
+            .execute(&mut *self.conn)
+            .await
+            .map_err(|e| {
+                // 別の id で同じ名前のラベルが保存されているか、同じ名前のラベルが複数含まれている
+                // どの名前が重複したかは `Key (name)=(...) already exists.` という詳細から探す
+                let duplicated_label = e
+                    .as_database_error()
+                    .filter(|db_error| db_error.is_unique_violation())
+                    .and_then(|db_error| db_error.try_downcast_ref::<PgDatabaseError>())
+                    .and_then(|pg_error| pg_error.detail())
+                    .and_then(|detail| {
+                        labels.iter().find(|label| {
+                            detail.contains(&format!("=({})", label.label_name.value()))
+                        })
+                    });
+                match duplicated_label {
+                    Some(label) => LabelRepositoryError::AlreadyExists(label.label_name.clone()),
+                    None => LabelRepositoryError::Unexpected(e.to_string()),
+                }
+            })?;
+        Ok(())
+    }
+
+    // 呼び出し側のトランザクション内で実行し、失敗した場合はロールバックしてもらう
+    pub(super) async fn rename_bulk(
+        &mut self,
+        renames: &[(LabelId, LabelName)],
+    ) -> Result<Vec<Label>> {
+        // name の UNIQUE 制約は 1 行ごとに確かめられるため、名前を入れ替えると途中で重複してしまう
+        // いったん 20 文字以上になる id の文字列に変えておき、LabelName と重複しないようにしてから変更する
+        let sql = r#"update labels set name=id::text where id = any($1::uuid[]) returning id"#;
+        let ids: Vec<Uuid> = renames
+            .iter()
+            .map(|(label_id, _)| *label_id.value())
+            .collect();
+        let found_ids = sqlx::query_scalar::<_, Uuid>(sql)
+            .bind(&ids)
+            .fetch_all(&mut *self.conn)
+            .await
+            .map_err(|e| LabelRepositoryError::Unexpected(e.to_string()))?;
+        if let Some((label_id, _)) = renames
+            .iter()
+            .find(|(label_id, _)| !found_ids.contains(label_id.value()))
+        {
+            return Err(LabelRepositoryError::NotFound(label_id.clone()));
+        }
+
+        let sql = r#"update labels set name=$2 where id=$1 returning *"#;
+        let mut renamed_labels = Vec::with_capacity(renames.len());
+        for (label_id, label_name) in renames {
+            let label_from_row = sqlx::query_as::<_, LabelRow>(sql)
+                .bind(label_id.value())
+                .bind(label_name.value())
+                .fetch_optional(&mut *self.conn)
+                .await
+                .map_err(|e| match e.as_database_error() {
+                    // 別の id で同じ名前のラベルが保存されている
+                    Some(db_error) if db_error.is_unique_violation() => {
+                        LabelRepositoryError::AlreadyExists(label_name.clone())
+                    }
+                    _ => LabelRepositoryError::Unexpected(e.to_string()),
+                })?
+                .ok_or_else(|| LabelRepositoryError::NotFound(label_id.clone()))?;
+            renamed_labels.push(label_from_row.into_label()?);
+        }
+        Ok(renamed_labels)
+    }
+
     async fn find(&mut self, label_id: &LabelId) -> Result<Option<Label>> {
         let sql = r#"select * from labels where id=$1"#;
         let label_from_row = sqlx::query_as::<_, LabelRow>(sql)
@@ -126,8 +428,94 @@ do update set name=$2
         Ok(label)
     }
 
+    // LabelName::slug と同じ規則で name から slug を作って比べる
+    async fn find_by_slug(&mut self, slug: &str) -> Result<Option<Label>> {
+        let sql = r#"
+            select * from labels
+            where trim(both '-' from lower(regexp_replace(name, '[^a-zA-Z0-9]+', '-', 'g')))=$1
+            order by id
+            limit 1"#;
+        let label_from_row = sqlx::query_as::<_, LabelRow>(sql)
+            .bind(slug)
+            .fetch_optional(&mut *self.conn)
+            .await
+            .map_err(|e| LabelRepositoryError::Unexpected(e.to_string()))?;
+        let label = label_from_row.map(|row| row.into_label()).transpose()?;
+        Ok(label)
+    }
+
+    // `prefix` に含まれる `%` や `_` はワイルドカードではなく文字として扱う
+    async fn find_starting_with(&mut self, prefix: &str) -> Result<Vec<Label>> {
+        let sql = r#"
+            select * from labels
+            where lower(name) like lower($1) || '%' escape '\'
+            order by name collate "C""#;
+        let prefix: String = prefix.nfc().collect();
+        let escaped_prefix = prefix
+            .replace('\\', r"\\")
+            .replace('%', r"\%")
+            .replace('_', r"\_");
+        let labels_from_rows = sqlx::query_as::<_, LabelRow>(sql)
+            .bind(escaped_prefix)
+            .fetch_all(&mut *self.conn)
+            .await
+            .map_err(|e| LabelRepositoryError::Unexpected(e.to_string()))?;
+        let labels = labels_from_rows
+            .into_iter()
+            .map(|row| row.into_label())
+            .collect::<Result<Vec<Label>>>()?;
+        Ok(labels)
+    }
+
     async fn find_all(&mut self) -> Result<Vec<Label>> {
-        let sql = r#"select * from labels order by id desc"#;
+        // in-memory の実装と同じく、データベースの照合順序によらずバイト順に並べる
+        let sql = r#"select * from labels order by name collate "C" asc"#;
+        let labels_from_rows = sqlx::query_as::<_, LabelRow>(sql)
+            .fetch_all(&mut *self.conn)
+            .await
+            .map_err(|e| LabelRepositoryError::Unexpected(e.to_string()))?;
+        let labels = labels_from_rows
+            .into_iter()
+            .map(|row| row.into_label())
+            .collect::<Result<Vec<Label>>>()?;
+        Ok(labels)
+    }
+
+    async fn find_all_ordered_by_position(&mut self) -> Result<Vec<Label>> {
+        let sql = r#"select * from labels order by position asc, id"#;
+        let labels_from_rows = sqlx::query_as::<_, LabelRow>(sql)
+            .fetch_all(&mut *self.conn)
+            .await
+            .map_err(|e| LabelRepositoryError::Unexpected(e.to_string()))?;
+        let labels = labels_from_rows
+            .into_iter()
+            .map(|row| row.into_label())
+            .collect::<Result<Vec<Label>>>()?;
+        Ok(labels)
+    }
+
+    async fn count_todos_per_label_map(&mut self) -> Result<HashMap<LabelId, u64>> {
+        let sql = r#"select label_id, count(*) from todo_labels group by label_id"#;
+        let count_rows = sqlx::query_as::<_, (Uuid, i64)>(sql)
+            .fetch_all(&mut *self.conn)
+            .await
+            .map_err(|e| LabelRepositoryError::Unexpected(e.to_string()))?;
+        count_rows
+            .into_iter()
+            .map(|(label_id, count)| {
+                let label_id = LabelId::new(label_id)
+                    .map_err(|e| LabelRepositoryError::Unexpected(e.to_string()))?;
+                Ok((label_id, count as u64))
+            })
+            .collect()
+    }
+
+    async fn find_orphaned(&mut self) -> Result<Vec<Label>> {
+        let sql = r#"
+            select labels.* from labels
+                left outer join todo_labels tl on labels.id = tl.label_id
+            where tl.label_id is null
+            order by labels.id desc"#;
         let labels_from_rows = sqlx::query_as::<_, LabelRow>(sql)
             .fetch_all(&mut *self.conn)
             .await
@@ -139,6 +527,50 @@ do update set name=$2
         Ok(labels)
     }
 
+    async fn find_by_user_id_via_todos(&mut self, user_id: &UserId) -> Result<Vec<Label>> {
+        let sql = r#"
+            select labels.* from labels
+            where labels.id in (
+                select tl.label_id from todo_labels tl
+                    inner join todos on tl.todo_id = todos.id
+                where todos.assignee_id = $1
+            )
+            order by labels.name collate "C""#;
+        let labels_from_rows = sqlx::query_as::<_, LabelRow>(sql)
+            .bind(user_id.value())
+            .fetch_all(&mut *self.conn)
+            .await
+            .map_err(|e| LabelRepositoryError::Unexpected(e.to_string()))?;
+        let labels = labels_from_rows
+            .into_iter()
+            .map(|row| row.into_label())
+            .collect::<Result<Vec<Label>>>()?;
+        Ok(labels)
+    }
+
+    async fn find_recently_used(&mut self, limit: u32) -> Result<Vec<Label>> {
+        // 結合すると副問い合わせの並び順は保証されないため、外側でも並べ直す
+        let sql = r#"
+            select labels.* from labels
+                inner join (
+                    select label_id, max(attached_at) as last_used from todo_labels
+                    group by label_id
+                    order by last_used desc
+                    limit $1
+                ) recent on labels.id = recent.label_id
+            order by recent.last_used desc"#;
+        let labels_from_rows = sqlx::query_as::<_, LabelRow>(sql)
+            .bind(i64::from(limit))
+            .fetch_all(&mut *self.conn)
+            .await
+            .map_err(|e| LabelRepositoryError::Unexpected(e.to_string()))?;
+        let labels = labels_from_rows
+            .into_iter()
+            .map(|row| row.into_label())
+            .collect::<Result<Vec<Label>>>()?;
+        Ok(labels)
+    }
+
     async fn delete(&mut self, label: Label) -> Result<()> {
         let id = label.label_id();
         let sql = r#"delete from labels where id=$1"#;
@@ -152,18 +584,68 @@ do update set name=$2
             })?;
         Ok(())
     }
+
+    // 呼び出し側のトランザクション内で実行し、存在しないラベルがあった場合はロールバックしてもらう
+    // todo_labels の行は外部キーの `ON DELETE CASCADE` で削除される
+    pub(super) async fn delete_all(&mut self, label_ids: &[LabelId]) -> Result<()> {
+        let sql = r#"delete from labels where id = any($1::uuid[]) returning id"#;
+        let ids: Vec<Uuid> = label_ids.iter().map(|label_id| *label_id.value()).collect();
+        let deleted_ids = sqlx::query_scalar::<_, Uuid>(sql)
+            .bind(&ids)
+            .fetch_all(&mut *self.conn)
+            .await
+            .map_err(|e| LabelRepositoryError::Unexpected(e.to_string()))?;
+        if let Some(label_id) = label_ids
+            .iter()
+            .find(|label_id| !deleted_ids.contains(label_id.value()))
+        {
+            return Err(LabelRepositoryError::NotFound(label_id.clone()));
+        }
+        Ok(())
+    }
+
+    // todo_labels に行がないことの確認と削除を 1 つの文で行う
+    // 削除する行は todo_labels の外部キーで守られているため、その間に todo に付けられることはない
+    pub(super) async fn delete_all_unused(
+        &mut self,
+        label_ids: &[LabelId],
+    ) -> Result<Vec<LabelId>> {
+        let sql = r#"
+delete from labels
+where id = any($1::uuid[])
+and not exists (select 1 from todo_labels where todo_labels.label_id = labels.id)
+returning id
+"#;
+        let ids: Vec<Uuid> = label_ids.iter().map(|label_id| *label_id.value()).collect();
+        let deleted_ids = sqlx::query_scalar::<_, Uuid>(sql)
+            .bind(&ids)
+            .fetch_all(&mut *self.conn)
+            .await
+            .map_err(|e| LabelRepositoryError::Unexpected(e.to_string()))?;
+        // 指定された順に並べて返す
+        Ok(label_ids
+            .iter()
+            .filter(|label_id| deleted_ids.contains(label_id.value()))
+            .cloned()
+            .collect())
+    }
 }
 
 #[cfg(test)]
 #[cfg(feature = "database-test")]
 mod tests {
-    use std::collections::HashSet;
+    use std::time::{Duration, Instant};
 
     use anyhow::Result;
 
+    use crate::test_helpers::macros::assert_label_equal;
+
     use super::*;
     use crate::{
-        domain::models::todos::{todo::Todo, todo_text::TodoText},
+        domain::models::{
+            todos::{todo::Todo, todo_text::TodoText},
+            users::{user::User, user_name::UserName},
+        },
         infra::repository_impl::pg::pg_todo_repository::InternalTodoRepository,
         pg_pool,
     };
@@ -188,7 +670,7 @@ mod tests {
         let mut internal_todo_repository = InternalTodoRepository::new(&mut tx);
         let todo = Todo::new(
             TodoText::new("test-text".to_string())?,
-            HashSet::from([new_label.clone()]),
+            vec![new_label.clone()],
         )?;
         internal_todo_repository.save(&todo).await?;
 
@@ -204,7 +686,7 @@ mod tests {
             .await
             .expect("failed to find label.")
             .unwrap();
-        assert_eq!(expected, label_found);
+        assert_label_equal!(expected, label_found);
         assert_eq!("label name", label_found.label_name.value());
 
         // find_all
@@ -228,7 +710,7 @@ mod tests {
             .await
             .expect("failed to find label.")
             .unwrap();
-        assert_eq!(expected, label_found);
+        assert_label_equal!(expected, label_found);
         assert_eq!("updated name", label_found.label_name.value());
 
         // delete
@@ -252,4 +734,641 @@ mod tests {
         tx.rollback().await?;
         Ok(())
     }
+
+    #[tokio::test]
+    async fn should_fail_to_save_label_with_name_already_taken() -> Result<()> {
+        let pool = pg_pool::connect_to_test_pg_pool().await;
+
+        let mut tx = pool.begin().await?;
+        let mut internal_label_repository = InternalLabelRepository::new(&mut tx);
+
+        let label_1 = Label::new(LabelName::new("taken name".to_string())?)?;
+        internal_label_repository.save(&label_1).await?;
+
+        let label_2 = Label::new(LabelName::new("taken name".to_string())?)?;
+        let result = internal_label_repository.save(&label_2).await;
+
+        assert!(matches!(
+            result,
+            Err(LabelRepositoryError::AlreadyExists(label_name)) if label_name.value() == "taken name"
+        ));
+
+        tx.rollback().await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_find_all_labels_in_order_of_name() -> Result<()> {
+        let pool = pg_pool::connect_to_test_pg_pool().await;
+
+        let mut tx = pool.begin().await?;
+        let mut internal_label_repository = InternalLabelRepository::new(&mut tx);
+
+        // 照合順序によらず、大文字の後に小文字が並ぶバイト順になる
+        let names = ["Zebra", "apple", "Apple", "Mango"];
+        for name in names {
+            internal_label_repository
+                .save(&Label::new(LabelName::new(name.to_string())?)?)
+                .await?;
+        }
+
+        // 他のテストで保存されたラベルは除いて、並び順を確かめる
+        let names_found: Vec<String> = internal_label_repository
+            .find_all()
+            .await?
+            .into_iter()
+            .map(|label| label.label_name.into_value())
+            .filter(|name| names.contains(&name.as_str()))
+            .collect();
+        assert_eq!(vec!["Apple", "Mango", "Zebra", "apple"], names_found);
+
+        tx.rollback().await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_find_label_by_slug() -> Result<()> {
+        let pool = pg_pool::connect_to_test_pg_pool().await;
+
+        let mut tx = pool.begin().await?;
+        let mut internal_label_repository = InternalLabelRepository::new(&mut tx);
+
+        let label_1 = Label::new(LabelName::new("Work In Progress".to_string())?)?;
+        let label_2 = Label::new(LabelName::new("C++ Tasks!".to_string())?)?;
+        internal_label_repository
+            .save_all(&[label_1.clone(), label_2.clone()])
+            .await?;
+
+        let label_found = internal_label_repository
+            .find_by_slug("work-in-progress")
+            .await?;
+        assert_eq!(Some(label_1), label_found);
+        let label_found = internal_label_repository.find_by_slug("c-tasks").await?;
+        assert_eq!(Some(label_2), label_found);
+        let label_found = internal_label_repository.find_by_slug("work").await?;
+        assert_eq!(None, label_found);
+
+        tx.rollback().await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_find_labels_starting_with_prefix() -> Result<()> {
+        let pool = pg_pool::connect_to_test_pg_pool().await;
+
+        let mut tx = pool.begin().await?;
+        let mut internal_label_repository = InternalLabelRepository::new(&mut tx);
+
+        for name in ["Prefix_A", "prefix_b", "PrefixC", "no prefix_"] {
+            let label = Label::new(LabelName::new(name.to_string())?)?;
+            internal_label_repository.save(&label).await?;
+        }
+        let names = |labels: Vec<Label>| {
+            let mut names: Vec<String> = labels
+                .into_iter()
+                .map(|label| label.label_name.into_value())
+                .collect();
+            names.sort();
+            names
+        };
+
+        let labels_found = internal_label_repository
+            .find_starting_with("PREFIX")
+            .await?;
+        assert_eq!(vec!["PrefixC", "Prefix_A", "prefix_b"], names(labels_found));
+
+        // `_` はワイルドカードとして扱わない
+        let labels_found = internal_label_repository
+            .find_starting_with("prefix_")
+            .await?;
+        assert_eq!(vec!["Prefix_A", "prefix_b"], names(labels_found));
+
+        tx.rollback().await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_delete_all_labels_or_none() -> Result<()> {
+        let pool = pg_pool::connect_to_test_pg_pool().await;
+
+        let mut tx = pool.begin().await?;
+
+        let label_a = Label::new(LabelName::new("delete all A".to_string())?)?;
+        let label_b = Label::new(LabelName::new("delete all B".to_string())?)?;
+        InternalLabelRepository::new(&mut tx)
+            .save_all(&[label_a.clone(), label_b.clone()])
+            .await?;
+        let todo = Todo::new(
+            TodoText::new("delete all".to_string())?,
+            vec![label_a.clone()],
+        )?;
+        InternalTodoRepository::new(&mut tx).save(&todo).await?;
+
+        // 存在しないラベルが含まれていれば、一つも削除しない
+        let missing_id = LabelId::new(Uuid::new_v4())?;
+        let mut savepoint = sqlx::Connection::begin(&mut *tx).await?;
+        let result = InternalLabelRepository::new(&mut savepoint)
+            .delete_all(&[label_b.label_id().clone(), missing_id.clone()])
+            .await;
+        assert!(
+            matches!(result, Err(LabelRepositoryError::NotFound(label_id)) if label_id == missing_id)
+        );
+        savepoint.rollback().await?;
+        let mut internal_label_repository = InternalLabelRepository::new(&mut tx);
+        assert!(internal_label_repository
+            .find(label_b.label_id())
+            .await?
+            .is_some());
+
+        // todo に付いているラベルは todo から外れる
+        internal_label_repository
+            .delete_all(&[label_a.label_id().clone(), label_b.label_id().clone()])
+            .await?;
+        for label in [&label_a, &label_b] {
+            assert!(internal_label_repository
+                .find(label.label_id())
+                .await?
+                .is_none());
+        }
+        let attached_count: i64 =
+            sqlx::query_scalar(r#"select count(*) from todo_labels where todo_id = $1"#)
+                .bind(todo.todo_id().value())
+                .fetch_one(&mut *tx)
+                .await?;
+        assert_eq!(0, attached_count);
+
+        tx.rollback().await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_delete_only_labels_not_attached_to_todos() -> Result<()> {
+        let pool = pg_pool::connect_to_test_pg_pool().await;
+        let mut tx = pool.begin().await?;
+
+        let used = Label::new(LabelName::new("delete unused A".to_string())?)?;
+        let unused = Label::new(LabelName::new("delete unused B".to_string())?)?;
+        InternalLabelRepository::new(&mut tx)
+            .save_all(&[used.clone(), unused.clone()])
+            .await?;
+        let todo = Todo::new(
+            TodoText::new("delete unused".to_string())?,
+            vec![used.clone()],
+        )?;
+        InternalTodoRepository::new(&mut tx).save(&todo).await?;
+
+        let missing_id = LabelId::new(Uuid::new_v4())?;
+        let mut internal_label_repository = InternalLabelRepository::new(&mut tx);
+        let deleted_ids = internal_label_repository
+            .delete_all_unused(&[
+                used.label_id().clone(),
+                unused.label_id().clone(),
+                missing_id,
+            ])
+            .await?;
+
+        assert_eq!(vec![unused.label_id().clone()], deleted_ids);
+        assert!(internal_label_repository
+            .find(used.label_id())
+            .await?
+            .is_some());
+        assert!(internal_label_repository
+            .find(unused.label_id())
+            .await?
+            .is_none());
+
+        tx.rollback().await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_save_all_labels_at_once() -> Result<()> {
+        let pool = pg_pool::connect_to_test_pg_pool().await;
+
+        let mut tx = pool.begin().await?;
+        let mut internal_label_repository = InternalLabelRepository::new(&mut tx);
+
+        let mut label_1 = Label::new(LabelName::new("bulk label 1".to_string())?)?;
+        let label_2 = Label::new(LabelName::new("bulk label 2".to_string())?)?;
+        internal_label_repository
+            .save_all(&[label_1.clone(), label_2.clone()])
+            .await?;
+
+        // 既存のラベルは上書きされる
+        label_1.label_name = LabelName::new("bulk label updated".to_string())?;
+        internal_label_repository
+            .save_all(&[label_1.clone()])
+            .await?;
+
+        let label_found = internal_label_repository
+            .find(label_1.label_id())
+            .await?
+            .unwrap();
+        assert_eq!("bulk label updated", label_found.label_name.value());
+        let label_found = internal_label_repository
+            .find(label_2.label_id())
+            .await?
+            .unwrap();
+        assert_eq!("bulk label 2", label_found.label_name.value());
+
+        // 別の id で同じ名前のラベルが保存されていれば、save と同じく AlreadyExists になる
+        let duplicated = Label::new(LabelName::new("bulk label 2".to_string())?)?;
+        let label_3 = Label::new(LabelName::new("bulk label 3".to_string())?)?;
+        let result = internal_label_repository
+            .save_all(&[label_3, duplicated])
+            .await;
+        assert!(matches!(
+            result,
+            Err(LabelRepositoryError::AlreadyExists(label_name)) if label_name.value() == "bulk label 2"
+        ));
+
+        tx.rollback().await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_count_todos_per_label() -> Result<()> {
+        let pool = pg_pool::connect_to_test_pg_pool().await;
+        let mut tx = pool.begin().await?;
+
+        let label_a = Label::new(LabelName::new("label-a".to_string())?)?;
+        let label_b = Label::new(LabelName::new("label-b".to_string())?)?;
+        let label_c = Label::new(LabelName::new("label-c".to_string())?)?;
+        let mut internal_label_repository = InternalLabelRepository::new(&mut tx);
+        internal_label_repository
+            .save_all(&[label_a.clone(), label_b.clone(), label_c.clone()])
+            .await?;
+
+        // label-a: 3 件, label-b: 2 件, label-c: 0 件
+        let mut internal_todo_repository = InternalTodoRepository::new(&mut tx);
+        for (i, labels) in [
+            vec![label_a.clone()],
+            vec![label_a.clone(), label_b.clone()],
+            vec![label_a.clone()],
+            vec![label_b.clone()],
+            vec![],
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            let todo = Todo::new(TodoText::new(format!("todo-{}", i))?, labels)?;
+            internal_todo_repository.save(&todo).await?;
+        }
+
+        let mut internal_label_repository = InternalLabelRepository::new(&mut tx);
+        let todo_counts = internal_label_repository
+            .count_todos_per_label_map()
+            .await?;
+        let count_of = |label: &Label| todo_counts.get(label.label_id()).copied().unwrap_or(0);
+        assert_eq!(3, count_of(&label_a));
+        assert_eq!(2, count_of(&label_b));
+        assert_eq!(0, count_of(&label_c));
+        assert!(!todo_counts.contains_key(label_c.label_id()));
+
+        tx.rollback().await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_find_orphaned_labels() -> Result<()> {
+        let pool = pg_pool::connect_to_test_pg_pool().await;
+        let mut tx = pool.begin().await?;
+
+        let label_a = Label::new(LabelName::new("label-a".to_string())?)?;
+        let label_b = Label::new(LabelName::new("label-b".to_string())?)?;
+        let label_c = Label::new(LabelName::new("label-c".to_string())?)?;
+        let mut internal_label_repository = InternalLabelRepository::new(&mut tx);
+        internal_label_repository
+            .save_all(&[label_a.clone(), label_b.clone(), label_c.clone()])
+            .await?;
+
+        // label-c だけがどの todo にも付いていない
+        let mut internal_todo_repository = InternalTodoRepository::new(&mut tx);
+        let todo = Todo::new(
+            TodoText::new("todo".to_string())?,
+            vec![label_a.clone(), label_b.clone()],
+        )?;
+        internal_todo_repository.save(&todo).await?;
+
+        let mut internal_label_repository = InternalLabelRepository::new(&mut tx);
+        let orphaned_labels = internal_label_repository.find_orphaned().await?;
+        assert!(orphaned_labels.contains(&label_c));
+        assert!(!orphaned_labels.contains(&label_a));
+        assert!(!orphaned_labels.contains(&label_b));
+
+        tx.rollback().await?;
+        Ok(())
+    }
+
+    // 100 件のラベルを 1 件ずつ保存する場合と比べて、一括保存が 5 倍以上速いことを確かめる
+    // 実行時間を比べるため通常のテストからは外し、`cargo test -- --ignored` で実行する
+    #[tokio::test]
+    #[ignore]
+    async fn save_all_should_be_faster_than_saving_one_by_one() -> Result<()> {
+        const LABEL_COUNT: usize = 100;
+        const ROUNDS: usize = 5;
+
+        fn labels_for_bench(prefix: &str) -> Result<Vec<Label>> {
+            (0..LABEL_COUNT)
+                .map(|i| Label::new(LabelName::new(format!("{}-{}", prefix, i))?))
+                .collect()
+        }
+
+        let pool = pg_pool::connect_to_test_pg_pool().await;
+
+        let mut tx = pool.begin().await?;
+        let mut internal_label_repository = InternalLabelRepository::new(&mut tx);
+
+        // プリペアドステートメントのキャッシュによる差が出ないよう、先に一度ずつ実行しておく
+        let warm_up = labels_for_bench("warm-up")?;
+        internal_label_repository.save(&warm_up[0]).await?;
+        internal_label_repository.save_all(&warm_up[1..]).await?;
+
+        // 計測のぶれを抑えるため、複数回計測した最短時間どうしを比べる
+        let mut one_by_one = Duration::MAX;
+        let mut bulk = Duration::MAX;
+        for round in 0..ROUNDS {
+            let labels = labels_for_bench(&format!("one-by-one-{}", round))?;
+            let started_at = Instant::now();
+            for label in &labels {
+                internal_label_repository.save(label).await?;
+            }
+            one_by_one = one_by_one.min(started_at.elapsed());
+
+            let labels = labels_for_bench(&format!("bulk-{}", round))?;
+            let started_at = Instant::now();
+            internal_label_repository.save_all(&labels).await?;
+            bulk = bulk.min(started_at.elapsed());
+        }
+
+        tx.rollback().await?;
+
+        assert!(
+            bulk * 5 <= one_by_one,
+            "save_all: {:?}, save x {}: {:?}",
+            bulk,
+            LABEL_COUNT,
+            one_by_one
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_rename_labels_all_or_nothing() -> Result<()> {
+        let pool = pg_pool::connect_to_test_pg_pool().await;
+
+        let mut tx = pool.begin().await?;
+
+        let labels = [
+            Label::new(LabelName::new("rename-1".to_string())?)?,
+            Label::new(LabelName::new("rename-2".to_string())?)?,
+            Label::new(LabelName::new("rename-3".to_string())?)?,
+        ];
+        let taken = Label::new(LabelName::new("rename-taken".to_string())?)?;
+        let mut internal_label_repository = InternalLabelRepository::new(&mut tx);
+        internal_label_repository.save_all(&labels).await?;
+        internal_label_repository.save(&taken).await?;
+
+        let renames = |names: [&str; 3]| -> Result<Vec<(LabelId, LabelName)>> {
+            labels
+                .iter()
+                .zip(names)
+                .map(|(label, name)| {
+                    Ok((label.label_id().clone(), LabelName::new(name.to_string())?))
+                })
+                .collect()
+        };
+
+        // 2 件目が既存のラベルと衝突するため、セーブポイントまで戻す
+        let mut savepoint = sqlx::Connection::begin(&mut *tx).await?;
+        let result = InternalLabelRepository::new(&mut savepoint)
+            .rename_bulk(&renames(["renamed-1", "rename-taken", "renamed-3"])?)
+            .await;
+        assert!(matches!(
+            result,
+            Err(LabelRepositoryError::AlreadyExists(label_name)) if label_name.value() == "rename-taken"
+        ));
+        savepoint.rollback().await?;
+
+        let mut internal_label_repository = InternalLabelRepository::new(&mut tx);
+        for label in &labels {
+            let label_found = internal_label_repository
+                .find(label.label_id())
+                .await?
+                .unwrap();
+            assert_label_equal!(label.clone(), label_found);
+        }
+
+        let renamed_labels = internal_label_repository
+            .rename_bulk(&renames(["renamed-1", "renamed-2", "renamed-3"])?)
+            .await?;
+        let names: Vec<&str> = renamed_labels
+            .iter()
+            .map(|label| label.label_name.value().as_str())
+            .collect();
+        assert_eq!(vec!["renamed-1", "renamed-2", "renamed-3"], names);
+
+        // 名前を入れ替えても、途中で重複したとみなさない
+        let renamed_labels = internal_label_repository
+            .rename_bulk(&renames(["renamed-2", "renamed-1", "renamed-3"])?)
+            .await?;
+        let names: Vec<&str> = renamed_labels
+            .iter()
+            .map(|label| label.label_name.value().as_str())
+            .collect();
+        assert_eq!(vec!["renamed-2", "renamed-1", "renamed-3"], names);
+
+        tx.rollback().await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_find_distinct_labels_of_todos_assigned_to_user() -> Result<()> {
+        let pool = pg_pool::connect_to_test_pg_pool().await;
+
+        let mut tx = pool.begin().await?;
+
+        // save user for test
+        let user = User::new(UserName::new("label-owner".to_string())?)?;
+        sqlx::query(r#"insert into users (id, name) values ($1, $2)"#)
+            .bind(user.user_id().value())
+            .bind(user.user_name.value())
+            .execute(&mut *tx)
+            .await?;
+
+        let label_a = Label::new(LabelName::new("user label A".to_string())?)?;
+        let label_b = Label::new(LabelName::new("user label B".to_string())?)?;
+        let label_c = Label::new(LabelName::new("user label C".to_string())?)?;
+        InternalLabelRepository::new(&mut tx)
+            .save_all(&[label_a.clone(), label_b.clone(), label_c.clone()])
+            .await?;
+
+        let mut internal_todo_repository = InternalTodoRepository::new(&mut tx);
+        for labels in [
+            vec![label_b.clone(), label_a.clone()],
+            vec![label_a.clone()],
+        ] {
+            let mut todo = Todo::new(TodoText::new("assigned".to_string())?, labels)?;
+            todo.assignee_id = Some(user.user_id().clone());
+            internal_todo_repository.save(&todo).await?;
+        }
+        // 担当していない todo のラベルは含めない
+        let others_todo = Todo::new(TodoText::new("others".to_string())?, vec![label_c])?;
+        internal_todo_repository.save(&others_todo).await?;
+
+        let labels_found = InternalLabelRepository::new(&mut tx)
+            .find_by_user_id_via_todos(user.user_id())
+            .await?;
+        assert_eq!(2, labels_found.len());
+        assert_label_equal!(label_a, labels_found[0]);
+        assert_label_equal!(label_b, labels_found[1]);
+
+        tx.rollback().await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_find_recently_used_labels_first() -> Result<()> {
+        let pool = pg_pool::connect_to_test_pg_pool().await;
+
+        let mut tx = pool.begin().await?;
+
+        let label_a = Label::new(LabelName::new("recent label A".to_string())?)?;
+        let label_b = Label::new(LabelName::new("recent label B".to_string())?)?;
+        InternalLabelRepository::new(&mut tx)
+            .save_all(&[label_a.clone(), label_b.clone()])
+            .await?;
+
+        // A を付け、次に B を付け、もう一度 B を付ける
+        let mut internal_todo_repository = InternalTodoRepository::new(&mut tx);
+        let mut todos = Vec::new();
+        for (i, label) in [&label_a, &label_b, &label_b].into_iter().enumerate() {
+            let todo = Todo::new(
+                TodoText::new(format!("recently used {}", i))?,
+                vec![label.clone()],
+            )?;
+            internal_todo_repository.save(&todo).await?;
+            todos.push(todo);
+        }
+        // 同じトランザクション内では `NOW()` が変わらないため、付けた順に日時をずらす
+        // 既存のデータより新しくなるよう、未来の日時にする
+        for (i, todo) in todos.iter().enumerate() {
+            sqlx::query(
+                r#"update todo_labels set attached_at = now() + make_interval(hours => $2) where todo_id = $1"#,
+            )
+            .bind(todo.todo_id().value())
+            .bind(i as i32 + 1)
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        let labels_found = InternalLabelRepository::new(&mut tx)
+            .find_recently_used(2)
+            .await?;
+        assert_eq!(2, labels_found.len());
+        assert_label_equal!(label_b, labels_found[0]);
+        assert_label_equal!(label_a, labels_found[1]);
+
+        tx.rollback().await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_find_labels_in_order_of_position() -> Result<()> {
+        let pool = pg_pool::connect_to_test_pg_pool().await;
+
+        let mut tx = pool.begin().await?;
+        let mut internal_label_repository = InternalLabelRepository::new(&mut tx);
+
+        // 作成した順に並ぶ
+        let mut labels = Vec::new();
+        for name in ["ordered label B", "ordered label C", "ordered label A"] {
+            let label = Label::new(LabelName::new(name.to_string())?)?;
+            internal_label_repository.save(&label).await?;
+            labels.push(label);
+        }
+        let ordered_names = |labels_found: Vec<Label>| {
+            labels_found
+                .into_iter()
+                .map(|label| label.label_name.into_value())
+                .filter(|name| name.starts_with("ordered label"))
+                .collect::<Vec<_>>()
+        };
+        let labels_found = internal_label_repository
+            .find_all_ordered_by_position()
+            .await?;
+        assert_eq!(
+            vec!["ordered label B", "ordered label C", "ordered label A"],
+            ordered_names(labels_found)
+        );
+
+        // 間に差し込む
+        let label_d = Label::new(LabelName::new("ordered label D".to_string())?)?;
+        internal_label_repository
+            .save_with_order(&label_d, Some(labels[0].label_id()))
+            .await?;
+        let labels_found = internal_label_repository
+            .find_all_ordered_by_position()
+            .await?;
+        assert_eq!(
+            vec![
+                "ordered label B",
+                "ordered label D",
+                "ordered label C",
+                "ordered label A"
+            ],
+            ordered_names(labels_found)
+        );
+
+        // 間が空いていなければ、後ろのラベルをずらしてから差し込む
+        let sql = r#"update labels set position = $2 where id = $1"#;
+        for (label, position) in [(&labels[0], 100_i64), (&label_d, 101)] {
+            sqlx::query(sql)
+                .bind(label.label_id().value())
+                .bind(position)
+                .execute(&mut *internal_label_repository.conn)
+                .await?;
+        }
+        let label_e = Label::new(LabelName::new("ordered label E".to_string())?)?;
+        internal_label_repository
+            .save_with_order(&label_e, Some(labels[0].label_id()))
+            .await?;
+        let labels_found = internal_label_repository
+            .find_all_ordered_by_position()
+            .await?;
+        assert_eq!(
+            vec![
+                "ordered label B",
+                "ordered label E",
+                "ordered label D",
+                "ordered label C",
+                "ordered label A"
+            ],
+            ordered_names(labels_found)
+        );
+
+        // 存在しないラベルの後ろには並べられない
+        let label_f = Label::new(LabelName::new("ordered label F".to_string())?)?;
+        let result = internal_label_repository
+            .save_with_order(&label_f, Some(label_f.label_id()))
+            .await;
+        assert!(matches!(result, Err(LabelRepositoryError::NotFound(_))));
+
+        tx.rollback().await?;
+        Ok(())
+    }
+
+    // 行の構造体の各フィールドがスキーマ上の列と型に一致することを、コンパイル時に照合する
+    #[tokio::test]
+    async fn row_struct_should_type_check_against_schema() -> Result<()> {
+        let pool = pg_pool::connect_to_test_pg_pool().await;
+        let mut tx = pool.begin().await?;
+
+        sqlx::query_as!(LabelRow, r#"select * from labels order by id desc"#)
+            .fetch_all(&mut *tx)
+            .await?;
+
+        tx.rollback().await?;
+        Ok(())
+    }
 }
@@ -0,0 +1,193 @@
+use axum::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{pool::PoolConnection, FromRow, PgConnection, PgPool, Postgres};
+use uuid::Uuid;
+
+use crate::domain::{
+    models::{
+        sessions::{
+            session::Session,
+            session_id::SessionId,
+            session_repository::{ISessionRepository, Result, SessionRepositoryError},
+        },
+        users::user_id::UserId,
+    },
+    value_object::ValueObject,
+};
+
+#[derive(FromRow)]
+#[sqlx(rename_all = "snake_case")]
+struct SessionRow {
+    id: Uuid,
+    user_id: Uuid,
+    created_at: DateTime<Utc>,
+    revoked: bool,
+}
+
+impl SessionRow {
+    fn into_session(self) -> Result<Session> {
+        let session_id = SessionId::new(self.id)
+            .map_err(|e| SessionRepositoryError::Unexpected(e.to_string()))?;
+        let user_id = UserId::new(self.user_id)
+            .map_err(|e| SessionRepositoryError::Unexpected(e.to_string()))?;
+        Ok(Session::build(
+            session_id,
+            user_id,
+            self.created_at,
+            self.revoked,
+        ))
+    }
+}
+
+#[derive(Clone)]
+pub struct PgSessionRepository {
+    pool: PgPool,
+}
+
+impl PgSessionRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    async fn connection(&self) -> Result<PoolConnection<Postgres>> {
+        self.pool
+            .acquire()
+            .await
+            .map_err(|e| SessionRepositoryError::Unexpected(e.to_string()))
+    }
+}
+
+#[async_trait]
+impl ISessionRepository for PgSessionRepository {
+    async fn save(&self, session: &Session) -> Result<()> {
+        let mut conn = self.connection().await?;
+        let mut internal_session_repository = InternalSessionRepository::new(&mut conn);
+        internal_session_repository.save(session).await
+    }
+
+    async fn find(&self, session_id: &SessionId) -> Result<Option<Session>> {
+        let mut conn = self.connection().await?;
+        let mut internal_session_repository = InternalSessionRepository::new(&mut conn);
+        internal_session_repository.find(session_id).await
+    }
+}
+
+struct InternalSessionRepository<'a> {
+    conn: &'a mut PgConnection,
+}
+
+impl<'a> InternalSessionRepository<'a> {
+    fn new(conn: &'a mut PgConnection) -> Self {
+        Self { conn }
+    }
+
+    // 発行後に変わりうるのは `revoked` のみ
+    async fn save(&mut self, session: &Session) -> Result<()> {
+        let sql = r#"
+insert into sessions (id, user_id, created_at, revoked)
+values ($1, $2, $3, $4)
+on conflict (id)
+do update set revoked=$4
+"#;
+        sqlx::query(sql)
+            .bind(session.session_id().value())
+            .bind(session.user_id().value())
+            .bind(session.created_at())
+            .bind(session.revoked())
+            .execute(&mut *self.conn)
+            .await
+            .map_err(|e| SessionRepositoryError::Unexpected(e.to_string()))?;
+        Ok(())
+    }
+
+    async fn find(&mut self, session_id: &SessionId) -> Result<Option<Session>> {
+        let sql = r#"select * from sessions where id=$1"#;
+        let session_from_row = sqlx::query_as::<_, SessionRow>(sql)
+            .bind(session_id.value())
+            .fetch_optional(&mut *self.conn)
+            .await
+            .map_err(|e| SessionRepositoryError::Unexpected(e.to_string()))?;
+        let session = session_from_row.map(|row| row.into_session()).transpose()?;
+        Ok(session)
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "database-test")]
+mod tests {
+    use anyhow::Result;
+
+    use super::*;
+    use crate::{
+        domain::models::users::{user::User, user_name::UserName},
+        pg_pool,
+    };
+
+    #[tokio::test]
+    async fn session_crud_senario() -> Result<()> {
+        let pool = pg_pool::connect_to_test_pg_pool().await;
+
+        let mut tx = pool.begin().await?;
+
+        // save user for test
+        let user = User::new(UserName::new("user name".to_string())?)?;
+        sqlx::query(r#"insert into users (id, name) values ($1, $2)"#)
+            .bind(user.user_id().value())
+            .bind(user.user_name.value())
+            .execute(&mut *tx)
+            .await?;
+
+        let mut internal_session_repository = InternalSessionRepository::new(&mut tx);
+
+        // save
+        let mut new_session = Session::new(user.user_id().clone())?;
+        internal_session_repository.save(&new_session).await?;
+
+        // find
+        let session_found = internal_session_repository
+            .find(new_session.session_id())
+            .await?
+            .unwrap();
+        assert_eq!(new_session, session_found);
+        assert_eq!(user.user_id(), session_found.user_id());
+        assert!(session_found.is_valid());
+
+        // save (revoke)
+        new_session.revoke();
+        internal_session_repository.save(&new_session).await?;
+
+        // find
+        let session_found = internal_session_repository
+            .find(new_session.session_id())
+            .await?
+            .unwrap();
+        assert!(!session_found.is_valid());
+
+        // find (not found)
+        let session_found = internal_session_repository
+            .find(&SessionId::new(Uuid::new_v4())?)
+            .await?;
+        assert!(session_found.is_none());
+
+        tx.rollback().await?;
+        Ok(())
+    }
+
+    // 行の構造体の各フィールドがスキーマ上の列と型に一致することを、コンパイル時に照合する
+    #[tokio::test]
+    async fn row_struct_should_type_check_against_schema() -> Result<()> {
+        let pool = pg_pool::connect_to_test_pg_pool().await;
+        let mut tx = pool.begin().await?;
+
+        sqlx::query_as!(
+            SessionRow,
+            r#"select * from sessions where id=$1"#,
+            Uuid::new_v4()
+        )
+        .fetch_optional(&mut *tx)
+        .await?;
+
+        tx.rollback().await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,285 @@
+use axum::async_trait;
+use sqlx::{pool::PoolConnection, FromRow, PgConnection, PgPool, Postgres};
+use uuid::Uuid;
+
+use crate::domain::{
+    models::{
+        todo_dependencies::{
+            dependency_id::DependencyId,
+            todo_dependency::TodoDependency,
+            todo_dependency_repository::{
+                ITodoDependencyRepository, Result, TodoDependencyRepositoryError,
+            },
+        },
+        todos::todo_id::TodoId,
+    },
+    value_object::ValueObject,
+};
+
+#[derive(FromRow)]
+#[sqlx(rename_all = "snake_case")]
+struct TodoDependencyRow {
+    id: Uuid,
+    from_todo_id: Uuid,
+    to_todo_id: Uuid,
+}
+
+impl TodoDependencyRow {
+    fn into_todo_dependency(self) -> Result<TodoDependency> {
+        let dependency_id = DependencyId::new(self.id)
+            .map_err(|e| TodoDependencyRepositoryError::Unexpected(e.to_string()))?;
+        let from_todo_id = TodoId::new(self.from_todo_id)
+            .map_err(|e| TodoDependencyRepositoryError::Unexpected(e.to_string()))?;
+        let to_todo_id = TodoId::new(self.to_todo_id)
+            .map_err(|e| TodoDependencyRepositoryError::Unexpected(e.to_string()))?;
+        Ok(TodoDependency::build(
+            dependency_id,
+            from_todo_id,
+            to_todo_id,
+        ))
+    }
+}
+
+#[derive(Clone)]
+pub struct PgTodoDependencyRepository {
+    pool: PgPool,
+}
+
+impl PgTodoDependencyRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    async fn connection(&self) -> Result<PoolConnection<Postgres>> {
+        self.pool
+            .acquire()
+            .await
+            .map_err(|e| TodoDependencyRepositoryError::Unexpected(e.to_string()))
+    }
+}
+
+#[async_trait]
+impl ITodoDependencyRepository for PgTodoDependencyRepository {
+    async fn add(&self, todo_dependency: &TodoDependency) -> Result<()> {
+        let mut conn = self.connection().await?;
+        let mut internal_todo_dependency_repository =
+            InternalTodoDependencyRepository::new(&mut conn);
+        internal_todo_dependency_repository
+            .add(todo_dependency)
+            .await
+    }
+
+    async fn remove(&self, todo_dependency: TodoDependency) -> Result<()> {
+        let mut conn = self.connection().await?;
+        let mut internal_todo_dependency_repository =
+            InternalTodoDependencyRepository::new(&mut conn);
+        internal_todo_dependency_repository
+            .remove(todo_dependency)
+            .await
+    }
+
+    async fn find(&self, dependency_id: &DependencyId) -> Result<Option<TodoDependency>> {
+        let mut conn = self.connection().await?;
+        let mut internal_todo_dependency_repository =
+            InternalTodoDependencyRepository::new(&mut conn);
+        internal_todo_dependency_repository
+            .find(dependency_id)
+            .await
+    }
+
+    async fn find_dependencies_of(&self, todo_id: &TodoId) -> Result<Vec<TodoDependency>> {
+        let mut conn = self.connection().await?;
+        let mut internal_todo_dependency_repository =
+            InternalTodoDependencyRepository::new(&mut conn);
+        internal_todo_dependency_repository
+            .find_dependencies_of(todo_id)
+            .await
+    }
+
+    async fn find_dependents_of(&self, todo_id: &TodoId) -> Result<Vec<TodoDependency>> {
+        let mut conn = self.connection().await?;
+        let mut internal_todo_dependency_repository =
+            InternalTodoDependencyRepository::new(&mut conn);
+        internal_todo_dependency_repository
+            .find_dependents_of(todo_id)
+            .await
+    }
+}
+
+struct InternalTodoDependencyRepository<'a> {
+    conn: &'a mut PgConnection,
+}
+
+impl<'a> InternalTodoDependencyRepository<'a> {
+    fn new(conn: &'a mut PgConnection) -> Self {
+        Self { conn }
+    }
+
+    async fn add(&mut self, todo_dependency: &TodoDependency) -> Result<()> {
+        let sql = r#"
+insert into todo_dependencies (id, from_todo_id, to_todo_id)
+values ($1, $2, $3)
+"#;
+        sqlx::query(sql)
+            .bind(todo_dependency.dependency_id().value())
+            .bind(todo_dependency.from_todo_id().value())
+            .bind(todo_dependency.to_todo_id().value())
+            .execute(&mut *self.conn)
+            .await
+            .map_err(|e| TodoDependencyRepositoryError::Unexpected(e.to_string()))?;
+        Ok(())
+    }
+
+    async fn remove(&mut self, todo_dependency: TodoDependency) -> Result<()> {
+        let id = todo_dependency.dependency_id();
+        let sql = r#"delete from todo_dependencies where id=$1"#;
+        sqlx::query(sql)
+            .bind(id.value())
+            .execute(&mut *self.conn)
+            .await
+            .map_err(|e| match e {
+                sqlx::Error::RowNotFound => TodoDependencyRepositoryError::NotFound(id.clone()),
+                _ => TodoDependencyRepositoryError::Unexpected(e.to_string()),
+            })?;
+        Ok(())
+    }
+
+    async fn find(&mut self, dependency_id: &DependencyId) -> Result<Option<TodoDependency>> {
+        let sql = r#"select * from todo_dependencies where id=$1"#;
+        let todo_dependency_from_row = sqlx::query_as::<_, TodoDependencyRow>(sql)
+            .bind(dependency_id.value())
+            .fetch_optional(&mut *self.conn)
+            .await
+            .map_err(|e| TodoDependencyRepositoryError::Unexpected(e.to_string()))?;
+        let todo_dependency = todo_dependency_from_row
+            .map(|row| row.into_todo_dependency())
+            .transpose()?;
+        Ok(todo_dependency)
+    }
+
+    async fn find_dependencies_of(&mut self, todo_id: &TodoId) -> Result<Vec<TodoDependency>> {
+        let sql = r#"select * from todo_dependencies where to_todo_id=$1"#;
+        self.fetch_all_with_todo_id(sql, todo_id).await
+    }
+
+    async fn find_dependents_of(&mut self, todo_id: &TodoId) -> Result<Vec<TodoDependency>> {
+        let sql = r#"select * from todo_dependencies where from_todo_id=$1"#;
+        self.fetch_all_with_todo_id(sql, todo_id).await
+    }
+
+    async fn fetch_all_with_todo_id(
+        &mut self,
+        sql: &str,
+        todo_id: &TodoId,
+    ) -> Result<Vec<TodoDependency>> {
+        let todo_dependencies_from_rows = sqlx::query_as::<_, TodoDependencyRow>(sql)
+            .bind(todo_id.value())
+            .fetch_all(&mut *self.conn)
+            .await
+            .map_err(|e| TodoDependencyRepositoryError::Unexpected(e.to_string()))?;
+        let todo_dependencies = todo_dependencies_from_rows
+            .into_iter()
+            .map(|row| row.into_todo_dependency())
+            .collect::<Result<Vec<TodoDependency>>>()?;
+        Ok(todo_dependencies)
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "database-test")]
+mod tests {
+    use anyhow::Result;
+
+    use super::*;
+    use crate::{
+        domain::models::todos::{todo::Todo, todo_text::TodoText},
+        infra::repository_impl::pg::pg_todo_repository::InternalTodoRepository,
+        pg_pool,
+    };
+
+    #[tokio::test]
+    async fn todo_dependency_crud_senario() -> Result<()> {
+        let pool = pg_pool::connect_to_test_pg_pool().await;
+
+        let mut tx = pool.begin().await?;
+
+        // save todos for test
+        let mut internal_todo_repository = InternalTodoRepository::new(&mut tx);
+        let todo_1 = Todo::new(TodoText::new("todo 1".to_string())?, vec![])?;
+        internal_todo_repository.save(&todo_1).await?;
+        let todo_2 = Todo::new(TodoText::new("todo 2".to_string())?, vec![])?;
+        internal_todo_repository.save(&todo_2).await?;
+
+        let mut internal_todo_dependency_repository =
+            InternalTodoDependencyRepository::new(&mut tx);
+
+        // add
+        let new_todo_dependency =
+            TodoDependency::new(todo_1.todo_id().clone(), todo_2.todo_id().clone())?;
+        let new_dependency_id = new_todo_dependency.dependency_id();
+        internal_todo_dependency_repository
+            .add(&new_todo_dependency)
+            .await?;
+
+        // find
+        let expected = new_todo_dependency.clone();
+        let todo_dependency_found = internal_todo_dependency_repository
+            .find(new_dependency_id)
+            .await
+            .expect("failed to find todo dependency.")
+            .unwrap();
+        assert_eq!(expected, todo_dependency_found);
+        assert_eq!(todo_1.todo_id(), todo_dependency_found.from_todo_id());
+        assert_eq!(todo_2.todo_id(), todo_dependency_found.to_todo_id());
+
+        // find_dependencies_of
+        let todo_dependencies_found = internal_todo_dependency_repository
+            .find_dependencies_of(todo_2.todo_id())
+            .await?;
+        assert_eq!(vec![expected.clone()], todo_dependencies_found);
+        let todo_dependencies_found = internal_todo_dependency_repository
+            .find_dependencies_of(todo_1.todo_id())
+            .await?;
+        assert!(todo_dependencies_found.is_empty());
+
+        // find_dependents_of
+        let todo_dependencies_found = internal_todo_dependency_repository
+            .find_dependents_of(todo_1.todo_id())
+            .await?;
+        assert_eq!(vec![expected], todo_dependencies_found);
+
+        // remove
+        let dependency_id = new_dependency_id.clone();
+        internal_todo_dependency_repository
+            .remove(new_todo_dependency)
+            .await
+            .expect("failed to remove todo dependency.");
+
+        // find
+        let todo_dependency_found = internal_todo_dependency_repository
+            .find(&dependency_id)
+            .await?;
+        assert_eq!(todo_dependency_found, None);
+
+        tx.rollback().await?;
+        Ok(())
+    }
+
+    // 行の構造体の各フィールドがスキーマ上の列と型に一致することを、コンパイル時に照合する
+    #[tokio::test]
+    async fn row_struct_should_type_check_against_schema() -> Result<()> {
+        let pool = pg_pool::connect_to_test_pg_pool().await;
+        let mut tx = pool.begin().await?;
+
+        sqlx::query_as!(
+            TodoDependencyRow,
+            r#"select * from todo_dependencies where from_todo_id=$1"#,
+            Uuid::new_v4()
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        tx.rollback().await?;
+        Ok(())
+    }
+}
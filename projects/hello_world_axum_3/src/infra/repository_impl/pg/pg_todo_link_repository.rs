@@ -0,0 +1,141 @@
+use axum::async_trait;
+use sqlx::{PgConnection, PgPool};
+use url::Url;
+
+use crate::domain::{
+    models::{
+        todo_links::todo_link_repository::{ITodoLinkRepository, Result, TodoLinkRepositoryError},
+        todos::todo_id::TodoId,
+    },
+    value_object::ValueObject,
+};
+
+#[derive(Clone)]
+pub struct PgTodoLinkRepository {
+    pool: PgPool,
+}
+
+impl PgTodoLinkRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+fn map_sqlx_error(e: sqlx::Error) -> TodoLinkRepositoryError {
+    TodoLinkRepositoryError::Unexpected(e.to_string())
+}
+
+#[async_trait]
+impl ITodoLinkRepository for PgTodoLinkRepository {
+    async fn find_links(&self, todo_id: &TodoId) -> Result<Vec<Url>> {
+        let mut conn = self.pool.acquire().await.map_err(map_sqlx_error)?;
+        let mut internal_todo_link_repository = InternalTodoLinkRepository::new(&mut conn);
+        internal_todo_link_repository.find_links(todo_id).await
+    }
+}
+
+// todo と同じトランザクションで書き込めるよう、PgTodoRepository からも使う
+pub(super) struct InternalTodoLinkRepository<'a> {
+    conn: &'a mut PgConnection,
+}
+
+impl<'a> InternalTodoLinkRepository<'a> {
+    pub(super) fn new(conn: &'a mut PgConnection) -> Self {
+        Self { conn }
+    }
+
+    pub(super) async fn save_links(&mut self, todo_id: &TodoId, links: Vec<Url>) -> Result<()> {
+        sqlx::query(r#"delete from todo_links where todo_id=$1"#)
+            .bind(todo_id.value())
+            .execute(&mut *self.conn)
+            .await
+            .map_err(map_sqlx_error)?;
+
+        if links.is_empty() {
+            return Ok(());
+        }
+        let urls: Vec<String> = links.into_iter().map(String::from).collect();
+        let order_indexes: Vec<i32> = (0..urls.len() as i32).collect();
+        let sql = r#"
+insert into todo_links (todo_id, url, order_index)
+select $1, * from unnest($2::text[], $3::integer[])
+"#;
+        sqlx::query(sql)
+            .bind(todo_id.value())
+            .bind(urls)
+            .bind(order_indexes)
+            .execute(&mut *self.conn)
+            .await
+            .map_err(map_sqlx_error)?;
+        Ok(())
+    }
+
+    async fn find_links(&mut self, todo_id: &TodoId) -> Result<Vec<Url>> {
+        let sql = r#"select url from todo_links where todo_id=$1 order by order_index"#;
+        let urls = sqlx::query_scalar::<_, String>(sql)
+            .bind(todo_id.value())
+            .fetch_all(&mut *self.conn)
+            .await
+            .map_err(map_sqlx_error)?;
+        urls.iter()
+            .map(|url| {
+                Url::parse(url).map_err(|e| TodoLinkRepositoryError::Unexpected(e.to_string()))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "database-test")]
+mod tests {
+    use anyhow::Result;
+
+    use super::*;
+    use crate::{
+        domain::models::todos::{todo::Todo, todo_text::TodoText},
+        infra::repository_impl::pg::pg_todo_repository::InternalTodoRepository,
+        pg_pool,
+    };
+
+    #[tokio::test]
+    async fn should_replace_saved_links_in_order() -> Result<()> {
+        let pool = pg_pool::connect_to_test_pg_pool().await;
+
+        let mut tx = pool.begin().await?;
+
+        // save todo for test
+        let mut internal_todo_repository = InternalTodoRepository::new(&mut tx);
+        let todo = Todo::new(TodoText::new("todo".to_string())?, vec![])?;
+        internal_todo_repository.save(&todo).await?;
+
+        let mut internal_todo_link_repository = InternalTodoLinkRepository::new(&mut tx);
+        let links = vec![
+            Url::parse("http://b.example")?,
+            Url::parse("http://a.example")?,
+        ];
+        internal_todo_link_repository
+            .save_links(todo.todo_id(), links.clone())
+            .await?;
+        assert_eq!(
+            links,
+            internal_todo_link_repository
+                .find_links(todo.todo_id())
+                .await?
+        );
+
+        // 置き換える
+        let links = vec![Url::parse("https://c.example/x")?];
+        internal_todo_link_repository
+            .save_links(todo.todo_id(), links.clone())
+            .await?;
+        assert_eq!(
+            links,
+            internal_todo_link_repository
+                .find_links(todo.todo_id())
+                .await?
+        );
+
+        tx.rollback().await?;
+        Ok(())
+    }
+}
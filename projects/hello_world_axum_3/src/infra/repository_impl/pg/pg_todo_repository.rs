@@ -1,29 +1,60 @@
-use std::collections::HashSet;
+use std::{
+    collections::{HashMap, HashSet},
+    panic::AssertUnwindSafe,
+};
 
 use axum::async_trait;
-use sqlx::{pool::PoolConnection, FromRow, PgConnection, PgPool, Postgres};
+use chrono::{DateTime, NaiveDate, SecondsFormat, Utc};
+use futures_util::FutureExt;
+use indexmap::IndexMap;
+use sqlx::{
+    pool::PoolConnection, postgres::PgArguments, Arguments, FromRow, PgConnection, PgPool, Postgres,
+};
+use tokio::sync::mpsc;
+use tokio_stream::{wrappers::ReceiverStream, StreamExt};
+use tokio_util::sync::CancellationToken;
 use uuid::Uuid;
 
+use super::{
+    pg_todo_link_repository::InternalTodoLinkRepository,
+    slow_query_log::{PgConnectionWrapper, SlowQueryLogger},
+    transaction,
+};
 use crate::domain::{
     models::{
         labels::{label::Label, label_id::LabelId, label_name::LabelName},
         todos::{
+            label_filter::{FilterOperator, LabelFilter},
             todo::Todo,
             todo_id::TodoId,
-            todo_repository::{ITodoRepository, Result, TodoRepositoryError},
+            todo_note::TodoNote,
+            todo_repository::{
+                ITodoRepository, Result, TodoCollectionVersion, TodoRepositoryError, TodoStream,
+            },
             todo_text::TodoText,
         },
+        users::user_id::UserId,
     },
+    services::todo_link_service,
     value_object::ValueObject,
 };
 
 #[derive(Debug, FromRow)]
+#[sqlx(rename_all = "snake_case")]
 struct TodoRow {
     id: Uuid,
     text: String,
+    note: Option<String>,
+    due_date: Option<NaiveDate>,
+    assignee_id: Option<Uuid>,
     completed: bool,
+    completed_at: Option<DateTime<Utc>>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+    version: i64,
     label_id: Option<Uuid>,
     label_name: Option<String>,
+    label_attached_at: Option<DateTime<Utc>>,
 }
 
 impl TodoRow {
@@ -32,9 +63,19 @@ impl TodoRow {
             TodoId::new(self.id).map_err(|e| TodoRepositoryError::Unexpected(e.to_string()))?;
         let todo_text =
             TodoText::new(self.text).map_err(|e| TodoRepositoryError::Unexpected(e.to_string()))?;
+        let note = self
+            .note
+            .map(TodoNote::new)
+            .transpose()
+            .map_err(|e| TodoRepositoryError::Unexpected(e.to_string()))?;
+        let assignee_id = self
+            .assignee_id
+            .map(UserId::new)
+            .transpose()
+            .map_err(|e| TodoRepositoryError::Unexpected(e.to_string()))?;
         let completed = self.completed;
 
-        let mut labels = HashSet::new();
+        let mut labels = Vec::new();
         if let Some(label_id) = self.label_id {
             let label_id = LabelId::new(label_id)
                 .map_err(|e| TodoRepositoryError::Unexpected(e.to_string()))?;
@@ -43,45 +84,146 @@ impl TodoRow {
             ))?;
             let label_name = LabelName::new(label_name)
                 .map_err(|e| TodoRepositoryError::Unexpected(e.to_string()))?;
-            let label = Label::build(label_id, label_name);
-            labels.insert(label);
+            let mut label = Label::build(label_id, label_name);
+            label.attached_at = self.label_attached_at;
+            labels.push(label);
+        }
+
+        Ok(Todo::build(
+            todo_id,
+            todo_text,
+            note,
+            self.due_date,
+            assignee_id,
+            completed,
+            labels,
+            self.completed_at,
+            self.created_at,
+            self.updated_at,
+            self.version,
+        ))
+    }
+}
+
+// ラベルを配列に集約し、1 行が 1 つの todo に対応するようにした行
+// ストリームで読み出すときは行をまたいで todo をまとめられないため、こちらを使う
+#[derive(Debug, FromRow)]
+#[sqlx(rename_all = "snake_case")]
+struct TodoWithLabelsRow {
+    id: Uuid,
+    text: String,
+    note: Option<String>,
+    due_date: Option<NaiveDate>,
+    assignee_id: Option<Uuid>,
+    completed: bool,
+    completed_at: Option<DateTime<Utc>>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+    version: i64,
+    label_ids: Option<Vec<Uuid>>,
+    label_names: Option<Vec<String>>,
+    label_attached_ats: Option<Vec<DateTime<Utc>>>,
+}
+
+impl TodoWithLabelsRow {
+    fn into_todo(self) -> Result<Todo> {
+        let todo_id =
+            TodoId::new(self.id).map_err(|e| TodoRepositoryError::Unexpected(e.to_string()))?;
+        let todo_text =
+            TodoText::new(self.text).map_err(|e| TodoRepositoryError::Unexpected(e.to_string()))?;
+        let note = self
+            .note
+            .map(TodoNote::new)
+            .transpose()
+            .map_err(|e| TodoRepositoryError::Unexpected(e.to_string()))?;
+        let assignee_id = self
+            .assignee_id
+            .map(UserId::new)
+            .transpose()
+            .map_err(|e| TodoRepositoryError::Unexpected(e.to_string()))?;
+
+        let label_ids = self.label_ids.unwrap_or_default();
+        let label_names = self.label_names.unwrap_or_default();
+        let label_attached_ats = self.label_attached_ats.unwrap_or_default();
+        if label_ids.len() != label_names.len() || label_ids.len() != label_attached_ats.len() {
+            return Err(TodoRepositoryError::Unexpected(
+                "Unexpected error: The label corresponding to label_id was not found.".to_string(),
+            ));
         }
+        let labels = label_ids
+            .into_iter()
+            .zip(label_names)
+            .zip(label_attached_ats)
+            .map(|((label_id, label_name), attached_at)| LabelRow {
+                label_id,
+                label_name,
+                attached_at: Some(attached_at),
+            })
+            .map(LabelRow::into_label)
+            .collect::<Result<Vec<Label>>>()?;
 
-        Ok(Todo::build(todo_id, todo_text, completed, labels))
+        Ok(Todo::build(
+            todo_id,
+            todo_text,
+            note,
+            self.due_date,
+            assignee_id,
+            self.completed,
+            labels,
+            self.completed_at,
+            self.created_at,
+            self.updated_at,
+            self.version,
+        ))
     }
 }
 
+const FIND_ALL_WITH_LABELS_SQL: &str = r#"
+    select todos.*,
+        array_agg(labels.id order by tl.order_index) filter (where labels.id is not null) as label_ids,
+        array_agg(labels.name order by tl.order_index) filter (where labels.id is not null) as label_names,
+        array_agg(tl.attached_at order by tl.order_index) filter (where labels.id is not null) as label_attached_ats
+    from todos
+        left outer join todo_labels tl on todos.id = tl.todo_id
+        left outer join labels on labels.id = tl.label_id
+    group by todos.id
+    order by todos.id desc"#;
+
+// ストリームで読み出し済みで、まだ受け取られていない todo の最大件数
+const STREAM_BUFFER_SIZE: usize = 64;
+
 impl Todo {
     fn from_todo_rows(todo_rows: Vec<TodoRow>) -> Result<Vec<Todo>> {
         // 重複する todo_id を持つ todo_row を一つの Todo 構造体にまとめる
-        let mut todos = Vec::<Todo>::new();
+        // todo とラベルの並び順を保ったまま重複を取り除くため、IndexMap に集める
+        // ラベルの表示順を保つため、todo_rows は order_index 順に並んでいる必要がある
+        let mut todos = IndexMap::<TodoId, (Todo, IndexMap<LabelId, Label>)>::new();
         for todo_row in todo_rows {
-            let todo_with_same_id = todos
-                .iter_mut()
-                .find(|todo_row_acc| todo_row_acc.todo_id().value() == &todo_row.id);
-
-            match todo_with_same_id {
-                Some(todo_with_same_id) => {
-                    let label_id = LabelId::new(todo_row.label_id.unwrap())
-                        .map_err(|e| TodoRepositoryError::Unexpected(e.to_string()))?;
-                    let label_name = LabelName::new(todo_row.label_name.unwrap())
-                        .map_err(|e| TodoRepositoryError::Unexpected(e.to_string()))?;
-                    let label = Label::build(label_id, label_name);
-                    todo_with_same_id.labels.insert(label);
-                }
-                None => {
-                    todos.push(todo_row.into_todo()?);
-                }
-            };
+            let mut todo_from_row = todo_row.into_todo()?;
+            let labels_from_row = std::mem::take(&mut todo_from_row.labels);
+            let (_, labels) = todos
+                .entry(todo_from_row.todo_id().clone())
+                .or_insert_with(|| (todo_from_row, IndexMap::new()));
+            for label in labels_from_row {
+                labels.entry(label.label_id().clone()).or_insert(label);
+            }
         }
-        Ok(todos)
+        Ok(todos
+            .into_values()
+            .map(|(mut todo, labels)| {
+                todo.labels = labels.into_values().collect();
+                todo
+            })
+            .collect())
     }
 }
 
 #[derive(FromRow)]
+#[sqlx(rename_all = "snake_case")]
 pub struct LabelRow {
     label_id: Uuid,
     label_name: String,
+    attached_at: Option<DateTime<Utc>>,
 }
 
 impl LabelRow {
@@ -90,8 +232,116 @@ impl LabelRow {
             .map_err(|e| TodoRepositoryError::Unexpected(e.to_string()))?;
         let label_name = LabelName::new(self.label_name)
             .map_err(|e| TodoRepositoryError::Unexpected(e.to_string()))?;
-        Ok(Label::build(label_id, label_name))
+        let mut label = Label::build(label_id, label_name);
+        label.attached_at = self.attached_at;
+        Ok(label)
+    }
+}
+
+// Postgres の SQLSTATE `query_canceled`。statement_timeout を超えたクエリはこのエラーになる
+const QUERY_CANCELED: &str = "57014";
+
+fn map_sqlx_error(e: sqlx::Error) -> TodoRepositoryError {
+    match &e {
+        sqlx::Error::Database(db_error) if db_error.code().as_deref() == Some(QUERY_CANCELED) => {
+            TodoRepositoryError::Timeout
+        }
+        _ => TodoRepositoryError::Unexpected(e.to_string()),
+    }
+}
+
+// `find_all_stream` の読み出しを行い、todo を 1 件ずつ `tx` に送る
+// 読み出しの途中で `cancellation_token` がキャンセルされるか、受信側が破棄された場合は、
+// サーバー側でクエリが走り続けないよう `pg_cancel_backend` で取り消す
+async fn send_all_todos(
+    pool: PgPool,
+    tx: mpsc::Sender<Result<Todo>>,
+    cancellation_token: CancellationToken,
+) -> Result<()> {
+    let mut conn = pool.acquire().await.map_err(map_sqlx_error)?;
+    let backend_pid: i32 = sqlx::query_scalar("select pg_backend_pid()")
+        .fetch_one(&mut *conn)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    let mut rows =
+        sqlx::query_as::<_, TodoWithLabelsRow>(FIND_ALL_WITH_LABELS_SQL).fetch(&mut *conn);
+    loop {
+        let row = tokio::select! {
+            _ = cancellation_token.cancelled() => break,
+            _ = tx.closed() => break,
+            row = rows.next() => row,
+        };
+        match row {
+            Some(row) => {
+                let todo = row.map_err(map_sqlx_error).and_then(|row| row.into_todo());
+                if tx.send(todo).await.is_err() {
+                    break;
+                }
+            }
+            None => return Ok(()),
+        }
+    }
+    drop(rows);
+
+    sqlx::query("select pg_cancel_backend($1)")
+        .bind(backend_pid)
+        .execute(&pool)
+        .await
+        .map_err(map_sqlx_error)?;
+    // 取り消したクエリの結果が残っているため、プールには戻さずに閉じる
+    conn.close().await.map_err(map_sqlx_error)
+}
+
+// COPY のテキスト形式で、区切り文字や改行として解釈される文字をエスケープする
+fn escape_copy_field(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '\t' => escaped.push_str("\\t"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            _ => escaped.push(c),
+        }
     }
+    escaped
+}
+
+// COPY のテキスト形式では NULL を `\N` で表す
+const COPY_NULL: &str = "\\N";
+
+// todo を COPY のテキスト形式の 1 行 (タブ区切り・改行終わり) に変換する
+fn copy_row(todo: &Todo) -> String {
+    let fields = [
+        todo.todo_id().value().to_string(),
+        escape_copy_field(todo.todo_text.value()),
+        todo.completed.to_string(),
+        // timestamptz はマイクロ秒までなので、ナノ秒の桁を渡さずに揃える
+        todo.created_at()
+            .to_rfc3339_opts(SecondsFormat::Micros, true),
+        todo.updated_at()
+            .to_rfc3339_opts(SecondsFormat::Micros, true),
+        todo.note.as_ref().map_or(COPY_NULL.to_string(), |note| {
+            escape_copy_field(note.value())
+        }),
+        todo.due_date
+            .map_or(COPY_NULL.to_string(), |due_date| due_date.to_string()),
+        todo.assignee_id
+            .as_ref()
+            .map_or(COPY_NULL.to_string(), |assignee_id| {
+                assignee_id.value().to_string()
+            }),
+        todo.version().to_string(),
+        // save と同じく、完了した状態で作る todo は更新日時を完了した日時にする
+        if todo.completed {
+            todo.updated_at()
+                .to_rfc3339_opts(SecondsFormat::Micros, true)
+        } else {
+            COPY_NULL.to_string()
+        },
+    ];
+    format!("{}\n", fields.join("\t"))
 }
 
 #[derive(Clone)]
@@ -105,31 +355,39 @@ impl PgTodoRepository {
     }
 
     async fn connection(&self) -> Result<PoolConnection<Postgres>> {
-        self.pool
-            .acquire()
-            .await
-            .map_err(|e| TodoRepositoryError::Unexpected(e.to_string()))
+        self.pool.acquire().await.map_err(map_sqlx_error)
     }
 
     async fn start_tx(&self) -> Result<sqlx::Transaction<'_, Postgres>> {
-        let tx = self
-            .pool
-            .begin()
-            .await
-            .map_err(|e| TodoRepositoryError::Unexpected(e.to_string()))?;
+        let tx = self.pool.begin().await.map_err(map_sqlx_error)?;
         Ok(tx)
     }
+
+    // 大量の todo を COPY でまとめて登録する
+    // 既存の todo の更新はできないため、新規の todo の取り込みにだけ使う
+    pub async fn copy_in_bulk(&self, todos: &[Todo]) -> Result<()> {
+        let mut tx = self.start_tx().await?;
+        let result = AssertUnwindSafe(async {
+            let mut internal_todo_repository = InternalTodoRepository::new(&mut tx);
+            internal_todo_repository.copy_in_bulk(todos).await
+        })
+        .catch_unwind()
+        .await;
+        transaction::finish_tx(tx, result, map_sqlx_error).await
+    }
 }
 
 #[async_trait]
 impl ITodoRepository for PgTodoRepository {
     async fn save(&self, todo: &Todo) -> Result<()> {
         let mut tx = self.start_tx().await?;
-        let mut internal_todo_repository = InternalTodoRepository::new(&mut tx);
-        internal_todo_repository.save(todo).await?;
-        tx.commit()
-            .await
-            .map_err(|e| TodoRepositoryError::Unexpected(e.to_string()))
+        let result = AssertUnwindSafe(async {
+            let mut internal_todo_repository = InternalTodoRepository::new(&mut tx);
+            internal_todo_repository.save(todo).await
+        })
+        .catch_unwind()
+        .await;
+        transaction::finish_tx(tx, result, map_sqlx_error).await
     }
 
     async fn find(&self, todo_id: &TodoId) -> Result<Option<Todo>> {
@@ -138,19 +396,120 @@ impl ITodoRepository for PgTodoRepository {
         internal_todo_repository.find(todo_id).await
     }
 
+    async fn find_by_text_exact(&self, todo_text: &TodoText) -> Result<Option<Todo>> {
+        let mut conn = self.connection().await?;
+        let mut internal_todo_repository = InternalTodoRepository::new(&mut conn);
+        internal_todo_repository.find_by_text_exact(todo_text).await
+    }
+
     async fn find_all(&self) -> Result<Vec<Todo>> {
         let mut conn = self.connection().await?;
         let mut internal_todo_repository = InternalTodoRepository::new(&mut conn);
         internal_todo_repository.find_all().await
     }
 
+    async fn find_many_by_ids(&self, todo_ids: &[TodoId]) -> Result<Vec<Todo>> {
+        let mut conn = self.connection().await?;
+        let mut internal_todo_repository = InternalTodoRepository::new(&mut conn);
+        internal_todo_repository.find_many_by_ids(todo_ids).await
+    }
+
+    async fn find_by_label_filter(&self, label_filter: &LabelFilter) -> Result<Vec<Todo>> {
+        let mut conn = self.connection().await?;
+        let mut internal_todo_repository = InternalTodoRepository::new(&mut conn);
+        internal_todo_repository
+            .find_by_label_filter(label_filter)
+            .await
+    }
+
+    async fn find_by_assignee(&self, assignee_id: &UserId) -> Result<Vec<Todo>> {
+        let mut conn = self.connection().await?;
+        let mut internal_todo_repository = InternalTodoRepository::new(&mut conn);
+        internal_todo_repository.find_by_assignee(assignee_id).await
+    }
+
+    async fn count_by_user_and_completion(&self, user_id: &UserId) -> Result<(u64, u64)> {
+        let mut conn = self.connection().await?;
+        let mut internal_todo_repository = InternalTodoRepository::new(&mut conn);
+        internal_todo_repository
+            .count_by_user_and_completion(user_id)
+            .await
+    }
+
+    async fn collection_version(&self) -> Result<TodoCollectionVersion> {
+        let mut conn = self.connection().await?;
+        let mut internal_todo_repository = InternalTodoRepository::new(&mut conn);
+        internal_todo_repository.collection_version().await
+    }
+
+    async fn find_recently_completed_dates_by_user(
+        &self,
+        user_id: &UserId,
+        limit: u32,
+    ) -> Result<Vec<NaiveDate>> {
+        let mut conn = self.connection().await?;
+        let mut internal_todo_repository = InternalTodoRepository::new(&mut conn);
+        internal_todo_repository
+            .find_recently_completed_dates_by_user(user_id, limit)
+            .await
+    }
+
+    async fn find_due_within(&self, days: u32) -> Result<Vec<Todo>> {
+        let mut conn = self.connection().await?;
+        let mut internal_todo_repository = InternalTodoRepository::new(&mut conn);
+        internal_todo_repository.find_due_within(days).await
+    }
+
+    async fn find_similar_to(
+        &self,
+        todo_id: &TodoId,
+        threshold: f64,
+        limit: u32,
+    ) -> Result<Vec<(Todo, f64)>> {
+        let mut conn = self.connection().await?;
+        let mut internal_todo_repository = InternalTodoRepository::new(&mut conn);
+        internal_todo_repository
+            .find_similar_to(todo_id, threshold, limit)
+            .await
+    }
+
+    fn find_all_stream(&self, cancellation_token: CancellationToken) -> TodoStream<'_> {
+        // キャンセルされたときにクエリを取り消せるよう、接続を占有して別タスクで読み出す
+        let (tx, rx) = mpsc::channel(STREAM_BUFFER_SIZE);
+        let pool = self.pool.clone();
+        tokio::spawn(async move {
+            if let Err(e) = send_all_todos(pool, tx.clone(), cancellation_token).await {
+                let _ = tx.send(Err(e)).await;
+            }
+        });
+        Box::pin(ReceiverStream::new(rx))
+    }
+
     async fn delete(&self, todo: Todo) -> Result<()> {
         let mut tx = self.start_tx().await?;
-        let mut internal_todo_repository = InternalTodoRepository::new(&mut tx);
-        internal_todo_repository.delete(todo).await?;
-        tx.commit()
+        let result = AssertUnwindSafe(async {
+            let mut internal_todo_repository = InternalTodoRepository::new(&mut tx);
+            internal_todo_repository.delete(todo).await
+        })
+        .catch_unwind()
+        .await;
+        transaction::finish_tx(tx, result, map_sqlx_error).await
+    }
+
+    async fn delete_all_completed_by_user(&self, user_id: &UserId) -> Result<Vec<TodoId>> {
+        let mut conn = self.connection().await?;
+        let mut internal_todo_repository = InternalTodoRepository::new(&mut conn);
+        internal_todo_repository
+            .delete_all_completed_by_user(user_id)
+            .await
+    }
+
+    async fn set_completed_many(&self, todo_ids: &[TodoId], completed: bool) -> Result<u64> {
+        let mut conn = self.connection().await?;
+        let mut internal_todo_repository = InternalTodoRepository::new(&mut conn);
+        internal_todo_repository
+            .set_completed_many(todo_ids, completed)
             .await
-            .map_err(|e| TodoRepositoryError::Unexpected(e.to_string()))
     }
 }
 
@@ -163,29 +522,51 @@ impl<'a> InternalTodoRepository<'a> {
         Self { conn }
     }
 
+    // 遅いクエリの実行計画をログに出力できるよう、読み出しのクエリはこれを通して発行する
+    fn slow_query_logged(&mut self) -> PgConnectionWrapper<&mut PgConnection> {
+        SlowQueryLogger::from_env().wrap(&mut *self.conn)
+    }
+
     // todo を生成し
     // todo_labels の差分を取得し、それを反映する
     pub(super) async fn save(&mut self, todo: &Todo) -> Result<()> {
         // 1. save todos
+        // completed_at は完了に変わったときだけ更新日時を設定し、完了のままなら元の日時を保つ
         let sql = r#"
-            insert into todos (id, text, completed)
-            values ($1, $2, $3)
+            insert into todos (id, text, completed, created_at, updated_at, note, due_date, assignee_id, version, completed_at)
+            values ($1, $2, $3, $4, $5, $6, $7, $8, $9, case when $3 then $5 end)
             on conflict (id)
-            do update set text=$2, completed=$3
+            do update set text=$2, completed=$3, updated_at=$5, note=$6, due_date=$7, assignee_id=$8, version=$9,
+                completed_at=case when not $3 then null when todos.completed then todos.completed_at else $5 end
+            where todos.version=$9-1
             "#;
 
-        sqlx::query(sql)
+        let result = sqlx::query(sql)
             .bind(todo.todo_id().value())
             .bind(todo.todo_text.value())
             .bind(todo.completed)
+            .bind(todo.created_at())
+            .bind(todo.updated_at())
+            .bind(todo.note.as_ref().map(|note| note.value()))
+            .bind(todo.due_date)
+            .bind(
+                todo.assignee_id
+                    .as_ref()
+                    .map(|assignee_id| assignee_id.value()),
+            )
+            .bind(todo.version())
             .execute(&mut *self.conn)
             .await
-            .map_err(|e| TodoRepositoryError::Unexpected(e.to_string()))?;
+            .map_err(map_sqlx_error)?;
+        // 保存されている版が想定と違えば、他の書き込みで更新されている
+        if result.rows_affected() == 0 {
+            return Err(TodoRepositoryError::StaleData(todo.todo_id().clone()));
+        }
 
         // 2. get todo_labels difference
         // 2-1. Get labels associated with given todo
         let sql = r#"
-            select tl.label_id, l.name as label_name 
+            select tl.label_id, l.name as label_name, tl.attached_at
             from todo_labels as tl inner join labels as l
             on tl.label_id=l.id
             where tl.todo_id=$1"#;
@@ -195,7 +576,7 @@ impl<'a> InternalTodoRepository<'a> {
             .bind(todo.todo_id().value())
             .fetch_all(&mut *self.conn)
             .await
-            .map_err(|e| TodoRepositoryError::Unexpected(e.to_string()))?;
+            .map_err(map_sqlx_error)?;
         let current_label_vec: Vec<Label> = current_label_rows
             .into_iter()
             .map(|row| row.into_label())
@@ -203,9 +584,10 @@ impl<'a> InternalTodoRepository<'a> {
         let current_labels: HashSet<Label> = HashSet::from_iter(current_label_vec);
 
         // 2-3. calculate todo_labels difference
-        let new_labels = &todo.labels;
-        let labels_to_be_removed = current_labels.difference(new_labels);
-        let labels_to_be_added = new_labels.difference(&current_labels);
+        let new_labels: HashSet<&Label> = todo.labels.iter().collect();
+        let labels_to_be_removed = current_labels
+            .iter()
+            .filter(|label| !new_labels.contains(label));
 
         // 3. save todo_labels difference
         // 3-1. remove labels (not delete)
@@ -219,23 +601,72 @@ impl<'a> InternalTodoRepository<'a> {
                 .bind(label_to_be_removed.label_id().value())
                 .execute(&mut *self.conn)
                 .await
-                .map_err(|e| TodoRepositoryError::Unexpected(e.to_string()))?;
+                .map_err(map_sqlx_error)?;
         }
 
-        // 3-2. remove labels (not delete)
-        for label_to_be_added in labels_to_be_added {
+        // 3-2. add labels and update display order of labels already attached
+        for (order_index, label) in todo.labels.iter().enumerate() {
             let sql = r#"
-                insert into todo_labels (todo_id, label_id)
-                values ($1, $2)
+                insert into todo_labels (todo_id, label_id, order_index)
+                values ($1, $2, $3)
                 on conflict (todo_id, label_id)
-                do nothing"#;
+                do update set order_index=$3"#;
 
             sqlx::query(sql)
                 .bind(todo.todo_id().value())
-                .bind(label_to_be_added.label_id().value())
+                .bind(label.label_id().value())
+                .bind(order_index as i32)
                 .execute(&mut *self.conn)
                 .await
-                .map_err(|e| TodoRepositoryError::Unexpected(e.to_string()))?;
+                .map_err(map_sqlx_error)?;
+        }
+
+        // 4. replace todo_links with URLs in the text
+        self.save_links(todo).await?;
+
+        Ok(())
+    }
+
+    // テキストに含まれる URL で todo_links を置き換える
+    async fn save_links(&mut self, todo: &Todo) -> Result<()> {
+        InternalTodoLinkRepository::new(&mut *self.conn)
+            .save_links(
+                todo.todo_id(),
+                todo_link_service::extract_links(&todo.todo_text),
+            )
+            .await
+            .map_err(|e| TodoRepositoryError::Unexpected(e.to_string()))
+    }
+
+    // ラベルのない todo は COPY で 1 度に送り、ラベルのある todo は save で 1 件ずつ保存する
+    pub(super) async fn copy_in_bulk(&mut self, todos: &[Todo]) -> Result<()> {
+        let (todos_without_labels, todos_with_labels): (Vec<&Todo>, Vec<&Todo>) =
+            todos.iter().partition(|todo| todo.labels.is_empty());
+
+        if !todos_without_labels.is_empty() {
+            let sql = r#"
+                copy todos (id, text, completed, created_at, updated_at, note, due_date, assignee_id, version, completed_at)
+                from stdin"#;
+            let rows: String = todos_without_labels.iter().copied().map(copy_row).collect();
+
+            let mut copy_in = self.conn.copy_in_raw(sql).await.map_err(map_sqlx_error)?;
+            if let Err(e) = copy_in.send(rows.as_bytes()).await {
+                // 中断しないと COPY が終わらないままコネクションが返される
+                copy_in.abort(e.to_string()).await.map_err(map_sqlx_error)?;
+                return Err(map_sqlx_error(e));
+            }
+            copy_in.finish().await.map_err(map_sqlx_error)?;
+
+            // COPY では todo_links を書き込めないため、URL を含む todo の分だけ続けて保存する
+            for todo in todos_without_labels {
+                if todo.todo_text.contains_url() {
+                    self.save_links(todo).await?;
+                }
+            }
+        }
+
+        for todo in todos_with_labels {
+            self.save(todo).await?;
         }
 
         Ok(())
@@ -243,17 +674,22 @@ impl<'a> InternalTodoRepository<'a> {
 
     async fn find(&mut self, todo_id: &TodoId) -> Result<Option<Todo>> {
         let sql = r#"
-        select todos.*, labels.id as label_id, labels.name as label_name 
+        select todos.*, labels.id as label_id, labels.name as label_name, tl.attached_at as label_attached_at
         from todos 
             left outer join todo_labels tl on todos.id = tl.todo_id
             left outer join labels on labels.id = tl.label_id
-        where todos.id=$1"#;
+        where todos.id=$1
+        order by tl.order_index"#;
 
-        let todo_rows = sqlx::query_as::<_, TodoRow>(sql)
-            .bind(todo_id.value())
-            .fetch_all(&mut *self.conn)
+        let todo_rows = self
+            .slow_query_logged()
+            .fetch_all::<TodoRow>(sql, || {
+                let mut arguments = PgArguments::default();
+                arguments.add(*todo_id.value());
+                arguments
+            })
             .await
-            .map_err(|e| TodoRepositoryError::Unexpected(e.to_string()))?;
+            .map_err(map_sqlx_error)?;
 
         let mut todos = Todo::from_todo_rows(todo_rows)?;
         match todos.len() {
@@ -262,23 +698,288 @@ impl<'a> InternalTodoRepository<'a> {
         }
     }
 
+    async fn find_by_text_exact(&mut self, todo_text: &TodoText) -> Result<Option<Todo>> {
+        let sql = r#"
+        select todos.*, labels.id as label_id, labels.name as label_name, tl.attached_at as label_attached_at
+        from todos 
+            left outer join todo_labels tl on todos.id = tl.todo_id
+            left outer join labels on labels.id = tl.label_id
+        where todos.text=$1
+        order by todos.id, tl.order_index"#;
+
+        let todo_rows = self
+            .slow_query_logged()
+            .fetch_all::<TodoRow>(sql, || {
+                let mut arguments = PgArguments::default();
+                arguments.add(todo_text.value().as_str());
+                arguments
+            })
+            .await
+            .map_err(map_sqlx_error)?;
+
+        // text にはユニーク制約がないため、複数ヒットした場合は先頭の todo を返す
+        let todos = Todo::from_todo_rows(todo_rows)?;
+        Ok(todos.into_iter().next())
+    }
+
     async fn find_all(&mut self) -> Result<Vec<Todo>> {
         let sql = r#"
-        select todos.*, labels.id as label_id, labels.name as label_name 
+        select todos.*, labels.id as label_id, labels.name as label_name, tl.attached_at as label_attached_at
+        from todos 
+            left outer join todo_labels tl on todos.id = tl.todo_id
+            left outer join labels on labels.id = tl.label_id
+        order by todos.id desc, tl.order_index"#;
+
+        let todos_from_rows = self
+            .slow_query_logged()
+            .fetch_all::<TodoRow>(sql, PgArguments::default)
+            .await
+            .map_err(map_sqlx_error)?;
+
+        let todos = Todo::from_todo_rows(todos_from_rows)?;
+        Ok(todos)
+    }
+
+    async fn find_many_by_ids(&mut self, todo_ids: &[TodoId]) -> Result<Vec<Todo>> {
+        let sql = r#"
+        select todos.*, labels.id as label_id, labels.name as label_name, tl.attached_at as label_attached_at
+        from todos
+            left outer join todo_labels tl on todos.id = tl.todo_id
+            left outer join labels on labels.id = tl.label_id
+        where todos.id = any($1::uuid[])
+        order by todos.id desc, tl.order_index"#;
+
+        let ids: Vec<Uuid> = todo_ids.iter().map(|todo_id| *todo_id.value()).collect();
+        let todos_from_rows = self
+            .slow_query_logged()
+            .fetch_all::<TodoRow>(sql, || {
+                let mut arguments = PgArguments::default();
+                arguments.add(ids.clone());
+                arguments
+            })
+            .await
+            .map_err(map_sqlx_error)?;
+
+        let todos = Todo::from_todo_rows(todos_from_rows)?;
+        Ok(todos)
+    }
+
+    async fn find_by_label_filter(&mut self, label_filter: &LabelFilter) -> Result<Vec<Todo>> {
+        let LabelFilter { ids, operator } = label_filter;
+        // 条件のラベルが無い場合は、InMemory 実装の all / any と同じく And は全件、Or は 0 件とする
+        if ids.is_empty() {
+            return match operator {
+                FilterOperator::And => self.find_all().await,
+                FilterOperator::Or => Ok(vec![]),
+            };
+        }
+
+        // 条件に合う todo を副問い合わせで求め、その todo に付いたラベルはすべて読み出す
+        let matched_todo_ids_sql = match operator {
+            FilterOperator::And => {
+                r#"
+                select tl.todo_id from todo_labels tl
+                where tl.label_id = any($1::uuid[])
+                group by tl.todo_id
+                having count(distinct tl.label_id) = $2"#
+            }
+            FilterOperator::Or => {
+                r#"
+                select tl.todo_id from todo_labels tl
+                where tl.label_id = any($1::uuid[])"#
+            }
+        };
+        let sql = format!(
+            r#"
+        select todos.*, labels.id as label_id, labels.name as label_name, tl.attached_at as label_attached_at
+        from todos
+            left outer join todo_labels tl on todos.id = tl.todo_id
+            left outer join labels on labels.id = tl.label_id
+        where todos.id in ({})
+        order by todos.id desc, tl.order_index"#,
+            matched_todo_ids_sql
+        );
+
+        let label_ids: Vec<Uuid> = ids.iter().map(|label_id| *label_id.value()).collect();
+        let todos_from_rows = self
+            .slow_query_logged()
+            .fetch_all::<TodoRow>(&sql, || {
+                let mut arguments = PgArguments::default();
+                arguments.add(label_ids.clone());
+                if *operator == FilterOperator::And {
+                    arguments.add(ids.len() as i64);
+                }
+                arguments
+            })
+            .await
+            .map_err(map_sqlx_error)?;
+
+        let todos = Todo::from_todo_rows(todos_from_rows)?;
+        Ok(todos)
+    }
+
+    async fn find_by_assignee(&mut self, assignee_id: &UserId) -> Result<Vec<Todo>> {
+        let sql = r#"
+        select todos.*, labels.id as label_id, labels.name as label_name, tl.attached_at as label_attached_at
         from todos 
             left outer join todo_labels tl on todos.id = tl.todo_id
             left outer join labels on labels.id = tl.label_id
-        order by id desc"#;
+        where todos.assignee_id=$1
+        order by todos.id desc, tl.order_index"#;
+
+        let todos_from_rows = self
+            .slow_query_logged()
+            .fetch_all::<TodoRow>(sql, || {
+                let mut arguments = PgArguments::default();
+                arguments.add(*assignee_id.value());
+                arguments
+            })
+            .await
+            .map_err(map_sqlx_error)?;
+
+        let todos = Todo::from_todo_rows(todos_from_rows)?;
+        Ok(todos)
+    }
+
+    async fn count_by_user_and_completion(&mut self, user_id: &UserId) -> Result<(u64, u64)> {
+        let sql = r#"
+        select count(*) as total, count(*) filter (where completed) as completed
+        from todos
+        where assignee_id=$1"#;
+
+        let (total, completed) = sqlx::query_as::<_, (i64, i64)>(sql)
+            .bind(user_id.value())
+            .fetch_one(&mut *self.conn)
+            .await
+            .map_err(map_sqlx_error)?;
+        Ok((total as u64, completed as u64))
+    }
+
+    async fn collection_version(&mut self) -> Result<TodoCollectionVersion> {
+        let sql = r#"
+        select count(*) as count, max(updated_at) as last_updated_at, coalesce(sum(version), 0)::bigint as version_sum
+        from todos"#;
+
+        let (count, last_updated_at, version_sum) =
+            sqlx::query_as::<_, (i64, Option<DateTime<Utc>>, i64)>(sql)
+                .fetch_one(&mut *self.conn)
+                .await
+                .map_err(map_sqlx_error)?;
+        Ok(TodoCollectionVersion {
+            count: count as u64,
+            last_updated_at,
+            version_sum,
+        })
+    }
+
+    async fn find_recently_completed_dates_by_user(
+        &mut self,
+        user_id: &UserId,
+        limit: u32,
+    ) -> Result<Vec<NaiveDate>> {
+        let sql = r#"
+        select distinct (completed_at at time zone 'UTC')::date as completed_on
+        from todos
+        where assignee_id=$1 and completed and completed_at is not null
+        order by completed_on desc
+        limit $2"#;
 
-        let todos_from_rows = sqlx::query_as::<_, TodoRow>(sql)
+        let completed_dates = sqlx::query_scalar::<_, NaiveDate>(sql)
+            .bind(user_id.value())
+            .bind(i64::from(limit))
             .fetch_all(&mut *self.conn)
             .await
-            .map_err(|e| TodoRepositoryError::Unexpected(e.to_string()))?;
+            .map_err(map_sqlx_error)?;
+        Ok(completed_dates)
+    }
+
+    async fn find_due_within(&mut self, days: u32) -> Result<Vec<Todo>> {
+        let sql = r#"
+        select todos.*, labels.id as label_id, labels.name as label_name, tl.attached_at as label_attached_at
+        from todos 
+            left outer join todo_labels tl on todos.id = tl.todo_id
+            left outer join labels on labels.id = tl.label_id
+        where todos.due_date between current_date and current_date + $1
+            and todos.completed = false
+        order by todos.due_date, todos.id desc, tl.order_index"#;
+
+        let todos_from_rows = self
+            .slow_query_logged()
+            .fetch_all::<TodoRow>(sql, || {
+                let mut arguments = PgArguments::default();
+                arguments.add(days as i32);
+                arguments
+            })
+            .await
+            .map_err(map_sqlx_error)?;
 
         let todos = Todo::from_todo_rows(todos_from_rows)?;
         Ok(todos)
     }
 
+    // 類似度は pg_trgm の similarity() で求める
+    async fn find_similar_to(
+        &mut self,
+        todo_id: &TodoId,
+        threshold: f64,
+        limit: u32,
+    ) -> Result<Vec<(Todo, f64)>> {
+        let sql = r#"
+        select others.id, similarity(target.text, others.text)::float8 as similarity
+        from todos target
+            inner join todos others on others.id <> target.id
+        where target.id=$1
+            and similarity(target.text, others.text) >= $2
+        order by similarity desc, others.id desc
+        limit $3"#;
+
+        let similarities = sqlx::query_as::<_, (Uuid, f64)>(sql)
+            .bind(todo_id.value())
+            .bind(threshold)
+            .bind(i64::from(limit))
+            .fetch_all(&mut *self.conn)
+            .await
+            .map_err(map_sqlx_error)?;
+
+        let sql = r#"
+        select todos.*, labels.id as label_id, labels.name as label_name, tl.attached_at as label_attached_at
+        from todos 
+            left outer join todo_labels tl on todos.id = tl.todo_id
+            left outer join labels on labels.id = tl.label_id
+        where todos.id = any($1)
+        order by todos.id desc, tl.order_index"#;
+
+        let ids: Vec<Uuid> = similarities.iter().map(|(id, _)| *id).collect();
+        let todos_from_rows = self
+            .slow_query_logged()
+            .fetch_all::<TodoRow>(sql, || {
+                let mut arguments = PgArguments::default();
+                arguments.add(ids.clone());
+                arguments
+            })
+            .await
+            .map_err(map_sqlx_error)?;
+
+        // 類似度の高い順に並べ直す
+        // 2 つのクエリの間に削除された todo は含めない
+        let mut todos: HashMap<Uuid, Todo> = Todo::from_todo_rows(todos_from_rows)?
+            .into_iter()
+            .map(|todo| (*todo.todo_id().value(), todo))
+            .collect();
+        Ok(similarities
+            .into_iter()
+            .filter_map(|(id, similarity)| Some((todos.remove(&id)?, similarity)))
+            .collect())
+    }
+
+    #[cfg(test)]
+    fn find_all_stream(&mut self) -> TodoStream<'_> {
+        let stream = sqlx::query_as::<_, TodoWithLabelsRow>(FIND_ALL_WITH_LABELS_SQL)
+            .fetch(&mut *self.conn)
+            .map(|row| row.map_err(map_sqlx_error)?.into_todo());
+        Box::pin(stream)
+    }
+
     async fn delete(&mut self, todo: Todo) -> Result<()> {
         let id = todo.todo_id();
 
@@ -301,10 +1002,40 @@ impl<'a> InternalTodoRepository<'a> {
             .await
             .map_err(|e| match e {
                 sqlx::Error::RowNotFound => TodoRepositoryError::NotFound(id.clone()),
-                _ => TodoRepositoryError::Unexpected(e.to_string()),
+                _ => map_sqlx_error(e),
             })?;
         Ok(())
     }
+
+    // todo_labels などは `ON DELETE CASCADE` によって一緒に削除される
+    async fn delete_all_completed_by_user(&mut self, user_id: &UserId) -> Result<Vec<TodoId>> {
+        let sql = r#"delete from todos where completed=true and assignee_id=$1 returning id"#;
+        let deleted_ids = sqlx::query_scalar::<_, Uuid>(sql)
+            .bind(user_id.value())
+            .fetch_all(&mut *self.conn)
+            .await
+            .map_err(map_sqlx_error)?;
+        deleted_ids
+            .into_iter()
+            .map(|id| TodoId::new(id).map_err(|e| TodoRepositoryError::Unexpected(e.to_string())))
+            .collect()
+    }
+
+    async fn set_completed_many(&mut self, todo_ids: &[TodoId], completed: bool) -> Result<u64> {
+        let sql = r#"
+update todos set completed=$1, updated_at=now(), version=version+1,
+    completed_at=case when $1 then now() end
+where id = any($2) and completed<>$1
+"#;
+        let ids: Vec<Uuid> = todo_ids.iter().map(|todo_id| *todo_id.value()).collect();
+        let result = sqlx::query(sql)
+            .bind(completed)
+            .bind(ids)
+            .execute(&mut *self.conn)
+            .await
+            .map_err(map_sqlx_error)?;
+        Ok(result.rows_affected())
+    }
 }
 
 #[cfg(test)]
@@ -312,9 +1043,16 @@ impl<'a> InternalTodoRepository<'a> {
 mod tests {
     use anyhow::Result;
 
+    use crate::test_helpers::macros::assert_todo_equal;
+
     use super::*;
     use crate::{
-        infra::repository_impl::pg::pg_label_repository::InternalLabelRepository, pg_pool,
+        domain::models::{
+            common::batch_result::BatchFindResult,
+            users::{user::User, user_name::UserName},
+        },
+        infra::repository_impl::pg::pg_label_repository::InternalLabelRepository,
+        pg_pool,
     };
 
     #[derive(FromRow)]
@@ -330,20 +1068,20 @@ mod tests {
         let mut tx = pool.begin().await?;
         let mut internal_label_repository = InternalLabelRepository::new(&mut tx);
 
-        let mut labels = HashSet::<Label>::new();
+        let mut labels = Vec::<Label>::new();
 
         // save labels for test
         let label_1 = Label::new(LabelName::new("label_1".to_string())?)?;
         internal_label_repository.save(&label_1).await?;
-        labels.insert(label_1);
+        labels.push(label_1);
 
         let label_2 = Label::new(LabelName::new("label_2".to_string())?)?;
         internal_label_repository.save(&label_2).await?;
-        labels.insert(label_2);
+        labels.push(label_2);
 
         let label_3 = Label::new(LabelName::new("label_3".to_string())?)?;
         internal_label_repository.save(&label_3).await?;
-        labels.insert(label_3);
+        labels.push(label_3);
 
         let mut internal_todo_repository = InternalTodoRepository::new(&mut tx);
 
@@ -361,26 +1099,64 @@ mod tests {
             .await
             .expect("failed to find todo.")
             .unwrap();
-        assert_eq!(expected, todo_found);
+        assert_todo_equal!(expected, todo_found);
         assert_eq!("todo text", todo_found.todo_text.value());
-        assert_eq!(expected.completed, todo_found.completed);
-        assert_eq!(expected.labels, todo_found.labels);
 
-        // find_all
+        // find_by_text_exact
         let expected = new_todo.clone();
-        let todos_found = internal_todo_repository.find_all().await?;
-        assert!(todos_found
-            .into_iter()
+        let todo_found = internal_todo_repository
+            .find_by_text_exact(&TodoText::new("todo text".to_string())?)
+            .await?
+            .unwrap();
+        assert_todo_equal!(expected, todo_found);
+        let todo_found = internal_todo_repository
+            .find_by_text_exact(&TodoText::new("todo tex".to_string())?)
+            .await?;
+        assert_eq!(None, todo_found);
+
+        // find_all
+        let expected = new_todo.clone();
+        let todos_found = internal_todo_repository.find_all().await?;
+        assert!(todos_found
+            .into_iter()
             .find(|todo| todo == &expected)
             .is_some());
 
+        // find_all_stream
+        let expected = new_todo.clone();
+        let todos_found = internal_todo_repository
+            .find_all_stream()
+            .collect::<Result<Vec<Todo>, TodoRepositoryError>>()
+            .await?;
+        let todo_found = todos_found
+            .into_iter()
+            .find(|todo| todo == &expected)
+            .unwrap();
+        assert_todo_equal!(expected, todo_found);
+
+        // save (reorder labels)
+        let mut reordered_todo = new_todo.clone();
+        reordered_todo.labels = vec![new_todo.labels[2].clone(), new_todo.labels[0].clone()];
+        reordered_todo.touch();
+        internal_todo_repository.save(&reordered_todo).await?;
+
+        let todo_found = internal_todo_repository
+            .find(new_todo_id)
+            .await
+            .expect("failed to find todo.")
+            .unwrap();
+        assert_todo_equal!(reordered_todo, todo_found);
+
         // save (update)
-        let mut updated_todo = new_todo.clone();
+        let mut updated_todo = reordered_todo.clone();
         let updated_text = TodoText::new("updated text".to_string())?;
-        let updated_labels = HashSet::new();
+        let updated_labels = vec![];
         updated_todo.todo_text = updated_text;
         updated_todo.completed = true;
+        updated_todo.note = Some(TodoNote::new("updated note".to_string())?);
+        updated_todo.due_date = NaiveDate::from_ymd_opt(2023, 10, 31);
         updated_todo.labels = updated_labels;
+        updated_todo.touch();
         internal_todo_repository.save(&updated_todo).await?;
 
         // find
@@ -390,10 +1166,16 @@ mod tests {
             .await
             .expect("failed to find todo.")
             .unwrap();
-        assert_eq!(expected, todo_found);
+        assert_todo_equal!(expected, todo_found);
         assert_eq!("updated text", todo_found.todo_text.value());
-        assert_eq!(true, todo_found.completed);
-        assert_eq!(HashSet::new(), todo_found.labels);
+        assert_eq!(new_todo.version() + 1, todo_found.version());
+        assert!(todo_found.completed);
+        assert_eq!(
+            Some("updated note"),
+            todo_found.note.as_ref().map(|note| note.value().as_str())
+        );
+        assert_eq!(NaiveDate::from_ymd_opt(2023, 10, 31), todo_found.due_date);
+        assert!(todo_found.labels.is_empty());
 
         // delete
         let todo_id = new_todo_id.clone();
@@ -416,4 +1198,688 @@ mod tests {
         tx.rollback().await?;
         Ok(())
     }
+
+    #[tokio::test]
+    async fn should_find_todos_by_label_filter() -> Result<()> {
+        let pool = pg_pool::connect_to_test_pg_pool().await;
+
+        let mut tx = pool.begin().await?;
+        let mut internal_label_repository = InternalLabelRepository::new(&mut tx);
+
+        let label_a = Label::new(LabelName::new("label-a".to_string())?)?;
+        let label_b = Label::new(LabelName::new("label-b".to_string())?)?;
+        internal_label_repository
+            .save_all(&[label_a.clone(), label_b.clone()])
+            .await?;
+
+        let mut internal_todo_repository = InternalTodoRepository::new(&mut tx);
+        let todo_1 = Todo::new(TodoText::new("todo-1".to_string())?, vec![label_a.clone()])?;
+        let todo_2 = Todo::new(
+            TodoText::new("todo-2".to_string())?,
+            vec![label_a.clone(), label_b.clone()],
+        )?;
+        internal_todo_repository.save(&todo_1).await?;
+        internal_todo_repository.save(&todo_2).await?;
+
+        let filter_of = |operator| LabelFilter {
+            ids: vec![label_a.label_id().clone(), label_b.label_id().clone()],
+            operator,
+        };
+
+        // A AND B
+        let todos_found = internal_todo_repository
+            .find_by_label_filter(&filter_of(FilterOperator::And))
+            .await?;
+        assert_eq!(vec![todo_2.clone()], todos_found);
+        // 条件に使わなかったラベルも含めて読み出す
+        assert_eq!(2, todos_found[0].labels.len());
+
+        // A OR B
+        let mut todos_found = internal_todo_repository
+            .find_by_label_filter(&filter_of(FilterOperator::Or))
+            .await?;
+        todos_found.sort_by(|a, b| a.todo_text.value().cmp(b.todo_text.value()));
+        assert_eq!(vec![todo_1, todo_2], todos_found);
+
+        tx.rollback().await?;
+        Ok(())
+    }
+
+    // ラベルの無い todo は left outer join で label_id が NULL の行になる
+    // その行が他の todo の行と混ざっていても、すべての todo を読み出せることを確かめる
+    #[tokio::test]
+    async fn should_find_todos_with_and_without_labels_together() -> Result<()> {
+        let pool = pg_pool::connect_to_test_pg_pool().await;
+
+        let mut tx = pool.begin().await?;
+        let mut internal_label_repository = InternalLabelRepository::new(&mut tx);
+
+        let label_a = Label::new(LabelName::new("label-a".to_string())?)?;
+        internal_label_repository
+            .save_all(std::slice::from_ref(&label_a))
+            .await?;
+
+        let mut internal_todo_repository = InternalTodoRepository::new(&mut tx);
+        let unlabeled_todo = Todo::new(TodoText::new("unlabeled".to_string())?, vec![])?;
+        let labeled_todo = Todo::new(TodoText::new("labeled".to_string())?, vec![label_a.clone()])?;
+        internal_todo_repository.save(&unlabeled_todo).await?;
+        internal_todo_repository.save(&labeled_todo).await?;
+
+        let mut todos_found = internal_todo_repository
+            .find_many_by_ids(&[
+                unlabeled_todo.todo_id().clone(),
+                labeled_todo.todo_id().clone(),
+            ])
+            .await?;
+        todos_found.sort_by(|a, b| b.todo_text.value().cmp(a.todo_text.value()));
+        assert_eq!(
+            vec![unlabeled_todo.clone(), labeled_todo.clone()],
+            todos_found
+        );
+        assert!(todos_found[0].labels.is_empty());
+        assert_eq!(vec![label_a], todos_found[1].labels);
+
+        // 一覧でも、ラベルの無い todo を読み飛ばさない
+        let todos_found = internal_todo_repository.find_all().await?;
+        for todo in [&unlabeled_todo, &labeled_todo] {
+            assert!(todos_found.contains(todo));
+        }
+
+        tx.rollback().await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_set_completed_of_given_todos() -> Result<()> {
+        let pool = pg_pool::connect_to_test_pg_pool().await;
+
+        let mut tx = pool.begin().await?;
+
+        // 対象の todo を 3 件 (うち 1 件は完了済み) と、対象外の todo を 1 件用意する
+        let mut internal_todo_repository = InternalTodoRepository::new(&mut tx);
+        let mut target_todos = Vec::<Todo>::new();
+        for (i, completed) in [false, false, true].into_iter().enumerate() {
+            let mut todo = Todo::new(TodoText::new(format!("target-{}", i))?, vec![])?;
+            todo.completed = completed;
+            internal_todo_repository.save(&todo).await?;
+            target_todos.push(todo);
+        }
+        let other_todo = Todo::new(TodoText::new("other".to_string())?, vec![])?;
+        internal_todo_repository.save(&other_todo).await?;
+
+        let todo_ids: Vec<TodoId> = target_todos
+            .iter()
+            .map(|todo| todo.todo_id().clone())
+            .collect();
+        let updated_count = internal_todo_repository
+            .set_completed_many(&todo_ids, true)
+            .await?;
+        assert_eq!(2, updated_count);
+
+        let todos_found = internal_todo_repository.find_many_by_ids(&todo_ids).await?;
+        for todo in target_todos {
+            let todo_found = todos_found.iter().find(|found| **found == todo).unwrap();
+            assert!(todo_found.completed);
+            // 変更した todo だけ版が進む
+            let expected_version = if todo.completed {
+                todo.version()
+            } else {
+                todo.version() + 1
+            };
+            assert_eq!(expected_version, todo_found.version());
+        }
+        let other_todo_found = internal_todo_repository
+            .find(other_todo.todo_id())
+            .await?
+            .unwrap();
+        assert!(!other_todo_found.completed);
+
+        tx.rollback().await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_keep_label_order_instead_of_sorting_by_name() -> Result<()> {
+        let pool = pg_pool::connect_to_test_pg_pool().await;
+
+        let mut tx = pool.begin().await?;
+        let mut internal_label_repository = InternalLabelRepository::new(&mut tx);
+
+        let mut labels = Vec::<Label>::new();
+        for name in ["label-z", "label-a", "label-m"] {
+            let label = Label::new(LabelName::new(name.to_string())?)?;
+            internal_label_repository.save(&label).await?;
+            labels.push(label);
+        }
+
+        let mut internal_todo_repository = InternalTodoRepository::new(&mut tx);
+        let todo = Todo::new(TodoText::new("ordered labels".to_string())?, labels)?;
+        internal_todo_repository.save(&todo).await?;
+
+        let label_names = |todo: &Todo| -> Vec<String> {
+            todo.labels
+                .iter()
+                .map(|label| label.label_name.value().clone())
+                .collect()
+        };
+
+        // find
+        let todo_found = internal_todo_repository
+            .find(todo.todo_id())
+            .await?
+            .unwrap();
+        assert_eq!(
+            vec!["label-z", "label-a", "label-m"],
+            label_names(&todo_found)
+        );
+
+        // find_all
+        let todos_found = internal_todo_repository.find_all().await?;
+        let todo_found = todos_found.iter().find(|found| found == &&todo).unwrap();
+        assert_eq!(
+            vec!["label-z", "label-a", "label-m"],
+            label_names(todo_found)
+        );
+
+        // find_all_stream
+        let todos_found = internal_todo_repository
+            .find_all_stream()
+            .collect::<Result<Vec<Todo>, TodoRepositoryError>>()
+            .await?;
+        let todo_found = todos_found.iter().find(|found| found == &&todo).unwrap();
+        assert_eq!(
+            vec!["label-z", "label-a", "label-m"],
+            label_names(todo_found)
+        );
+
+        tx.rollback().await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_record_when_label_was_attached() -> Result<()> {
+        let pool = pg_pool::connect_to_test_pg_pool().await;
+
+        let mut tx = pool.begin().await?;
+        let mut internal_label_repository = InternalLabelRepository::new(&mut tx);
+        let label = Label::new(LabelName::new("attached label".to_string())?)?;
+        internal_label_repository.save(&label).await?;
+
+        let mut internal_todo_repository = InternalTodoRepository::new(&mut tx);
+        let mut todo = Todo::new(TodoText::new("attached at".to_string())?, vec![label])?;
+        internal_todo_repository.save(&todo).await?;
+        tokio::time::sleep(std::time::Duration::from_millis(1)).await;
+
+        let todo_found = internal_todo_repository
+            .find(todo.todo_id())
+            .await?
+            .unwrap();
+        // `DEFAULT NOW()` はトランザクションの開始時刻になるため、直近であることだけを確認する
+        let attached_at = todo_found.labels[0].attached_at.unwrap();
+        let now = Utc::now();
+        assert!(attached_at <= now);
+        assert!(now - attached_at < chrono::Duration::minutes(1));
+
+        // 付いたままのラベルは、todo を保存し直しても日時が変わらない
+        todo.todo_text = TodoText::new("attached at (updated)".to_string())?;
+        todo.touch();
+        internal_todo_repository.save(&todo).await?;
+        let todo_found = internal_todo_repository
+            .find(todo.todo_id())
+            .await?
+            .unwrap();
+        assert_eq!(Some(attached_at), todo_found.labels[0].attached_at);
+
+        // ストリームで読み出した場合も日時を持つ
+        let todos_found = internal_todo_repository
+            .find_all_stream()
+            .collect::<Result<Vec<Todo>, TodoRepositoryError>>()
+            .await?;
+        let todo_found = todos_found.iter().find(|found| found == &&todo).unwrap();
+        assert_eq!(Some(attached_at), todo_found.labels[0].attached_at);
+
+        tx.rollback().await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_keep_completed_at_until_todo_is_reopened() -> Result<()> {
+        let pool = pg_pool::connect_to_test_pg_pool().await;
+
+        let mut tx = pool.begin().await?;
+        let mut internal_todo_repository = InternalTodoRepository::new(&mut tx);
+
+        let mut todo = Todo::new(TodoText::new("completed at".to_string())?, vec![])?;
+        internal_todo_repository.save(&todo).await?;
+        let todo_found = internal_todo_repository
+            .find(todo.todo_id())
+            .await?
+            .unwrap();
+        assert!(todo_found.completed_at.is_none());
+
+        todo.completed = true;
+        todo.touch();
+        internal_todo_repository.save(&todo).await?;
+        let completed_at = internal_todo_repository
+            .find(todo.todo_id())
+            .await?
+            .unwrap()
+            .completed_at;
+        assert!(completed_at.is_some());
+
+        // 完了後に編集しても、完了した日時は変わらない
+        todo.todo_text = TodoText::new("edited".to_string())?;
+        todo.touch();
+        internal_todo_repository.save(&todo).await?;
+        let todo_found = internal_todo_repository
+            .find(todo.todo_id())
+            .await?
+            .unwrap();
+        assert_eq!(completed_at, todo_found.completed_at);
+
+        todo.completed = false;
+        todo.touch();
+        internal_todo_repository.save(&todo).await?;
+        let todo_found = internal_todo_repository
+            .find(todo.todo_id())
+            .await?
+            .unwrap();
+        assert!(todo_found.completed_at.is_none());
+
+        tx.rollback().await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_find_todos_by_assignee_and_unassign_when_user_is_deleted() -> Result<()> {
+        let pool = pg_pool::connect_to_test_pg_pool().await;
+
+        let mut tx = pool.begin().await?;
+
+        // save user for test
+        let user = User::new(UserName::new("assignee".to_string())?)?;
+        sqlx::query(r#"insert into users (id, name) values ($1, $2)"#)
+            .bind(user.user_id().value())
+            .bind(user.user_name.value())
+            .execute(&mut *tx)
+            .await?;
+
+        let mut internal_todo_repository = InternalTodoRepository::new(&mut tx);
+
+        let mut assigned_todo = Todo::new(TodoText::new("assigned".to_string())?, vec![])?;
+        assigned_todo.assignee_id = Some(user.user_id().clone());
+        internal_todo_repository.save(&assigned_todo).await?;
+        let unassigned_todo = Todo::new(TodoText::new("unassigned".to_string())?, vec![])?;
+        internal_todo_repository.save(&unassigned_todo).await?;
+
+        // find_by_assignee
+        let todos_found = internal_todo_repository
+            .find_by_assignee(user.user_id())
+            .await?;
+        assert_eq!(vec![assigned_todo.clone()], todos_found);
+        assert_eq!(Some(user.user_id()), todos_found[0].assignee_id.as_ref());
+
+        // count_by_user_and_completion
+        let mut completed_todo = Todo::new(TodoText::new("completed".to_string())?, vec![])?;
+        completed_todo.assignee_id = Some(user.user_id().clone());
+        completed_todo.completed = true;
+        let collection_version = internal_todo_repository.collection_version().await?;
+        internal_todo_repository.save(&completed_todo).await?;
+
+        // collection_version
+        let saved_collection_version = internal_todo_repository.collection_version().await?;
+        assert_eq!(collection_version.count + 1, saved_collection_version.count);
+        assert_eq!(
+            collection_version.version_sum + 1,
+            saved_collection_version.version_sum
+        );
+
+        let counts = internal_todo_repository
+            .count_by_user_and_completion(user.user_id())
+            .await?;
+        assert_eq!((2, 1), counts);
+
+        // find_recently_completed_dates_by_user
+        let completed_dates = internal_todo_repository
+            .find_recently_completed_dates_by_user(user.user_id(), 365)
+            .await?;
+        assert_eq!(
+            vec![completed_todo.updated_at().date_naive()],
+            completed_dates
+        );
+
+        // delete_all_completed_by_user
+        let mut others_completed_todo =
+            Todo::new(TodoText::new("others completed".to_string())?, vec![])?;
+        others_completed_todo.completed = true;
+        internal_todo_repository
+            .save(&others_completed_todo)
+            .await?;
+        let deleted_ids = internal_todo_repository
+            .delete_all_completed_by_user(user.user_id())
+            .await?;
+        assert_eq!(vec![completed_todo.todo_id().clone()], deleted_ids);
+        assert_eq!(
+            None,
+            internal_todo_repository
+                .find(completed_todo.todo_id())
+                .await?
+        );
+        // 担当していない完了済みの todo は残る
+        assert!(internal_todo_repository
+            .find(others_completed_todo.todo_id())
+            .await?
+            .is_some());
+
+        // 担当者のユーザーを削除すると未割り当てに戻る
+        sqlx::query(r#"delete from users where id=$1"#)
+            .bind(user.user_id().value())
+            .execute(&mut *tx)
+            .await?;
+        let mut internal_todo_repository = InternalTodoRepository::new(&mut tx);
+        let todo_found = internal_todo_repository
+            .find(assigned_todo.todo_id())
+            .await?
+            .unwrap();
+        assert_eq!(None, todo_found.assignee_id);
+
+        tx.rollback().await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_find_incomplete_todos_due_within_days() -> Result<()> {
+        let pool = pg_pool::connect_to_test_pg_pool().await;
+
+        let mut tx = pool.begin().await?;
+        let mut internal_todo_repository = InternalTodoRepository::new(&mut tx);
+
+        let today = Utc::now().date_naive();
+        let due_in = |text: &str, days: i64| -> Result<Todo> {
+            let mut todo = Todo::new(TodoText::new(text.to_string())?, vec![])?;
+            todo.due_date = Some(today + chrono::Duration::days(days));
+            Ok(todo)
+        };
+        let due_in_3_days = due_in("due in 3 days", 3)?;
+        let due_in_8_days = due_in("due in 8 days", 8)?;
+        let overdue = due_in("overdue", -1)?;
+        let mut completed = due_in("completed", 3)?;
+        completed.completed = true;
+        for todo in [&due_in_3_days, &due_in_8_days, &overdue, &completed] {
+            internal_todo_repository.save(todo).await?;
+        }
+
+        // 他のテストが残した todo が含まれていても判定できるよう、作成したものだけを見る
+        let todos_found = internal_todo_repository.find_due_within(7).await?;
+        assert!(todos_found.contains(&due_in_3_days));
+        for todo in [&due_in_8_days, &overdue, &completed] {
+            assert!(!todos_found.contains(todo));
+        }
+
+        tx.rollback().await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_find_many_todos_by_ids() -> Result<()> {
+        let pool = pg_pool::connect_to_test_pg_pool().await;
+
+        let mut tx = pool.begin().await?;
+        let mut internal_todo_repository = InternalTodoRepository::new(&mut tx);
+
+        let mut todo_ids = vec![];
+        for i in 0..3 {
+            let todo = Todo::new(TodoText::new(format!("find many {}", i))?, vec![])?;
+            internal_todo_repository.save(&todo).await?;
+            todo_ids.push(todo.todo_id().clone());
+        }
+        let missing_ids = vec![TodoId::new(Uuid::new_v4())?, TodoId::new(Uuid::new_v4())?];
+        todo_ids.extend(missing_ids.clone());
+
+        let todos_found = internal_todo_repository.find_many_by_ids(&todo_ids).await?;
+        let result = BatchFindResult::new(&todo_ids, todos_found);
+        assert_eq!(3, result.found.len());
+        assert!(result
+            .found
+            .iter()
+            .all(|todo| todo_ids[..3].contains(todo.todo_id())));
+        assert_eq!(missing_ids, result.not_found);
+
+        tx.rollback().await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_find_similar_todos_by_trigram() -> Result<()> {
+        let pool = pg_pool::connect_to_test_pg_pool().await;
+
+        let mut tx = pool.begin().await?;
+        let mut internal_todo_repository = InternalTodoRepository::new(&mut tx);
+
+        let mut todos = vec![];
+        for text in ["Buy groceries", "Buy grocery items", "Walk the dog"] {
+            let todo = Todo::new(TodoText::new(text.to_string())?, vec![])?;
+            internal_todo_repository.save(&todo).await?;
+            todos.push(todo);
+        }
+
+        // trigram の類似度は編集距離によるものより低く出るため、閾値を下げて確かめる
+        // 他のテストが残した todo が含まれていても判定できるよう、作成したものだけを見る
+        let todos_found = internal_todo_repository
+            .find_similar_to(todos[0].todo_id(), 0.4, 100)
+            .await?;
+        let (todo_found, similarity) = todos_found
+            .iter()
+            .find(|(todo, _)| todo == &todos[1])
+            .unwrap();
+        assert_eq!(&todos[1], todo_found);
+        assert!((0.4..1.0).contains(similarity));
+        for todo in [&todos[0], &todos[2]] {
+            assert!(!todos_found.iter().any(|(todo_found, _)| todo_found == todo));
+        }
+        // 類似度の高い順に並ぶ
+        assert!(todos_found.windows(2).all(|pair| pair[0].1 >= pair[1].1));
+
+        tx.rollback().await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_end_stream_without_panic_when_cancelled() -> Result<()> {
+        let pool = pg_pool::connect_to_test_pg_pool().await;
+        let todo_repository = PgTodoRepository::new(pool.clone());
+
+        let cancellation_token = CancellationToken::new();
+        let mut todos_found = todo_repository.find_all_stream(cancellation_token.clone());
+        cancellation_token.cancel();
+        // 受け取り済みの分を除き、残りは読み出されずに終わる
+        while let Some(todo_found) = todos_found.next().await {
+            todo_found?;
+        }
+
+        // クエリを取り消した後も、プールの接続は使える
+        sqlx::query("select 1").execute(&pool).await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_map_query_exceeding_statement_timeout_to_timeout_error() -> Result<()> {
+        let pool = pg_pool::connect_to_test_pg_pool().await;
+        let mut tx = pool.begin().await?;
+
+        let (statement_timeout,): (String,) = sqlx::query_as("show statement_timeout")
+            .fetch_one(&mut *tx)
+            .await?;
+        assert_eq!(pg_pool::STATEMENT_TIMEOUT, statement_timeout);
+
+        // statement_timeout を超える 10 秒間の待機は途中でキャンセルされる
+        let result = sqlx::query("select pg_sleep(10)")
+            .execute(&mut *tx)
+            .await
+            .map_err(map_sqlx_error);
+        assert!(matches!(result, Err(TodoRepositoryError::Timeout)));
+
+        tx.rollback().await?;
+        Ok(())
+    }
+
+    #[test]
+    fn should_write_timestamps_in_microseconds_in_copy_row() -> Result<()> {
+        let timestamp =
+            DateTime::parse_from_rfc3339("2023-10-20T12:34:56.123456789Z")?.with_timezone(&Utc);
+        let todo = Todo::build(
+            TodoId::new(Uuid::new_v4())?,
+            TodoText::new("todo".to_string())?,
+            None,
+            None,
+            None,
+            false,
+            vec![],
+            None,
+            timestamp,
+            timestamp,
+            1,
+        );
+
+        let row = copy_row(&todo);
+
+        let fields: Vec<&str> = row.trim_end().split('\t').collect();
+        assert_eq!("2023-10-20T12:34:56.123456Z", fields[3]);
+        assert_eq!("2023-10-20T12:34:56.123456Z", fields[4]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_copy_todos_with_escaped_text_and_save_labeled_todos() -> Result<()> {
+        let pool = pg_pool::connect_to_test_pg_pool().await;
+        let mut tx = pool.begin().await?;
+
+        let label = Label::new(LabelName::new("copy_label".to_string())?)?;
+        InternalLabelRepository::new(&mut tx).save(&label).await?;
+
+        let mut todo_with_note =
+            Todo::new(TodoText::new("tab\tand\nnewline \\".to_string())?, vec![])?;
+        todo_with_note.note = Some(TodoNote::new("note".to_string())?);
+        todo_with_note.due_date = NaiveDate::from_ymd_opt(2023, 10, 31);
+        let todo_with_label = Todo::new(TodoText::new("labeled".to_string())?, vec![label])?;
+
+        let mut internal_todo_repository = InternalTodoRepository::new(&mut tx);
+        internal_todo_repository
+            .copy_in_bulk(&[todo_with_note.clone(), todo_with_label.clone()])
+            .await?;
+
+        let todo_found = internal_todo_repository
+            .find(todo_with_note.todo_id())
+            .await?
+            .unwrap();
+        assert_eq!("tab\tand\nnewline \\", todo_found.todo_text.value());
+        assert_todo_equal!(todo_with_note, todo_found);
+
+        let todo_found = internal_todo_repository
+            .find(todo_with_label.todo_id())
+            .await?
+            .unwrap();
+        assert_todo_equal!(todo_with_label, todo_found);
+
+        tx.rollback().await?;
+        Ok(())
+    }
+
+    // 10,000 件を save の繰り返しと copy_in_bulk で取り込み、それぞれの時間を表示する
+    // 同じトランザクション内で比べているため、save ごとにコミットする実際の取り込みより差は小さく出る
+    // 実行時間に依存するため、`cargo test --features database-test -- --ignored` で明示的に実行する
+    #[tokio::test]
+    #[ignore]
+    async fn compare_copy_in_bulk_with_saving_one_by_one() -> Result<()> {
+        const TODO_COUNT: usize = 10_000;
+
+        let pool = pg_pool::connect_to_test_pg_pool().await;
+        let mut tx = pool.begin().await?;
+        let mut internal_todo_repository = InternalTodoRepository::new(&mut tx);
+
+        let new_todos = |prefix: &str| -> Result<Vec<Todo>> {
+            (0..TODO_COUNT)
+                .map(|i| Todo::new(TodoText::new(format!("{} {}", prefix, i))?, vec![]))
+                .collect()
+        };
+
+        let todos = new_todos("saved todo")?;
+        let started_at = std::time::Instant::now();
+        for todo in &todos {
+            internal_todo_repository.save(todo).await?;
+        }
+        let save_elapsed = started_at.elapsed();
+
+        let todos = new_todos("copied todo")?;
+        let started_at = std::time::Instant::now();
+        internal_todo_repository.copy_in_bulk(&todos).await?;
+        let copy_elapsed = started_at.elapsed();
+
+        let copied_count = internal_todo_repository
+            .find_all()
+            .await?
+            .iter()
+            .filter(|todo| todo.todo_text.value().starts_with("copied todo "))
+            .count();
+        assert_eq!(TODO_COUNT, copied_count);
+        println!(
+            "save: {:?}, copy_in_bulk: {:?}, ratio: {:.1}",
+            save_elapsed,
+            copy_elapsed,
+            save_elapsed.as_secs_f64() / copy_elapsed.as_secs_f64()
+        );
+
+        tx.rollback().await?;
+        Ok(())
+    }
+
+    // 行の構造体の各フィールドがスキーマ上の列と型に一致することを、コンパイル時に照合する
+    // オフラインモードでは `.sqlx` に保存したクエリの情報を使う
+    #[tokio::test]
+    async fn row_structs_should_type_check_against_schema() -> Result<()> {
+        let pool = pg_pool::connect_to_test_pg_pool().await;
+        let mut tx = pool.begin().await?;
+
+        sqlx::query_as!(
+            TodoRow,
+            r#"
+            select todos.*, labels.id as "label_id?", labels.name as "label_name?",
+                tl.attached_at as "label_attached_at?"
+            from todos
+                left outer join todo_labels tl on todos.id = tl.todo_id
+                left outer join labels on labels.id = tl.label_id
+            order by todos.id desc, tl.order_index"#
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+        sqlx::query_as!(
+            TodoWithLabelsRow,
+            r#"
+            select todos.*,
+                array_agg(labels.id order by tl.order_index) filter (where labels.id is not null) as label_ids,
+                array_agg(labels.name order by tl.order_index) filter (where labels.id is not null) as label_names,
+                array_agg(tl.attached_at order by tl.order_index) filter (where labels.id is not null) as label_attached_ats
+            from todos
+                left outer join todo_labels tl on todos.id = tl.todo_id
+                left outer join labels on labels.id = tl.label_id
+            group by todos.id
+            order by todos.id desc"#
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+        sqlx::query_as!(
+            LabelRow,
+            r#"
+            select tl.label_id, l.name as label_name, tl.attached_at as "attached_at?"
+            from todo_labels as tl inner join labels as l
+            on tl.label_id=l.id
+            where tl.todo_id=$1"#,
+            Uuid::new_v4()
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        tx.rollback().await?;
+        Ok(())
+    }
 }
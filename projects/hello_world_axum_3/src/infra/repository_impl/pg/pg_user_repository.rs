@@ -1,21 +1,30 @@
+use std::{collections::HashMap, panic::AssertUnwindSafe};
+
 use axum::async_trait;
+use chrono::{DateTime, Utc};
+use futures_util::FutureExt;
 use sqlx::{pool::PoolConnection, FromRow, PgConnection, PgPool, Postgres};
 use uuid::Uuid;
 
+use super::transaction;
 use crate::domain::{
     models::users::{
         user::User,
         user_id::UserId,
         user_name::UserName,
         user_repository::{IUserRepository, Result, UserRepositoryError},
+        user_role::UserRole,
     },
     value_object::ValueObject,
 };
 
 #[derive(FromRow)]
+#[sqlx(rename_all = "snake_case")]
 struct UserFromRow {
     id: Uuid,
     name: String,
+    role: String,
+    deleted_at: Option<DateTime<Utc>>,
 }
 
 impl UserFromRow {
@@ -24,7 +33,9 @@ impl UserFromRow {
             UserId::new(self.id).map_err(|e| UserRepositoryError::Unexpected(e.to_string()))?;
         let user_name =
             UserName::new(self.name).map_err(|e| UserRepositoryError::Unexpected(e.to_string()))?;
-        Ok(User::build(user_id, user_name))
+        let user_role = UserRole::parse(self.role)
+            .map_err(|e| UserRepositoryError::Unexpected(e.to_string()))?;
+        Ok(User::build(user_id, user_name, user_role).with_deleted_at(self.deleted_at))
     }
 }
 
@@ -44,6 +55,15 @@ impl PgUserRepository {
             .await
             .map_err(|e| UserRepositoryError::Unexpected(e.to_string()))
     }
+
+    async fn start_tx(&self) -> Result<sqlx::Transaction<'_, Postgres>> {
+        let tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| UserRepositoryError::Unexpected(e.to_string()))?;
+        Ok(tx)
+    }
 }
 
 #[async_trait]
@@ -60,6 +80,12 @@ impl IUserRepository for PgUserRepository {
         internal_user_repository.find(user_id).await
     }
 
+    async fn exists(&self, user_id: &UserId) -> Result<bool> {
+        let mut conn = self.connection().await?;
+        let mut internal_user_repository = InternalUserRepository::new(&mut conn);
+        internal_user_repository.exists(user_id).await
+    }
+
     async fn find_by_name(&self, user_name: &UserName) -> Result<Option<User>> {
         let mut conn = self.connection().await?;
         let mut internal_user_repository = InternalUserRepository::new(&mut conn);
@@ -72,11 +98,49 @@ impl IUserRepository for PgUserRepository {
         internal_user_repository.find_all().await
     }
 
+    async fn find_all_by_role(&self, user_role: &UserRole) -> Result<Vec<User>> {
+        let mut conn = self.connection().await?;
+        let mut internal_user_repository = InternalUserRepository::new(&mut conn);
+        internal_user_repository.find_all_by_role(user_role).await
+    }
+
+    async fn count_by_role(&self) -> Result<HashMap<UserRole, u64>> {
+        let mut conn = self.connection().await?;
+        let mut internal_user_repository = InternalUserRepository::new(&mut conn);
+        internal_user_repository.count_by_role().await
+    }
+
+    async fn search_by_name(&self, query: &str, limit: u32) -> Result<Vec<User>> {
+        let mut conn = self.connection().await?;
+        let mut internal_user_repository = InternalUserRepository::new(&mut conn);
+        internal_user_repository.search_by_name(query, limit).await
+    }
+
     async fn delete(&self, user: User) -> Result<()> {
         let mut conn = self.connection().await?;
         let mut internal_user_repository = InternalUserRepository::new(&mut conn);
         internal_user_repository.delete(user).await
     }
+
+    async fn soft_delete(&self, user: &User) -> Result<()> {
+        let mut tx = self.start_tx().await?;
+        let result = AssertUnwindSafe(async {
+            let mut internal_user_repository = InternalUserRepository::new(&mut tx);
+            internal_user_repository.soft_delete(user).await
+        })
+        .catch_unwind()
+        .await;
+        transaction::finish_tx(tx, result, |e| {
+            UserRepositoryError::Unexpected(e.to_string())
+        })
+        .await
+    }
+
+    async fn hard_delete(&self, user_id: &UserId) -> Result<()> {
+        let mut conn = self.connection().await?;
+        let mut internal_user_repository = InternalUserRepository::new(&mut conn);
+        internal_user_repository.hard_delete(user_id).await
+    }
 }
 
 struct InternalUserRepository<'a> {
@@ -90,14 +154,15 @@ impl<'a> InternalUserRepository<'a> {
 
     async fn save(&mut self, user: &User) -> Result<()> {
         let sql = r#"
-insert into users (id, name)
-values ($1, $2)
+insert into users (id, name, role)
+values ($1, $2, $3)
 on conflict (id)
-do update set name=$2
+do update set name=$2, role=$3
 "#;
         sqlx::query(sql)
             .bind(user.user_id().value())
             .bind(user.user_name.value())
+            .bind(user.user_role.as_str())
             .execute(&mut *self.conn)
             .await
             .map_err(|e| UserRepositoryError::Unexpected(e.to_string()))?;
@@ -105,7 +170,7 @@ do update set name=$2
     }
 
     async fn find(&mut self, user_id: &UserId) -> Result<Option<User>> {
-        let sql = r#"select * from users where id=$1"#;
+        let sql = r#"select * from users where id=$1 and deleted_at is null"#;
         let user_from_row = sqlx::query_as::<_, UserFromRow>(sql)
             .bind(user_id.value())
             .fetch_optional(&mut *self.conn)
@@ -115,8 +180,18 @@ do update set name=$2
         Ok(user)
     }
 
+    async fn exists(&mut self, user_id: &UserId) -> Result<bool> {
+        let sql = r#"select exists(select 1 from users where id=$1 and deleted_at is null)"#;
+        let exists = sqlx::query_scalar::<_, bool>(sql)
+            .bind(user_id.value())
+            .fetch_one(&mut *self.conn)
+            .await
+            .map_err(|e| UserRepositoryError::Unexpected(e.to_string()))?;
+        Ok(exists)
+    }
+
     async fn find_by_name(&mut self, user_name: &UserName) -> Result<Option<User>> {
-        let sql = r#"select * from users where name=$1"#;
+        let sql = r#"select * from users where name=$1 and deleted_at is null"#;
         let user_from_row = sqlx::query_as::<_, UserFromRow>(sql)
             .bind(user_name.value())
             .fetch_optional(&mut *self.conn)
@@ -127,7 +202,7 @@ do update set name=$2
     }
 
     async fn find_all(&mut self) -> Result<Vec<User>> {
-        let sql = r#"select * from users order by id desc"#;
+        let sql = r#"select * from users where deleted_at is null order by id desc"#;
         let users_from_rows = sqlx::query_as::<_, UserFromRow>(sql)
             .fetch_all(&mut *self.conn)
             .await
@@ -139,6 +214,56 @@ do update set name=$2
         Ok(users)
     }
 
+    async fn find_all_by_role(&mut self, user_role: &UserRole) -> Result<Vec<User>> {
+        let sql = r#"select * from users where role=$1 and deleted_at is null order by id desc"#;
+        let users_from_rows = sqlx::query_as::<_, UserFromRow>(sql)
+            .bind(user_role.as_str())
+            .fetch_all(&mut *self.conn)
+            .await
+            .map_err(|e| UserRepositoryError::Unexpected(e.to_string()))?;
+        let users = users_from_rows
+            .into_iter()
+            .map(|row| row.into_user())
+            .collect::<Result<Vec<User>>>()?;
+        Ok(users)
+    }
+
+    async fn count_by_role(&mut self) -> Result<HashMap<UserRole, u64>> {
+        let sql = r#"select role, count(*) from users where deleted_at is null group by role"#;
+        let rows = sqlx::query_as::<_, (String, i64)>(sql)
+            .fetch_all(&mut *self.conn)
+            .await
+            .map_err(|e| UserRepositoryError::Unexpected(e.to_string()))?;
+        rows.into_iter()
+            .map(|(role, count)| {
+                let user_role = role
+                    .parse::<UserRole>()
+                    .map_err(|e| UserRepositoryError::Unexpected(e.to_string()))?;
+                Ok((user_role, count as u64))
+            })
+            .collect()
+    }
+
+    async fn search_by_name(&mut self, query: &str, limit: u32) -> Result<Vec<User>> {
+        let sql = r#"
+select * from users
+where lower(name) like lower($1) || '%' and deleted_at is null
+order by name
+limit $2
+"#;
+        let users_from_rows = sqlx::query_as::<_, UserFromRow>(sql)
+            .bind(escape_like_pattern(query))
+            .bind(i64::from(limit))
+            .fetch_all(&mut *self.conn)
+            .await
+            .map_err(|e| UserRepositoryError::Unexpected(e.to_string()))?;
+        let users = users_from_rows
+            .into_iter()
+            .map(|row| row.into_user())
+            .collect::<Result<Vec<User>>>()?;
+        Ok(users)
+    }
+
     async fn delete(&mut self, user: User) -> Result<()> {
         let id = user.user_id();
         let sql = r#"delete from users where id=$1"#;
@@ -152,6 +277,67 @@ do update set name=$2
             })?;
         Ok(())
     }
+
+    async fn soft_delete(&mut self, user: &User) -> Result<()> {
+        let user_id = user.user_id();
+        let sql = r#"update users set name=$2, deleted_at=$3 where id=$1 and deleted_at is null"#;
+        let updated = sqlx::query(sql)
+            .bind(user_id.value())
+            .bind(user.user_name.value())
+            .bind(user.deleted_at())
+            .execute(&mut *self.conn)
+            .await
+            .map_err(|e| UserRepositoryError::Unexpected(e.to_string()))?;
+        if updated.rows_affected() == 0 {
+            return Err(UserRepositoryError::NotFound(user_id.clone()));
+        }
+
+        // todo は残したまま担当者だけを外し、変更されたことが分かるようバージョンを上げる
+        let sql = r#"
+update todos
+set assignee_id = null, version = version + 1, updated_at = now()
+where assignee_id = $1
+"#;
+        sqlx::query(sql)
+            .bind(user_id.value())
+            .execute(&mut *self.conn)
+            .await
+            .map_err(|e| UserRepositoryError::Unexpected(e.to_string()))?;
+
+        // 論理削除したユーザーとしてログインできないよう、パスワードとセッションは物理削除する
+        for sql in [
+            r#"delete from credentials where user_id=$1"#,
+            r#"delete from sessions where user_id=$1"#,
+        ] {
+            sqlx::query(sql)
+                .bind(user_id.value())
+                .execute(&mut *self.conn)
+                .await
+                .map_err(|e| UserRepositoryError::Unexpected(e.to_string()))?;
+        }
+        Ok(())
+    }
+
+    async fn hard_delete(&mut self, user_id: &UserId) -> Result<()> {
+        let sql = r#"delete from users where id=$1"#;
+        let deleted = sqlx::query(sql)
+            .bind(user_id.value())
+            .execute(&mut *self.conn)
+            .await
+            .map_err(|e| UserRepositoryError::Unexpected(e.to_string()))?;
+        if deleted.rows_affected() == 0 {
+            return Err(UserRepositoryError::NotFound(user_id.clone()));
+        }
+        Ok(())
+    }
+}
+
+// `like` の特殊文字を文字どおりに一致させる (postgres の既定のエスケープ文字は `\`)
+fn escape_like_pattern(query: &str) -> String {
+    query
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_")
 }
 
 #[cfg(test)]
@@ -159,6 +345,8 @@ do update set name=$2
 mod tests {
     use anyhow::Result;
 
+    use crate::test_helpers::macros::assert_user_equal;
+
     use super::*;
     use crate::pg_pool;
 
@@ -183,9 +371,12 @@ mod tests {
             .await
             .expect("failed to find user.")
             .unwrap();
-        assert_eq!(expected, user_found);
+        assert_user_equal!(expected, user_found);
         assert_eq!("user name", user_found.user_name.value());
 
+        // exists
+        assert!(internal_todo_repository.exists(new_user_id).await?);
+
         // find_all
         let expected = new_user.clone();
         let users_found = internal_todo_repository.find_all().await?;
@@ -198,6 +389,7 @@ mod tests {
         let mut updated_user = new_user.clone();
         let updated_name = UserName::new("updated name".to_string())?;
         updated_user.user_name = updated_name;
+        updated_user.user_role = UserRole::Admin;
         internal_todo_repository.save(&updated_user).await?;
 
         // find
@@ -207,8 +399,19 @@ mod tests {
             .await
             .expect("failed to find user.")
             .unwrap();
-        assert_eq!(expected, user_found);
+        assert_user_equal!(expected, user_found);
         assert_eq!("updated name", user_found.user_name.value());
+        assert_eq!(UserRole::Admin, user_found.user_role);
+
+        // find_all_by_role
+        let users_found = internal_todo_repository
+            .find_all_by_role(&UserRole::Admin)
+            .await?;
+        assert!(users_found.iter().any(|user| user == &expected));
+        let users_found = internal_todo_repository
+            .find_all_by_role(&UserRole::Member)
+            .await?;
+        assert!(!users_found.iter().any(|user| user == &expected));
 
         // delete
         let user_id = new_user_id.clone();
@@ -221,6 +424,152 @@ mod tests {
         let user_found = internal_todo_repository.find(&user_id).await?;
         assert_eq!(user_found, None);
 
+        // exists
+        assert!(!internal_todo_repository.exists(&user_id).await?);
+
+        tx.rollback().await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_search_users_by_name_prefix() -> Result<()> {
+        let pool = pg_pool::connect_to_test_pg_pool().await;
+
+        let mut tx = pool.begin().await?;
+        let mut internal_user_repository = InternalUserRepository::new(&mut tx);
+
+        for name in ["srch_Alice", "srch_Alison", "srch_Bob", "srchXAlan"] {
+            let user = User::new(UserName::new(name.to_string())?)?;
+            internal_user_repository.save(&user).await?;
+        }
+
+        // 大文字小文字を区別せず、`_` はワイルドカードとして扱わない
+        let users_found = internal_user_repository
+            .search_by_name("SRCH_al", 10)
+            .await?;
+        let names: Vec<&str> = users_found
+            .iter()
+            .map(|user| user.user_name.value().as_str())
+            .collect();
+        assert_eq!(vec!["srch_Alice", "srch_Alison"], names);
+
+        let users_found = internal_user_repository
+            .search_by_name("srch_al", 1)
+            .await?;
+        assert_eq!(1, users_found.len());
+
+        tx.rollback().await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_count_users_by_role() -> Result<()> {
+        let pool = pg_pool::connect_to_test_pg_pool().await;
+
+        let mut tx = pool.begin().await?;
+        let mut internal_user_repository = InternalUserRepository::new(&mut tx);
+
+        // 既存のデータがあっても比較できるよう、追加前との差分を見る
+        let before = internal_user_repository.count_by_role().await?;
+        for (i, user_role) in [UserRole::Admin, UserRole::Admin, UserRole::Member]
+            .into_iter()
+            .enumerate()
+        {
+            let mut user = User::new(UserName::new(format!("count_role_{}", i))?)?;
+            user.user_role = user_role;
+            internal_user_repository.save(&user).await?;
+        }
+        let after = internal_user_repository.count_by_role().await?;
+
+        let added = |user_role: UserRole| {
+            after.get(&user_role).copied().unwrap_or(0)
+                - before.get(&user_role).copied().unwrap_or(0)
+        };
+        assert_eq!(2, added(UserRole::Admin));
+        assert_eq!(1, added(UserRole::Member));
+        assert_eq!(0, added(UserRole::Viewer));
+
+        tx.rollback().await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_soft_delete_user_and_unassign_todos() -> Result<()> {
+        let pool = pg_pool::connect_to_test_pg_pool().await;
+
+        let mut tx = pool.begin().await?;
+        let mut internal_user_repository = InternalUserRepository::new(&mut tx);
+
+        let mut user = User::new(UserName::new("soft_delete".to_string())?)?;
+        let user_id = user.user_id().clone();
+        internal_user_repository.save(&user).await?;
+        let todo_id = Uuid::new_v4();
+        sqlx::query(
+            "insert into todos (id, text, completed, assignee_id) values ($1, $2, false, $3)",
+        )
+        .bind(todo_id)
+        .bind("assigned to soft deleted user")
+        .bind(user_id.value())
+        .execute(&mut *internal_user_repository.conn)
+        .await?;
+
+        user.anonymize(Utc::now());
+        internal_user_repository.soft_delete(&user).await?;
+
+        // 論理削除したユーザーは読み出されない
+        assert_eq!(None, internal_user_repository.find(&user_id).await?);
+        assert!(!internal_user_repository.exists(&user_id).await?);
+        let users_found = internal_user_repository.find_all().await?;
+        assert!(!users_found.iter().any(|user_found| user_found == &user));
+        // もう一度論理削除しようとしても見つからない
+        assert!(matches!(
+            internal_user_repository.soft_delete(&user).await,
+            Err(UserRepositoryError::NotFound(_))
+        ));
+
+        // 行は匿名化した名前で残り、todo は担当者が外れた状態で残る
+        let (name, deleted): (String, bool) =
+            sqlx::query_as("select name, deleted_at is not null from users where id=$1")
+                .bind(user_id.value())
+                .fetch_one(&mut *internal_user_repository.conn)
+                .await?;
+        assert_eq!(user.user_name.value(), &name);
+        assert!(name.starts_with("deleted_user_"));
+        assert!(deleted);
+        let (assignee_id, version): (Option<Uuid>, i64) =
+            sqlx::query_as("select assignee_id, version from todos where id=$1")
+                .bind(todo_id)
+                .fetch_one(&mut *internal_user_repository.conn)
+                .await?;
+        assert_eq!(None, assignee_id);
+        assert_eq!(2, version);
+
+        // hard_delete は論理削除したユーザーも物理削除する
+        internal_user_repository.hard_delete(&user_id).await?;
+        let count: i64 = sqlx::query_scalar("select count(*) from users where id=$1")
+            .bind(user_id.value())
+            .fetch_one(&mut *internal_user_repository.conn)
+            .await?;
+        assert_eq!(0, count);
+        assert!(matches!(
+            internal_user_repository.hard_delete(&user_id).await,
+            Err(UserRepositoryError::NotFound(_))
+        ));
+
+        tx.rollback().await?;
+        Ok(())
+    }
+
+    // 行の構造体の各フィールドがスキーマ上の列と型に一致することを、コンパイル時に照合する
+    #[tokio::test]
+    async fn row_struct_should_type_check_against_schema() -> Result<()> {
+        let pool = pg_pool::connect_to_test_pg_pool().await;
+        let mut tx = pool.begin().await?;
+
+        sqlx::query_as!(UserFromRow, r#"select * from users order by id desc"#)
+            .fetch_all(&mut *tx)
+            .await?;
+
         tx.rollback().await?;
         Ok(())
     }
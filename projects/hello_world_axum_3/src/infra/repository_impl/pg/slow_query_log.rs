@@ -0,0 +1,212 @@
+use std::{env, ops::DerefMut, sync::OnceLock, time::Duration};
+
+use sqlx::{
+    postgres::{PgArguments, PgRow},
+    FromRow, PgConnection,
+};
+
+// 設定すると、この時間 (ミリ秒) を超えたクエリの実行計画をログに出力する
+const SLOW_QUERY_THRESHOLD_MS: &str = "SLOW_QUERY_THRESHOLD_MS";
+
+// 遅いクエリを検出したときに `EXPLAIN ANALYZE` の結果を出力する
+// 開発時の調査用なので、リリースビルドでは常に無効になる
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(super) struct SlowQueryLogger {
+    threshold: Option<Duration>,
+}
+
+impl SlowQueryLogger {
+    pub(super) fn new(threshold: Option<Duration>) -> Self {
+        Self { threshold }
+    }
+
+    // 環境変数はクエリのたびに読まず、最初に読んだ値を使い回す
+    pub(super) fn from_env() -> Self {
+        static LOGGER: OnceLock<SlowQueryLogger> = OnceLock::new();
+        *LOGGER.get_or_init(|| Self::from_lookup(|key| env::var(key).ok()))
+    }
+
+    fn from_lookup(lookup: impl Fn(&str) -> Option<String>) -> Self {
+        if cfg!(not(debug_assertions)) {
+            return Self::default();
+        }
+        let threshold = lookup(SLOW_QUERY_THRESHOLD_MS)
+            .and_then(|threshold_ms| threshold_ms.parse::<u64>().ok())
+            .map(Duration::from_millis);
+        Self::new(threshold)
+    }
+
+    pub(super) fn wrap<C>(self, conn: C) -> PgConnectionWrapper<C>
+    where
+        C: DerefMut<Target = PgConnection>,
+    {
+        PgConnectionWrapper { conn, logger: self }
+    }
+}
+
+// コネクションへのクエリの発行を仲介し、遅いクエリの実行計画をログに出力する
+// `EXPLAIN ANALYZE` はもう一度同じクエリを実行するため、同じ引数を作り直せるよう関数で受け取る
+pub(super) struct PgConnectionWrapper<C> {
+    conn: C,
+    // リリースビルドでは参照しない
+    #[cfg_attr(not(debug_assertions), allow(dead_code))]
+    logger: SlowQueryLogger,
+}
+
+impl<C> PgConnectionWrapper<C>
+where
+    C: DerefMut<Target = PgConnection>,
+{
+    pub(super) async fn fetch_all<T>(
+        &mut self,
+        sql: &str,
+        arguments: impl Fn() -> PgArguments,
+    ) -> Result<Vec<T>, sqlx::Error>
+    where
+        T: for<'r> FromRow<'r, PgRow> + Send + Unpin,
+    {
+        #[cfg(debug_assertions)]
+        if let Some(threshold) = self.logger.threshold {
+            let started_at = std::time::Instant::now();
+            let result = sqlx::query_as_with(sql, arguments())
+                .fetch_all(&mut *self.conn)
+                .await;
+            let elapsed = started_at.elapsed();
+            if result.is_ok() && elapsed >= threshold {
+                self.log_query_plan(sql, arguments(), elapsed).await;
+            }
+            return result;
+        }
+
+        sqlx::query_as_with(sql, arguments())
+            .fetch_all(&mut *self.conn)
+            .await
+    }
+
+    // 実行計画を求めるために書き込みが二重に行われないよう、セーブポイントの中で実行してロールバックする
+    #[cfg(debug_assertions)]
+    async fn log_query_plan(&mut self, sql: &str, arguments: PgArguments, elapsed: Duration) {
+        let explain_sql = format!("EXPLAIN ANALYZE {}", sql);
+        let query_plan = async {
+            let mut tx = sqlx::Connection::begin(&mut *self.conn).await?;
+            let query_plan = sqlx::query_scalar_with::<_, String, _>(&explain_sql, arguments)
+                .fetch_all(&mut *tx)
+                .await;
+            tx.rollback().await?;
+            query_plan
+        }
+        .await;
+
+        match query_plan {
+            Ok(query_plan) => tracing::warn!(
+                "slow query took {} ms: {}\n{}",
+                elapsed.as_millis(),
+                explain_sql.trim(),
+                query_plan.join("\n")
+            ),
+            Err(e) => tracing::warn!(
+                "slow query took {} ms, but failed to run {}: [{}]",
+                elapsed.as_millis(),
+                explain_sql.trim(),
+                e
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::Write,
+        sync::{Arc, Mutex},
+    };
+
+    use anyhow::Result;
+    use sqlx::Arguments;
+    use tracing_subscriber::fmt::MakeWriter;
+
+    use super::*;
+
+    #[test]
+    fn should_read_threshold_from_env() {
+        let logger = SlowQueryLogger::from_lookup(|key| match key {
+            SLOW_QUERY_THRESHOLD_MS => Some("250".to_string()),
+            _ => None,
+        });
+        assert_eq!(
+            SlowQueryLogger::new(Some(Duration::from_millis(250))),
+            logger
+        );
+
+        // 未指定や数値でない場合は無効になる
+        assert_eq!(
+            SlowQueryLogger::default(),
+            SlowQueryLogger::from_lookup(|_| None)
+        );
+        assert_eq!(
+            SlowQueryLogger::default(),
+            SlowQueryLogger::from_lookup(|_| Some("slow".to_string()))
+        );
+    }
+
+    #[derive(Clone, Default)]
+    struct LogBuffer(Arc<Mutex<Vec<u8>>>);
+
+    impl LogBuffer {
+        fn contents(&self) -> String {
+            String::from_utf8(self.0.lock().unwrap().clone()).unwrap()
+        }
+    }
+
+    impl Write for LogBuffer {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl<'a> MakeWriter<'a> for LogBuffer {
+        type Writer = Self;
+
+        fn make_writer(&'a self) -> Self::Writer {
+            self.clone()
+        }
+    }
+
+    #[cfg(feature = "database-test")]
+    #[tokio::test]
+    async fn should_log_query_plan_of_slow_query() -> Result<()> {
+        use crate::pg_pool;
+
+        let log_buffer = LogBuffer::default();
+        let subscriber = tracing_subscriber::fmt()
+            .with_ansi(false)
+            .with_writer(log_buffer.clone())
+            .finish();
+        let _guard = tracing::subscriber::set_default(subscriber);
+
+        let pool = pg_pool::connect_to_test_pg_pool().await;
+        let mut tx = pool.begin().await?;
+
+        // 0 ms を閾値にすると、すべてのクエリが遅いものとして扱われる
+        let mut conn = SlowQueryLogger::new(Some(Duration::ZERO)).wrap(&mut *tx);
+        let rows: Vec<(i32,)> = conn
+            .fetch_all("select $1::int4", || {
+                let mut arguments = PgArguments::default();
+                arguments.add(1_i32);
+                arguments
+            })
+            .await?;
+        assert_eq!(vec![(1,)], rows);
+
+        let log = log_buffer.contents();
+        assert!(log.contains("EXPLAIN ANALYZE select $1::int4"));
+        assert!(log.contains("actual time="));
+
+        tx.rollback().await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,77 @@
+use std::thread;
+
+use sqlx::{Postgres, Transaction};
+
+// `catch_unwind` で実行したトランザクション内の処理の結果に応じて、トランザクションを終了する
+// 処理がパニックした場合は、コネクションを不正な状態のままプールに返さないよう
+// 明示的にロールバックしてからパニックを再開する
+pub(super) async fn finish_tx<T, E>(
+    tx: Transaction<'_, Postgres>,
+    result: thread::Result<Result<T, E>>,
+    into_error: impl FnOnce(sqlx::Error) -> E,
+) -> Result<T, E> {
+    match result {
+        Ok(Ok(value)) => {
+            tx.commit().await.map_err(into_error)?;
+            Ok(value)
+        }
+        // エラーの場合は drop 時にロールバックされる
+        Ok(Err(e)) => Err(e),
+        Err(panic) => {
+            if let Err(e) = tx.rollback().await {
+                tracing::error!("failed to rollback transaction after panic: [{}]", e);
+            }
+            std::panic::resume_unwind(panic)
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "database-test")]
+mod tests {
+    use std::panic::AssertUnwindSafe;
+
+    use anyhow::Result;
+    use futures_util::FutureExt;
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::pg_pool;
+
+    #[tokio::test]
+    async fn should_rollback_transaction_when_body_panics() -> Result<()> {
+        let pool = pg_pool::connect_to_test_pg_pool().await;
+        let todo_id = Uuid::new_v4();
+
+        let mut tx = pool.begin().await?;
+        let result = AssertUnwindSafe(async {
+            // 1 つ目のクエリは成功する
+            sqlx::query("insert into todos (id, text, completed) values ($1, $2, false)")
+                .bind(todo_id)
+                .bind("should be rolled back")
+                .execute(&mut *tx)
+                .await?;
+            // 2 つ目のクエリの実行中にパニックしたものとする
+            panic!("panic in the second query");
+            #[allow(unreachable_code)]
+            Ok::<(), sqlx::Error>(())
+        })
+        .catch_unwind()
+        .await;
+        assert!(result.is_err());
+
+        // パニックが呼び出し元まで伝播すること
+        let finished = AssertUnwindSafe(finish_tx(tx, result, |e| e))
+            .catch_unwind()
+            .await;
+        assert!(finished.is_err());
+
+        // 途中までの書き込みがコミットされていないこと
+        let count: i64 = sqlx::query_scalar("select count(*) from todos where id=$1")
+            .bind(todo_id)
+            .fetch_one(&pool)
+            .await?;
+        assert_eq!(0, count);
+        Ok(())
+    }
+}
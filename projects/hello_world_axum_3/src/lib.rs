@@ -1,6 +1,10 @@
-pub mod log;
-pub mod domain;
-pub mod router;
+pub mod app_config;
 pub mod application;
+pub mod domain;
+pub mod feature_flags;
 pub mod infra;
-pub mod pg_pool;
\ No newline at end of file
+pub mod log;
+pub mod pg_pool;
+pub mod router;
+#[cfg(test)]
+mod test_helpers;
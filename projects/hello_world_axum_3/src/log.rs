@@ -4,4 +4,4 @@ pub fn init_log() {
     let log_level = env::var("RUST_LOG").unwrap_or("info".to_string());
     env::set_var("RUST_LOG", log_level);
     tracing_subscriber::fmt::init();
-}
\ No newline at end of file
+}
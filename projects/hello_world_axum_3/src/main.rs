@@ -1,27 +1,57 @@
 use std::net::SocketAddr;
 
 use anyhow::Result;
+use tracing::Instrument;
 
 use hello_world_axum_3::{
+    app_config::AppConfig,
+    feature_flags::FeatureFlags,
     infra::repository_impl::pg::{
-        pg_label_repository::PgLabelRepository, pg_todo_repository::PgTodoRepository,
-        pg_user_repository::PgUserRepository,
+        pg_audit_log_repository::PgAuditLogRepository,
+        pg_credential_repository::PgCredentialRepository,
+        pg_invitation_repository::PgInvitationRepository, pg_label_repository::PgLabelRepository,
+        pg_session_repository::PgSessionRepository,
+        pg_todo_dependency_repository::PgTodoDependencyRepository,
+        pg_todo_repository::PgTodoRepository, pg_user_repository::PgUserRepository,
     },
     log::init_log,
     pg_pool,
     router::{create_app, ArgCreateApp},
 };
 
+// CI では DB を起動しないため、SQLX_OFFLINE=true (.cargo/config.toml で設定済み) のまま
+// .sqlx に保存したクエリの情報でビルド・テストする
 #[tokio::main]
 async fn main() -> Result<()> {
     init_log();
 
-    let pool = pg_pool::connect_to_pg_pool().await;
-    let app = create_app(ArgCreateApp::<
-        PgLabelRepository,
-        PgTodoRepository,
-        PgUserRepository,
-    >::new(pool));
+    // 起動時のログにもインスタンスの ID を含めるため、span の中で起動する
+    run(AppConfig::from_env())
+        .instrument(tracing::info_span!(
+            "app",
+            service.instance = tracing::field::Empty
+        ))
+        .await
+}
+
+async fn run(app_config: AppConfig) -> Result<()> {
+    tracing::Span::current().record("service.instance", app_config.telemetry_id.as_str());
+
+    let pool = pg_pool::connect_to_pg_pool().await?;
+    let app = create_app(
+        ArgCreateApp::<
+            PgLabelRepository,
+            PgTodoRepository,
+            PgUserRepository,
+            PgTodoDependencyRepository,
+            PgCredentialRepository,
+            PgSessionRepository,
+            PgInvitationRepository,
+            PgAuditLogRepository,
+        >::new(pool)
+        .app_config(app_config)
+        .feature_flags(FeatureFlags::from_env()),
+    );
 
     let addr = SocketAddr::from(([127, 0, 0, 1], 3000));
 
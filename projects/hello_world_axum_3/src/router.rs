@@ -1,114 +1,431 @@
+mod api_docs;
+mod authentication;
+mod authorization;
+mod extractors;
+mod feature_flag_guard;
+mod health_handlers;
+mod invitation_handlers;
 mod label_handlers;
+mod locale;
+mod messages;
+mod options_response;
+mod pagination;
+mod problem_details;
+mod request_body_log_layer;
+mod request_id_layer;
+mod resource_location;
 mod root_handlers;
+mod session_handlers;
+mod todo_collection_etag;
+mod todo_dependency_handlers;
+mod todo_event_handlers;
 mod todo_handlers;
+mod trailing_slash_layer;
 mod user_handlers;
 
 use std::sync::Arc;
 
-use axum::{http::HeaderValue, routing::get, Extension, Router};
-use hyper::header::CONTENT_TYPE;
+use axum::{
+    http::HeaderValue,
+    middleware,
+    routing::{delete, get, patch, post},
+    Extension, Router,
+};
+use hyper::header::{ACCEPT_LANGUAGE, AUTHORIZATION, CONTENT_TYPE};
 use sqlx::PgPool;
 use tower_http::cors::{Any, CorsLayer};
 
-#[cfg(test)]
+#[cfg(any(test, feature = "in-memory-repository"))]
+use crate::infra::health_probe::InMemoryHealthProbe;
+#[cfg(any(test, feature = "in-memory-repository"))]
 use crate::infra::repository_impl::in_memory::{
+    audit_logs::in_memory_audit_log_repository::InMemoryAuditLogRepository,
+    credentials::in_memory_credential_repository::InMemoryCredentialRepository,
+    invitations::in_memory_invitation_repository::InMemoryInvitationRepository,
     labels::in_memory_label_repository::InMemoryLabelRepository,
+    sessions::in_memory_session_repository::InMemorySessionRepository,
+    todo_dependencies::in_memory_todo_dependency_repository::InMemoryTodoDependencyRepository,
+    todo_links::in_memory_todo_link_repository::InMemoryTodoLinkRepository,
     todos::in_memory_todo_repository::InMemoryTodoRepository,
     users::in_memory_user_repository::InMemoryUserRepository,
 };
 
 use crate::{
+    app_config::AppConfig,
     application::{
+        invitations::{
+            invitation_accept_application_service::InvitationAcceptApplicationService,
+            invitation_create_application_service::InvitationCreateApplicationService,
+            invitation_decline_application_service::InvitationDeclineApplicationService,
+        },
         labels::{
+            label_bulk_create_application_service::LabelBulkCreateApplicationService,
+            label_bulk_delete_application_service::LabelBulkDeleteApplicationService,
             label_create_application_service::LabelCreateApplicationService,
             label_delete_application_service::LabelDeleteApplicationService,
             label_get_all_aplication_service::LabelGetAllApplicationService,
             label_get_application_service::LabelGetApplicationService,
+            label_get_orphaned_application_service::LabelGetOrphanedApplicationService,
+            label_get_recently_used_application_service::LabelGetRecentlyUsedApplicationService,
             label_update_application_service::LabelUpdateApplicationService,
         },
+        sessions::{
+            session_authenticate_application_service::SessionAuthenticateApplicationService,
+            session_login_application_service::SessionLoginApplicationService,
+            session_logout_application_service::SessionLogoutApplicationService,
+        },
+        todo_dependencies::{
+            todo_dependency_add_application_service::TodoDependencyAddApplicationService,
+            todo_dependency_get_all_application_service::TodoDependencyGetAllApplicationService,
+            todo_dependency_remove_application_service::TodoDependencyRemoveApplicationService,
+        },
         todos::{
+            todo_bulk_complete_by_label_application_service::TodoBulkCompleteByLabelApplicationService,
+            todo_bulk_create_application_service::TodoBulkCreateApplicationService,
+            todo_bulk_get_application_service::TodoBulkGetApplicationService,
+            todo_clear_completed_application_service::TodoClearCompletedApplicationService,
             todo_create_application_service::TodoCreateApplicationService,
             todo_delete_application_service::TodoDeleteApplicationService,
+            todo_export_application_service::TodoExportApplicationService,
+            todo_filter_by_label_application_service::TodoFilterByLabelApplicationService,
             todo_get_all_aplication_service::TodoGetAllApplicationService,
             todo_get_application_service::TodoGetApplicationService,
+            todo_get_assigned_application_service::TodoGetAssignedApplicationService,
+            todo_get_audit_application_service::TodoGetAuditApplicationService,
+            todo_get_due_soon_application_service::TodoGetDueSoonApplicationService,
+            todo_get_similar_application_service::TodoGetSimilarApplicationService,
+            todo_ical_export_application_service::TodoIcalExportApplicationService,
+            todo_search_application_service::TodoSearchApplicationService,
             todo_update_application_service::TodoUpdateApplicationService,
         },
         users::{
+            user_authenticate_application_service::UserAuthenticateApplicationService,
             user_create_application_service::UserCreateApplicationService,
             user_delete_application_service::UserDeleteApplicationService,
             user_get_all_aplication_service::UserGetAllApplicationService,
             user_get_application_service::UserGetApplicationService,
+            user_get_by_role_application_service::UserGetByRoleApplicationService,
+            user_label_application_service::UserLabelApplicationService,
+            user_password_change_application_service::UserPasswordChangeApplicationService,
+            user_role_distribution_application_service::UserRoleDistributionApplicationService,
+            user_search_application_service::UserSearchApplicationService,
+            user_self_update_application_service::UserSelfUpdateApplicationService,
             user_update_application_service::UserUpdateApplicationService,
         },
     },
-    domain::models::{
-        labels::label_repository::ILabelRepository, todos::todo_repository::ITodoRepository,
-        users::user_repository::IUserRepository,
+    domain::{
+        events::event_bus::EventBus,
+        models::{
+            audit_logs::audit_log_repository::IAuditLogRepository,
+            credentials::credential_repository::ICredentialRepository,
+            invitations::invitation_repository::IInvitationRepository,
+            labels::label_repository::ILabelRepository,
+            sessions::session_repository::ISessionRepository,
+            todo_dependencies::todo_dependency_repository::ITodoDependencyRepository,
+            todos::todo_repository::ITodoRepository,
+            users::{user_repository::IUserRepository, user_role::UserRole},
+        },
+        services::profanity_filter::ProfanityFilter,
     },
-    infra::repository_impl::pg::{
-        pg_label_repository::PgLabelRepository, pg_todo_repository::PgTodoRepository,
-        pg_user_repository::PgUserRepository,
+    feature_flags::{Feature, FeatureFlags},
+    infra::{
+        health_probe::{HealthProbe, PgHealthProbe},
+        repository_impl::pg::{
+            pg_audit_log_repository::PgAuditLogRepository,
+            pg_credential_repository::PgCredentialRepository,
+            pg_invitation_repository::PgInvitationRepository,
+            pg_label_repository::PgLabelRepository, pg_session_repository::PgSessionRepository,
+            pg_todo_dependency_repository::PgTodoDependencyRepository,
+            pg_todo_repository::PgTodoRepository, pg_user_repository::PgUserRepository,
+        },
     },
 };
 
-pub struct ArgCreateApp<LabelRep, TodoRep, UserRep>
-where
+use self::{
+    authentication::SessionCache, request_body_log_layer::RequestBodyLogLayer,
+    request_id_layer::RequestIdLayer, trailing_slash_layer::TrailingSlashLayer,
+};
+
+pub struct ArgCreateApp<
+    LabelRep,
+    TodoRep,
+    UserRep,
+    TodoDependencyRep,
+    CredentialRep,
+    SessionRep,
+    InvitationRep,
+    AuditLogRep,
+> where
     LabelRep: ILabelRepository,
     UserRep: IUserRepository,
     TodoRep: ITodoRepository,
+    TodoDependencyRep: ITodoDependencyRepository,
+    CredentialRep: ICredentialRepository,
+    SessionRep: ISessionRepository,
+    InvitationRep: IInvitationRepository,
+    AuditLogRep: IAuditLogRepository,
 {
     label_repository: LabelRep,
     todo_repository: TodoRep,
     user_repository: UserRep,
+    todo_dependency_repository: TodoDependencyRep,
+    credential_repository: CredentialRep,
+    session_repository: SessionRep,
+    invitation_repository: InvitationRep,
+    audit_log_repository: AuditLogRep,
+    event_bus: EventBus,
+    app_config: AppConfig,
+    feature_flags: FeatureFlags,
+    health_probe: Arc<dyn HealthProbe>,
 }
 
-#[cfg(test)]
-impl ArgCreateApp<InMemoryLabelRepository, InMemoryTodoRepository, InMemoryUserRepository> {
+impl<
+        LabelRep,
+        TodoRep,
+        UserRep,
+        TodoDependencyRep,
+        CredentialRep,
+        SessionRep,
+        InvitationRep,
+        AuditLogRep,
+    >
+    ArgCreateApp<
+        LabelRep,
+        TodoRep,
+        UserRep,
+        TodoDependencyRep,
+        CredentialRep,
+        SessionRep,
+        InvitationRep,
+        AuditLogRep,
+    >
+where
+    LabelRep: ILabelRepository,
+    UserRep: IUserRepository,
+    TodoRep: ITodoRepository,
+    TodoDependencyRep: ITodoDependencyRepository,
+    CredentialRep: ICredentialRepository,
+    SessionRep: ISessionRepository,
+    InvitationRep: IInvitationRepository,
+    AuditLogRep: IAuditLogRepository,
+{
+    // 環境変数から読み込んだ設定を渡す
+    pub fn app_config(mut self, app_config: AppConfig) -> Self {
+        self.app_config = app_config;
+        self
+    }
+
+    // 機能ごとの有効・無効を設定する
+    pub fn feature_flags(mut self, feature_flags: FeatureFlags) -> Self {
+        self.feature_flags = feature_flags;
+        self
+    }
+
+    // `create_app` に渡す前に、テスト用のユーザーなどを登録するために使う
+    pub fn user_repository(&self) -> &UserRep {
+        &self.user_repository
+    }
+
+    pub fn credential_repository(&self) -> &CredentialRep {
+        &self.credential_repository
+    }
+}
+
+#[cfg(any(test, feature = "in-memory-repository"))]
+impl Default
+    for ArgCreateApp<
+        InMemoryLabelRepository,
+        InMemoryTodoRepository,
+        InMemoryUserRepository,
+        InMemoryTodoDependencyRepository,
+        InMemoryCredentialRepository,
+        InMemorySessionRepository,
+        InMemoryInvitationRepository,
+        InMemoryAuditLogRepository,
+    >
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(any(test, feature = "in-memory-repository"))]
+impl
+    ArgCreateApp<
+        InMemoryLabelRepository,
+        InMemoryTodoRepository,
+        InMemoryUserRepository,
+        InMemoryTodoDependencyRepository,
+        InMemoryCredentialRepository,
+        InMemorySessionRepository,
+        InMemoryInvitationRepository,
+        InMemoryAuditLogRepository,
+    >
+{
     pub fn new() -> Self {
-        let label_repository = InMemoryLabelRepository::new();
-        let todo_repository = InMemoryTodoRepository::new();
-        let user_repository = InMemoryUserRepository::new();
+        let todo_dependency_repository = InMemoryTodoDependencyRepository::new();
+        let todo_repository = InMemoryTodoRepository::with_related_repositories(
+            todo_dependency_repository.clone(),
+            InMemoryTodoLinkRepository::new(),
+        );
+        let label_repository =
+            InMemoryLabelRepository::with_todo_repository(todo_repository.clone());
+        let user_repository = InMemoryUserRepository::with_todo_repository(todo_repository.clone());
+        let credential_repository = InMemoryCredentialRepository::new();
+        let session_repository = InMemorySessionRepository::new();
+        let invitation_repository = InMemoryInvitationRepository::new();
+        let audit_log_repository = InMemoryAuditLogRepository::new();
         Self {
             label_repository,
             todo_repository,
             user_repository,
+            todo_dependency_repository,
+            credential_repository,
+            session_repository,
+            invitation_repository,
+            audit_log_repository,
+            event_bus: EventBus::new(),
+            app_config: AppConfig::default(),
+            feature_flags: FeatureFlags::default(),
+            health_probe: Arc::new(InMemoryHealthProbe),
         }
     }
 }
 
-impl ArgCreateApp<PgLabelRepository, PgTodoRepository, PgUserRepository> {
+impl
+    ArgCreateApp<
+        PgLabelRepository,
+        PgTodoRepository,
+        PgUserRepository,
+        PgTodoDependencyRepository,
+        PgCredentialRepository,
+        PgSessionRepository,
+        PgInvitationRepository,
+        PgAuditLogRepository,
+    >
+{
     pub fn new(pg_pool: PgPool) -> Self {
         let label_repository = PgLabelRepository::new(pg_pool.clone());
         let todo_repository = PgTodoRepository::new(pg_pool.clone());
-        let user_repository = PgUserRepository::new(pg_pool);
+        let user_repository = PgUserRepository::new(pg_pool.clone());
+        let todo_dependency_repository = PgTodoDependencyRepository::new(pg_pool.clone());
+        let credential_repository = PgCredentialRepository::new(pg_pool.clone());
+        let session_repository = PgSessionRepository::new(pg_pool.clone());
+        let invitation_repository = PgInvitationRepository::new(pg_pool.clone());
+        let audit_log_repository = PgAuditLogRepository::new(pg_pool.clone());
         Self {
             label_repository,
             todo_repository,
             user_repository,
+            todo_dependency_repository,
+            credential_repository,
+            session_repository,
+            invitation_repository,
+            audit_log_repository,
+            event_bus: EventBus::new(),
+            app_config: AppConfig::default(),
+            feature_flags: FeatureFlags::default(),
+            health_probe: Arc::new(PgHealthProbe::new(pg_pool)),
         }
     }
 }
 
-pub fn create_app<LabelRep, TodoRep, UserRep>(
+pub fn create_app<
+    LabelRep,
+    TodoRep,
+    UserRep,
+    TodoDependencyRep,
+    CredentialRep,
+    SessionRep,
+    InvitationRep,
+    AuditLogRep,
+>(
     ArgCreateApp {
         label_repository,
         todo_repository,
         user_repository,
-    }: ArgCreateApp<LabelRep, TodoRep, UserRep>,
+        todo_dependency_repository,
+        credential_repository,
+        session_repository,
+        invitation_repository,
+        audit_log_repository,
+        event_bus,
+        app_config,
+        feature_flags,
+        health_probe,
+    }: ArgCreateApp<
+        LabelRep,
+        TodoRep,
+        UserRep,
+        TodoDependencyRep,
+        CredentialRep,
+        SessionRep,
+        InvitationRep,
+        AuditLogRep,
+    >,
 ) -> Router
 where
     LabelRep: ILabelRepository,
     UserRep: IUserRepository,
     TodoRep: ITodoRepository,
+    TodoDependencyRep: ITodoDependencyRepository,
+    CredentialRep: ICredentialRepository,
+    SessionRep: ISessionRepository,
+    InvitationRep: IInvitationRepository,
+    AuditLogRep: IAuditLogRepository,
 {
-    Router::new()
+    // ロケールファイルの誤りに起動時に気づけるよう、ここで読み込んでおく
+    messages::Messages::get();
+
+    let router = Router::new()
         .route("/", get(root_handlers::index))
+        .route("/health", get(health_handlers::health))
+        // API docs
+        .route("/api-docs/openapi.json", get(api_docs::openapi_json))
+        .route("/api-docs/swagger-ui", get(api_docs::swagger_ui))
         // labels
         .route(
             "/labels",
             get(label_handlers::get_all::<LabelRep, LabelGetAllApplicationService<LabelRep>>)
                 .post(label_handlers::create::<LabelRep, LabelCreateApplicationService<LabelRep>>),
         )
+        .route(
+            "/labels/bulk",
+            post(
+                label_handlers::bulk_create::<
+                    LabelRep,
+                    LabelBulkCreateApplicationService<LabelRep>,
+                >,
+            )
+            .delete(
+                label_handlers::bulk_delete::<
+                    LabelRep,
+                    LabelBulkDeleteApplicationService<LabelRep>,
+                >,
+            )
+            .route_layer(middleware::from_fn_with_state(
+                Feature::BulkOperations,
+                feature_flag_guard::feature_flag_guard,
+            )),
+        )
+        .route(
+            "/labels/orphaned",
+            get(
+                label_handlers::get_orphaned::<
+                    LabelRep,
+                    LabelGetOrphanedApplicationService<LabelRep>,
+                >,
+            ),
+        )
+        .route(
+            "/labels/recently-used",
+            get(
+                label_handlers::get_recently_used::<
+                    LabelRep,
+                    LabelGetRecentlyUsedApplicationService<LabelRep>,
+                >,
+            ),
+        )
         .route(
             "/labels/:id",
             get(label_handlers::get::<LabelRep, LabelGetApplicationService<LabelRep>>)
@@ -121,46 +438,3222 @@ where
         // todos
         .route(
             "/todos",
-            get(todo_handlers::get_all::<TodoRep, TodoGetAllApplicationService<TodoRep>>).post(
-                todo_handlers::create::<
+            get(
+                todo_handlers::get_all::<
                     TodoRep,
                     LabelRep,
-                    TodoCreateApplicationService<TodoRep, LabelRep>,
+                    UserRep,
+                    TodoGetAllApplicationService<TodoRep, UserRep>,
+                    TodoSearchApplicationService<TodoRep, UserRep>,
+                    TodoFilterByLabelApplicationService<TodoRep, LabelRep, UserRep>,
                 >,
-            ),
-        )
-        .route(
-            "/todos/:id",
-            get(todo_handlers::get::<TodoRep, TodoGetApplicationService<TodoRep>>)
-                .patch(
-                    todo_handlers::update::<
+            )
+            .route_layer(middleware::from_fn_with_state(
+                Feature::Search,
+                feature_flag_guard::feature_flag_guard,
+            ))
+            // 監査ログに操作したユーザーを残すため、書き込みでは任意で認証する
+            .merge(
+                post(
+                    todo_handlers::create::<
                         TodoRep,
                         LabelRep,
-                        TodoUpdateApplicationService<TodoRep, LabelRep>,
+                        UserRep,
+                        AuditLogRep,
+                        TodoCreateApplicationService<TodoRep, LabelRep, UserRep, AuditLogRep>,
                     >,
                 )
-                .delete(todo_handlers::delete::<TodoRep, TodoDeleteApplicationService<TodoRep>>),
+                .route_layer(middleware::from_fn(
+                authentication::authenticate_if_present::<
+                    CredentialRep,
+                    SessionRep,
+                    UserAuthenticateApplicationService<CredentialRep>,
+                    SessionAuthenticateApplicationService<SessionRep>,
+                    _,
+                >,
+            )),
+            ),
         )
-        .layer(Extension(Arc::new(todo_repository)))
+        .route(
+            "/todos/bulk",
+            post(
+                todo_handlers::bulk_create::<
+                    TodoRep,
+                    LabelRep,
+                    UserRep,
+                    AuditLogRep,
+                    TodoBulkCreateApplicationService<TodoRep, LabelRep, UserRep, AuditLogRep>,
+                >,
+            )
+            .route_layer(middleware::from_fn(
+                authentication::authenticate_if_present::<
+                    CredentialRep,
+                    SessionRep,
+                    UserAuthenticateApplicationService<CredentialRep>,
+                    SessionAuthenticateApplicationService<SessionRep>,
+                    _,
+                >,
+            ))
+            .get(todo_handlers::bulk_get::<TodoRep, TodoBulkGetApplicationService<TodoRep>>)
+            .route_layer(middleware::from_fn_with_state(
+                Feature::BulkOperations,
+                feature_flag_guard::feature_flag_guard,
+            )),
+        )
+        .route(
+            "/todos/events",
+            get(todo_event_handlers::stream).route_layer(middleware::from_fn_with_state(
+                Feature::Sse,
+                feature_flag_guard::feature_flag_guard,
+            )),
+        )
+        .route(
+            "/todos/due-soon",
+            get(todo_handlers::get_due_soon::<TodoRep, TodoGetDueSoonApplicationService<TodoRep>>),
+        )
+        .route(
+            "/todos/export",
+            get(todo_handlers::export::<TodoRep, TodoExportApplicationService<TodoRep>>)
+                .route_layer(middleware::from_fn_with_state(
+                    Feature::Export,
+                    feature_flag_guard::feature_flag_guard,
+                )),
+        )
+        .route(
+            "/todos/calendar.ics",
+            get(todo_handlers::export_ical::<TodoRep, TodoIcalExportApplicationService<TodoRep>>)
+                .route_layer(middleware::from_fn_with_state(
+                    Feature::Export,
+                    feature_flag_guard::feature_flag_guard,
+                )),
+        )
+        .route(
+            "/todos/:id",
+            get(
+                todo_handlers::get::<TodoRep, TodoGetApplicationService<TodoRep>>,
+            )
+                // 監査ログに操作したユーザーを残すため、書き込みでは任意で認証する
+                .merge(
+                    patch(
+                        todo_handlers::update::<
+                            TodoRep,
+                            LabelRep,
+                            TodoDependencyRep,
+                            UserRep,
+                            AuditLogRep,
+                            TodoUpdateApplicationService<
+                                TodoRep,
+                                LabelRep,
+                                TodoDependencyRep,
+                                UserRep,
+                                AuditLogRep,
+                            >,
+                        >,
+                    )
+                    .delete(
+                        todo_handlers::delete::<
+                            TodoRep,
+                            AuditLogRep,
+                            TodoDeleteApplicationService<TodoRep, AuditLogRep>,
+                        >,
+                    )
+                    .route_layer(middleware::from_fn(
+                authentication::authenticate_if_present::<
+                    CredentialRep,
+                    SessionRep,
+                    UserAuthenticateApplicationService<CredentialRep>,
+                    SessionAuthenticateApplicationService<SessionRep>,
+                    _,
+                >,
+            )),
+                ),
+        )
+        .route(
+            "/todos/:id/audit",
+            get(
+                todo_handlers::get_audit::<
+                    AuditLogRep,
+                    UserRep,
+                    TodoGetAuditApplicationService<AuditLogRep, UserRep>,
+                >,
+            ),
+        )
+        .route(
+            "/todos/:id/similar",
+            get(todo_handlers::get_similar::<TodoRep, TodoGetSimilarApplicationService<TodoRep>>),
+        )
+        // todo dependencies
+        .route(
+            "/todos/:id/dependencies",
+            get(todo_dependency_handlers::get_all::<
+                TodoRep,
+                TodoDependencyRep,
+                TodoDependencyGetAllApplicationService<TodoRep, TodoDependencyRep>,
+            >)
+            .post(
+                todo_dependency_handlers::add::<
+                    TodoRep,
+                    TodoDependencyRep,
+                    TodoDependencyAddApplicationService<TodoRep, TodoDependencyRep>,
+                >,
+            ),
+        )
+        .route(
+            "/todos/:id/dependencies/:dependency_id",
+            delete(
+                todo_dependency_handlers::remove::<
+                    TodoDependencyRep,
+                    TodoDependencyRemoveApplicationService<TodoDependencyRep>,
+                >,
+            ),
+        )
+        // todo のリポジトリを使うため、users ではなく todos と一緒に登録する
+        .route(
+            "/users/:id/assigned",
+            get(
+                todo_handlers::get_assigned::<
+                    TodoRep,
+                    UserRep,
+                    TodoGetAssignedApplicationService<TodoRep, UserRep>,
+                >,
+            ),
+        )
+        .route(
+            "/users/:id/todos/completed",
+            delete(
+                todo_handlers::clear_completed::<
+                    TodoRep,
+                    UserRep,
+                    TodoClearCompletedApplicationService<TodoRep, UserRep>,
+                >,
+            )
+            .route_layer(middleware::from_fn(
+                authentication::require_authentication::<
+                    CredentialRep,
+                    SessionRep,
+                    UserAuthenticateApplicationService<CredentialRep>,
+                    SessionAuthenticateApplicationService<SessionRep>,
+                    _,
+                >,
+            )),
+        )
+        // todo のリポジトリを使うため、labels ではなく todos と一緒に登録する
+        .route(
+            "/labels/:id/todos/complete",
+            patch(
+                todo_handlers::bulk_complete_by_label::<
+                    TodoRep,
+                    LabelRep,
+                    TodoDependencyRep,
+                    TodoBulkCompleteByLabelApplicationService<TodoRep, LabelRep, TodoDependencyRep>,
+                >,
+            ),
+        )
+        // ラベルのリポジトリを使うため、users ではなく todos と一緒に登録する
+        .route(
+            "/users/:id/labels",
+            get(
+                user_handlers::get_labels::<
+                    UserRep,
+                    LabelRep,
+                    UserLabelApplicationService<UserRep, LabelRep>,
+                >,
+            ),
+        )
+        // invitations
+        // 招待の作成時に発行するイベントを配信するため、todos と一緒に登録する
+        .route(
+            "/lists/:id/invitations",
+            post(
+                invitation_handlers::create::<
+                    InvitationRep,
+                    UserRep,
+                    InvitationCreateApplicationService<InvitationRep, UserRep>,
+                >,
+            )
+            .route_layer(middleware::from_fn(
+                authentication::require_authentication::<
+                    CredentialRep,
+                    SessionRep,
+                    UserAuthenticateApplicationService<CredentialRep>,
+                    SessionAuthenticateApplicationService<SessionRep>,
+                    _,
+                >,
+            )),
+        )
+        .route(
+            "/invitations/:invite_id/accept",
+            post(
+                invitation_handlers::accept::<
+                    InvitationRep,
+                    InvitationAcceptApplicationService<InvitationRep>,
+                >,
+            )
+            .route_layer(middleware::from_fn(
+                authentication::require_authentication::<
+                    CredentialRep,
+                    SessionRep,
+                    UserAuthenticateApplicationService<CredentialRep>,
+                    SessionAuthenticateApplicationService<SessionRep>,
+                    _,
+                >,
+            )),
+        )
+        .route(
+            "/invitations/:invite_id/decline",
+            post(
+                invitation_handlers::decline::<
+                    InvitationRep,
+                    InvitationDeclineApplicationService<InvitationRep>,
+                >,
+            )
+            .route_layer(middleware::from_fn(
+                authentication::require_authentication::<
+                    CredentialRep,
+                    SessionRep,
+                    UserAuthenticateApplicationService<CredentialRep>,
+                    SessionAuthenticateApplicationService<SessionRep>,
+                    _,
+                >,
+            )),
+        )
+        .layer(Extension(Arc::new(invitation_repository)))
+        .layer(Extension(Arc::new(audit_log_repository)))
+        .layer(Extension(Arc::new(todo_dependency_repository)))
+        .layer(Extension(Arc::new(event_bus)))
+        .layer(Extension(Arc::new(todo_repository.clone())))
         .layer(Extension(Arc::new(label_repository)))
         // users
         .route(
             "/users",
-            get(user_handlers::get_all::<UserRep, UserGetAllApplicationService<UserRep>>)
-                .post(user_handlers::create::<UserRep, UserCreateApplicationService<UserRep>>),
+            get(user_handlers::get_all::<
+                UserRep,
+                UserGetAllApplicationService<UserRep>,
+                UserGetByRoleApplicationService<UserRep>,
+            >)
+            .post(user_handlers::create::<UserRep, UserCreateApplicationService<UserRep>>)
+            // 本人か管理者として認証していれば、ロールも返す
+            .route_layer(middleware::from_fn(
+                authentication::authenticate_if_present::<
+                    CredentialRep,
+                    SessionRep,
+                    UserAuthenticateApplicationService<CredentialRep>,
+                    SessionAuthenticateApplicationService<SessionRep>,
+                    _,
+                >,
+            )),
+        )
+        // `/users/:id` より先に登録し、`search` や `me` が id として扱われないようにする
+        .route(
+            "/users/search",
+            get(user_handlers::search::<UserRep, UserSearchApplicationService<UserRep>>)
+                .route_layer(middleware::from_fn(
+                    authentication::authenticate_if_present::<
+                        CredentialRep,
+                        SessionRep,
+                        UserAuthenticateApplicationService<CredentialRep>,
+                        SessionAuthenticateApplicationService<SessionRep>,
+                        _,
+                    >,
+                )),
+        )
+        .route(
+            "/users/me",
+            get(
+                user_handlers::me::<
+                    UserRep,
+                    TodoRep,
+                    UserGetApplicationService<UserRep, TodoRep>,
+                >,
+            )
+                .patch(
+                    user_handlers::update_me::<UserRep, UserSelfUpdateApplicationService<UserRep>>,
+                )
+                .route_layer(middleware::from_fn(
+                    authentication::require_authentication::<
+                        CredentialRep,
+                        SessionRep,
+                        UserAuthenticateApplicationService<CredentialRep>,
+                        SessionAuthenticateApplicationService<SessionRep>,
+                        _,
+                    >,
+                )),
         )
         .route(
             "/users/:id",
-            get(user_handlers::get::<UserRep, UserGetApplicationService<UserRep>>)
+            get(
+                user_handlers::get::<
+                    UserRep,
+                    TodoRep,
+                    UserGetApplicationService<UserRep, TodoRep>,
+                >,
+            )
                 .patch(user_handlers::update::<UserRep, UserUpdateApplicationService<UserRep>>)
-                .delete(user_handlers::delete::<UserRep, UserDeleteApplicationService<UserRep>>),
+                // `route_layer` はそれまでに登録したメソッドにだけかかるため、GET と PATCH のみ任意で認証する
+                .route_layer(middleware::from_fn(
+                    authentication::authenticate_if_present::<
+                        CredentialRep,
+                        SessionRep,
+                        UserAuthenticateApplicationService<CredentialRep>,
+                        SessionAuthenticateApplicationService<SessionRep>,
+                        _,
+                    >,
+                ))
+                // 削除は本人か管理者だけができるため、DELETE には認証を必須にする
+                .merge(
+                    delete(
+                        user_handlers::delete::<
+                            UserRep,
+                            CredentialRep,
+                            UserDeleteApplicationService<UserRep, CredentialRep>,
+                        >,
+                    )
+                    .route_layer(middleware::from_fn(
+                        authentication::require_authentication::<
+                            CredentialRep,
+                            SessionRep,
+                            UserAuthenticateApplicationService<CredentialRep>,
+                            SessionAuthenticateApplicationService<SessionRep>,
+                            _,
+                        >,
+                    )),
+                ),
+        )
+        .route(
+            "/users/:id/password",
+            patch(
+                user_handlers::change_password::<
+                    CredentialRep,
+                    UserPasswordChangeApplicationService<CredentialRep>,
+                >,
+            )
+            .route_layer(middleware::from_fn(
+                authentication::require_authentication::<
+                    CredentialRep,
+                    SessionRep,
+                    UserAuthenticateApplicationService<CredentialRep>,
+                    SessionAuthenticateApplicationService<SessionRep>,
+                    _,
+                >,
+            )),
+        )
+        // admin
+        .route(
+            "/admin/stats/users",
+            get(
+                user_handlers::get_role_distribution::<
+                    UserRep,
+                    UserRoleDistributionApplicationService<UserRep>,
+                >,
+            )
+            // 後から追加したレイヤーが先に実行されるため、認証してからロールを確認する
+            .route_layer(middleware::from_fn_with_state(
+                UserRole::Admin,
+                authorization::authorize::<
+                    UserRep,
+                    TodoRep,
+                    UserGetApplicationService<UserRep, TodoRep>,
+                    _,
+                >,
+            ))
+            .route_layer(middleware::from_fn(
+                authentication::require_authentication::<
+                    CredentialRep,
+                    SessionRep,
+                    UserAuthenticateApplicationService<CredentialRep>,
+                    SessionAuthenticateApplicationService<SessionRep>,
+                    _,
+                >,
+            )),
         )
         .layer(Extension(Arc::new(user_repository)))
+        .layer(Extension(Arc::new(todo_repository)))
+        // auth
+        .route(
+            "/auth/login",
+            post(
+                session_handlers::login::<
+                    CredentialRep,
+                    SessionRep,
+                    SessionLoginApplicationService<CredentialRep, SessionRep>,
+                >,
+            ),
+        )
+        .route(
+            "/auth/logout",
+            post(
+                session_handlers::logout::<SessionRep, SessionLogoutApplicationService<SessionRep>>,
+            )
+            .route_layer(middleware::from_fn(
+                authentication::require_authentication::<
+                    CredentialRep,
+                    SessionRep,
+                    UserAuthenticateApplicationService<CredentialRep>,
+                    SessionAuthenticateApplicationService<SessionRep>,
+                    _,
+                >,
+            )),
+        )
+        .layer(Extension(Arc::new(credential_repository)))
+        .layer(Extension(Arc::new(session_repository)))
+        .layer(Extension(SessionCache::new()))
+        .layer(Extension(Arc::new(feature_flags)))
+        .layer(Extension(Arc::new(app_config.clone())))
+        .layer(Extension(Arc::new(ProfanityFilter::new())))
+        .layer(Extension(health_handlers::HealthChecker::new(
+            health_probe,
+            app_config.telemetry_id.clone(),
+        )))
         // CORS
         .layer(
             CorsLayer::new()
                 .allow_origin("http://127.0.0.1:3001".parse::<HeaderValue>().unwrap())
                 .allow_methods(Any)
-                .allow_headers(vec![CONTENT_TYPE]),
+                .allow_headers(vec![CONTENT_TYPE, AUTHORIZATION, ACCEPT_LANGUAGE]),
+        )
+        .layer(middleware::from_fn(locale::locale_extractor));
+
+    let router = if app_config.request_body_logging_enabled {
+        router.layer(RequestBodyLogLayer::new())
+    } else {
+        router
+    };
+
+    // `Allow` ヘッダーはルートごとのレイヤーの外側で付与されるため、ルーター全体を包む
+    // パスの正規化もルーティングより前に行う必要があるため、同様にルーター全体を包む
+    // 版を指定したい API の利用者向けに、同じルートを `/v1` の下にも置く
+    Router::new()
+        .nest_service("/v1", router.clone())
+        .fallback_service(router)
+        .layer(middleware::from_fn(options_response::respond_to_options))
+        .layer(TrailingSlashLayer::new())
+        .layer(RequestIdLayer::new(&app_config.telemetry_id))
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use axum::{
+        body::Body,
+        http::{header, method::Method, Request, StatusCode},
+        response::Response,
+        Router,
+    };
+    use serde::de::DeserializeOwned;
+
+    pub fn build_req_with_empty(uri: &str, method: Method) -> Result<Request<Body>> {
+        let req = Request::builder()
+            .uri(uri)
+            .method(method)
+            .header(header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+            .body(Body::empty())?;
+        Ok(req)
+    }
+
+    pub fn build_req_with_json(
+        uri: &str,
+        method: Method,
+        json_body_string: String,
+    ) -> Result<Request<Body>> {
+        let req = Request::builder()
+            .uri(uri)
+            .method(method)
+            .header(header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+            .body(Body::from(json_body_string))?;
+        Ok(req)
+    }
+
+    pub async fn res_to_struct<T>(res: Response) -> Result<T>
+    where
+        T: DeserializeOwned,
+    {
+        // レスポンスからボディを取得
+        let bytes = hyper::body::to_bytes(res.into_body()).await?;
+
+        // ボディをバイト列から文字列に変換
+        let body = String::from_utf8(bytes.to_vec())?;
+
+        // ボディを json としてパース
+        let data: T = serde_json::from_str(&body)?;
+        Ok(data)
+    }
+
+    #[tokio::test]
+    async fn should_pass_request_body_through_when_body_logging_is_enabled() -> Result<()> {
+        use serde_json::Value;
+        use tower::ServiceExt;
+
+        use super::{create_app, ArgCreateApp};
+        use crate::app_config::AppConfig;
+
+        let app = create_app(ArgCreateApp::default().app_config(AppConfig {
+            request_body_logging_enabled: true,
+            ..AppConfig::default()
+        }));
+
+        let req_body = r#"{"name": "label-1"}"#.to_string();
+        let req = build_req_with_json("/labels", Method::POST, req_body)?;
+        let res = app.clone().oneshot(req).await?;
+        let created: Value = res_to_struct(res).await?;
+        assert_eq!("label-1", created["name"]);
+
+        let req = build_req_with_empty("/labels", Method::GET)?;
+        let res = app.oneshot(req).await?;
+        let page: Value = res_to_struct(res).await?;
+        let labels: Vec<Value> = serde_json::from_value(page["data"].clone())?;
+        assert_eq!(vec![created], labels);
+        Ok(())
+    }
+
+    // `/users/me` のテスト用に、パスワードを登録したユーザーを用意する
+    async fn create_app_with_user_and_password(
+        user_name: &str,
+        password: &str,
+    ) -> Result<(Router, String)> {
+        let (app, mut user_ids) =
+            create_app_with_users_and_passwords(&[(user_name, password)]).await?;
+        Ok((app, user_ids.remove(0)))
+    }
+
+    async fn create_app_with_users_and_passwords(
+        users: &[(&str, &str)],
+    ) -> Result<(Router, Vec<String>)> {
+        use crate::domain::{
+            models::{
+                credentials::{
+                    credential_repository::ICredentialRepository,
+                    password_credential::PasswordCredential,
+                },
+                users::{user::User, user_name::UserName, user_repository::IUserRepository},
+            },
+            value_object::ValueObject,
+        };
+
+        use super::{create_app, ArgCreateApp};
+
+        let arg_create_app = ArgCreateApp::default();
+        let mut user_ids = Vec::new();
+        for (user_name, password) in users {
+            let user = User::new(UserName::new(user_name.to_string())?)?;
+            arg_create_app.user_repository.save(&user).await?;
+            arg_create_app
+                .credential_repository
+                .save(&PasswordCredential::new(user.user_id().clone(), password)?)
+                .await?;
+            user_ids.push(user.user_id().to_string());
+        }
+
+        Ok((create_app(arg_create_app), user_ids))
+    }
+
+    pub fn basic_authorization(user_id: &str, password: &str) -> String {
+        use base64::{engine::general_purpose::STANDARD, Engine};
+
+        format!(
+            "Basic {}",
+            STANDARD.encode(format!("{}:{}", user_id, password))
         )
+    }
+
+    #[tokio::test]
+    async fn should_get_authenticated_user_at_users_me() -> Result<()> {
+        use serde_json::Value;
+        use tower::ServiceExt;
+
+        let (app, user_id) = create_app_with_user_and_password("tester-1", "password1").await?;
+
+        let mut req = build_req_with_empty("/users/me", Method::GET)?;
+        req.headers_mut().insert(
+            header::AUTHORIZATION,
+            basic_authorization(&user_id, "password1").parse()?,
+        );
+        let res = app.oneshot(req).await?;
+
+        assert_eq!(StatusCode::OK, res.status());
+        let user: Value = res_to_struct(res).await?;
+        assert_eq!(user_id, user["id"]);
+        assert_eq!("tester-1", user["name"]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_change_password_only_with_correct_current_password() -> Result<()> {
+        use tower::ServiceExt;
+
+        let (app, user_id) = create_app_with_user_and_password("tester-1", "password1").await?;
+        let uri = format!("/users/{}/password", user_id);
+        let change_password = |req_body: &str| -> Result<Request<Body>> {
+            let mut req = build_req_with_json(&uri, Method::PATCH, req_body.to_string())?;
+            req.headers_mut().insert(
+                header::AUTHORIZATION,
+                basic_authorization(&user_id, "password1").parse()?,
+            );
+            Ok(req)
+        };
+
+        // 現在のパスワードが誤っている
+        let req =
+            change_password(r#"{"current_password": "password9", "new_password": "password2"}"#)?;
+        let res = app.clone().oneshot(req).await?;
+        assert_eq!(StatusCode::FORBIDDEN, res.status());
+
+        // 新しいパスワードが弱い
+        let req =
+            change_password(r#"{"current_password": "password1", "new_password": "short1"}"#)?;
+        let res = app.clone().oneshot(req).await?;
+        assert_eq!(StatusCode::BAD_REQUEST, res.status());
+
+        let req =
+            change_password(r#"{"current_password": "password1", "new_password": "password2"}"#)?;
+        let res = app.clone().oneshot(req).await?;
+        assert_eq!(StatusCode::NO_CONTENT, res.status());
+
+        // 変更後は新しいパスワードでだけ認証できる
+        for (password, status) in [
+            ("password1", StatusCode::UNAUTHORIZED),
+            ("password2", StatusCode::OK),
+        ] {
+            let mut req = build_req_with_empty("/users/me", Method::GET)?;
+            req.headers_mut().insert(
+                header::AUTHORIZATION,
+                basic_authorization(&user_id, password).parse()?,
+            );
+            let res = app.clone().oneshot(req).await?;
+            assert_eq!(status, res.status());
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_change_password_only_of_authenticated_user_themselves() -> Result<()> {
+        use tower::ServiceExt;
+
+        let (app, user_ids) = create_app_with_users_and_passwords(&[
+            ("tester-1", "password1"),
+            ("tester-2", "password2"),
+        ])
+        .await?;
+        let uri = format!("/users/{}/password", user_ids[0]);
+        let req_body = r#"{"current_password": "password1", "new_password": "password3"}"#;
+
+        // 認証していない
+        let req = build_req_with_json(&uri, Method::PATCH, req_body.to_string())?;
+        let res = app.clone().oneshot(req).await?;
+        assert_eq!(StatusCode::UNAUTHORIZED, res.status());
+
+        // 他のユーザーのパスワードは、現在のパスワードを知っていても変更できない
+        let mut req = build_req_with_json(&uri, Method::PATCH, req_body.to_string())?;
+        req.headers_mut().insert(
+            header::AUTHORIZATION,
+            basic_authorization(&user_ids[1], "password2").parse()?,
+        );
+        let res = app.clone().oneshot(req).await?;
+        assert_eq!(StatusCode::FORBIDDEN, res.status());
+
+        let mut req = build_req_with_empty("/users/me", Method::GET)?;
+        req.headers_mut().insert(
+            header::AUTHORIZATION,
+            basic_authorization(&user_ids[0], "password1").parse()?,
+        );
+        let res = app.oneshot(req).await?;
+        assert_eq!(StatusCode::OK, res.status());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_reject_unauthenticated_request_to_users_me() -> Result<()> {
+        use tower::ServiceExt;
+
+        let (app, user_id) = create_app_with_user_and_password("tester-1", "password1").await?;
+
+        // Authorization ヘッダーなし
+        let req = build_req_with_empty("/users/me", Method::GET)?;
+        let res = app.clone().oneshot(req).await?;
+        assert_eq!(StatusCode::UNAUTHORIZED, res.status());
+        assert!(res.headers().contains_key(header::WWW_AUTHENTICATE));
+
+        // パスワード誤り
+        let mut req = build_req_with_empty("/users/me", Method::GET)?;
+        req.headers_mut().insert(
+            header::AUTHORIZATION,
+            basic_authorization(&user_id, "password2").parse()?,
+        );
+        let res = app.oneshot(req).await?;
+        assert_eq!(StatusCode::UNAUTHORIZED, res.status());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_update_authenticated_user_at_users_me() -> Result<()> {
+        use serde_json::Value;
+        use tower::ServiceExt;
+
+        let (app, user_id) = create_app_with_user_and_password("tester-1", "password1").await?;
+
+        let mut req = build_req_with_json(
+            "/users/me",
+            Method::PATCH,
+            r#"{"user_name": "updated"}"#.to_string(),
+        )?;
+        req.headers_mut().insert(
+            header::AUTHORIZATION,
+            basic_authorization(&user_id, "password1").parse()?,
+        );
+        let res = app.clone().oneshot(req).await?;
+
+        assert_eq!(StatusCode::OK, res.status());
+        let user: Value = res_to_struct(res).await?;
+        assert_eq!(user_id, user["id"]);
+        assert_eq!("updated", user["name"]);
+
+        // 認証なしでは更新できない
+        let req = build_req_with_json(
+            "/users/me",
+            Method::PATCH,
+            r#"{"user_name": "hijacked"}"#.to_string(),
+        )?;
+        let res = app.oneshot(req).await?;
+        assert_eq!(StatusCode::UNAUTHORIZED, res.status());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_reject_bearer_token_after_logout() -> Result<()> {
+        use serde_json::Value;
+        use tower::ServiceExt;
+
+        let (app, user_id) = create_app_with_user_and_password("tester-1", "password1").await?;
+
+        // login
+        let req_body = format!(r#"{{"user_id": "{}", "password": "password1"}}"#, user_id);
+        let req = build_req_with_json("/auth/login", Method::POST, req_body)?;
+        let res = app.clone().oneshot(req).await?;
+        assert_eq!(StatusCode::OK, res.status());
+        let session: Value = res_to_struct(res).await?;
+        assert_eq!("Bearer", session["token_type"]);
+        let bearer_authorization = format!("Bearer {}", session["access_token"].as_str().unwrap());
+
+        // ログイン中はトークンで認証できる
+        let mut req = build_req_with_empty("/users/me", Method::GET)?;
+        req.headers_mut()
+            .insert(header::AUTHORIZATION, bearer_authorization.parse()?);
+        let res = app.clone().oneshot(req).await?;
+        assert_eq!(StatusCode::OK, res.status());
+
+        // logout
+        let mut req = build_req_with_empty("/auth/logout", Method::POST)?;
+        req.headers_mut()
+            .insert(header::AUTHORIZATION, bearer_authorization.parse()?);
+        let res = app.clone().oneshot(req).await?;
+        assert_eq!(StatusCode::NO_CONTENT, res.status());
+
+        // ログアウト後は同じトークンで認証できない
+        let mut req = build_req_with_empty("/users/me", Method::GET)?;
+        req.headers_mut()
+            .insert(header::AUTHORIZATION, bearer_authorization.parse()?);
+        let res = app.oneshot(req).await?;
+        assert_eq!(StatusCode::UNAUTHORIZED, res.status());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_lock_account_after_five_failed_logins() -> Result<()> {
+        use tower::ServiceExt;
+
+        let (app, user_id) = create_app_with_user_and_password("tester-1", "password1").await?;
+
+        let wrong_password = format!(r#"{{"user_id": "{}", "password": "password2"}}"#, user_id);
+        for _ in 0..5 {
+            let req = build_req_with_json("/auth/login", Method::POST, wrong_password.clone())?;
+            let res = app.clone().oneshot(req).await?;
+            assert_eq!(StatusCode::UNAUTHORIZED, res.status());
+        }
+
+        // 6 回目は正しいパスワードでもロックされている
+        let req_body = format!(r#"{{"user_id": "{}", "password": "password1"}}"#, user_id);
+        let req = build_req_with_json("/auth/login", Method::POST, req_body)?;
+        let res = app.clone().oneshot(req).await?;
+        assert_eq!(StatusCode::LOCKED, res.status());
+        let retry_after: i64 = res.headers()[header::RETRY_AFTER].to_str()?.parse()?;
+        assert!(0 < retry_after && retry_after <= 15 * 60);
+
+        // Basic 認証も同じようにロックされる
+        let mut req = build_req_with_empty("/users/me", Method::GET)?;
+        req.headers_mut().insert(
+            header::AUTHORIZATION,
+            basic_authorization(&user_id, "password1").parse()?,
+        );
+        let res = app.oneshot(req).await?;
+        assert_eq!(StatusCode::LOCKED, res.status());
+        Ok(())
+    }
+
+    // ログインして発行されたトークンを `Authorization` ヘッダーの値にして返す
+    // Basic 認証と違い、リクエストのたびにパスワードを検証しないため、間違えた回数が消えない
+    async fn bearer_authorization(app: &Router, user_id: &str, password: &str) -> Result<String> {
+        use serde_json::Value;
+        use tower::ServiceExt;
+
+        let req_body = format!(
+            r#"{{"user_id": "{}", "password": "{}"}}"#,
+            user_id, password
+        );
+        let req = build_req_with_json("/auth/login", Method::POST, req_body)?;
+        let res = app.clone().oneshot(req).await?;
+        let session: Value = res_to_struct(res).await?;
+        Ok(format!(
+            "Bearer {}",
+            session["access_token"].as_str().unwrap()
+        ))
+    }
+
+    #[tokio::test]
+    async fn should_lock_account_after_five_wrong_current_passwords() -> Result<()> {
+        use tower::ServiceExt;
+
+        let (app, user_id) = create_app_with_user_and_password("tester-1", "password1").await?;
+        let authorization = bearer_authorization(&app, &user_id, "password1").await?;
+        let change_password = |current_password: &str| -> Result<Request<Body>> {
+            let mut req = build_req_with_json(
+                &format!("/users/{}/password", user_id),
+                Method::PATCH,
+                format!(
+                    r#"{{"current_password": "{}", "new_password": "password2"}}"#,
+                    current_password
+                ),
+            )?;
+            req.headers_mut()
+                .insert(header::AUTHORIZATION, authorization.parse()?);
+            Ok(req)
+        };
+
+        for _ in 0..5 {
+            let res = app.clone().oneshot(change_password("password9")?).await?;
+            assert_eq!(StatusCode::FORBIDDEN, res.status());
+        }
+
+        // 6 回目は正しいパスワードでもロックされている
+        let res = app.clone().oneshot(change_password("password1")?).await?;
+        assert_eq!(StatusCode::LOCKED, res.status());
+        assert!(res.headers().contains_key(header::RETRY_AFTER));
+
+        // ログインとも同じ回数を数えるため、ログインもできない
+        let req_body = format!(r#"{{"user_id": "{}", "password": "password1"}}"#, user_id);
+        let req = build_req_with_json("/auth/login", Method::POST, req_body)?;
+        let res = app.oneshot(req).await?;
+        assert_eq!(StatusCode::LOCKED, res.status());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_lock_account_after_five_wrong_password_confirmations() -> Result<()> {
+        use tower::ServiceExt;
+
+        let (app, user_id) = create_app_with_user_and_password("tester-1", "password1").await?;
+        let authorization = bearer_authorization(&app, &user_id, "password1").await?;
+        let delete_user = |password_confirmation: &str| -> Result<Request<Body>> {
+            let mut req = build_req_with_json(
+                &format!("/users/{}", user_id),
+                Method::DELETE,
+                format!(
+                    r#"{{"password_confirmation": "{}"}}"#,
+                    password_confirmation
+                ),
+            )?;
+            req.headers_mut()
+                .insert(header::AUTHORIZATION, authorization.parse()?);
+            Ok(req)
+        };
+
+        for _ in 0..5 {
+            let res = app.clone().oneshot(delete_user("password9")?).await?;
+            assert_eq!(StatusCode::FORBIDDEN, res.status());
+        }
+
+        // 6 回目は正しいパスワードでもロックされている
+        let res = app.clone().oneshot(delete_user("password1")?).await?;
+        assert_eq!(StatusCode::LOCKED, res.status());
+        assert!(res.headers().contains_key(header::RETRY_AFTER));
+
+        let req_body = format!(r#"{{"user_id": "{}", "password": "password1"}}"#, user_id);
+        let req = build_req_with_json("/auth/login", Method::POST, req_body)?;
+        let res = app.oneshot(req).await?;
+        assert_eq!(StatusCode::LOCKED, res.status());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_return_existing_user_only_if_asked_to() -> Result<()> {
+        use serde_json::Value;
+        use tower::ServiceExt;
+
+        use super::{create_app, ArgCreateApp};
+
+        let app = create_app(ArgCreateApp::default());
+        let req_body = r#"{"user_name": "tester-1"}"#;
+
+        let req = build_req_with_json("/users", Method::POST, req_body.to_string())?;
+        let res = app.clone().oneshot(req).await?;
+        assert_eq!(StatusCode::CREATED, res.status());
+        let created: Value = res_to_struct(res).await?;
+
+        // 指定がなければ重複はエラー
+        let req = build_req_with_json("/users", Method::POST, req_body.to_string())?;
+        let res = app.clone().oneshot(req).await?;
+        assert_eq!(StatusCode::UNPROCESSABLE_ENTITY, res.status());
+
+        for if_exists in ["return", "update"] {
+            let uri = format!("/users?if_exists={}", if_exists);
+            let req = build_req_with_json(&uri, Method::POST, req_body.to_string())?;
+            let res = app.clone().oneshot(req).await?;
+            assert_eq!(StatusCode::OK, res.status());
+            let existing: Value = res_to_struct(res).await?;
+            assert_eq!(created["id"], existing["id"]);
+        }
+
+        let req = build_req_with_json(
+            "/users?if_exists=unknown",
+            Method::POST,
+            req_body.to_string(),
+        )?;
+        let res = app.oneshot(req).await?;
+        assert_eq!(StatusCode::BAD_REQUEST, res.status());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_search_users_by_name_prefix() -> Result<()> {
+        use serde_json::Value;
+        use tower::ServiceExt;
+
+        use super::{create_app, ArgCreateApp};
+
+        let app = create_app(ArgCreateApp::default());
+        for user_name in ["Alice", "Alison", "Bob", "Carol"] {
+            let req_body = format!(r#"{{"user_name": "{}"}}"#, user_name);
+            let req = build_req_with_json("/users", Method::POST, req_body)?;
+            let res = app.clone().oneshot(req).await?;
+            assert_eq!(StatusCode::CREATED, res.status());
+        }
+
+        let req = build_req_with_empty("/users/search?q=al&limit=5", Method::GET)?;
+        let res = app.clone().oneshot(req).await?;
+
+        assert_eq!(StatusCode::OK, res.status());
+        let users: Vec<Value> = res_to_struct(res).await?;
+        let names: Vec<&str> = users
+            .iter()
+            .filter_map(|user| user["name"].as_str())
+            .collect();
+        assert_eq!(vec!["Alice", "Alison"], names);
+
+        // 空のクエリは受け付けない
+        let req = build_req_with_empty("/users/search?q=", Method::GET)?;
+        let res = app.oneshot(req).await?;
+        assert_eq!(StatusCode::UNPROCESSABLE_ENTITY, res.status());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_list_allowed_methods_for_options_request() -> Result<()> {
+        use tower::ServiceExt;
+
+        use super::{create_app, ArgCreateApp};
+
+        let app = create_app(ArgCreateApp::default());
+
+        let req = build_req_with_empty("/todos", Method::OPTIONS)?;
+        let res = app.clone().oneshot(req).await?;
+        assert_eq!(StatusCode::OK, res.status());
+        let allow = res.headers().get(header::ALLOW).unwrap().to_str()?;
+        let methods: Vec<&str> = allow.split(", ").collect();
+        assert!(methods.contains(&"GET"));
+        assert!(methods.contains(&"POST"));
+        assert!(methods.contains(&"OPTIONS"));
+        assert!(!methods.contains(&"DELETE"));
+
+        // `Allow` は各ルートに登録されたメソッドに合わせて変わる
+        let req = build_req_with_empty("/todos/calendar.ics", Method::OPTIONS)?;
+        let res = app.clone().oneshot(req).await?;
+        assert_eq!(StatusCode::OK, res.status());
+        assert_eq!(
+            "GET, HEAD, OPTIONS",
+            res.headers().get(header::ALLOW).unwrap()
+        );
+
+        // OPTIONS 以外のメソッドは 405 のまま
+        let req = build_req_with_empty("/todos", Method::PUT)?;
+        let res = app.oneshot(req).await?;
+        assert_eq!(StatusCode::METHOD_NOT_ALLOWED, res.status());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_distinguish_semantic_errors_from_malformed_requests() -> Result<()> {
+        use tower::ServiceExt;
+
+        use super::{create_app, ArgCreateApp};
+
+        let app = create_app(ArgCreateApp::default());
+
+        // 業務ルールに反する値は 422
+        let req = build_req_with_json("/labels", Method::POST, r#"{"name": ""}"#.to_string())?;
+        let res = app.clone().oneshot(req).await?;
+        assert_eq!(StatusCode::UNPROCESSABLE_ENTITY, res.status());
+
+        let req = build_req_with_json(
+            "/labels",
+            Method::POST,
+            r#"{"name": "label-1"}"#.to_string(),
+        )?;
+        let res = app.clone().oneshot(req).await?;
+        assert_eq!(StatusCode::CREATED, res.status());
+
+        let req = build_req_with_json(
+            "/labels",
+            Method::POST,
+            r#"{"name": "label-1"}"#.to_string(),
+        )?;
+        let res = app.clone().oneshot(req).await?;
+        assert_eq!(StatusCode::UNPROCESSABLE_ENTITY, res.status());
+
+        // id の形式が正しくない場合は 400
+        let req = build_req_with_empty("/labels/not-a-uuid", Method::GET)?;
+        let res = app.oneshot(req).await?;
+        assert_eq!(StatusCode::BAD_REQUEST, res.status());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_localize_error_detail_by_accept_language() -> Result<()> {
+        use serde_json::Value;
+        use tower::ServiceExt;
+
+        use super::{create_app, ArgCreateApp};
+
+        let app = create_app(ArgCreateApp::default());
+        let req_body = r#"{"text": "", "label_ids": []}"#;
+
+        let mut req = build_req_with_json("/todos", Method::POST, req_body.to_string())?;
+        req.headers_mut()
+            .insert(header::ACCEPT_LANGUAGE, "ja".parse()?);
+        let res = app.clone().oneshot(req).await?;
+        assert_eq!(StatusCode::UNPROCESSABLE_ENTITY, res.status());
+        let problem: Value = res_to_struct(res).await?;
+        assert_eq!(
+            "指定された todo が正しくありません: [todo のテキストは空にできません。]",
+            problem["detail"]
+        );
+
+        // 対応していない言語は英語になる
+        let mut req = build_req_with_json("/todos", Method::POST, req_body.to_string())?;
+        req.headers_mut()
+            .insert(header::ACCEPT_LANGUAGE, "fr-FR".parse()?);
+        let res = app.oneshot(req).await?;
+        let problem: Value = res_to_struct(res).await?;
+        assert_eq!(
+            "Given todo is incorrect: [Todo text must not be empty.]",
+            problem["detail"]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_localize_label_and_user_error_detail_by_accept_language() -> Result<()> {
+        use serde_json::Value;
+        use tower::ServiceExt;
+
+        use super::{create_app, ArgCreateApp};
+
+        let app = create_app(ArgCreateApp::default());
+
+        for (uri, expected_status, expected_detail) in [
+            (
+                "/labels/not-a-uuid",
+                StatusCode::BAD_REQUEST,
+                "ラベルの id の形式が正しくありません: ",
+            ),
+            (
+                "/users/search?q=",
+                StatusCode::UNPROCESSABLE_ENTITY,
+                "指定されたユーザーが正しくありません: ",
+            ),
+        ] {
+            let mut req = build_req_with_empty(uri, Method::GET)?;
+            req.headers_mut()
+                .insert(header::ACCEPT_LANGUAGE, "ja".parse()?);
+            let res = app.clone().oneshot(req).await?;
+            assert_eq!(expected_status, res.status(), "{}", uri);
+            assert_eq!(
+                Some("application/problem+json"),
+                res.headers()
+                    .get(header::CONTENT_TYPE)
+                    .and_then(|value| value.to_str().ok()),
+                "{}",
+                uri
+            );
+            let problem: Value = res_to_struct(res).await?;
+            assert!(
+                problem["detail"]
+                    .as_str()
+                    .is_some_and(|detail| detail.starts_with(expected_detail)),
+                "{}: {}",
+                uri,
+                problem
+            );
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_show_id_as_is_in_error_detail() -> Result<()> {
+        use serde_json::Value;
+        use tower::ServiceExt;
+        use uuid::Uuid;
+
+        use super::{create_app, ArgCreateApp};
+
+        let app = create_app(ArgCreateApp::default());
+        let label_id = Uuid::new_v4();
+
+        let req = build_req_with_empty(&format!("/labels/{}", label_id), Method::GET)?;
+        let res = app.oneshot(req).await?;
+        assert_eq!(StatusCode::NOT_FOUND, res.status());
+        let problem: Value = res_to_struct(res).await?;
+        assert_eq!(
+            format!("Label cannnot be found: [id: {}]", label_id),
+            problem["detail"]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_localize_authentication_error_detail_by_accept_language() -> Result<()> {
+        use serde_json::Value;
+        use tower::ServiceExt;
+
+        let (app, user_id) = create_app_with_user_and_password("tester-1", "password1").await?;
+
+        let mut req = build_req_with_empty("/users/me", Method::GET)?;
+        req.headers_mut()
+            .insert(header::ACCEPT_LANGUAGE, "ja".parse()?);
+        let res = app.clone().oneshot(req).await?;
+        assert_eq!(StatusCode::UNAUTHORIZED, res.status());
+        assert!(res.headers().contains_key(header::WWW_AUTHENTICATE));
+        assert_eq!(
+            Some("application/problem+json"),
+            res.headers()
+                .get(header::CONTENT_TYPE)
+                .and_then(|value| value.to_str().ok())
+        );
+        let problem: Value = res_to_struct(res).await?;
+        assert_eq!(
+            "Authorization ヘッダーに Basic 認証の資格情報か Bearer トークンを指定してください。",
+            problem["detail"]
+        );
+
+        let mut req = build_req_with_empty("/users/me", Method::GET)?;
+        req.headers_mut().insert(
+            header::AUTHORIZATION,
+            basic_authorization(&user_id, "password2").parse()?,
+        );
+        req.headers_mut()
+            .insert(header::ACCEPT_LANGUAGE, "ja".parse()?);
+        let res = app.oneshot(req).await?;
+        assert_eq!(StatusCode::UNAUTHORIZED, res.status());
+        let problem: Value = res_to_struct(res).await?;
+        assert_eq!(
+            "ユーザー id またはパスワードが正しくありません。",
+            problem["detail"]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_return_501_when_search_is_disabled() -> Result<()> {
+        use serde_json::Value;
+        use tower::ServiceExt;
+
+        use super::{create_app, ArgCreateApp};
+        use crate::feature_flags::FeatureFlags;
+
+        let feature_flags = FeatureFlags {
+            enable_search: false,
+            ..FeatureFlags::default()
+        };
+        let app = create_app(ArgCreateApp::default().feature_flags(feature_flags));
+
+        let req = build_req_with_empty("/todos?q=foo", Method::GET)?;
+        let res = app.clone().oneshot(req).await?;
+        assert_eq!(StatusCode::NOT_IMPLEMENTED, res.status());
+        assert_eq!(
+            "application/problem+json",
+            res.headers().get(header::CONTENT_TYPE).unwrap()
+        );
+        let problem: Value = res_to_struct(res).await?;
+        assert_eq!(501, problem["status"]);
+        assert_eq!("The search feature is disabled.", problem["detail"]);
+
+        // 検索をしない一覧取得には影響しない
+        let req = build_req_with_empty("/todos", Method::GET)?;
+        let res = app.oneshot(req).await?;
+        assert_eq!(StatusCode::OK, res.status());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_search_todos_when_search_is_enabled() -> Result<()> {
+        use serde_json::Value;
+        use tower::ServiceExt;
+
+        use super::{create_app, ArgCreateApp};
+
+        let app = create_app(ArgCreateApp::default());
+
+        for text in ["buy food", "write report"] {
+            let req_body = format!(r#"{{"text": "{}", "label_ids": []}}"#, text);
+            let req = build_req_with_json("/todos", Method::POST, req_body)?;
+            app.clone().oneshot(req).await?;
+        }
+
+        let req = build_req_with_empty("/todos?q=foo", Method::GET)?;
+        let res = app.oneshot(req).await?;
+        assert_eq!(StatusCode::OK, res.status());
+        let page: Value = res_to_struct(res).await?;
+        let todos: Vec<Value> = serde_json::from_value(page["data"].clone())?;
+        assert_eq!(1, todos.len());
+        assert_eq!("buy food", todos[0]["text"]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_add_link_header_to_paginated_list() -> Result<()> {
+        use serde_json::Value;
+        use tower::ServiceExt;
+
+        use super::{create_app, ArgCreateApp};
+
+        let app = create_app(ArgCreateApp::default());
+
+        for i in 1..=5 {
+            let req_body = format!(r#"{{"name": "label-{}"}}"#, i);
+            let req = build_req_with_json("/labels", Method::POST, req_body)?;
+            app.clone().oneshot(req).await?;
+        }
+
+        let req = build_req_with_empty("/labels?page=1&per_page=2", Method::GET)?;
+        let res = app.oneshot(req).await?;
+        let link = res
+            .headers()
+            .get(header::LINK)
+            .unwrap()
+            .to_str()?
+            .to_string();
+        let page: Value = res_to_struct(res).await?;
+        let labels: Vec<Value> = serde_json::from_value(page["data"].clone())?;
+
+        assert_eq!(2, labels.len());
+        assert!(link.contains(r#"</labels?page=2&per_page=2>; rel="next""#));
+        assert!(link.contains(r#"</labels?page=3&per_page=2>; rel="last""#));
+        assert!(!link.contains(r#"rel="prev""#));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_return_pagination_meta_with_labels() -> Result<()> {
+        use serde_json::Value;
+        use tower::ServiceExt;
+
+        use super::{create_app, ArgCreateApp};
+
+        let app = create_app(ArgCreateApp::default());
+
+        for i in 1..=5 {
+            let req_body = format!(r#"{{"name": "label-{}"}}"#, i);
+            let req = build_req_with_json("/labels", Method::POST, req_body)?;
+            let res = app.clone().oneshot(req).await?;
+            assert_eq!(StatusCode::CREATED, res.status());
+        }
+
+        let req = build_req_with_empty("/labels?page=3&per_page=2", Method::GET)?;
+        let res = app.clone().oneshot(req).await?;
+        assert_eq!(StatusCode::OK, res.status());
+        let page: Value = res_to_struct(res).await?;
+        assert_eq!(1, page["data"].as_array().unwrap().len());
+        assert_eq!(5, page["meta"]["total"]);
+        assert_eq!(3, page["meta"]["page"]);
+        assert_eq!(2, page["meta"]["per_page"]);
+        assert_eq!(3, page["meta"]["total_pages"]);
+
+        // ページ指定が無い場合は、全件を 1 ページとして扱う
+        let req = build_req_with_empty("/labels", Method::GET)?;
+        let res = app.oneshot(req).await?;
+        let page: Value = res_to_struct(res).await?;
+        assert_eq!(5, page["data"].as_array().unwrap().len());
+        assert_eq!(5, page["meta"]["total"]);
+        assert_eq!(1, page["meta"]["total_pages"]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_export_todos_with_due_date_as_ical() -> Result<()> {
+        use serde_json::Value;
+        use tower::ServiceExt;
+
+        use super::{create_app, ArgCreateApp};
+
+        let app = create_app(ArgCreateApp::default());
+
+        let mut todo_ids = vec![];
+        for req_body in [
+            r#"{"text": "todo-1", "label_ids": [], "due_date": "2023-10-31"}"#,
+            r#"{"text": "todo-2", "label_ids": [], "due_date": "2023-11-01"}"#,
+            r#"{"text": "todo-3", "label_ids": []}"#,
+        ] {
+            let req = build_req_with_json("/todos", Method::POST, req_body.to_string())?;
+            let res = app.clone().oneshot(req).await?;
+            let created: Value = res_to_struct(res).await?;
+            todo_ids.push(created["id"].as_str().unwrap().to_string());
+        }
+
+        let req = build_req_with_empty("/todos/calendar.ics", Method::GET)?;
+        let res = app.oneshot(req).await?;
+        assert_eq!(
+            "text/calendar; charset=utf-8",
+            res.headers().get(header::CONTENT_TYPE).unwrap().to_str()?
+        );
+        let bytes = hyper::body::to_bytes(res.into_body()).await?;
+        let ics = String::from_utf8(bytes.to_vec())?;
+
+        assert_eq!(2, ics.matches("BEGIN:VEVENT").count());
+        assert!(ics.contains(&format!("UID:{}\r\n", todo_ids[0])));
+        assert!(ics.contains(&format!("UID:{}\r\n", todo_ids[1])));
+        assert!(!ics.contains(&todo_ids[2]));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_clear_due_date_only_if_null_is_given() -> Result<()> {
+        use serde_json::Value;
+        use tower::ServiceExt;
+
+        use super::{create_app, ArgCreateApp};
+
+        let app = create_app(ArgCreateApp::default());
+
+        let req_body = r#"{"text": "todo-1", "label_ids": [], "due_date": "2023-10-31"}"#;
+        let req = build_req_with_json("/todos", Method::POST, req_body.to_string())?;
+        let res = app.clone().oneshot(req).await?;
+        let created: Value = res_to_struct(res).await?;
+        let todo_id = created["id"].as_str().unwrap();
+
+        // キーがなければ期限日は変わらない
+        let req = build_req_with_json(
+            &format!("/todos/{}", todo_id),
+            Method::PATCH,
+            "{}".to_string(),
+        )?;
+        let res = app.clone().oneshot(req).await?;
+        assert_eq!(StatusCode::OK, res.status());
+        let updated: Value = res_to_struct(res).await?;
+        assert_eq!("2023-10-31", updated["due_date"]);
+
+        // null を渡すと期限日が削除される
+        let req = build_req_with_json(
+            &format!("/todos/{}", todo_id),
+            Method::PATCH,
+            r#"{"due_date": null}"#.to_string(),
+        )?;
+        let res = app.oneshot(req).await?;
+        assert_eq!(StatusCode::OK, res.status());
+        let updated: Value = res_to_struct(res).await?;
+        assert!(updated["due_date"].is_null());
+        assert_eq!("todo-1", updated["text"]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_get_todos_assigned_to_user() -> Result<()> {
+        use serde_json::Value;
+        use tower::ServiceExt;
+
+        let (app, user_id) = create_app_with_user_and_password("tester-1", "password1").await?;
+
+        for req_body in [
+            format!(
+                r#"{{"text": "todo-1", "label_ids": [], "assignee_id": "{}"}}"#,
+                user_id
+            ),
+            r#"{"text": "todo-2", "label_ids": []}"#.to_string(),
+        ] {
+            let req = build_req_with_json("/todos", Method::POST, req_body)?;
+            let res = app.clone().oneshot(req).await?;
+            assert_eq!(StatusCode::CREATED, res.status());
+        }
+
+        let req = build_req_with_empty(&format!("/users/{}/assigned", user_id), Method::GET)?;
+        let res = app.oneshot(req).await?;
+        assert_eq!(StatusCode::OK, res.status());
+        let todos: Vec<Value> = res_to_struct(res).await?;
+        assert_eq!(1, todos.len());
+        assert_eq!("todo-1", todos[0]["text"]);
+        assert_eq!(user_id, todos[0]["assignee_id"]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_delete_user_only_by_user_themselves_or_admin() -> Result<()> {
+        use tower::ServiceExt;
+
+        use crate::domain::{
+            models::{
+                credentials::{
+                    credential_repository::ICredentialRepository,
+                    password_credential::PasswordCredential,
+                },
+                users::{
+                    user::User, user_name::UserName, user_repository::IUserRepository,
+                    user_role::UserRole,
+                },
+            },
+            value_object::ValueObject,
+        };
+
+        use super::{create_app, ArgCreateApp};
+
+        let arg_create_app = ArgCreateApp::default();
+        let mut user_ids = Vec::new();
+        for (user_name, user_role) in [
+            ("admin", UserRole::Admin),
+            ("member", UserRole::Member),
+            ("user-b", UserRole::Member),
+        ] {
+            let mut user = User::new(UserName::new(user_name.to_string())?)?;
+            user.user_role = user_role;
+            arg_create_app.user_repository.save(&user).await?;
+            arg_create_app
+                .credential_repository
+                .save(&PasswordCredential::new(
+                    user.user_id().clone(),
+                    "password1",
+                )?)
+                .await?;
+            user_ids.push(user.user_id().to_string());
+        }
+        // パスワードを登録していないユーザー
+        let user_without_password = User::new(UserName::new("user-c".to_string())?)?;
+        arg_create_app
+            .user_repository
+            .save(&user_without_password)
+            .await?;
+        let user_c_id = user_without_password.user_id().to_string();
+        let [admin_id, member_id, user_b_id] = &user_ids[..] else {
+            unreachable!()
+        };
+        let app = create_app(arg_create_app);
+
+        for (user_id, requested_by, expected_status) in [
+            // 認証していない
+            (user_b_id, None, StatusCode::UNAUTHORIZED),
+            (&user_c_id, None, StatusCode::UNAUTHORIZED),
+            // 本人でも管理者でもない
+            (user_b_id, Some(member_id), StatusCode::FORBIDDEN),
+            (&user_c_id, Some(member_id), StatusCode::FORBIDDEN),
+            // 管理者はパスワードの確認なしで削除できる
+            (user_b_id, Some(admin_id), StatusCode::NO_CONTENT),
+            (&user_c_id, Some(admin_id), StatusCode::NO_CONTENT),
+        ] {
+            let mut req = build_req_with_empty(&format!("/users/{}", user_id), Method::DELETE)?;
+            if let Some(requested_by) = requested_by {
+                req.headers_mut().insert(
+                    header::AUTHORIZATION,
+                    basic_authorization(requested_by, "password1").parse()?,
+                );
+            }
+            let res = app.clone().oneshot(req).await?;
+            assert_eq!(expected_status, res.status());
+        }
+
+        // 本人はパスワードを確認してから削除する
+        let uri = format!("/users/{}", member_id);
+        for (password_confirmation, expected_status) in [
+            ("password2", StatusCode::FORBIDDEN),
+            ("password1", StatusCode::NO_CONTENT),
+        ] {
+            let mut req = build_req_with_json(
+                &uri,
+                Method::DELETE,
+                format!(
+                    r#"{{"password_confirmation": "{}"}}"#,
+                    password_confirmation
+                ),
+            )?;
+            req.headers_mut().insert(
+                header::AUTHORIZATION,
+                basic_authorization(member_id, "password1").parse()?,
+            );
+            let res = app.clone().oneshot(req).await?;
+            assert_eq!(expected_status, res.status());
+        }
+        let req = build_req_with_empty(&uri, Method::GET)?;
+        let res = app.oneshot(req).await?;
+        assert_eq!(StatusCode::NOT_FOUND, res.status());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_anonymize_user_and_keep_assigned_todos() -> Result<()> {
+        use serde_json::Value;
+        use tower::ServiceExt;
+
+        let (app, user_id) = create_app_with_user_and_password("tester-1", "password1").await?;
+
+        let req_body = format!(
+            r#"{{"text": "todo-1", "label_ids": [], "assignee_id": "{}"}}"#,
+            user_id
+        );
+        let req = build_req_with_json("/todos", Method::POST, req_body)?;
+        let created: Value = res_to_struct(app.clone().oneshot(req).await?).await?;
+
+        let mut req = build_req_with_json(
+            &format!("/users/{}", user_id),
+            Method::DELETE,
+            r#"{"password_confirmation": "password1", "anonymize": true}"#.to_string(),
+        )?;
+        req.headers_mut().insert(
+            header::AUTHORIZATION,
+            basic_authorization(&user_id, "password1").parse()?,
+        );
+        let res = app.clone().oneshot(req).await?;
+        assert_eq!(StatusCode::NO_CONTENT, res.status());
+
+        // ユーザーは見えなくなるが、担当していた todo は未割り当てで残る
+        let req = build_req_with_empty(&format!("/users/{}", user_id), Method::GET)?;
+        let res = app.clone().oneshot(req).await?;
+        assert_eq!(StatusCode::NOT_FOUND, res.status());
+        let req = build_req_with_empty("/users", Method::GET)?;
+        let users: Value = res_to_struct(app.clone().oneshot(req).await?).await?;
+        assert_eq!(Some(0), users["data"].as_array().map(Vec::len));
+
+        let req = build_req_with_empty(
+            &format!("/todos/{}", created["id"].as_str().unwrap()),
+            Method::GET,
+        )?;
+        let res = app.oneshot(req).await?;
+        assert_eq!(StatusCode::OK, res.status());
+        let todo: Value = res_to_struct(res).await?;
+        assert!(todo["assignee_id"].is_null());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_include_completion_rate_only_if_asked_to() -> Result<()> {
+        use serde_json::Value;
+        use tower::ServiceExt;
+
+        let (app, user_id) = create_app_with_user_and_password("tester-1", "password1").await?;
+
+        // 4 件のうち 2 件を完了にする
+        for (i, completed) in [true, true, false, false].into_iter().enumerate() {
+            let req_body = format!(
+                r#"{{"text": "todo-{}", "label_ids": [], "assignee_id": "{}"}}"#,
+                i, user_id
+            );
+            let req = build_req_with_json("/todos", Method::POST, req_body)?;
+            let res = app.clone().oneshot(req).await?;
+            let created: Value = res_to_struct(res).await?;
+            if completed {
+                let req = build_req_with_json(
+                    &format!("/todos/{}", created["id"].as_str().unwrap()),
+                    Method::PATCH,
+                    r#"{"completed": true}"#.to_string(),
+                )?;
+                let res = app.clone().oneshot(req).await?;
+                assert_eq!(StatusCode::OK, res.status());
+            }
+        }
+
+        // 統計は本人か管理者にだけ返す
+        let authorization = basic_authorization(&user_id, "password1");
+        let mut req = build_req_with_empty(&format!("/users/{}", user_id), Method::GET)?;
+        req.headers_mut()
+            .insert(header::AUTHORIZATION, authorization.parse()?);
+        let res = app.clone().oneshot(req).await?;
+        let user: Value = res_to_struct(res).await?;
+        assert!(user.get("completion_rate").is_none());
+        assert!(user.get("current_streak").is_none());
+
+        let mut req = build_req_with_empty(
+            &format!("/users/{}?include_stats=true", user_id),
+            Method::GET,
+        )?;
+        req.headers_mut()
+            .insert(header::AUTHORIZATION, authorization.parse()?);
+        let res = app.oneshot(req).await?;
+        assert_eq!(StatusCode::OK, res.status());
+        let user: Value = res_to_struct(res).await?;
+        assert_eq!(0.5, user["completion_rate"]);
+        assert_eq!(1, user["current_streak"]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_point_to_created_resource_in_location_headers() -> Result<()> {
+        use serde_json::Value;
+        use tower::ServiceExt;
+
+        use super::{create_app, ArgCreateApp};
+        use crate::app_config::AppConfig;
+
+        let app = create_app(ArgCreateApp::default().app_config(AppConfig {
+            base_url: "https://example.com".to_string(),
+            ..AppConfig::default()
+        }));
+
+        for (uri, req_body, collection) in [
+            (
+                "/todos",
+                r#"{"text": "should_return_created_todo", "label_ids": []}"#,
+                "todos",
+            ),
+            ("/labels", r#"{"name": "label-1"}"#, "labels"),
+            ("/users", r#"{"user_name": "tester-1"}"#, "users"),
+        ] {
+            let req = build_req_with_json(uri, Method::POST, req_body.to_string())?;
+            let res = app.clone().oneshot(req).await?;
+            assert_eq!(StatusCode::CREATED, res.status());
+            let location = res.headers()[header::LOCATION].to_str()?.to_string();
+            let content_location = res.headers()[header::CONTENT_LOCATION]
+                .to_str()?
+                .to_string();
+            let created: Value = res_to_struct(res).await?;
+            let id = created["id"].as_str().unwrap();
+
+            let expected = format!("https://example.com/{}/{}", collection, id);
+            assert_eq!(expected, location);
+            assert_eq!(expected, content_location);
+        }
+
+        // 既存のユーザーを返した場合は、作成していないので Location は付かない
+        let req = build_req_with_json(
+            "/users?if_exists=return",
+            Method::POST,
+            r#"{"user_name": "tester-1"}"#.to_string(),
+        )?;
+        let res = app.oneshot(req).await?;
+        assert_eq!(StatusCode::OK, res.status());
+        assert!(res.headers().get(header::LOCATION).is_none());
+        assert!(res.headers()[header::CONTENT_LOCATION]
+            .to_str()?
+            .starts_with("https://example.com/users/"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_capitalize_todo_text_following_app_config_unless_specified() -> Result<()> {
+        use serde_json::Value;
+        use tower::ServiceExt;
+
+        use super::{create_app, ArgCreateApp};
+        use crate::app_config::AppConfig;
+
+        let app = create_app(ArgCreateApp::default().app_config(AppConfig {
+            auto_sentence_case: true,
+            ..AppConfig::default()
+        }));
+
+        for (req_body, expected) in [
+            (r#"{"text": "buy milk", "label_ids": []}"#, "Buy milk"),
+            (
+                r#"{"text": "buy bread", "label_ids": [], "normalize_case": false}"#,
+                "buy bread",
+            ),
+        ] {
+            let req = build_req_with_json("/todos", Method::POST, req_body.to_string())?;
+            let res = app.clone().oneshot(req).await?;
+            assert_eq!(StatusCode::CREATED, res.status());
+            let created: Value = res_to_struct(res).await?;
+            assert_eq!(expected, created["text"]);
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_reject_todo_with_more_labels_than_app_config_allows() -> Result<()> {
+        use serde_json::Value;
+        use tower::ServiceExt;
+
+        use super::{create_app, ArgCreateApp};
+        use crate::app_config::AppConfig;
+
+        let app = create_app(ArgCreateApp::default().app_config(AppConfig {
+            max_labels_per_todo: 1,
+            ..AppConfig::default()
+        }));
+
+        let mut label_ids = Vec::new();
+        for name in ["label-a", "label-b"] {
+            let req_body = format!(r#"{{"name": "{}"}}"#, name);
+            let req = build_req_with_json("/labels", Method::POST, req_body)?;
+            let created: Value = res_to_struct(app.clone().oneshot(req).await?).await?;
+            label_ids.push(created["id"].as_str().unwrap().to_string());
+        }
+
+        let req_body = format!(
+            r#"{{"text": "todo-1", "label_ids": ["{}", "{}"]}}"#,
+            label_ids[0], label_ids[1]
+        );
+        let req = build_req_with_json("/todos", Method::POST, req_body)?;
+        let res = app.clone().oneshot(req).await?;
+        assert_eq!(StatusCode::UNPROCESSABLE_ENTITY, res.status());
+
+        let req_body = format!(r#"{{"text": "todo-1", "label_ids": ["{}"]}}"#, label_ids[0]);
+        let req = build_req_with_json("/todos", Method::POST, req_body)?;
+        let res = app.oneshot(req).await?;
+        assert_eq!(StatusCode::CREATED, res.status());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_report_health() -> Result<()> {
+        use serde_json::Value;
+        use tower::ServiceExt;
+
+        use super::{create_app, ArgCreateApp};
+        use crate::app_config::AppConfig;
+
+        let app = create_app(ArgCreateApp::default().app_config(AppConfig {
+            telemetry_id: "instance-a".to_string(),
+            ..AppConfig::default()
+        }));
+
+        let req = build_req_with_empty("/health", Method::GET)?;
+        let res = app.oneshot(req).await?;
+        assert_eq!(StatusCode::OK, res.status());
+        assert!(res.headers()["x-request-id"]
+            .to_str()?
+            .starts_with("instance-a-"));
+        let health: Value = res_to_struct(res).await?;
+        assert_eq!("healthy", health["status"]);
+        assert!(health["latency_ms"].is_u64());
+        assert_eq!("instance-a", health["instance_id"]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_get_only_orphaned_labels() -> Result<()> {
+        use serde_json::Value;
+        use tower::ServiceExt;
+
+        use super::{create_app, ArgCreateApp};
+
+        let app = create_app(ArgCreateApp::default());
+
+        let mut label_ids = vec![];
+        for name in ["label-a", "label-b", "label-c"] {
+            let req_body = format!(r#"{{"name": "{}"}}"#, name);
+            let req = build_req_with_json("/labels", Method::POST, req_body)?;
+            let res = app.clone().oneshot(req).await?;
+            let created: Value = res_to_struct(res).await?;
+            label_ids.push(created["id"].as_str().unwrap().to_string());
+        }
+
+        // label-a と label-b を todo に付け、label-c だけを残す
+        for (i, label_id) in label_ids[..2].iter().enumerate() {
+            let req_body = format!(r#"{{"text": "todo-{}", "label_ids": ["{}"]}}"#, i, label_id);
+            let req = build_req_with_json("/todos", Method::POST, req_body)?;
+            let res = app.clone().oneshot(req).await?;
+            assert_eq!(StatusCode::CREATED, res.status());
+        }
+
+        let req = build_req_with_empty("/labels/orphaned", Method::GET)?;
+        let res = app.oneshot(req).await?;
+        assert_eq!(StatusCode::OK, res.status());
+        let labels: Vec<Value> = res_to_struct(res).await?;
+        assert_eq!(1, labels.len());
+        assert_eq!(label_ids[2], labels[0]["id"]);
+        assert_eq!("label-c", labels[0]["name"]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_get_labels_in_created_order_if_sorted_by_position() -> Result<()> {
+        use serde_json::Value;
+        use tower::ServiceExt;
+
+        use super::{create_app, ArgCreateApp};
+
+        let app = create_app(ArgCreateApp::default());
+
+        let names = ["label-b", "label-c", "label-a"];
+        for name in names {
+            let req_body = format!(r#"{{"name": "{}"}}"#, name);
+            let req = build_req_with_json("/labels", Method::POST, req_body)?;
+            let res = app.clone().oneshot(req).await?;
+            assert_eq!(StatusCode::CREATED, res.status());
+        }
+
+        let req = build_req_with_empty("/labels?sort=position", Method::GET)?;
+        let res = app.clone().oneshot(req).await?;
+        assert_eq!(StatusCode::OK, res.status());
+        let page: Value = res_to_struct(res).await?;
+        let labels: Vec<Value> = serde_json::from_value(page["data"].clone())?;
+        assert_eq!(
+            names.to_vec(),
+            labels
+                .iter()
+                .map(|label| label["name"].as_str().unwrap())
+                .collect::<Vec<_>>()
+        );
+
+        // 未知の並び順は受け付けない
+        let req = build_req_with_empty("/labels?sort=name", Method::GET)?;
+        let res = app.oneshot(req).await?;
+        assert_eq!(StatusCode::BAD_REQUEST, res.status());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_delete_labels_at_once_unless_in_use() -> Result<()> {
+        use serde_json::Value;
+        use tower::ServiceExt;
+        use uuid::Uuid;
+
+        use super::{create_app, ArgCreateApp};
+
+        let app = create_app(ArgCreateApp::default());
+
+        let mut label_ids = vec![];
+        for name in ["label-a", "label-b"] {
+            let req_body = format!(r#"{{"name": "{}"}}"#, name);
+            let req = build_req_with_json("/labels", Method::POST, req_body)?;
+            let res = app.clone().oneshot(req).await?;
+            let created: Value = res_to_struct(res).await?;
+            label_ids.push(created["id"].as_str().unwrap().to_string());
+        }
+        // label-b だけを todo に付ける
+        let req_body = format!(r#"{{"text": "todo", "label_ids": ["{}"]}}"#, label_ids[1]);
+        let req = build_req_with_json("/todos", Method::POST, req_body)?;
+        let res = app.clone().oneshot(req).await?;
+        assert_eq!(StatusCode::CREATED, res.status());
+
+        let missing_id = Uuid::new_v4().to_string();
+        let req_body = format!(
+            r#"{{"ids": ["{}", "{}", "{}", "not-a-uuid"]}}"#,
+            label_ids[0], label_ids[1], missing_id
+        );
+        let req = build_req_with_json("/labels/bulk", Method::DELETE, req_body)?;
+        let res = app.clone().oneshot(req).await?;
+        assert_eq!(StatusCode::MULTI_STATUS, res.status());
+        let body: Value = res_to_struct(res).await?;
+        assert_eq!(serde_json::json!([label_ids[0]]), body["deleted"]);
+        assert_eq!(
+            serde_json::json!([{ "id": label_ids[1], "todo_count": 1 }]),
+            body["in_use"]
+        );
+        assert_eq!(serde_json::json!([missing_id]), body["not_found"]);
+        assert_eq!(3, body["parse_errors"][0]["index"]);
+
+        // force を指定すれば、todo に付いていても削除する
+        let req_body = format!(r#"{{"ids": ["{}"], "force": true}}"#, label_ids[1]);
+        let req = build_req_with_json("/labels/bulk", Method::DELETE, req_body)?;
+        let res = app.clone().oneshot(req).await?;
+        let body: Value = res_to_struct(res).await?;
+        assert_eq!(serde_json::json!([label_ids[1]]), body["deleted"]);
+
+        let req = build_req_with_empty(&format!("/labels/{}", label_ids[1]), Method::GET)?;
+        let res = app.oneshot(req).await?;
+        assert_eq!(StatusCode::NOT_FOUND, res.status());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_keep_label_order_of_todo() -> Result<()> {
+        use serde_json::Value;
+        use tower::ServiceExt;
+
+        use super::{create_app, ArgCreateApp};
+
+        let app = create_app(ArgCreateApp::default());
+
+        let mut label_ids = vec![];
+        for name in ["label-a", "label-b", "label-c"] {
+            let req_body = format!(r#"{{"name": "{}"}}"#, name);
+            let req = build_req_with_json("/labels", Method::POST, req_body)?;
+            let res = app.clone().oneshot(req).await?;
+            let created: Value = res_to_struct(res).await?;
+            label_ids.push(created["id"].as_str().unwrap().to_string());
+        }
+        let label_names = |todo: &Value| -> Vec<String> {
+            todo["labels"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|label| label["name"].as_str().unwrap().to_string())
+                .collect()
+        };
+
+        // 1. Create with [A, B, C]
+        let req_body = format!(
+            r#"{{"text": "todo-1", "label_ids": ["{}", "{}", "{}"]}}"#,
+            label_ids[0], label_ids[1], label_ids[2]
+        );
+        let req = build_req_with_json("/todos", Method::POST, req_body)?;
+        let res = app.clone().oneshot(req).await?;
+        let created: Value = res_to_struct(res).await?;
+        assert_eq!(vec!["label-a", "label-b", "label-c"], label_names(&created));
+
+        // 2. Update with [C, A]
+        let todo_id = created["id"].as_str().unwrap();
+        let req_body = format!(
+            r#"{{"label_ids": ["{}", "{}"]}}"#,
+            label_ids[2], label_ids[0]
+        );
+        let req = build_req_with_json(&format!("/todos/{}", todo_id), Method::PATCH, req_body)?;
+        let res = app.clone().oneshot(req).await?;
+        let updated: Value = res_to_struct(res).await?;
+        assert_eq!(vec!["label-c", "label-a"], label_names(&updated));
+
+        let req = build_req_with_empty(&format!("/todos/{}", todo_id), Method::GET)?;
+        let res = app.oneshot(req).await?;
+        let found: Value = res_to_struct(res).await?;
+        assert_eq!(vec!["label-c", "label-a"], label_names(&found));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_filter_todos_by_label_slug() -> Result<()> {
+        use serde_json::Value;
+        use tower::ServiceExt;
+
+        use super::{create_app, ArgCreateApp};
+
+        let app = create_app(ArgCreateApp::default());
+
+        for req_body in [
+            r#"{"text": "todo-1", "label_ids": [], "label_names": ["Work In Progress"]}"#,
+            r#"{"text": "todo-2", "label_ids": [], "label_names": ["Done"]}"#,
+        ] {
+            let req = build_req_with_json("/todos", Method::POST, req_body.to_string())?;
+            let res = app.clone().oneshot(req).await?;
+            assert_eq!(StatusCode::CREATED, res.status());
+        }
+
+        let req = build_req_with_empty("/todos?label_slug=work-in-progress", Method::GET)?;
+        let res = app.oneshot(req).await?;
+        assert_eq!(StatusCode::OK, res.status());
+        let page: Value = res_to_struct(res).await?;
+        let todos: Vec<Value> = serde_json::from_value(page["data"].clone())?;
+        assert_eq!(1, todos.len());
+        assert_eq!("todo-1", todos[0]["text"]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_filter_todos_by_multiple_labels() -> Result<()> {
+        use serde_json::Value;
+        use tower::ServiceExt;
+
+        use super::{create_app, ArgCreateApp};
+
+        let app = create_app(ArgCreateApp::default());
+
+        let mut label_ids = vec![];
+        for name in ["label-a", "label-b"] {
+            let req_body = format!(r#"{{"name": "{}"}}"#, name);
+            let req = build_req_with_json("/labels", Method::POST, req_body)?;
+            let res = app.clone().oneshot(req).await?;
+            let created: Value = res_to_struct(res).await?;
+            label_ids.push(created["id"].as_str().unwrap().to_string());
+        }
+        for req_body in [
+            format!(r#"{{"text": "todo-1", "label_ids": ["{}"]}}"#, label_ids[0]),
+            format!(
+                r#"{{"text": "todo-2", "label_ids": ["{}", "{}"]}}"#,
+                label_ids[0], label_ids[1]
+            ),
+            r#"{"text": "todo-3", "label_ids": []}"#.to_string(),
+        ] {
+            let req = build_req_with_json("/todos", Method::POST, req_body)?;
+            let res = app.clone().oneshot(req).await?;
+            assert_eq!(StatusCode::CREATED, res.status());
+        }
+        let label_ids = label_ids.join(",");
+
+        // A AND B
+        let req = build_req_with_empty(&format!("/todos?label_ids={}", label_ids), Method::GET)?;
+        let res = app.clone().oneshot(req).await?;
+        assert_eq!(StatusCode::OK, res.status());
+        let page: Value = res_to_struct(res).await?;
+        let todos: Vec<Value> = serde_json::from_value(page["data"].clone())?;
+        assert_eq!(1, todos.len());
+        assert_eq!("todo-2", todos[0]["text"]);
+
+        // A OR B
+        let req = build_req_with_empty(
+            &format!("/todos?label_ids={}&label_operator=or&page=1", label_ids),
+            Method::GET,
+        )?;
+        let res = app.clone().oneshot(req).await?;
+        assert_eq!(StatusCode::OK, res.status());
+        let page: Value = res_to_struct(res).await?;
+        let todos: Vec<Value> = serde_json::from_value(page["data"].clone())?;
+        assert_eq!(2, todos.len());
+
+        // 不正なラベル id
+        let req = build_req_with_empty("/todos?label_ids=not-a-uuid", Method::GET)?;
+        let res = app.oneshot(req).await?;
+        assert_eq!(StatusCode::BAD_REQUEST, res.status());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_return_multi_status_if_some_todos_cannot_be_created() -> Result<()> {
+        use serde_json::Value;
+        use tower::ServiceExt;
+
+        use super::{create_app, ArgCreateApp};
+
+        let app = create_app(ArgCreateApp::default());
+
+        let req_body = r#"{"todos": [
+            {"text": "todo-0", "label_ids": []},
+            {"text": "", "label_ids": []},
+            {"text": "todo-2", "label_ids": []}
+        ]}"#;
+        let req = build_req_with_json("/todos/bulk", Method::POST, req_body.to_string())?;
+        let res = app.clone().oneshot(req).await?;
+        assert_eq!(StatusCode::MULTI_STATUS, res.status());
+        let body: Value = res_to_struct(res).await?;
+        let created_texts: Vec<&str> = body["created"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|todo| todo["text"].as_str().unwrap())
+            .collect();
+        assert_eq!(vec!["todo-0", "todo-2"], created_texts);
+        assert_eq!(1, body["errors"].as_array().unwrap().len());
+        assert_eq!(1, body["errors"][0]["index"]);
+
+        // すべて作成できた場合は 201
+        let req_body = r#"{"todos": [{"text": "todo-3", "label_ids": []}]}"#;
+        let req = build_req_with_json("/todos/bulk", Method::POST, req_body.to_string())?;
+        let res = app.oneshot(req).await?;
+        assert_eq!(StatusCode::CREATED, res.status());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_return_not_modified_while_todos_are_unchanged() -> Result<()> {
+        use axum::http::HeaderValue;
+        use serde_json::Value;
+        use tower::ServiceExt;
+
+        use super::{create_app, ArgCreateApp};
+
+        let app = create_app(ArgCreateApp::default());
+        let get_todos = |etag: Option<HeaderValue>| -> Result<Request<Body>> {
+            let mut req = build_req_with_empty("/todos", Method::GET)?;
+            if let Some(etag) = etag {
+                req.headers_mut().insert(header::IF_NONE_MATCH, etag);
+            }
+            Ok(req)
+        };
+
+        let req_body = r#"{"text": "todo-1", "label_ids": []}"#;
+        let req = build_req_with_json("/todos", Method::POST, req_body.to_string())?;
+        let created: Value = res_to_struct(app.clone().oneshot(req).await?).await?;
+
+        let res = app.clone().oneshot(get_todos(None)?).await?;
+        assert_eq!(StatusCode::OK, res.status());
+        let etag = res.headers().get(header::ETAG).unwrap().clone();
+
+        // 変わっていなければ本文を返さない
+        let res = app.clone().oneshot(get_todos(Some(etag.clone()))?).await?;
+        assert_eq!(StatusCode::NOT_MODIFIED, res.status());
+        assert_eq!(Some(&etag), res.headers().get(header::ETAG));
+        assert!(hyper::body::to_bytes(res.into_body()).await?.is_empty());
+
+        // 作成・更新・削除のたびに ETag が変わる
+        let todo_uri = format!("/todos/{}", created["id"].as_str().unwrap());
+        let mut etags = vec![etag];
+        for req in [
+            build_req_with_json(
+                "/todos",
+                Method::POST,
+                r#"{"text": "todo-2", "label_ids": []}"#.to_string(),
+            )?,
+            build_req_with_json(
+                &todo_uri,
+                Method::PATCH,
+                r#"{"text": "todo-1-updated"}"#.to_string(),
+            )?,
+            build_req_with_empty(&todo_uri, Method::DELETE)?,
+        ] {
+            let method = req.method().clone();
+            let res = app.clone().oneshot(req).await?;
+            assert!(res.status().is_success(), "{}", method);
+
+            let previous_etag = etags.last().unwrap().clone();
+            let res = app.clone().oneshot(get_todos(Some(previous_etag))?).await?;
+            assert_eq!(StatusCode::OK, res.status(), "{}", method);
+            let etag = res.headers().get(header::ETAG).unwrap().clone();
+            assert!(!etags.contains(&etag), "{}", method);
+            etags.push(etag);
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_get_todos_at_once_and_report_ids_not_found() -> Result<()> {
+        use serde_json::Value;
+        use tower::ServiceExt;
+        use uuid::Uuid;
+
+        use super::{create_app, ArgCreateApp};
+
+        let app = create_app(ArgCreateApp::default());
+
+        let mut todo_ids = vec![];
+        for i in 0..3 {
+            let req_body = format!(r#"{{"text": "todo-{}", "label_ids": []}}"#, i);
+            let req = build_req_with_json("/todos", Method::POST, req_body)?;
+            let res = app.clone().oneshot(req).await?;
+            let created: Value = res_to_struct(res).await?;
+            todo_ids.push(created["id"].as_str().unwrap().to_string());
+        }
+        let missing_ids = vec![Uuid::new_v4().to_string(), Uuid::new_v4().to_string()];
+
+        let ids = [&todo_ids[..], &missing_ids[..]].concat().join(",");
+        let req = build_req_with_empty(&format!("/todos/bulk?ids={}", ids), Method::GET)?;
+        let res = app.clone().oneshot(req).await?;
+        assert_eq!(StatusCode::OK, res.status());
+        let body: Value = res_to_struct(res).await?;
+        let found_ids: Vec<&str> = body["todos"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|todo| todo["id"].as_str().unwrap())
+            .collect();
+        assert_eq!(todo_ids, found_ids);
+        assert_eq!(serde_json::json!(missing_ids), body["not_found_ids"]);
+
+        let req = build_req_with_empty("/todos/bulk?ids=not-a-uuid", Method::GET)?;
+        let res = app.oneshot(req).await?;
+        assert_eq!(StatusCode::BAD_REQUEST, res.status());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_add_pagination_meta_to_todo_list() -> Result<()> {
+        use serde_json::Value;
+        use tower::ServiceExt;
+
+        use super::{create_app, ArgCreateApp};
+
+        let app = create_app(ArgCreateApp::default());
+
+        for i in 1..=25 {
+            let req_body = format!(r#"{{"text": "todo-{}", "label_ids": []}}"#, i);
+            let req = build_req_with_json("/todos", Method::POST, req_body)?;
+            let res = app.clone().oneshot(req).await?;
+            assert_eq!(StatusCode::CREATED, res.status());
+        }
+
+        let req = build_req_with_empty("/todos?page=2&per_page=10", Method::GET)?;
+        let res = app.clone().oneshot(req).await?;
+        assert_eq!(StatusCode::OK, res.status());
+        let page: Value = res_to_struct(res).await?;
+        assert_eq!(10, page["data"].as_array().unwrap().len());
+        assert_eq!(25, page["meta"]["total"]);
+        assert_eq!(2, page["meta"]["page"]);
+        assert_eq!(10, page["meta"]["per_page"]);
+        assert_eq!(3, page["meta"]["total_pages"]);
+
+        // ページ指定が無くストリームで返す場合は、全件を 1 ページとして扱う
+        let req = build_req_with_empty("/todos", Method::GET)?;
+        let res = app.oneshot(req).await?;
+        let page: Value = res_to_struct(res).await?;
+        assert_eq!(25, page["data"].as_array().unwrap().len());
+        assert_eq!(25, page["meta"]["total"]);
+        assert_eq!(1, page["meta"]["total_pages"]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_add_list_view_fields_to_todo_list() -> Result<()> {
+        use serde_json::Value;
+        use tower::ServiceExt;
+
+        let (app, user_id) = create_app_with_user_and_password("tester-1", "password1").await?;
+
+        let req_body = format!(
+            r#"{{"text": "todo-1", "label_ids": [], "label_names": ["label-1"], "due_date": "2000-01-01", "assignee_id": "{}"}}"#,
+            user_id
+        );
+        let req = build_req_with_json("/todos", Method::POST, req_body)?;
+        let res = app.clone().oneshot(req).await?;
+        assert_eq!(StatusCode::CREATED, res.status());
+
+        // ストリームで返す場合もページ分割して返す場合も、同じ項目が付く
+        for uri in ["/todos", "/todos?page=1"] {
+            let req = build_req_with_empty(uri, Method::GET)?;
+            let res = app.clone().oneshot(req).await?;
+            let page: Value = res_to_struct(res).await?;
+            let todos: Vec<Value> = serde_json::from_value(page["data"].clone())?;
+            assert_eq!("todo-1", todos[0]["text"]);
+            assert_eq!(true, todos[0]["is_overdue"]);
+            assert_eq!(serde_json::json!(["label-1"]), todos[0]["label_names"]);
+            assert_eq!("tester-1", todos[0]["assignee_name"]);
+            assert!(todos[0]["days_until_due"].as_i64().unwrap() < 0);
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_return_links_saved_on_creation() -> Result<()> {
+        use serde_json::Value;
+        use tower::ServiceExt;
+
+        use super::{create_app, ArgCreateApp};
+
+        let app = create_app(ArgCreateApp::default());
+
+        let req_body = r#"{"text": "see http://example.com for details", "label_ids": []}"#;
+        let req = build_req_with_json("/todos", Method::POST, req_body.to_string())?;
+        let res = app.clone().oneshot(req).await?;
+        assert_eq!(StatusCode::CREATED, res.status());
+        let created: Value = res_to_struct(res).await?;
+
+        let req = build_req_with_empty(
+            &format!("/todos/{}", created["id"].as_str().unwrap()),
+            Method::GET,
+        )?;
+        let res = app.oneshot(req).await?;
+        assert_eq!(StatusCode::OK, res.status());
+        let todo: Value = res_to_struct(res).await?;
+        let links: Vec<String> = serde_json::from_value(todo["links"].clone())?;
+        assert!(links
+            .iter()
+            .any(|link| link.starts_with("http://example.com")));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_get_only_todos_due_soon() -> Result<()> {
+        use chrono::{Duration, Utc};
+        use serde_json::Value;
+        use tower::ServiceExt;
+
+        use super::{create_app, ArgCreateApp};
+
+        let app = create_app(ArgCreateApp::default());
+
+        let today = Utc::now().date_naive();
+        for (text, days) in [("due in 3 days", 3), ("due in 8 days", 8), ("overdue", -1)] {
+            let req_body = format!(
+                r#"{{"text": "{}", "label_ids": [], "due_date": "{}"}}"#,
+                text,
+                today + Duration::days(days)
+            );
+            let req = build_req_with_json("/todos", Method::POST, req_body)?;
+            let res = app.clone().oneshot(req).await?;
+            assert_eq!(StatusCode::CREATED, res.status());
+        }
+
+        let req = build_req_with_empty("/todos/due-soon?days=7", Method::GET)?;
+        let res = app.clone().oneshot(req).await?;
+        assert_eq!(StatusCode::OK, res.status());
+        let page: Value = res_to_struct(res).await?;
+        let todos: Vec<Value> = serde_json::from_value(page["data"].clone())?;
+        assert_eq!(1, todos.len());
+        assert_eq!("due in 3 days", todos[0]["text"]);
+        assert_eq!(1, page["meta"]["total"]);
+
+        let req = build_req_with_empty("/todos/due-soon?days=0", Method::GET)?;
+        let res = app.oneshot(req).await?;
+        assert_eq!(StatusCode::BAD_REQUEST, res.status());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_get_similar_todos() -> Result<()> {
+        use serde_json::Value;
+        use tower::ServiceExt;
+
+        use super::{create_app, ArgCreateApp};
+
+        let app = create_app(ArgCreateApp::default());
+
+        let mut ids = vec![];
+        for text in ["Buy groceries", "Buy grocery items", "Walk the dog"] {
+            let req_body = format!(r#"{{"text": "{}", "label_ids": []}}"#, text);
+            let req = build_req_with_json("/todos", Method::POST, req_body)?;
+            let res = app.clone().oneshot(req).await?;
+            assert_eq!(StatusCode::CREATED, res.status());
+            let todo: Value = res_to_struct(res).await?;
+            ids.push(todo["id"].as_str().unwrap().to_string());
+        }
+
+        // 互いに似ている todo として返される
+        for (id, expected) in [(&ids[0], "Buy grocery items"), (&ids[1], "Buy groceries")] {
+            let uri = format!("/todos/{}/similar?threshold=0.6&limit=5", id);
+            let req = build_req_with_empty(&uri, Method::GET)?;
+            let res = app.clone().oneshot(req).await?;
+            assert_eq!(StatusCode::OK, res.status());
+            let similar_todos: Vec<Value> = res_to_struct(res).await?;
+            assert_eq!(1, similar_todos.len());
+            assert_eq!(expected, similar_todos[0]["todo"]["text"]);
+            assert!(similar_todos[0]["similarity_score"].as_f64().unwrap() >= 0.6);
+        }
+
+        let uri = format!("/todos/{}/similar?threshold=2", ids[0]);
+        let req = build_req_with_empty(&uri, Method::GET)?;
+        let res = app.clone().oneshot(req).await?;
+        assert_eq!(StatusCode::BAD_REQUEST, res.status());
+
+        let uri = format!("/todos/{}/similar", uuid::Uuid::new_v4());
+        let req = build_req_with_empty(&uri, Method::GET)?;
+        let res = app.oneshot(req).await?;
+        assert_eq!(StatusCode::NOT_FOUND, res.status());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_ignore_trailing_and_duplicated_slashes() -> Result<()> {
+        use serde_json::Value;
+        use tower::ServiceExt;
+
+        use super::{create_app, ArgCreateApp};
+
+        let app = create_app(ArgCreateApp::default());
+        let req = build_req_with_json(
+            "/todos",
+            Method::POST,
+            r#"{"text": "todo-1", "label_ids": []}"#.to_string(),
+        )?;
+        let res = app.clone().oneshot(req).await?;
+        assert_eq!(StatusCode::CREATED, res.status());
+        let todo: Value = res_to_struct(res).await?;
+        let todo_id = todo["id"].as_str().unwrap();
+
+        let get = |uri: String| {
+            let app = app.clone();
+            async move {
+                let res = app
+                    .oneshot(build_req_with_empty(&uri, Method::GET)?)
+                    .await?;
+                let status = res.status();
+                let body: Value = res_to_struct(res).await?;
+                Ok::<_, anyhow::Error>((status, body))
+            }
+        };
+
+        let expected = get("/todos".to_string()).await?;
+        assert_eq!(StatusCode::OK, expected.0);
+        assert_eq!(expected, get("/todos/".to_string()).await?);
+
+        let expected = get(format!("/todos/{}", todo_id)).await?;
+        assert_eq!(StatusCode::OK, expected.0);
+        assert_eq!(expected, get(format!("/todos/{}/", todo_id)).await?);
+        assert_eq!(expected, get(format!("/todos//{}", todo_id)).await?);
+
+        // `/` はそのままルーティングされる
+        let res = app.oneshot(build_req_with_empty("/", Method::GET)?).await?;
+        assert_eq!(StatusCode::OK, res.status());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_export_todos_as_markdown() -> Result<()> {
+        use tower::ServiceExt;
+
+        use super::{create_app, ArgCreateApp};
+
+        let app = create_app(ArgCreateApp::default());
+        let req = build_req_with_json(
+            "/todos",
+            Method::POST,
+            r#"{"text": "todo-1", "label_ids": [], "due_date": "2024-01-15"}"#.to_string(),
+        )?;
+        let res = app.clone().oneshot(req).await?;
+        assert_eq!(StatusCode::CREATED, res.status());
+
+        let req = build_req_with_empty("/todos/export?format=markdown", Method::GET)?;
+        let res = app.clone().oneshot(req).await?;
+        assert_eq!(StatusCode::OK, res.status());
+        assert_eq!(
+            "text/markdown; charset=utf-8",
+            res.headers().get(header::CONTENT_TYPE).unwrap().to_str()?
+        );
+        let bytes = hyper::body::to_bytes(res.into_body()).await?;
+        assert_eq!(
+            "- [ ] todo-1 (due: 2024-01-15)",
+            String::from_utf8(bytes.to_vec())?
+        );
+
+        let req = build_req_with_empty("/todos/export", Method::GET)?;
+        let res = app.clone().oneshot(req).await?;
+        assert_eq!(
+            "application/x-ndjson",
+            res.headers().get(header::CONTENT_TYPE).unwrap().to_str()?
+        );
+
+        let req = build_req_with_empty("/todos/export?format=csv", Method::GET)?;
+        let res = app.oneshot(req).await?;
+        assert_eq!(StatusCode::BAD_REQUEST, res.status());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_filter_and_sort_todos_by_typed_query_params() -> Result<()> {
+        use serde_json::Value;
+        use tower::ServiceExt;
+
+        use super::{create_app, ArgCreateApp};
+
+        let app = create_app(ArgCreateApp::default());
+        for req_body in [
+            r#"{"text": "todo-1", "label_ids": [], "due_date": "2023-11-02"}"#,
+            r#"{"text": "todo-2", "label_ids": []}"#,
+            r#"{"text": "todo-3", "label_ids": [], "due_date": "2023-11-01"}"#,
+        ] {
+            let req = build_req_with_json("/todos", Method::POST, req_body.to_string())?;
+            let res = app.clone().oneshot(req).await?;
+            assert_eq!(StatusCode::CREATED, res.status());
+        }
+
+        let req = build_req_with_empty("/todos?sort=due_date&completed=false", Method::GET)?;
+        let res = app.clone().oneshot(req).await?;
+        assert_eq!(StatusCode::OK, res.status());
+        let page: Value = res_to_struct(res).await?;
+        let texts: Vec<&str> = page["data"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|todo| todo["text"].as_str().unwrap())
+            .collect();
+        assert_eq!(vec!["todo-3", "todo-1", "todo-2"], texts);
+
+        let req = build_req_with_empty("/todos?completed=true", Method::GET)?;
+        let res = app.clone().oneshot(req).await?;
+        let page: Value = res_to_struct(res).await?;
+        assert!(page["data"].as_array().unwrap().is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_return_problem_details_for_malformed_query_params() -> Result<()> {
+        use serde_json::Value;
+        use tower::ServiceExt;
+
+        use super::{create_app, ArgCreateApp};
+
+        let app = create_app(ArgCreateApp::default());
+        for uri in [
+            "/todos?page=abc",
+            "/todos?completed=maybe",
+            "/todos?sort=text",
+        ] {
+            let req = build_req_with_empty(uri, Method::GET)?;
+            let res = app.clone().oneshot(req).await?;
+            assert_eq!(StatusCode::BAD_REQUEST, res.status(), "{}", uri);
+            assert_eq!(
+                "application/problem+json",
+                res.headers().get(header::CONTENT_TYPE).unwrap().to_str()?
+            );
+            let problem: Value = res_to_struct(res).await?;
+            assert_eq!(400, problem["status"]);
+            assert!(problem["detail"]
+                .as_str()
+                .unwrap()
+                .starts_with("Query parameters are malformed"));
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_return_409_if_todo_is_updated_with_stale_version() -> Result<()> {
+        use serde_json::Value;
+        use tower::ServiceExt;
+
+        use super::{create_app, ArgCreateApp};
+
+        let app = create_app(ArgCreateApp::default());
+        let req = build_req_with_json(
+            "/todos",
+            Method::POST,
+            r#"{"text": "todo-1", "label_ids": []}"#.to_string(),
+        )?;
+        let res = app.clone().oneshot(req).await?;
+        let todo: Value = res_to_struct(res).await?;
+        let todo_id = todo["id"].as_str().unwrap();
+        let version = todo["version"].as_i64().unwrap();
+
+        // 同じ版を読み出した 2 つのクライアントが、続けて更新する
+        let update = |text: &str| {
+            build_req_with_json(
+                &format!("/todos/{}", todo_id),
+                Method::PATCH,
+                format!(r#"{{"text": "{}", "version": {}}}"#, text, version),
+            )
+        };
+        let res = app.clone().oneshot(update("todo-2")?).await?;
+        assert_eq!(StatusCode::OK, res.status());
+        let updated: Value = res_to_struct(res).await?;
+        assert_eq!(version + 1, updated["version"]);
+
+        let res = app.clone().oneshot(update("todo-3")?).await?;
+        assert_eq!(StatusCode::CONFLICT, res.status());
+        assert_eq!(
+            "application/problem+json",
+            res.headers().get(header::CONTENT_TYPE).unwrap().to_str()?
+        );
+
+        let req = build_req_with_empty(&format!("/todos/{}", todo_id), Method::GET)?;
+        let res = app.oneshot(req).await?;
+        let todo: Value = res_to_struct(res).await?;
+        assert_eq!("todo-2", todo["text"]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_record_changes_of_todo_in_audit_log() -> Result<()> {
+        use serde_json::Value;
+        use tower::ServiceExt;
+
+        let (app, user_id) = create_app_with_user_and_password("tester-1", "password1").await?;
+        let authorization = basic_authorization(&user_id, "password1");
+
+        let mut req = build_req_with_json(
+            "/todos",
+            Method::POST,
+            r#"{"text": "todo-1", "label_ids": []}"#.to_string(),
+        )?;
+        req.headers_mut()
+            .insert(header::AUTHORIZATION, authorization.parse()?);
+        let res = app.clone().oneshot(req).await?;
+        let todo: Value = res_to_struct(res).await?;
+        let todo_uri = format!("/todos/{}", todo["id"].as_str().unwrap());
+
+        let req = build_req_with_json(
+            &todo_uri,
+            Method::PATCH,
+            r#"{"text": "todo-2"}"#.to_string(),
+        )?;
+        let res = app.clone().oneshot(req).await?;
+        assert_eq!(StatusCode::OK, res.status());
+
+        let req = build_req_with_empty(&todo_uri, Method::DELETE)?;
+        let res = app.clone().oneshot(req).await?;
+        assert_eq!(StatusCode::NO_CONTENT, res.status());
+
+        // 削除した後も履歴は取得できる
+        let req = build_req_with_empty(&format!("{}/audit", todo_uri), Method::GET)?;
+        let res = app.oneshot(req).await?;
+        assert_eq!(StatusCode::OK, res.status());
+        let audit: Value = res_to_struct(res).await?;
+        let entries = audit["data"].as_array().unwrap();
+        let actions: Vec<&str> = entries
+            .iter()
+            .map(|entry| entry["action"].as_str().unwrap())
+            .collect();
+        assert_eq!(vec!["Created", "Updated", "Deleted"], actions);
+        assert!(entries
+            .windows(2)
+            .all(|pair| pair[0]["occurred_at"].as_str() <= pair[1]["occurred_at"].as_str()));
+        // 認証して作成した場合だけ、操作したユーザーが残る
+        assert_eq!("tester-1", entries[0]["actor_name"]);
+        assert_eq!(Value::Null, entries[1]["actor_name"]);
+        assert_eq!("text: 'todo-1' → 'todo-2'", entries[1]["changes_summary"]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_get_distinct_labels_of_todos_assigned_to_user() -> Result<()> {
+        use serde_json::Value;
+        use tower::ServiceExt;
+
+        let (app, user_id) = create_app_with_user_and_password("tester-1", "password1").await?;
+
+        let mut label_ids = vec![];
+        for name in ["B", "A"] {
+            let req = build_req_with_json(
+                "/labels",
+                Method::POST,
+                format!(r#"{{"name": "{}"}}"#, name),
+            )?;
+            let res = app.clone().oneshot(req).await?;
+            let label: Value = res_to_struct(res).await?;
+            label_ids.push(label["id"].as_str().unwrap().to_string());
+        }
+        let (label_b, label_a) = (&label_ids[0], &label_ids[1]);
+
+        // A の付いた todo と A, B の付いた todo を担当する
+        for (i, labels) in [
+            format!(r#""{}""#, label_a),
+            format!(r#""{}", "{}""#, label_a, label_b),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            let req_body = format!(
+                r#"{{"text": "todo-{}", "label_ids": [{}], "assignee_id": "{}"}}"#,
+                i, labels, user_id
+            );
+            let req = build_req_with_json("/todos", Method::POST, req_body)?;
+            let res = app.clone().oneshot(req).await?;
+            assert_eq!(StatusCode::CREATED, res.status());
+        }
+
+        let req = build_req_with_empty(&format!("/users/{}/labels", user_id), Method::GET)?;
+        let res = app.clone().oneshot(req).await?;
+        assert_eq!(StatusCode::OK, res.status());
+        let labels: Vec<Value> = res_to_struct(res).await?;
+        let names: Vec<&str> = labels
+            .iter()
+            .map(|label| label["name"].as_str().unwrap())
+            .collect();
+        assert_eq!(vec!["A", "B"], names);
+
+        let req = build_req_with_empty(
+            &format!("/users/{}/labels", uuid::Uuid::new_v4()),
+            Method::GET,
+        )?;
+        let res = app.oneshot(req).await?;
+        assert_eq!(StatusCode::NOT_FOUND, res.status());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_clear_completed_todos_only_of_authenticated_user() -> Result<()> {
+        use serde_json::Value;
+        use tower::ServiceExt;
+
+        let (app, user_id) = create_app_with_user_and_password("tester-1", "password1").await?;
+        let uri = format!("/users/{}/todos/completed", user_id);
+
+        // 4 件のうち 3 件を完了にする
+        for (i, completed) in [true, true, true, false].into_iter().enumerate() {
+            let req_body = format!(
+                r#"{{"text": "todo-{}", "label_ids": [], "assignee_id": "{}"}}"#,
+                i, user_id
+            );
+            let req = build_req_with_json("/todos", Method::POST, req_body)?;
+            let res = app.clone().oneshot(req).await?;
+            let created: Value = res_to_struct(res).await?;
+            if completed {
+                let req = build_req_with_json(
+                    &format!("/todos/{}", created["id"].as_str().unwrap()),
+                    Method::PATCH,
+                    r#"{"completed": true}"#.to_string(),
+                )?;
+                let res = app.clone().oneshot(req).await?;
+                assert_eq!(StatusCode::OK, res.status());
+            }
+        }
+
+        // 認証していない
+        let req = build_req_with_empty(&uri, Method::DELETE)?;
+        let res = app.clone().oneshot(req).await?;
+        assert_eq!(StatusCode::UNAUTHORIZED, res.status());
+
+        // 他のユーザーの todo は削除できない
+        let req = build_req_with_json(
+            "/users",
+            Method::POST,
+            r#"{"user_name": "tester-2"}"#.to_string(),
+        )?;
+        let res = app.clone().oneshot(req).await?;
+        let other_user: Value = res_to_struct(res).await?;
+        let mut req = build_req_with_empty(
+            &format!(
+                "/users/{}/todos/completed",
+                other_user["id"].as_str().unwrap()
+            ),
+            Method::DELETE,
+        )?;
+        req.headers_mut().insert(
+            header::AUTHORIZATION,
+            basic_authorization(&user_id, "password1").parse()?,
+        );
+        let res = app.clone().oneshot(req).await?;
+        assert_eq!(StatusCode::FORBIDDEN, res.status());
+
+        let mut req = build_req_with_empty(&uri, Method::DELETE)?;
+        req.headers_mut().insert(
+            header::AUTHORIZATION,
+            basic_authorization(&user_id, "password1").parse()?,
+        );
+        let res = app.clone().oneshot(req).await?;
+        assert_eq!(StatusCode::OK, res.status());
+        let body: Value = res_to_struct(res).await?;
+        assert_eq!(3, body["deleted_count"]);
+
+        let req = build_req_with_empty(&format!("/users/{}/assigned", user_id), Method::GET)?;
+        let res = app.oneshot(req).await?;
+        let todos: Vec<Value> = res_to_struct(res).await?;
+        assert_eq!(1, todos.len());
+        assert_eq!("todo-3", todos[0]["text"]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_complete_all_todos_with_label() -> Result<()> {
+        use serde_json::Value;
+        use tower::ServiceExt;
+
+        use super::{create_app, ArgCreateApp};
+
+        let app = create_app(ArgCreateApp::default());
+
+        let req = build_req_with_json(
+            "/labels",
+            Method::POST,
+            r#"{"name": "label-1"}"#.to_string(),
+        )?;
+        let res = app.clone().oneshot(req).await?;
+        let label: Value = res_to_struct(res).await?;
+        let label_id = label["id"].as_str().unwrap();
+
+        // ラベルの付いた 3 件のうち 1 件を完了にしておく
+        for (i, completed) in [false, false, true].into_iter().enumerate() {
+            let req_body = format!(r#"{{"text": "todo-{}", "label_ids": ["{}"]}}"#, i, label_id);
+            let req = build_req_with_json("/todos", Method::POST, req_body)?;
+            let res = app.clone().oneshot(req).await?;
+            let created: Value = res_to_struct(res).await?;
+            if completed {
+                let req = build_req_with_json(
+                    &format!("/todos/{}", created["id"].as_str().unwrap()),
+                    Method::PATCH,
+                    r#"{"completed": true}"#.to_string(),
+                )?;
+                let res = app.clone().oneshot(req).await?;
+                assert_eq!(StatusCode::OK, res.status());
+            }
+        }
+        // ラベルの付いていない todo は変更しない
+        let req = build_req_with_json(
+            "/todos",
+            Method::POST,
+            r#"{"text": "other", "label_ids": []}"#.to_string(),
+        )?;
+        app.clone().oneshot(req).await?;
+
+        let req = build_req_with_json(
+            &format!("/labels/{}/todos/complete", label_id),
+            Method::PATCH,
+            r#"{"completed": true}"#.to_string(),
+        )?;
+        let res = app.clone().oneshot(req).await?;
+        assert_eq!(StatusCode::OK, res.status());
+        let body: Value = res_to_struct(res).await?;
+        assert_eq!(2, body["updated_count"]);
+        assert_eq!(0, body["skipped_ids"].as_array().unwrap().len());
+
+        let req = build_req_with_empty("/todos", Method::GET)?;
+        let res = app.clone().oneshot(req).await?;
+        let todos: Value = res_to_struct(res).await?;
+        let todos = todos["data"].as_array().unwrap();
+        assert_eq!(4, todos.len());
+        for todo in todos {
+            assert_eq!(todo["text"] != "other", todo["completed"]);
+        }
+
+        // 存在しないラベル
+        let req = build_req_with_json(
+            &format!("/labels/{}/todos/complete", uuid::Uuid::new_v4()),
+            Method::PATCH,
+            r#"{"completed": true}"#.to_string(),
+        )?;
+        let res = app.oneshot(req).await?;
+        assert_eq!(StatusCode::NOT_FOUND, res.status());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_count_users_per_role_only_for_admin() -> Result<()> {
+        use std::collections::{hash_map::Entry, HashMap};
+
+        use serde_json::Value;
+        use tower::ServiceExt;
+
+        use crate::domain::{
+            models::{
+                credentials::{
+                    credential_repository::ICredentialRepository,
+                    password_credential::PasswordCredential,
+                },
+                users::{
+                    user::User, user_name::UserName, user_repository::IUserRepository,
+                    user_role::UserRole,
+                },
+            },
+            value_object::ValueObject,
+        };
+
+        use super::{create_app, ArgCreateApp};
+
+        // 管理者 2 人とメンバー 3 人を登録し、それぞれの 1 人目にパスワードを設定する
+        let arg_create_app = ArgCreateApp::default();
+        let mut user_ids = HashMap::new();
+        let roles = [
+            UserRole::Admin,
+            UserRole::Admin,
+            UserRole::Member,
+            UserRole::Member,
+            UserRole::Member,
+        ];
+        for (i, user_role) in roles.into_iter().enumerate() {
+            let mut user = User::new(UserName::new(format!("tester-{}", i))?)?;
+            user.user_role = user_role;
+            arg_create_app.user_repository.save(&user).await?;
+            if let Entry::Vacant(entry) = user_ids.entry(user_role) {
+                arg_create_app
+                    .credential_repository
+                    .save(&PasswordCredential::new(
+                        user.user_id().clone(),
+                        "password1",
+                    )?)
+                    .await?;
+                entry.insert(user.user_id().to_string());
+            }
+        }
+        let app = create_app(arg_create_app);
+
+        // 認証していない
+        let req = build_req_with_empty("/admin/stats/users", Method::GET)?;
+        let res = app.clone().oneshot(req).await?;
+        assert_eq!(StatusCode::UNAUTHORIZED, res.status());
+
+        // 管理者でない
+        let mut req = build_req_with_empty("/admin/stats/users", Method::GET)?;
+        req.headers_mut().insert(
+            header::AUTHORIZATION,
+            basic_authorization(&user_ids[&UserRole::Member], "password1").parse()?,
+        );
+        let res = app.clone().oneshot(req).await?;
+        assert_eq!(StatusCode::FORBIDDEN, res.status());
+
+        let mut req = build_req_with_empty("/admin/stats/users", Method::GET)?;
+        req.headers_mut().insert(
+            header::AUTHORIZATION,
+            basic_authorization(&user_ids[&UserRole::Admin], "password1").parse()?,
+        );
+        let res = app.oneshot(req).await?;
+        assert_eq!(StatusCode::OK, res.status());
+        let counts: Value = res_to_struct(res).await?;
+        assert_eq!(
+            serde_json::json!({ "Admin": 2, "Member": 3, "Viewer": 0 }),
+            counts
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_show_user_role_only_to_admin_or_user_themselves() -> Result<()> {
+        use serde_json::Value;
+        use tower::ServiceExt;
+
+        use crate::domain::{
+            models::{
+                credentials::{
+                    credential_repository::ICredentialRepository,
+                    password_credential::PasswordCredential,
+                },
+                users::{
+                    user::User, user_name::UserName, user_repository::IUserRepository,
+                    user_role::UserRole,
+                },
+            },
+            value_object::ValueObject,
+        };
+
+        use super::{create_app, ArgCreateApp};
+
+        let arg_create_app = ArgCreateApp::default();
+        let mut user_ids = Vec::new();
+        for (user_name, user_role) in [
+            ("admin", UserRole::Admin),
+            ("member", UserRole::Member),
+            ("user-b", UserRole::Viewer),
+        ] {
+            let mut user = User::new(UserName::new(user_name.to_string())?)?;
+            user.user_role = user_role;
+            arg_create_app.user_repository.save(&user).await?;
+            arg_create_app
+                .credential_repository
+                .save(&PasswordCredential::new(
+                    user.user_id().clone(),
+                    "password1",
+                )?)
+                .await?;
+            user_ids.push(user.user_id().to_string());
+        }
+        let [admin_id, member_id, user_b_id] = &user_ids[..] else {
+            unreachable!()
+        };
+        let app = create_app(arg_create_app);
+
+        for (requested_by, expected_role) in [
+            (Some(admin_id), Some("Viewer")),
+            (Some(user_b_id), Some("Viewer")),
+            (Some(member_id), None),
+            (None, None),
+        ] {
+            let mut req = build_req_with_empty(&format!("/users/{}", user_b_id), Method::GET)?;
+            if let Some(requested_by) = requested_by {
+                req.headers_mut().insert(
+                    header::AUTHORIZATION,
+                    basic_authorization(requested_by, "password1").parse()?,
+                );
+            }
+            let res = app.clone().oneshot(req).await?;
+            assert_eq!(StatusCode::OK, res.status());
+            let user: Value = res_to_struct(res).await?;
+            assert_eq!("user-b", user["name"]);
+            assert_eq!(expected_role, user.get("role").and_then(Value::as_str));
+        }
+
+        // 認証情報が誤っていれば 401 を返す
+        let mut req = build_req_with_empty(&format!("/users/{}", user_b_id), Method::GET)?;
+        req.headers_mut().insert(
+            header::AUTHORIZATION,
+            basic_authorization(admin_id, "wrong-password").parse()?,
+        );
+        let res = app.oneshot(req).await?;
+        assert_eq!(StatusCode::UNAUTHORIZED, res.status());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_show_user_roles_in_list_only_to_admin_or_user_themselves() -> Result<()> {
+        use serde_json::Value;
+        use tower::ServiceExt;
+
+        use crate::domain::{
+            models::{
+                credentials::{
+                    credential_repository::ICredentialRepository,
+                    password_credential::PasswordCredential,
+                },
+                users::{
+                    user::User, user_name::UserName, user_repository::IUserRepository,
+                    user_role::UserRole,
+                },
+            },
+            value_object::ValueObject,
+        };
+
+        use super::{create_app, ArgCreateApp};
+
+        let arg_create_app = ArgCreateApp::default();
+        let mut user_ids = Vec::new();
+        for (user_name, user_role) in [("admin", UserRole::Admin), ("member", UserRole::Member)] {
+            let mut user = User::new(UserName::new(user_name.to_string())?)?;
+            user.user_role = user_role;
+            arg_create_app.user_repository.save(&user).await?;
+            arg_create_app
+                .credential_repository
+                .save(&PasswordCredential::new(
+                    user.user_id().clone(),
+                    "password1",
+                )?)
+                .await?;
+            user_ids.push(user.user_id().to_string());
+        }
+        let [admin_id, member_id] = &user_ids[..] else {
+            unreachable!()
+        };
+        let app = create_app(arg_create_app);
+
+        // (名前, ロール) の組を名前順に返す
+        let roles_of = |users: &Value| -> Vec<(String, Option<String>)> {
+            let mut roles: Vec<(String, Option<String>)> = users
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|user| {
+                    (
+                        user["name"].as_str().unwrap().to_string(),
+                        user.get("role").and_then(Value::as_str).map(str::to_string),
+                    )
+                })
+                .collect();
+            roles.sort();
+            roles
+        };
+        for (requested_by, expected_roles) in [
+            (
+                Some(admin_id),
+                vec![
+                    ("admin".to_string(), Some("Admin".to_string())),
+                    ("member".to_string(), Some("Member".to_string())),
+                ],
+            ),
+            (
+                Some(member_id),
+                vec![
+                    ("admin".to_string(), None),
+                    ("member".to_string(), Some("Member".to_string())),
+                ],
+            ),
+            (
+                None,
+                vec![("admin".to_string(), None), ("member".to_string(), None)],
+            ),
+        ] {
+            for (uri, pointer) in [("/users", "/data"), ("/users/search?q=m", "")] {
+                let mut req = build_req_with_empty(uri, Method::GET)?;
+                if let Some(requested_by) = requested_by {
+                    req.headers_mut().insert(
+                        header::AUTHORIZATION,
+                        basic_authorization(requested_by, "password1").parse()?,
+                    );
+                }
+                let res = app.clone().oneshot(req).await?;
+                assert_eq!(StatusCode::OK, res.status(), "{}", uri);
+                let body: Value = res_to_struct(res).await?;
+                let users = body.pointer(pointer).unwrap();
+                let expected_roles: Vec<_> = expected_roles
+                    .iter()
+                    .filter(|(name, _)| uri == "/users" || name.starts_with('m'))
+                    .cloned()
+                    .collect();
+                assert_eq!(
+                    expected_roles,
+                    roles_of(users),
+                    "{} {:?}",
+                    uri,
+                    requested_by
+                );
+            }
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_get_recently_used_labels_first() -> Result<()> {
+        use std::time::Duration;
+
+        use serde_json::Value;
+        use tower::ServiceExt;
+
+        use super::{create_app, ArgCreateApp};
+
+        let app = create_app(ArgCreateApp::default());
+
+        let mut label_ids = Vec::new();
+        for name in ["label-a", "label-b"] {
+            let req_body = format!(r#"{{"name": "{}"}}"#, name);
+            let req = build_req_with_json("/labels", Method::POST, req_body)?;
+            let res = app.clone().oneshot(req).await?;
+            let created: Value = res_to_struct(res).await?;
+            label_ids.push(created["id"].as_str().unwrap().to_string());
+        }
+
+        // A を付け、次に B を付け、もう一度 B を付ける
+        for (i, label_id) in [&label_ids[0], &label_ids[1], &label_ids[1]]
+            .into_iter()
+            .enumerate()
+        {
+            let req_body = format!(r#"{{"text": "todo-{}", "label_ids": ["{}"]}}"#, i, label_id);
+            let req = build_req_with_json("/todos", Method::POST, req_body)?;
+            let res = app.clone().oneshot(req).await?;
+            assert_eq!(StatusCode::CREATED, res.status());
+            tokio::time::sleep(Duration::from_millis(1)).await;
+        }
+
+        let req = build_req_with_empty("/labels/recently-used?limit=10", Method::GET)?;
+        let res = app.oneshot(req).await?;
+        assert_eq!(StatusCode::OK, res.status());
+        let labels: Vec<Value> = res_to_struct(res).await?;
+        let ids: Vec<&str> = labels
+            .iter()
+            .map(|label| label["id"].as_str().unwrap())
+            .collect();
+        assert_eq!(vec![label_ids[1].as_str(), label_ids[0].as_str()], ids);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_invite_user_and_accept_invitation_only_once() -> Result<()> {
+        use serde_json::Value;
+        use tower::ServiceExt;
+
+        let (app, user_ids) = create_app_with_users_and_passwords(&[
+            ("tester-1", "password1"),
+            ("tester-2", "password2"),
+            ("tester-3", "password3"),
+        ])
+        .await?;
+        let (user_id, invitee_id, outsider_id) = (&user_ids[0], &user_ids[1], &user_ids[2]);
+        let list_id = uuid::Uuid::new_v4();
+        let uri = format!("/lists/{}/invitations", list_id);
+        let req_body = format!(
+            r#"{{"invitee_email": "invitee@example.com", "invitee_id": "{}"}}"#,
+            invitee_id
+        );
+
+        // 認証していない
+        let req = build_req_with_json(&uri, Method::POST, req_body.clone())?;
+        let res = app.clone().oneshot(req).await?;
+        assert_eq!(StatusCode::UNAUTHORIZED, res.status());
+
+        let mut req = build_req_with_json(&uri, Method::POST, req_body.clone())?;
+        req.headers_mut().insert(
+            header::AUTHORIZATION,
+            basic_authorization(user_id, "password1").parse()?,
+        );
+        let res = app.clone().oneshot(req).await?;
+        assert_eq!(StatusCode::CREATED, res.status());
+        let invitation: Value = res_to_struct(res).await?;
+        assert_eq!(list_id.to_string(), invitation["list_id"]);
+        assert_eq!(*invitee_id, invitation["invitee_id"]);
+        assert_eq!(*user_id, invitation["invited_by"]);
+        assert_eq!("pending", invitation["status"]);
+        let invite_id = invitation["id"].as_str().unwrap();
+
+        // リストのメンバーでないユーザーは招待できない
+        let mut req = build_req_with_json(&uri, Method::POST, req_body)?;
+        req.headers_mut().insert(
+            header::AUTHORIZATION,
+            basic_authorization(outsider_id, "password3").parse()?,
+        );
+        let res = app.clone().oneshot(req).await?;
+        assert_eq!(StatusCode::FORBIDDEN, res.status());
+
+        for (responder_id, password, action, status) in [
+            // 招待先のユーザー以外は応答できない
+            (user_id, "password1", "accept", StatusCode::FORBIDDEN),
+            (outsider_id, "password3", "decline", StatusCode::FORBIDDEN),
+            (invitee_id, "password2", "accept", StatusCode::OK),
+            // 応答済みの招待には応答できない
+            (invitee_id, "password2", "decline", StatusCode::CONFLICT),
+        ] {
+            let mut req = build_req_with_empty(
+                &format!("/invitations/{}/{}", invite_id, action),
+                Method::POST,
+            )?;
+            req.headers_mut().insert(
+                header::AUTHORIZATION,
+                basic_authorization(responder_id, password).parse()?,
+            );
+            let res = app.clone().oneshot(req).await?;
+            assert_eq!(status, res.status());
+        }
+
+        // 存在しない招待
+        let mut req = build_req_with_empty(
+            &format!("/invitations/{}/accept", uuid::Uuid::new_v4()),
+            Method::POST,
+        )?;
+        req.headers_mut().insert(
+            header::AUTHORIZATION,
+            basic_authorization(user_id, "password1").parse()?,
+        );
+        let res = app.oneshot(req).await?;
+        assert_eq!(StatusCode::NOT_FOUND, res.status());
+        Ok(())
+    }
 }
@@ -0,0 +1,2070 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::Extension,
+    response::{Html, IntoResponse},
+    Json,
+};
+use serde_json::{json, Value};
+
+use crate::app_config::AppConfig;
+
+// OpenAPI 3.0 形式の API 仕様を返す
+pub async fn openapi_json() -> impl IntoResponse {
+    Json(openapi_spec())
+}
+
+// `AppConfig::swagger_ui_assets_url` から読み込んだ Swagger UI で `/api-docs/openapi.json` を表示する
+pub async fn swagger_ui(Extension(app_config): Extension<Arc<AppConfig>>) -> impl IntoResponse {
+    Html(swagger_ui_html(&app_config.swagger_ui_assets_url))
+}
+
+fn swagger_ui_html(assets_url: &str) -> String {
+    format!(
+        r##"<!DOCTYPE html>
+<html lang="en">
+<head>
+  <meta charset="utf-8" />
+  <title>hello_world_axum_3 API docs</title>
+  <link rel="stylesheet" href="{assets_url}/swagger-ui.css" />
+</head>
+<body>
+  <div id="swagger-ui"></div>
+  <script src="{assets_url}/swagger-ui-bundle.js"></script>
+  <script>
+    window.onload = () => {{
+      window.ui = SwaggerUIBundle({{ url: "/api-docs/openapi.json", dom_id: "#swagger-ui" }});
+    }};
+  </script>
+</body>
+</html>
+"##
+    )
+}
+
+// ルーティングを追加・変更したときはここも合わせて更新する
+pub fn openapi_spec() -> Value {
+    json!({
+        "openapi": "3.0.3",
+        "info": {
+            "title": "hello_world_axum_3",
+            "version": env!("CARGO_PKG_VERSION"),
+        },
+        // 同じルートを `/v1` の下にも置いている
+        "servers": [{ "url": "/" }, { "url": "/v1" }],
+        "paths": paths(),
+        "components": {
+            "schemas": schemas(),
+            "securitySchemes": {
+                "basicAuth": { "type": "http", "scheme": "basic" },
+                "bearerAuth": { "type": "http", "scheme": "bearer" },
+            },
+        },
+    })
+}
+
+fn paths() -> Value {
+    let user_role_query = json!({
+        "name": "role",
+        "in": "query",
+        "required": false,
+        "schema": { "type": "string", "enum": ["Admin", "Member", "Viewer"] },
+    });
+    let mut get_labels = operation("List labels", &[], None, ok(paged_of("LabelResponse")));
+    get_labels["parameters"] = pagination_queries();
+    get_labels["parameters"]
+        .as_array_mut()
+        .unwrap()
+        .push(json!({
+            "name": "include_counts",
+            "in": "query",
+            "required": false,
+            "description": "Include the number of todos with each label as `todo_count`",
+            "schema": { "type": "boolean", "default": false },
+        }));
+    get_labels["parameters"].as_array_mut().unwrap().push(json!({
+        "name": "sort",
+        "in": "query",
+        "required": false,
+        "description": "Sort by the order labels were created or inserted in. Sorted by id in descending order if omitted",
+        "schema": { "type": "string", "enum": ["position"] },
+    }));
+    let mut get_todos = operation("List todos", &[], None, ok(paged_of("TodoListResponse")));
+    get_todos["parameters"] = pagination_queries();
+    get_todos["parameters"].as_array_mut().unwrap().push(json!({
+        "name": "q",
+        "in": "query",
+        "required": false,
+        "description": "Return only todos whose text contains this string (case-insensitive)",
+        "schema": { "type": "string" },
+    }));
+    get_todos["parameters"].as_array_mut().unwrap().push(json!({
+        "name": "label_slug",
+        "in": "query",
+        "required": false,
+        "description": "Return only todos with the label whose slug is this string (ignored if `q` is given)",
+        "schema": { "type": "string" },
+    }));
+    get_todos["parameters"].as_array_mut().unwrap().push(json!({
+        "name": "label_ids",
+        "in": "query",
+        "required": false,
+        "description": "Comma-separated label ids. Return only todos with all (`label_operator=and`) or any (`label_operator=or`) of them (ignored if `q` or `label_slug` is given)",
+        "schema": { "type": "string" },
+    }));
+    get_todos["parameters"].as_array_mut().unwrap().push(json!({
+        "name": "label_id",
+        "in": "query",
+        "required": false,
+        "description": "A single label id, combined with `label_ids` if both are given",
+        "schema": { "type": "string", "format": "uuid" },
+    }));
+    get_todos["parameters"].as_array_mut().unwrap().push(json!({
+        "name": "completed",
+        "in": "query",
+        "required": false,
+        "schema": { "type": "boolean" },
+    }));
+    get_todos["parameters"].as_array_mut().unwrap().push(json!({
+        "name": "status",
+        "in": "query",
+        "required": false,
+        "description": "Status computed from `completed` and `due_date`. A todo due today is still `active`",
+        "schema": todo_status(),
+    }));
+    get_todos["parameters"].as_array_mut().unwrap().push(json!({
+        "name": "sort",
+        "in": "query",
+        "required": false,
+        "description": "Descending if prefixed with `-`. Todos without due dates come last when sorting by `due_date`",
+        "schema": { "type": "string", "enum": ["created_at", "-created_at", "due_date", "-due_date"] },
+    }));
+    get_todos["parameters"].as_array_mut().unwrap().push(json!({
+        "name": "label_operator",
+        "in": "query",
+        "required": false,
+        "schema": { "type": "string", "enum": ["and", "or"], "default": "and" },
+    }));
+    get_todos["parameters"].as_array_mut().unwrap().push(json!({
+        "name": "If-None-Match",
+        "in": "header",
+        "required": false,
+        "description": "ETag of the whole todo list returned earlier. Responds with 304 if no todo has changed since",
+        "schema": { "type": "string" },
+    }));
+    get_todos["responses"]["304"] = json!({ "description": "Not Modified" });
+    let mut get_todos_due_soon = operation(
+        "List incomplete todos due within the given days, nearest first",
+        &[],
+        None,
+        ok(paged_of("TodoResponse")),
+    );
+    get_todos_due_soon["parameters"] = pagination_queries();
+    get_todos_due_soon["parameters"]
+        .as_array_mut()
+        .unwrap()
+        .push(json!({
+            "name": "days",
+            "in": "query",
+            "required": false,
+            "schema": { "type": "integer", "minimum": 1, "maximum": 365, "default": 7 },
+        }));
+    let mut get_similar_todos = operation(
+        "List todos whose text is similar to the given todo, most similar first",
+        &["id"],
+        None,
+        ok(array_of("SimilarTodoResponse")),
+    );
+    for parameter in [
+        json!({
+            "name": "threshold",
+            "in": "query",
+            "required": false,
+            "schema": { "type": "number", "minimum": 0, "maximum": 1, "default": 0.7 },
+        }),
+        json!({
+            "name": "limit",
+            "in": "query",
+            "required": false,
+            "schema": { "type": "integer", "minimum": 1, "maximum": 100, "default": 5 },
+        }),
+    ] {
+        get_similar_todos["parameters"]
+            .as_array_mut()
+            .unwrap()
+            .push(parameter);
+    }
+    // 削除済みの todo の履歴も返す
+    let mut get_todo_audit = operation(
+        "List changes made to a todo, oldest first",
+        &["id"],
+        None,
+        ok(paged_of("AuditEntryResponse")),
+    );
+    get_todo_audit["parameters"]
+        .as_array_mut()
+        .unwrap()
+        .extend(pagination_queries().as_array().unwrap().iter().cloned());
+    let mut bulk_get_todos = operation(
+        "Get todos by ids at once, reporting ids not found",
+        &[],
+        None,
+        ok(schema_ref("TodoBulkGetResponse")),
+    );
+    bulk_get_todos["parameters"] = json!([{
+        "name": "ids",
+        "in": "query",
+        "required": true,
+        "description": "Comma-separated todo ids, up to 100",
+        "schema": { "type": "string" },
+    }]);
+    let mut export_todos = operation(
+        "Export all todos as NDJSON or a Markdown task list",
+        &[],
+        None,
+        json!({ "200": {
+            "description": "OK",
+            "content": { "application/x-ndjson": {}, "text/markdown": {} },
+        } }),
+    );
+    export_todos["parameters"] = json!([{
+        "name": "format",
+        "in": "query",
+        "required": false,
+        "schema": { "type": "string", "enum": ["ndjson", "markdown"], "default": "ndjson" },
+    }]);
+    let mut get_users = operation("List users", &[], None, ok(paged_of("UserResponse")));
+    get_users["parameters"] = pagination_queries();
+    get_users["parameters"]
+        .as_array_mut()
+        .unwrap()
+        .push(user_role_query);
+    let mut create_user = operation(
+        "Create a user",
+        &[],
+        Some("UserCreatePayload"),
+        created("UserResponse"),
+    );
+    create_user["parameters"] = json!([{
+        "name": "if_exists",
+        "in": "query",
+        "required": false,
+        "description": "What to do if a user with the same name exists: fail with 422, return it, or update it (both with 200)",
+        "schema": { "type": "string", "enum": ["fail", "return", "update"], "default": "fail" },
+    }]);
+    create_user["responses"]["200"] = json_response("Existing user", schema_ref("UserResponse"));
+    let mut get_user = operation("Get a user", &["id"], None, ok(schema_ref("UserResponse")));
+    get_user["parameters"].as_array_mut().unwrap().push(json!({
+        "name": "include_stats",
+        "in": "query",
+        "required": false,
+        "description": "Include the rate of completed todos assigned to the user as `completion_rate` and the number of consecutive days with a completion as `current_streak`",
+        "schema": { "type": "boolean", "default": false },
+    }));
+    let mut search_users = operation(
+        "Search users whose name starts with the query (case-insensitive)",
+        &[],
+        None,
+        ok(array_of("UserResponse")),
+    );
+    search_users["parameters"] = json!([
+        { "name": "q", "in": "query", "required": true, "schema": { "type": "string", "minLength": 1 } },
+        { "name": "limit", "in": "query", "required": false, "schema": { "type": "integer", "minimum": 0, "default": 10 } },
+    ]);
+    let mut get_recently_used_labels = operation(
+        "List labels most recently attached to todos first",
+        &[],
+        None,
+        ok(array_of("LabelResponse")),
+    );
+    get_recently_used_labels["parameters"] = json!([
+        { "name": "limit", "in": "query", "required": false, "schema": { "type": "integer", "minimum": 0, "default": 10 } },
+    ]);
+    let mut get_me = operation(
+        "Get the authenticated user",
+        &[],
+        None,
+        ok(schema_ref("UserResponse")),
+    );
+    let mut update_me = operation(
+        "Update the authenticated user",
+        &[],
+        Some("UserUpdatePayload"),
+        ok(schema_ref("UserResponse")),
+    );
+    let mut login = operation(
+        "Log in and issue a bearer token",
+        &[],
+        Some("SessionLoginPayload"),
+        ok(schema_ref("SessionResponse")),
+    );
+    login["responses"]["401"] = json!({ "description": "Unauthorized" });
+    // パスワードを続けて間違えると、Retry-After で示す時間が経つまでログインできない
+    login["responses"]["423"] = json!({ "description": "Locked" });
+    let mut logout = operation(
+        "Revoke the bearer token used for this request",
+        &[],
+        None,
+        no_content(),
+    );
+    // 管理者でなければ自分の todo しか削除できない
+    let mut clear_completed = operation(
+        "Delete all completed todos assigned to a user",
+        &["id"],
+        None,
+        ok(schema_ref("TodoClearCompletedResponse")),
+    );
+    clear_completed["responses"]["403"] = json!({ "description": "Forbidden" });
+    // 本人か管理者のみ。本人の場合はパスワードの確認が必要
+    let mut delete_user = operation(
+        "Delete a user",
+        &["id"],
+        Some("UserDeletePayload"),
+        no_content(),
+    );
+    delete_user["responses"]["403"] = json!({ "description": "Forbidden" });
+    // 本人による削除では、確認のパスワードを続けて間違えるとロックする
+    delete_user["responses"]["423"] = json!({ "description": "Locked" });
+    // 本人のみ。現在のパスワードが誤っている場合も 403 を返す
+    let mut change_password = operation(
+        "Change a user's password",
+        &["id"],
+        Some("UserPasswordChangePayload"),
+        no_content(),
+    );
+    change_password["responses"]["403"] = json!({ "description": "Forbidden" });
+    // ログインと同じく、現在のパスワードを続けて間違えるとロックする
+    change_password["responses"]["423"] = json!({ "description": "Locked" });
+    // 管理者のみ
+    let mut user_stats = operation(
+        "Count users per role",
+        &[],
+        None,
+        ok(schema_ref("UserRoleDistributionResponse")),
+    );
+    user_stats["responses"]["403"] = json!({ "description": "Forbidden" });
+    let mut create_invitation = operation(
+        "Invite a user to a todo list",
+        &["id"],
+        Some("InvitationCreatePayload"),
+        created("InvitationResponse"),
+    );
+    // 承諾したユーザーはリストのメンバーに加わる
+    let mut accept_invitation = operation(
+        "Accept an invitation and join the todo list",
+        &["invite_id"],
+        None,
+        ok(schema_ref("InvitationResponse")),
+    );
+    let mut decline_invitation = operation(
+        "Decline an invitation",
+        &["invite_id"],
+        None,
+        ok(schema_ref("InvitationResponse")),
+    );
+    for operation in [&mut accept_invitation, &mut decline_invitation] {
+        operation["responses"]["404"] = json!({ "description": "Not Found" });
+        // すでに応答済み
+        operation["responses"]["409"] = json!({ "description": "Conflict" });
+        // 期限切れ
+        operation["responses"]["410"] = json!({ "description": "Gone" });
+    }
+    // 招待先のユーザー本人以外は応答できず、リストのメンバー以外は招待できない
+    for operation in [
+        &mut create_invitation,
+        &mut accept_invitation,
+        &mut decline_invitation,
+    ] {
+        operation["responses"]["403"] = json!({ "description": "Forbidden" });
+    }
+    // 招待先のユーザーが存在しない
+    create_invitation["responses"]["404"] = json!({ "description": "Not Found" });
+    for operation in [
+        &mut get_me,
+        &mut update_me,
+        &mut logout,
+        &mut clear_completed,
+        &mut delete_user,
+        &mut change_password,
+        &mut user_stats,
+        &mut create_invitation,
+        &mut accept_invitation,
+        &mut decline_invitation,
+    ] {
+        // ユーザー id とパスワードの Basic 認証、または `/auth/login` で発行したトークンが必要
+        operation["security"] = json!([{ "basicAuth": [] }, { "bearerAuth": [] }]);
+        operation["responses"]["401"] = json!({ "description": "Unauthorized" });
+    }
+    let multi_status = json!({
+        "207": json_response(
+            "Created labels and per-item errors",
+            schema_ref("LabelBulkCreateResponse")
+        ),
+    });
+    // すべて作成できれば 201、一部だけ作成できれば 207 を返す
+    let todo_bulk_create_responses = json!({
+        "201": json_response("Created todos", schema_ref("TodoBulkCreateResponse")),
+        "207": json_response(
+            "Created todos and per-item errors",
+            schema_ref("TodoBulkCreateResponse")
+        ),
+    });
+
+    let mut health = operation(
+        "Check whether the database responds (`degraded` if it takes more than 500ms)",
+        &[],
+        None,
+        ok(schema_ref("HealthResponse")),
+    );
+    health["responses"]["503"] = json_response("Unhealthy", schema_ref("HealthResponse"));
+
+    map([
+        (
+            "/",
+            map([(
+                "get",
+                operation("Say hello", &[], None, ok_with_content_type("text/plain")),
+            )]),
+        ),
+        (
+            "/api-docs/openapi.json",
+            map([(
+                "get",
+                operation("Get this OpenAPI spec", &[], None, ok(json!({ "type": "object" }))),
+            )]),
+        ),
+        (
+            "/api-docs/swagger-ui",
+            map([(
+                "get",
+                operation("Show Swagger UI", &[], None, ok_with_content_type("text/html")),
+            )]),
+        ),
+        ("/health", map([("get", health)])),
+        (
+            "/labels",
+            map([
+                ("get", get_labels),
+                (
+                    "post",
+                    operation(
+                        "Create a label",
+                        &[],
+                        Some("LabelCreatePayload"),
+                        created("LabelResponse"),
+                    ),
+                ),
+            ]),
+        ),
+        (
+            "/labels/bulk",
+            map([
+                (
+                    "post",
+                    operation(
+                        "Create labels at once",
+                        &[],
+                        Some("LabelBulkCreatePayload"),
+                        multi_status,
+                    ),
+                ),
+                (
+                    "delete",
+                    operation(
+                        "Delete labels at once, keeping labels attached to todos unless `force` is set",
+                        &[],
+                        Some("LabelBulkDeletePayload"),
+                        json!({
+                            "207": json_response(
+                                "Deleted labels and labels not deleted",
+                                schema_ref("LabelBulkDeleteResponse")
+                            ),
+                        }),
+                    ),
+                ),
+            ]),
+        ),
+        (
+            "/labels/orphaned",
+            map([(
+                "get",
+                operation(
+                    "List labels not attached to any todo",
+                    &[],
+                    None,
+                    ok(array_of("LabelResponse")),
+                ),
+            )]),
+        ),
+        (
+            "/labels/recently-used",
+            map([("get", get_recently_used_labels)]),
+        ),
+        (
+            "/labels/{id}",
+            map([
+                (
+                    "get",
+                    operation(
+                        "Get a label",
+                        &["id"],
+                        None,
+                        ok(schema_ref("LabelResponse")),
+                    ),
+                ),
+                (
+                    "patch",
+                    operation(
+                        "Update a label",
+                        &["id"],
+                        Some("LabelUpdatePayload"),
+                        ok(schema_ref("LabelResponse")),
+                    ),
+                ),
+                (
+                    "delete",
+                    operation("Delete a label", &["id"], None, no_content()),
+                ),
+            ]),
+        ),
+        (
+            "/labels/{id}/todos/complete",
+            map([(
+                "patch",
+                operation(
+                    "Set completion of all todos with a label",
+                    &["id"],
+                    Some("TodoBulkCompleteByLabelPayload"),
+                    ok(schema_ref("TodoBulkCompleteByLabelResponse")),
+                ),
+            )]),
+        ),
+        (
+            "/todos",
+            map([
+                ("get", get_todos),
+                (
+                    "post",
+                    operation(
+                        "Create a todo",
+                        &[],
+                        Some("TodoCreatePayload"),
+                        created("TodoResponse"),
+                    ),
+                ),
+            ]),
+        ),
+        (
+            "/todos/bulk",
+            map([
+                ("get", bulk_get_todos),
+                (
+                    "post",
+                    operation(
+                        "Create todos at once",
+                        &[],
+                        Some("TodoBulkCreatePayload"),
+                        todo_bulk_create_responses,
+                    ),
+                ),
+            ]),
+        ),
+        (
+            "/todos/events",
+            map([(
+                "get",
+                operation(
+                    "Stream todo changes as Server-Sent Events",
+                    &[],
+                    None,
+                    ok_with_content_type("text/event-stream"),
+                ),
+            )]),
+        ),
+        ("/todos/due-soon", map([("get", get_todos_due_soon)])),
+        ("/todos/export", map([("get", export_todos)])),
+        (
+            "/todos/calendar.ics",
+            map([(
+                "get",
+                operation(
+                    "Export incomplete todos with due dates as iCalendar",
+                    &[],
+                    None,
+                    ok_with_content_type("text/calendar"),
+                ),
+            )]),
+        ),
+        (
+            "/todos/{id}",
+            map([
+                (
+                    "get",
+                    operation("Get a todo", &["id"], None, ok(schema_ref("TodoResponse"))),
+                ),
+                (
+                    "patch",
+                    operation(
+                        "Update a todo",
+                        &["id"],
+                        Some("TodoUpdatePayload"),
+                        ok(schema_ref("TodoResponse")),
+                    ),
+                ),
+                (
+                    "delete",
+                    operation("Delete a todo", &["id"], None, no_content()),
+                ),
+            ]),
+        ),
+        ("/todos/{id}/similar", map([("get", get_similar_todos)])),
+        ("/todos/{id}/audit", map([("get", get_todo_audit)])),
+        (
+            "/todos/{id}/dependencies",
+            map([
+                (
+                    "get",
+                    operation(
+                        "List todos that must be completed first",
+                        &["id"],
+                        None,
+                        ok(array_of("TodoDependencyResponse")),
+                    ),
+                ),
+                (
+                    "post",
+                    operation(
+                        "Add a dependency",
+                        &["id"],
+                        Some("TodoDependencyAddPayload"),
+                        created("TodoDependencyResponse"),
+                    ),
+                ),
+            ]),
+        ),
+        (
+            "/todos/{id}/dependencies/{dependency_id}",
+            map([(
+                "delete",
+                operation(
+                    "Remove a dependency",
+                    &["id", "dependency_id"],
+                    None,
+                    no_content(),
+                ),
+            )]),
+        ),
+        ("/users", map([("get", get_users), ("post", create_user)])),
+        ("/auth/login", map([("post", login)])),
+        ("/auth/logout", map([("post", logout)])),
+        ("/users/search", map([("get", search_users)])),
+        ("/users/me", map([("get", get_me), ("patch", update_me)])),
+        (
+            "/users/{id}",
+            map([
+                ("get", get_user),
+                (
+                    "patch",
+                    operation(
+                        "Update a user",
+                        &["id"],
+                        Some("UserUpdatePayload"),
+                        ok(schema_ref("UserResponse")),
+                    ),
+                ),
+                ("delete", delete_user),
+            ]),
+        ),
+        (
+            "/users/{id}/password",
+            map([("patch", change_password)]),
+        ),
+        (
+            "/users/{id}/assigned",
+            map([(
+                "get",
+                operation(
+                    "List todos assigned to a user",
+                    &["id"],
+                    None,
+                    ok(array_of("TodoResponse")),
+                ),
+            )]),
+        ),
+        (
+            "/users/{id}/todos/completed",
+            map([("delete", clear_completed)]),
+        ),
+        (
+            "/users/{id}/labels",
+            map([(
+                "get",
+                operation(
+                    "List labels on todos assigned to a user",
+                    &["id"],
+                    None,
+                    ok(array_of("LabelResponse")),
+                ),
+            )]),
+        ),
+        ("/admin/stats/users", map([("get", user_stats)])),
+        (
+            "/lists/{id}/invitations",
+            map([("post", create_invitation)]),
+        ),
+        (
+            "/invitations/{invite_id}/accept",
+            map([("post", accept_invitation)]),
+        ),
+        (
+            "/invitations/{invite_id}/decline",
+            map([("post", decline_invitation)]),
+        ),
+    ])
+}
+
+fn schemas() -> Value {
+    let label_ids = json!({ "type": "array", "items": uuid() });
+    let bulk_create_error = object(
+        [
+            ("index", json!({ "type": "integer" })),
+            ("message", string()),
+        ],
+        &["index", "message"],
+    );
+
+    map([
+        (
+            "HealthResponse",
+            object(
+                [
+                    (
+                        "status",
+                        json!({ "type": "string", "enum": ["healthy", "degraded", "unhealthy"] }),
+                    ),
+                    ("latency_ms", integer()),
+                    ("instance_id", string()),
+                ],
+                &["status", "latency_ms", "instance_id"],
+            ),
+        ),
+        (
+            "PaginationMeta",
+            object(
+                [
+                    ("total", integer()),
+                    ("page", integer()),
+                    ("per_page", integer()),
+                    ("total_pages", integer()),
+                ],
+                &["total", "page", "per_page", "total_pages"],
+            ),
+        ),
+        (
+            "LabelResponse",
+            object(
+                [
+                    ("id", uuid()),
+                    ("name", string()),
+                    // include_counts=true のときだけ含まれる
+                    ("todo_count", integer()),
+                    // todo のラベルとして返すときだけ含まれる
+                    ("attached_at", date_time()),
+                ],
+                &["id", "name"],
+            ),
+        ),
+        (
+            "LabelCreatePayload",
+            object([("name", string())], &["name"]),
+        ),
+        ("LabelUpdatePayload", object([("name", string())], &[])),
+        (
+            "LabelBulkCreatePayload",
+            object([("labels", array_of("LabelCreatePayload"))], &["labels"]),
+        ),
+        (
+            "LabelBulkCreateResponse",
+            object(
+                [
+                    ("created", array_of("LabelResponse")),
+                    (
+                        "errors",
+                        json!({ "type": "array", "items": bulk_create_error }),
+                    ),
+                ],
+                &["created", "errors"],
+            ),
+        ),
+        (
+            "LabelBulkDeletePayload",
+            object(
+                [
+                    ("ids", label_ids.clone()),
+                    ("force", json!({ "type": "boolean", "default": false })),
+                ],
+                &["ids"],
+            ),
+        ),
+        (
+            "LabelBulkDeleteResponse",
+            object(
+                [
+                    ("deleted", label_ids.clone()),
+                    (
+                        "in_use",
+                        json!({
+                            "type": "array",
+                            "items": object([("id", uuid()), ("todo_count", integer())], &["id", "todo_count"]),
+                        }),
+                    ),
+                    ("not_found", label_ids.clone()),
+                    (
+                        "parse_errors",
+                        json!({ "type": "array", "items": bulk_create_error }),
+                    ),
+                ],
+                &["deleted", "in_use", "not_found", "parse_errors"],
+            ),
+        ),
+        (
+            "TodoResponse",
+            object(
+                [
+                    ("id", uuid()),
+                    ("text", string()),
+                    ("note", nullable(string())),
+                    ("due_date", nullable(date())),
+                    ("assignee_id", nullable(uuid())),
+                    ("completed", boolean()),
+                    ("status", todo_status()),
+                    ("labels", array_of("LabelResponse")),
+                    (
+                        "links",
+                        json!({ "type": "array", "items": { "type": "string", "format": "uri" } }),
+                    ),
+                    ("word_count", integer()),
+                    ("char_count", integer()),
+                    ("created_at", date_time()),
+                    ("updated_at", date_time()),
+                    ("version", integer()),
+                ],
+                &[
+                    "id",
+                    "text",
+                    "note",
+                    "due_date",
+                    "assignee_id",
+                    "completed",
+                    "status",
+                    "labels",
+                    "links",
+                    "word_count",
+                    "char_count",
+                    "created_at",
+                    "updated_at",
+                    "version",
+                ],
+            ),
+        ),
+        (
+            "TodoBulkGetResponse",
+            object(
+                [
+                    ("todos", array_of("TodoResponse")),
+                    ("not_found_ids", json!({ "type": "array", "items": uuid() })),
+                ],
+                &["todos", "not_found_ids"],
+            ),
+        ),
+        (
+            "SimilarTodoResponse",
+            object(
+                [
+                    ("todo", schema_ref("TodoResponse")),
+                    (
+                        "similarity_score",
+                        json!({ "type": "number", "minimum": 0, "maximum": 1 }),
+                    ),
+                ],
+                &["todo", "similarity_score"],
+            ),
+        ),
+        (
+            "AuditEntryResponse",
+            object(
+                [
+                    ("entry_id", uuid()),
+                    ("actor_name", nullable(string())),
+                    (
+                        "action",
+                        json!({ "type": "string", "enum": ["Created", "Updated", "Deleted"] }),
+                    ),
+                    ("occurred_at", date_time()),
+                    ("changes_summary", string()),
+                ],
+                &[
+                    "entry_id",
+                    "actor_name",
+                    "action",
+                    "occurred_at",
+                    "changes_summary",
+                ],
+            ),
+        ),
+        (
+            "TodoListResponse",
+            json!({
+                "allOf": [
+                    schema_ref("TodoResponse"),
+                    object(
+                        [
+                            ("is_overdue", boolean()),
+                            ("label_names", json!({ "type": "array", "items": string() })),
+                            ("assignee_name", nullable(string())),
+                            ("days_until_due", nullable(json!({ "type": "integer" }))),
+                        ],
+                        &["is_overdue", "label_names", "assignee_name", "days_until_due"],
+                    ),
+                ],
+            }),
+        ),
+        (
+            "TodoCreatePayload",
+            object(
+                [
+                    ("text", string()),
+                    ("label_ids", label_ids.clone()),
+                    ("label_names", json!({ "type": "array", "items": string() })),
+                    ("note", string()),
+                    ("due_date", date()),
+                    ("assignee_id", uuid()),
+                    // true なら先頭の文字を大文字にする。省略時はサーバーの設定に従う
+                    ("normalize_case", boolean()),
+                ],
+                &["text", "label_ids"],
+            ),
+        ),
+        (
+            "TodoBulkCreatePayload",
+            object([("todos", array_of("TodoCreatePayload"))], &["todos"]),
+        ),
+        (
+            "TodoBulkCreateResponse",
+            object(
+                [
+                    ("created", array_of("TodoResponse")),
+                    (
+                        "errors",
+                        json!({ "type": "array", "items": bulk_create_error }),
+                    ),
+                ],
+                &["created", "errors"],
+            ),
+        ),
+        (
+            "TodoUpdatePayload",
+            object(
+                [
+                    ("text", string()),
+                    ("completed", boolean()),
+                    ("label_ids", label_ids),
+                    // null を渡すと値を削除する
+                    ("note", nullable(string())),
+                    ("due_date", nullable(date())),
+                    // null か空文字列を渡すと担当者を外す
+                    ("assignee_id", nullable(string())),
+                    // 読み出した時点の版を渡すと、その後に更新されていた場合は 409 を返す
+                    ("version", integer()),
+                    ("normalize_case", boolean()),
+                ],
+                &[],
+            ),
+        ),
+        (
+            "TodoBulkCompleteByLabelPayload",
+            object([("completed", boolean())], &["completed"]),
+        ),
+        (
+            "TodoBulkCompleteByLabelResponse",
+            object(
+                [
+                    ("updated_count", integer()),
+                    ("skipped_ids", json!({ "type": "array", "items": uuid() })),
+                ],
+                &["updated_count", "skipped_ids"],
+            ),
+        ),
+        (
+            "TodoClearCompletedResponse",
+            object([("deleted_count", integer())], &["deleted_count"]),
+        ),
+        (
+            "TodoDependencyResponse",
+            object(
+                [
+                    ("id", uuid()),
+                    ("from_todo_id", uuid()),
+                    ("to_todo_id", uuid()),
+                ],
+                &["id", "from_todo_id", "to_todo_id"],
+            ),
+        ),
+        (
+            "InvitationResponse",
+            object(
+                [
+                    ("id", uuid()),
+                    ("list_id", uuid()),
+                    (
+                        "invitee_email",
+                        json!({ "type": "string", "format": "email" }),
+                    ),
+                    ("invitee_id", nullable(uuid())),
+                    ("invited_by", uuid()),
+                    (
+                        "status",
+                        json!({
+                            "type": "string",
+                            "enum": ["pending", "accepted", "declined", "expired"],
+                        }),
+                    ),
+                    ("expires_at", date_time()),
+                ],
+                &[
+                    "id",
+                    "list_id",
+                    "invitee_email",
+                    "invited_by",
+                    "status",
+                    "expires_at",
+                ],
+            ),
+        ),
+        (
+            "InvitationCreatePayload",
+            object(
+                [
+                    (
+                        "invitee_email",
+                        json!({ "type": "string", "format": "email" }),
+                    ),
+                    ("invitee_id", uuid()),
+                ],
+                &["invitee_email", "invitee_id"],
+            ),
+        ),
+        (
+            "TodoDependencyAddPayload",
+            object([("depends_on_todo_id", uuid())], &["depends_on_todo_id"]),
+        ),
+        (
+            "UserResponse",
+            object(
+                [
+                    ("id", uuid()),
+                    ("name", string()),
+                    // 本人か管理者として認証したときだけ含まれる
+                    ("role", string()),
+                    // include_stats=true で、担当している todo があるときだけ含まれる
+                    (
+                        "completion_rate",
+                        json!({ "type": "number", "minimum": 0, "maximum": 1 }),
+                    ),
+                    // include_stats=true のときだけ含まれる
+                    ("current_streak", integer()),
+                ],
+                &["id", "name"],
+            ),
+        ),
+        (
+            "UserCreatePayload",
+            object([("user_name", string())], &["user_name"]),
+        ),
+        ("UserUpdatePayload", object([("user_name", string())], &[])),
+        (
+            "UserPasswordChangePayload",
+            object(
+                [
+                    ("current_password", string()),
+                    // 8 文字以上で、数字を 1 文字以上含む
+                    ("new_password", json!({ "type": "string", "minLength": 8 })),
+                ],
+                &["current_password", "new_password"],
+            ),
+        ),
+        (
+            "SessionLoginPayload",
+            object(
+                [("user_id", uuid()), ("password", string())],
+                &["user_id", "password"],
+            ),
+        ),
+        (
+            "SessionResponse",
+            object(
+                [("access_token", string()), ("token_type", string())],
+                &["access_token", "token_type"],
+            ),
+        ),
+        (
+            "UserRoleDistributionResponse",
+            object(
+                [
+                    ("Admin", integer()),
+                    ("Member", integer()),
+                    ("Viewer", integer()),
+                ],
+                &["Admin", "Member", "Viewer"],
+            ),
+        ),
+        (
+            "UserDeletePayload",
+            object(
+                [
+                    ("password_confirmation", string()),
+                    // true の場合は名前を匿名化して論理削除する
+                    ("anonymize", boolean()),
+                ],
+                &["password_confirmation"],
+            ),
+        ),
+    ])
+}
+
+fn operation(
+    summary: &str,
+    path_params: &[&str],
+    request_schema: Option<&str>,
+    responses: Value,
+) -> Value {
+    let mut operation = json!({ "summary": summary, "responses": responses });
+    if !path_params.is_empty() {
+        operation["parameters"] = path_params
+            .iter()
+            .map(|name| json!({ "name": name, "in": "path", "required": true, "schema": uuid() }))
+            .collect();
+    }
+    if let Some(request_schema) = request_schema {
+        operation["requestBody"] = json!({
+            "required": true,
+            "content": { "application/json": { "schema": schema_ref(request_schema) } },
+        });
+    }
+    operation
+}
+
+// 一覧取得のページ指定。結果が複数ページにまたがるときは `Link` ヘッダーが付く
+fn pagination_queries() -> Value {
+    ["page", "per_page"]
+        .into_iter()
+        .map(|name| {
+            json!({
+                "name": name,
+                "in": "query",
+                "required": false,
+                "schema": { "type": "integer", "minimum": 1 },
+            })
+        })
+        .collect()
+}
+
+fn json_response(description: &str, schema: Value) -> Value {
+    json!({
+        "description": description,
+        "content": { "application/json": { "schema": schema } },
+    })
+}
+
+fn ok(schema: Value) -> Value {
+    json!({ "200": json_response("OK", schema) })
+}
+
+fn ok_with_content_type(content_type: &str) -> Value {
+    json!({ "200": { "description": "OK", "content": { content_type: {} } } })
+}
+
+fn created(schema_name: &str) -> Value {
+    json!({ "201": json_response("Created", schema_ref(schema_name)) })
+}
+
+fn no_content() -> Value {
+    json!({ "204": { "description": "No Content" } })
+}
+
+fn schema_ref(schema_name: &str) -> Value {
+    json!({ "$ref": format!("#/components/schemas/{}", schema_name) })
+}
+
+fn array_of(schema_name: &str) -> Value {
+    json!({ "type": "array", "items": schema_ref(schema_name) })
+}
+
+// 一覧取得のレスポンス。要素は `data` に入り、ページ送りの情報が `meta` に付く
+fn paged_of(schema_name: &str) -> Value {
+    object(
+        [
+            ("data", array_of(schema_name)),
+            ("meta", schema_ref("PaginationMeta")),
+        ],
+        &["data", "meta"],
+    )
+}
+
+fn map<const N: usize>(entries: [(&str, Value); N]) -> Value {
+    Value::Object(
+        entries
+            .into_iter()
+            .map(|(key, value)| (key.to_string(), value))
+            .collect(),
+    )
+}
+
+fn object<const N: usize>(properties: [(&str, Value); N], required: &[&str]) -> Value {
+    json!({ "type": "object", "properties": map(properties), "required": required })
+}
+
+fn string() -> Value {
+    json!({ "type": "string" })
+}
+
+fn integer() -> Value {
+    json!({ "type": "integer", "minimum": 0 })
+}
+
+fn boolean() -> Value {
+    json!({ "type": "boolean" })
+}
+
+fn uuid() -> Value {
+    json!({ "type": "string", "format": "uuid" })
+}
+
+fn date() -> Value {
+    json!({ "type": "string", "format": "date" })
+}
+
+fn nullable(mut schema: Value) -> Value {
+    schema["nullable"] = json!(true);
+    schema
+}
+
+fn date_time() -> Value {
+    json!({ "type": "string", "format": "date-time" })
+}
+
+fn todo_status() -> Value {
+    json!({ "type": "string", "enum": ["active", "completed", "overdue", "archived"] })
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use axum::http::{header, method::Method};
+    use tower::ServiceExt;
+
+    use crate::router::{create_app, tests, ArgCreateApp};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn should_serve_openapi_spec() -> Result<()> {
+        let app = create_app(ArgCreateApp::default());
+
+        let req = tests::build_req_with_empty("/v1/api-docs/openapi.json", Method::GET)?;
+        let res = app.oneshot(req).await?;
+        let spec: Value = tests::res_to_struct(res).await?;
+
+        // パスは `/v1` を付けても付けなくても同じルートになる
+        assert_eq!(json!([{ "url": "/" }, { "url": "/v1" }]), spec["servers"]);
+
+        let todos = &spec["paths"]["/todos"];
+        assert!(todos["get"].is_object());
+        assert!(todos["get"]["responses"]["304"].is_object());
+        assert!(todos["post"].is_object());
+        assert_eq!(
+            "#/components/schemas/TodoCreatePayload",
+            todos["post"]["requestBody"]["content"]["application/json"]["schema"]["$ref"]
+        );
+        assert!(
+            spec["paths"]["/todos/events"]["get"]["responses"]["200"]["content"]
+                ["text/event-stream"]
+                .is_object()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn should_define_every_referenced_schema() {
+        let spec = openapi_spec();
+        let spec_string = spec.to_string();
+
+        let schemas = spec["components"]["schemas"].as_object().unwrap();
+        for (_, [schema_name]) in regex::Regex::new(r"#/components/schemas/(\w+)")
+            .unwrap()
+            .captures_iter(&spec_string)
+            .map(|captures| captures.extract())
+        {
+            assert!(
+                schemas.contains_key(schema_name),
+                "{} is not defined",
+                schema_name
+            );
+        }
+    }
+
+    // create_app に登録したルートのパスを、ソースから取り出す
+    fn registered_paths() -> Vec<String> {
+        regex::Regex::new(r#"\.route\(\s*"([^"]+)""#)
+            .unwrap()
+            .captures_iter(include_str!("../router.rs"))
+            .map(|captures| captures[1].to_string())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn should_document_every_registered_route_and_method() -> Result<()> {
+        let app = create_app(ArgCreateApp::default());
+        let spec = openapi_spec();
+        let parameter = regex::Regex::new(r":(\w+)").unwrap();
+
+        let registered_paths = registered_paths();
+        assert!(registered_paths.contains(&"/todos".to_string()));
+        let mut undocumented = vec![];
+        for prefix in ["", "/v1"] {
+            for path in &registered_paths {
+                // `:id` などのパラメータは、ルートに一致する値に置き換えて問い合わせる
+                let uri = parameter.replace_all(path, uuid::Uuid::nil().to_string());
+                let uri = format!("{}{}", prefix, uri);
+                let req = tests::build_req_with_empty(&uri, Method::OPTIONS)?;
+                let res = app.clone().oneshot(req).await?;
+                let allow = res
+                    .headers()
+                    .get(header::ALLOW)
+                    .unwrap_or_else(|| panic!("{} is not routed", uri))
+                    .to_str()?
+                    .to_string();
+
+                let spec_path = parameter.replace_all(path, "{$1}");
+                // HEAD は GET に、OPTIONS は全てのルートに axum とミドルウェアが付けるため除く
+                for method in allow
+                    .split(", ")
+                    .filter(|method| !["HEAD", "OPTIONS"].contains(method))
+                {
+                    if !spec["paths"][spec_path.as_ref()][method.to_lowercase()].is_object() {
+                        undocumented.push(format!("{} {}", method, spec_path));
+                    }
+                }
+            }
+        }
+        undocumented.sort();
+        undocumented.dedup();
+        assert!(undocumented.is_empty(), "undocumented: {:?}", undocumented);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_load_swagger_ui_from_configured_assets_url() -> Result<()> {
+        use crate::app_config::AppConfig;
+
+        let app = create_app(ArgCreateApp::default().app_config(AppConfig {
+            swagger_ui_assets_url: "https://assets.example.com/swagger-ui".to_string(),
+            ..AppConfig::default()
+        }));
+
+        let req = tests::build_req_with_empty("/api-docs/swagger-ui", Method::GET)?;
+        let res = app.oneshot(req).await?;
+        let html = String::from_utf8(hyper::body::to_bytes(res.into_body()).await?.to_vec())?;
+
+        assert!(html.contains(r#"href="https://assets.example.com/swagger-ui/swagger-ui.css""#));
+        assert!(
+            html.contains(r#"src="https://assets.example.com/swagger-ui/swagger-ui-bundle.js""#)
+        );
+        assert!(!html.contains("unpkg.com"));
+        Ok(())
+    }
+
+    // `$ref` で参照しているスキーマを取り出す
+    fn resolve<'a>(spec: &'a Value, schema: &'a Value) -> &'a Value {
+        match schema["$ref"].as_str() {
+            Some(reference) => {
+                let schema_name = reference.trim_start_matches("#/components/schemas/");
+                &spec["components"]["schemas"][schema_name]
+            }
+            None => schema,
+        }
+    }
+
+    // `allOf` で合成したスキーマを、プロパティと必須のプロパティをまとめた 1 つのスキーマにする
+    fn merge_all_of(spec: &Value, all_of: &[Value]) -> Value {
+        let mut properties = serde_json::Map::new();
+        let mut required = vec![];
+        for schema in all_of {
+            let schema = resolve(spec, schema);
+            properties.extend(
+                schema["properties"]
+                    .as_object()
+                    .cloned()
+                    .unwrap_or_default(),
+            );
+            required.extend(schema["required"].as_array().cloned().unwrap_or_default());
+        }
+        json!({ "type": "object", "properties": properties, "required": required })
+    }
+
+    // 仕様に書いたスキーマどおりの値を 1 つ作る。省略できるプロパティもすべて含める
+    fn example_of(spec: &Value, schema: &Value) -> Value {
+        let schema = resolve(spec, schema);
+        if let Some(value) = schema["enum"].as_array().and_then(|values| values.first()) {
+            return value.clone();
+        }
+        match (schema["type"].as_str(), schema["format"].as_str()) {
+            (Some("object"), _) => Value::Object(
+                schema["properties"]
+                    .as_object()
+                    .unwrap()
+                    .iter()
+                    .map(|(key, property)| (key.clone(), example_of(spec, property)))
+                    .collect(),
+            ),
+            (Some("array"), _) => json!([example_of(spec, &schema["items"])]),
+            (Some("string"), Some("uuid")) => json!(uuid::Uuid::nil()),
+            (Some("string"), Some("date")) => json!("2023-10-31"),
+            (Some("string"), Some("date-time")) => json!("2023-10-31T00:00:00Z"),
+            (Some("string"), Some("email")) => json!("tester@example.com"),
+            (Some("string"), _) => json!("password1"),
+            (Some("integer"), _) => json!(1),
+            (Some("number"), _) => json!(0.5),
+            (Some("boolean"), _) => json!(true),
+            _ => panic!("unsupported schema: {}", schema),
+        }
+    }
+
+    // `#[derive(Deserialize)]` した構造体が受け付けるフィールドの名前を取り出す
+    // `deserialize_struct` に渡されるフィールドの一覧を記録するだけで、値は読み出さない
+    fn field_names<T: serde::de::DeserializeOwned>() -> Vec<&'static str> {
+        use serde::de::{value::Error, Error as _, Visitor};
+
+        struct FieldNameCollector<'a>(&'a mut Vec<&'static str>);
+
+        impl<'de, 'a> serde::Deserializer<'de> for FieldNameCollector<'a> {
+            type Error = Error;
+
+            fn deserialize_any<V: Visitor<'de>>(self, _: V) -> Result<V::Value, Error> {
+                Err(Error::custom("not a struct"))
+            }
+
+            fn deserialize_struct<V: Visitor<'de>>(
+                self,
+                _: &'static str,
+                fields: &'static [&'static str],
+                _: V,
+            ) -> Result<V::Value, Error> {
+                self.0.extend(fields);
+                Err(Error::custom("fields are collected"))
+            }
+
+            serde::forward_to_deserialize_any! {
+                bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes
+                byte_buf option unit unit_struct newtype_struct seq tuple tuple_struct map enum
+                identifier ignored_any
+            }
+        }
+
+        let mut field_names = vec![];
+        let _ = T::deserialize(FieldNameCollector(&mut field_names));
+        field_names
+    }
+
+    // リクエストボディのスキーマが、実際に受け取る構造体と一致しているかを確かめる
+    fn assert_payload_matches_schema<T: serde::de::DeserializeOwned>(
+        spec: &Value,
+        schema_name: &str,
+    ) {
+        let schema = &spec["components"]["schemas"][schema_name];
+        let mut documented: Vec<&str> = schema["properties"]
+            .as_object()
+            .unwrap_or_else(|| panic!("{} is not defined", schema_name))
+            .keys()
+            .map(String::as_str)
+            .collect();
+        documented.sort();
+        let mut fields = field_names::<T>();
+        fields.sort();
+        assert_eq!(fields, documented, "properties of {}", schema_name);
+
+        let example = example_of(spec, schema);
+        if let Err(e) = serde_json::from_value::<T>(example.clone()) {
+            panic!("{} cannot be read: {} ({})", schema_name, example, e);
+        }
+        // 必須と書いたプロパティだけが、省略すると受け付けられない
+        let required: Vec<&str> = schema["required"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|name| name.as_str().unwrap())
+            .collect();
+        for field in fields {
+            let mut example = example.clone();
+            example.as_object_mut().unwrap().remove(field);
+            assert_eq!(
+                required.contains(&field),
+                serde_json::from_value::<T>(example).is_err(),
+                "{}.{} is documented as {}",
+                schema_name,
+                field,
+                if required.contains(&field) {
+                    "required"
+                } else {
+                    "optional"
+                }
+            );
+        }
+    }
+
+    type PayloadCheck = fn(&Value, &str);
+
+    #[test]
+    fn should_document_request_bodies_as_payload_structs_read_them() {
+        use crate::router::{
+            invitation_handlers::InvitationCreatePayload,
+            label_handlers::{
+                LabelBulkCreatePayload, LabelBulkDeletePayload, LabelCreatePayload,
+                LabelUpdatePayload,
+            },
+            session_handlers::SessionLoginPayload,
+            todo_dependency_handlers::TodoDependencyAddPayload,
+            todo_handlers::{
+                TodoBulkCompleteByLabelPayload, TodoBulkCreatePayload, TodoCreatePayload,
+                TodoUpdatePayload,
+            },
+            user_handlers::{
+                UserCreatePayload, UserDeletePayload, UserPasswordChangePayload, UserUpdatePayload,
+            },
+        };
+
+        let spec = openapi_spec();
+        let checks: [(&str, PayloadCheck); 15] = [
+            (
+                "InvitationCreatePayload",
+                assert_payload_matches_schema::<InvitationCreatePayload>,
+            ),
+            (
+                "LabelBulkCreatePayload",
+                assert_payload_matches_schema::<LabelBulkCreatePayload>,
+            ),
+            (
+                "LabelBulkDeletePayload",
+                assert_payload_matches_schema::<LabelBulkDeletePayload>,
+            ),
+            (
+                "LabelCreatePayload",
+                assert_payload_matches_schema::<LabelCreatePayload>,
+            ),
+            (
+                "LabelUpdatePayload",
+                assert_payload_matches_schema::<LabelUpdatePayload>,
+            ),
+            (
+                "SessionLoginPayload",
+                assert_payload_matches_schema::<SessionLoginPayload>,
+            ),
+            (
+                "TodoBulkCompleteByLabelPayload",
+                assert_payload_matches_schema::<TodoBulkCompleteByLabelPayload>,
+            ),
+            (
+                "TodoBulkCreatePayload",
+                assert_payload_matches_schema::<TodoBulkCreatePayload>,
+            ),
+            (
+                "TodoCreatePayload",
+                assert_payload_matches_schema::<TodoCreatePayload>,
+            ),
+            (
+                "TodoDependencyAddPayload",
+                assert_payload_matches_schema::<TodoDependencyAddPayload>,
+            ),
+            (
+                "TodoUpdatePayload",
+                assert_payload_matches_schema::<TodoUpdatePayload>,
+            ),
+            (
+                "UserCreatePayload",
+                assert_payload_matches_schema::<UserCreatePayload>,
+            ),
+            (
+                "UserDeletePayload",
+                assert_payload_matches_schema::<UserDeletePayload>,
+            ),
+            (
+                "UserPasswordChangePayload",
+                assert_payload_matches_schema::<UserPasswordChangePayload>,
+            ),
+            (
+                "UserUpdatePayload",
+                assert_payload_matches_schema::<UserUpdatePayload>,
+            ),
+        ];
+        for (schema_name, check) in checks {
+            check(&spec, schema_name);
+        }
+
+        // リクエストボディのスキーマを追加したときに、ここでの確認が漏れないようにする
+        let mut payload_schemas: Vec<&str> = spec["components"]["schemas"]
+            .as_object()
+            .unwrap()
+            .keys()
+            .map(String::as_str)
+            .filter(|schema_name| schema_name.ends_with("Payload"))
+            .collect();
+        payload_schemas.sort();
+        let checked: Vec<&str> = checks.iter().map(|(schema_name, _)| *schema_name).collect();
+        assert_eq!(checked, payload_schemas);
+    }
+
+    // 応答の JSON が、仕様に書いたスキーマと一致しているかを確かめる
+    // 仕様にないプロパティがある場合や、必須のプロパティがない場合も一致しないものとする
+    // 確かめたスキーマの名前を `visited` に記録する
+    fn assert_matches_schema(
+        spec: &Value,
+        schema: &Value,
+        value: &Value,
+        location: &str,
+        visited: &mut Vec<String>,
+    ) {
+        if let Some(reference) = schema["$ref"].as_str() {
+            visited.push(
+                reference
+                    .trim_start_matches("#/components/schemas/")
+                    .to_string(),
+            );
+        }
+        let schema = resolve(spec, schema);
+        if value.is_null() && schema["nullable"] == json!(true) {
+            return;
+        }
+        if let Some(all_of) = schema["allOf"].as_array() {
+            for schema in all_of {
+                if let Some(reference) = schema["$ref"].as_str() {
+                    visited.push(
+                        reference
+                            .trim_start_matches("#/components/schemas/")
+                            .to_string(),
+                    );
+                }
+            }
+            let merged = merge_all_of(spec, all_of);
+            return assert_matches_schema(spec, &merged, value, location, visited);
+        }
+        if let Some(values) = schema["enum"].as_array() {
+            assert!(
+                values.contains(value),
+                "{}: {} is not one of {:?}",
+                location,
+                value,
+                values
+            );
+        }
+        match schema["type"].as_str() {
+            Some("object") => {
+                let object = value
+                    .as_object()
+                    .unwrap_or_else(|| panic!("{}: {} is not an object", location, value));
+                let properties = schema["properties"].as_object().unwrap();
+                for required in schema["required"].as_array().unwrap() {
+                    let required = required.as_str().unwrap();
+                    assert!(
+                        object.contains_key(required),
+                        "{}: {} is missing",
+                        location,
+                        required
+                    );
+                }
+                for (key, value) in object {
+                    let property = properties
+                        .get(key)
+                        .unwrap_or_else(|| panic!("{}: {} is not documented", location, key));
+                    let location = format!("{}.{}", location, key);
+                    assert_matches_schema(spec, property, value, &location, visited);
+                }
+            }
+            Some("array") => {
+                let items = value
+                    .as_array()
+                    .unwrap_or_else(|| panic!("{}: {} is not an array", location, value));
+                for (i, item) in items.iter().enumerate() {
+                    let location = format!("{}[{}]", location, i);
+                    assert_matches_schema(spec, &schema["items"], item, &location, visited);
+                }
+            }
+            Some("string") => {
+                let string = value
+                    .as_str()
+                    .unwrap_or_else(|| panic!("{}: {} is not a string", location, value));
+                let is_valid = match schema["format"].as_str() {
+                    Some("uuid") => uuid::Uuid::parse_str(string).is_ok(),
+                    Some("date") => chrono::NaiveDate::parse_from_str(string, "%Y-%m-%d").is_ok(),
+                    Some("date-time") => chrono::DateTime::parse_from_rfc3339(string).is_ok(),
+                    _ => true,
+                };
+                assert!(
+                    is_valid,
+                    "{}: {} is not {}",
+                    location, value, schema["format"]
+                );
+            }
+            Some("integer") => assert!(
+                value.is_i64() || value.is_u64(),
+                "{}: {} is not an integer",
+                location,
+                value
+            ),
+            Some("number") => assert!(value.is_number(), "{}: {} is not a number", location, value),
+            Some("boolean") => {
+                assert!(
+                    value.is_boolean(),
+                    "{}: {} is not a boolean",
+                    location,
+                    value
+                )
+            }
+            _ => panic!("{}: unsupported schema: {}", location, schema),
+        }
+    }
+
+    // 実際のリクエストの応答を、仕様に書いたそのルートの応答と比べる
+    struct DocumentedApp {
+        app: axum::Router,
+        spec: Value,
+        visited: Vec<String>,
+    }
+
+    impl DocumentedApp {
+        async fn send(
+            &mut self,
+            method: Method,
+            uri: &str,
+            body: Option<Value>,
+            authorization: Option<&str>,
+        ) -> Result<Value> {
+            let mut req = match body {
+                Some(body) => tests::build_req_with_json(uri, method.clone(), body.to_string())?,
+                None => tests::build_req_with_empty(uri, method.clone())?,
+            };
+            if let Some(authorization) = authorization {
+                req.headers_mut()
+                    .insert(header::AUTHORIZATION, authorization.parse()?);
+            }
+            let res = self.app.clone().oneshot(req).await?;
+            let status = res.status();
+            let bytes = hyper::body::to_bytes(res.into_body()).await?;
+
+            let path = uri.split('?').next().unwrap();
+            let spec_path = self.spec["paths"]
+                .as_object()
+                .unwrap()
+                .keys()
+                .find(|spec_path| {
+                    let spec_segments: Vec<&str> = spec_path.split('/').collect();
+                    let segments: Vec<&str> = path.split('/').collect();
+                    spec_segments.len() == segments.len()
+                        && spec_segments
+                            .iter()
+                            .zip(&segments)
+                            .all(|(spec_segment, segment)| {
+                                spec_segment.starts_with('{') || spec_segment == segment
+                            })
+                })
+                .unwrap_or_else(|| panic!("{} is not documented", path))
+                .clone();
+            let location = format!("{} {} ({})", method, spec_path, status.as_u16());
+            let response = &self.spec["paths"][&spec_path][method.as_str().to_lowercase()]
+                ["responses"][status.as_str()];
+            assert!(
+                response.is_object(),
+                "{} is not documented: {}",
+                location,
+                String::from_utf8_lossy(&bytes)
+            );
+
+            let schema = &response["content"]["application/json"]["schema"];
+            if schema.is_null() {
+                return Ok(Value::Null);
+            }
+            let body: Value = serde_json::from_slice(&bytes)?;
+            let spec = self.spec.clone();
+            assert_matches_schema(&spec, schema, &body, &location, &mut self.visited);
+            Ok(body)
+        }
+    }
+
+    #[tokio::test]
+    async fn should_respond_as_documented_in_schemas() -> Result<()> {
+        use crate::domain::{
+            models::{
+                credentials::{
+                    credential_repository::ICredentialRepository,
+                    password_credential::PasswordCredential,
+                },
+                users::{
+                    user::User, user_name::UserName, user_repository::IUserRepository,
+                    user_role::UserRole,
+                },
+            },
+            value_object::ValueObject,
+        };
+
+        let arg_create_app = ArgCreateApp::default();
+        let mut authorizations = vec![];
+        let mut user_ids = vec![];
+        for (user_name, user_role) in [("admin", UserRole::Admin), ("member", UserRole::Member)] {
+            let mut user = User::new(UserName::new(user_name.to_string())?)?;
+            user.user_role = user_role;
+            arg_create_app.user_repository().save(&user).await?;
+            arg_create_app
+                .credential_repository()
+                .save(&PasswordCredential::new(
+                    user.user_id().clone(),
+                    "password1",
+                )?)
+                .await?;
+            authorizations.push(tests::basic_authorization(
+                &user.user_id().to_string(),
+                "password1",
+            ));
+            user_ids.push(user.user_id().to_string());
+        }
+        let (admin, member) = (
+            Some(authorizations[0].as_str()),
+            Some(authorizations[1].as_str()),
+        );
+        let member_id = &user_ids[1];
+        let mut app = DocumentedApp {
+            app: create_app(arg_create_app),
+            spec: openapi_spec(),
+            visited: vec![],
+        };
+
+        app.send(Method::GET, "/health", None, None).await?;
+
+        // labels
+        let label = app
+            .send(
+                Method::POST,
+                "/labels",
+                Some(json!({ "name": "label-1" })),
+                None,
+            )
+            .await?;
+        let label_id = label["id"].as_str().unwrap();
+        let label_uri = format!("/labels/{}", label_id);
+        app.send(Method::GET, &label_uri, None, None).await?;
+        app.send(
+            Method::PATCH,
+            &label_uri,
+            Some(json!({ "name": "label-2" })),
+            None,
+        )
+        .await?;
+        app.send(Method::GET, "/labels?include_counts=true", None, None)
+            .await?;
+        let bulk_created = app
+            .send(
+                Method::POST,
+                "/labels/bulk",
+                Some(json!({ "labels": [{ "name": "label-3" }, { "name": "" }] })),
+                None,
+            )
+            .await?;
+
+        // users
+        let user = app
+            .send(
+                Method::POST,
+                "/users",
+                Some(json!({ "user_name": "tester-1" })),
+                None,
+            )
+            .await?;
+        let user_uri = format!("/users/{}", user["id"].as_str().unwrap());
+        app.send(
+            Method::GET,
+            &format!("{}?include_stats=true", user_uri),
+            None,
+            admin,
+        )
+        .await?;
+        app.send(
+            Method::PATCH,
+            &user_uri,
+            Some(json!({ "user_name": "tester-2" })),
+            None,
+        )
+        .await?;
+        app.send(Method::GET, "/users", None, None).await?;
+        app.send(Method::GET, "/users/search?q=te", None, None)
+            .await?;
+        app.send(Method::GET, "/users/me", None, member).await?;
+        app.send(
+            Method::PATCH,
+            "/users/me",
+            Some(json!({ "user_name": "member-1" })),
+            member,
+        )
+        .await?;
+        app.send(Method::GET, "/admin/stats/users", None, admin)
+            .await?;
+        app.send(
+            Method::POST,
+            "/auth/login",
+            Some(json!({ "user_id": member_id, "password": "password1" })),
+            None,
+        )
+        .await?;
+
+        // todos
+        let todo = app
+            .send(
+                Method::POST,
+                "/todos",
+                Some(json!({
+                    "text": "todo-1",
+                    "label_ids": [label_id],
+                    "note": "note",
+                    "due_date": "2023-10-31",
+                    "assignee_id": member_id,
+                })),
+                None,
+            )
+            .await?;
+        let todo_id = todo["id"].as_str().unwrap();
+        let todo_uri = format!("/todos/{}", todo_id);
+        let bulk_created_todos = app
+            .send(
+                Method::POST,
+                "/todos/bulk",
+                Some(json!({ "todos": [{ "text": "todo-2", "label_ids": [] }] })),
+                None,
+            )
+            .await?;
+        let other_todo_id = bulk_created_todos["created"][0]["id"].as_str().unwrap();
+        app.send(Method::GET, "/todos", None, None).await?;
+        app.send(Method::GET, "/todos?sort=due_date&page=1", None, None)
+            .await?;
+        app.send(Method::GET, &todo_uri, None, None).await?;
+        app.send(
+            Method::GET,
+            &format!("/todos/bulk?ids={},{}", todo_id, uuid::Uuid::nil()),
+            None,
+            None,
+        )
+        .await?;
+        app.send(
+            Method::GET,
+            &format!("{}/similar?threshold=0", todo_uri),
+            None,
+            None,
+        )
+        .await?;
+        app.send(Method::GET, "/todos/due-soon?days=365", None, None)
+            .await?;
+        app.send(
+            Method::POST,
+            &format!("/todos/{}/dependencies", other_todo_id),
+            Some(json!({ "depends_on_todo_id": todo_id })),
+            None,
+        )
+        .await?;
+        app.send(
+            Method::GET,
+            &format!("/todos/{}/dependencies", other_todo_id),
+            None,
+            None,
+        )
+        .await?;
+        app.send(
+            Method::PATCH,
+            &format!("{}/todos/complete", label_uri),
+            Some(json!({ "completed": true })),
+            None,
+        )
+        .await?;
+        app.send(
+            Method::PATCH,
+            &todo_uri,
+            Some(json!({ "note": null })),
+            None,
+        )
+        .await?;
+        app.send(Method::GET, &format!("{}/audit", todo_uri), None, None)
+            .await?;
+        app.send(
+            Method::GET,
+            &format!("/users/{}/assigned", member_id),
+            None,
+            None,
+        )
+        .await?;
+        app.send(
+            Method::GET,
+            &format!("/users/{}/labels", member_id),
+            None,
+            None,
+        )
+        .await?;
+        app.send(
+            Method::DELETE,
+            &format!("/users/{}/todos/completed", member_id),
+            None,
+            member,
+        )
+        .await?;
+
+        // invitations
+        let invitation = app
+            .send(
+                Method::POST,
+                &format!("/lists/{}/invitations", uuid::Uuid::new_v4()),
+                Some(json!({ "invitee_email": "member@example.com", "invitee_id": member_id })),
+                admin,
+            )
+            .await?;
+        app.send(
+            Method::POST,
+            &format!("/invitations/{}/accept", invitation["id"].as_str().unwrap()),
+            None,
+            member,
+        )
+        .await?;
+
+        let label_ids: Vec<&Value> = bulk_created["created"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|label| &label["id"])
+            .chain([&label["id"]])
+            .collect();
+        app.send(
+            Method::DELETE,
+            "/labels/bulk",
+            Some(json!({ "ids": label_ids })),
+            None,
+        )
+        .await?;
+
+        // 応答のスキーマを追加したときに、ここでの確認が漏れないようにする
+        let mut response_schemas: Vec<&str> = app.spec["components"]["schemas"]
+            .as_object()
+            .unwrap()
+            .keys()
+            .map(String::as_str)
+            .filter(|schema_name| !schema_name.ends_with("Payload"))
+            .collect();
+        response_schemas.sort();
+        app.visited.sort();
+        app.visited.dedup();
+        assert_eq!(response_schemas, app.visited);
+        Ok(())
+    }
+}
@@ -0,0 +1,392 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use axum::{
+    extract::Extension,
+    http::Request,
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use chrono::{DateTime, Utc};
+use hyper::{
+    header::{HeaderValue, AUTHORIZATION, WWW_AUTHENTICATE},
+    StatusCode,
+};
+
+use crate::{
+    application::{
+        sessions::{
+            session_application_error::SessionApplicationError,
+            session_authenticate_application_service::{
+                ISessionAuthenticateApplicationService, SessionAuthenticateCommand,
+            },
+        },
+        users::{
+            user_application_error::UserApplicationError,
+            user_authenticate_application_service::{
+                IUserAuthenticateApplicationService, UserAuthenticateCommand,
+            },
+        },
+    },
+    domain::models::{
+        credentials::credential_repository::ICredentialRepository,
+        sessions::{session_id::SessionId, session_repository::ISessionRepository},
+        users::user_id::UserId,
+    },
+};
+
+use super::{
+    locale::Locale,
+    messages::{Localize, Messages},
+    problem_details::ProblemDetails,
+};
+
+// セッションの有効性の確認結果をキャッシュしておく時間
+const SESSION_CACHE_TTL: Duration = Duration::from_secs(60);
+// キャッシュしておくセッションの数の上限
+const SESSION_CACHE_MAX_ENTRIES: usize = 10_000;
+
+// 認証済みのユーザー
+// `require_authentication` を通過したリクエストにのみ Extension として付与される
+#[derive(Debug, Clone)]
+pub struct AuthenticatedUser {
+    pub user_id: UserId,
+    // Bearer トークンで認証した場合のみ設定される
+    pub session_id: Option<SessionId>,
+}
+
+type SessionCacheEntries = HashMap<SessionId, (UserId, Instant)>;
+
+// 有効だったセッションごとの認証結果と確認した時刻を保持する
+// リクエストのたびにリポジトリへ問い合わせないよう、`SESSION_CACHE_TTL` の間は再利用する
+// 無効なトークンは保持しない。でたらめなトークンを送り続けられてもキャッシュが膨らまないようにするため
+#[derive(Clone)]
+pub struct SessionCache {
+    entries: Arc<Mutex<SessionCacheEntries>>,
+    max_entries: usize,
+}
+
+impl Default for SessionCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SessionCache {
+    pub fn new() -> Self {
+        Self::with_max_entries(SESSION_CACHE_MAX_ENTRIES)
+    }
+
+    fn with_max_entries(max_entries: usize) -> Self {
+        Self {
+            entries: Arc::default(),
+            max_entries,
+        }
+    }
+
+    fn get(&self, session_id: &SessionId) -> Option<UserId> {
+        let mut entries = self.entries.lock().unwrap();
+        match entries.get(session_id) {
+            Some((user_id, checked_at)) if checked_at.elapsed() < SESSION_CACHE_TTL => {
+                Some(user_id.clone())
+            }
+            Some(_) => {
+                entries.remove(session_id);
+                None
+            }
+            None => None,
+        }
+    }
+
+    fn insert(&self, session_id: SessionId, user_id: UserId) {
+        let mut entries = self.entries.lock().unwrap();
+        // 期限の切れたエントリーは参照されないまま残り続けるため、追加のたびに取り除く
+        entries.retain(|_, (_, checked_at)| checked_at.elapsed() < SESSION_CACHE_TTL);
+        // 上限に達している場合は、最も古いエントリーを追い出す
+        if entries.len() >= self.max_entries && !entries.contains_key(&session_id) {
+            let oldest = entries
+                .iter()
+                .min_by_key(|(_, (_, checked_at))| *checked_at)
+                .map(|(session_id, _)| session_id.clone());
+            if let Some(oldest) = oldest {
+                entries.remove(&oldest);
+            }
+        }
+        entries.insert(session_id, (user_id, Instant::now()));
+    }
+
+    // ログアウトしたセッションがキャッシュによって有効と判定され続けないよう破棄する
+    pub fn evict(&self, session_id: &SessionId) {
+        self.entries.lock().unwrap().remove(session_id);
+    }
+}
+
+// `Authorization: Bearer <session_id>` または `Authorization: Basic base64(<user_id>:<password>)` を検証し、
+// 成功すれば `AuthenticatedUser` をリクエストに付与して後続のハンドラーに渡す
+pub async fn require_authentication<CredentialRep, SessionRep, AS, SAS, B>(
+    Extension(credential_repository): Extension<Arc<CredentialRep>>,
+    Extension(session_repository): Extension<Arc<SessionRep>>,
+    Extension(session_cache): Extension<SessionCache>,
+    mut req: Request<B>,
+    next: Next<B>,
+) -> Response
+where
+    CredentialRep: ICredentialRepository,
+    SessionRep: ISessionRepository,
+    AS: IUserAuthenticateApplicationService<CredentialRep>,
+    SAS: ISessionAuthenticateApplicationService<SessionRep>,
+{
+    // locale_extractor より外側で使われた場合は英語で返す
+    let locale = req
+        .extensions()
+        .get::<Arc<Locale>>()
+        .map_or(Locale::En, |locale| **locale);
+    let messages = Messages::get();
+
+    if let Some(token) = parse_bearer_token(&req) {
+        let token = token.to_string();
+        return match authenticate_session::<SessionRep, SAS>(
+            session_repository,
+            &session_cache,
+            token,
+            locale,
+        )
+        .await
+        {
+            Ok(authenticated_user) => {
+                req.extensions_mut().insert(authenticated_user);
+                next.run(req).await
+            }
+            Err(res) => res,
+        };
+    }
+
+    let Some(command) = parse_basic_credentials(&req) else {
+        return unauthorized(messages.error(locale, "auth.credentials_required", &[]));
+    };
+
+    let user_authenticate_application_service = AS::new(credential_repository);
+
+    match user_authenticate_application_service.handle(command).await {
+        Ok(user_id) => {
+            req.extensions_mut().insert(AuthenticatedUser {
+                user_id,
+                session_id: None,
+            });
+            next.run(req).await
+        }
+        Err(e @ UserApplicationError::DuplicatedUser(_)) => {
+            ProblemDetails::new(StatusCode::INTERNAL_SERVER_ERROR, e.localize(locale))
+                .into_response()
+        }
+        Err(e @ UserApplicationError::IllegalArgumentError(_)) => {
+            ProblemDetails::new(StatusCode::INTERNAL_SERVER_ERROR, e.localize(locale))
+                .into_response()
+        }
+        Err(e @ UserApplicationError::IllegalUserId(_)) => unauthorized(e.localize(locale)),
+        Err(e @ UserApplicationError::IllegalUserRole(_)) => {
+            ProblemDetails::new(StatusCode::INTERNAL_SERVER_ERROR, e.localize(locale))
+                .into_response()
+        }
+        // どちらの理由で失敗したかは応答に含めない
+        Err(UserApplicationError::PasswordMismatch)
+        | Err(UserApplicationError::UserNotFound(_)) => {
+            unauthorized(messages.error(locale, "auth.invalid_credentials", &[]))
+        }
+        Err(UserApplicationError::AccountLocked { until }) => account_locked(until, locale),
+        Err(e @ UserApplicationError::PermissionDenied(_)) => {
+            ProblemDetails::new(StatusCode::INTERNAL_SERVER_ERROR, e.localize(locale))
+                .into_response()
+        }
+        Err(e @ UserApplicationError::Unexpected(_)) => {
+            ProblemDetails::new(StatusCode::INTERNAL_SERVER_ERROR, e.localize(locale))
+                .into_response()
+        }
+    }
+}
+
+// `Authorization` ヘッダーがあれば `require_authentication` と同じく検証し、無ければ認証せずに後続に渡す
+// 認証していなくても使えるが、認証したユーザーによって応答を変えるハンドラーに使う
+pub async fn authenticate_if_present<CredentialRep, SessionRep, AS, SAS, B>(
+    credential_repository: Extension<Arc<CredentialRep>>,
+    session_repository: Extension<Arc<SessionRep>>,
+    session_cache: Extension<SessionCache>,
+    req: Request<B>,
+    next: Next<B>,
+) -> Response
+where
+    CredentialRep: ICredentialRepository,
+    SessionRep: ISessionRepository,
+    AS: IUserAuthenticateApplicationService<CredentialRep>,
+    SAS: ISessionAuthenticateApplicationService<SessionRep>,
+{
+    if !req.headers().contains_key(AUTHORIZATION) {
+        return next.run(req).await;
+    }
+    require_authentication::<CredentialRep, SessionRep, AS, SAS, B>(
+        credential_repository,
+        session_repository,
+        session_cache,
+        req,
+        next,
+    )
+    .await
+}
+
+async fn authenticate_session<SessionRep, SAS>(
+    session_repository: Arc<SessionRep>,
+    session_cache: &SessionCache,
+    token: String,
+    locale: Locale,
+) -> Result<AuthenticatedUser, Response>
+where
+    SessionRep: ISessionRepository,
+    SAS: ISessionAuthenticateApplicationService<SessionRep>,
+{
+    let session_id = SessionId::parse(token).map_err(|e| {
+        unauthorized(SessionApplicationError::IllegalSessionId(e.to_string()).localize(locale))
+    })?;
+
+    let user_id = match session_cache.get(&session_id) {
+        Some(user_id) => Some(user_id),
+        None => {
+            let session_authenticate_application_service = SAS::new(session_repository);
+            let user_id = match session_authenticate_application_service
+                .handle(SessionAuthenticateCommand {
+                    session_id: session_id.clone(),
+                })
+                .await
+            {
+                Ok(user_id) => Some(user_id),
+                Err(SessionApplicationError::SessionNotFound(_))
+                | Err(SessionApplicationError::SessionRevoked(_)) => None,
+                Err(e @ SessionApplicationError::IllegalSessionId(_)) => {
+                    return Err(ProblemDetails::new(
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                        e.localize(locale),
+                    )
+                    .into_response())
+                }
+                Err(e @ SessionApplicationError::IllegalUserId(_)) => {
+                    return Err(ProblemDetails::new(
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                        e.localize(locale),
+                    )
+                    .into_response())
+                }
+                Err(e @ SessionApplicationError::InvalidCredentials) => {
+                    return Err(ProblemDetails::new(
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                        e.localize(locale),
+                    )
+                    .into_response())
+                }
+                Err(e @ SessionApplicationError::AccountLocked { .. }) => {
+                    return Err(ProblemDetails::new(
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                        e.localize(locale),
+                    )
+                    .into_response())
+                }
+                Err(e @ SessionApplicationError::Unexpected(_)) => {
+                    return Err(ProblemDetails::new(
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                        e.localize(locale),
+                    )
+                    .into_response())
+                }
+            };
+            if let Some(user_id) = &user_id {
+                session_cache.insert(session_id.clone(), user_id.clone());
+            }
+            user_id
+        }
+    };
+
+    match user_id {
+        Some(user_id) => Ok(AuthenticatedUser {
+            user_id,
+            session_id: Some(session_id),
+        }),
+        None => Err(unauthorized(Messages::get().error(
+            locale,
+            "auth.session_invalid",
+            &[],
+        ))),
+    }
+}
+
+fn parse_bearer_token<B>(req: &Request<B>) -> Option<&str> {
+    req.headers()
+        .get(AUTHORIZATION)?
+        .to_str()
+        .ok()?
+        .strip_prefix("Bearer ")
+}
+
+fn parse_basic_credentials<B>(req: &Request<B>) -> Option<UserAuthenticateCommand> {
+    let encoded = req
+        .headers()
+        .get(AUTHORIZATION)?
+        .to_str()
+        .ok()?
+        .strip_prefix("Basic ")?;
+    let decoded = String::from_utf8(STANDARD.decode(encoded).ok()?).ok()?;
+    let (user_id, password) = decoded.split_once(':')?;
+    Some(UserAuthenticateCommand {
+        user_id: user_id.to_string(),
+        password: password.to_string(),
+    })
+}
+
+// ロックが解除されるまでの秒数を Retry-After で伝える
+pub(super) fn account_locked(until: DateTime<Utc>, locale: Locale) -> Response {
+    let retry_after = (until - Utc::now()).num_seconds().max(1) as u64;
+    ProblemDetails::new(
+        StatusCode::LOCKED,
+        Messages::get().error(locale, "auth.account_locked", &[&until.to_rfc3339()]),
+    )
+    .with_retry_after(retry_after)
+    .into_response()
+}
+
+fn unauthorized(detail: String) -> Response {
+    let mut res = ProblemDetails::new(StatusCode::UNAUTHORIZED, detail).into_response();
+    res.headers_mut().insert(
+        WWW_AUTHENTICATE,
+        HeaderValue::from_static(
+            r#"Basic realm="hello_world_axum_3", Bearer realm="hello_world_axum_3""#,
+        ),
+    );
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::domain::value_object::ValueObject;
+
+    #[test]
+    fn should_not_exceed_max_entries() -> anyhow::Result<()> {
+        let session_cache = SessionCache::with_max_entries(2);
+        let session_ids = (0..3)
+            .map(|_| SessionId::new(Uuid::new_v4()))
+            .collect::<Result<Vec<_>, _>>()?;
+        let user_id = UserId::new(Uuid::new_v4())?;
+
+        for session_id in &session_ids {
+            session_cache.insert(session_id.clone(), user_id.clone());
+        }
+
+        // 上限を超えないよう古いものが追い出され、最後に追加したものは残る
+        assert_eq!(2, session_cache.entries.lock().unwrap().len());
+        assert_eq!(Some(user_id), session_cache.get(&session_ids[2]));
+        Ok(())
+    }
+}
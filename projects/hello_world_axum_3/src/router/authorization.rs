@@ -0,0 +1,94 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Extension, State},
+    http::Request,
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use hyper::StatusCode;
+
+use crate::{
+    application::users::{
+        user_application_error::UserApplicationError,
+        user_get_application_service::{IUserGetApplicationService, UserGetCommand},
+    },
+    domain::models::{
+        todos::todo_repository::ITodoRepository,
+        users::{user_repository::IUserRepository, user_role::UserRole},
+    },
+};
+
+use super::authentication::AuthenticatedUser;
+
+// 認証済みのユーザーが `required_role` を持っていなければ `403 Forbidden` を返す
+// `AuthenticatedUser` を使うため、`require_authentication` より内側に
+// `axum::middleware::from_fn_with_state` で必要なロールを渡して使う
+pub async fn authorize<UserRep, TodoRep, UGS, B>(
+    State(required_role): State<UserRole>,
+    Extension(user_repository): Extension<Arc<UserRep>>,
+    Extension(todo_repository): Extension<Arc<TodoRep>>,
+    Extension(authenticated_user): Extension<AuthenticatedUser>,
+    req: Request<B>,
+    next: Next<B>,
+) -> Response
+where
+    UserRep: IUserRepository,
+    TodoRep: ITodoRepository,
+    UGS: IUserGetApplicationService<UserRep, TodoRep>,
+{
+    let user_get_application_service = UGS::new(user_repository, todo_repository);
+
+    let user_data = match user_get_application_service
+        .handle(UserGetCommand {
+            user_id: authenticated_user.user_id.to_string(),
+            include_stats: false,
+            requested_by: Some(authenticated_user.user_id.clone()),
+        })
+        .await
+    {
+        // 本人の情報なので、すべての項目が見える
+        Ok(user_data) => match user_data.into_full() {
+            Some(user_data) => user_data,
+            None => return StatusCode::FORBIDDEN.into_response(),
+        },
+        Err(e @ UserApplicationError::DuplicatedUser(_)) => {
+            return (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response()
+        }
+        Err(e @ UserApplicationError::IllegalArgumentError(_)) => {
+            return (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response()
+        }
+        Err(e @ UserApplicationError::IllegalUserId(_)) => {
+            return (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response()
+        }
+        Err(e @ UserApplicationError::IllegalUserRole(_)) => {
+            return (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response()
+        }
+        Err(e @ UserApplicationError::PasswordMismatch) => {
+            return (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response()
+        }
+        Err(e @ UserApplicationError::AccountLocked { .. }) => {
+            return (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response()
+        }
+        Err(e @ UserApplicationError::PermissionDenied(_)) => {
+            return (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response()
+        }
+        // 認証後にユーザーが削除された場合は、権限がないものとして扱う
+        Err(e @ UserApplicationError::UserNotFound(_)) => {
+            return (StatusCode::FORBIDDEN, e.to_string()).into_response()
+        }
+        Err(e @ UserApplicationError::Unexpected(_)) => {
+            return (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response()
+        }
+    };
+
+    match user_data.user_role.parse::<UserRole>() {
+        Ok(user_role) if user_role == required_role => next.run(req).await,
+        Ok(_) => (
+            StatusCode::FORBIDDEN,
+            format!("The {} role is required.", required_role),
+        )
+            .into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
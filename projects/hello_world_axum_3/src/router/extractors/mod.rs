@@ -0,0 +1 @@
+pub mod query_params;
@@ -0,0 +1,91 @@
+use std::sync::Arc;
+
+use axum::{async_trait, extract::FromRequestParts, http::request::Parts};
+use hyper::StatusCode;
+use serde::de::DeserializeOwned;
+
+use crate::router::{locale::Locale, messages::Messages, problem_details::ProblemDetails};
+
+// クエリ文字列を serde_qs で `T` に変換するエクストラクター
+// `a[b]=c` のような入れ子の構造も扱え、変換できなければ ProblemDetails 形式の 400 を返す
+#[derive(Debug)]
+pub struct QueryParams<T>(pub T);
+
+#[async_trait]
+impl<T, S> FromRequestParts<S> for QueryParams<T>
+where
+    T: DeserializeOwned,
+    S: Send + Sync,
+{
+    type Rejection = ProblemDetails;
+
+    async fn from_request_parts(parts: &mut Parts, _: &S) -> Result<Self, Self::Rejection> {
+        let query = parts.uri.query().unwrap_or_default();
+        serde_qs::from_str(query).map(QueryParams).map_err(|e| {
+            // locale_extractor より外側で使われた場合は英語で返す
+            let locale = parts
+                .extensions
+                .get::<Arc<Locale>>()
+                .map_or(Locale::En, |locale| **locale);
+            ProblemDetails::new(
+                StatusCode::BAD_REQUEST,
+                Messages::get().error(locale, "query.malformed", &[&e.to_string()]),
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use axum::{http::Request, response::IntoResponse};
+    use serde::Deserialize;
+
+    use super::*;
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Params {
+        page: Option<u32>,
+        filter: Option<Filter>,
+    }
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Filter {
+        completed: bool,
+    }
+
+    async fn extract(uri: &str) -> Result<Params, ProblemDetails> {
+        let (mut parts, _) = Request::get(uri).body(()).unwrap().into_parts();
+        QueryParams::<Params>::from_request_parts(&mut parts, &())
+            .await
+            .map(|QueryParams(params)| params)
+    }
+
+    #[tokio::test]
+    async fn should_deserialize_nested_query() -> Result<()> {
+        let params = extract("/todos?page=2&filter[completed]=true")
+            .await
+            .unwrap();
+        assert_eq!(
+            Params {
+                page: Some(2),
+                filter: Some(Filter { completed: true }),
+            },
+            params
+        );
+        assert_eq!(
+            Params {
+                page: None,
+                filter: None,
+            },
+            extract("/todos").await.unwrap()
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_reject_malformed_query() {
+        let e = extract("/todos?page=abc").await.unwrap_err();
+        assert_eq!(StatusCode::BAD_REQUEST, e.into_response().status());
+    }
+}
@@ -0,0 +1,45 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Extension, State},
+    http::Request,
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use hyper::StatusCode;
+
+use crate::feature_flags::{Feature, FeatureFlags};
+
+use super::{locale::Locale, messages::Messages, problem_details::ProblemDetails};
+
+// ルートに対応する機能が無効化されていれば `501 Not Implemented` を返す
+// `axum::middleware::from_fn_with_state` で、ルートごとに対象の機能を渡して使う
+pub async fn feature_flag_guard<B>(
+    State(feature): State<Feature>,
+    Extension(feature_flags): Extension<Arc<FeatureFlags>>,
+    Extension(locale): Extension<Arc<Locale>>,
+    req: Request<B>,
+    next: Next<B>,
+) -> Response {
+    if is_guarded(feature, &req) && !feature_flags.is_enabled(feature) {
+        return ProblemDetails::new(
+            StatusCode::NOT_IMPLEMENTED,
+            Messages::get().error(*locale, "feature.disabled", &[feature.name()]),
+        )
+        .into_response();
+    }
+    next.run(req).await
+}
+
+// 検索は一覧取得と同じルートを使うため、`q` クエリパラメータがあるときのみ対象とする
+fn is_guarded<B>(feature: Feature, req: &Request<B>) -> bool {
+    match feature {
+        Feature::Search => req
+            .uri()
+            .query()
+            .unwrap_or_default()
+            .split('&')
+            .any(|pair| pair.split('=').next() == Some("q")),
+        Feature::BulkOperations | Feature::Sse | Feature::Export => true,
+    }
+}
@@ -0,0 +1,187 @@
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use axum::{extract::Extension, response::IntoResponse, Json};
+use hyper::StatusCode;
+use serde::Serialize;
+use tokio::time::Instant;
+
+use crate::infra::health_probe::HealthProbe;
+
+// データベースの応答にこれより長くかかった場合は `Degraded` とする
+const DEGRADED_LATENCY_MS: u64 = 500;
+
+// リクエストのたびにデータベースへ問い合わせないよう、直前の結果をこの秒数だけ再利用する
+const DEFAULT_CACHE_SECONDS: u64 = 5;
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum HealthStatus {
+    Healthy,
+    // 遅いが問い合わせには応答している
+    Degraded,
+    // 問い合わせに失敗した
+    Unhealthy,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct HealthResponse {
+    status: HealthStatus,
+    latency_ms: u64,
+    // 複数のインスタンスのどれが応答したかを区別するための値
+    instance_id: String,
+}
+
+#[derive(Clone)]
+pub struct HealthChecker {
+    probe: Arc<dyn HealthProbe>,
+    instance_id: String,
+    cache_seconds: u64,
+    last_checked: Arc<Mutex<Option<(HealthResponse, Instant)>>>,
+}
+
+impl HealthChecker {
+    pub fn new(probe: Arc<dyn HealthProbe>, instance_id: String) -> Self {
+        Self {
+            probe,
+            instance_id,
+            cache_seconds: DEFAULT_CACHE_SECONDS,
+            last_checked: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    async fn check(&self) -> HealthResponse {
+        let cache_ttl = Duration::from_secs(self.cache_seconds);
+        if let Some((response, checked_at)) = &*self.last_checked.lock().unwrap() {
+            if checked_at.elapsed() < cache_ttl {
+                return response.clone();
+            }
+        }
+
+        let started_at = Instant::now();
+        let result = self.probe.ping().await;
+        let latency_ms = started_at.elapsed().as_millis() as u64;
+        let status = match result {
+            Ok(()) if latency_ms > DEGRADED_LATENCY_MS => HealthStatus::Degraded,
+            Ok(()) => HealthStatus::Healthy,
+            Err(e) => {
+                tracing::warn!("health check failed: {}", e);
+                HealthStatus::Unhealthy
+            }
+        };
+        let response = HealthResponse {
+            status,
+            latency_ms,
+            instance_id: self.instance_id.clone(),
+        };
+        *self.last_checked.lock().unwrap() = Some((response.clone(), Instant::now()));
+        response
+    }
+}
+
+// `Degraded` はサービスを続けられるため 200 を、`Unhealthy` のみ 503 を返す
+pub async fn health(Extension(health_checker): Extension<HealthChecker>) -> impl IntoResponse {
+    let response = health_checker.check().await;
+    let status_code = match response.status {
+        HealthStatus::Healthy | HealthStatus::Degraded => StatusCode::OK,
+        HealthStatus::Unhealthy => StatusCode::SERVICE_UNAVAILABLE,
+    };
+    (status_code, Json(response))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use anyhow::anyhow;
+    use axum::async_trait;
+
+    use super::*;
+
+    // 決まった時間だけ待ってから応答する、データベースの代わり
+    struct FakeProbe {
+        latency: Duration,
+        fails: bool,
+        calls: AtomicUsize,
+    }
+
+    impl FakeProbe {
+        fn new(latency_ms: u64, fails: bool) -> Arc<Self> {
+            Arc::new(Self {
+                latency: Duration::from_millis(latency_ms),
+                fails,
+                calls: AtomicUsize::new(0),
+            })
+        }
+    }
+
+    #[async_trait]
+    impl HealthProbe for FakeProbe {
+        async fn ping(&self) -> anyhow::Result<()> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            tokio::time::sleep(self.latency).await;
+            if self.fails {
+                return Err(anyhow!("connection refused"));
+            }
+            Ok(())
+        }
+    }
+
+    async fn status_of(health_checker: &HealthChecker) -> (StatusCode, HealthStatus) {
+        let status_code = health(Extension(health_checker.clone()))
+            .await
+            .into_response()
+            .status();
+        (status_code, health_checker.check().await.status)
+    }
+
+    // 時間を止めておき、sleep した分だけ進める
+    #[tokio::test(start_paused = true)]
+    async fn should_be_healthy_if_database_responds_quickly() {
+        let health_checker =
+            HealthChecker::new(FakeProbe::new(10, false), "instance-1".to_string());
+        assert_eq!(
+            (StatusCode::OK, HealthStatus::Healthy),
+            status_of(&health_checker).await
+        );
+        let response = health_checker.check().await;
+        assert_eq!(10, response.latency_ms);
+        assert_eq!("instance-1", response.instance_id);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn should_be_degraded_but_ok_if_database_responds_slowly() {
+        let health_checker =
+            HealthChecker::new(FakeProbe::new(600, false), "instance-1".to_string());
+        assert_eq!(
+            (StatusCode::OK, HealthStatus::Degraded),
+            status_of(&health_checker).await
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn should_be_unhealthy_if_query_fails() {
+        let health_checker = HealthChecker::new(FakeProbe::new(10, true), "instance-1".to_string());
+        assert_eq!(
+            (StatusCode::SERVICE_UNAVAILABLE, HealthStatus::Unhealthy),
+            status_of(&health_checker).await
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn should_reuse_last_result_for_cache_seconds() {
+        let probe = FakeProbe::new(10, false);
+        let health_checker = HealthChecker::new(probe.clone(), "instance-1".to_string());
+
+        health_checker.check().await;
+        tokio::time::advance(Duration::from_secs(4)).await;
+        health_checker.check().await;
+        assert_eq!(1, probe.calls.load(Ordering::SeqCst));
+
+        tokio::time::advance(Duration::from_secs(2)).await;
+        health_checker.check().await;
+        assert_eq!(2, probe.calls.load(Ordering::SeqCst));
+    }
+}
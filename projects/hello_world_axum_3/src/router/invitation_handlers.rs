@@ -0,0 +1,183 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Extension, Path},
+    response::IntoResponse,
+    Json,
+};
+use chrono::{DateTime, Utc};
+use hyper::StatusCode;
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+
+use crate::{
+    application::{
+        invitations::{
+            invitation_accept_application_service::{
+                IInvitationAcceptApplicationService, InvitationAcceptCommand,
+            },
+            invitation_application_error::InvitationApplicationError,
+            invitation_create_application_service::{
+                IInvitationCreateApplicationService, InvitationCreateCommand,
+            },
+            invitation_data::InvitationData,
+            invitation_decline_application_service::{
+                IInvitationDeclineApplicationService, InvitationDeclineCommand,
+            },
+        },
+        rfc3339::Rfc3339,
+    },
+    domain::{
+        events::event_bus::EventBus,
+        models::{
+            invitations::invitation_repository::IInvitationRepository,
+            users::user_repository::IUserRepository,
+        },
+    },
+};
+
+use super::authentication::AuthenticatedUser;
+
+#[serde_as]
+#[derive(Serialize)]
+pub struct InvitationResponse {
+    id: String,
+    list_id: String,
+    invitee_email: String,
+    invitee_id: Option<String>,
+    invited_by: String,
+    status: String,
+    #[serde_as(as = "Rfc3339")]
+    expires_at: DateTime<Utc>,
+}
+
+impl InvitationResponse {
+    fn new(invitation_data: InvitationData) -> Self {
+        Self {
+            id: invitation_data.invite_id.to_string(),
+            list_id: invitation_data.list_id.to_string(),
+            invitee_email: invitation_data.invitee_email,
+            invitee_id: invitation_data
+                .invitee_id
+                .map(|invitee_id| invitee_id.to_string()),
+            invited_by: invitation_data.invited_by.to_string(),
+            status: invitation_data.status,
+            expires_at: invitation_data.expires_at,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+pub struct InvitationCreatePayload {
+    invitee_email: String,
+    invitee_id: String,
+}
+
+fn error_response(e: InvitationApplicationError) -> (StatusCode, String) {
+    let status_code = match e {
+        InvitationApplicationError::InvitationNotFound(_) => StatusCode::NOT_FOUND,
+        // 期限を過ぎた招待には、もう応答できない
+        InvitationApplicationError::InvitationExpired(_) => StatusCode::GONE,
+        InvitationApplicationError::AlreadyResponded(_, _) => StatusCode::CONFLICT,
+        // 招待先のユーザー以外は応答できず、リストのメンバー以外は招待できない
+        InvitationApplicationError::NotInvitee(_) => StatusCode::FORBIDDEN,
+        InvitationApplicationError::NotListMember(_) => StatusCode::FORBIDDEN,
+        InvitationApplicationError::InviteeNotFound(_) => StatusCode::NOT_FOUND,
+        InvitationApplicationError::IllegalArgumentError(_) => StatusCode::BAD_REQUEST,
+        InvitationApplicationError::IllegalInvitationId(_) => StatusCode::BAD_REQUEST,
+        InvitationApplicationError::IllegalListId(_) => StatusCode::BAD_REQUEST,
+        InvitationApplicationError::IllegalUserId(_) => StatusCode::BAD_REQUEST,
+        InvitationApplicationError::Unexpected(_) => StatusCode::INTERNAL_SERVER_ERROR,
+    };
+    (status_code, e.to_string())
+}
+
+// 認証済みのユーザーから、指定したユーザーへリストへの招待を送る
+// 招待できるのはリストのメンバーだけ
+pub async fn create<InvitationRep, UserRep, AS>(
+    Extension(invitation_repository): Extension<Arc<InvitationRep>>,
+    Extension(user_repository): Extension<Arc<UserRep>>,
+    Extension(event_bus): Extension<Arc<EventBus>>,
+    Extension(authenticated_user): Extension<AuthenticatedUser>,
+    Path(id): Path<String>,
+    Json(payload): Json<InvitationCreatePayload>,
+) -> Result<impl IntoResponse, impl IntoResponse>
+where
+    InvitationRep: IInvitationRepository,
+    UserRep: IUserRepository,
+    AS: IInvitationCreateApplicationService<InvitationRep, UserRep>,
+{
+    let invitation_create_application_service =
+        AS::new(invitation_repository, user_repository, event_bus);
+
+    match invitation_create_application_service
+        .handle(InvitationCreateCommand {
+            list_id: id,
+            invitee_email: payload.invitee_email,
+            invitee_id: payload.invitee_id,
+            invited_by: authenticated_user.user_id,
+        })
+        .await
+    {
+        Ok(invitation_data) => Ok((
+            StatusCode::CREATED,
+            Json(InvitationResponse::new(invitation_data)),
+        )),
+        Err(e) => Err(error_response(e)),
+    }
+}
+
+// 招待を承諾し、認証済みのユーザーをリストのメンバーに加える
+// 承諾できるのは招待先のユーザー本人だけ
+pub async fn accept<InvitationRep, AS>(
+    Extension(invitation_repository): Extension<Arc<InvitationRep>>,
+    Extension(authenticated_user): Extension<AuthenticatedUser>,
+    Path(invite_id): Path<String>,
+) -> Result<impl IntoResponse, impl IntoResponse>
+where
+    InvitationRep: IInvitationRepository,
+    AS: IInvitationAcceptApplicationService<InvitationRep>,
+{
+    let invitation_accept_application_service = AS::new(invitation_repository);
+
+    match invitation_accept_application_service
+        .handle(InvitationAcceptCommand {
+            invite_id,
+            user_id: authenticated_user.user_id,
+        })
+        .await
+    {
+        Ok(invitation_data) => Ok((
+            StatusCode::OK,
+            Json(InvitationResponse::new(invitation_data)),
+        )),
+        Err(e) => Err(error_response(e)),
+    }
+}
+
+// 辞退できるのも招待先のユーザー本人だけ
+pub async fn decline<InvitationRep, AS>(
+    Extension(invitation_repository): Extension<Arc<InvitationRep>>,
+    Extension(authenticated_user): Extension<AuthenticatedUser>,
+    Path(invite_id): Path<String>,
+) -> Result<impl IntoResponse, impl IntoResponse>
+where
+    InvitationRep: IInvitationRepository,
+    AS: IInvitationDeclineApplicationService<InvitationRep>,
+{
+    let invitation_decline_application_service = AS::new(invitation_repository);
+
+    match invitation_decline_application_service
+        .handle(InvitationDeclineCommand {
+            invite_id,
+            user_id: authenticated_user.user_id,
+        })
+        .await
+    {
+        Ok(invitation_data) => Ok((
+            StatusCode::OK,
+            Json(InvitationResponse::new(invitation_data)),
+        )),
+        Err(e) => Err(error_response(e)),
+    }
+}
@@ -1,7 +1,7 @@
 use std::sync::Arc;
 
 use axum::{
-    extract::{Extension, Path},
+    extract::{Extension, OriginalUri, Path, Query},
     response::IntoResponse,
     Json,
 };
@@ -9,22 +9,76 @@ use hyper::StatusCode;
 use serde::{Deserialize, Serialize};
 
 use crate::{
+    app_config::AppConfig,
     application::labels::{
         label_application_error::LabelApplicationError,
+        label_bulk_create_application_service::{
+            BulkCreateLabelResult, ILabelBulkCreateApplicationService, LabelBulkCreateCommand,
+        },
+        label_bulk_delete_application_service::{
+            BulkDeleteLabelResult, ILabelBulkDeleteApplicationService, LabelBulkDeleteCommand,
+        },
         label_create_application_service::{ILabelCreateApplicationService, LabelCreateCommand},
         label_data::LabelData,
         label_delete_application_service::{ILabelDeleteApplicationService, LabelDeleteCommand},
         label_get_all_aplication_service::{ILabelGetAllApplicationService, LabelGetAllCommand},
         label_get_application_service::{ILabelGetApplicationService, LabelGetCommand},
+        label_get_orphaned_application_service::ILabelGetOrphanedApplicationService,
+        label_get_recently_used_application_service::{
+            ILabelGetRecentlyUsedApplicationService, LabelGetRecentlyUsedCommand,
+        },
         label_update_application_service::{ILabelUpdateApplicationService, LabelUpdateCommand},
     },
-    domain::models::labels::label_repository::ILabelRepository,
+    domain::{
+        models::labels::label_repository::ILabelRepository,
+        services::profanity_filter::ProfanityFilter, value_object::ValueObject,
+    },
+};
+
+use super::{
+    locale::Locale,
+    messages::{Localize, Messages},
+    pagination::{paginate, PagedResponse, PaginationQuery},
+    problem_details::ProblemDetails,
+    resource_location::insert_resource_location,
 };
 
+impl Localize for LabelApplicationError {
+    fn localize(&self, locale: Locale) -> String {
+        let messages = Messages::get();
+        match self {
+            LabelApplicationError::DuplicatedLabel(label) => {
+                messages.error(locale, "label.duplicated", &[label.label_name.value()])
+            }
+            LabelApplicationError::LabelNotFound(label_id) => {
+                messages.error(locale, "label.not_found", &[&label_id.to_string()])
+            }
+            LabelApplicationError::IllegalArgumentError(message) => messages.error(
+                locale,
+                "label.illegal_argument",
+                &[&messages.domain_message(locale, message)],
+            ),
+            LabelApplicationError::IllegalLabelId(message) => messages.error(
+                locale,
+                "label.illegal_label_id",
+                &[&messages.domain_message(locale, message)],
+            ),
+            LabelApplicationError::Unexpected(message) => {
+                messages.error(locale, "unexpected", &[message])
+            }
+        }
+    }
+}
+
 #[derive(Serialize)]
 pub struct LabelResponse {
     id: String,
     name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    todo_count: Option<u64>,
+    // todo に付いたラベルとして返す場合のみ含める
+    #[serde(skip_serializing_if = "Option::is_none")]
+    attached_at: Option<String>,
 }
 
 impl LabelResponse {
@@ -32,6 +86,8 @@ impl LabelResponse {
         Self {
             id: label_data.label_id.to_string(),
             name: label_data.label_name,
+            todo_count: label_data.todo_count,
+            attached_at: label_data.attached_at,
         }
     }
 }
@@ -49,6 +105,103 @@ impl LabelCreatePayload {
     }
 }
 
+#[derive(Deserialize)]
+pub struct LabelBulkCreatePayload {
+    labels: Vec<LabelCreatePayload>,
+}
+
+impl LabelBulkCreatePayload {
+    fn into_command(self) -> LabelBulkCreateCommand {
+        LabelBulkCreateCommand {
+            labels: self
+                .labels
+                .into_iter()
+                .map(LabelCreatePayload::into_command)
+                .collect(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct LabelBulkCreateErrorResponse {
+    index: usize,
+    message: String,
+}
+
+#[derive(Serialize)]
+pub struct LabelBulkCreateResponse {
+    created: Vec<LabelResponse>,
+    errors: Vec<LabelBulkCreateErrorResponse>,
+}
+
+impl LabelBulkCreateResponse {
+    fn new(result: BulkCreateLabelResult, locale: Locale) -> Self {
+        Self {
+            created: result.created.into_iter().map(LabelResponse::new).collect(),
+            errors: result
+                .errors
+                .into_iter()
+                .map(|(index, e)| LabelBulkCreateErrorResponse {
+                    index,
+                    message: e.localize(locale),
+                })
+                .collect(),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+pub struct LabelBulkDeletePayload {
+    ids: Vec<String>,
+    #[serde(default)]
+    force: bool,
+}
+
+impl LabelBulkDeletePayload {
+    fn into_command(self) -> LabelBulkDeleteCommand {
+        LabelBulkDeleteCommand {
+            label_ids: self.ids,
+            force: self.force,
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct LabelInUseResponse {
+    id: String,
+    todo_count: u64,
+}
+
+#[derive(Serialize)]
+pub struct LabelBulkDeleteResponse {
+    deleted: Vec<String>,
+    in_use: Vec<LabelInUseResponse>,
+    not_found: Vec<String>,
+    parse_errors: Vec<LabelBulkCreateErrorResponse>,
+}
+
+impl LabelBulkDeleteResponse {
+    fn new(result: BulkDeleteLabelResult) -> Self {
+        Self {
+            deleted: result.deleted.iter().map(ToString::to_string).collect(),
+            in_use: result
+                .in_use
+                .iter()
+                .map(|(label_id, todo_count)| LabelInUseResponse {
+                    id: label_id.to_string(),
+                    todo_count: *todo_count,
+                })
+                .collect(),
+            not_found: result.not_found.iter().map(ToString::to_string).collect(),
+            parse_errors: result
+                .parse_errors
+                .into_iter()
+                .map(|(index, message)| LabelBulkCreateErrorResponse { index, message })
+                .collect(),
+        }
+    }
+}
+
 #[derive(Deserialize)]
 pub struct LabelUpdatePayload {
     name: Option<String>,
@@ -64,39 +217,140 @@ impl LabelUpdatePayload {
 }
 
 pub async fn create<Rep, AS>(
+    Extension(locale): Extension<Arc<Locale>>,
     Extension(repository): Extension<Arc<Rep>>,
+    Extension(profanity_filter): Extension<Arc<ProfanityFilter>>,
+    Extension(app_config): Extension<Arc<AppConfig>>,
     Json(payload): Json<LabelCreatePayload>,
 ) -> Result<impl IntoResponse, impl IntoResponse>
 where
     Rep: ILabelRepository,
     AS: ILabelCreateApplicationService<Rep>,
 {
-    let label_create_application_service = AS::new(repository);
+    let label_create_application_service = AS::new(repository, profanity_filter);
 
     match label_create_application_service
         .handle(payload.into_command())
         .await
     {
-        Ok(label_data) => Ok((StatusCode::CREATED, Json(LabelResponse::new(label_data)))),
-        Err(e @ LabelApplicationError::DuplicatedLabel(_)) => {
-            Err((StatusCode::BAD_REQUEST, e.to_string()))
-        }
-        Err(e @ LabelApplicationError::IllegalArgumentError(_)) => {
-            Err((StatusCode::BAD_REQUEST, e.to_string()))
-        }
-        Err(e @ LabelApplicationError::IllegalLabelId(_)) => {
-            Err((StatusCode::BAD_REQUEST, e.to_string()))
-        }
-        Err(e @ LabelApplicationError::LabelNotFound(_)) => {
-            Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
-        }
-        Err(e @ LabelApplicationError::Unexpected(_)) => {
-            Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+        Ok(label_data) => {
+            let path = format!("/labels/{}", label_data.label_id);
+            let mut response =
+                (StatusCode::CREATED, Json(LabelResponse::new(label_data))).into_response();
+            insert_resource_location(&mut response, &app_config, &path, true);
+            Ok(response)
         }
+        Err(e @ LabelApplicationError::DuplicatedLabel(_)) => Err(ProblemDetails::new(
+            StatusCode::UNPROCESSABLE_ENTITY,
+            e.localize(*locale),
+        )),
+        Err(e @ LabelApplicationError::IllegalArgumentError(_)) => Err(ProblemDetails::new(
+            StatusCode::UNPROCESSABLE_ENTITY,
+            e.localize(*locale),
+        )),
+        Err(e @ LabelApplicationError::IllegalLabelId(_)) => Err(ProblemDetails::new(
+            StatusCode::BAD_REQUEST,
+            e.localize(*locale),
+        )),
+        Err(e @ LabelApplicationError::LabelNotFound(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ LabelApplicationError::Unexpected(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+    }
+}
+
+pub async fn bulk_create<Rep, AS>(
+    Extension(locale): Extension<Arc<Locale>>,
+    Extension(repository): Extension<Arc<Rep>>,
+    Json(payload): Json<LabelBulkCreatePayload>,
+) -> Result<impl IntoResponse, impl IntoResponse>
+where
+    Rep: ILabelRepository,
+    AS: ILabelBulkCreateApplicationService<Rep>,
+{
+    let label_bulk_create_application_service = AS::new(repository);
+
+    match label_bulk_create_application_service
+        .handle(payload.into_command())
+        .await
+    {
+        Ok(result) => Ok((
+            StatusCode::MULTI_STATUS,
+            Json(LabelBulkCreateResponse::new(result, *locale)),
+        )),
+        Err(e @ LabelApplicationError::DuplicatedLabel(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ LabelApplicationError::IllegalArgumentError(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ LabelApplicationError::IllegalLabelId(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ LabelApplicationError::LabelNotFound(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ LabelApplicationError::Unexpected(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+    }
+}
+
+// `force` が false の場合、todo に付いているラベルは削除せずに `in_use` で知らせる
+pub async fn bulk_delete<Rep, AS>(
+    Extension(locale): Extension<Arc<Locale>>,
+    Extension(repository): Extension<Arc<Rep>>,
+    Json(payload): Json<LabelBulkDeletePayload>,
+) -> Result<impl IntoResponse, impl IntoResponse>
+where
+    Rep: ILabelRepository,
+    AS: ILabelBulkDeleteApplicationService<Rep>,
+{
+    let label_bulk_delete_application_service = AS::new(repository);
+
+    match label_bulk_delete_application_service
+        .handle(payload.into_command())
+        .await
+    {
+        Ok(result) => Ok((
+            StatusCode::MULTI_STATUS,
+            Json(LabelBulkDeleteResponse::new(result)),
+        )),
+        Err(e @ LabelApplicationError::DuplicatedLabel(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ LabelApplicationError::IllegalArgumentError(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ LabelApplicationError::IllegalLabelId(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        // 確認してから削除するまでの間に、別のリクエストで削除された
+        Err(e @ LabelApplicationError::LabelNotFound(_)) => Err(ProblemDetails::new(
+            StatusCode::CONFLICT,
+            e.localize(*locale),
+        )),
+        Err(e @ LabelApplicationError::Unexpected(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
     }
 }
 
 pub async fn get<Rep, AS>(
+    Extension(locale): Extension<Arc<Locale>>,
     Extension(repository): Extension<Arc<Rep>>,
     Path(id): Path<String>,
 ) -> Result<impl IntoResponse, impl IntoResponse>
@@ -111,26 +365,49 @@ where
         .await
     {
         Ok(label_data) => Ok((StatusCode::OK, Json(LabelResponse::new(label_data)))),
-        Err(e @ LabelApplicationError::DuplicatedLabel(_)) => {
-            Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
-        }
-        Err(e @ LabelApplicationError::IllegalArgumentError(_)) => {
-            Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
-        }
-        Err(e @ LabelApplicationError::IllegalLabelId(_)) => {
-            Err((StatusCode::BAD_REQUEST, e.to_string()))
-        }
-        Err(e @ LabelApplicationError::LabelNotFound(_)) => {
-            Err((StatusCode::NOT_FOUND, e.to_string()))
-        }
-        Err(e @ LabelApplicationError::Unexpected(_)) => {
-            Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
-        }
+        Err(e @ LabelApplicationError::DuplicatedLabel(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ LabelApplicationError::IllegalArgumentError(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ LabelApplicationError::IllegalLabelId(_)) => Err(ProblemDetails::new(
+            StatusCode::BAD_REQUEST,
+            e.localize(*locale),
+        )),
+        Err(e @ LabelApplicationError::LabelNotFound(_)) => Err(ProblemDetails::new(
+            StatusCode::NOT_FOUND,
+            e.localize(*locale),
+        )),
+        Err(e @ LabelApplicationError::Unexpected(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
     }
 }
 
+// 一覧の並び順。指定しなければ名前順に並べる
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum LabelSort {
+    #[serde(rename = "position")]
+    Position,
+}
+
+#[derive(Deserialize)]
+pub struct LabelGetAllQuery {
+    #[serde(default)]
+    include_counts: bool,
+    sort: Option<LabelSort>,
+}
+
 pub async fn get_all<Rep, AS>(
+    Extension(locale): Extension<Arc<Locale>>,
     Extension(repository): Extension<Arc<Rep>>,
+    OriginalUri(uri): OriginalUri,
+    Query(pagination): Query<PaginationQuery>,
+    Query(query): Query<LabelGetAllQuery>,
 ) -> Result<impl IntoResponse, impl IntoResponse>
 where
     Rep: ILabelRepository,
@@ -139,37 +416,151 @@ where
     let label_get_all_application_service = AS::new(repository);
 
     match label_get_all_application_service
-        .handle(LabelGetAllCommand {})
+        .handle(LabelGetAllCommand {
+            include_counts: query.include_counts,
+            order_by_position: query.sort == Some(LabelSort::Position),
+        })
         .await
     {
+        Ok(label_data) => {
+            let (label_data, meta, headers) = paginate(label_data, &uri, &pagination);
+            Ok((
+                StatusCode::OK,
+                headers,
+                Json(PagedResponse::new(
+                    label_data.into_iter().map(LabelResponse::new).collect(),
+                    meta,
+                )),
+            ))
+        }
+        Err(e @ LabelApplicationError::DuplicatedLabel(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ LabelApplicationError::IllegalArgumentError(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ LabelApplicationError::IllegalLabelId(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ LabelApplicationError::LabelNotFound(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ LabelApplicationError::Unexpected(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+    }
+}
+
+// どの todo にも付いていないラベルの一覧を返す
+pub async fn get_orphaned<Rep, AS>(
+    Extension(locale): Extension<Arc<Locale>>,
+    Extension(repository): Extension<Arc<Rep>>,
+) -> Result<impl IntoResponse, impl IntoResponse>
+where
+    Rep: ILabelRepository,
+    AS: ILabelGetOrphanedApplicationService<Rep>,
+{
+    let label_get_orphaned_application_service = AS::new(repository);
+
+    match label_get_orphaned_application_service.handle().await {
         Ok(label_data) => Ok((
             StatusCode::OK,
             Json(
                 label_data
                     .into_iter()
-                    .map(|label_data| LabelResponse::new(label_data))
-                    .collect::<Vec<LabelResponse>>(),
+                    .map(LabelResponse::new)
+                    .collect::<Vec<_>>(),
             ),
         )),
-        Err(e @ LabelApplicationError::DuplicatedLabel(_)) => {
-            Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
-        }
-        Err(e @ LabelApplicationError::IllegalArgumentError(_)) => {
-            Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
-        }
-        Err(e @ LabelApplicationError::IllegalLabelId(_)) => {
-            Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
-        }
-        Err(e @ LabelApplicationError::LabelNotFound(_)) => {
-            Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
-        }
-        Err(e @ LabelApplicationError::Unexpected(_)) => {
-            Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
-        }
+        Err(e @ LabelApplicationError::DuplicatedLabel(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ LabelApplicationError::IllegalArgumentError(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ LabelApplicationError::IllegalLabelId(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ LabelApplicationError::LabelNotFound(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ LabelApplicationError::Unexpected(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+    }
+}
+
+// `limit` が指定されない場合は 10 件まで返す
+const DEFAULT_RECENTLY_USED_LIMIT: u32 = 10;
+
+#[derive(Deserialize)]
+pub struct LabelGetRecentlyUsedQuery {
+    limit: Option<u32>,
+}
+
+// todo に最後に付けた日時が新しい順にラベルの一覧を返す
+pub async fn get_recently_used<Rep, AS>(
+    Extension(locale): Extension<Arc<Locale>>,
+    Extension(repository): Extension<Arc<Rep>>,
+    Query(query): Query<LabelGetRecentlyUsedQuery>,
+) -> Result<impl IntoResponse, impl IntoResponse>
+where
+    Rep: ILabelRepository,
+    AS: ILabelGetRecentlyUsedApplicationService<Rep>,
+{
+    let label_get_recently_used_application_service = AS::new(repository);
+    let command = LabelGetRecentlyUsedCommand {
+        limit: query.limit.unwrap_or(DEFAULT_RECENTLY_USED_LIMIT),
+    };
+
+    match label_get_recently_used_application_service
+        .handle(command)
+        .await
+    {
+        Ok(label_data) => Ok((
+            StatusCode::OK,
+            Json(
+                label_data
+                    .into_iter()
+                    .map(LabelResponse::new)
+                    .collect::<Vec<_>>(),
+            ),
+        )),
+        Err(e @ LabelApplicationError::DuplicatedLabel(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ LabelApplicationError::IllegalArgumentError(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ LabelApplicationError::IllegalLabelId(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ LabelApplicationError::LabelNotFound(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ LabelApplicationError::Unexpected(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
     }
 }
 
 pub async fn update<Rep, AS>(
+    Extension(locale): Extension<Arc<Locale>>,
     Extension(repository): Extension<Arc<Rep>>,
     Path(id): Path<String>,
     Json(payload): Json<LabelUpdatePayload>,
@@ -185,25 +576,31 @@ where
         .await
     {
         Ok(label_data) => Ok((StatusCode::OK, Json(LabelResponse::new(label_data)))),
-        Err(e @ LabelApplicationError::DuplicatedLabel(_)) => {
-            Err((StatusCode::BAD_REQUEST, e.to_string()))
-        }
-        Err(e @ LabelApplicationError::IllegalArgumentError(_)) => {
-            Err((StatusCode::BAD_REQUEST, e.to_string()))
-        }
-        Err(e @ LabelApplicationError::IllegalLabelId(_)) => {
-            Err((StatusCode::BAD_REQUEST, e.to_string()))
-        }
-        Err(e @ LabelApplicationError::LabelNotFound(_)) => {
-            Err((StatusCode::NOT_FOUND, e.to_string()))
-        }
-        Err(e @ LabelApplicationError::Unexpected(_)) => {
-            Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
-        }
+        Err(e @ LabelApplicationError::DuplicatedLabel(_)) => Err(ProblemDetails::new(
+            StatusCode::UNPROCESSABLE_ENTITY,
+            e.localize(*locale),
+        )),
+        Err(e @ LabelApplicationError::IllegalArgumentError(_)) => Err(ProblemDetails::new(
+            StatusCode::UNPROCESSABLE_ENTITY,
+            e.localize(*locale),
+        )),
+        Err(e @ LabelApplicationError::IllegalLabelId(_)) => Err(ProblemDetails::new(
+            StatusCode::BAD_REQUEST,
+            e.localize(*locale),
+        )),
+        Err(e @ LabelApplicationError::LabelNotFound(_)) => Err(ProblemDetails::new(
+            StatusCode::NOT_FOUND,
+            e.localize(*locale),
+        )),
+        Err(e @ LabelApplicationError::Unexpected(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
     }
 }
 
 pub async fn delete<Rep, AS>(
+    Extension(locale): Extension<Arc<Locale>>,
     Extension(repository): Extension<Arc<Rep>>,
     Path(id): Path<String>,
 ) -> Result<StatusCode, impl IntoResponse>
@@ -218,20 +615,25 @@ where
         .await
     {
         Ok(_) => Ok(StatusCode::NO_CONTENT),
-        Err(e @ LabelApplicationError::DuplicatedLabel(_)) => {
-            Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
-        }
-        Err(e @ LabelApplicationError::IllegalArgumentError(_)) => {
-            Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
-        }
-        Err(e @ LabelApplicationError::IllegalLabelId(_)) => {
-            Err((StatusCode::BAD_REQUEST, e.to_string()))
-        }
-        Err(e @ LabelApplicationError::LabelNotFound(_)) => {
-            Err((StatusCode::NOT_FOUND, e.to_string()))
-        }
-        Err(e @ LabelApplicationError::Unexpected(_)) => {
-            Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
-        }
+        Err(e @ LabelApplicationError::DuplicatedLabel(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ LabelApplicationError::IllegalArgumentError(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ LabelApplicationError::IllegalLabelId(_)) => Err(ProblemDetails::new(
+            StatusCode::BAD_REQUEST,
+            e.localize(*locale),
+        )),
+        Err(e @ LabelApplicationError::LabelNotFound(_)) => Err(ProblemDetails::new(
+            StatusCode::NOT_FOUND,
+            e.localize(*locale),
+        )),
+        Err(e @ LabelApplicationError::Unexpected(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
     }
 }
@@ -0,0 +1,86 @@
+use std::sync::Arc;
+
+use axum::{http::Request, middleware::Next, response::Response};
+use hyper::header::ACCEPT_LANGUAGE;
+
+// エラーメッセージを返す言語
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Locale {
+    En,
+    Ja,
+}
+
+impl Locale {
+    // `Accept-Language` ヘッダーの値から、対応している言語のうち最も優先度の高いものを選ぶ
+    // 対応している言語が含まれなければ英語とする
+    pub fn from_accept_language(accept_language: &str) -> Self {
+        let mut languages: Vec<(&str, f32)> = accept_language
+            .split(',')
+            .filter_map(|language| {
+                let mut parts = language.split(';');
+                let tag = parts.next()?.trim();
+                let quality = parts
+                    .find_map(|param| param.trim().strip_prefix("q="))
+                    .map(|q| q.parse::<f32>().unwrap_or(0.0))
+                    .unwrap_or(1.0);
+                Some((tag, quality))
+            })
+            .filter(|(_, quality)| *quality > 0.0)
+            .collect();
+        // sort_by は安定ソートのため、同じ優先度ならヘッダーに書かれた順になる
+        languages.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+
+        languages
+            .into_iter()
+            .find_map(|(tag, _)| Self::from_language_tag(tag))
+            .unwrap_or(Locale::En)
+    }
+
+    fn from_language_tag(tag: &str) -> Option<Self> {
+        let primary = tag.split('-').next().unwrap_or_default();
+        match primary.to_lowercase().as_str() {
+            "en" => Some(Locale::En),
+            "ja" => Some(Locale::Ja),
+            _ => None,
+        }
+    }
+}
+
+// リクエストの `Accept-Language` を読み取り、`Arc<Locale>` を Extension として付与する
+pub async fn locale_extractor<B>(mut req: Request<B>, next: Next<B>) -> Response {
+    let locale = req
+        .headers()
+        .get(ACCEPT_LANGUAGE)
+        .and_then(|accept_language| accept_language.to_str().ok())
+        .map(Locale::from_accept_language)
+        .unwrap_or(Locale::En);
+    req.extensions_mut().insert(Arc::new(locale));
+    next.run(req).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_pick_supported_locale_with_highest_quality() {
+        assert_eq!(Locale::Ja, Locale::from_accept_language("ja"));
+        assert_eq!(Locale::Ja, Locale::from_accept_language("ja-JP,en;q=0.8"));
+        assert_eq!(
+            Locale::Ja,
+            Locale::from_accept_language("en;q=0.5, ja;q=0.9")
+        );
+        assert_eq!(
+            Locale::En,
+            Locale::from_accept_language("fr-FR, en-US;q=0.9, ja;q=0.8")
+        );
+        assert_eq!(Locale::En, Locale::from_accept_language("ja;q=0, en"));
+    }
+
+    #[test]
+    fn should_default_to_english_for_unsupported_locale() {
+        assert_eq!(Locale::En, Locale::from_accept_language("fr, de;q=0.5"));
+        assert_eq!(Locale::En, Locale::from_accept_language(""));
+        assert_eq!(Locale::En, Locale::from_accept_language("*"));
+    }
+}
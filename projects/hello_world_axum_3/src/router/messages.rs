@@ -0,0 +1,116 @@
+use std::{collections::HashMap, sync::OnceLock};
+
+use serde::Deserialize;
+
+use super::locale::Locale;
+
+// ロケールごとのメッセージ定義
+// `errors` はエラーコードをキーにしたメッセージで、`{0}`, `{1}`, ... が引数に置き換えられる
+// `domain_messages` はドメイン層が返す英語のメッセージをキーにした翻訳
+#[derive(Debug, Default, Deserialize)]
+struct LocaleMessages {
+    errors: HashMap<String, String>,
+    domain_messages: HashMap<String, String>,
+}
+
+#[derive(Debug)]
+pub struct Messages {
+    locales: HashMap<Locale, LocaleMessages>,
+}
+
+impl Messages {
+    // 起動時に一度だけロケールファイルを読み込む
+    pub fn get() -> &'static Messages {
+        static MESSAGES: OnceLock<Messages> = OnceLock::new();
+        MESSAGES.get_or_init(|| Messages {
+            locales: HashMap::from([
+                (Locale::En, parse(include_str!("../../locales/en.json"))),
+                (Locale::Ja, parse(include_str!("../../locales/ja.json"))),
+            ]),
+        })
+    }
+
+    // 指定したロケールにメッセージがなければ英語、それもなければエラーコードをそのまま返す
+    pub fn error(&self, locale: Locale, code: &str, args: &[&str]) -> String {
+        let template = self
+            .locales
+            .get(&locale)
+            .and_then(|messages| messages.errors.get(code))
+            .or_else(|| {
+                self.locales
+                    .get(&Locale::En)
+                    .and_then(|messages| messages.errors.get(code))
+            });
+        let Some(template) = template else {
+            return code.to_string();
+        };
+
+        args.iter()
+            .enumerate()
+            .fold(template.clone(), |message, (i, arg)| {
+                message.replace(&format!("{{{}}}", i), arg)
+            })
+    }
+
+    // 翻訳が登録されていないメッセージはそのまま返す
+    pub fn domain_message(&self, locale: Locale, message: &str) -> String {
+        self.locales
+            .get(&locale)
+            .and_then(|messages| messages.domain_messages.get(message))
+            .cloned()
+            .unwrap_or_else(|| message.to_string())
+    }
+}
+
+fn parse(json: &str) -> LocaleMessages {
+    serde_json::from_str(json).expect("invalid locale file")
+}
+
+// ロケールに合わせたエラーメッセージに変換する
+pub trait Localize {
+    fn localize(&self, locale: Locale) -> String;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_define_every_error_code_in_every_locale() {
+        let messages = Messages::get();
+        let en = &messages.locales[&Locale::En];
+        let ja = &messages.locales[&Locale::Ja];
+
+        let mut en_codes: Vec<&String> = en.errors.keys().collect();
+        let mut ja_codes: Vec<&String> = ja.errors.keys().collect();
+        en_codes.sort();
+        ja_codes.sort();
+        assert_eq!(en_codes, ja_codes);
+    }
+
+    #[test]
+    fn should_fill_arguments_and_fall_back() {
+        let messages = Messages::get();
+
+        assert_eq!(
+            "The search feature is disabled.",
+            messages.error(Locale::En, "feature.disabled", &["search"])
+        );
+        assert_eq!(
+            "search の機能は無効になっています。",
+            messages.error(Locale::Ja, "feature.disabled", &["search"])
+        );
+        assert_eq!(
+            "no.such.code",
+            messages.error(Locale::Ja, "no.such.code", &[])
+        );
+        assert_eq!(
+            "todo のテキストは空にできません。",
+            messages.domain_message(Locale::Ja, "Todo text must not be empty.")
+        );
+        assert_eq!(
+            "Todo text must not be empty.",
+            messages.domain_message(Locale::En, "Todo text must not be empty.")
+        );
+    }
+}
@@ -0,0 +1,47 @@
+use axum::{
+    http::{HeaderValue, Method, Request},
+    middleware::Next,
+    response::Response,
+};
+use hyper::{header::ALLOW, StatusCode};
+
+// `OPTIONS` リクエストに、ルートが受け付けるメソッドを `Allow` ヘッダーで返す (RFC 7231 §4.3.7)
+// axum は `Router::layer` で追加したレイヤーの外側で `Allow` ヘッダーを付与するため、
+// このミドルウェアはルーター全体を包むように追加する必要がある
+pub async fn respond_to_options<B>(req: Request<B>, next: Next<B>) -> Response {
+    if req.method() != Method::OPTIONS {
+        return next.run(req).await;
+    }
+
+    let mut res = next.run(req).await;
+    let Some(allow) = res
+        .headers()
+        .get(ALLOW)
+        .and_then(|allow| allow.to_str().ok())
+    else {
+        return res;
+    };
+
+    let mut methods = allow
+        .split(',')
+        .map(str::trim)
+        .filter(|method| !method.is_empty())
+        .map(str::to_string)
+        .collect::<Vec<String>>();
+    if !methods
+        .iter()
+        .any(|method| method == Method::OPTIONS.as_str())
+    {
+        methods.push(Method::OPTIONS.to_string());
+    }
+    let Ok(allow) = HeaderValue::from_str(&methods.join(", ")) else {
+        return res;
+    };
+
+    // CORS レイヤーを通らなかった場合は axum が 405 を返すため、200 に差し替える
+    if res.status() == StatusCode::METHOD_NOT_ALLOWED {
+        *res.status_mut() = StatusCode::OK;
+    }
+    res.headers_mut().insert(ALLOW, allow);
+    res
+}
@@ -0,0 +1,262 @@
+use std::fmt::Display;
+
+use axum::http::{header::LINK, HeaderMap, HeaderValue, Uri};
+use serde::{Deserialize, Serialize};
+
+const DEFAULT_PER_PAGE: u32 = 20;
+const MAX_PER_PAGE: u32 = 100;
+
+// 一覧取得のクエリパラメータ
+// `page` も `per_page` も指定されなければ、全件を返す
+#[derive(Deserialize)]
+pub struct PaginationQuery {
+    page: Option<u32>,
+    per_page: Option<u32>,
+}
+
+impl PaginationQuery {
+    pub fn new(page: Option<u32>, per_page: Option<u32>) -> Self {
+        Self { page, per_page }
+    }
+
+    pub fn is_requested(&self) -> bool {
+        self.page.is_some() || self.per_page.is_some()
+    }
+}
+
+// クライアントがページ送りを表示するための情報
+#[derive(Serialize, Debug, PartialEq)]
+pub struct PaginationMeta {
+    pub total: u64,
+    pub page: u32,
+    pub per_page: u32,
+    pub total_pages: u32,
+}
+
+impl PaginationMeta {
+    pub fn new(total: u64, page: u32, per_page: u32) -> Self {
+        let total_pages = if per_page == 0 {
+            0
+        } else {
+            u32::try_from(total.div_ceil(per_page as u64)).unwrap_or(u32::MAX)
+        };
+        Self {
+            total,
+            page,
+            per_page,
+            total_pages,
+        }
+    }
+
+    // ページ指定が無く全件を返すときは、全件を 1 ページとして扱う
+    pub fn unpaged(total: u64) -> Self {
+        Self::new(total, 1, u32::try_from(total).unwrap_or(u32::MAX))
+    }
+}
+
+// 一覧取得のレスポンス
+#[derive(Serialize)]
+pub struct PagedResponse<T> {
+    pub data: Vec<T>,
+    pub meta: PaginationMeta,
+}
+
+impl<T> PagedResponse<T> {
+    pub fn new(data: Vec<T>, meta: PaginationMeta) -> Self {
+        Self { data, meta }
+    }
+}
+
+// RFC 5988 形式の `Link` ヘッダーに載せるページ URL
+#[derive(Debug, PartialEq)]
+pub struct PaginationLinks {
+    first: String,
+    prev: Option<String>,
+    next: Option<String>,
+    last: String,
+}
+
+impl PaginationLinks {
+    // 前後どちらのページも無いときは `None` を返す
+    pub fn new(base_url: &str, current_page: u32, per_page: u32, total: u64) -> Option<Self> {
+        let last_page = last_page(per_page, total);
+        let has_prev = current_page > 1;
+        let has_next = current_page < last_page;
+        if !has_prev && !has_next {
+            return None;
+        }
+
+        let page_url = |page: u32| {
+            let separator = if base_url.contains('?') { '&' } else { '?' };
+            format!("{base_url}{separator}page={page}&per_page={per_page}")
+        };
+        Some(Self {
+            first: page_url(1),
+            prev: has_prev.then(|| page_url((current_page - 1).min(last_page))),
+            next: has_next.then(|| page_url(current_page + 1)),
+            last: page_url(last_page),
+        })
+    }
+}
+
+impl Display for PaginationLinks {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let links = [
+            ("first", Some(&self.first)),
+            ("prev", self.prev.as_ref()),
+            ("next", self.next.as_ref()),
+            ("last", Some(&self.last)),
+        ]
+        .into_iter()
+        .filter_map(|(rel, url)| url.map(|url| format!("<{url}>; rel=\"{rel}\"")))
+        .collect::<Vec<String>>();
+        write!(f, "{}", links.join(", "))
+    }
+}
+
+// 指定されたページの要素だけを取り出し、ページ送りの情報と `Link` ヘッダーを組み立てる
+pub fn paginate<T>(
+    items: Vec<T>,
+    uri: &Uri,
+    query: &PaginationQuery,
+) -> (Vec<T>, PaginationMeta, HeaderMap) {
+    let mut headers = HeaderMap::new();
+    if !query.is_requested() {
+        let meta = PaginationMeta::unpaged(items.len() as u64);
+        return (items, meta, headers);
+    }
+
+    let current_page = query.page.unwrap_or(1).max(1);
+    let per_page = query
+        .per_page
+        .unwrap_or(DEFAULT_PER_PAGE)
+        .clamp(1, MAX_PER_PAGE);
+    let total = items.len() as u64;
+
+    let links = PaginationLinks::new(&base_url(uri), current_page, per_page, total);
+    if let Some(link) = links.and_then(|links| HeaderValue::from_str(&links.to_string()).ok()) {
+        headers.insert(LINK, link);
+    }
+
+    let offset = (current_page as usize - 1).saturating_mul(per_page as usize);
+    let page_items = items
+        .into_iter()
+        .skip(offset)
+        .take(per_page as usize)
+        .collect();
+    (
+        page_items,
+        PaginationMeta::new(total, current_page, per_page),
+        headers,
+    )
+}
+
+fn last_page(per_page: u32, total: u64) -> u32 {
+    let last_page = total.div_ceil(per_page as u64).max(1);
+    u32::try_from(last_page).unwrap_or(u32::MAX)
+}
+
+// `page` と `per_page` 以外のクエリパラメータは残したまま、リクエストの URL を組み立て直す
+fn base_url(uri: &Uri) -> String {
+    let query = uri
+        .query()
+        .unwrap_or_default()
+        .split('&')
+        .filter(|pair| {
+            let key = pair.split('=').next().unwrap_or_default();
+            !pair.is_empty() && key != "page" && key != "per_page"
+        })
+        .collect::<Vec<&str>>()
+        .join("&");
+    if query.is_empty() {
+        uri.path().to_string()
+    } else {
+        format!("{}?{}", uri.path(), query)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use super::*;
+
+    #[test]
+    fn should_build_links_on_first_page() {
+        let links = PaginationLinks::new("/todos", 1, 20, 60).unwrap();
+
+        assert_eq!(
+            r#"</todos?page=1&per_page=20>; rel="first", </todos?page=2&per_page=20>; rel="next", </todos?page=3&per_page=20>; rel="last""#,
+            links.to_string()
+        );
+    }
+
+    #[test]
+    fn should_build_links_on_middle_and_last_page() {
+        let links = PaginationLinks::new("/users?role=Admin", 2, 20, 41).unwrap();
+        assert_eq!(
+            Some("/users?role=Admin&page=1&per_page=20".to_string()),
+            links.prev
+        );
+        assert_eq!(
+            Some("/users?role=Admin&page=3&per_page=20".to_string()),
+            links.next
+        );
+
+        let links = PaginationLinks::new("/todos", 3, 20, 60).unwrap();
+        assert_eq!(Some("/todos?page=2&per_page=20".to_string()), links.prev);
+        assert_eq!(None, links.next);
+        assert_eq!("/todos?page=3&per_page=20", links.last);
+    }
+
+    #[test]
+    fn should_not_build_links_for_single_page() {
+        assert_eq!(None, PaginationLinks::new("/todos", 1, 20, 20));
+        assert_eq!(None, PaginationLinks::new("/todos", 1, 20, 0));
+    }
+
+    #[test]
+    fn should_return_requested_page_only() -> Result<()> {
+        let uri: Uri = "/labels?page=2&per_page=2&sort=name".parse()?;
+        let query = PaginationQuery {
+            page: Some(2),
+            per_page: Some(2),
+        };
+
+        let (items, meta, headers) = paginate(vec![1, 2, 3, 4, 5], &uri, &query);
+
+        assert_eq!(vec![3, 4], items);
+        assert_eq!(PaginationMeta::new(5, 2, 2), meta);
+        assert_eq!(3, meta.total_pages);
+        assert_eq!(
+            r#"</labels?sort=name&page=1&per_page=2>; rel="first", </labels?sort=name&page=1&per_page=2>; rel="prev", </labels?sort=name&page=3&per_page=2>; rel="next", </labels?sort=name&page=3&per_page=2>; rel="last""#,
+            headers.get(LINK).unwrap().to_str()?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn should_return_all_items_without_pagination_query() -> Result<()> {
+        let uri: Uri = "/labels".parse()?;
+        let query = PaginationQuery {
+            page: None,
+            per_page: None,
+        };
+
+        let (items, meta, headers) = paginate(vec![1, 2, 3], &uri, &query);
+
+        assert_eq!(vec![1, 2, 3], items);
+        assert_eq!(PaginationMeta::new(3, 1, 3), meta);
+        assert_eq!(1, meta.total_pages);
+        assert!(headers.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn should_round_up_total_pages() {
+        assert_eq!(3, PaginationMeta::new(25, 2, 10).total_pages);
+        assert_eq!(2, PaginationMeta::new(20, 1, 10).total_pages);
+        assert_eq!(0, PaginationMeta::new(0, 1, 10).total_pages);
+        assert_eq!(0, PaginationMeta::unpaged(0).total_pages);
+    }
+}
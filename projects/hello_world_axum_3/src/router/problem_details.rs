@@ -0,0 +1,57 @@
+use axum::{
+    response::{IntoResponse, Response},
+    Json,
+};
+use hyper::{
+    header::{CONTENT_TYPE, RETRY_AFTER},
+    StatusCode,
+};
+use serde::Serialize;
+
+// RFC 7807 の Problem Details 形式のエラーレスポンス
+#[derive(Debug, Serialize)]
+pub struct ProblemDetails {
+    #[serde(rename = "type")]
+    problem_type: String,
+    title: String,
+    status: u16,
+    detail: String,
+    // 再試行までに待つべき秒数。本文には含めず Retry-After ヘッダーとして返す
+    #[serde(skip)]
+    retry_after: Option<u64>,
+}
+
+impl ProblemDetails {
+    // 種別を特に定義しないエラーは `about:blank` とし、title にはステータスの説明を使う
+    pub fn new(status: StatusCode, detail: impl Into<String>) -> Self {
+        Self {
+            problem_type: "about:blank".to_string(),
+            title: status.canonical_reason().unwrap_or_default().to_string(),
+            status: status.as_u16(),
+            detail: detail.into(),
+            retry_after: None,
+        }
+    }
+
+    pub fn with_retry_after(mut self, seconds: u64) -> Self {
+        self.retry_after = Some(seconds);
+        self
+    }
+}
+
+impl IntoResponse for ProblemDetails {
+    fn into_response(self) -> Response {
+        let status = StatusCode::from_u16(self.status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+        let retry_after = self.retry_after;
+        let mut response = (
+            status,
+            [(CONTENT_TYPE, "application/problem+json")],
+            Json(self),
+        )
+            .into_response();
+        if let Some(seconds) = retry_after {
+            response.headers_mut().insert(RETRY_AFTER, seconds.into());
+        }
+        response
+    }
+}
@@ -0,0 +1,286 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::OnceLock,
+    task::{Context, Poll},
+};
+
+use axum::{
+    body::Body,
+    http::Request,
+    response::{IntoResponse, Response},
+};
+use http_body::{LengthLimitError, Limited};
+use hyper::{header::CONTENT_TYPE, StatusCode};
+use regex::Regex;
+use tower::{Layer, Service};
+
+// ログに出力するリクエストボディの最大バイト数
+const MAX_LOGGED_BODY_BYTES: usize = 4096;
+// ログのために読み出すリクエストボディの最大バイト数
+// axum の `Json` などが既定で受け付ける上限 (`DefaultBodyLimit`) に合わせ、
+// このレイヤーの有無で受け付けるリクエストが変わらないようにする
+const MAX_BUFFERED_BODY_BYTES: usize = 2 * 1024 * 1024;
+
+// `Content-Type: application/json` のリクエストボディを `debug` レベルでログに出力するレイヤー
+// password などの機密情報はマスクしてから出力する
+#[derive(Clone, Default)]
+pub struct RequestBodyLogLayer;
+
+impl RequestBodyLogLayer {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl<S> Layer<S> for RequestBodyLogLayer {
+    type Service = RequestBodyLog<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RequestBodyLog { inner }
+    }
+}
+
+#[derive(Clone)]
+pub struct RequestBodyLog<S> {
+    inner: S,
+}
+
+impl<S> Service<Request<Body>> for RequestBodyLog<S>
+where
+    S: Service<Request<Body>, Response = Response> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: Request<Body>) -> Self::Future {
+        // poll_ready 済みのサービスを使うために clone したものと入れ替える
+        // <https://docs.rs/tower/latest/tower/trait.Service.html#be-careful-when-cloning-inner-services> を参考に実装
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+
+        Box::pin(async move {
+            if !is_json(&req) {
+                return inner.call(req).await;
+            }
+
+            // ボディを読み出してログに出力したあと、同じバイト列でボディを組み立て直す
+            // 読み出せなかったボディを空のボディとして後続に渡さないよう、ここでエラーを返す
+            let (parts, body) = req.into_parts();
+            let bytes =
+                match hyper::body::to_bytes(Limited::new(body, MAX_BUFFERED_BODY_BYTES)).await {
+                    Ok(bytes) => bytes,
+                    Err(e) if e.is::<LengthLimitError>() => {
+                        return Ok((StatusCode::PAYLOAD_TOO_LARGE, e.to_string()).into_response());
+                    }
+                    Err(e) => {
+                        tracing::warn!("failed to read request body: [{}]", e);
+                        return Ok((StatusCode::BAD_REQUEST, e.to_string()).into_response());
+                    }
+                };
+            tracing::debug!("request_body={}", sanitize_body(&bytes));
+
+            inner
+                .call(Request::from_parts(parts, Body::from(bytes)))
+                .await
+        })
+    }
+}
+
+fn is_json(req: &Request<Body>) -> bool {
+    req.headers()
+        .get(CONTENT_TYPE)
+        .and_then(|content_type| content_type.to_str().ok())
+        .map(|content_type| content_type.starts_with(mime::APPLICATION_JSON.as_ref()))
+        .unwrap_or(false)
+}
+
+// "Password" や "newPassword" のようなキーも漏らさないよう、大文字・小文字を区別せずに照合する
+fn password_pattern() -> &'static Regex {
+    static PASSWORD_PATTERN: OnceLock<Regex> = OnceLock::new();
+    PASSWORD_PATTERN.get_or_init(|| {
+        Regex::new(r#"(?i)"(\w*password\w*)"\s*:\s*"(?:[^"\\]|\\.)*""#).expect("invalid regex")
+    })
+}
+
+// password を含むキーの値をマスクし、最大 `MAX_LOGGED_BODY_BYTES` バイトに切り詰める
+// 切り詰めによってマスク対象の値が途中で切れないよう、マスクを先に行う
+fn sanitize_body(bytes: &[u8]) -> String {
+    let body = String::from_utf8_lossy(bytes);
+    let mut redacted = password_pattern()
+        .replace_all(&body, r#""$1":"<REDACTED>""#)
+        .into_owned();
+
+    if redacted.len() > MAX_LOGGED_BODY_BYTES {
+        let mut end = MAX_LOGGED_BODY_BYTES;
+        while !redacted.is_char_boundary(end) {
+            end -= 1;
+        }
+        redacted.truncate(end);
+        redacted.push_str("...(truncated)");
+    }
+    redacted
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::Write,
+        sync::{Arc, Mutex},
+    };
+
+    use anyhow::Result;
+    use axum::{http::method::Method, routing::post, Router};
+    use tower::ServiceExt;
+    use tracing_subscriber::fmt::MakeWriter;
+
+    use super::*;
+    use crate::router::tests;
+
+    #[derive(Clone, Default)]
+    struct LogBuffer(Arc<Mutex<Vec<u8>>>);
+
+    impl LogBuffer {
+        fn contents(&self) -> String {
+            String::from_utf8(self.0.lock().unwrap().clone()).unwrap()
+        }
+    }
+
+    impl Write for LogBuffer {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl<'a> MakeWriter<'a> for LogBuffer {
+        type Writer = Self;
+
+        fn make_writer(&'a self) -> Self::Writer {
+            self.clone()
+        }
+    }
+
+    fn create_echo_app() -> Router {
+        Router::new()
+            .route("/echo", post(|body: String| async { body }))
+            .layer(RequestBodyLogLayer::new())
+    }
+
+    #[tokio::test]
+    async fn should_log_request_body_with_password_redacted() -> Result<()> {
+        let log_buffer = LogBuffer::default();
+        let subscriber = tracing_subscriber::fmt()
+            .with_max_level(tracing::Level::DEBUG)
+            .with_ansi(false)
+            .with_writer(log_buffer.clone())
+            .finish();
+        let _guard = tracing::subscriber::set_default(subscriber);
+
+        let req_body = r#"{"user_name":"tester-1","password":"secret-password"}"#.to_string();
+        let req = tests::build_req_with_json("/echo", Method::POST, req_body.clone())?;
+        let res = create_echo_app().oneshot(req).await?;
+
+        // The downstream handler receives the original body
+        let res_body = hyper::body::to_bytes(res.into_body()).await?;
+        assert_eq!(req_body.as_bytes(), &res_body[..]);
+
+        let log = log_buffer.contents();
+        assert!(log.contains(r#"request_body={"user_name":"tester-1","password":"<REDACTED>"}"#));
+        assert!(!log.contains("secret-password"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_not_log_non_json_request_body() -> Result<()> {
+        let log_buffer = LogBuffer::default();
+        let subscriber = tracing_subscriber::fmt()
+            .with_max_level(tracing::Level::DEBUG)
+            .with_ansi(false)
+            .with_writer(log_buffer.clone())
+            .finish();
+        let _guard = tracing::subscriber::set_default(subscriber);
+
+        let req = Request::builder()
+            .uri("/echo")
+            .method(Method::POST)
+            .header(CONTENT_TYPE, mime::TEXT_PLAIN.as_ref())
+            .body(Body::from("plain text"))?;
+        let res = create_echo_app().oneshot(req).await?;
+
+        let res_body = hyper::body::to_bytes(res.into_body()).await?;
+        assert_eq!(b"plain text", &res_body[..]);
+        assert!(!log_buffer.contents().contains("request_body="));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_reject_too_large_request_body() -> Result<()> {
+        let req_body = format!(r#"{{"text":"{}"}}"#, "a".repeat(MAX_BUFFERED_BODY_BYTES));
+        let req = tests::build_req_with_json("/echo", Method::POST, req_body)?;
+        let res = create_echo_app().oneshot(req).await?;
+
+        assert_eq!(StatusCode::PAYLOAD_TOO_LARGE, res.status());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_reject_request_body_that_cannot_be_read() -> Result<()> {
+        // 読み出しの途中で失敗するボディ
+        let (sender, body) = Body::channel();
+        sender.abort();
+        let req = Request::builder()
+            .uri("/echo")
+            .method(Method::POST)
+            .header(CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+            .body(body)?;
+        let res = create_echo_app().oneshot(req).await?;
+
+        assert_eq!(StatusCode::BAD_REQUEST, res.status());
+        Ok(())
+    }
+
+    #[test]
+    fn should_redact_every_password_field() {
+        let body =
+            r#"{"current_password": "old\"pass", "new_password":"new-pass", "name":"tester"}"#;
+
+        let sanitized = sanitize_body(body.as_bytes());
+
+        assert_eq!(
+            r#"{"current_password":"<REDACTED>", "new_password":"<REDACTED>", "name":"tester"}"#,
+            sanitized
+        );
+    }
+
+    #[test]
+    fn should_redact_password_fields_regardless_of_case() {
+        let body = r#"{"Password":"pass-1", "newPassword":"pass-2", "CURRENT_PASSWORD":"pass-3"}"#;
+
+        let sanitized = sanitize_body(body.as_bytes());
+
+        assert_eq!(
+            r#"{"Password":"<REDACTED>", "newPassword":"<REDACTED>", "CURRENT_PASSWORD":"<REDACTED>"}"#,
+            sanitized
+        );
+    }
+
+    #[test]
+    fn should_truncate_large_body() {
+        let body = format!(r#"{{"text":"{}"}}"#, "あ".repeat(2000));
+
+        let sanitized = sanitize_body(body.as_bytes());
+
+        assert!(sanitized.ends_with("...(truncated)"));
+        assert!(sanitized.len() <= MAX_LOGGED_BODY_BYTES + "...(truncated)".len());
+    }
+}
@@ -0,0 +1,128 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+};
+
+use axum::http::{HeaderName, HeaderValue, Request, Response};
+use tower::{Layer, Service};
+use tracing::Instrument;
+use uuid::Uuid;
+
+pub const X_REQUEST_ID: HeaderName = HeaderName::from_static("x-request-id");
+
+// リクエストごとに `<telemetry_id>-<uuid>` の形式の ID を振り、`X-Request-Id` ヘッダーで返すレイヤー
+// リクエストの処理中に出力するログは、この ID とインスタンスの ID を持つ span に含める
+#[derive(Clone)]
+pub struct RequestIdLayer {
+    telemetry_id: Arc<str>,
+}
+
+impl RequestIdLayer {
+    pub fn new(telemetry_id: &str) -> Self {
+        Self {
+            telemetry_id: telemetry_id.into(),
+        }
+    }
+}
+
+impl<S> Layer<S> for RequestIdLayer {
+    type Service = RequestId<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RequestId {
+            inner,
+            telemetry_id: self.telemetry_id.clone(),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct RequestId<S> {
+    inner: S,
+    telemetry_id: Arc<str>,
+}
+
+impl<S, ReqBody, ResBody> Service<Request<ReqBody>> for RequestId<S>
+where
+    S: Service<Request<ReqBody>, Response = Response<ResBody>> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+    ReqBody: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: Request<ReqBody>) -> Self::Future {
+        // poll_ready 済みのサービスを使うために clone したものと入れ替える
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+
+        let request_id = format!("{}-{}", self.telemetry_id, Uuid::new_v4());
+        let span = tracing::info_span!(
+            "request",
+            service.instance = %self.telemetry_id,
+            request_id = %request_id,
+        );
+
+        Box::pin(
+            async move {
+                let mut res = inner.call(req).await?;
+                // telemetry_id に使えない文字が含まれている場合はヘッダーを付けない
+                if let Ok(value) = HeaderValue::from_str(&request_id) {
+                    res.headers_mut().insert(X_REQUEST_ID, value);
+                }
+                Ok(res)
+            }
+            .instrument(span),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use axum::{http::method::Method, routing::get, Router};
+    use tower::ServiceExt;
+
+    use super::*;
+    use crate::router::tests;
+
+    async fn request_id_of(app: &Router) -> Result<String> {
+        let req = tests::build_req_with_empty("/", Method::GET)?;
+        let res = app.clone().oneshot(req).await?;
+        Ok(res.headers()[X_REQUEST_ID].to_str()?.to_string())
+    }
+
+    #[tokio::test]
+    async fn should_prefix_request_id_with_telemetry_id() -> Result<()> {
+        let app_of = |telemetry_id: &str| {
+            Router::new()
+                .route("/", get(|| async { "ok" }))
+                .layer(RequestIdLayer::new(telemetry_id))
+        };
+        let app_a = app_of("instance-a");
+        let app_b = app_of("instance-b");
+
+        let request_ids_a = [request_id_of(&app_a).await?, request_id_of(&app_a).await?];
+        let request_ids_b = [request_id_of(&app_b).await?, request_id_of(&app_b).await?];
+
+        // 同じインスタンスでもリクエストごとに異なる ID を振る
+        assert_ne!(request_ids_a[0], request_ids_a[1]);
+        for request_id in &request_ids_a {
+            let uuid = request_id.strip_prefix("instance-a-").unwrap();
+            assert!(Uuid::parse_str(uuid).is_ok());
+        }
+        // 別のインスタンスが振った ID とは接頭辞が重ならない
+        for request_id in &request_ids_b {
+            assert!(request_id.starts_with("instance-b-"));
+            assert!(!request_ids_a.contains(request_id));
+        }
+        Ok(())
+    }
+}
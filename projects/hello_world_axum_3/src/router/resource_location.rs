@@ -0,0 +1,29 @@
+use axum::{
+    http::{
+        header::{CONTENT_LOCATION, LOCATION},
+        HeaderValue,
+    },
+    response::Response,
+};
+
+use crate::app_config::AppConfig;
+
+// レスポンスの本文が表すリソースの URL を `Content-Location` ヘッダーで示す
+// 新しく作成した場合は、201 Created の慣例に従って `Location` ヘッダーも付ける
+pub fn insert_resource_location(
+    response: &mut Response,
+    app_config: &AppConfig,
+    path: &str,
+    created: bool,
+) {
+    // 設定された base_url がヘッダーに使えない文字を含む場合は付けない
+    let Ok(url) = HeaderValue::from_str(&app_config.resource_url(path)) else {
+        tracing::warn!("cannot use resource url as a header value: {}", path);
+        return;
+    };
+    let headers = response.headers_mut();
+    if created {
+        headers.insert(LOCATION, url.clone());
+    }
+    headers.insert(CONTENT_LOCATION, url);
+}
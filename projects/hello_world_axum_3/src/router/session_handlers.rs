@@ -0,0 +1,206 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::Extension,
+    response::{IntoResponse, Response},
+    Json,
+};
+use hyper::StatusCode;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    application::sessions::{
+        session_application_error::SessionApplicationError,
+        session_login_application_service::{ISessionLoginApplicationService, SessionLoginCommand},
+        session_logout_application_service::{
+            ISessionLogoutApplicationService, SessionLogoutCommand,
+        },
+    },
+    domain::models::{
+        credentials::credential_repository::ICredentialRepository,
+        sessions::{session_id::SessionId, session_repository::ISessionRepository},
+    },
+};
+
+use super::{
+    authentication::{account_locked, AuthenticatedUser, SessionCache},
+    locale::Locale,
+    messages::{Localize, Messages},
+    problem_details::ProblemDetails,
+};
+
+impl Localize for SessionApplicationError {
+    fn localize(&self, locale: Locale) -> String {
+        let messages = Messages::get();
+        match self {
+            SessionApplicationError::SessionNotFound(session_id) => {
+                messages.error(locale, "session.not_found", &[&session_id.to_string()])
+            }
+            SessionApplicationError::SessionRevoked(session_id) => {
+                messages.error(locale, "session.revoked", &[&session_id.to_string()])
+            }
+            SessionApplicationError::IllegalSessionId(message) => messages.error(
+                locale,
+                "session.illegal_session_id",
+                &[&messages.domain_message(locale, message)],
+            ),
+            SessionApplicationError::IllegalUserId(message) => messages.error(
+                locale,
+                "session.illegal_user_id",
+                &[&messages.domain_message(locale, message)],
+            ),
+            SessionApplicationError::InvalidCredentials => {
+                messages.error(locale, "auth.invalid_credentials", &[])
+            }
+            SessionApplicationError::AccountLocked { until } => {
+                messages.error(locale, "auth.account_locked", &[&until.to_rfc3339()])
+            }
+            SessionApplicationError::Unexpected(message) => {
+                messages.error(locale, "unexpected", &[message])
+            }
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct SessionResponse {
+    access_token: String,
+    token_type: String,
+}
+
+impl SessionResponse {
+    fn new(session_id: SessionId) -> Self {
+        Self {
+            access_token: session_id.to_string(),
+            token_type: "Bearer".to_string(),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+pub struct SessionLoginPayload {
+    user_id: String,
+    password: String,
+}
+
+impl SessionLoginPayload {
+    fn into_command(self) -> SessionLoginCommand {
+        SessionLoginCommand {
+            user_id: self.user_id,
+            password: self.password,
+        }
+    }
+}
+
+// ユーザー id とパスワードを検証してセッションを開始し、Bearer トークンとしてセッション id を返す
+pub async fn login<CredentialRep, SessionRep, AS>(
+    Extension(credential_repository): Extension<Arc<CredentialRep>>,
+    Extension(session_repository): Extension<Arc<SessionRep>>,
+    Extension(locale): Extension<Arc<Locale>>,
+    Json(payload): Json<SessionLoginPayload>,
+) -> Result<impl IntoResponse, Response>
+where
+    CredentialRep: ICredentialRepository,
+    SessionRep: ISessionRepository,
+    AS: ISessionLoginApplicationService<CredentialRep, SessionRep>,
+{
+    let session_login_application_service = AS::new(credential_repository, session_repository);
+
+    match session_login_application_service
+        .handle(payload.into_command())
+        .await
+    {
+        Ok(session_id) => Ok((StatusCode::OK, Json(SessionResponse::new(session_id)))),
+        Err(e @ SessionApplicationError::SessionNotFound(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )
+        .into_response()),
+        Err(e @ SessionApplicationError::SessionRevoked(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )
+        .into_response()),
+        Err(e @ SessionApplicationError::IllegalSessionId(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )
+        .into_response()),
+        Err(e @ SessionApplicationError::IllegalUserId(_)) => {
+            Err(ProblemDetails::new(StatusCode::BAD_REQUEST, e.localize(*locale)).into_response())
+        }
+        Err(e @ SessionApplicationError::InvalidCredentials) => {
+            Err(ProblemDetails::new(StatusCode::UNAUTHORIZED, e.localize(*locale)).into_response())
+        }
+        Err(SessionApplicationError::AccountLocked { until }) => {
+            Err(account_locked(until, *locale))
+        }
+        Err(e @ SessionApplicationError::Unexpected(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )
+        .into_response()),
+    }
+}
+
+// 認証に使ったセッションを失効させる
+pub async fn logout<SessionRep, AS>(
+    Extension(session_repository): Extension<Arc<SessionRep>>,
+    Extension(session_cache): Extension<SessionCache>,
+    Extension(authenticated_user): Extension<AuthenticatedUser>,
+    Extension(locale): Extension<Arc<Locale>>,
+) -> Result<StatusCode, impl IntoResponse>
+where
+    SessionRep: ISessionRepository,
+    AS: ISessionLogoutApplicationService<SessionRep>,
+{
+    // Basic 認証にはログアウトの対象となるセッションがない
+    let Some(session_id) = authenticated_user.session_id else {
+        return Err(ProblemDetails::new(
+            StatusCode::BAD_REQUEST,
+            Messages::get().error(*locale, "session.bearer_token_required", &[]),
+        ));
+    };
+
+    let session_logout_application_service = AS::new(session_repository);
+
+    match session_logout_application_service
+        .handle(SessionLogoutCommand {
+            session_id: session_id.to_string(),
+        })
+        .await
+    {
+        Ok(_) => {
+            session_cache.evict(&session_id);
+            Ok(StatusCode::NO_CONTENT)
+        }
+        Err(e @ SessionApplicationError::SessionNotFound(_)) => Err(ProblemDetails::new(
+            StatusCode::NOT_FOUND,
+            e.localize(*locale),
+        )),
+        Err(e @ SessionApplicationError::SessionRevoked(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ SessionApplicationError::IllegalSessionId(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ SessionApplicationError::IllegalUserId(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ SessionApplicationError::InvalidCredentials) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ SessionApplicationError::AccountLocked { .. }) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ SessionApplicationError::Unexpected(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+    }
+}
@@ -0,0 +1,124 @@
+use axum::{
+    http::{
+        header::{ETAG, IF_NONE_MATCH},
+        HeaderMap, HeaderValue,
+    },
+    response::Response,
+};
+use chrono::NaiveDate;
+
+use crate::domain::models::todos::todo_repository::TodoCollectionVersion;
+
+// todo 一覧全体の ETag を、todo の件数と最後に更新された日時、版の合計から求める
+// 一覧を読み直して内容から求めると、304 を返すだけのリクエストでも全件を読むことになるため
+// 一覧には期限日までの日数が含まれるため、日付が変わった場合も値を変える
+pub fn collection_etag(collection_version: &TodoCollectionVersion, today: NaiveDate) -> String {
+    let TodoCollectionVersion {
+        count,
+        last_updated_at,
+        version_sum,
+    } = collection_version;
+    format!(
+        "\"{}-{}-{}-{}\"",
+        count,
+        last_updated_at.map_or(0, |last_updated_at| last_updated_at.timestamp_micros()),
+        version_sum,
+        today.format("%Y%m%d")
+    )
+}
+
+// レスポンスに ETag を付ける。計算できた場合にだけ付くよう Option で受け取る
+pub fn insert_etag(response: &mut Response, etag: Option<&str>) {
+    if let Some(etag) = etag.and_then(|etag| HeaderValue::from_str(etag).ok()) {
+        response.headers_mut().insert(ETAG, etag);
+    }
+}
+
+// `If-None-Match` が ETag と一致するかどうか。一致を弱い比較で判定するため、`W/` は無視する
+pub fn matches_if_none_match(headers: &HeaderMap, etag: &str) -> bool {
+    headers
+        .get_all(IF_NONE_MATCH)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .map(str::trim)
+        .any(|tag| tag == "*" || tag.trim_start_matches("W/") == etag)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+
+    use super::*;
+
+    fn collection_version_for_test() -> TodoCollectionVersion {
+        TodoCollectionVersion {
+            count: 2,
+            last_updated_at: Some(Utc.with_ymd_and_hms(2023, 10, 1, 9, 0, 0).unwrap()),
+            version_sum: 3,
+        }
+    }
+
+    fn today_for_test() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2023, 10, 2).unwrap()
+    }
+
+    #[test]
+    fn should_match_if_none_match_with_etag() {
+        let etag = collection_etag(&collection_version_for_test(), today_for_test());
+
+        for (if_none_match, expected) in [
+            (etag.clone(), true),
+            (format!("W/{}", etag), true),
+            (format!("\"other\", {}", etag), true),
+            ("*".to_string(), true),
+            ("\"other\"".to_string(), false),
+        ] {
+            let mut headers = HeaderMap::new();
+            headers.insert(
+                IF_NONE_MATCH,
+                HeaderValue::from_str(&if_none_match).unwrap(),
+            );
+            assert_eq!(
+                expected,
+                matches_if_none_match(&headers, &etag),
+                "{}",
+                if_none_match
+            );
+        }
+        assert!(!matches_if_none_match(&HeaderMap::new(), &etag));
+    }
+
+    #[test]
+    fn should_change_etag_when_collection_or_date_changes() {
+        let collection_version = collection_version_for_test();
+        let etag = collection_etag(&collection_version, today_for_test());
+        assert_eq!(etag, collection_etag(&collection_version, today_for_test()));
+
+        for changed in [
+            TodoCollectionVersion {
+                count: 1,
+                ..collection_version.clone()
+            },
+            TodoCollectionVersion {
+                last_updated_at: Some(Utc.with_ymd_and_hms(2023, 10, 1, 9, 0, 1).unwrap()),
+                ..collection_version.clone()
+            },
+            TodoCollectionVersion {
+                version_sum: 4,
+                ..collection_version.clone()
+            },
+        ] {
+            assert_ne!(
+                etag,
+                collection_etag(&changed, today_for_test()),
+                "{:?}",
+                changed
+            );
+        }
+        assert_ne!(
+            etag,
+            collection_etag(&collection_version, today_for_test().succ_opt().unwrap())
+        );
+    }
+}
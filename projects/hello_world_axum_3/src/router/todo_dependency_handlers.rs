@@ -0,0 +1,208 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Extension, Path},
+    response::IntoResponse,
+    Json,
+};
+use hyper::StatusCode;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    application::todo_dependencies::{
+        todo_dependency_add_application_service::{
+            ITodoDependencyAddApplicationService, TodoDependencyAddCommand,
+        },
+        todo_dependency_application_error::TodoDependencyApplicationError,
+        todo_dependency_data::TodoDependencyData,
+        todo_dependency_get_all_application_service::{
+            ITodoDependencyGetAllApplicationService, TodoDependencyGetAllCommand,
+        },
+        todo_dependency_remove_application_service::{
+            ITodoDependencyRemoveApplicationService, TodoDependencyRemoveCommand,
+        },
+    },
+    domain::models::{
+        todo_dependencies::todo_dependency_repository::ITodoDependencyRepository,
+        todos::todo_repository::ITodoRepository,
+    },
+};
+
+#[derive(Serialize)]
+pub struct TodoDependencyResponse {
+    id: String,
+    from_todo_id: String,
+    to_todo_id: String,
+}
+
+impl TodoDependencyResponse {
+    fn new(todo_dependency_data: TodoDependencyData) -> Self {
+        Self {
+            id: todo_dependency_data.dependency_id.to_string(),
+            from_todo_id: todo_dependency_data.from_todo_id.to_string(),
+            to_todo_id: todo_dependency_data.to_todo_id.to_string(),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+pub struct TodoDependencyAddPayload {
+    depends_on_todo_id: String,
+}
+
+impl TodoDependencyAddPayload {
+    fn into_command(self, id: String) -> TodoDependencyAddCommand {
+        TodoDependencyAddCommand {
+            todo_id: id,
+            depends_on_todo_id: self.depends_on_todo_id,
+        }
+    }
+}
+
+pub async fn add<TodoRep, TodoDependencyRep, AS>(
+    Extension(todo_repository): Extension<Arc<TodoRep>>,
+    Extension(todo_dependency_repository): Extension<Arc<TodoDependencyRep>>,
+    Path(id): Path<String>,
+    Json(payload): Json<TodoDependencyAddPayload>,
+) -> Result<impl IntoResponse, impl IntoResponse>
+where
+    TodoRep: ITodoRepository,
+    TodoDependencyRep: ITodoDependencyRepository,
+    AS: ITodoDependencyAddApplicationService<TodoRep, TodoDependencyRep>,
+{
+    let todo_dependency_add_application_service =
+        AS::new(todo_repository, todo_dependency_repository);
+
+    match todo_dependency_add_application_service
+        .handle(payload.into_command(id))
+        .await
+    {
+        Ok(todo_dependency_data) => Ok((
+            StatusCode::CREATED,
+            Json(TodoDependencyResponse::new(todo_dependency_data)),
+        )),
+        Err(e @ TodoDependencyApplicationError::DuplicatedDependency(_)) => {
+            Err((StatusCode::BAD_REQUEST, e.to_string()))
+        }
+        // 依存関係が循環すると、どちらの todo も完了できなくなる
+        Err(e @ TodoDependencyApplicationError::CyclicDependency(_)) => {
+            Err((StatusCode::BAD_REQUEST, e.to_string()))
+        }
+        Err(e @ TodoDependencyApplicationError::TodoNotFound(_)) => {
+            Err((StatusCode::NOT_FOUND, e.to_string()))
+        }
+        Err(e @ TodoDependencyApplicationError::DependencyNotFound(_)) => {
+            Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+        }
+        Err(e @ TodoDependencyApplicationError::IllegalArgumentError(_)) => {
+            Err((StatusCode::BAD_REQUEST, e.to_string()))
+        }
+        Err(e @ TodoDependencyApplicationError::IllegalTodoId(_)) => {
+            Err((StatusCode::BAD_REQUEST, e.to_string()))
+        }
+        Err(e @ TodoDependencyApplicationError::IllegalDependencyId(_)) => {
+            Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+        }
+        Err(e @ TodoDependencyApplicationError::Unexpected(_)) => {
+            Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+        }
+    }
+}
+
+pub async fn get_all<TodoRep, TodoDependencyRep, AS>(
+    Extension(todo_repository): Extension<Arc<TodoRep>>,
+    Extension(todo_dependency_repository): Extension<Arc<TodoDependencyRep>>,
+    Path(id): Path<String>,
+) -> Result<impl IntoResponse, impl IntoResponse>
+where
+    TodoRep: ITodoRepository,
+    TodoDependencyRep: ITodoDependencyRepository,
+    AS: ITodoDependencyGetAllApplicationService<TodoRep, TodoDependencyRep>,
+{
+    let todo_dependency_get_all_application_service =
+        AS::new(todo_repository, todo_dependency_repository);
+
+    match todo_dependency_get_all_application_service
+        .handle(TodoDependencyGetAllCommand { todo_id: id })
+        .await
+    {
+        Ok(todo_dependency_data) => Ok((
+            StatusCode::OK,
+            Json(
+                todo_dependency_data
+                    .into_iter()
+                    .map(TodoDependencyResponse::new)
+                    .collect::<Vec<TodoDependencyResponse>>(),
+            ),
+        )),
+        Err(e @ TodoDependencyApplicationError::DuplicatedDependency(_)) => {
+            Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+        }
+        Err(e @ TodoDependencyApplicationError::CyclicDependency(_)) => {
+            Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+        }
+        Err(e @ TodoDependencyApplicationError::TodoNotFound(_)) => {
+            Err((StatusCode::NOT_FOUND, e.to_string()))
+        }
+        Err(e @ TodoDependencyApplicationError::DependencyNotFound(_)) => {
+            Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+        }
+        Err(e @ TodoDependencyApplicationError::IllegalArgumentError(_)) => {
+            Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+        }
+        Err(e @ TodoDependencyApplicationError::IllegalTodoId(_)) => {
+            Err((StatusCode::BAD_REQUEST, e.to_string()))
+        }
+        Err(e @ TodoDependencyApplicationError::IllegalDependencyId(_)) => {
+            Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+        }
+        Err(e @ TodoDependencyApplicationError::Unexpected(_)) => {
+            Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+        }
+    }
+}
+
+pub async fn remove<TodoDependencyRep, AS>(
+    Extension(todo_dependency_repository): Extension<Arc<TodoDependencyRep>>,
+    Path((id, dependency_id)): Path<(String, String)>,
+) -> Result<StatusCode, impl IntoResponse>
+where
+    TodoDependencyRep: ITodoDependencyRepository,
+    AS: ITodoDependencyRemoveApplicationService<TodoDependencyRep>,
+{
+    let todo_dependency_remove_application_service = AS::new(todo_dependency_repository);
+
+    match todo_dependency_remove_application_service
+        .handle(TodoDependencyRemoveCommand {
+            todo_id: id,
+            dependency_id,
+        })
+        .await
+    {
+        Ok(_) => Ok(StatusCode::NO_CONTENT),
+        Err(e @ TodoDependencyApplicationError::DuplicatedDependency(_)) => {
+            Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+        }
+        Err(e @ TodoDependencyApplicationError::CyclicDependency(_)) => {
+            Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+        }
+        Err(e @ TodoDependencyApplicationError::TodoNotFound(_)) => {
+            Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+        }
+        Err(e @ TodoDependencyApplicationError::DependencyNotFound(_)) => {
+            Err((StatusCode::NOT_FOUND, e.to_string()))
+        }
+        Err(e @ TodoDependencyApplicationError::IllegalArgumentError(_)) => {
+            Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+        }
+        Err(e @ TodoDependencyApplicationError::IllegalTodoId(_)) => {
+            Err((StatusCode::BAD_REQUEST, e.to_string()))
+        }
+        Err(e @ TodoDependencyApplicationError::IllegalDependencyId(_)) => {
+            Err((StatusCode::BAD_REQUEST, e.to_string()))
+        }
+        Err(e @ TodoDependencyApplicationError::Unexpected(_)) => {
+            Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+        }
+    }
+}
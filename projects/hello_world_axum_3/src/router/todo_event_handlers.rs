@@ -0,0 +1,81 @@
+use std::{convert::Infallible, pin::Pin, sync::Arc, time::Duration};
+
+use axum::{
+    extract::Extension,
+    response::{
+        sse::{Event, Sse},
+        IntoResponse,
+    },
+};
+use hyper::header::CACHE_CONTROL;
+use tokio_stream::{wrappers::BroadcastStream, Stream, StreamExt};
+
+use crate::domain::events::{domain_event::DomainEvent, event_bus::EventBus};
+
+// 接続が切れたときにクライアントが再接続するまでの待ち時間
+const RETRY_INTERVAL: Duration = Duration::from_millis(5000);
+
+pub type EventStream = Sse<Pin<Box<dyn Stream<Item = Result<Event, Infallible>> + Send>>>;
+
+// todo の変更を Server-Sent Events で配信する
+pub async fn stream(Extension(event_bus): Extension<Arc<EventBus>>) -> impl IntoResponse {
+    let stream =
+        BroadcastStream::new(event_bus.subscribe()).filter_map(|received| match received {
+            Ok(event) if event.is_public() => Some(Ok(to_sse_event(event.as_ref()))),
+            Ok(_) => None,
+            // 受信が追いつかずに取りこぼしたイベントは読み飛ばす
+            Err(e) => {
+                tracing::warn!("skipped todo events: [{}]", e);
+                None
+            }
+        });
+    let event_stream: EventStream = Sse::new(Box::pin(stream));
+
+    ([(CACHE_CONTROL, "no-cache")], event_stream)
+}
+
+fn to_sse_event(event: &dyn DomainEvent) -> Event {
+    Event::default()
+        .event(event.event_type())
+        .data(event.to_json().to_string())
+        .retry(RETRY_INTERVAL)
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use axum::http::method::Method;
+    use hyper::body::HttpBody;
+    use tower::ServiceExt;
+
+    use crate::router::{create_app, tests, ArgCreateApp};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn should_stream_todo_created_event() -> Result<()> {
+        let app = create_app(ArgCreateApp::default());
+
+        let req = tests::build_req_with_empty("/todos/events", Method::GET)?;
+        let res = app.clone().oneshot(req).await?;
+        assert_eq!(
+            "no-cache",
+            res.headers().get(CACHE_CONTROL).unwrap().to_str()?
+        );
+        let mut body = res.into_body();
+
+        let req_body = r#"{"text": "todo text", "label_ids": []}"#.to_string();
+        let req = tests::build_req_with_json("/todos", Method::POST, req_body)?;
+        app.oneshot(req).await?;
+
+        let chunk = tokio::time::timeout(Duration::from_millis(200), body.data())
+            .await?
+            .unwrap()?;
+        let message = String::from_utf8(chunk.to_vec())?;
+
+        assert!(message.contains("event:TodoCreated\n"));
+        assert!(message.contains(r#""text":"todo text""#));
+        assert!(message.contains("retry:5000\n"));
+        Ok(())
+    }
+}
@@ -1,51 +1,334 @@
-use std::sync::Arc;
+use std::sync::{
+    atomic::{AtomicU64, Ordering},
+    Arc,
+};
 
 use axum::{
-    extract::{Extension, Path},
+    body::StreamBody,
+    extract::{Extension, OriginalUri, Path, Query},
     response::IntoResponse,
-    Json,
+    BoxError, Json,
 };
-use hyper::StatusCode;
+use chrono::{DateTime, NaiveDate, Utc};
+use hyper::{header::CONTENT_TYPE, HeaderMap, StatusCode};
 use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+use tokio_stream::StreamExt;
+use tokio_util::sync::CancellationToken;
 
 use crate::{
-    application::todos::{
-        todo_application_error::TodoApplicationError,
-        todo_create_application_service::{ITodoCreateApplicationService, TodoCreateCommand},
-        todo_data::TodoData,
-        todo_delete_application_service::{ITodoDeleteApplicationService, TodoDeleteCommand},
-        todo_get_all_aplication_service::{ITodoGetAllApplicationService, TodoGetAllCommand},
-        todo_get_application_service::{ITodoGetApplicationService, TodoGetCommand},
-        todo_update_application_service::{ITodoUpdateApplicationService, TodoUpdateCommand},
+    app_config::AppConfig,
+    application::{
+        audit::audit_entry_data::AuditEntryData,
+        rfc3339::Rfc3339,
+        todos::{
+            todo_application_error::TodoApplicationError,
+            todo_bulk_complete_by_label_application_service::{
+                BulkUpdateResult, ITodoBulkCompleteByLabelApplicationService,
+                TodoBulkCompleteByLabelCommand,
+            },
+            todo_bulk_create_application_service::{
+                ITodoBulkCreateApplicationService, TodoBulkCreateCommand,
+            },
+            todo_bulk_get_application_service::{
+                ITodoBulkGetApplicationService, TodoBulkGetCommand,
+            },
+            todo_clear_completed_application_service::{
+                ITodoClearCompletedApplicationService, TodoClearCompletedCommand,
+            },
+            todo_create_application_service::{ITodoCreateApplicationService, TodoCreateCommand},
+            todo_data::TodoData,
+            todo_delete_application_service::{ITodoDeleteApplicationService, TodoDeleteCommand},
+            todo_export_application_service::{
+                ITodoExportApplicationService, TodoExportCommand, TodoExportFormat,
+            },
+            todo_filter_by_label_application_service::{
+                ITodoFilterByLabelApplicationService, TodoFilterByLabelCommand,
+            },
+            todo_get_all_aplication_service::{
+                ITodoGetAllApplicationService, TodoGetAllCommand, TodoListViewDataStream,
+            },
+            todo_get_application_service::{ITodoGetApplicationService, TodoGetCommand},
+            todo_get_assigned_application_service::{
+                ITodoGetAssignedApplicationService, TodoGetAssignedCommand,
+            },
+            todo_get_audit_application_service::{
+                ITodoGetAuditApplicationService, TodoGetAuditCommand,
+            },
+            todo_get_due_soon_application_service::{
+                ITodoGetDueSoonApplicationService, TodoGetDueSoonCommand,
+            },
+            todo_get_similar_application_service::{
+                ITodoGetSimilarApplicationService, SimilarTodoData, TodoGetSimilarCommand,
+            },
+            todo_ical_export_application_service::{
+                ITodoIcalExportApplicationService, TodoIcalExportCommand,
+            },
+            todo_list_view_data::TodoListViewData,
+            todo_search_application_service::{ITodoSearchApplicationService, TodoSearchCommand},
+            todo_update_application_service::{ITodoUpdateApplicationService, TodoUpdateCommand},
+        },
     },
-    domain::models::{
-        labels::label_repository::ILabelRepository, todos::todo_repository::ITodoRepository,
+    domain::{
+        events::event_bus::EventBus,
+        models::{
+            audit_logs::audit_log_repository::IAuditLogRepository,
+            common::batch_result::BatchFindResult,
+            labels::{
+                label_id::{LabelId, LabelIdError},
+                label_repository::ILabelRepository,
+            },
+            todo_dependencies::todo_dependency_repository::ITodoDependencyRepository,
+            todos::{
+                label_filter::{FilterOperator, LabelFilter},
+                todo_id::TodoId,
+                todo_repository::ITodoRepository,
+                todo_status::TodoStatus,
+            },
+            users::{user_id::UserId, user_repository::IUserRepository},
+        },
+        value_object::ValueObject,
     },
 };
 
-use super::label_handlers::LabelResponse;
+use super::{
+    authentication::AuthenticatedUser,
+    extractors::query_params::QueryParams,
+    label_handlers::LabelResponse,
+    locale::Locale,
+    messages::{Localize, Messages},
+    pagination::{paginate, PagedResponse, PaginationMeta, PaginationQuery},
+    problem_details::ProblemDetails,
+    resource_location::insert_resource_location,
+    todo_collection_etag::{collection_etag, insert_etag, matches_if_none_match},
+};
+
+// statement_timeout と同じ秒数だけ待ってから再試行してもらう
+const TIMEOUT_RETRY_AFTER_SECONDS: u64 = 5;
+
+impl Localize for TodoApplicationError {
+    fn localize(&self, locale: Locale) -> String {
+        let messages = Messages::get();
+        match self {
+            TodoApplicationError::DuplicatedTodo(todo) => {
+                messages.error(locale, "todo.duplicated", &[todo.todo_text.value()])
+            }
+            TodoApplicationError::TodoNotFound(todo_id) => {
+                messages.error(locale, "todo.not_found", &[&todo_id.to_string()])
+            }
+            TodoApplicationError::LabelNotFound(label_id) => {
+                messages.error(locale, "todo.label_not_found", &[&label_id.to_string()])
+            }
+            TodoApplicationError::AssigneeNotFound(user_id) => {
+                messages.error(locale, "todo.assignee_not_found", &[&user_id.to_string()])
+            }
+            TodoApplicationError::IllegalArgumentError(message) => messages.error(
+                locale,
+                "todo.illegal_argument",
+                &[&messages.domain_message(locale, message)],
+            ),
+            TodoApplicationError::IllegalTodoId(message) => messages.error(
+                locale,
+                "todo.illegal_todo_id",
+                &[&messages.domain_message(locale, message)],
+            ),
+            TodoApplicationError::IllegalLabelId(message) => messages.error(
+                locale,
+                "todo.illegal_label_id",
+                &[&messages.domain_message(locale, message)],
+            ),
+            TodoApplicationError::IllegalUserId(message) => messages.error(
+                locale,
+                "todo.illegal_user_id",
+                &[&messages.domain_message(locale, message)],
+            ),
+            TodoApplicationError::DependencyNotMet(todo_ids) => {
+                messages.error(locale, "todo.dependency_not_met", &[&join(todo_ids)])
+            }
+            TodoApplicationError::StaleData(todo_id) => {
+                messages.error(locale, "todo.stale_data", &[&todo_id.to_string()])
+            }
+            TodoApplicationError::PermissionDenied(user_id) => {
+                messages.error(locale, "todo.permission_denied", &[&user_id.to_string()])
+            }
+            TodoApplicationError::PartialSuccess { failed, .. } => {
+                let failed = failed
+                    .iter()
+                    .map(|(index, message)| {
+                        format!("#{}: {}", index, messages.domain_message(locale, message))
+                    })
+                    .collect::<Vec<_>>();
+                messages.error(locale, "todo.partial_success", &[&failed.join(", ")])
+            }
+            TodoApplicationError::Timeout => messages.error(locale, "todo.timeout", &[]),
+            TodoApplicationError::Unexpected(message) => {
+                messages.error(locale, "unexpected", &[message])
+            }
+        }
+    }
+}
+
+fn join(todo_ids: &[TodoId]) -> String {
+    todo_ids
+        .iter()
+        .map(|todo_id| todo_id.to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+// 認証していれば、監査ログに操作したユーザーとして残す
+fn requested_by(authenticated_user: Option<Extension<AuthenticatedUser>>) -> Option<UserId> {
+    authenticated_user.map(|Extension(authenticated_user)| authenticated_user.user_id)
+}
+
+// `GET /todos` のクエリパラメータ
+#[derive(Deserialize)]
+pub struct TodoListParams {
+    page: Option<u32>,
+    per_page: Option<u32>,
+    completed: Option<bool>,
+    // 完了状態と期限日から求めた状態 (`active`, `completed`, `overdue`, `archived`)
+    status: Option<TodoStatus>,
+    // ラベルを 1 つだけ指定する場合の書き方。label_ids と併せて指定した場合は両方を条件にする
+    label_id: Option<String>,
+    q: Option<String>,
+    sort: Option<TodoSort>,
+    label_slug: Option<String>,
+    // カンマ区切りのラベル id
+    label_ids: Option<String>,
+    #[serde(default)]
+    label_operator: FilterOperator,
+}
+
+// 一覧の並び順。`-` が付いていれば降順で、期限日の無い todo は常に最後に並べる
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum TodoSort {
+    #[serde(rename = "created_at")]
+    CreatedAt,
+    #[serde(rename = "-created_at")]
+    CreatedAtDesc,
+    #[serde(rename = "due_date")]
+    DueDate,
+    #[serde(rename = "-due_date")]
+    DueDateDesc,
+}
+
+impl TodoSort {
+    fn sort(self, todo_views: &mut [TodoListViewData]) {
+        match self {
+            TodoSort::CreatedAt => todo_views.sort_by_key(|view| view.todo.created_at),
+            TodoSort::CreatedAtDesc => {
+                todo_views.sort_by_key(|view| std::cmp::Reverse(view.todo.created_at))
+            }
+            TodoSort::DueDate => {
+                todo_views.sort_by_key(|view| (view.todo.due_date.is_none(), view.todo.due_date))
+            }
+            TodoSort::DueDateDesc => todo_views.sort_by_key(|view| {
+                (
+                    view.todo.due_date.is_none(),
+                    std::cmp::Reverse(view.todo.due_date),
+                )
+            }),
+        }
+    }
+}
+
+impl TodoListParams {
+    fn label_filter(&self) -> Result<Option<LabelFilter>, LabelIdError> {
+        let label_ids = self
+            .label_ids
+            .iter()
+            .flat_map(|label_ids| label_ids.split(','))
+            .chain(self.label_id.as_deref());
+        let ids = label_ids
+            .map(|label_id| LabelId::parse(label_id.trim().to_string()))
+            .collect::<Result<Vec<LabelId>, LabelIdError>>()?;
+        if ids.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(LabelFilter {
+            ids,
+            operator: self.label_operator,
+        }))
+    }
+
+    fn pagination(&self) -> PaginationQuery {
+        PaginationQuery::new(self.page, self.per_page)
+    }
+}
 
 #[derive(Deserialize)]
 pub struct TodoCreatePayload {
     text: String,
     label_ids: Vec<String>,
+    #[serde(default)]
+    label_names: Vec<String>,
+    note: Option<String>,
+    due_date: Option<String>,
+    assignee_id: Option<String>,
+    // 先頭の文字を大文字にするかどうか。未指定の場合は `AppConfig::auto_sentence_case` に従う
+    normalize_case: Option<bool>,
 }
 
 impl TodoCreatePayload {
-    fn into_command(self) -> TodoCreateCommand {
+    fn into_command(
+        self,
+        app_config: &AppConfig,
+        requested_by: Option<UserId>,
+    ) -> TodoCreateCommand {
         TodoCreateCommand {
             todo_text: self.text,
             label_ids: self.label_ids,
+            label_names: self.label_names,
+            note: self.note,
+            due_date: self.due_date,
+            assignee_id: self.assignee_id,
+            normalize_case: self.normalize_case.unwrap_or(app_config.auto_sentence_case),
+            requested_by,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+pub struct TodoBulkCreatePayload {
+    todos: Vec<TodoCreatePayload>,
+}
+
+impl TodoBulkCreatePayload {
+    fn into_command(
+        self,
+        app_config: &AppConfig,
+        requested_by: Option<UserId>,
+    ) -> TodoBulkCreateCommand {
+        TodoBulkCreateCommand {
+            todos: self
+                .todos
+                .into_iter()
+                .map(|todo| todo.into_command(app_config, requested_by.clone()))
+                .collect(),
         }
     }
 }
 
+#[serde_as]
 #[derive(Serialize)]
 pub struct TodoResponse {
     id: String,
     text: String,
+    note: Option<String>,
+    due_date: Option<NaiveDate>,
+    assignee_id: Option<String>,
     completed: bool,
+    status: TodoStatus,
     labels: Vec<LabelResponse>,
+    links: Vec<String>,
+    word_count: usize,
+    char_count: usize,
+    #[serde_as(as = "Rfc3339")]
+    created_at: DateTime<Utc>,
+    #[serde_as(as = "Rfc3339")]
+    updated_at: DateTime<Utc>,
+    version: i64,
 }
 
 impl TodoResponse {
@@ -53,13 +336,72 @@ impl TodoResponse {
         let labels = todo_data
             .labels
             .into_iter()
-            .map(|label_data| LabelResponse::new(label_data))
+            .map(LabelResponse::new)
             .collect();
         Self {
             id: todo_data.todo_id.to_string(),
             text: todo_data.todo_text,
+            note: todo_data.note,
+            due_date: todo_data.due_date,
+            assignee_id: todo_data
+                .assignee_id
+                .map(|assignee_id| assignee_id.to_string()),
             completed: todo_data.completed,
+            status: todo_data.status,
             labels,
+            links: todo_data.links,
+            word_count: todo_data.word_count,
+            char_count: todo_data.char_count,
+            created_at: todo_data.created_at,
+            updated_at: todo_data.updated_at,
+            version: todo_data.version,
+        }
+    }
+}
+
+// 一覧取得のレスポンス。TodoResponse の各項目に、一覧表示用に計算した項目を加える
+#[derive(Serialize)]
+pub struct TodoListResponse {
+    #[serde(flatten)]
+    todo: TodoResponse,
+    is_overdue: bool,
+    label_names: Vec<String>,
+    assignee_name: Option<String>,
+    days_until_due: Option<i64>,
+}
+
+impl TodoListResponse {
+    fn new(todo_list_view_data: TodoListViewData) -> Self {
+        Self {
+            todo: TodoResponse::new(todo_list_view_data.todo),
+            is_overdue: todo_list_view_data.is_overdue,
+            label_names: todo_list_view_data.label_names,
+            assignee_name: todo_list_view_data.assignee_name,
+            days_until_due: todo_list_view_data.days_until_due,
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct TodoBulkCreateErrorResponse {
+    index: usize,
+    message: String,
+}
+
+#[derive(Serialize)]
+pub struct TodoBulkCreateResponse {
+    created: Vec<TodoResponse>,
+    errors: Vec<TodoBulkCreateErrorResponse>,
+}
+
+impl TodoBulkCreateResponse {
+    fn new(created: Vec<TodoData>, failed: Vec<(usize, String)>) -> Self {
+        Self {
+            created: created.into_iter().map(TodoResponse::new).collect(),
+            errors: failed
+                .into_iter()
+                .map(|(index, message)| TodoBulkCreateErrorResponse { index, message })
+                .collect(),
         }
     }
 }
@@ -69,61 +411,239 @@ pub struct TodoUpdatePayload {
     text: Option<String>,
     completed: Option<bool>,
     label_ids: Option<Vec<String>>,
+    // JSON Merge Patch と同様に、キーがなければ変更なし、null であれば値の削除として扱う
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    note: Option<Option<String>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    due_date: Option<Option<String>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    assignee_id: Option<Option<String>>,
+    // 読み出したときの版。指定されていれば、その後に他から更新されていた場合は 409 を返す
+    version: Option<i64>,
+    // 先頭の文字を大文字にするかどうか。未指定の場合は `AppConfig::auto_sentence_case` に従う
+    normalize_case: Option<bool>,
 }
 
 impl TodoUpdatePayload {
-    fn into_command(self, id: String) -> TodoUpdateCommand {
+    fn into_command(
+        self,
+        id: String,
+        app_config: &AppConfig,
+        requested_by: Option<UserId>,
+    ) -> TodoUpdateCommand {
         TodoUpdateCommand {
             todo_id: id,
             todo_text: self.text,
             completed: self.completed,
             label_ids: self.label_ids,
+            note: self.note,
+            due_date: self.due_date,
+            assignee_id: self.assignee_id,
+            version: self.version,
+            normalize_case: self.normalize_case.unwrap_or(app_config.auto_sentence_case),
+            requested_by,
         }
     }
 }
 
-pub async fn create<TodoRep, LabelRep, AS>(
+#[allow(clippy::too_many_arguments)]
+pub async fn create<TodoRep, LabelRep, UserRep, AuditLogRep, AS>(
+    Extension(locale): Extension<Arc<Locale>>,
     Extension(todo_repository): Extension<Arc<TodoRep>>,
     Extension(label_repository): Extension<Arc<LabelRep>>,
+    Extension(user_repository): Extension<Arc<UserRep>>,
+    Extension(audit_log_repository): Extension<Arc<AuditLogRep>>,
+    Extension(event_bus): Extension<Arc<EventBus>>,
+    Extension(app_config): Extension<Arc<AppConfig>>,
+    authenticated_user: Option<Extension<AuthenticatedUser>>,
     Json(payload): Json<TodoCreatePayload>,
 ) -> Result<impl IntoResponse, impl IntoResponse>
 where
     TodoRep: ITodoRepository,
     LabelRep: ILabelRepository,
-    AS: ITodoCreateApplicationService<TodoRep, LabelRep>,
+    UserRep: IUserRepository,
+    AuditLogRep: IAuditLogRepository,
+    AS: ITodoCreateApplicationService<TodoRep, LabelRep, UserRep, AuditLogRep>,
 {
-    let todo_create_application_service = AS::new(todo_repository, label_repository);
+    let todo_create_application_service = AS::new(
+        todo_repository,
+        label_repository,
+        user_repository,
+        audit_log_repository,
+        event_bus,
+    )
+    .with_max_labels(app_config.max_labels_per_todo);
 
     match todo_create_application_service
-        .handle(payload.into_command())
+        .handle(payload.into_command(&app_config, requested_by(authenticated_user)))
         .await
     {
-        Ok(todo_data) => Ok((StatusCode::CREATED, Json(TodoResponse::new(todo_data)))),
-        Err(e @ TodoApplicationError::DuplicatedTodo(_)) => {
-            Err((StatusCode::BAD_REQUEST, e.to_string()))
-        }
-        Err(e @ TodoApplicationError::IllegalArgumentError(_)) => {
-            Err((StatusCode::BAD_REQUEST, e.to_string()))
-        }
-        Err(e @ TodoApplicationError::IllegalTodoId(_)) => {
-            Err((StatusCode::BAD_REQUEST, e.to_string()))
-        }
-        Err(e @ TodoApplicationError::TodoNotFound(_)) => {
-            Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
-        }
-        Err(e @ TodoApplicationError::Unexpected(_)) => {
-            Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
-        }
-        Err(e @ TodoApplicationError::LabelNotFound(_)) => {
-            Err((StatusCode::BAD_REQUEST, e.to_string()))
-        }
-        Err(e @ TodoApplicationError::IllegalLabelId(_)) => {
-            Err((StatusCode::BAD_REQUEST, e.to_string()))
+        Ok(todo_data) => {
+            let path = format!("/todos/{}", todo_data.todo_id);
+            let mut response =
+                (StatusCode::CREATED, Json(TodoResponse::new(todo_data))).into_response();
+            insert_resource_location(&mut response, &app_config, &path, true);
+            Ok(response)
         }
+        Err(e @ TodoApplicationError::DuplicatedTodo(_)) => Err(ProblemDetails::new(
+            StatusCode::UNPROCESSABLE_ENTITY,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::IllegalArgumentError(_)) => Err(ProblemDetails::new(
+            StatusCode::UNPROCESSABLE_ENTITY,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::IllegalTodoId(_)) => Err(ProblemDetails::new(
+            StatusCode::BAD_REQUEST,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::TodoNotFound(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::Unexpected(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::Timeout) => Err(ProblemDetails::new(
+            StatusCode::SERVICE_UNAVAILABLE,
+            e.localize(*locale),
+        )
+        .with_retry_after(TIMEOUT_RETRY_AFTER_SECONDS)),
+        Err(e @ TodoApplicationError::PartialSuccess { .. }) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::LabelNotFound(_)) => Err(ProblemDetails::new(
+            StatusCode::BAD_REQUEST,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::AssigneeNotFound(_)) => Err(ProblemDetails::new(
+            StatusCode::BAD_REQUEST,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::IllegalLabelId(_)) => Err(ProblemDetails::new(
+            StatusCode::BAD_REQUEST,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::IllegalUserId(_)) => Err(ProblemDetails::new(
+            StatusCode::BAD_REQUEST,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::DependencyNotMet(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::StaleData(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::PermissionDenied(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub async fn bulk_create<TodoRep, LabelRep, UserRep, AuditLogRep, AS>(
+    Extension(locale): Extension<Arc<Locale>>,
+    Extension(todo_repository): Extension<Arc<TodoRep>>,
+    Extension(label_repository): Extension<Arc<LabelRep>>,
+    Extension(user_repository): Extension<Arc<UserRep>>,
+    Extension(audit_log_repository): Extension<Arc<AuditLogRep>>,
+    Extension(event_bus): Extension<Arc<EventBus>>,
+    Extension(app_config): Extension<Arc<AppConfig>>,
+    authenticated_user: Option<Extension<AuthenticatedUser>>,
+    Json(payload): Json<TodoBulkCreatePayload>,
+) -> Result<impl IntoResponse, impl IntoResponse>
+where
+    TodoRep: ITodoRepository,
+    LabelRep: ILabelRepository,
+    UserRep: IUserRepository,
+    AuditLogRep: IAuditLogRepository,
+    AS: ITodoBulkCreateApplicationService<TodoRep, LabelRep, UserRep, AuditLogRep>,
+{
+    let todo_bulk_create_application_service = AS::new(
+        todo_repository,
+        label_repository,
+        user_repository,
+        audit_log_repository,
+        event_bus,
+    )
+    .with_max_labels(app_config.max_labels_per_todo);
+
+    match todo_bulk_create_application_service
+        .handle(payload.into_command(&app_config, requested_by(authenticated_user)))
+        .await
+    {
+        Ok(created) => Ok((
+            StatusCode::CREATED,
+            Json(TodoBulkCreateResponse::new(created, vec![])),
+        )),
+        // 一部だけ作成できた場合は、todo ごとの結果を 207 で返す
+        Err(TodoApplicationError::PartialSuccess { created, failed }) => Ok((
+            StatusCode::MULTI_STATUS,
+            Json(TodoBulkCreateResponse::new(created, failed)),
+        )),
+        Err(e @ TodoApplicationError::DuplicatedTodo(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::IllegalArgumentError(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::IllegalTodoId(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::TodoNotFound(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::Unexpected(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::Timeout) => Err(ProblemDetails::new(
+            StatusCode::SERVICE_UNAVAILABLE,
+            e.localize(*locale),
+        )
+        .with_retry_after(TIMEOUT_RETRY_AFTER_SECONDS)),
+        Err(e @ TodoApplicationError::LabelNotFound(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::AssigneeNotFound(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::IllegalLabelId(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::IllegalUserId(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::DependencyNotMet(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::StaleData(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::PermissionDenied(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
     }
 }
 
 pub async fn get<TodoRep, AS>(
+    Extension(locale): Extension<Arc<Locale>>,
     Extension(todo_repository): Extension<Arc<TodoRep>>,
     Path(id): Path<String>,
 ) -> Result<impl IntoResponse, impl IntoResponse>
@@ -138,153 +658,1475 @@ where
         .await
     {
         Ok(todo_data) => Ok((StatusCode::OK, Json(TodoResponse::new(todo_data)))),
-        Err(e @ TodoApplicationError::DuplicatedTodo(_)) => {
-            Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
-        }
-        Err(e @ TodoApplicationError::IllegalArgumentError(_)) => {
-            Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
-        }
-        Err(e @ TodoApplicationError::IllegalTodoId(_)) => {
-            Err((StatusCode::BAD_REQUEST, e.to_string()))
-        }
-        Err(e @ TodoApplicationError::TodoNotFound(_)) => {
-            Err((StatusCode::NOT_FOUND, e.to_string()))
-        }
-        Err(e @ TodoApplicationError::Unexpected(_)) => {
-            Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+        Err(e @ TodoApplicationError::DuplicatedTodo(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::IllegalArgumentError(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::IllegalTodoId(_)) => Err(ProblemDetails::new(
+            StatusCode::BAD_REQUEST,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::TodoNotFound(_)) => Err(ProblemDetails::new(
+            StatusCode::NOT_FOUND,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::Unexpected(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::Timeout) => Err(ProblemDetails::new(
+            StatusCode::SERVICE_UNAVAILABLE,
+            e.localize(*locale),
+        )
+        .with_retry_after(TIMEOUT_RETRY_AFTER_SECONDS)),
+        Err(e @ TodoApplicationError::PartialSuccess { .. }) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::LabelNotFound(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::AssigneeNotFound(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::IllegalLabelId(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::IllegalUserId(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::DependencyNotMet(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::StaleData(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::PermissionDenied(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+    }
+}
+
+// 指定したユーザーが担当者になっている todo を返す
+pub async fn get_assigned<TodoRep, UserRep, AS>(
+    Extension(locale): Extension<Arc<Locale>>,
+    Extension(todo_repository): Extension<Arc<TodoRep>>,
+    Extension(user_repository): Extension<Arc<UserRep>>,
+    Path(id): Path<String>,
+) -> Result<impl IntoResponse, impl IntoResponse>
+where
+    TodoRep: ITodoRepository,
+    UserRep: IUserRepository,
+    AS: ITodoGetAssignedApplicationService<TodoRep, UserRep>,
+{
+    let todo_get_assigned_application_service = AS::new(todo_repository, user_repository);
+
+    match todo_get_assigned_application_service
+        .handle(TodoGetAssignedCommand { user_id: id })
+        .await
+    {
+        Ok(todos_data) => {
+            let todos_response: Vec<TodoResponse> =
+                todos_data.into_iter().map(TodoResponse::new).collect();
+            Ok((StatusCode::OK, Json(todos_response)))
         }
-        Err(e @ TodoApplicationError::LabelNotFound(_)) => {
-            Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+        Err(e @ TodoApplicationError::DuplicatedTodo(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::IllegalArgumentError(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::IllegalTodoId(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::TodoNotFound(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::Unexpected(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::Timeout) => Err(ProblemDetails::new(
+            StatusCode::SERVICE_UNAVAILABLE,
+            e.localize(*locale),
+        )
+        .with_retry_after(TIMEOUT_RETRY_AFTER_SECONDS)),
+        Err(e @ TodoApplicationError::PartialSuccess { .. }) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::LabelNotFound(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::AssigneeNotFound(_)) => Err(ProblemDetails::new(
+            StatusCode::NOT_FOUND,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::IllegalLabelId(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::IllegalUserId(_)) => Err(ProblemDetails::new(
+            StatusCode::BAD_REQUEST,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::DependencyNotMet(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::StaleData(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::PermissionDenied(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+    }
+}
+
+#[derive(Serialize)]
+pub struct TodoClearCompletedResponse {
+    deleted_count: u64,
+}
+
+// 指定したユーザーが担当している完了済みの todo をまとめて削除する
+pub async fn clear_completed<TodoRep, UserRep, AS>(
+    Extension(locale): Extension<Arc<Locale>>,
+    Extension(todo_repository): Extension<Arc<TodoRep>>,
+    Extension(user_repository): Extension<Arc<UserRep>>,
+    Extension(event_bus): Extension<Arc<EventBus>>,
+    Extension(authenticated_user): Extension<AuthenticatedUser>,
+    Path(id): Path<String>,
+) -> Result<impl IntoResponse, impl IntoResponse>
+where
+    TodoRep: ITodoRepository,
+    UserRep: IUserRepository,
+    AS: ITodoClearCompletedApplicationService<TodoRep, UserRep>,
+{
+    let todo_clear_completed_application_service =
+        AS::new(todo_repository, user_repository, event_bus);
+
+    match todo_clear_completed_application_service
+        .handle(TodoClearCompletedCommand {
+            user_id: id,
+            requested_by: authenticated_user.user_id,
+        })
+        .await
+    {
+        Ok(deleted_count) => Ok((
+            StatusCode::OK,
+            Json(TodoClearCompletedResponse { deleted_count }),
+        )),
+        Err(e @ TodoApplicationError::DuplicatedTodo(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::IllegalArgumentError(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::IllegalTodoId(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::TodoNotFound(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::Unexpected(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::Timeout) => Err(ProblemDetails::new(
+            StatusCode::SERVICE_UNAVAILABLE,
+            e.localize(*locale),
+        )
+        .with_retry_after(TIMEOUT_RETRY_AFTER_SECONDS)),
+        Err(e @ TodoApplicationError::PartialSuccess { .. }) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::LabelNotFound(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::AssigneeNotFound(_)) => Err(ProblemDetails::new(
+            StatusCode::NOT_FOUND,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::IllegalLabelId(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::IllegalUserId(_)) => Err(ProblemDetails::new(
+            StatusCode::BAD_REQUEST,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::DependencyNotMet(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::StaleData(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::PermissionDenied(_)) => Err(ProblemDetails::new(
+            StatusCode::FORBIDDEN,
+            e.localize(*locale),
+        )),
+    }
+}
+
+#[derive(Deserialize)]
+pub struct TodoBulkCompleteByLabelPayload {
+    completed: bool,
+}
+
+#[derive(Serialize)]
+pub struct TodoBulkCompleteByLabelResponse {
+    updated_count: u64,
+    // 先に完了していなければならない todo が完了していないため、完了にしなかった todo の id
+    skipped_ids: Vec<String>,
+}
+
+// 指定したラベルが付いた todo の完了状態をまとめて変更する
+pub async fn bulk_complete_by_label<TodoRep, LabelRep, TodoDependencyRep, AS>(
+    Extension(locale): Extension<Arc<Locale>>,
+    Extension(todo_repository): Extension<Arc<TodoRep>>,
+    Extension(label_repository): Extension<Arc<LabelRep>>,
+    Extension(todo_dependency_repository): Extension<Arc<TodoDependencyRep>>,
+    Extension(event_bus): Extension<Arc<EventBus>>,
+    Path(id): Path<String>,
+    Json(payload): Json<TodoBulkCompleteByLabelPayload>,
+) -> Result<impl IntoResponse, impl IntoResponse>
+where
+    TodoRep: ITodoRepository,
+    LabelRep: ILabelRepository,
+    TodoDependencyRep: ITodoDependencyRepository,
+    AS: ITodoBulkCompleteByLabelApplicationService<TodoRep, LabelRep, TodoDependencyRep>,
+{
+    let todo_bulk_complete_by_label_application_service = AS::new(
+        todo_repository,
+        label_repository,
+        todo_dependency_repository,
+        event_bus,
+    );
+
+    match todo_bulk_complete_by_label_application_service
+        .handle(TodoBulkCompleteByLabelCommand {
+            label_id: id,
+            completed: payload.completed,
+        })
+        .await
+    {
+        Ok(BulkUpdateResult {
+            updated_count,
+            skipped_todo_ids,
+        }) => Ok((
+            StatusCode::OK,
+            Json(TodoBulkCompleteByLabelResponse {
+                updated_count,
+                skipped_ids: skipped_todo_ids
+                    .iter()
+                    .map(|todo_id| todo_id.to_string())
+                    .collect(),
+            }),
+        )),
+        Err(e @ TodoApplicationError::DuplicatedTodo(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::IllegalArgumentError(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::IllegalTodoId(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::TodoNotFound(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::Unexpected(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::Timeout) => Err(ProblemDetails::new(
+            StatusCode::SERVICE_UNAVAILABLE,
+            e.localize(*locale),
+        )
+        .with_retry_after(TIMEOUT_RETRY_AFTER_SECONDS)),
+        Err(e @ TodoApplicationError::PartialSuccess { .. }) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::LabelNotFound(_)) => Err(ProblemDetails::new(
+            StatusCode::NOT_FOUND,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::AssigneeNotFound(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::IllegalLabelId(_)) => Err(ProblemDetails::new(
+            StatusCode::BAD_REQUEST,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::IllegalUserId(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::DependencyNotMet(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::StaleData(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::PermissionDenied(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+    }
+}
+
+// `days` が指定されなければ 1 週間先までを対象にする
+const DEFAULT_DUE_SOON_DAYS: u32 = 7;
+
+#[derive(Deserialize)]
+pub struct TodoGetDueSoonQuery {
+    #[serde(default = "default_due_soon_days")]
+    days: u32,
+}
+
+fn default_due_soon_days() -> u32 {
+    DEFAULT_DUE_SOON_DAYS
+}
+
+// `days` 日後までに期限を迎える未完了の todo を、期限の近い順に返す
+pub async fn get_due_soon<TodoRep, AS>(
+    Extension(locale): Extension<Arc<Locale>>,
+    Extension(todo_repository): Extension<Arc<TodoRep>>,
+    OriginalUri(uri): OriginalUri,
+    Query(pagination): Query<PaginationQuery>,
+    Query(query): Query<TodoGetDueSoonQuery>,
+) -> Result<impl IntoResponse, impl IntoResponse>
+where
+    TodoRep: ITodoRepository,
+    AS: ITodoGetDueSoonApplicationService<TodoRep>,
+{
+    let todo_get_due_soon_application_service = AS::new(todo_repository);
+
+    match todo_get_due_soon_application_service
+        .handle(TodoGetDueSoonCommand { days: query.days })
+        .await
+    {
+        Ok(todos_data) => {
+            let (todos_data, meta, headers) = paginate(todos_data, &uri, &pagination);
+            Ok((
+                StatusCode::OK,
+                headers,
+                Json(PagedResponse::new(
+                    todos_data.into_iter().map(TodoResponse::new).collect(),
+                    meta,
+                )),
+            ))
         }
-        Err(e @ TodoApplicationError::IllegalLabelId(_)) => {
-            Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+        Err(e @ TodoApplicationError::DuplicatedTodo(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::IllegalArgumentError(_)) => Err(ProblemDetails::new(
+            StatusCode::BAD_REQUEST,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::IllegalTodoId(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::TodoNotFound(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::Unexpected(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::Timeout) => Err(ProblemDetails::new(
+            StatusCode::SERVICE_UNAVAILABLE,
+            e.localize(*locale),
+        )
+        .with_retry_after(TIMEOUT_RETRY_AFTER_SECONDS)),
+        Err(e @ TodoApplicationError::PartialSuccess { .. }) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::LabelNotFound(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::AssigneeNotFound(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::IllegalLabelId(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::IllegalUserId(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::DependencyNotMet(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::StaleData(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::PermissionDenied(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+    }
+}
+
+// 類似度の閾値と件数が指定されなければ、0.7 以上のものを 5 件まで返す
+const DEFAULT_SIMILAR_THRESHOLD: f64 = 0.7;
+const DEFAULT_SIMILAR_LIMIT: u32 = 5;
+
+#[derive(Deserialize)]
+pub struct TodoGetSimilarQuery {
+    #[serde(default = "default_similar_threshold")]
+    threshold: f64,
+    #[serde(default = "default_similar_limit")]
+    limit: u32,
+}
+
+fn default_similar_threshold() -> f64 {
+    DEFAULT_SIMILAR_THRESHOLD
+}
+
+fn default_similar_limit() -> u32 {
+    DEFAULT_SIMILAR_LIMIT
+}
+
+#[derive(Serialize)]
+pub struct SimilarTodoResponse {
+    todo: TodoResponse,
+    similarity_score: f64,
+}
+
+impl SimilarTodoResponse {
+    fn new(similar_todo_data: SimilarTodoData) -> Self {
+        Self {
+            todo: TodoResponse::new(similar_todo_data.todo),
+            similarity_score: similar_todo_data.similarity_score,
         }
     }
 }
 
-pub async fn get_all<Rep, AS>(
-    Extension(repository): Extension<Arc<Rep>>,
+// text が似ている todo を、類似度の高い順に返す
+pub async fn get_similar<TodoRep, AS>(
+    Extension(locale): Extension<Arc<Locale>>,
+    Extension(todo_repository): Extension<Arc<TodoRep>>,
+    Path(id): Path<String>,
+    Query(query): Query<TodoGetSimilarQuery>,
 ) -> Result<impl IntoResponse, impl IntoResponse>
 where
-    Rep: ITodoRepository,
-    AS: ITodoGetAllApplicationService<Rep>,
+    TodoRep: ITodoRepository,
+    AS: ITodoGetSimilarApplicationService<TodoRep>,
 {
-    let todo_get_all_application_service = AS::new(repository);
+    let todo_get_similar_application_service = AS::new(todo_repository);
 
-    match todo_get_all_application_service
-        .handle(TodoGetAllCommand {})
+    match todo_get_similar_application_service
+        .handle(TodoGetSimilarCommand {
+            todo_id: id,
+            threshold: query.threshold,
+            limit: query.limit,
+        })
         .await
     {
-        Ok(todo_data) => Ok((
+        Ok(similar_todos_data) => Ok((
             StatusCode::OK,
             Json(
-                todo_data
+                similar_todos_data
                     .into_iter()
-                    .map(|todo_data| TodoResponse::new(todo_data))
-                    .collect::<Vec<TodoResponse>>(),
+                    .map(SimilarTodoResponse::new)
+                    .collect::<Vec<_>>(),
             ),
         )),
-        Err(e @ TodoApplicationError::DuplicatedTodo(_)) => {
-            Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+        Err(e @ TodoApplicationError::DuplicatedTodo(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::IllegalArgumentError(_)) => Err(ProblemDetails::new(
+            StatusCode::BAD_REQUEST,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::IllegalTodoId(_)) => Err(ProblemDetails::new(
+            StatusCode::BAD_REQUEST,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::TodoNotFound(_)) => Err(ProblemDetails::new(
+            StatusCode::NOT_FOUND,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::Unexpected(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::Timeout) => Err(ProblemDetails::new(
+            StatusCode::SERVICE_UNAVAILABLE,
+            e.localize(*locale),
+        )
+        .with_retry_after(TIMEOUT_RETRY_AFTER_SECONDS)),
+        Err(e @ TodoApplicationError::PartialSuccess { .. }) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::LabelNotFound(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::AssigneeNotFound(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::IllegalLabelId(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::IllegalUserId(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::DependencyNotMet(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::StaleData(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::PermissionDenied(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+    }
+}
+
+#[derive(Deserialize)]
+pub struct TodoBulkGetQuery {
+    // カンマ区切りの todo の id
+    ids: String,
+}
+
+#[derive(Serialize)]
+pub struct TodoBulkGetResponse {
+    todos: Vec<TodoResponse>,
+    not_found_ids: Vec<String>,
+}
+
+impl TodoBulkGetResponse {
+    fn new(result: BatchFindResult<TodoData, TodoId>) -> Self {
+        Self {
+            todos: result.found.into_iter().map(TodoResponse::new).collect(),
+            not_found_ids: result
+                .not_found
+                .iter()
+                .map(|todo_id| todo_id.to_string())
+                .collect(),
         }
-        Err(e @ TodoApplicationError::IllegalArgumentError(_)) => {
-            Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+    }
+}
+
+// 指定された id の todo をまとめて返す
+// 存在しない id があってもエラーにはせず、`not_found_ids` で知らせる
+pub async fn bulk_get<TodoRep, AS>(
+    Extension(locale): Extension<Arc<Locale>>,
+    Extension(todo_repository): Extension<Arc<TodoRep>>,
+    Query(query): Query<TodoBulkGetQuery>,
+) -> Result<impl IntoResponse, impl IntoResponse>
+where
+    TodoRep: ITodoRepository,
+    AS: ITodoBulkGetApplicationService<TodoRep>,
+{
+    let todo_bulk_get_application_service = AS::new(todo_repository);
+    let todo_ids = query
+        .ids
+        .split(',')
+        .map(str::trim)
+        .filter(|todo_id| !todo_id.is_empty())
+        .map(ToString::to_string)
+        .collect();
+
+    match todo_bulk_get_application_service
+        .handle(TodoBulkGetCommand { todo_ids })
+        .await
+    {
+        Ok(result) => Ok((StatusCode::OK, Json(TodoBulkGetResponse::new(result)))),
+        Err(e @ TodoApplicationError::DuplicatedTodo(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::IllegalArgumentError(_)) => Err(ProblemDetails::new(
+            StatusCode::BAD_REQUEST,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::IllegalTodoId(_)) => Err(ProblemDetails::new(
+            StatusCode::BAD_REQUEST,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::TodoNotFound(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::Unexpected(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::Timeout) => Err(ProblemDetails::new(
+            StatusCode::SERVICE_UNAVAILABLE,
+            e.localize(*locale),
+        )
+        .with_retry_after(TIMEOUT_RETRY_AFTER_SECONDS)),
+        Err(e @ TodoApplicationError::PartialSuccess { .. }) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::LabelNotFound(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::AssigneeNotFound(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::IllegalLabelId(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::IllegalUserId(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::DependencyNotMet(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::StaleData(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::PermissionDenied(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+    }
+}
+
+// `completed`, `status` クエリパラメータによる絞り込み。None の項目では絞り込まない
+fn matches_list_params(
+    todo_view: &TodoListViewData,
+    completed: Option<bool>,
+    status: Option<TodoStatus>,
+) -> bool {
+    completed.is_none_or(|completed| todo_view.todo.completed == completed)
+        && status.is_none_or(|status| todo_view.todo.status == status)
+}
+
+// `q` クエリパラメータが指定された場合は text に `q` を含む todo のみを返す
+// `label_slug` クエリパラメータが指定された場合は、その slug のラベルが付いた todo のみを返す
+// `label_ids` クエリパラメータが指定された場合は、`label_operator` (`and` / `or`, 既定は `and`) に従って
+// すべて、またはいずれかのラベルが付いた todo のみを返す
+// 複数が指定された場合は `q`, `label_slug`, `label_ids` の順に優先する
+// 一覧全体の ETag を付けて返し、`If-None-Match` が一致すれば 304 を返す
+#[allow(clippy::too_many_arguments)]
+pub async fn get_all<Rep, LabelRep, UserRep, AS, SearchAS, FilterAS>(
+    Extension(locale): Extension<Arc<Locale>>,
+    Extension(app_config): Extension<Arc<AppConfig>>,
+    Extension(repository): Extension<Arc<Rep>>,
+    Extension(label_repository): Extension<Arc<LabelRep>>,
+    Extension(user_repository): Extension<Arc<UserRep>>,
+    OriginalUri(uri): OriginalUri,
+    headers: HeaderMap,
+    QueryParams(params): QueryParams<TodoListParams>,
+) -> Result<impl IntoResponse, impl IntoResponse>
+where
+    Rep: ITodoRepository,
+    LabelRep: ILabelRepository,
+    UserRep: IUserRepository,
+    AS: ITodoGetAllApplicationService<Rep, UserRep>,
+    SearchAS: ITodoSearchApplicationService<Rep, UserRep>,
+    FilterAS: ITodoFilterByLabelApplicationService<Rep, LabelRep, UserRep>,
+{
+    // 一覧全体が前回から変わっていなければ、絞り込みや並べ替えをせずに 304 を返す
+    // todo 全体の版を読み出せなかった場合は、ETag を付けずに一覧を返す
+    let todo_get_all_application_service = AS::new(repository.clone(), user_repository.clone())
+        .with_query_timeout(app_config.todo_query_timeout());
+    let etag = todo_get_all_application_service
+        .collection_version()
+        .await
+        .ok()
+        .map(|collection_version| collection_etag(&collection_version, Utc::now().date_naive()));
+    if let Some(etag) = etag.as_deref() {
+        if matches_if_none_match(&headers, etag) {
+            let mut res = StatusCode::NOT_MODIFIED.into_response();
+            insert_etag(&mut res, Some(etag));
+            return Ok(res);
+        }
+    }
+
+    let label_filter = match params.label_filter() {
+        Ok(label_filter) => label_filter,
+        Err(e) => {
+            return Err(ProblemDetails::new(
+                StatusCode::BAD_REQUEST,
+                TodoApplicationError::IllegalLabelId(e.to_string()).localize(*locale),
+            ))
         }
-        Err(e @ TodoApplicationError::IllegalTodoId(_)) => {
-            Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+    };
+    let pagination = params.pagination();
+    let TodoListParams {
+        completed,
+        status,
+        q,
+        sort,
+        label_slug,
+        ..
+    } = params;
+    let result = match (q, label_slug) {
+        (Some(q), _) => {
+            let todo_search_application_service = SearchAS::new(repository, user_repository)
+                .with_query_timeout(app_config.todo_query_timeout());
+            todo_search_application_service
+                .handle(TodoSearchCommand { query: q })
+                .await
         }
-        Err(e @ TodoApplicationError::TodoNotFound(_)) => {
-            Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+        (None, Some(label_slug)) => {
+            let todo_filter_by_label_application_service =
+                FilterAS::new(repository, label_repository, user_repository)
+                    .with_query_timeout(app_config.todo_query_timeout());
+            todo_filter_by_label_application_service
+                .handle(TodoFilterByLabelCommand { label_slug })
+                .await
         }
-        Err(e @ TodoApplicationError::Unexpected(_)) => {
-            Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+        // ページ指定が無いときは、全件をメモリに載せずにストリームで返す
+        // 並べ替えるには全件が必要なため、並び順の指定があればストリームにしない
+        (None, None) if !pagination.is_requested() && sort.is_none() => {
+            // リクエストごとに token を作り、その guard をレスポンスのボディと一緒に保持する
+            // クライアントが切断してボディが破棄されると guard も破棄され、token がキャンセルされて
+            // リポジトリで実行中のクエリが取り消される
+            let cancellation_token = CancellationToken::new();
+            let drop_guard = cancellation_token.clone().drop_guard();
+            let mut todo_views = todo_get_all_application_service
+                .handle_streaming(TodoGetAllCommand { label_filter }, cancellation_token)
+                .await;
+            // 最初の要素が届くまで待ち、読み出せなかった場合はストリームにせずにエラーを返す
+            match todo_views.next().await {
+                Some(Err(e)) => Err(e),
+                first_todo_view => {
+                    let todo_views: TodoListViewDataStream =
+                        Box::pin(tokio_stream::iter(first_todo_view).chain(todo_views));
+                    // 要素はすべて通し、ストリームが破棄されるまで guard を保持するためだけに使う
+                    let todo_views: TodoListViewDataStream =
+                        Box::pin(todo_views.take_while(move |_| {
+                            let _drop_guard = &drop_guard;
+                            true
+                        }));
+                    let todo_views: TodoListViewDataStream =
+                        if completed.is_some() || status.is_some() {
+                            // 読み出しに失敗した要素は、打ち切るために残しておく
+                            Box::pin(todo_views.filter(move |todo_view| {
+                                todo_view.as_ref().map_or(true, |todo_view| {
+                                    matches_list_params(todo_view, completed, status)
+                                })
+                            }))
+                        } else {
+                            todo_views
+                        };
+                    let mut res = stream_json_array(todo_views).into_response();
+                    insert_etag(&mut res, etag.as_deref());
+                    return Ok(res);
+                }
+            }
         }
-        Err(e @ TodoApplicationError::LabelNotFound(_)) => {
-            Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+        (None, None) => {
+            todo_get_all_application_service
+                .handle(TodoGetAllCommand { label_filter })
+                .await
         }
-        Err(e @ TodoApplicationError::IllegalLabelId(_)) => {
-            Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+    };
+
+    match result {
+        Ok(mut todo_views) => {
+            todo_views.retain(|todo_view| matches_list_params(todo_view, completed, status));
+            if let Some(sort) = sort {
+                sort.sort(&mut todo_views);
+            }
+            let (todo_views, meta, headers) = paginate(todo_views, &uri, &pagination);
+            let mut res = (
+                StatusCode::OK,
+                headers,
+                Json(PagedResponse::new(
+                    todo_views.into_iter().map(TodoListResponse::new).collect(),
+                    meta,
+                )),
+            )
+                .into_response();
+            insert_etag(&mut res, etag.as_deref());
+            Ok(res)
+        }
+        Err(e @ TodoApplicationError::DuplicatedTodo(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::IllegalArgumentError(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::IllegalTodoId(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::TodoNotFound(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::Unexpected(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::Timeout) => Err(ProblemDetails::new(
+            StatusCode::SERVICE_UNAVAILABLE,
+            e.localize(*locale),
+        )
+        .with_retry_after(TIMEOUT_RETRY_AFTER_SECONDS)),
+        Err(e @ TodoApplicationError::PartialSuccess { .. }) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::LabelNotFound(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::AssigneeNotFound(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::IllegalLabelId(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::IllegalUserId(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::DependencyNotMet(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::StaleData(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::PermissionDenied(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+    }
+}
+
+// todo を 1 件ずつ `data` 配列の要素として書き出し、最後に件数を `meta` として書き出す
+// 途中で読み出しに失敗した場合はステータスコードを変えられないため、レスポンスを打ち切る
+fn stream_json_array(todo_views: TodoListViewDataStream) -> impl IntoResponse {
+    let total = Arc::new(AtomicU64::new(0));
+    let elements = {
+        let total = total.clone();
+        todo_views.map(move |todo_view| {
+            let separator = if total.fetch_add(1, Ordering::Relaxed) == 0 {
+                ""
+            } else {
+                ","
+            };
+            let todo_response = serde_json::to_string(&TodoListResponse::new(todo_view?))?;
+            Ok::<_, BoxError>(format!("{}{}", separator, todo_response))
+        })
+    };
+    // 全件を書き出し終えてから件数を求めるため、最後の要素は読み出されたときに組み立てる
+    let meta = futures_util::stream::once(async move {
+        let meta = PaginationMeta::unpaged(total.load(Ordering::Relaxed));
+        Ok::<_, BoxError>(format!(r#"],"meta":{}}}"#, serde_json::to_string(&meta)?))
+    });
+    let body = tokio_stream::once(Ok(r#"{"data":["#.to_string()))
+        .chain(elements)
+        .chain(meta);
+
+    (
+        [(CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())],
+        StreamBody::new(body),
+    )
+}
+
+pub async fn export_ical<Rep, AS>(
+    Extension(locale): Extension<Arc<Locale>>,
+    Extension(repository): Extension<Arc<Rep>>,
+) -> Result<impl IntoResponse, impl IntoResponse>
+where
+    Rep: ITodoRepository,
+    AS: ITodoIcalExportApplicationService<Rep>,
+{
+    let todo_ical_export_application_service = AS::new(repository);
+
+    match todo_ical_export_application_service
+        .handle(TodoIcalExportCommand {})
+        .await
+    {
+        Ok(ics) => Ok((
+            StatusCode::OK,
+            [(CONTENT_TYPE, "text/calendar; charset=utf-8")],
+            ics,
+        )),
+        Err(e @ TodoApplicationError::DuplicatedTodo(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::IllegalArgumentError(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::IllegalTodoId(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::TodoNotFound(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::Unexpected(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::Timeout) => Err(ProblemDetails::new(
+            StatusCode::SERVICE_UNAVAILABLE,
+            e.localize(*locale),
+        )
+        .with_retry_after(TIMEOUT_RETRY_AFTER_SECONDS)),
+        Err(e @ TodoApplicationError::PartialSuccess { .. }) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::LabelNotFound(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::AssigneeNotFound(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::IllegalLabelId(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::IllegalUserId(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::DependencyNotMet(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::StaleData(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::PermissionDenied(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+    }
+}
+
+#[derive(Deserialize)]
+pub struct TodoExportQuery {
+    format: Option<String>,
+}
+
+// `format=markdown` で Markdown のタスクリスト、指定が無いか `format=ndjson` で NDJSON を返す
+pub async fn export<Rep, AS>(
+    Extension(locale): Extension<Arc<Locale>>,
+    Extension(repository): Extension<Arc<Rep>>,
+    Query(query): Query<TodoExportQuery>,
+) -> Result<impl IntoResponse, impl IntoResponse>
+where
+    Rep: ITodoRepository,
+    AS: ITodoExportApplicationService<Rep>,
+{
+    let todo_export_application_service = AS::new(repository);
+
+    match todo_export_application_service
+        .handle(TodoExportCommand {
+            format: query.format,
+        })
+        .await
+    {
+        Ok(export_data) => {
+            let content_type = match export_data.format {
+                TodoExportFormat::Ndjson => "application/x-ndjson",
+                TodoExportFormat::Markdown => "text/markdown; charset=utf-8",
+            };
+            Ok((
+                StatusCode::OK,
+                [(CONTENT_TYPE, content_type)],
+                export_data.body,
+            ))
         }
+        Err(e @ TodoApplicationError::DuplicatedTodo(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::IllegalArgumentError(_)) => Err(ProblemDetails::new(
+            StatusCode::BAD_REQUEST,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::IllegalTodoId(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::TodoNotFound(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::Unexpected(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::Timeout) => Err(ProblemDetails::new(
+            StatusCode::SERVICE_UNAVAILABLE,
+            e.localize(*locale),
+        )
+        .with_retry_after(TIMEOUT_RETRY_AFTER_SECONDS)),
+        Err(e @ TodoApplicationError::PartialSuccess { .. }) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::LabelNotFound(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::AssigneeNotFound(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::IllegalLabelId(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::IllegalUserId(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::DependencyNotMet(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::StaleData(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::PermissionDenied(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
     }
 }
 
-pub async fn update<TodoRep, LabelRep, AS>(
+#[allow(clippy::too_many_arguments)]
+pub async fn update<TodoRep, LabelRep, TodoDependencyRep, UserRep, AuditLogRep, AS>(
+    Extension(locale): Extension<Arc<Locale>>,
     Extension(todo_repository): Extension<Arc<TodoRep>>,
     Extension(label_repository): Extension<Arc<LabelRep>>,
+    Extension(todo_dependency_repository): Extension<Arc<TodoDependencyRep>>,
+    Extension(user_repository): Extension<Arc<UserRep>>,
+    Extension(audit_log_repository): Extension<Arc<AuditLogRep>>,
+    Extension(event_bus): Extension<Arc<EventBus>>,
+    Extension(app_config): Extension<Arc<AppConfig>>,
+    authenticated_user: Option<Extension<AuthenticatedUser>>,
     Path(id): Path<String>,
     Json(payload): Json<TodoUpdatePayload>,
 ) -> Result<impl IntoResponse, impl IntoResponse>
 where
     TodoRep: ITodoRepository,
     LabelRep: ILabelRepository,
-    AS: ITodoUpdateApplicationService<TodoRep, LabelRep>,
+    TodoDependencyRep: ITodoDependencyRepository,
+    UserRep: IUserRepository,
+    AuditLogRep: IAuditLogRepository,
+    AS: ITodoUpdateApplicationService<TodoRep, LabelRep, TodoDependencyRep, UserRep, AuditLogRep>,
 {
-    let todo_update_application_service = AS::new(todo_repository, label_repository);
+    let todo_update_application_service = AS::new(
+        todo_repository,
+        label_repository,
+        todo_dependency_repository,
+        user_repository,
+        audit_log_repository,
+        event_bus,
+    )
+    .with_max_labels(app_config.max_labels_per_todo);
 
     match todo_update_application_service
-        .handle(payload.into_command(id))
+        .handle(payload.into_command(id, &app_config, requested_by(authenticated_user)))
         .await
     {
         Ok(todo_data) => Ok((StatusCode::OK, Json(TodoResponse::new(todo_data)))),
-        Err(e @ TodoApplicationError::DuplicatedTodo(_)) => {
-            Err((StatusCode::BAD_REQUEST, e.to_string()))
-        }
-        Err(e @ TodoApplicationError::IllegalArgumentError(_)) => {
-            Err((StatusCode::BAD_REQUEST, e.to_string()))
-        }
-        Err(e @ TodoApplicationError::IllegalTodoId(_)) => {
-            Err((StatusCode::BAD_REQUEST, e.to_string()))
-        }
-        Err(e @ TodoApplicationError::TodoNotFound(_)) => {
-            Err((StatusCode::NOT_FOUND, e.to_string()))
-        }
-        Err(e @ TodoApplicationError::Unexpected(_)) => {
-            Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
-        }
-        Err(e @ TodoApplicationError::LabelNotFound(_)) => {
-            Err((StatusCode::BAD_REQUEST, e.to_string()))
-        }
-        Err(e @ TodoApplicationError::IllegalLabelId(_)) => {
-            Err((StatusCode::BAD_REQUEST, e.to_string()))
-        }
+        Err(e @ TodoApplicationError::DuplicatedTodo(_)) => Err(ProblemDetails::new(
+            StatusCode::UNPROCESSABLE_ENTITY,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::IllegalArgumentError(_)) => Err(ProblemDetails::new(
+            StatusCode::UNPROCESSABLE_ENTITY,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::IllegalTodoId(_)) => Err(ProblemDetails::new(
+            StatusCode::BAD_REQUEST,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::TodoNotFound(_)) => Err(ProblemDetails::new(
+            StatusCode::NOT_FOUND,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::Unexpected(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::Timeout) => Err(ProblemDetails::new(
+            StatusCode::SERVICE_UNAVAILABLE,
+            e.localize(*locale),
+        )
+        .with_retry_after(TIMEOUT_RETRY_AFTER_SECONDS)),
+        Err(e @ TodoApplicationError::PartialSuccess { .. }) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::LabelNotFound(_)) => Err(ProblemDetails::new(
+            StatusCode::BAD_REQUEST,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::AssigneeNotFound(_)) => Err(ProblemDetails::new(
+            StatusCode::BAD_REQUEST,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::IllegalLabelId(_)) => Err(ProblemDetails::new(
+            StatusCode::BAD_REQUEST,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::IllegalUserId(_)) => Err(ProblemDetails::new(
+            StatusCode::BAD_REQUEST,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::DependencyNotMet(_)) => Err(ProblemDetails::new(
+            StatusCode::BAD_REQUEST,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::StaleData(_)) => Err(ProblemDetails::new(
+            StatusCode::CONFLICT,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::PermissionDenied(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
     }
 }
 
-pub async fn delete<Rep, AS>(
+pub async fn delete<Rep, AuditLogRep, AS>(
+    Extension(locale): Extension<Arc<Locale>>,
     Extension(repository): Extension<Arc<Rep>>,
+    Extension(audit_log_repository): Extension<Arc<AuditLogRep>>,
+    Extension(event_bus): Extension<Arc<EventBus>>,
+    authenticated_user: Option<Extension<AuthenticatedUser>>,
     Path(id): Path<String>,
 ) -> Result<StatusCode, impl IntoResponse>
 where
     Rep: ITodoRepository,
-    AS: ITodoDeleteApplicationService<Rep>,
+    AuditLogRep: IAuditLogRepository,
+    AS: ITodoDeleteApplicationService<Rep, AuditLogRep>,
 {
-    let todo_delete_application_service = AS::new(repository);
+    let todo_delete_application_service = AS::new(repository, audit_log_repository, event_bus);
 
     match todo_delete_application_service
-        .handle(TodoDeleteCommand { todo_id: id })
+        .handle(TodoDeleteCommand {
+            todo_id: id,
+            requested_by: requested_by(authenticated_user),
+        })
         .await
     {
         Ok(_) => Ok(StatusCode::NO_CONTENT),
-        Err(e @ TodoApplicationError::DuplicatedTodo(_)) => {
-            Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
-        }
-        Err(e @ TodoApplicationError::IllegalArgumentError(_)) => {
-            Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
-        }
-        Err(e @ TodoApplicationError::IllegalTodoId(_)) => {
-            Err((StatusCode::BAD_REQUEST, e.to_string()))
-        }
-        Err(e @ TodoApplicationError::TodoNotFound(_)) => {
-            Err((StatusCode::NOT_FOUND, e.to_string()))
-        }
-        Err(e @ TodoApplicationError::Unexpected(_)) => {
-            Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+        Err(e @ TodoApplicationError::DuplicatedTodo(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::IllegalArgumentError(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::IllegalTodoId(_)) => Err(ProblemDetails::new(
+            StatusCode::BAD_REQUEST,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::TodoNotFound(_)) => Err(ProblemDetails::new(
+            StatusCode::NOT_FOUND,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::Unexpected(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::Timeout) => Err(ProblemDetails::new(
+            StatusCode::SERVICE_UNAVAILABLE,
+            e.localize(*locale),
+        )
+        .with_retry_after(TIMEOUT_RETRY_AFTER_SECONDS)),
+        Err(e @ TodoApplicationError::PartialSuccess { .. }) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::LabelNotFound(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::AssigneeNotFound(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::IllegalLabelId(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::IllegalUserId(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::DependencyNotMet(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::StaleData(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::PermissionDenied(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+    }
+}
+
+#[serde_as]
+#[derive(Serialize)]
+pub struct AuditEntryResponse {
+    entry_id: String,
+    actor_name: Option<String>,
+    action: String,
+    #[serde_as(as = "Rfc3339")]
+    occurred_at: DateTime<Utc>,
+    changes_summary: String,
+}
+
+impl AuditEntryResponse {
+    fn new(audit_entry_data: AuditEntryData) -> Self {
+        Self {
+            entry_id: audit_entry_data.entry_id.to_string(),
+            actor_name: audit_entry_data.actor_name,
+            action: audit_entry_data.action,
+            occurred_at: audit_entry_data.occurred_at,
+            changes_summary: audit_entry_data.changes_summary,
         }
-        Err(e @ TodoApplicationError::LabelNotFound(_)) => {
-            Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+    }
+}
+
+// todo の変更履歴を古い順に返す。削除済みの todo の履歴も返す
+pub async fn get_audit<AuditLogRep, UserRep, AS>(
+    Extension(locale): Extension<Arc<Locale>>,
+    Extension(audit_log_repository): Extension<Arc<AuditLogRep>>,
+    Extension(user_repository): Extension<Arc<UserRep>>,
+    OriginalUri(uri): OriginalUri,
+    Path(id): Path<String>,
+    Query(pagination): Query<PaginationQuery>,
+) -> Result<impl IntoResponse, impl IntoResponse>
+where
+    AuditLogRep: IAuditLogRepository,
+    UserRep: IUserRepository,
+    AS: ITodoGetAuditApplicationService<AuditLogRep, UserRep>,
+{
+    let todo_get_audit_application_service = AS::new(audit_log_repository, user_repository);
+
+    match todo_get_audit_application_service
+        .handle(TodoGetAuditCommand { todo_id: id })
+        .await
+    {
+        Ok(audit_entries_data) => {
+            let (audit_entries_data, meta, headers) =
+                paginate(audit_entries_data, &uri, &pagination);
+            Ok((
+                StatusCode::OK,
+                headers,
+                Json(PagedResponse::new(
+                    audit_entries_data
+                        .into_iter()
+                        .map(AuditEntryResponse::new)
+                        .collect(),
+                    meta,
+                )),
+            ))
         }
-        Err(e @ TodoApplicationError::IllegalLabelId(_)) => {
-            Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+        Err(e @ TodoApplicationError::DuplicatedTodo(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::IllegalArgumentError(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::IllegalTodoId(_)) => Err(ProblemDetails::new(
+            StatusCode::BAD_REQUEST,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::TodoNotFound(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::Unexpected(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::Timeout) => Err(ProblemDetails::new(
+            StatusCode::SERVICE_UNAVAILABLE,
+            e.localize(*locale),
+        )
+        .with_retry_after(TIMEOUT_RETRY_AFTER_SECONDS)),
+        Err(e @ TodoApplicationError::PartialSuccess { .. }) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::LabelNotFound(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::AssigneeNotFound(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::IllegalLabelId(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::IllegalUserId(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::DependencyNotMet(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::StaleData(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ TodoApplicationError::PermissionDenied(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::domain::{
+        models::{
+            labels::label_id::LabelId,
+            todos::{todo::Todo, todo_id::TodoId, todo_text::TodoText},
+        },
+        value_object::ValueObject,
+    };
+
+    #[test]
+    fn should_localize_to_same_message_as_display_in_english() -> Result<()> {
+        use TodoApplicationError::*;
+
+        let errors = [
+            IllegalArgumentError("a".to_string()),
+            IllegalTodoId("a".to_string()),
+            IllegalLabelId("a".to_string()),
+            Unexpected("a".to_string()),
+        ];
+
+        for error in errors {
+            assert_eq!(error.to_string(), error.localize(Locale::En));
         }
+        Ok(())
+    }
+
+    #[test]
+    fn should_localize_values_with_display() -> Result<()> {
+        use TodoApplicationError::*;
+
+        let todo = Todo::new(TodoText::new("test".to_string())?, vec![])?;
+        let todo_id_1 = TodoId::new(Uuid::new_v4())?;
+        let todo_id_2 = TodoId::new(Uuid::new_v4())?;
+        let label_id = LabelId::new(Uuid::new_v4())?;
+
+        assert_eq!(
+            "Given todo is duplicated: [given todo: test]",
+            DuplicatedTodo(todo).localize(Locale::En)
+        );
+        assert_eq!(
+            format!("Todo cannnot be found: [id: {}]", todo_id_1),
+            TodoNotFound(todo_id_1.clone()).localize(Locale::En)
+        );
+        assert_eq!(
+            format!("Label cannnot be found: [id: {}]", label_id),
+            LabelNotFound(label_id).localize(Locale::En)
+        );
+        assert_eq!(
+            format!(
+                "Todos that must be completed first are not completed: [ids: {}, {}]",
+                todo_id_1, todo_id_2
+            ),
+            DependencyNotMet(vec![todo_id_1, todo_id_2]).localize(Locale::En)
+        );
+        assert_eq!(
+            "Some todos cannot be created: [failed: #1: Todo text must not be empty.]",
+            PartialSuccess {
+                created: vec![],
+                failed: vec![(1, "Todo text must not be empty.".to_string())],
+            }
+            .localize(Locale::En)
+        );
+        Ok(())
     }
 }
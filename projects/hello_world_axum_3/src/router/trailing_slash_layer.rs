@@ -0,0 +1,96 @@
+use std::task::{Context, Poll};
+
+use axum::http::{uri::PathAndQuery, Request, Uri};
+use tower::{Layer, Service};
+
+// ルーティングの前にリクエストのパスを正規化するレイヤー
+// 末尾のスラッシュを取り除き、連続したスラッシュを 1 つにまとめる (`/todos//123/` → `/todos/123`)
+// ルーターのレイヤーはルーティングの後に実行されるため、ルーター全体を包むように適用する
+#[derive(Clone, Default)]
+pub struct TrailingSlashLayer;
+
+impl TrailingSlashLayer {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl<S> Layer<S> for TrailingSlashLayer {
+    type Service = TrailingSlash<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        TrailingSlash { inner }
+    }
+}
+
+#[derive(Clone)]
+pub struct TrailingSlash<S> {
+    inner: S,
+}
+
+impl<S, ReqBody> Service<Request<ReqBody>> for TrailingSlash<S>
+where
+    S: Service<Request<ReqBody>>,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = S::Future;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut req: Request<ReqBody>) -> Self::Future {
+        if let Some(uri) = normalize_uri(req.uri()) {
+            *req.uri_mut() = uri;
+        }
+        self.inner.call(req)
+    }
+}
+
+// パスを書き換える必要がない場合は None を返す
+fn normalize_uri(uri: &Uri) -> Option<Uri> {
+    let path = normalize_path(uri.path());
+    if path == uri.path() {
+        return None;
+    }
+    let path_and_query = match uri.query() {
+        Some(query) => format!("{}?{}", path, query),
+        None => path,
+    };
+
+    let mut parts = uri.clone().into_parts();
+    parts.path_and_query = Some(PathAndQuery::try_from(path_and_query).ok()?);
+    Uri::from_parts(parts).ok()
+}
+
+// `/` はそのまま `/` になる
+fn normalize_path(path: &str) -> String {
+    let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+    format!("/{}", segments.join("/"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_normalize_path() {
+        assert_eq!("/todos", normalize_path("/todos/"));
+        assert_eq!("/todos/123", normalize_path("/todos//123"));
+        assert_eq!("/todos/123", normalize_path("//todos/123//"));
+        assert_eq!("/", normalize_path("/"));
+        assert_eq!("/", normalize_path("//"));
+    }
+
+    #[test]
+    fn should_keep_query_string() {
+        let uri: Uri = "/todos/?page=2&per_page=10".parse().unwrap();
+        assert_eq!(
+            Some("/todos?page=2&per_page=10".parse::<Uri>().unwrap()),
+            normalize_uri(&uri)
+        );
+        let uri: Uri = "/todos?page=2".parse().unwrap();
+        assert_eq!(None, normalize_uri(&uri));
+    }
+}
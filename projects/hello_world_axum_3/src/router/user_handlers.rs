@@ -1,30 +1,111 @@
-use std::sync::Arc;
+use std::{collections::BTreeMap, sync::Arc};
 
 use axum::{
-    extract::{Extension, Path},
-    response::IntoResponse,
+    extract::{Extension, OriginalUri, Path, Query},
+    response::{IntoResponse, Response},
     Json,
 };
 use hyper::StatusCode;
 use serde::{Deserialize, Serialize};
 
 use crate::{
+    app_config::AppConfig,
     application::users::{
         user_application_error::UserApplicationError,
-        user_create_application_service::{IUserCreateApplicationService, UserCreateCommand},
-        user_data::UserData,
+        user_create_application_service::{
+            IUserCreateApplicationService, IfExists, UserCreateCommand,
+        },
+        user_data::{UserData, UserDataView},
         user_delete_application_service::{IUserDeleteApplicationService, UserDeleteCommand},
         user_get_all_aplication_service::{IUserGetAllApplicationService, UserGetAllCommand},
         user_get_application_service::{IUserGetApplicationService, UserGetCommand},
+        user_get_by_role_application_service::{
+            IUserGetByRoleApplicationService, UserGetByRoleCommand,
+        },
+        user_label_application_service::{IUserLabelApplicationService, UserLabelCommand},
+        user_password_change_application_service::{
+            IUserPasswordChangeApplicationService, UserPasswordChangeCommand,
+        },
+        user_role_distribution_application_service::IUserRoleDistributionApplicationService,
+        user_search_application_service::{IUserSearchApplicationService, UserSearchCommand},
+        user_self_update_application_service::{
+            IUserSelfUpdateApplicationService, UserSelfUpdateCommand,
+        },
         user_update_application_service::{IUserUpdateApplicationService, UserUpdateCommand},
     },
-    domain::models::users::user_repository::IUserRepository,
+    domain::{
+        models::{
+            credentials::credential_repository::ICredentialRepository,
+            labels::label_repository::ILabelRepository, todos::todo_repository::ITodoRepository,
+            users::user_repository::IUserRepository,
+        },
+        services::profanity_filter::ProfanityFilter,
+        value_object::ValueObject,
+    },
+};
+
+use super::{
+    authentication::{account_locked, AuthenticatedUser},
+    label_handlers::LabelResponse,
+    locale::Locale,
+    messages::{Localize, Messages},
+    pagination::{paginate, PagedResponse, PaginationQuery},
+    problem_details::ProblemDetails,
+    resource_location::insert_resource_location,
 };
 
+impl Localize for UserApplicationError {
+    fn localize(&self, locale: Locale) -> String {
+        let messages = Messages::get();
+        match self {
+            UserApplicationError::DuplicatedUser(user) => {
+                messages.error(locale, "user.duplicated", &[user.user_name.value()])
+            }
+            UserApplicationError::UserNotFound(user_id) => {
+                messages.error(locale, "user.not_found", &[&user_id.to_string()])
+            }
+            UserApplicationError::IllegalArgumentError(message) => messages.error(
+                locale,
+                "user.illegal_argument",
+                &[&messages.domain_message(locale, message)],
+            ),
+            UserApplicationError::IllegalUserId(message) => messages.error(
+                locale,
+                "user.illegal_user_id",
+                &[&messages.domain_message(locale, message)],
+            ),
+            UserApplicationError::IllegalUserRole(message) => messages.error(
+                locale,
+                "user.illegal_user_role",
+                &[&messages.domain_message(locale, message)],
+            ),
+            UserApplicationError::PasswordMismatch => {
+                messages.error(locale, "user.password_mismatch", &[])
+            }
+            UserApplicationError::AccountLocked { until } => {
+                messages.error(locale, "user.account_locked", &[&until.to_string()])
+            }
+            UserApplicationError::PermissionDenied(user_id) => {
+                messages.error(locale, "user.permission_denied", &[&user_id.to_string()])
+            }
+            UserApplicationError::Unexpected(message) => {
+                messages.error(locale, "unexpected", &[message])
+            }
+        }
+    }
+}
+
 #[derive(Serialize)]
 pub struct UserResponse {
     id: String,
     name: String,
+    // 本人と管理者以外には見せない
+    #[serde(skip_serializing_if = "Option::is_none")]
+    role: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    completion_rate: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    current_streak: Option<u32>,
 }
 
 impl UserResponse {
@@ -32,19 +113,84 @@ impl UserResponse {
         Self {
             id: user_data.user_id.to_string(),
             name: user_data.user_name,
+            role: Some(user_data.user_role),
+            completion_rate: user_data.completion_rate,
+            current_streak: user_data.current_streak,
+        }
+    }
+
+    fn from_view(user_data_view: UserDataView) -> Self {
+        match user_data_view {
+            UserDataView::Public(user_data) => Self {
+                id: user_data.user_id.to_string(),
+                name: user_data.user_name,
+                role: None,
+                completion_rate: None,
+                current_streak: None,
+            },
+            UserDataView::Full(user_data) => Self::new(user_data),
         }
     }
 }
 
+#[derive(Deserialize)]
+pub struct UserGetAllQuery {
+    role: Option<String>,
+}
+
+// `limit` が指定されない場合は 10 件まで返す
+const DEFAULT_USER_SEARCH_LIMIT: u32 = 10;
+
+#[derive(Deserialize)]
+pub struct UserSearchQuery {
+    q: String,
+    limit: Option<u32>,
+}
+
 #[derive(Deserialize)]
 pub struct UserCreatePayload {
     user_name: String,
 }
 
 impl UserCreatePayload {
-    fn into_command(self) -> UserCreateCommand {
+    fn into_command(
+        self,
+        if_exists: IfExists,
+        authenticated_user: Option<Extension<AuthenticatedUser>>,
+    ) -> UserCreateCommand {
         UserCreateCommand {
             user_name: self.user_name,
+            if_exists,
+            requested_by: authenticated_user
+                .map(|Extension(authenticated_user)| authenticated_user.user_id),
+        }
+    }
+}
+
+// 同じ名前のユーザーがいる場合の扱い。指定がなければエラーにする
+#[derive(Deserialize)]
+pub struct UserCreateQuery {
+    #[serde(default)]
+    if_exists: IfExists,
+}
+
+#[derive(Deserialize)]
+pub struct UserPasswordChangePayload {
+    current_password: String,
+    new_password: String,
+}
+
+impl UserPasswordChangePayload {
+    fn into_command(
+        self,
+        id: String,
+        authenticated_user: AuthenticatedUser,
+    ) -> UserPasswordChangeCommand {
+        UserPasswordChangeCommand {
+            user_id: id,
+            current_password: self.current_password,
+            new_password: self.new_password,
+            requested_by: authenticated_user.user_id,
         }
     }
 }
@@ -55,122 +201,528 @@ pub struct UserUpdatePayload {
 }
 
 impl UserUpdatePayload {
-    fn into_command(self, id: String) -> UserUpdateCommand {
+    fn into_command(
+        self,
+        id: String,
+        authenticated_user: Option<Extension<AuthenticatedUser>>,
+    ) -> UserUpdateCommand {
         UserUpdateCommand {
             user_id: id,
             user_name: self.user_name,
+            requested_by: authenticated_user
+                .map(|Extension(authenticated_user)| authenticated_user.user_id),
+        }
+    }
+}
+
+impl UserUpdatePayload {
+    fn into_self_update_command(
+        self,
+        authenticated_user: AuthenticatedUser,
+    ) -> UserSelfUpdateCommand {
+        UserSelfUpdateCommand {
+            authenticated_user_id: authenticated_user.user_id,
+            user_name: self.user_name,
+        }
+    }
+}
+
+#[derive(Deserialize, Default)]
+pub struct UserDeletePayload {
+    password_confirmation: String,
+    // true の場合は物理削除せず、名前を匿名化して論理削除する
+    #[serde(default)]
+    anonymize: bool,
+}
+
+impl UserDeletePayload {
+    // 管理者かどうかは、認証したユーザーのロールをもとにアプリケーションサービスで判断する
+    fn into_command(self, id: String, authenticated_user: AuthenticatedUser) -> UserDeleteCommand {
+        UserDeleteCommand {
+            user_id: id,
+            password_confirmation: self.password_confirmation,
+            requested_by: authenticated_user.user_id,
         }
     }
 }
 
 pub async fn create<Rep, AS>(
+    Extension(locale): Extension<Arc<Locale>>,
     Extension(repository): Extension<Arc<Rep>>,
+    Extension(profanity_filter): Extension<Arc<ProfanityFilter>>,
+    Extension(app_config): Extension<Arc<AppConfig>>,
+    authenticated_user: Option<Extension<AuthenticatedUser>>,
+    Query(query): Query<UserCreateQuery>,
     Json(payload): Json<UserCreatePayload>,
 ) -> Result<impl IntoResponse, impl IntoResponse>
 where
     Rep: IUserRepository,
     AS: IUserCreateApplicationService<Rep>,
 {
-    let user_create_application_service = AS::new(repository);
+    let user_create_application_service = AS::new(repository, profanity_filter);
 
     match user_create_application_service
-        .handle(payload.into_command())
+        .handle(payload.into_command(query.if_exists, authenticated_user))
         .await
     {
-        Ok(user_data) => Ok((StatusCode::CREATED, Json(UserResponse::new(user_data)))),
-        Err(e @ UserApplicationError::DuplicatedUser(_)) => {
-            Err((StatusCode::BAD_REQUEST, e.to_string()))
-        }
-        Err(e @ UserApplicationError::IllegalArgumentError(_)) => {
-            Err((StatusCode::BAD_REQUEST, e.to_string()))
-        }
-        Err(e @ UserApplicationError::IllegalUserId(_)) => {
-            Err((StatusCode::BAD_REQUEST, e.to_string()))
-        }
-        Err(e @ UserApplicationError::UserNotFound(_)) => {
-            Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
-        }
-        Err(e @ UserApplicationError::Unexpected(_)) => {
-            Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+        Ok(result) => {
+            // 既存のユーザーを返した場合は 200
+            let status = if result.created {
+                StatusCode::CREATED
+            } else {
+                StatusCode::OK
+            };
+            let path = format!("/users/{}", result.user_data.user_id());
+            let mut response =
+                (status, Json(UserResponse::from_view(result.user_data))).into_response();
+            insert_resource_location(&mut response, &app_config, &path, result.created);
+            Ok(response)
         }
+        Err(e @ UserApplicationError::DuplicatedUser(_)) => Err(ProblemDetails::new(
+            StatusCode::UNPROCESSABLE_ENTITY,
+            e.localize(*locale),
+        )),
+        Err(e @ UserApplicationError::IllegalArgumentError(_)) => Err(ProblemDetails::new(
+            StatusCode::UNPROCESSABLE_ENTITY,
+            e.localize(*locale),
+        )),
+        Err(e @ UserApplicationError::IllegalUserId(_)) => Err(ProblemDetails::new(
+            StatusCode::BAD_REQUEST,
+            e.localize(*locale),
+        )),
+        Err(e @ UserApplicationError::IllegalUserRole(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ UserApplicationError::PasswordMismatch) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ UserApplicationError::AccountLocked { .. }) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ UserApplicationError::PermissionDenied(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ UserApplicationError::UserNotFound(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ UserApplicationError::Unexpected(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
     }
 }
 
-pub async fn get<Rep, AS>(
+#[derive(Deserialize)]
+pub struct UserGetQuery {
+    #[serde(default)]
+    include_stats: bool,
+}
+
+// 認証していれば、本人か管理者にはロールも返す
+pub async fn get<Rep, TodoRep, AS>(
+    Extension(locale): Extension<Arc<Locale>>,
     Extension(repository): Extension<Arc<Rep>>,
+    Extension(todo_repository): Extension<Arc<TodoRep>>,
+    authenticated_user: Option<Extension<AuthenticatedUser>>,
     Path(id): Path<String>,
+    Query(query): Query<UserGetQuery>,
 ) -> Result<impl IntoResponse, impl IntoResponse>
 where
     Rep: IUserRepository,
-    AS: IUserGetApplicationService<Rep>,
+    TodoRep: ITodoRepository,
+    AS: IUserGetApplicationService<Rep, TodoRep>,
 {
-    let user_get_application_service = AS::new(repository);
+    let user_get_application_service = AS::new(repository, todo_repository);
 
     match user_get_application_service
-        .handle(UserGetCommand { user_id: id })
+        .handle(UserGetCommand {
+            user_id: id,
+            include_stats: query.include_stats,
+            requested_by: authenticated_user
+                .map(|Extension(authenticated_user)| authenticated_user.user_id),
+        })
         .await
     {
-        Ok(user_data) => Ok((StatusCode::OK, Json(UserResponse::new(user_data)))),
-        Err(e @ UserApplicationError::DuplicatedUser(_)) => {
-            Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
-        }
-        Err(e @ UserApplicationError::IllegalArgumentError(_)) => {
-            Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
-        }
-        Err(e @ UserApplicationError::IllegalUserId(_)) => {
-            Err((StatusCode::BAD_REQUEST, e.to_string()))
-        }
-        Err(e @ UserApplicationError::UserNotFound(_)) => {
-            Err((StatusCode::NOT_FOUND, e.to_string()))
+        Ok(user_data) => Ok((StatusCode::OK, Json(UserResponse::from_view(user_data)))),
+        Err(e @ UserApplicationError::DuplicatedUser(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ UserApplicationError::IllegalArgumentError(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ UserApplicationError::IllegalUserId(_)) => Err(ProblemDetails::new(
+            StatusCode::BAD_REQUEST,
+            e.localize(*locale),
+        )),
+        Err(e @ UserApplicationError::IllegalUserRole(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ UserApplicationError::PasswordMismatch) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ UserApplicationError::AccountLocked { .. }) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ UserApplicationError::PermissionDenied(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ UserApplicationError::UserNotFound(_)) => Err(ProblemDetails::new(
+            StatusCode::NOT_FOUND,
+            e.localize(*locale),
+        )),
+        Err(e @ UserApplicationError::Unexpected(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+    }
+}
+
+// 指定したユーザーが担当している todo に付いているラベルを返す
+pub async fn get_labels<Rep, LabelRep, AS>(
+    Extension(locale): Extension<Arc<Locale>>,
+    Extension(repository): Extension<Arc<Rep>>,
+    Extension(label_repository): Extension<Arc<LabelRep>>,
+    Path(id): Path<String>,
+) -> Result<impl IntoResponse, impl IntoResponse>
+where
+    Rep: IUserRepository,
+    LabelRep: ILabelRepository,
+    AS: IUserLabelApplicationService<Rep, LabelRep>,
+{
+    let user_label_application_service = AS::new(repository, label_repository);
+
+    match user_label_application_service
+        .handle(UserLabelCommand { user_id: id })
+        .await
+    {
+        Ok(labels_data) => {
+            let labels_response: Vec<LabelResponse> =
+                labels_data.into_iter().map(LabelResponse::new).collect();
+            Ok((StatusCode::OK, Json(labels_response)))
         }
-        Err(e @ UserApplicationError::Unexpected(_)) => {
-            Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+        Err(e @ UserApplicationError::DuplicatedUser(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ UserApplicationError::IllegalArgumentError(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ UserApplicationError::IllegalUserId(_)) => Err(ProblemDetails::new(
+            StatusCode::BAD_REQUEST,
+            e.localize(*locale),
+        )),
+        Err(e @ UserApplicationError::IllegalUserRole(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ UserApplicationError::PasswordMismatch) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ UserApplicationError::AccountLocked { .. }) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ UserApplicationError::PermissionDenied(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ UserApplicationError::UserNotFound(_)) => Err(ProblemDetails::new(
+            StatusCode::NOT_FOUND,
+            e.localize(*locale),
+        )),
+        Err(e @ UserApplicationError::Unexpected(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+    }
+}
+
+// 管理者向けに、ロールごとのユーザー数を `{"Admin": 2, "Member": 45, "Viewer": 12}` の形で返す
+pub async fn get_role_distribution<Rep, AS>(
+    Extension(locale): Extension<Arc<Locale>>,
+    Extension(repository): Extension<Arc<Rep>>,
+) -> Result<impl IntoResponse, impl IntoResponse>
+where
+    Rep: IUserRepository,
+    AS: IUserRoleDistributionApplicationService<Rep>,
+{
+    let user_role_distribution_application_service = AS::new(repository);
+
+    match user_role_distribution_application_service.handle().await {
+        Ok(counts) => {
+            // キーの順序を固定するため BTreeMap に詰め替える
+            let counts_response: BTreeMap<&str, u64> = counts
+                .into_iter()
+                .map(|(user_role, count)| (user_role.as_str(), count))
+                .collect();
+            Ok((StatusCode::OK, Json(counts_response)))
         }
+        Err(e @ UserApplicationError::DuplicatedUser(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ UserApplicationError::IllegalArgumentError(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ UserApplicationError::IllegalUserId(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ UserApplicationError::IllegalUserRole(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ UserApplicationError::PasswordMismatch) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ UserApplicationError::AccountLocked { .. }) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ UserApplicationError::PermissionDenied(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ UserApplicationError::UserNotFound(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ UserApplicationError::Unexpected(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
     }
 }
 
-pub async fn get_all<Rep, AS>(
+// 認証済みのユーザー自身の情報を返す
+pub async fn me<Rep, TodoRep, AS>(
+    Extension(locale): Extension<Arc<Locale>>,
     Extension(repository): Extension<Arc<Rep>>,
+    Extension(todo_repository): Extension<Arc<TodoRep>>,
+    Extension(authenticated_user): Extension<AuthenticatedUser>,
 ) -> Result<impl IntoResponse, impl IntoResponse>
 where
     Rep: IUserRepository,
-    AS: IUserGetAllApplicationService<Rep>,
+    TodoRep: ITodoRepository,
+    AS: IUserGetApplicationService<Rep, TodoRep>,
 {
-    let user_get_all_application_service = AS::new(repository);
+    let user_get_application_service = AS::new(repository, todo_repository);
 
-    match user_get_all_application_service
-        .handle(UserGetAllCommand {})
+    match user_get_application_service
+        .handle(UserGetCommand {
+            user_id: authenticated_user.user_id.to_string(),
+            include_stats: false,
+            requested_by: Some(authenticated_user.user_id),
+        })
         .await
     {
+        Ok(user_data) => Ok((StatusCode::OK, Json(UserResponse::from_view(user_data)))),
+        Err(e @ UserApplicationError::DuplicatedUser(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ UserApplicationError::IllegalArgumentError(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ UserApplicationError::IllegalUserId(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ UserApplicationError::IllegalUserRole(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ UserApplicationError::PasswordMismatch) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ UserApplicationError::AccountLocked { .. }) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ UserApplicationError::PermissionDenied(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ UserApplicationError::UserNotFound(_)) => Err(ProblemDetails::new(
+            StatusCode::NOT_FOUND,
+            e.localize(*locale),
+        )),
+        Err(e @ UserApplicationError::Unexpected(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+    }
+}
+
+// `role` クエリパラメータが指定された場合はそのロールのユーザーのみを返す
+pub async fn get_all<Rep, AS, ByRoleAS>(
+    Extension(locale): Extension<Arc<Locale>>,
+    Extension(repository): Extension<Arc<Rep>>,
+    authenticated_user: Option<Extension<AuthenticatedUser>>,
+    OriginalUri(uri): OriginalUri,
+    Query(query): Query<UserGetAllQuery>,
+    Query(pagination): Query<PaginationQuery>,
+) -> Result<impl IntoResponse, impl IntoResponse>
+where
+    Rep: IUserRepository,
+    AS: IUserGetAllApplicationService<Rep>,
+    ByRoleAS: IUserGetByRoleApplicationService<Rep>,
+{
+    let requested_by =
+        authenticated_user.map(|Extension(authenticated_user)| authenticated_user.user_id);
+    let result = match query.role {
+        Some(role) => {
+            let user_get_by_role_application_service = ByRoleAS::new(repository);
+            user_get_by_role_application_service
+                .handle(UserGetByRoleCommand { role, requested_by })
+                .await
+        }
+        None => {
+            let user_get_all_application_service = AS::new(repository);
+            user_get_all_application_service
+                .handle(UserGetAllCommand { requested_by })
+                .await
+        }
+    };
+
+    match result {
+        Ok(user_data) => {
+            let (user_data, meta, headers) = paginate(user_data, &uri, &pagination);
+            Ok((
+                StatusCode::OK,
+                headers,
+                Json(PagedResponse::new(
+                    user_data.into_iter().map(UserResponse::from_view).collect(),
+                    meta,
+                )),
+            ))
+        }
+        Err(e @ UserApplicationError::DuplicatedUser(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ UserApplicationError::IllegalArgumentError(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ UserApplicationError::IllegalUserId(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ UserApplicationError::IllegalUserRole(_)) => Err(ProblemDetails::new(
+            StatusCode::BAD_REQUEST,
+            e.localize(*locale),
+        )),
+        Err(e @ UserApplicationError::PasswordMismatch) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ UserApplicationError::AccountLocked { .. }) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ UserApplicationError::PermissionDenied(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ UserApplicationError::UserNotFound(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ UserApplicationError::Unexpected(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+    }
+}
+
+pub async fn search<Rep, AS>(
+    Extension(locale): Extension<Arc<Locale>>,
+    Extension(repository): Extension<Arc<Rep>>,
+    authenticated_user: Option<Extension<AuthenticatedUser>>,
+    Query(query): Query<UserSearchQuery>,
+) -> Result<impl IntoResponse, impl IntoResponse>
+where
+    Rep: IUserRepository,
+    AS: IUserSearchApplicationService<Rep>,
+{
+    let user_search_application_service = AS::new(repository);
+    let command = UserSearchCommand {
+        query: query.q,
+        limit: query.limit.unwrap_or(DEFAULT_USER_SEARCH_LIMIT),
+        requested_by: authenticated_user
+            .map(|Extension(authenticated_user)| authenticated_user.user_id),
+    };
+    match user_search_application_service.handle(command).await {
         Ok(user_data) => Ok((
             StatusCode::OK,
             Json(
                 user_data
                     .into_iter()
-                    .map(|user_data| UserResponse::new(user_data))
+                    .map(UserResponse::from_view)
                     .collect::<Vec<UserResponse>>(),
             ),
         )),
-        Err(e @ UserApplicationError::DuplicatedUser(_)) => {
-            Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
-        }
-        Err(e @ UserApplicationError::IllegalArgumentError(_)) => {
-            Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
-        }
-        Err(e @ UserApplicationError::IllegalUserId(_)) => {
-            Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
-        }
-        Err(e @ UserApplicationError::UserNotFound(_)) => {
-            Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
-        }
-        Err(e @ UserApplicationError::Unexpected(_)) => {
-            Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
-        }
+        Err(e @ UserApplicationError::DuplicatedUser(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ UserApplicationError::IllegalArgumentError(_)) => Err(ProblemDetails::new(
+            StatusCode::UNPROCESSABLE_ENTITY,
+            e.localize(*locale),
+        )),
+        Err(e @ UserApplicationError::IllegalUserId(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ UserApplicationError::IllegalUserRole(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ UserApplicationError::PasswordMismatch) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ UserApplicationError::AccountLocked { .. }) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ UserApplicationError::PermissionDenied(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ UserApplicationError::UserNotFound(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ UserApplicationError::Unexpected(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
     }
 }
 
 pub async fn update<Rep, AS>(
+    Extension(locale): Extension<Arc<Locale>>,
     Extension(repository): Extension<Arc<Rep>>,
+    authenticated_user: Option<Extension<AuthenticatedUser>>,
     Path(id): Path<String>,
     Json(payload): Json<UserUpdatePayload>,
 ) -> Result<impl IntoResponse, impl IntoResponse>
@@ -181,57 +733,220 @@ where
     let user_update_application_service = AS::new(repository);
 
     match user_update_application_service
-        .handle(payload.into_command(id))
+        .handle(payload.into_command(id, authenticated_user))
+        .await
+    {
+        Ok(user_data) => Ok((StatusCode::OK, Json(UserResponse::from_view(user_data)))),
+        Err(e @ UserApplicationError::DuplicatedUser(_)) => Err(ProblemDetails::new(
+            StatusCode::UNPROCESSABLE_ENTITY,
+            e.localize(*locale),
+        )),
+        Err(e @ UserApplicationError::IllegalArgumentError(_)) => Err(ProblemDetails::new(
+            StatusCode::UNPROCESSABLE_ENTITY,
+            e.localize(*locale),
+        )),
+        Err(e @ UserApplicationError::IllegalUserId(_)) => Err(ProblemDetails::new(
+            StatusCode::BAD_REQUEST,
+            e.localize(*locale),
+        )),
+        Err(e @ UserApplicationError::IllegalUserRole(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ UserApplicationError::PasswordMismatch) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ UserApplicationError::AccountLocked { .. }) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ UserApplicationError::PermissionDenied(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ UserApplicationError::UserNotFound(_)) => Err(ProblemDetails::new(
+            StatusCode::NOT_FOUND,
+            e.localize(*locale),
+        )),
+        Err(e @ UserApplicationError::Unexpected(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+    }
+}
+
+// 認証済みのユーザー自身の情報のみを更新する
+pub async fn update_me<Rep, AS>(
+    Extension(locale): Extension<Arc<Locale>>,
+    Extension(repository): Extension<Arc<Rep>>,
+    Extension(authenticated_user): Extension<AuthenticatedUser>,
+    Json(payload): Json<UserUpdatePayload>,
+) -> Result<impl IntoResponse, impl IntoResponse>
+where
+    Rep: IUserRepository,
+    AS: IUserSelfUpdateApplicationService<Rep>,
+{
+    let user_self_update_application_service = AS::new(repository);
+
+    match user_self_update_application_service
+        .handle(payload.into_self_update_command(authenticated_user))
         .await
     {
-        Ok(user_data) => Ok((StatusCode::OK, Json(UserResponse::new(user_data)))),
-        Err(e @ UserApplicationError::DuplicatedUser(_)) => {
-            Err((StatusCode::BAD_REQUEST, e.to_string()))
+        Ok(user_data) => Ok((StatusCode::OK, Json(UserResponse::from_view(user_data)))),
+        Err(e @ UserApplicationError::DuplicatedUser(_)) => Err(ProblemDetails::new(
+            StatusCode::UNPROCESSABLE_ENTITY,
+            e.localize(*locale),
+        )),
+        Err(e @ UserApplicationError::IllegalArgumentError(_)) => Err(ProblemDetails::new(
+            StatusCode::UNPROCESSABLE_ENTITY,
+            e.localize(*locale),
+        )),
+        Err(e @ UserApplicationError::IllegalUserId(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ UserApplicationError::IllegalUserRole(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ UserApplicationError::PasswordMismatch) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ UserApplicationError::AccountLocked { .. }) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ UserApplicationError::PermissionDenied(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+        Err(e @ UserApplicationError::UserNotFound(_)) => Err(ProblemDetails::new(
+            StatusCode::NOT_FOUND,
+            e.localize(*locale),
+        )),
+        Err(e @ UserApplicationError::Unexpected(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )),
+    }
+}
+
+// 本人か管理者だけが削除できる。管理者はパスワードを確認せずに物理削除する
+pub async fn delete<Rep, CredentialRep, AS>(
+    Extension(locale): Extension<Arc<Locale>>,
+    Extension(repository): Extension<Arc<Rep>>,
+    Extension(credential_repository): Extension<Arc<CredentialRep>>,
+    Extension(authenticated_user): Extension<AuthenticatedUser>,
+    Path(id): Path<String>,
+    payload: Option<Json<UserDeletePayload>>,
+) -> Result<StatusCode, Response>
+where
+    Rep: IUserRepository,
+    CredentialRep: ICredentialRepository,
+    AS: IUserDeleteApplicationService<Rep, CredentialRep>,
+{
+    let user_delete_application_service = AS::new(repository, credential_repository);
+    let payload = payload.map(|Json(payload)| payload).unwrap_or_default();
+    let anonymize = payload.anonymize;
+    let command = payload.into_command(id, authenticated_user);
+    let result = if anonymize {
+        user_delete_application_service
+            .soft_delete_with_anonymization(command)
+            .await
+    } else {
+        user_delete_application_service.handle(command).await
+    };
+
+    match result {
+        Ok(_) => Ok(StatusCode::NO_CONTENT),
+        Err(e @ UserApplicationError::DuplicatedUser(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )
+        .into_response()),
+        Err(e @ UserApplicationError::IllegalArgumentError(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )
+        .into_response()),
+        Err(e @ UserApplicationError::IllegalUserId(_)) => {
+            Err(ProblemDetails::new(StatusCode::BAD_REQUEST, e.localize(*locale)).into_response())
         }
-        Err(e @ UserApplicationError::IllegalArgumentError(_)) => {
-            Err((StatusCode::BAD_REQUEST, e.to_string()))
+        Err(e @ UserApplicationError::IllegalUserRole(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )
+        .into_response()),
+        Err(e @ UserApplicationError::PasswordMismatch) => {
+            Err(ProblemDetails::new(StatusCode::FORBIDDEN, e.localize(*locale)).into_response())
         }
-        Err(e @ UserApplicationError::IllegalUserId(_)) => {
-            Err((StatusCode::BAD_REQUEST, e.to_string()))
+        Err(UserApplicationError::AccountLocked { until }) => Err(account_locked(until, *locale)),
+        Err(e @ UserApplicationError::PermissionDenied(_)) => {
+            Err(ProblemDetails::new(StatusCode::FORBIDDEN, e.localize(*locale)).into_response())
         }
         Err(e @ UserApplicationError::UserNotFound(_)) => {
-            Err((StatusCode::NOT_FOUND, e.to_string()))
-        }
-        Err(e @ UserApplicationError::Unexpected(_)) => {
-            Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+            Err(ProblemDetails::new(StatusCode::NOT_FOUND, e.localize(*locale)).into_response())
         }
+        Err(e @ UserApplicationError::Unexpected(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )
+        .into_response()),
     }
 }
 
-pub async fn delete<Rep, AS>(
-    Extension(repository): Extension<Arc<Rep>>,
+// 本人だけが変更できる。ログインと同じく、現在のパスワードを続けて間違えるとロックする
+pub async fn change_password<CredentialRep, AS>(
+    Extension(locale): Extension<Arc<Locale>>,
+    Extension(credential_repository): Extension<Arc<CredentialRep>>,
+    Extension(authenticated_user): Extension<AuthenticatedUser>,
     Path(id): Path<String>,
-) -> Result<StatusCode, impl IntoResponse>
+    Json(payload): Json<UserPasswordChangePayload>,
+) -> Result<StatusCode, Response>
 where
-    Rep: IUserRepository,
-    AS: IUserDeleteApplicationService<Rep>,
+    CredentialRep: ICredentialRepository,
+    AS: IUserPasswordChangeApplicationService<CredentialRep>,
 {
-    let user_delete_application_service = AS::new(repository);
+    let user_password_change_application_service = AS::new(credential_repository);
 
-    match user_delete_application_service
-        .handle(UserDeleteCommand { user_id: id })
+    match user_password_change_application_service
+        .handle(payload.into_command(id, authenticated_user))
         .await
     {
-        Ok(_) => Ok(StatusCode::NO_CONTENT),
-        Err(e @ UserApplicationError::DuplicatedUser(_)) => {
-            Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
-        }
+        Ok(()) => Ok(StatusCode::NO_CONTENT),
+        Err(e @ UserApplicationError::DuplicatedUser(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )
+        .into_response()),
+        // 新しいパスワードが弱すぎる
         Err(e @ UserApplicationError::IllegalArgumentError(_)) => {
-            Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+            Err(ProblemDetails::new(StatusCode::BAD_REQUEST, e.localize(*locale)).into_response())
         }
         Err(e @ UserApplicationError::IllegalUserId(_)) => {
-            Err((StatusCode::BAD_REQUEST, e.to_string()))
+            Err(ProblemDetails::new(StatusCode::BAD_REQUEST, e.localize(*locale)).into_response())
         }
-        Err(e @ UserApplicationError::UserNotFound(_)) => {
-            Err((StatusCode::NOT_FOUND, e.to_string()))
+        Err(e @ UserApplicationError::IllegalUserRole(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )
+        .into_response()),
+        Err(e @ UserApplicationError::PasswordMismatch) => {
+            Err(ProblemDetails::new(StatusCode::FORBIDDEN, e.localize(*locale)).into_response())
+        }
+        Err(UserApplicationError::AccountLocked { until }) => Err(account_locked(until, *locale)),
+        Err(e @ UserApplicationError::PermissionDenied(_)) => {
+            Err(ProblemDetails::new(StatusCode::FORBIDDEN, e.localize(*locale)).into_response())
         }
-        Err(e @ UserApplicationError::Unexpected(_)) => {
-            Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+        Err(e @ UserApplicationError::UserNotFound(_)) => {
+            Err(ProblemDetails::new(StatusCode::NOT_FOUND, e.localize(*locale)).into_response())
         }
+        Err(e @ UserApplicationError::Unexpected(_)) => Err(ProblemDetails::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            e.localize(*locale),
+        )
+        .into_response()),
     }
 }
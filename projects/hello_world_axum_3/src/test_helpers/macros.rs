@@ -0,0 +1,144 @@
+// entity の `PartialEq` は id しか比べないため、テストでは項目ごとに比べて違いをまとめて表示する
+use std::fmt::Debug;
+
+use chrono::{DateTime, Utc};
+
+use crate::domain::models::{labels::label::Label, todos::todo::Todo, users::user::User};
+
+// 食い違った項目を `項目名: expected `..`, actual `..`` の形で集める
+#[derive(Default)]
+pub struct FieldDiffs {
+    diffs: Vec<String>,
+}
+
+impl FieldDiffs {
+    pub fn compare<T: PartialEq + Debug>(&mut self, field: &str, expected: T, actual: T) {
+        if expected != actual {
+            self.diffs.push(format!(
+                "  {field}: expected `{expected:?}`, actual `{actual:?}`"
+            ));
+        }
+    }
+
+    // 違いがあれば、どの項目が食い違ったかを並べて panic する
+    pub fn assert_empty(self, type_name: &str) {
+        if !self.diffs.is_empty() {
+            panic!("{type_name} mismatch:\n{}", self.diffs.join("\n"));
+        }
+    }
+}
+
+// Postgres の timestamptz はマイクロ秒までしか保存しないため、その精度で比べる
+fn micros(date_time: &DateTime<Utc>) -> i64 {
+    date_time.timestamp_micros()
+}
+
+pub fn todo_diffs(expected: &Todo, actual: &Todo) -> FieldDiffs {
+    let mut diffs = FieldDiffs::default();
+    diffs.compare("todo_id", expected.todo_id(), actual.todo_id());
+    diffs.compare("todo_text", &expected.todo_text, &actual.todo_text);
+    diffs.compare("note", &expected.note, &actual.note);
+    diffs.compare("due_date", &expected.due_date, &actual.due_date);
+    diffs.compare("assignee_id", &expected.assignee_id, &actual.assignee_id);
+    diffs.compare("completed", &expected.completed, &actual.completed);
+    diffs.compare("labels", &expected.labels, &actual.labels);
+    diffs.compare("version", expected.version(), actual.version());
+    diffs.compare(
+        "created_at",
+        micros(expected.created_at()),
+        micros(actual.created_at()),
+    );
+    diffs.compare(
+        "updated_at",
+        micros(expected.updated_at()),
+        micros(actual.updated_at()),
+    );
+    diffs
+}
+
+pub fn label_diffs(expected: &Label, actual: &Label) -> FieldDiffs {
+    let mut diffs = FieldDiffs::default();
+    diffs.compare("label_id", expected.label_id(), actual.label_id());
+    diffs.compare("label_name", &expected.label_name, &actual.label_name);
+    diffs
+}
+
+pub fn user_diffs(expected: &User, actual: &User) -> FieldDiffs {
+    let mut diffs = FieldDiffs::default();
+    diffs.compare("user_id", expected.user_id(), actual.user_id());
+    diffs.compare("user_name", &expected.user_name, &actual.user_name);
+    diffs.compare("user_role", &expected.user_role, &actual.user_role);
+    diffs
+}
+
+macro_rules! assert_todo_equal {
+    ($expected:expr, $actual:expr $(,)?) => {
+        $crate::test_helpers::macros::todo_diffs(&$expected, &$actual).assert_empty("Todo")
+    };
+}
+
+macro_rules! assert_label_equal {
+    ($expected:expr, $actual:expr $(,)?) => {
+        $crate::test_helpers::macros::label_diffs(&$expected, &$actual).assert_empty("Label")
+    };
+}
+
+macro_rules! assert_user_equal {
+    ($expected:expr, $actual:expr $(,)?) => {
+        $crate::test_helpers::macros::user_diffs(&$expected, &$actual).assert_empty("User")
+    };
+}
+
+pub(crate) use assert_label_equal;
+pub(crate) use assert_todo_equal;
+pub(crate) use assert_user_equal;
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use crate::domain::{
+        models::{
+            labels::label_name::LabelName, todos::todo_text::TodoText, users::user_name::UserName,
+        },
+        value_object::ValueObject,
+    };
+
+    use super::*;
+
+    #[test]
+    fn should_pass_if_every_field_is_equal() -> Result<()> {
+        let todo = Todo::new(TodoText::new("todo-1".to_string())?, vec![])?;
+        assert_todo_equal!(todo, todo.clone());
+        let label = Label::new(LabelName::new("label-1".to_string())?)?;
+        assert_label_equal!(label, label.clone());
+        let user = User::new(UserName::new("tester-1".to_string())?)?;
+        assert_user_equal!(user, user.clone());
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic(expected = "completed: expected `false`, actual `true`")]
+    fn should_panic_with_mismatched_field() {
+        let expected = Todo::new(TodoText::new("todo-1".to_string()).unwrap(), vec![]).unwrap();
+        let mut actual = expected.clone();
+        actual.completed = true;
+        assert_todo_equal!(expected, actual);
+    }
+
+    #[test]
+    fn should_list_only_mismatched_fields() -> Result<()> {
+        let expected = User::new(UserName::new("tester-1".to_string())?)?;
+        let mut actual = expected.clone();
+        actual.user_name = UserName::new("tester-2".to_string())?;
+
+        let message = std::panic::catch_unwind(|| assert_user_equal!(expected, actual))
+            .unwrap_err()
+            .downcast::<String>()
+            .unwrap();
+        assert!(message.starts_with("User mismatch:\n  user_name:"));
+        assert!(!message.contains("user_id"));
+        assert!(!message.contains("user_role"));
+        Ok(())
+    }
+}
@@ -0,0 +1,66 @@
+use anyhow::Result;
+use axum::http::{header::AUTHORIZATION, HeaderValue};
+use axum_test::{TestRequest, TestServer};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use hello_world_axum_3::{
+    domain::{
+        models::{
+            credentials::{
+                credential_repository::ICredentialRepository,
+                password_credential::PasswordCredential,
+            },
+            users::{user::User, user_name::UserName, user_repository::IUserRepository},
+        },
+        value_object::ValueObject,
+    },
+    router::{create_app, ArgCreateApp},
+};
+
+// in-memory のリポジトリを使うアプリケーションを起動する
+pub fn test_server() -> TestServer {
+    TestServer::new(create_app(ArgCreateApp::default())).expect("failed to start test server")
+}
+
+// パスワードを登録したユーザーを用意してから起動し、そのユーザーの id とあわせて返す
+pub async fn test_server_with_user(
+    user_name: &str,
+    password: &str,
+) -> Result<(TestServer, String)> {
+    let arg_create_app = ArgCreateApp::default();
+    let user = User::new(UserName::new(user_name.to_string())?)?;
+    arg_create_app.user_repository().save(&user).await?;
+    arg_create_app
+        .credential_repository()
+        .save(&PasswordCredential::new(user.user_id().clone(), password)?)
+        .await?;
+
+    let server = TestServer::new(create_app(arg_create_app))?;
+    Ok((server, user.user_id().to_string()))
+}
+
+// リクエストに認証情報を付ける
+pub trait WithAuthorization {
+    fn basic_auth(self, user_id: &str, password: &str) -> Self;
+    fn bearer_auth(self, access_token: &str) -> Self;
+}
+
+impl WithAuthorization for TestRequest {
+    fn basic_auth(self, user_id: &str, password: &str) -> Self {
+        let credentials = STANDARD.encode(format!("{}:{}", user_id, password));
+        self.add_header(
+            AUTHORIZATION,
+            authorization(format!("Basic {}", credentials)),
+        )
+    }
+
+    fn bearer_auth(self, access_token: &str) -> Self {
+        self.add_header(
+            AUTHORIZATION,
+            authorization(format!("Bearer {}", access_token)),
+        )
+    }
+}
+
+fn authorization(value: String) -> HeaderValue {
+    HeaderValue::from_str(&value).expect("invalid authorization header")
+}
@@ -0,0 +1,6 @@
+// `create_app` で組み立てたルーター全体 (認証・CORS・リクエスト ID などのミドルウェアを含む) を
+// `axum_test::TestServer` で起動し、HTTP のリクエストとレスポンスを通して確認する
+mod helpers;
+mod test_label_crud;
+mod test_todo_crud;
+mod test_user_crud;
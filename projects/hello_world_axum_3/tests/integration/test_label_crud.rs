@@ -0,0 +1,47 @@
+use anyhow::Result;
+use axum::http::StatusCode;
+use serde_json::{json, Value};
+
+use crate::helpers::test_server;
+
+#[tokio::test]
+async fn should_create_get_update_and_delete_label() -> Result<()> {
+    let server = test_server();
+
+    // create
+    let res = server
+        .post("/labels")
+        .json(&json!({ "name": "label-1" }))
+        .await;
+    assert_eq!(StatusCode::CREATED, res.status_code());
+    let created: Value = res.json();
+    let label_id = created["id"].as_str().unwrap().to_string();
+    assert_eq!("label-1", created["name"]);
+
+    // get
+    let res = server.get(&format!("/labels/{}", label_id)).await;
+    assert_eq!(StatusCode::OK, res.status_code());
+    assert_eq!("label-1", res.json::<Value>()["name"]);
+
+    // update
+    let res = server
+        .patch(&format!("/labels/{}", label_id))
+        .json(&json!({ "name": "label-2" }))
+        .await;
+    assert_eq!(StatusCode::OK, res.status_code());
+    assert_eq!("label-2", res.json::<Value>()["name"]);
+
+    // get all
+    let res = server.get("/labels").await;
+    assert_eq!(StatusCode::OK, res.status_code());
+    let page: Value = res.json();
+    assert_eq!(1, page["data"].as_array().unwrap().len());
+    assert_eq!("label-2", page["data"][0]["name"]);
+
+    // delete
+    let res = server.delete(&format!("/labels/{}", label_id)).await;
+    assert_eq!(StatusCode::NO_CONTENT, res.status_code());
+    let res = server.get(&format!("/labels/{}", label_id)).await;
+    assert_eq!(StatusCode::NOT_FOUND, res.status_code());
+    Ok(())
+}
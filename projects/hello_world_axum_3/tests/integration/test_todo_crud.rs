@@ -0,0 +1,97 @@
+use anyhow::Result;
+use axum::http::{
+    header::{ACCESS_CONTROL_ALLOW_ORIGIN, ORIGIN},
+    HeaderValue, StatusCode,
+};
+use serde_json::{json, Value};
+
+use crate::helpers::{test_server, test_server_with_user, WithAuthorization};
+
+#[tokio::test]
+async fn should_create_get_update_and_delete_todo() -> Result<()> {
+    let server = test_server();
+
+    // create
+    let res = server
+        .post("/todos")
+        .json(&json!({ "text": "buy milk", "label_ids": [] }))
+        .await;
+    assert_eq!(StatusCode::CREATED, res.status_code());
+    let created: Value = res.json();
+    let todo_id = created["id"].as_str().unwrap().to_string();
+    assert_eq!("buy milk", created["text"]);
+    assert_eq!(false, created["completed"]);
+
+    // get
+    let res = server.get(&format!("/todos/{}", todo_id)).await;
+    assert_eq!(StatusCode::OK, res.status_code());
+    assert_eq!("buy milk", res.json::<Value>()["text"]);
+
+    // update
+    let res = server
+        .patch(&format!("/todos/{}", todo_id))
+        .json(&json!({ "text": "buy bread", "completed": true }))
+        .await;
+    assert_eq!(StatusCode::OK, res.status_code());
+    let updated: Value = res.json();
+    assert_eq!("buy bread", updated["text"]);
+    assert_eq!(true, updated["completed"]);
+
+    // get all
+    let res = server.get("/todos").await;
+    assert_eq!(StatusCode::OK, res.status_code());
+    let page: Value = res.json();
+    assert_eq!(1, page["data"].as_array().unwrap().len());
+    assert_eq!(todo_id, page["data"][0]["id"]);
+
+    // delete
+    let res = server.delete(&format!("/todos/{}", todo_id)).await;
+    assert_eq!(StatusCode::NO_CONTENT, res.status_code());
+    let res = server.get(&format!("/todos/{}", todo_id)).await;
+    assert_eq!(StatusCode::NOT_FOUND, res.status_code());
+    Ok(())
+}
+
+#[tokio::test]
+async fn should_pass_through_cors_and_request_id_layers() -> Result<()> {
+    let server = test_server();
+
+    let res = server
+        .get("/todos")
+        .add_header(ORIGIN, HeaderValue::from_static("http://127.0.0.1:3001"))
+        .await;
+    assert_eq!(StatusCode::OK, res.status_code());
+    assert_eq!(
+        "http://127.0.0.1:3001",
+        res.header(ACCESS_CONTROL_ALLOW_ORIGIN)
+    );
+    assert!(res.maybe_header("x-request-id").is_some());
+    Ok(())
+}
+
+#[tokio::test]
+async fn should_clear_completed_todos_only_if_authenticated() -> Result<()> {
+    let (server, user_id) = test_server_with_user("tester-1", "password1").await?;
+
+    let res = server
+        .post("/todos")
+        .json(&json!({ "text": "todo-1", "label_ids": [], "assignee_id": user_id }))
+        .await;
+    let todo_id = res.json::<Value>()["id"].as_str().unwrap().to_string();
+    server
+        .patch(&format!("/todos/{}", todo_id))
+        .json(&json!({ "completed": true }))
+        .await
+        .assert_status_ok();
+
+    let uri = format!("/users/{}/todos/completed", user_id);
+    let res = server.delete(&uri).await;
+    assert_eq!(StatusCode::UNAUTHORIZED, res.status_code());
+    let res = server.delete(&uri).basic_auth(&user_id, "password2").await;
+    assert_eq!(StatusCode::UNAUTHORIZED, res.status_code());
+
+    let res = server.delete(&uri).basic_auth(&user_id, "password1").await;
+    assert_eq!(StatusCode::OK, res.status_code());
+    assert_eq!(1, res.json::<Value>()["deleted_count"]);
+    Ok(())
+}
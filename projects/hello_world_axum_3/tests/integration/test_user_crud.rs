@@ -0,0 +1,114 @@
+use anyhow::Result;
+use axum::http::StatusCode;
+use serde_json::{json, Value};
+
+use crate::helpers::{test_server, test_server_with_user, WithAuthorization};
+
+#[tokio::test]
+async fn should_create_get_update_and_delete_user() -> Result<()> {
+    let server = test_server();
+
+    // create
+    let res = server
+        .post("/users")
+        .json(&json!({ "user_name": "tester-1" }))
+        .await;
+    assert_eq!(StatusCode::CREATED, res.status_code());
+    let created: Value = res.json();
+    let user_id = created["id"].as_str().unwrap().to_string();
+    assert_eq!("tester-1", created["name"]);
+
+    // get
+    let res = server.get(&format!("/users/{}", user_id)).await;
+    assert_eq!(StatusCode::OK, res.status_code());
+    assert_eq!("tester-1", res.json::<Value>()["name"]);
+
+    // update
+    let res = server
+        .patch(&format!("/users/{}", user_id))
+        .json(&json!({ "user_name": "tester-2" }))
+        .await;
+    assert_eq!(StatusCode::OK, res.status_code());
+    assert_eq!("tester-2", res.json::<Value>()["name"]);
+
+    // get all
+    let res = server.get("/users").await;
+    assert_eq!(StatusCode::OK, res.status_code());
+    let page: Value = res.json();
+    assert_eq!(1, page["data"].as_array().unwrap().len());
+    assert_eq!(user_id, page["data"][0]["id"]);
+
+    // 削除には認証が必要
+    let res = server.delete(&format!("/users/{}", user_id)).await;
+    assert_eq!(StatusCode::UNAUTHORIZED, res.status_code());
+    Ok(())
+}
+
+#[tokio::test]
+async fn should_delete_authenticated_user_with_password_confirmation() -> Result<()> {
+    let (server, user_id) = test_server_with_user("tester-1", "password1").await?;
+
+    let res = server
+        .delete(&format!("/users/{}", user_id))
+        .basic_auth(&user_id, "password1")
+        .json(&json!({ "password_confirmation": "password1" }))
+        .await;
+    assert_eq!(StatusCode::NO_CONTENT, res.status_code());
+    let res = server.get(&format!("/users/{}", user_id)).await;
+    assert_eq!(StatusCode::NOT_FOUND, res.status_code());
+    Ok(())
+}
+
+#[tokio::test]
+async fn should_require_authentication_at_users_me() -> Result<()> {
+    let (server, user_id) = test_server_with_user("tester-1", "password1").await?;
+
+    let res = server.get("/users/me").await;
+    assert_eq!(StatusCode::UNAUTHORIZED, res.status_code());
+
+    let res = server
+        .get("/users/me")
+        .basic_auth(&user_id, "password2")
+        .await;
+    assert_eq!(StatusCode::UNAUTHORIZED, res.status_code());
+
+    let res = server
+        .get("/users/me")
+        .basic_auth(&user_id, "password1")
+        .await;
+    assert_eq!(StatusCode::OK, res.status_code());
+    assert_eq!(user_id, res.json::<Value>()["id"]);
+
+    let res = server
+        .patch("/users/me")
+        .basic_auth(&user_id, "password1")
+        .json(&json!({ "user_name": "updated" }))
+        .await;
+    assert_eq!(StatusCode::OK, res.status_code());
+    assert_eq!("updated", res.json::<Value>()["name"]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn should_log_in_and_out_with_bearer_token() -> Result<()> {
+    let (server, user_id) = test_server_with_user("tester-1", "password1").await?;
+
+    let res = server
+        .post("/auth/login")
+        .json(&json!({ "user_id": user_id, "password": "password1" }))
+        .await;
+    assert_eq!(StatusCode::OK, res.status_code());
+    let session: Value = res.json();
+    let access_token = session["access_token"].as_str().unwrap();
+
+    let res = server.get("/users/me").bearer_auth(access_token).await;
+    assert_eq!(StatusCode::OK, res.status_code());
+
+    let res = server.post("/auth/logout").bearer_auth(access_token).await;
+    assert_eq!(StatusCode::NO_CONTENT, res.status_code());
+
+    // ログアウト後は同じトークンで認証できない
+    let res = server.get("/users/me").bearer_auth(access_token).await;
+    assert_eq!(StatusCode::UNAUTHORIZED, res.status_code());
+    Ok(())
+}
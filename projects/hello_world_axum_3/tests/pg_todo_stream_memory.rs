@@ -0,0 +1,102 @@
+// todo を 1 件ずつ流したときに、全件を読み込まずに済んでいるかを確保したメモリの量で確かめる
+// `#[global_allocator]` はバイナリ全体のアロケータを差し替えるため、ライブラリのテストとは別のバイナリにする
+// このバイナリにはこのテストしか置かないので、他のテストの確保量は混ざらない
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    env,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use anyhow::Result;
+use dotenv::dotenv;
+use futures_util::StreamExt;
+use sqlx::PgPool;
+use tokio_util::sync::CancellationToken;
+
+use hello_world_axum_3::{
+    domain::{models::todos::todo_repository::ITodoRepository, value_object::ValueObject},
+    infra::repository_impl::pg::pg_todo_repository::PgTodoRepository,
+};
+
+// 確保中のバイト数とその最大値を記録するアロケータ
+struct CountingAllocator;
+
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+static PEAK_ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            let current = ALLOCATED.fetch_add(layout.size(), Ordering::Relaxed) + layout.size();
+            PEAK_ALLOCATED.fetch_max(current, Ordering::Relaxed);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        ALLOCATED.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+async fn connect_to_test_pg_pool() -> PgPool {
+    dotenv().ok();
+    let database_url = &env::var("DATABASE_URL_TEST").expect("undefined [DATABASE_URL_TEST]");
+    PgPool::connect(database_url)
+        .await
+        .unwrap_or_else(|_| panic!("fail connect test database, url is [{}]", database_url))
+}
+
+#[tokio::test]
+async fn should_stream_large_number_of_todos_with_bounded_memory() -> Result<()> {
+    const TODO_COUNT: usize = 10_000;
+    const MAX_PEAK_BYTES: usize = 10 * 1024 * 1024;
+    const TEXT_PREFIX: &str = "streamed todo ";
+
+    let pool = connect_to_test_pg_pool().await;
+
+    // 流す処理は別の接続で読み出すため、トランザクションを巻き戻す代わりに最後に削除する
+    let sql = r#"
+        insert into todos (id, text, completed, created_at, updated_at)
+        select gen_random_uuid(), $1 || i, false, now(), now()
+        from generate_series(1, $2) as i"#;
+    sqlx::query(sql)
+        .bind(TEXT_PREFIX)
+        .bind(TODO_COUNT as i32)
+        .execute(&pool)
+        .await?;
+
+    let todo_repository = PgTodoRepository::new(pool.clone());
+
+    let base_allocated = ALLOCATED.load(Ordering::Relaxed);
+    PEAK_ALLOCATED.store(base_allocated, Ordering::Relaxed);
+
+    // 1 件ずつ読み出し、保持せずに数えるだけにする
+    let mut streamed_count = 0;
+    let mut todos_found = todo_repository.find_all_stream(CancellationToken::new());
+    while let Some(todo_found) = todos_found.next().await {
+        if todo_found?.todo_text.value().starts_with(TEXT_PREFIX) {
+            streamed_count += 1;
+        }
+    }
+    drop(todos_found);
+
+    let peak_bytes = PEAK_ALLOCATED.load(Ordering::Relaxed) - base_allocated;
+
+    sqlx::query(r#"delete from todos where text like $1 || '%'"#)
+        .bind(TEXT_PREFIX)
+        .execute(&pool)
+        .await?;
+
+    assert_eq!(TODO_COUNT, streamed_count);
+    assert!(
+        peak_bytes < MAX_PEAK_BYTES,
+        "peak memory while streaming was {} bytes",
+        peak_bytes
+    );
+    Ok(())
+}